@@ -1,6 +1,5 @@
 use indexmap::IndexMap;
 use log::trace;
-use petgraph::graph::Graph;
 use petgraph::Incoming;
 use proxy_wasm::traits::Context;
 use proxy_wasm::traits::HttpContext;
@@ -12,15 +11,19 @@ use std::convert::TryFrom;
 use std::fmt;
 use std::time::Duration;
 
-use utils::graph::iso::find_mapping_shamir_decentralized;
+use utils::graph::iso::find_accepted_mapping_decentralized;
 use utils::graph::graph_utils::get_node_with_id;
+use utils::graph::graph_utils::TargetGraphType;
+use utils::graph::graph_utils::Value;
 use utils::graph::serde::FerriedData;
 
 // These are generated by the filter
+use super::filter::collect_edge_properties;
 use super::filter::collect_envoy_properties;
-use super::filter::create_target_graph;
+use super::filter::create_target_graphs;
 use super::filter::execute_udfs;
 use super::filter::check_trace_lvl_prop;
+use super::filter::check_expression_filters;
 use super::filter::get_value_for_storage;
 use super::filter::get_root_name;
 
@@ -161,13 +164,13 @@ pub fn _start() {
     proxy_wasm::set_log_level(LogLevel::Info);
     proxy_wasm::set_root_context(|_| -> Box<dyn RootContext> {
         Box::new(HttpHeadersRoot {
-            target_graph: create_target_graph(),
+            target_graphs: create_target_graphs(),
         })
     });
 }
 
 struct HttpHeadersRoot {
-    target_graph: Graph<(String, IndexMap<u64, String>), ()>,
+    target_graphs: Vec<TargetGraphType>,
 }
 
 impl Context for HttpHeadersRoot {}
@@ -196,7 +199,7 @@ impl RootContext for HttpHeadersRoot {
             workload_name,
             // FIXME: This should be a reference instead of a copy
             // Extremely annoying but I can not guarantee a life-time here
-            target_graph: self.target_graph.clone(),
+            target_graphs: self.target_graphs.clone(),
         }))
     }
 }
@@ -204,7 +207,7 @@ impl RootContext for HttpHeadersRoot {
 pub struct HttpHeaders {
     pub context_id: u32,
     pub workload_name: String,
-    pub target_graph: Graph<(String, IndexMap<u64, String>), ()>,
+    pub target_graphs: Vec<TargetGraphType>,
 }
 
 impl Context for HttpHeaders {}
@@ -322,7 +325,7 @@ impl HttpHeaders {
             return;
         }
         let mut my_indexmap = IndexMap::new();
-        my_indexmap.insert(0, self.workload_name.clone());
+        my_indexmap.insert(0, Value::Str(self.workload_name.clone()));
 
         // Retrieve the data we have stored
         let stored_data_opt = get_shared_data(&trace_id, self);
@@ -356,7 +359,7 @@ impl HttpHeaders {
         for previous_root in previous_roots {
             stored_data
                 .trace_graph
-                .add_edge(me, previous_root, ());
+                .add_edge(me, previous_root, IndexMap::new());
         }
         stored_data.assign_properties();
         execute_udfs(self, &mut stored_data);
@@ -364,23 +367,37 @@ impl HttpHeaders {
         if !stored_data.found_match {
             let am_root = self.workload_name == get_root_name();
 
-            let mapping_opt =
-                find_mapping_shamir_decentralized(
+            let workload_node = get_node_with_id(&stored_data.trace_graph, &self.workload_name).unwrap();
+            // every target graph has its own S set, and the first one to match, with a
+            // mapping that passes the expression filters, is used
+            let mut set_s = stored_data.set_s.take().unwrap();
+            set_s.resize_with(self.target_graphs.len(), IndexMap::new);
+            let mut mapping_opt = None;
+            for (target_graph, target_set_s) in self.target_graphs.iter().zip(set_s.iter_mut()) {
+                let target_mapping = find_accepted_mapping_decentralized(
                     &stored_data.trace_graph,
-                    &self.target_graph,
-                    stored_data.set_s.as_mut().unwrap(),
-                    get_node_with_id(&stored_data.trace_graph, &self.workload_name).unwrap(),
-                    am_root);
+                    target_graph,
+                    target_set_s,
+                    workload_node,
+                    am_root,
+                    |mapping| check_expression_filters(target_graph, mapping, &stored_data).is_some());
+                if let Some(mapping) = target_mapping {
+                    mapping_opt = Some((target_graph, mapping));
+                    break;
+                }
+            }
+            stored_data.set_s = Some(set_s);
             if mapping_opt.is_some() && check_trace_lvl_prop(self, &mut stored_data) {
-                let mapping = mapping_opt.unwrap();
-                stored_data.found_match = true;
+                let (target_graph, mapping) = mapping_opt.unwrap();
                 let key = join_str(&vec!["node", "metadata", "WORKLOAD_NAME"]);
                 let value_wrapped =
-                    get_value_for_storage(&self.target_graph, &mapping, &stored_data);
+                    get_value_for_storage(target_graph, &mapping, &stored_data);
                 if value_wrapped.is_none() {
                     return;
                 }
                 let value = value_wrapped.unwrap();
+                // only a trace whose value is stored has found its match
+                stored_data.found_match = true;
                 let call_result = self.dispatch_http_call(
                     "storage-upstream",
                     vec![
@@ -424,6 +441,13 @@ impl HttpHeaders {
         }
         // Fetch ferried data
         let mut ferried_data = fetch_data_from_headers(self, HttpType::Response);
+        // the properties of the call are collected by the caller, as its response comes back
+        match collect_edge_properties(self, &mut ferried_data) {
+            Ok(_) => {}
+            Err(_) => {
+                return;
+            }
+        }
 
         store_data(&mut ferried_data, &trace_id, self);
     }
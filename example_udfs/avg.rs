@@ -3,12 +3,13 @@
 // exec_func: execute
 // struct_name: Avg
 // id: avg
+// arg_type: i64
 
 #[derive(Clone, Copy, Debug, Serialize, Deserialize)]
 pub struct Avg {
-    avg: u64,
-    total: u64,
-    num_instances: u64,
+    avg: i64,
+    total: i64,
+    num_instances: i64,
 }
 
 impl Avg {
@@ -16,7 +17,7 @@ impl Avg {
         Avg { avg: 0, total: 0 , num_instances: 0}
     }
     fn execute(&mut self, _trace_id: &str, instance: String) -> String {
-        self.total += instance.parse::<u64>().unwrap();
+        self.total += instance.parse::<i64>().unwrap();
         self.num_instances += 1;
         self.avg = self.total/self.num_instances;
         self.avg.to_string()
@@ -3,12 +3,12 @@
 // mid_func: mid_height
 // id: height
 
-fn leaf_height(_graph: &Graph<(String, IndexMap<u64, String>), ()>) -> u32 {
+fn leaf_height(_graph: &Graph<(String, IndexMap<u64, Value>), IndexMap<u64, Value>>) -> u32 {
     return 0;
 }
 
 // TODO:  must children's responses always be in string form?  can we generalize?
-fn mid_height(_graph: &Graph<(String, IndexMap<u64, String>), ()>, children_responses: Vec<String>) -> u32 {
+fn mid_height(_graph: &Graph<(String, IndexMap<u64, Value>), IndexMap<u64, Value>>, children_responses: Vec<String>) -> u32 {
     let mut max = 0;
     for response in children_responses {
         let response_as_u32 = response.parse::<u32>();
@@ -1,6 +1,5 @@
 use indexmap::IndexMap;
 use log::trace;
-use petgraph::graph::Graph;
 use petgraph::Incoming;
 use proxy_wasm::traits::Context;
 use proxy_wasm::traits::HttpContext;
@@ -12,12 +11,16 @@ use std::convert::TryFrom;
 use std::fmt;
 use std::time::Duration;
 
-use utils::graph::iso::find_mapping_shamir_centralized;
+use utils::graph::graph_utils::TargetGraphType;
+use utils::graph::graph_utils::Value;
+use utils::graph::iso::find_accepted_mapping_centralized;
 use utils::graph::serde::FerriedData;
 
 // These are generated by the filter
+use super::filter::check_expression_filters;
+use super::filter::collect_edge_properties;
 use super::filter::collect_envoy_properties;
-use super::filter::create_target_graph;
+use super::filter::create_target_graphs;
 use super::filter::execute_udfs_and_check_trace_lvl_prop;
 use super::filter::get_value_for_storage;
 
@@ -158,13 +161,13 @@ pub fn _start() {
     proxy_wasm::set_log_level(LogLevel::Info);
     proxy_wasm::set_root_context(|_| -> Box<dyn RootContext> {
         Box::new(HttpHeadersRoot {
-            target_graph: create_target_graph(),
+            target_graphs: create_target_graphs(),
         })
     });
 }
 
 struct HttpHeadersRoot {
-    target_graph: Graph<(String, IndexMap<u64, String>), ()>,
+    target_graphs: Vec<TargetGraphType>,
 }
 
 impl Context for HttpHeadersRoot {}
@@ -193,7 +196,7 @@ impl RootContext for HttpHeadersRoot {
             workload_name,
             // TODO: This should be a reference instead of a copy
             // Extremely annoying but I can not guarantee a life-time here
-            target_graph: self.target_graph.clone(),
+            target_graphs: self.target_graphs.clone(),
         }))
     }
 }
@@ -201,7 +204,7 @@ impl RootContext for HttpHeadersRoot {
 pub struct HttpHeaders {
     pub context_id: u32,
     pub workload_name: String,
-    pub target_graph: Graph<(String, IndexMap<u64, String>), ()>,
+    pub target_graphs: Vec<TargetGraphType>,
 }
 
 impl Context for HttpHeaders {
@@ -292,7 +295,7 @@ impl HttpHeaders {
         let mut my_indexmap = IndexMap::new();
         my_indexmap.insert(
             0, 
-            self.workload_name.clone(),
+            Value::Str(self.workload_name.clone()),
         );
 
         // Retrieve the data we have stored
@@ -320,7 +323,7 @@ impl HttpHeaders {
             .add_node((self.workload_name.clone(), my_indexmap));
 
         for previous_root in previous_roots {
-            stored_data.trace_graph.add_edge(me, previous_root, ());
+            stored_data.trace_graph.add_edge(me, previous_root, IndexMap::new());
         }
         stored_data.assign_properties();
 
@@ -331,10 +334,17 @@ impl HttpHeaders {
         if self.workload_name == "productpage-v1" && trace_prop_sat {
             // 2. calculate UDFs and store result, and check trace level properties
 
-            if let Some(mapping) =
-                find_mapping_shamir_centralized(&stored_data.trace_graph, &self.target_graph)
+            // the first target graph to match, with a mapping that passes the
+            // expression filters, is used
+            if let Some((target_graph, mapping)) =
+                self.target_graphs.iter().find_map(|target_graph| {
+                    find_accepted_mapping_centralized(&stored_data.trace_graph, target_graph, |mapping| {
+                        check_expression_filters(target_graph, mapping, &stored_data).is_some()
+                    })
+                    .map(|mapping| (target_graph, mapping))
+                })
             {
-                let value = get_value_for_storage(&self.target_graph, &mapping, &stored_data)
+                let value = get_value_for_storage(target_graph, &mapping, &stored_data)
                     .ok_or_else(|| "Failed to retrieve value from storage.")?;
                 let call_result = self.dispatch_http_call(
                     "storage-upstream",
@@ -376,6 +386,8 @@ impl HttpHeaders {
             .ok_or_else(|| "Response outbound: x-request-id not found in header!")?;
         // Fetch ferried data
         let mut ferried_data = fetch_data_from_headers(self, HttpType::Response);
+        // the properties of the call are collected by the caller, as its response comes back
+        collect_edge_properties(self, &mut ferried_data)?;
 
         store_data(&mut ferried_data, &trace_id, self);
         Ok(())
@@ -1,11 +1,653 @@
 /* This file contains functions relating to creating and comparing trace and target (user-given) graphs */
 
 use indexmap::map::IndexMap;
+use petgraph::algo::is_cyclic_undirected;
 use petgraph::graph::{Graph, NodeIndex};
 use petgraph::visit::DfsPostOrder;
 use petgraph::Incoming;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+// trace graph edges are calls, and carry the properties the caller collected for them
+pub type GraphType = Graph<(String, IndexMap<u64, Value>), IndexMap<u64, Value>>;
+pub type TargetGraphType = Graph<(String, Vec<Predicate>), EdgeFilter>;
+
+/* The value of a property in a trace graph.  Which variant a property has is fixed
+ * by its type in the property_to_type table of the compiler, so 5 and "5" are
+ * different values.  Durations and timestamps are in nanoseconds.  Values only
+ * have the partial order of partial_cmp_typed, and floats are never NaN, which
+ * ValueType::parse and from_text reject, so every value is equal to itself.
+ */
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum Value {
+    Int(i64),
+    Uint(u64),
+    Float(f64),
+    Bool(bool),
+    Str(String),
+    Duration(u64),
+    Timestamp(u64),
+}
+
+impl Eq for Value {}
+
+impl Hash for Value {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            Value::Int(value) => value.hash(state),
+            Value::Uint(value) | Value::Duration(value) | Value::Timestamp(value) => {
+                value.hash(state)
+            }
+            // 0.0 and -0.0 are equal, so they have to hash alike
+            Value::Float(value) if *value == 0.0 => 0u64.hash(state),
+            Value::Float(value) => value.to_bits().hash(state),
+            Value::Bool(value) => value.hash(state),
+            Value::Str(value) => value.hash(state),
+        }
+    }
+}
+
+impl Value {
+    /* Reads the text an expression evaluates to.  Integers and finite floats are
+     * numbers, and anything else is a string.
+     */
+    pub fn from_text(text: &str) -> Value {
+        if let Ok(value) = text.parse::<i64>() {
+            return Value::Int(value);
+        }
+        if let Ok(value) = text.parse::<u64>() {
+            return Value::Uint(value);
+        }
+        match text.parse::<f64>() {
+            Ok(value) if value.is_finite() => Value::Float(value),
+            _ => Value::Str(text.to_string()),
+        }
+    }
+
+    /* Orders two values of the same kind.  Integers and floats compare by their
+     * numeric value, while values of different kinds, say a string and an
+     * integer, have no order at all.
+     */
+    pub fn partial_cmp_typed(&self, other: &Value) -> Option<Ordering> {
+        match (self, other) {
+            (Value::Int(lhs), Value::Int(rhs)) => Some(lhs.cmp(rhs)),
+            (Value::Uint(lhs), Value::Uint(rhs)) => Some(lhs.cmp(rhs)),
+            (Value::Int(lhs), Value::Uint(rhs)) => Some((*lhs as i128).cmp(&(*rhs as i128))),
+            (Value::Uint(lhs), Value::Int(rhs)) => Some((*lhs as i128).cmp(&(*rhs as i128))),
+            (Value::Bool(lhs), Value::Bool(rhs)) => Some(lhs.cmp(rhs)),
+            (Value::Str(lhs), Value::Str(rhs)) => Some(lhs.cmp(rhs)),
+            (Value::Duration(lhs), Value::Duration(rhs)) => Some(lhs.cmp(rhs)),
+            (Value::Timestamp(lhs), Value::Timestamp(rhs)) => Some(lhs.cmp(rhs)),
+            (lhs, rhs) => lhs.as_float()?.partial_cmp(&rhs.as_float()?),
+        }
+    }
+
+    // the value of a number as a float, for comparing integers to floats
+    fn as_float(&self) -> Option<f64> {
+        match self {
+            Value::Int(value) => Some(*value as f64),
+            Value::Uint(value) => Some(*value as f64),
+            Value::Float(value) => Some(*value),
+            _ => None,
+        }
+    }
+}
+
+// values print without quotes or units, which is what UDFs and aggregations parse
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Value::Int(value) => write!(f, "{}", value),
+            Value::Uint(value) => write!(f, "{}", value),
+            Value::Float(value) => write!(f, "{}", value),
+            Value::Bool(value) => write!(f, "{}", value),
+            Value::Str(value) => write!(f, "{}", value),
+            Value::Duration(value) => write!(f, "{}", value),
+            Value::Timestamp(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+/* The kinds of values.  The compiler picks the kind of each property and UDF result
+ * from the type it is declared with, and the generated code uses it to turn text
+ * into values, such as the simulator reading properties from its filter state.
+ */
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ValueType {
+    Int,
+    Uint,
+    Float,
+    Bool,
+    Str,
+    Duration,
+    Timestamp,
+}
+
+impl ValueType {
+    /* Maps an envoy property type or a Rust type to the kind of value stored for it.
+     * Maps, metadata and other types nothing is known about have no kind.
+     */
+    pub fn from_type_name(type_name: &str) -> Option<ValueType> {
+        match type_name {
+            "int" | "i8" | "i16" | "i32" | "i64" | "isize" => Some(ValueType::Int),
+            "u8" | "u16" | "u32" | "u64" | "usize" => Some(ValueType::Uint),
+            "f32" | "f64" => Some(ValueType::Float),
+            "bool" => Some(ValueType::Bool),
+            "String" | "str" | "&str" => Some(ValueType::Str),
+            "Duration" => Some(ValueType::Duration),
+            "Timestamp" => Some(ValueType::Timestamp),
+            _ => None,
+        }
+    }
+
+    pub fn is_numeric(&self) -> bool {
+        match self {
+            ValueType::Bool | ValueType::Str => false,
+            ValueType::Int
+            | ValueType::Uint
+            | ValueType::Float
+            | ValueType::Duration
+            | ValueType::Timestamp => true,
+        }
+    }
+
+    pub fn parse(&self, text: &str) -> Option<Value> {
+        match self {
+            ValueType::Int => text.parse().ok().map(Value::Int),
+            ValueType::Uint => text.parse().ok().map(Value::Uint),
+            ValueType::Float => text
+                .parse()
+                .ok()
+                .filter(|value: &f64| !value.is_nan())
+                .map(Value::Float),
+            ValueType::Bool => text.parse().ok().map(Value::Bool),
+            ValueType::Str => Some(Value::Str(text.to_string())),
+            ValueType::Duration => text.parse().ok().map(Value::Duration),
+            ValueType::Timestamp => text.parse().ok().map(Value::Timestamp),
+        }
+    }
+}
+
+/* The comparison a target graph node applies to a property of a trace node.
+ * Typed values are compared with compare_values.  The text of expressions is compared
+ * with compare, which reads both sides with Value::from_text, so that "10" > "9" holds
+ * for response codes and sizes while a number and a string have no order.  The string operators always
+ * compare strings, and RegexMatch has to match the whole value, as =~ does in Cypher.
+ */
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum CompareOp {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    StartsWith,
+    EndsWith,
+    Contains,
+    RegexMatch,
+}
+
+thread_local! {
+    // the regular expressions of =~ comparisons, compiled once per pattern
+    static REGEXES: RefCell<HashMap<String, Option<Regex>>> = RefCell::new(HashMap::new());
+}
+
+/* Whether all of a string matches a regular expression.  The compiler rejects
+ * invalid patterns, so one only shows up here if a predicate was built by hand.
+ */
+fn regex_match(string: &str, pattern: &str) -> bool {
+    REGEXES.with(|regexes| {
+        let mut regexes = regexes.borrow_mut();
+        if !regexes.contains_key(pattern) {
+            let regex = match Regex::new(&format!("^(?:{})$", pattern)) {
+                Ok(regex) => Some(regex),
+                Err(e) => {
+                    log::error!("Invalid regular expression {:?}: {}", pattern, e);
+                    None
+                }
+            };
+            regexes.insert(pattern.to_string(), regex);
+        }
+        regexes[pattern]
+            .as_ref()
+            .map_or(false, |regex| regex.is_match(string))
+    })
+}
+
+impl CompareOp {
+    pub fn compare(&self, lhs: &str, rhs: &str) -> bool {
+        match self {
+            CompareOp::StartsWith => lhs.starts_with(rhs),
+            CompareOp::EndsWith => lhs.ends_with(rhs),
+            CompareOp::Contains => lhs.contains(rhs),
+            CompareOp::RegexMatch => regex_match(lhs, rhs),
+            CompareOp::Eq
+            | CompareOp::Ne
+            | CompareOp::Lt
+            | CompareOp::Le
+            | CompareOp::Gt
+            | CompareOp::Ge => {
+                let ordering = Value::from_text(lhs).partial_cmp_typed(&Value::from_text(rhs));
+                match ordering {
+                    Some(ord) => self.holds_for(ord) == Some(true),
+                    // values without an order are only unequal
+                    None => *self == CompareOp::Ne,
+                }
+            }
+        }
+    }
+
+    /* Whether two values in this order satisfy the operator.  None for the
+     * string operators, which do not compare by order.
+     */
+    fn holds_for(&self, ord: Ordering) -> Option<bool> {
+        match self {
+            CompareOp::Eq => Some(ord == Ordering::Equal),
+            CompareOp::Ne => Some(ord != Ordering::Equal),
+            CompareOp::Lt => Some(ord == Ordering::Less),
+            CompareOp::Le => Some(ord != Ordering::Greater),
+            CompareOp::Gt => Some(ord == Ordering::Greater),
+            CompareOp::Ge => Some(ord != Ordering::Less),
+            CompareOp::StartsWith
+            | CompareOp::EndsWith
+            | CompareOp::Contains
+            | CompareOp::RegexMatch => None,
+        }
+    }
+
+    /* Compares a property value to the value of a predicate.  The result is unknown
+     * if the values have no order, and the string operators only apply to strings.
+     */
+    pub fn compare_values(&self, lhs: &Value, rhs: &Value) -> Option<bool> {
+        match (self, lhs, rhs) {
+            (CompareOp::StartsWith, Value::Str(lhs), Value::Str(rhs))
+            | (CompareOp::EndsWith, Value::Str(lhs), Value::Str(rhs))
+            | (CompareOp::Contains, Value::Str(lhs), Value::Str(rhs))
+            | (CompareOp::RegexMatch, Value::Str(lhs), Value::Str(rhs)) => {
+                Some(self.compare(lhs, rhs))
+            }
+            (CompareOp::StartsWith, _, _)
+            | (CompareOp::EndsWith, _, _)
+            | (CompareOp::Contains, _, _)
+            | (CompareOp::RegexMatch, _, _) => None,
+            (CompareOp::Eq, _, _)
+            | (CompareOp::Ne, _, _)
+            | (CompareOp::Lt, _, _)
+            | (CompareOp::Le, _, _)
+            | (CompareOp::Gt, _, _)
+            | (CompareOp::Ge, _, _) => self.holds_for(lhs.partial_cmp_typed(rhs)?),
+        }
+    }
+}
+
+impl Default for CompareOp {
+    fn default() -> Self {
+        CompareOp::Eq
+    }
+}
+
+// the compiler reads the operators of a query with these
+impl CompareOp {
+    /* Parses the operator at the start of a Cypher partial comparison,
+     * e.g. ">= 500" yields Ge.
+     */
+    pub fn from_cypher_prefix(text: &str) -> Option<Self> {
+        let text = text.trim_start();
+        // two character operators need to be checked first
+        if text.starts_with("<>") {
+            Some(CompareOp::Ne)
+        } else if text.starts_with("<=") {
+            Some(CompareOp::Le)
+        } else if text.starts_with(">=") {
+            Some(CompareOp::Ge)
+        } else if text.starts_with('<') {
+            Some(CompareOp::Lt)
+        } else if text.starts_with('>') {
+            Some(CompareOp::Gt)
+        } else if text.starts_with('=') {
+            Some(CompareOp::Eq)
+        } else {
+            None
+        }
+    }
+
+    // the operator as written in a query
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            CompareOp::Eq => "=",
+            CompareOp::Ne => "<>",
+            CompareOp::Lt => "<",
+            CompareOp::Le => "<=",
+            CompareOp::Gt => ">",
+            CompareOp::Ge => ">=",
+            CompareOp::StartsWith => "STARTS WITH",
+            CompareOp::EndsWith => "ENDS WITH",
+            CompareOp::Contains => "CONTAINS",
+            CompareOp::RegexMatch => "=~",
+        }
+    }
+}
+
+/* The arithmetic operators of expressions in RETURN and WHERE clauses.
+ * Values are added as integers if both sides parse as one, and as floats otherwise.
+ * Applying an operator to something that is not a number, or dividing an integer
+ * by zero, has no result.
+ */
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ArithmeticOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Mod,
+}
+
+impl ArithmeticOp {
+    pub fn from_symbol(symbol: &str) -> Option<Self> {
+        match symbol {
+            "+" => Some(ArithmeticOp::Add),
+            "-" => Some(ArithmeticOp::Sub),
+            "*" => Some(ArithmeticOp::Mul),
+            "/" => Some(ArithmeticOp::Div),
+            "%" => Some(ArithmeticOp::Mod),
+            _ => None,
+        }
+    }
+
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            ArithmeticOp::Add => "+",
+            ArithmeticOp::Sub => "-",
+            ArithmeticOp::Mul => "*",
+            ArithmeticOp::Div => "/",
+            ArithmeticOp::Mod => "%",
+        }
+    }
+
+    pub fn apply(&self, lhs: &str, rhs: &str) -> Option<String> {
+        if let (Ok(lhs_num), Ok(rhs_num)) = (lhs.parse::<i64>(), rhs.parse::<i64>()) {
+            let result = match self {
+                ArithmeticOp::Add => lhs_num.checked_add(rhs_num),
+                ArithmeticOp::Sub => lhs_num.checked_sub(rhs_num),
+                ArithmeticOp::Mul => lhs_num.checked_mul(rhs_num),
+                ArithmeticOp::Div => lhs_num.checked_div(rhs_num),
+                ArithmeticOp::Mod => lhs_num.checked_rem(rhs_num),
+            };
+            return result.map(|num| num.to_string());
+        }
+        let lhs_num = lhs.parse::<f64>().ok()?;
+        let rhs_num = rhs.parse::<f64>().ok()?;
+        let result = match self {
+            ArithmeticOp::Add => lhs_num + rhs_num,
+            ArithmeticOp::Sub => lhs_num - rhs_num,
+            ArithmeticOp::Mul => lhs_num * rhs_num,
+            ArithmeticOp::Div => lhs_num / rhs_num,
+            ArithmeticOp::Mod => lhs_num % rhs_num,
+        };
+        Some(result.to_string())
+    }
+}
+
+/* Anything that can be checked against the properties collected for a trace node.
+ * Target graphs store their property constraints in a type implementing this trait,
+ * which is what the isomorphism algorithm uses to decide if two nodes may be mapped.
+ */
+pub trait PropertyFilter {
+    fn is_satisfied_by(&self, properties: &IndexMap<u64, Value>) -> bool;
+}
+
+// a plain property map is satisfied if every property is present with an equal value
+impl PropertyFilter for IndexMap<u64, Value> {
+    fn is_satisfied_by(&self, properties: &IndexMap<u64, Value>) -> bool {
+        for property in self.keys() {
+            if !properties.contains_key(property) {
+                return false;
+            }
+            if properties[property] != self[property] {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+/* A boolean expression over the properties of a single trace node.
+ * Comparisons against a property the node does not have, or with a value of another
+ * kind, are unknown rather than false, and unknown propagates through And/Or/Xor/Not the way null does in Cypher.
+ * A predicate that ends up unknown does not match.  Exists is never unknown, it
+ * checks whether the property was collected at all (IS NOT NULL).
+ */
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Predicate {
+    Compare(u64, CompareOp, Value),
+    Exists(u64),
+    And(Vec<Predicate>),
+    Or(Vec<Predicate>),
+    Xor(Vec<Predicate>),
+    Not(Box<Predicate>),
+}
+
+impl Predicate {
+    pub fn evaluate(&self, properties: &IndexMap<u64, Value>) -> bool {
+        self.evaluate_ternary(properties).unwrap_or(false)
+    }
+
+    fn evaluate_ternary(&self, properties: &IndexMap<u64, Value>) -> Option<bool> {
+        match self {
+            Predicate::Compare(property, op, value) => properties
+                .get(property)
+                .and_then(|trace_value| op.compare_values(trace_value, value)),
+            Predicate::Exists(property) => Some(properties.contains_key(property)),
+            Predicate::And(operands) => {
+                let mut result = Some(true);
+                for operand in operands {
+                    match operand.evaluate_ternary(properties) {
+                        Some(false) => return Some(false),
+                        None => result = None,
+                        Some(true) => {}
+                    }
+                }
+                result
+            }
+            Predicate::Or(operands) => {
+                let mut result = Some(false);
+                for operand in operands {
+                    match operand.evaluate_ternary(properties) {
+                        Some(true) => return Some(true),
+                        None => result = None,
+                        Some(false) => {}
+                    }
+                }
+                result
+            }
+            Predicate::Xor(operands) => {
+                let mut result = false;
+                for operand in operands {
+                    result ^= operand.evaluate_ternary(properties)?;
+                }
+                Some(result)
+            }
+            Predicate::Not(operand) => operand.evaluate_ternary(properties).map(|value| !value),
+        }
+    }
+}
+
+// a list of predicates is satisfied if every predicate holds
+impl PropertyFilter for Vec<Predicate> {
+    fn is_satisfied_by(&self, properties: &IndexMap<u64, Value>) -> bool {
+        self.iter().all(|predicate| predicate.evaluate(properties))
+    }
+}
+
+// edges that are unweighted or only know their hops place no constraints on calls
+impl PropertyFilter for () {
+    fn is_satisfied_by(&self, _properties: &IndexMap<u64, Value>) -> bool {
+        true
+    }
+}
+
+impl PropertyFilter for Hops {
+    fn is_satisfied_by(&self, _properties: &IndexMap<u64, Value>) -> bool {
+        true
+    }
+}
+
+/* The number of calls a target graph edge may span.  (a)-->(b) spans exactly one call,
+ * while (a)-[*1..3]->(b) matches any b that a reaches in one to three calls.
+ * A max of None means there is no upper bound.
+ */
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Hops {
+    pub min: u32,
+    pub max: Option<u32>,
+}
+
+impl Hops {
+    pub fn new(min: u32, max: Option<u32>) -> Hops {
+        Hops { min, max }
+    }
+
+    pub fn is_direct(&self) -> bool {
+        self.min == 1 && self.max == Some(1)
+    }
+
+    /* Parses a Cypher range literal such as "*", "*2", "*1..3" or "*..3".
+     */
+    pub fn from_range_literal(text: &str) -> Result<Self, String> {
+        let range: String = text
+            .trim_start_matches('*')
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .collect();
+        let parse_bound = |bound: &str| match bound.parse::<u32>() {
+            Ok(value) => Ok(Some(value)),
+            Err(_) if bound.is_empty() => Ok(None),
+            Err(_) => Err(format!("{:?} is not a valid number of hops", bound)),
+        };
+        let (min, max) = match range.find("..") {
+            Some(index) => (
+                parse_bound(&range[..index])?,
+                parse_bound(&range[index + 2..])?,
+            ),
+            None => {
+                // *2 is exactly two hops, while * alone is any number of them
+                let hops = parse_bound(&range)?;
+                (hops, hops)
+            }
+        };
+        let hops = Hops::new(min.unwrap_or(1), max);
+        if hops.min == 0 {
+            return Err("paths of length zero are not supported".to_string());
+        }
+        if let Some(max) = hops.max {
+            if max < hops.min {
+                return Err(format!("{} is less than {}", max, hops.min));
+            }
+        }
+        Ok(hops)
+    }
+}
+
+impl Default for Hops {
+    fn default() -> Self {
+        Hops::new(1, Some(1))
+    }
+}
+
+// edge weights of a target graph tell the matcher how many calls an edge spans,
+// whether the call may go the other way as well, and whether a match may leave
+// out the callee along with everything below it
+pub trait HopRange {
+    fn hops(&self) -> Hops;
+    fn is_undirected(&self) -> bool {
+        false
+    }
+    fn is_optional(&self) -> bool {
+        false
+    }
+}
+
+// graphs without edge weights only have direct calls
+impl HopRange for () {
+    fn hops(&self) -> Hops {
+        Hops::default()
+    }
+}
+
+impl HopRange for Hops {
+    fn hops(&self) -> Hops {
+        *self
+    }
+}
+
+// the edges of a trace graph are single calls
+impl HopRange for IndexMap<u64, Value> {
+    fn hops(&self) -> Hops {
+        Hops::default()
+    }
+}
+
+/* The weight of a target graph edge.  Besides the number of calls the edge spans,
+ * it holds the predicates the call itself has to satisfy, as in
+ * MATCH (a)-[r]->(b) WHERE r.response.code = 200.  Those are checked against the
+ * properties the caller collected for the call, so they only apply to direct calls.
+ * An undirected edge, as in MATCH (a)--(b), matches a call from either end.
+ * An optional edge is the one an OPTIONAL MATCH attaches its part of the
+ * pattern with.  A trace matches with or without the callee and its part.
+ */
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct EdgeFilter {
+    pub hops: Hops,
+    pub predicates: Vec<Predicate>,
+    #[serde(default)]
+    pub undirected: bool,
+    #[serde(default)]
+    pub optional: bool,
+}
+
+impl EdgeFilter {
+    pub fn new(hops: Hops, predicates: Vec<Predicate>) -> EdgeFilter {
+        EdgeFilter {
+            hops,
+            predicates,
+            undirected: false,
+            optional: false,
+        }
+    }
+
+    pub fn optional(mut self) -> EdgeFilter {
+        self.optional = true;
+        self
+    }
+}
+
+impl HopRange for EdgeFilter {
+    fn hops(&self) -> Hops {
+        self.hops
+    }
+
+    fn is_undirected(&self) -> bool {
+        self.undirected
+    }
+
+    fn is_optional(&self) -> bool {
+        self.optional
+    }
+}
+
+impl PropertyFilter for EdgeFilter {
+    fn is_satisfied_by(&self, properties: &IndexMap<u64, Value>) -> bool {
+        self.predicates.is_satisfied_by(properties)
+    }
+}
 
-pub type GraphType = Graph<(String, IndexMap<u64, String>), ()>;
 /* This function creates a petgraph graph representing the query given by the user.
  * For example, if the cql query were MATCH n -> m, e WHERE ... the input to this function
  * would be vertices = [n, m], edges = [(n,m)].
@@ -18,11 +660,11 @@ pub type GraphType = Graph<(String, IndexMap<u64, String>), ()>;
  * @graph: the constructed graph reprsenting the inputs
  */
 
-pub fn generate_target_graph(
+pub fn generate_target_graph<P: Clone + Default, E: Default>(
     vertices: Vec<String>,
     edges: Vec<(String, String)>,
-    ids_to_properties: IndexMap<String, IndexMap<u64, String>>,
-) -> GraphType {
+    ids_to_properties: IndexMap<String, P>,
+) -> Graph<(String, P), E> {
     let mut graph = Graph::new();
 
     // In order to make edges, we have to know the handles of the nodes, and you
@@ -38,7 +680,7 @@ pub fn generate_target_graph(
         } else {
             nodes_to_node_handles.insert(
                 node.clone(),
-                graph.add_node((node.clone(), IndexMap::new())),
+                graph.add_node((node.clone(), P::default())),
             );
         }
     }
@@ -55,8 +697,54 @@ pub fn generate_target_graph(
     graph
 }
 
-pub fn get_node_with_id(
-    graph: &GraphType,
+/* Undirected edges in a query may point either way in the trace.  They are added
+ * the way they are written and marked as undirected, and the matcher tries them
+ * both ways, so a single target graph covers every orientation.
+ *
+ * Arguments:
+ * @vertices:  the vertices of the graphs to construct
+ * @edges:  the directed edges of the graph
+ * @undirected_edges:  the edges that may go from caller to callee in either direction
+ * @edge_hops:  the weights of edges that span more than one call, have predicates,
+ *              or attach an OPTIONAL MATCH, in either direction
+ *
+ * Return Value:
+ * @graph: the target graph; None if the undirected edges close a cycle, which no
+ *         trace can match
+ */
+pub fn generate_filtered_target_graph<P: Clone + Default>(
+    vertices: Vec<String>,
+    edges: Vec<(String, String)>,
+    undirected_edges: Vec<(String, String)>,
+    edge_hops: Vec<(String, String, EdgeFilter)>,
+    ids_to_properties: IndexMap<String, P>,
+) -> Option<Graph<(String, P), EdgeFilter>> {
+    let mut all_edges = edges;
+    all_edges.extend(undirected_edges.iter().cloned());
+    let mut graph: Graph<(String, P), EdgeFilter> =
+        generate_target_graph(vertices, all_edges, ids_to_properties);
+    if !undirected_edges.is_empty() && is_cyclic_undirected(&graph) {
+        return None;
+    }
+    let find_edge = |graph: &Graph<(String, P), EdgeFilter>, node0: &str, node1: &str| {
+        let handle0 = get_node_with_id(graph, node0).unwrap();
+        let handle1 = get_node_with_id(graph, node1).unwrap();
+        graph.find_edge(handle0, handle1).or_else(|| graph.find_edge(handle1, handle0))
+    };
+    for (node0, node1, hops) in &edge_hops {
+        if let Some(edge) = find_edge(&graph, node0, node1) {
+            *graph.edge_weight_mut(edge).unwrap() = hops.clone();
+        }
+    }
+    for (node0, node1) in &undirected_edges {
+        let edge = find_edge(&graph, node0, node1).unwrap();
+        graph.edge_weight_mut(edge).unwrap().undirected = true;
+    }
+    Some(graph)
+}
+
+pub fn get_node_with_id<P, E>(
+    graph: &Graph<(String, P), E>,
     node_name: &str,
 ) -> Option<NodeIndex> {
     for index in graph.node_indices() {
@@ -67,9 +755,9 @@ pub fn get_node_with_id(
     None
 }
 
-pub fn find_leaves(
+pub fn find_leaves<N, E>(
     node: NodeIndex,
-    graph: &GraphType,
+    graph: &Graph<N, E>,
 ) -> Vec<NodeIndex> {
     let mut post_order = DfsPostOrder::new(&graph, node);
     let mut to_return = Vec::new();
@@ -81,7 +769,7 @@ pub fn find_leaves(
     to_return
 }
 
-pub fn find_root(graph: &GraphType) -> NodeIndex {
+pub fn find_root<N, E>(graph: &Graph<N, E>) -> NodeIndex {
     for node in graph.node_indices() {
         if graph.neighbors_directed(node, Incoming).count() == 0 {
             return node;
@@ -90,19 +778,11 @@ pub fn find_root(graph: &GraphType) -> NodeIndex {
     panic!("no root found");
 }
 
-pub fn has_property_subset(
-    property_set_1: &IndexMap<u64, String>, // set
-    property_set_2: &IndexMap<u64, String>, // subset
+pub fn has_property_subset<P: PropertyFilter>(
+    property_set_1: &IndexMap<u64, Value>, // set
+    property_set_2: &P,                     // subset
 ) -> bool {
-    for property in property_set_2.keys() {
-        if !property_set_1.contains_key(property) {
-            return false;
-        }
-        if property_set_1[property] != property_set_2[property] {
-            return false;
-        }
-    }
-    true
+    property_set_2.is_satisfied_by(property_set_1)
 }
 
 #[cfg(test)]
@@ -118,15 +798,15 @@ mod tests {
         let mut ids_to_properties = IndexMap::new();
 
         let mut a_hashmap = IndexMap::new();
-        a_hashmap.insert(0, "a".to_string());
+        a_hashmap.insert(0, Value::Str("a".to_string()));
         ids_to_properties.insert("a".to_string(), a_hashmap);
 
         let mut b_hashmap = IndexMap::new();
-        b_hashmap.insert(0, "b".to_string());
+        b_hashmap.insert(0, Value::Str("b".to_string()));
         ids_to_properties.insert("b".to_string(), b_hashmap);
 
         let mut c_hashmap = IndexMap::new();
-        c_hashmap.insert(0, "c".to_string());
+        c_hashmap.insert(0, Value::Str("c".to_string()));
         ids_to_properties.insert("c".to_string(), c_hashmap);
 
         assert!(ids_to_properties.keys().len() == 3);
@@ -150,8 +830,8 @@ mod tests {
         let a = graph.add_node(("a".to_string(), IndexMap::new()));
         let b = graph.add_node(("b".to_string(), IndexMap::new()));
         let c = graph.add_node(("c".to_string(), IndexMap::new()));
-        graph.add_edge(a, b, ());
-        graph.add_edge(b, c, ());
+        graph.add_edge(a, b, IndexMap::new());
+        graph.add_edge(b, c, IndexMap::new());
         graph
     }
 
@@ -179,6 +859,210 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_compare_op() {
+        assert!(CompareOp::Eq.compare("reviews-v1", "reviews-v1"));
+        assert!(CompareOp::Ne.compare("reviews-v1", "reviews-v2"));
+        // numbers are not compared lexicographically
+        assert!(CompareOp::Gt.compare("10", "9"));
+        assert!(CompareOp::Ge.compare("500", "500"));
+        assert!(!CompareOp::Lt.compare("503", "500"));
+        assert!(CompareOp::Le.compare("5", "5.0"));
+        // a number and a string are only unequal
+        assert!(!CompareOp::Lt.compare("10", "abc"));
+        assert!(CompareOp::Ne.compare("10", "abc"));
+        // string operators never compare numerically
+        assert!(CompareOp::StartsWith.compare("/api/v2/users", "/api/v2"));
+        assert!(!CompareOp::EndsWith.compare("5.0", "5"));
+        assert!(CompareOp::Contains.compare("curl/7.68.0", "curl"));
+        assert!(CompareOp::RegexMatch.compare("curl/7.68.0", ".*curl.*"));
+        // the whole value has to match
+        assert!(!CompareOp::RegexMatch.compare("curl/7.68.0", "curl"));
+        assert!(!CompareOp::RegexMatch.compare("curl", "("));
+        // each pattern is compiled once
+        assert!(CompareOp::RegexMatch.compare("curl/7.68.0", ".*curl.*"));
+        REGEXES.with(|regexes| {
+            let regexes = regexes.borrow();
+            assert!(regexes[".*curl.*"].is_some());
+            assert!(regexes["("].is_none());
+        });
+    }
+
+    #[test]
+    fn test_arithmetic_op() {
+        assert_eq!(ArithmeticOp::Sub.apply("1500", "500"), Some("1000".to_string()));
+        assert_eq!(ArithmeticOp::Div.apply("7", "2"), Some("3".to_string()));
+        assert_eq!(ArithmeticOp::Mod.apply("7", "2"), Some("1".to_string()));
+        assert_eq!(ArithmeticOp::Mul.apply("0.5", "3"), Some("1.5".to_string()));
+        assert_eq!(ArithmeticOp::Div.apply("1", "0"), None);
+        assert_eq!(ArithmeticOp::Add.apply("reviews-v1", "1"), None);
+    }
+
+    #[test]
+    fn test_has_property_subset_with_comparisons() {
+        let mut properties = IndexMap::new();
+        properties.insert(0, Value::Str("reviews-v1".to_string()));
+        properties.insert(1, Value::Int(503));
+
+        let filters = vec![
+            Predicate::Compare(0, CompareOp::Eq, Value::Str("reviews-v1".to_string())),
+            Predicate::Compare(1, CompareOp::Ge, Value::Int(500)),
+        ];
+        assert!(has_property_subset(&properties, &filters));
+
+        let filters = vec![
+            Predicate::Compare(1, CompareOp::Ge, Value::Int(500)),
+            Predicate::Compare(1, CompareOp::Lt, Value::Int(503)),
+        ];
+        assert!(!has_property_subset(&properties, &filters));
+
+        // a missing property never satisfies a comparison
+        let filters = vec![Predicate::Compare(2, CompareOp::Ne, Value::Int(0))];
+        assert!(!has_property_subset(&properties, &filters));
+    }
+
+    #[test]
+    fn test_compare_values() {
+        assert_eq!(CompareOp::Gt.compare_values(&Value::Int(10), &Value::Int(9)), Some(true));
+        assert_eq!(CompareOp::Eq.compare_values(&Value::Uint(5), &Value::Int(5)), Some(true));
+        assert_eq!(CompareOp::Lt.compare_values(&Value::Int(-1), &Value::Uint(0)), Some(true));
+        assert_eq!(
+            CompareOp::Ge.compare_values(&Value::Duration(1500), &Value::Duration(1000)),
+            Some(true)
+        );
+        // 5 and "5" are different values, and have no order
+        assert_eq!(CompareOp::Eq.compare_values(&Value::Int(5), &Value::Str("5".to_string())), None);
+        assert_eq!(CompareOp::Lt.compare_values(&Value::Duration(5), &Value::Uint(6)), None);
+        // integers and floats compare by their numeric value
+        assert_eq!(CompareOp::Lt.compare_values(&Value::Int(0), &Value::Float(0.5)), Some(true));
+        assert_eq!(CompareOp::Eq.compare_values(&Value::Float(2.0), &Value::Uint(2)), Some(true));
+        // the string operators only apply to strings
+        assert_eq!(
+            CompareOp::StartsWith.compare_values(
+                &Value::Str("/api/v2/users".to_string()),
+                &Value::Str("/api/v2".to_string())
+            ),
+            Some(true)
+        );
+        assert_eq!(CompareOp::Contains.compare_values(&Value::Int(503), &Value::Int(5)), None);
+    }
+
+    #[test]
+    fn test_value_type_parse() {
+        assert_eq!(ValueType::Int.parse("-3"), Some(Value::Int(-3)));
+        assert_eq!(ValueType::Uint.parse("-3"), None);
+        assert_eq!(ValueType::Bool.parse("true"), Some(Value::Bool(true)));
+        assert_eq!(ValueType::Str.parse("503"), Some(Value::Str("503".to_string())));
+        assert_eq!(ValueType::Timestamp.parse("1605112000"), Some(Value::Timestamp(1605112000)));
+        assert_eq!(ValueType::Float.parse("0.5"), Some(Value::Float(0.5)));
+        assert_eq!(ValueType::Float.parse("NaN"), None);
+        assert_eq!(Value::Duration(1500).to_string(), "1500");
+        assert_eq!(ValueType::from_type_name("f64"), Some(ValueType::Float));
+        assert_eq!(ValueType::from_type_name("Map"), None);
+    }
+
+    #[test]
+    fn test_predicate_evaluate() {
+        let mut properties = IndexMap::new();
+        properties.insert(0, Value::Str("reviews-v2".to_string()));
+
+        let v2 = Predicate::Compare(0, CompareOp::Eq, Value::Str("reviews-v2".to_string()));
+        let v3 = Predicate::Compare(0, CompareOp::Eq, Value::Str("reviews-v3".to_string()));
+        let missing = Predicate::Compare(1, CompareOp::Eq, Value::Int(1));
+
+        assert!(Predicate::Or(vec![v3.clone(), v2.clone()]).evaluate(&properties));
+        assert!(!Predicate::And(vec![v3.clone(), v2.clone()]).evaluate(&properties));
+        assert!(Predicate::Xor(vec![v3.clone(), v2.clone()]).evaluate(&properties));
+        assert!(!Predicate::Xor(vec![v2.clone(), v2.clone()]).evaluate(&properties));
+        assert!(Predicate::Not(Box::new(v3.clone())).evaluate(&properties));
+
+        // unknown values do not turn into matches when negated
+        assert!(!Predicate::Not(Box::new(missing.clone())).evaluate(&properties));
+        assert!(Predicate::Or(vec![missing.clone(), v2.clone()]).evaluate(&properties));
+        assert!(!Predicate::Not(Box::new(Predicate::And(vec![missing, v2]))).evaluate(&properties));
+
+        // existence checks are never unknown
+        assert!(Predicate::Exists(0).evaluate(&properties));
+        assert!(Predicate::Not(Box::new(Predicate::Exists(1))).evaluate(&properties));
+    }
+
+    #[test]
+    fn test_generate_filtered_target_graph() {
+        let vertices = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+        let edges = vec![("a".to_string(), "b".to_string())];
+        let ids_to_properties: IndexMap<String, Vec<Predicate>> = IndexMap::new();
+
+        // a calls b, and either a calls c or c calls a
+        let undirected_edges = vec![("a".to_string(), "c".to_string())];
+        let graph: TargetGraphType = generate_filtered_target_graph(
+            vertices.clone(),
+            edges.clone(),
+            undirected_edges,
+            Vec::new(),
+            ids_to_properties.clone(),
+        )
+        .unwrap();
+        let a = get_node_with_id(&graph, "a").unwrap();
+        let b = get_node_with_id(&graph, "b").unwrap();
+        let c = get_node_with_id(&graph, "c").unwrap();
+        assert!(!graph.edge_weight(graph.find_edge(a, b).unwrap()).unwrap().undirected);
+        assert!(graph.edge_weight(graph.find_edge(a, c).unwrap()).unwrap().undirected);
+
+        // with b calling c, the undirected edge between c and a would close a cycle
+        let edges = vec![("a".to_string(), "b".to_string()), ("b".to_string(), "c".to_string())];
+        let undirected_edges = vec![("c".to_string(), "a".to_string())];
+        let graph: Option<TargetGraphType> = generate_filtered_target_graph(
+            vertices,
+            edges,
+            undirected_edges,
+            Vec::new(),
+            ids_to_properties,
+        );
+        assert!(graph.is_none());
+    }
+
+    #[test]
+    fn test_generate_filtered_target_graph_many_undirected_edges() {
+        // one graph, however many undirected edges there are
+        let vertices: Vec<String> = (0..100).map(|i| format!("n{}", i)).collect();
+        let undirected_edges: Vec<(String, String)> =
+            (1..100).map(|i| ("n0".to_string(), format!("n{}", i))).collect();
+        let graph: TargetGraphType = generate_filtered_target_graph(
+            vertices,
+            Vec::new(),
+            undirected_edges,
+            Vec::new(),
+            IndexMap::new(),
+        )
+        .unwrap();
+        assert_eq!(graph.edge_count(), 99);
+        assert!(graph.edge_weights().all(|edge| edge.undirected));
+    }
+
+    #[test]
+    fn test_generate_filtered_target_graph_with_hops() {
+        let vertices = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+        let edges = vec![("a".to_string(), "b".to_string())];
+        let undirected_edges = vec![("c".to_string(), "a".to_string())];
+        let edge_hops = vec![
+            ("a".to_string(), "b".to_string(), EdgeFilter::new(Hops::new(1, Some(3)), Vec::new())),
+            ("c".to_string(), "a".to_string(), EdgeFilter::new(Hops::new(2, None), Vec::new())),
+        ];
+        let graph: TargetGraphType =
+            generate_filtered_target_graph(vertices, edges, undirected_edges, edge_hops, IndexMap::new())
+                .unwrap();
+        let a = get_node_with_id(&graph, "a").unwrap();
+        let b = get_node_with_id(&graph, "b").unwrap();
+        let c = get_node_with_id(&graph, "c").unwrap();
+        let ab = graph.edge_weight(graph.find_edge(a, b).unwrap()).unwrap();
+        assert_eq!(ab.hops, Hops::new(1, Some(3)));
+        assert!(!ab.undirected);
+        // the hops do not overwrite that the edge is undirected
+        let ca = graph.edge_weight(graph.find_edge(c, a).unwrap()).unwrap();
+        assert_eq!(ca.hops, Hops::new(2, None));
+        assert!(ca.undirected);
+    }
+
     #[test]
     fn test_find_root() {
         let graph = little_graph();
@@ -3,16 +3,22 @@
 /// Another thing to consider, but is not implemented here, is
 /// http://chasewoerner.org/popl87.pdf
 ///
-use super::graph_utils::{find_leaves, find_root, has_property_subset};
+use super::graph_utils::{
+    find_leaves, find_root, has_property_subset, HopRange, PropertyFilter, Value,
+};
 use indexmap::map::IndexMap;
+use indexmap::set::IndexSet;
 use pathfinding::directed::edmonds_karp::*;
-use petgraph::graph::{Graph, NodeIndex};
-use petgraph::visit::DfsPostOrder;
+use petgraph::graph::{EdgeIndex, Graph, NodeIndex};
+use petgraph::visit::{DfsPostOrder, EdgeRef};
 use petgraph::{Incoming, Outgoing};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 extern crate test;
 
-pub type GraphType = Graph<(String, IndexMap<u64, String>), ()>;
+pub type GraphType = Graph<(String, IndexMap<u64, Value>), IndexMap<u64, Value>>;
+// graph H only needs to know how to check its node and edge properties against
+// graph G, and how many calls each of its edges spans
+pub type TargetType<P, E = ()> = Graph<(String, P), E>;
 pub type SetSType = IndexMap<SetSKey, IndexMap<NodeIndex, Option<Vec<(NodeIndex, NodeIndex)>>>>;
 // -------------- Shamir Isomorphism Algorithm Helper Functions---------------
 #[derive(Debug, Hash, Eq, PartialEq)]
@@ -47,6 +53,120 @@ impl<'de> Deserialize<'de> for SetSKey {
 }
 
 
+/// The number of calls from ancestor down to node in graph G, if node is in the
+/// subtree of ancestor.
+fn depth_below(graph_g: &GraphType, ancestor: NodeIndex, node: NodeIndex) -> Option<u32> {
+    let mut depth = 0;
+    let mut current = node;
+    while current != ancestor {
+        current = graph_g.neighbors_directed(current, Incoming).next()?;
+        depth += 1;
+    }
+    Some(depth)
+}
+
+/// The nodes of graph G that the neighbors of a node of graph H mapped to v
+/// may map to.  Those are the children of v, and, when graph H has path edges,
+/// the descendants of v as far down as the longest of them reaches.  The caller
+/// of v is never one, since every edge of graph H maps below v.
+fn candidates_below<P, E: HopRange>(
+    v: NodeIndex,
+    graph_g: &GraphType,
+    graph_h: &TargetType<P, E>,
+) -> Vec<NodeIndex> {
+    let mut reach = Some(1);
+    for edge in graph_h.edge_weights() {
+        reach = match (reach, edge.hops().max) {
+            (Some(reach), Some(max)) => Some(reach.max(max)),
+            _ => None,
+        };
+    }
+    let mut level: Vec<NodeIndex> = graph_g.neighbors_directed(v, Outgoing).collect();
+    let mut candidates = level.clone();
+    let mut depth = 1;
+    while !level.is_empty() && reach.map_or(true, |reach| depth < reach) {
+        level = level
+            .iter()
+            .flat_map(|w| graph_g.neighbors_directed(*w, Outgoing))
+            .collect();
+        candidates.extend(&level);
+        depth += 1;
+    }
+    candidates
+}
+
+/// Whether x, a neighbor of u_null in graph H, can map to y, a node below
+/// v_null in graph G.  A direct edge maps to the call from v_null to y, whose
+/// properties it has to satisfy, and a path edge u_null-[*min..max]->x to any y
+/// min to max calls below v_null.  x has to be called by u_null, unless the
+/// edge between them is undirected.
+///
+/// Since y can be any descendant, several path edges may map below the same
+/// call of v_null, and so share the calls on their way down.
+fn can_map<P: PropertyFilter, E: HopRange + PropertyFilter>(
+    u_null: NodeIndex,
+    v_null: NodeIndex,
+    x: NodeIndex,
+    y: NodeIndex,
+    graph_g: &GraphType,
+    graph_h: &TargetType<P, E>,
+    set_s: &SetSType,
+) -> bool {
+    let edge_filter = match graph_h.find_edge(u_null, x) {
+        Some(edge) => graph_h.edge_weight(edge).unwrap(),
+        None => match graph_h.find_edge(x, u_null) {
+            // x is the caller of u_null, which only an undirected edge allows
+            Some(edge) if graph_h.edge_weight(edge).unwrap().is_undirected() => {
+                graph_h.edge_weight(edge).unwrap()
+            }
+            _ => return false,
+        },
+    };
+    let hops = edge_filter.hops();
+    let edge_matches = if hops.is_direct() {
+        graph_g.find_edge(v_null, y).map_or(false, |call| {
+            edge_filter.is_satisfied_by(graph_g.edge_weight(call).unwrap())
+        })
+    } else {
+        depth_below(graph_g, v_null, y).map_or(false, |depth| {
+            depth >= hops.min && hops.max.map_or(true, |max| depth <= max)
+        })
+    };
+    edge_matches
+        && set_s[&SetSKey { val1: y, val2: x }].contains_key(&u_null)
+        && has_property_subset(
+            &graph_g.node_weight(y).unwrap().1,
+            &graph_h.node_weight(x).unwrap().1,
+        )
+}
+
+/// Whether x is a callee of u in graph H that an OPTIONAL MATCH attached.  A
+/// match of u may leave out x, along with everything below it.
+fn is_optional_child<P, E: HopRange>(graph_h: &TargetType<P, E>, u: NodeIndex, x: NodeIndex) -> bool {
+    graph_h
+        .find_edge(u, x)
+        .map_or(false, |edge| graph_h.edge_weight(edge).unwrap().is_optional())
+}
+
+/// The number of nodes of graph H every match has to map, which are those that
+/// are not below an optional edge.
+fn mandatory_node_count<P, E: HopRange>(graph_h: &TargetType<P, E>) -> usize {
+    let mut optional = IndexSet::new();
+    for edge in graph_h.edge_indices() {
+        if !graph_h.edge_weight(edge).unwrap().is_optional() {
+            continue;
+        }
+        let (caller, callee) = graph_h.edge_endpoints(edge).unwrap();
+        let mut stack = vec![callee];
+        while let Some(node) = stack.pop() {
+            if optional.insert(node) {
+                stack.extend(graph_h.neighbors_undirected(node).filter(|n| *n != caller));
+            }
+        }
+    }
+    graph_h.node_count() - optional.len()
+}
+
 /// Given two sets of nodes, set x from graph g, and set y from graph h,
 /// creates a flow graph with the source connected to all nodes in x and
 /// the sink connected to all nodes in y.  Edges between x and y are computed
@@ -64,13 +184,13 @@ impl<'de> Deserialize<'de> for SetSKey {
 /// matchings later in the algorithm.  So if cost is below that threshold,
 /// that is not necessarily reflective of the true maximum flow, but rather a
 /// way of signaling that neither this nor subsequent matchings will be useful.
-fn max_matching<EK: EdmondsKarp<i32>>(
+fn max_matching<EK: EdmondsKarp<i32>, P: PropertyFilter, E: HopRange + PropertyFilter>(
     u_null: NodeIndex,
     v_null: NodeIndex,
     set_x: &[NodeIndex],
     set_y: &[NodeIndex],
     graph_g: &GraphType,
-    graph_h: &GraphType,
+    graph_h: &TargetType<P, E>,
     set_s: &mut SetSType,
 ) {
     // The NodeIndex objects probably share values between set X and set Y
@@ -117,10 +237,16 @@ fn max_matching<EK: EdmondsKarp<i32>>(
     let source = i+1;
     let sink = i+2;
 
-    // 2. Make the graph
+    // 2. Make the graph.  Optional neighbors, those an OPTIONAL MATCH attached
+    //    to u_null, only get their source edges once every other neighbor is
+    //    matched, so they are matched where they can be without taking the place
+    //    of one that has to be.  Augmenting never unmatches a neighbor.
+    let (optional, mandatory): (Vec<NodeIndex>, Vec<NodeIndex>) = set_x
+        .iter()
+        .partition(|x| is_optional_child(graph_h, u_null, **x));
     let mut ek = EK::new(index_to_node.keys().len()+3, source, sink);
 
-    for u in set_x {
+    for u in &mandatory {
         ek.set_capacity(source, x_node_to_index[u], 1); 
     }
     for v in set_y {
@@ -130,13 +256,10 @@ fn max_matching<EK: EdmondsKarp<i32>>(
     let mut num_xy_edges = 0;
     for u in set_x {
         for v in set_y {
-            if set_s[&SetSKey { val1: *v, val2: *u }].contains_key(&u_null)
-                && has_property_subset(
-                    &graph_g.node_weight(*v).unwrap().1,
-                    &graph_h.node_weight(*u).unwrap().1,
-                )
-            {
-                num_xy_edges += 1;
+            if can_map(u_null, v_null, *u, *v, graph_g, graph_h, set_s) {
+                if !optional.contains(u) {
+                    num_xy_edges += 1;
+                }
                 ek.set_capacity(x_node_to_index[u], y_node_to_index[v], 1);
             }
         }
@@ -144,60 +267,72 @@ fn max_matching<EK: EdmondsKarp<i32>>(
 
     // If even adding one more edge does not get you near the target size,
     // then there is no hope of having a useful matching.  Just return
-    if num_xy_edges + 1 < set_x.len() {
+    if num_xy_edges + 1 < mandatory.len() {
         return;
     }
 
+    // converts a matching from ints to nodes
+    let to_nodes = |mapping: Vec<((usize, usize), i32)>| {
+        let mut nodes_mapping = Vec::new();
+        for (map, flow) in mapping {
+            if map.0 != source && map.1 != sink && flow != 0 {
+                let x_node = *index_to_node[map.0];
+                let y_node = *index_to_node[map.1];
+                nodes_mapping.push((x_node, y_node));
+            }
+        }
+        nodes_mapping
+    };
+
     // 2. Try the mapping with the entirety of X
-    let (mapping, cost) = ek.augment();
-    if cost as usize == set_x.len() {
+    let (_, cost) = ek.augment();
+    if cost as usize == mandatory.len() {
+        for x in &optional {
+            ek.set_capacity(source, x_node_to_index[x], 1);
+        }
+        let (mapping, _) = ek.augment();
         if !set_s[&SetSKey { val1: v_null, val2: u_null }].contains_key(&u_null) {
-            // convert mapping from ints to nodes
-            let mut nodes_mapping = Vec::new();
-            for (map, flow) in mapping {
-                if map.0 != source && map.1 != sink && flow != 0 {
-                    nodes_mapping.push((*index_to_node[map.0], *index_to_node[map.1]));
-                }
-            }
             set_s
                 .get_mut(&SetSKey { val1: v_null, val2: u_null })
                 .unwrap()
-                .insert(u_null, Some(nodes_mapping));
+                .insert(u_null, Some(to_nodes(mapping)));
         }
-    } else if (cost as usize) < set_x.len()-1 {
+    } else if (cost as usize) + 1 < mandatory.len() {
         return; // you aren't going to get higher flows by taking away edges
     }
 
     // 3. Try mapping each of the X_i sets;  instead of creating new graphs
-    //    entirely, you can just zero out the related edges and recompute
-    for x_node in set_x {
+    //    entirely, you can just zero out the related edges and recompute.
+    //    Optional neighbors are always called by u_null, so they never map
+    //    above it and leave it out
+    for x_node in &mandatory {
         ek.set_capacity(source, x_node_to_index[x_node], 0);
-        let (mapping, cost) = ek.augment();
-        if cost as usize == set_x.len()-1 {
+        for x in &optional {
+            ek.set_capacity(source, x_node_to_index[x], 0);
+        }
+        let (_, cost) = ek.augment();
+        if cost as usize == mandatory.len()-1 {
+            for x in &optional {
+                ek.set_capacity(source, x_node_to_index[x], 1);
+            }
+            let (mapping, _) = ek.augment();
             if !set_s[&SetSKey { val1: v_null, val2: u_null }].contains_key(x_node) {
-                // convert mapping from ints to nodes
-                let mut nodes_mapping = Vec::new();
-                for (map, flow) in mapping {
-                    if map.0 != source && map.1 != sink && flow != 0 {
-                        nodes_mapping.push((*index_to_node[map.0], *index_to_node[map.1]));
-                    }
-                }
                 set_s
                     .get_mut(&SetSKey { val1: v_null, val2: u_null })
                     .unwrap()
-                    .insert(*x_node, Some(nodes_mapping));
+                    .insert(*x_node, Some(to_nodes(mapping)));
             }
         }
         // set it back the way it was
-        ek.set_capacity(source, x_node_to_index[&x_node], 1);
+        ek.set_capacity(source, x_node_to_index[x_node], 1);
     }
 }
 
 // For debugging only
 #[allow(dead_code)]
-fn print_set_s(
+fn print_set_s<P: std::fmt::Debug, E>(
     graph_g: &GraphType,
-    graph_h: &GraphType,
+    graph_h: &TargetType<P, E>,
     set_s: &SetSType,
 ) {
     for key in set_s.keys() {
@@ -208,7 +343,7 @@ fn print_set_s(
         );
         for value_key in set_s[key].keys() {
             print!("inner key: {:?} ", graph_h.node_weight(*value_key).unwrap());
-            for mapping in &set_s[key][value_key] {
+            if let Some(mapping) = &set_s[key][value_key] {
                 for map in mapping {
                     print!(
                         "maps {:?} to {:?} ",
@@ -228,16 +363,22 @@ fn print_set_s(
 // going to look up what None in graph H maps to, and duplicate matchings
 // between the same nodes are clearly not "wrong".  But because of this,
 // the size of the matching returned might be a bit wonky.
-fn get_mapping_from_set_s(
-    graph_h: &GraphType,
+// Every pair is (node in graph H, node in graph G), like the matchings stored in
+// set S.  Callers look up a target node by the first index of each pair, so a
+// reversed (node in G, node in H) pair would map some other target node, or one
+// graph H does not have, to the wrong trace node.  The walk starts from the
+// root of the match, a pair of the same kind.
+fn get_mapping_from_set_s<P, E>(
+    graph_h: &TargetType<P, E>,
     set_s: &SetSType,
-    root_in_g: &NodeIndex,
+    root: &(NodeIndex, NodeIndex),
 ) -> Vec<(NodeIndex, NodeIndex)> {
-    let root_h = find_root(graph_h);
     let mut to_return = Vec::with_capacity(graph_h.node_count());
-    let mut set_to_find_mapping = vec![(root_h, *root_in_g)];
+    // each pair comes with the node of graph H its S entry leaves out, which is
+    // the one it was matched under, and the root leaves out itself
+    let mut set_to_find_mapping = vec![(*root, root.0)];
     while !set_to_find_mapping.is_empty() {
-        let key = set_to_find_mapping.pop().unwrap();
+        let (key, without) = set_to_find_mapping.pop().unwrap();
         if !to_return.contains(&key) {
             to_return.push(key);
         }
@@ -246,12 +387,11 @@ fn get_mapping_from_set_s(
             val1: key.1,
             val2: key.0,
         };
-        if set_s[&set_s_key].contains_key(&key.0) {
-            if let Some(mapping_vec) = &set_s[&set_s_key][&key.0] {
+        if set_s[&set_s_key].contains_key(&without) {
+            if let Some(mapping_vec) = &set_s[&set_s_key][&without] {
                 for mapping in mapping_vec {
-                    if !to_return.contains(&(mapping.1, mapping.0)) {
-                        to_return.push((mapping.1, mapping.0));
-                        set_to_find_mapping.push(*mapping);
+                    if !to_return.contains(mapping) {
+                        set_to_find_mapping.push((*mapping, key.0));
                     }
                 }
             }
@@ -260,36 +400,38 @@ fn get_mapping_from_set_s(
     to_return
 }
 
-fn find_mapping_shamir_inner_loop(
+fn find_mapping_shamir_inner_loop<P: PropertyFilter, E: HopRange + PropertyFilter>(
     v: NodeIndex,
     graph_g: &GraphType,
-    graph_h: &GraphType,
+    graph_h: &TargetType<P, E>,
     set_s: &mut SetSType,
-) -> (bool, Option<NodeIndex>) {
-    let root_h = find_root(&graph_h);
-    let v_neighbors: Vec<NodeIndex> = graph_g.neighbors_undirected(v).collect();
+) -> (bool, Option<(NodeIndex, NodeIndex)>) {
+    let v_candidates = candidates_below(v, graph_g, graph_h);
     for u in graph_h.node_indices() {
         let u_neighbors: Vec<NodeIndex> = graph_h.neighbors_undirected(u).collect();
-        // all vertices of degree at most t+1
-        if u_neighbors.len() > v_neighbors.len() + 1 {
+        // all vertices of degree at most t+1, not counting optional neighbors,
+        // which a match may leave out
+        let degree = u_neighbors
+            .iter()
+            .filter(|x| !is_optional_child(graph_h, u, **x))
+            .count();
+        if degree > v_candidates.len() + 1 {
             continue;
         }
         // perform all max matching problems
-        max_matching::<DenseCapacity<_>>(
-            u, v, &u_neighbors, &v_neighbors, graph_g, graph_h, set_s);
-
-        // lines 12-14 in Shamir and Tsur pseudocode
-        if set_s[&SetSKey {
-            val1: v,
-            val2: root_h,
-        }]
-            .contains_key(&root_h)
+        max_matching::<DenseCapacity<_>, P, E>(
+            u, v, &u_neighbors, &v_candidates, graph_g, graph_h, set_s);
+
+        // lines 12-14 in Shamir and Tsur pseudocode.  Undirected edges may
+        // let more than one node of graph H be the root of the match, so any
+        // node that maps to v with all of its neighbors below it will do
+        if set_s[&SetSKey { val1: v, val2: u }].contains_key(&u)
             && has_property_subset(
                 &graph_g.node_weight(v).unwrap().1,
-                &graph_h.node_weight(root_h).unwrap().1,
+                &graph_h.node_weight(u).unwrap().1,
             )
         {
-            return (true, Some(v));
+            return (true, Some((u, v)));
         }
     }
     // before returning false, we can trim set S
@@ -312,12 +454,92 @@ fn find_mapping_shamir_inner_loop(
     (false, None)
 }
 
+// ----------------- Patterns With Several Components ----------------------
+
+/// Splits graph H into its weakly connected components.  Each component comes
+/// with the index its nodes have in graph H, by their index in the component.
+fn split_components<P: Clone, E: Clone>(
+    graph_h: &TargetType<P, E>,
+) -> Vec<(TargetType<P, E>, Vec<NodeIndex>)> {
+    let mut component_of: IndexMap<NodeIndex, usize> = IndexMap::new();
+    let mut components = Vec::new();
+    for start in graph_h.node_indices() {
+        if component_of.contains_key(&start) {
+            continue;
+        }
+        let mut component: TargetType<P, E> = Graph::new();
+        let mut indices = Vec::new();
+        let mut new_index = IndexMap::new();
+        let mut stack = vec![start];
+        component_of.insert(start, components.len());
+        while let Some(node) = stack.pop() {
+            new_index.insert(node, component.add_node(graph_h.node_weight(node).unwrap().clone()));
+            indices.push(node);
+            for neighbor in graph_h.neighbors_undirected(node) {
+                if !component_of.contains_key(&neighbor) {
+                    component_of.insert(neighbor, components.len());
+                    stack.push(neighbor);
+                }
+            }
+        }
+        for edge in graph_h.edge_indices() {
+            let (source, target) = graph_h.edge_endpoints(edge).unwrap();
+            if new_index.contains_key(&source) {
+                component.add_edge(
+                    new_index[&source],
+                    new_index[&target],
+                    graph_h.edge_weight(edge).unwrap().clone(),
+                );
+            }
+        }
+        components.push((component, indices));
+    }
+    components
+}
+
+/// The components of graph H, if it has more than one.  A single tree has
+/// exactly one root, so that is checked first.
+fn unconnected_components<P: Clone, E: Clone>(
+    graph_h: &TargetType<P, E>,
+) -> Option<Vec<(TargetType<P, E>, Vec<NodeIndex>)>> {
+    if graph_h.externals(Incoming).count() <= 1 {
+        return None;
+    }
+    let components = split_components(graph_h);
+    if components.len() <= 1 {
+        return None;
+    }
+    Some(components)
+}
+
 // ----------------- Shamir Isomorphism Algorithm Centralized ----------------
 
+/// The nodes of graph H that can map to a leaf of graph G, with the key of
+/// their S entry.  Undirected edges leave it open which way graph H hangs, so
+/// these are the nodes with at most one neighbor, whichever way their edge
+/// points, besides optional ones a match leaves out.  With its neighbor left
+/// out such a node is all that remains, and a node without neighbors is all of
+/// graph H.
+fn leaves_h<P, E: HopRange>(graph_h: &TargetType<P, E>) -> Vec<(NodeIndex, NodeIndex)> {
+    let mut to_return = Vec::new();
+    for u in graph_h.node_indices() {
+        let neighbors: Vec<NodeIndex> = graph_h
+            .neighbors_undirected(u)
+            .filter(|x| !is_optional_child(graph_h, u, *x))
+            .collect();
+        match neighbors[..] {
+            [] => to_return.push((u, u)),
+            [neighbor] => to_return.push((u, neighbor)),
+            _ => {}
+        }
+    }
+    to_return
+}
+
 // this performs lines 0-4 in the Shamir paper figure 3
-fn initialize_s(
+fn initialize_s<P, E: HopRange>(
     graph_g: &GraphType,
-    graph_h: &GraphType,
+    graph_h: &TargetType<P, E>,
 ) -> SetSType {
     let mut s : SetSType =
         IndexMap::with_capacity(graph_g.node_count()*graph_h.node_count());
@@ -334,35 +556,41 @@ fn initialize_s(
         }
     }
     let root_g = find_root(&graph_g);
-    let root_h = find_root(&graph_h);
     for leaf_g in find_leaves(root_g, &graph_g) {
-        for leaf_h in find_leaves(root_h, &graph_h) {
+        for (leaf_h, without) in leaves_h(graph_h) {
             s.get_mut(&SetSKey {
                 val1: leaf_g,
                 val2: leaf_h,
             })
             .unwrap()
-            .insert(leaf_h, Some(vec![(leaf_h, leaf_g)]));
-            for neighbor in graph_h.neighbors_directed(leaf_h, Incoming) {
-                s.get_mut(&SetSKey {
-                    val1: leaf_g,
-                    val2: leaf_h,
-                })
-                .unwrap()
-                .insert(neighbor, Some(vec![(leaf_h, leaf_g)]));
-            }
+            .insert(without, Some(vec![(leaf_h, leaf_g)]));
         }
     }
     s
 }
 
-pub fn find_mapping_shamir_centralized(
+pub fn find_mapping_shamir_centralized<P: PropertyFilter + Clone, E: HopRange + PropertyFilter + Clone>(
     graph_g: &GraphType,
-    graph_h: &GraphType,
+    graph_h: &TargetType<P, E>,
 ) -> Option<Vec<(NodeIndex, NodeIndex)>> {
+    // a pattern of several unconnected parts matches if each of them matches
+    // somewhere in the trace.  The parts are matched on their own, so they may
+    // overlap, and even map to the same nodes and calls.
+    if let Some(components) = unconnected_components(graph_h) {
+        let mut mapping = Vec::new();
+        for (component, indices) in &components {
+            let component_mapping = find_mapping_shamir_centralized(graph_g, component)?;
+            mapping.extend(
+                component_mapping
+                    .into_iter()
+                    .map(|(node_h, node_g)| (indices[node_h.index()], node_g)),
+            );
+        }
+        return Some(mapping);
+    }
     // TODO:  before even dealing with isomorphism, ask if breadth,
     // height, num nodes match up
-    if graph_g.node_count() < graph_h.node_count() {
+    if graph_g.node_count() < mandatory_node_count(graph_h) {
         return None;
     }
 
@@ -388,9 +616,9 @@ pub fn find_mapping_shamir_centralized(
 }
 
 // ---------------- Shamir Isomorphism Algorithm Decentralized ---------------
-fn initialize_s_for_node(
+fn initialize_s_for_node<P, E: HopRange>(
     graph_g: &GraphType,
-    graph_h: &GraphType,
+    graph_h: &TargetType<P, E>,
     set_s: &mut SetSType,
     node: NodeIndex,
 ) {
@@ -404,38 +632,36 @@ fn initialize_s_for_node(
             IndexMap::new(),
         );
     }
-    let root_h = find_root(&graph_h);
 
     // if I am a leaf
     if graph_g.neighbors_directed(node, Outgoing).count() == 0 {
-        for leaf_h in find_leaves(root_h, &graph_h) {
+        for (leaf_h, without) in leaves_h(graph_h) {
             set_s
                 .get_mut(&SetSKey {
                     val1: node,
                     val2: leaf_h,
                 })
                 .unwrap()
-                .insert(leaf_h, Some(vec![(leaf_h, node)]));
-            for neighbor in graph_h.neighbors_directed(leaf_h, Incoming) {
-                set_s
-                    .get_mut(&SetSKey {
-                        val1: node,
-                        val2: leaf_h,
-                    })
-                    .unwrap()
-                    .insert(neighbor, Some(vec![(leaf_h, node)]));
-            }
+                .insert(without, Some(vec![(leaf_h, node)]));
         }
     }
 }
 
-pub fn find_mapping_shamir_decentralized(
+pub fn find_mapping_shamir_decentralized<P: PropertyFilter + Clone, E: HopRange + PropertyFilter + Clone>(
     graph_g: &GraphType,
-    graph_h: &GraphType,
+    graph_h: &TargetType<P, E>,
     set_s: &mut SetSType,
     cur_node: NodeIndex, // what node we are in graph_g
     am_root: bool,
 ) -> Option<Vec<(NodeIndex, NodeIndex)>> {
+    // the parts of a pattern of several unconnected parts may be anywhere in
+    // the trace, so they are matched once the whole trace reaches the root
+    if unconnected_components(graph_h).is_some() {
+        if am_root {
+            return find_mapping_shamir_centralized(graph_g, graph_h);
+        }
+        return None;
+    }
     // 1. Add yourself (that is, all your entries) to set S
     initialize_s_for_node(graph_g, graph_h, set_s, cur_node);
 
@@ -482,10 +708,305 @@ pub fn find_mapping_shamir_decentralized(
     None
 }
 
+// ------------- Mappings the Whole of Which Has to Be Accepted --------------
+
+/// A node of graph H in the order mappings are built in, with the node of its
+/// component mapped before it that it is reached from, and the edge between
+/// them.  The first node of each component is reached from none.
+struct Step {
+    node: NodeIndex,
+    from: Option<(NodeIndex, EdgeIndex)>,
+    component: usize,
+}
+
+/// Orders the nodes of graph H so that every node but the first of each
+/// component comes after a neighbor.  Each component starts from its root, so
+/// an optional callee comes after its caller, and everything below it after
+/// the callee.
+fn mapping_order<P, E>(graph_h: &TargetType<P, E>) -> Vec<Step> {
+    let mut order: Vec<Step> = Vec::with_capacity(graph_h.node_count());
+    let starts: Vec<NodeIndex> = graph_h
+        .externals(Incoming)
+        .chain(graph_h.node_indices())
+        .collect();
+    let mut component = 0;
+    for start in starts {
+        if order.iter().any(|step| step.node == start) {
+            continue;
+        }
+        let mut stack = vec![Step {
+            node: start,
+            from: None,
+            component,
+        }];
+        while let Some(step) = stack.pop() {
+            let node = step.node;
+            order.push(step);
+            for edge in graph_h
+                .edges_directed(node, Outgoing)
+                .chain(graph_h.edges_directed(node, Incoming))
+            {
+                let neighbor = if edge.source() == node {
+                    edge.target()
+                } else {
+                    edge.source()
+                };
+                if !order.iter().any(|step| step.node == neighbor) {
+                    stack.push(Step {
+                        node: neighbor,
+                        from: Some((node, edge.id())),
+                        component,
+                    });
+                }
+            }
+        }
+        component += 1;
+    }
+    order
+}
+
+/// Whether the calls of graph G from caller to callee match an edge of graph
+/// H, as in can_map.  An undirected edge may map to the call the other way.
+fn edge_maps<E: HopRange + PropertyFilter>(
+    edge_filter: &E,
+    caller: NodeIndex,
+    callee: NodeIndex,
+    graph_g: &GraphType,
+) -> bool {
+    let hops = edge_filter.hops();
+    if !hops.is_direct() {
+        return depth_below(graph_g, caller, callee).map_or(false, |depth| {
+            depth >= hops.min && hops.max.map_or(true, |max| depth <= max)
+        });
+    }
+    let call_matches = |from: NodeIndex, to: NodeIndex| {
+        graph_g.find_edge(from, to).map_or(false, |call| {
+            edge_filter.is_satisfied_by(graph_g.edge_weight(call).unwrap())
+        })
+    };
+    call_matches(caller, callee) || (edge_filter.is_undirected() && call_matches(callee, caller))
+}
+
+/// How many nodes of graph H a search for an accepted mapping places, at
+/// most, before it gives up.  The filters search on every request, so the
+/// search is bounded rather than exhaustive.
+const MAX_PLACEMENTS: usize = 10_000;
+
+/// A search for a mapping of graph H that accept takes, among the nodes of
+/// graph G in scope.  Each node of graph H is only tried at the nodes its edge
+/// to one mapped before it can reach, and every node placed uses up one of the
+/// budget.
+struct MappingSearch<'a, P, E, F> {
+    graph_g: &'a GraphType,
+    graph_h: &'a TargetType<P, E>,
+    order: Vec<Step>,
+    scope: IndexSet<NodeIndex>,
+    accept: &'a F,
+    budget: usize,
+}
+
+impl<'a, P: PropertyFilter, E: HopRange + PropertyFilter, F> MappingSearch<'a, P, E, F>
+where
+    F: Fn(&Vec<(NodeIndex, NodeIndex)>) -> bool,
+{
+    /// The nodes in scope that node, reached from from_g over edge, may map
+    /// to.  Reached from its caller, those are below from_g, and otherwise
+    /// above it, as far as the hops of the edge go.  The first node of a
+    /// component may map to any of them.
+    fn candidates(&self, node: NodeIndex, from: Option<(NodeIndex, EdgeIndex)>) -> Vec<NodeIndex> {
+        let (from_g, edge) = match from {
+            Some(from) => from,
+            None => return self.scope.iter().cloned().collect(),
+        };
+        let edge_filter = self.graph_h.edge_weight(edge).unwrap();
+        let direction = if self.graph_h.edge_endpoints(edge).unwrap().0 == node {
+            Incoming
+        } else {
+            Outgoing
+        };
+        let hops = edge_filter.hops();
+        let reach = if hops.is_direct() { Some(1) } else { hops.max };
+        let mut candidates = Vec::new();
+        let mut level = vec![from_g];
+        let mut depth = 0;
+        while !level.is_empty() && reach.map_or(true, |reach| depth < reach) {
+            level = level
+                .iter()
+                .flat_map(|w| self.graph_g.neighbors_directed(*w, direction))
+                .collect();
+            candidates.extend(&level);
+            depth += 1;
+        }
+        if hops.is_direct() && edge_filter.is_undirected() {
+            candidates.extend(
+                self.graph_g
+                    .neighbors_directed(from_g, direction.opposite()),
+            );
+        }
+        candidates.retain(|y| self.scope.contains(y));
+        candidates
+    }
+
+    /// Maps the nodes of the order from the one at position on, below the
+    /// nodes already in mapping, until accept takes the whole mapping.  Nodes
+    /// of the same component map to distinct nodes of graph G, while
+    /// components may overlap, as in find_mapping_shamir_centralized.  An
+    /// optional callee is left out, with everything below it, only once no way
+    /// of mapping it is accepted.
+    fn extend(&mut self, position: usize, mapping: &mut Vec<(NodeIndex, NodeIndex)>) -> bool {
+        let (node, from, component) = match self.order.get(position) {
+            Some(step) => (step.node, step.from, step.component),
+            None => return (self.accept)(mapping),
+        };
+        let from_g = match from {
+            Some((from_h, edge)) => match mapping.iter().find(|(node_h, _)| *node_h == from_h) {
+                Some((_, from_g)) => Some((*from_g, edge)),
+                // the node it is reached from was left out, and so is it
+                None => return self.extend(position + 1, mapping),
+            },
+            None => None,
+        };
+        for y in self.candidates(node, from_g) {
+            if self.budget == 0 {
+                return false;
+            }
+            let taken = mapping.iter().any(|(node_h, node_g)| {
+                *node_g == y
+                    && self
+                        .order
+                        .iter()
+                        .any(|other| other.node == *node_h && other.component == component)
+            });
+            if taken
+                || !has_property_subset(
+                    &self.graph_g.node_weight(y).unwrap().1,
+                    &self.graph_h.node_weight(node).unwrap().1,
+                )
+            {
+                continue;
+            }
+            if let Some((from_g, edge)) = from_g {
+                let (caller, callee) = if self.graph_h.edge_endpoints(edge).unwrap().0 == node {
+                    (y, from_g)
+                } else {
+                    (from_g, y)
+                };
+                if !edge_maps(
+                    self.graph_h.edge_weight(edge).unwrap(),
+                    caller,
+                    callee,
+                    self.graph_g,
+                ) {
+                    continue;
+                }
+            }
+            self.budget -= 1;
+            mapping.push((node, y));
+            if self.extend(position + 1, mapping) {
+                return true;
+            }
+            mapping.pop();
+        }
+        match from {
+            Some((from_h, _)) if is_optional_child(self.graph_h, from_h, node) => {
+                self.extend(position + 1, mapping)
+            }
+            _ => false,
+        }
+    }
+}
+
+/// The mappings of graph H into the nodes of graph G in scope, in turn, until
+/// accept takes one, or the search runs out of budget.
+fn find_accepted_mapping<P: PropertyFilter, E: HopRange + PropertyFilter, F>(
+    graph_g: &GraphType,
+    graph_h: &TargetType<P, E>,
+    scope: IndexSet<NodeIndex>,
+    accept: &F,
+) -> Option<Vec<(NodeIndex, NodeIndex)>>
+where
+    F: Fn(&Vec<(NodeIndex, NodeIndex)>) -> bool,
+{
+    let mut search = MappingSearch {
+        graph_g,
+        graph_h,
+        order: mapping_order(graph_h),
+        scope,
+        accept,
+        budget: MAX_PLACEMENTS,
+    };
+    let mut mapping = Vec::with_capacity(graph_h.node_count());
+    if search.extend(0, &mut mapping) {
+        return Some(mapping);
+    }
+    None
+}
+
+/// Like find_mapping_shamir_centralized, but the mapping also has to satisfy
+/// accept.  The expression filters of a query compare several nodes, while the
+/// matcher looks at one at a time, so when the mapping it finds does not
+/// satisfy them, other mappings are tried, up to MAX_PLACEMENTS nodes placed.
+pub fn find_accepted_mapping_centralized<
+    P: PropertyFilter + Clone,
+    E: HopRange + PropertyFilter + Clone,
+    F,
+>(
+    graph_g: &GraphType,
+    graph_h: &TargetType<P, E>,
+    accept: F,
+) -> Option<Vec<(NodeIndex, NodeIndex)>>
+where
+    F: Fn(&Vec<(NodeIndex, NodeIndex)>) -> bool,
+{
+    let mapping = find_mapping_shamir_centralized(graph_g, graph_h)?;
+    if accept(&mapping) {
+        return Some(mapping);
+    }
+    find_accepted_mapping(graph_g, graph_h, graph_g.node_indices().collect(), &accept)
+}
+
+/// Like find_mapping_shamir_decentralized, but the mapping also has to satisfy
+/// accept.  When the mapping the matcher finds does not, other mappings are
+/// tried among the nodes it looks at: the nodes below cur_node, and the whole
+/// trace at the root.
+pub fn find_accepted_mapping_decentralized<
+    P: PropertyFilter + Clone,
+    E: HopRange + PropertyFilter + Clone,
+    F,
+>(
+    graph_g: &GraphType,
+    graph_h: &TargetType<P, E>,
+    set_s: &mut SetSType,
+    cur_node: NodeIndex,
+    am_root: bool,
+    accept: F,
+) -> Option<Vec<(NodeIndex, NodeIndex)>>
+where
+    F: Fn(&Vec<(NodeIndex, NodeIndex)>) -> bool,
+{
+    let mapping = find_mapping_shamir_decentralized(graph_g, graph_h, set_s, cur_node, am_root)?;
+    if accept(&mapping) {
+        return Some(mapping);
+    }
+    let scope = if am_root {
+        graph_g.node_indices().collect()
+    } else {
+        let mut below = IndexSet::new();
+        let mut stack: Vec<NodeIndex> = graph_g.neighbors_directed(cur_node, Outgoing).collect();
+        while let Some(node) = stack.pop() {
+            if below.insert(node) {
+                stack.extend(graph_g.neighbors_directed(node, Outgoing));
+            }
+        }
+        below
+    };
+    find_accepted_mapping(graph_g, graph_h, scope, &accept)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::graph::graph_utils::get_node_with_id;
+    use crate::graph::graph_utils::{get_node_with_id, CompareOp, EdgeFilter, Hops, Predicate};
     use serde_json;
     use test::Bencher;
 
@@ -495,8 +1016,8 @@ mod tests {
         let a = graph.add_node(("a".to_string(), IndexMap::new()));
         let b = graph.add_node(("b".to_string(), IndexMap::new()));
         let c = graph.add_node(("c".to_string(), IndexMap::new()));
-        graph.add_edge(a, b, ());
-        graph.add_edge(a, c, ());
+        graph.add_edge(a, b, IndexMap::new());
+        graph.add_edge(a, c, IndexMap::new());
         return graph;
     }
 
@@ -505,8 +1026,8 @@ mod tests {
         let a = graph.add_node(("a".to_string(), IndexMap::new()));
         let b = graph.add_node(("b".to_string(), IndexMap::new()));
         let c = graph.add_node(("c".to_string(), IndexMap::new()));
-        graph.add_edge(a, b, ());
-        graph.add_edge(b, c, ());
+        graph.add_edge(a, b, IndexMap::new());
+        graph.add_edge(b, c, IndexMap::new());
         return graph;
     }
 
@@ -515,15 +1036,15 @@ mod tests {
         let a = graph.add_node(("a".to_string(), IndexMap::new()));
         let b = graph.add_node(("b".to_string(), IndexMap::new()));
 
-        graph.add_edge(a, b, ());
+        graph.add_edge(a, b, IndexMap::new());
         return graph;
     }
 
     fn three_node_graph_with_properties() -> GraphType {
         let mut graph : GraphType = Graph::new();
-        let a_hashmap: IndexMap<u64, String> = [
-            (1, "100".to_string()), // height
-            (2, "5".to_string()), // breadth
+        let a_hashmap: IndexMap<u64, Value> = [
+            (1, Value::Uint(100)), // height
+            (2, Value::Uint(5)), // breadth
         ]
         .iter()
         .cloned()
@@ -531,34 +1052,34 @@ mod tests {
         let a = graph.add_node(("a".to_string(), a_hashmap));
         let b = graph.add_node(("b".to_string(), IndexMap::new()));
         let c = graph.add_node(("c".to_string(), IndexMap::new()));
-        graph.add_edge(a, b, ());
-        graph.add_edge(a, c, ());
+        graph.add_edge(a, b, IndexMap::new());
+        graph.add_edge(a, c, IndexMap::new());
         return graph;
     }
 
     fn two_node_graph_with_properties() -> GraphType {
         let mut graph : GraphType = Graph::new();
-        let a_hashmap: IndexMap<u64, String> = [(1, "100".to_string())]
+        let a_hashmap: IndexMap<u64, Value> = [(1, Value::Uint(100))]
             .iter()
             .cloned()
             .collect();
         let a = graph.add_node(("a".to_string(), a_hashmap));
         let b = graph.add_node(("b".to_string(), IndexMap::new()));
 
-        graph.add_edge(a, b, ());
+        graph.add_edge(a, b, IndexMap::new());
         return graph;
     }
 
     fn two_node_graph_with_wrong_properties() -> GraphType {
         let mut graph : GraphType = Graph::new();
-        let a_hashmap: IndexMap<u64, String> = [(1, "1".to_string())]
+        let a_hashmap: IndexMap<u64, Value> = [(1, Value::Uint(1))]
             .iter()
             .cloned()
             .collect();
         let a = graph.add_node(("a".to_string(), a_hashmap));
         let b = graph.add_node(("b".to_string(), IndexMap::new()));
 
-        graph.add_edge(a, b, ());
+        graph.add_edge(a, b, IndexMap::new());
         return graph;
     }
 
@@ -569,9 +1090,9 @@ mod tests {
         let c = graph.add_node(("c".to_string(), IndexMap::new()));
         let star = graph.add_node(("*".to_string(), IndexMap::new()));
 
-        graph.add_edge(a, b, ());
-        graph.add_edge(b, c, ());
-        graph.add_edge(c, star, ());
+        graph.add_edge(a, b, IndexMap::new());
+        graph.add_edge(b, c, IndexMap::new());
+        graph.add_edge(c, star, IndexMap::new());
         return graph;
     }
 
@@ -587,12 +1108,12 @@ mod tests {
         let left_unnamed_child = graph.add_node((String::from("leftchild"), IndexMap::new()));
         let right_unnamed_child = graph.add_node((String::from("rightchild"), IndexMap::new()));
 
-        graph.add_edge(r, v, ());
-        graph.add_edge(v, v1, ());
-        graph.add_edge(v, v2, ());
-        graph.add_edge(v, v3, ());
-        graph.add_edge(v1, left_unnamed_child, ());
-        graph.add_edge(v1, right_unnamed_child, ());
+        graph.add_edge(r, v, IndexMap::new());
+        graph.add_edge(v, v1, IndexMap::new());
+        graph.add_edge(v, v2, IndexMap::new());
+        graph.add_edge(v, v3, IndexMap::new());
+        graph.add_edge(v1, left_unnamed_child, IndexMap::new());
+        graph.add_edge(v1, right_unnamed_child, IndexMap::new());
 
         return graph;
     }
@@ -608,12 +1129,12 @@ mod tests {
         let u1_right_child = graph.add_node((String::from("u1right"), IndexMap::new()));
         let u3_child = graph.add_node((String::from("u3child"), IndexMap::new()));
 
-        graph.add_edge(u, u1, ());
-        graph.add_edge(u, u2, ());
-        graph.add_edge(u, u3, ());
-        graph.add_edge(u1, u1_left_child, ());
-        graph.add_edge(u1, u1_right_child, ());
-        graph.add_edge(u3, u3_child, ());
+        graph.add_edge(u, u1, IndexMap::new());
+        graph.add_edge(u, u2, IndexMap::new());
+        graph.add_edge(u, u3, IndexMap::new());
+        graph.add_edge(u1, u1_left_child, IndexMap::new());
+        graph.add_edge(u1, u1_right_child, IndexMap::new());
+        graph.add_edge(u3, u3_child, IndexMap::new());
 
         return graph;
     }
@@ -625,9 +1146,9 @@ mod tests {
         let child2 = graph.add_node((String::from("child2"), IndexMap::new()));
         let child3 = graph.add_node((String::from("child3"), IndexMap::new()));
 
-        graph.add_edge(root, child1, ());
-        graph.add_edge(root, child2, ());
-        graph.add_edge(root, child3, ());
+        graph.add_edge(root, child1, IndexMap::new());
+        graph.add_edge(root, child2, IndexMap::new());
+        graph.add_edge(root, child3, IndexMap::new());
 
         return graph;
     }
@@ -640,10 +1161,10 @@ mod tests {
         let child3 = graph.add_node((String::from("child3"), IndexMap::new()));
         let child4 = graph.add_node((String::from("child4"), IndexMap::new()));
 
-        graph.add_edge(root, child1, ());
-        graph.add_edge(root, child2, ());
-        graph.add_edge(root, child3, ());
-        graph.add_edge(root, child4, ());
+        graph.add_edge(root, child1, IndexMap::new());
+        graph.add_edge(root, child2, IndexMap::new());
+        graph.add_edge(root, child3, IndexMap::new());
+        graph.add_edge(root, child4, IndexMap::new());
 
         return graph;
     }
@@ -655,54 +1176,54 @@ mod tests {
         let ratings = graph.add_node((String::from("ratings-v1"), IndexMap::new()));
         let details = graph.add_node((String::from("details-v1"), IndexMap::new()));
 
-        graph.add_edge(productpage, reviews, ());
-        graph.add_edge(productpage, details, ());
-        graph.add_edge(reviews, ratings, ());
+        graph.add_edge(productpage, reviews, IndexMap::new());
+        graph.add_edge(productpage, details, IndexMap::new());
+        graph.add_edge(reviews, ratings, IndexMap::new());
 
         return graph;
     }
 
     fn simulation_example() -> GraphType {
         let mut graph_g : GraphType = Graph::new();
-        let prod_hashmap: IndexMap<u64, String> = [
-            (0, "productpage-v1".to_string()), //service name
-            (1, "2".to_string()), // height
+        let prod_hashmap: IndexMap<u64, Value> = [
+            (0, Value::Str("productpage-v1".to_string())), //service name
+            (1, Value::Uint(2)), // height
         ]
         .iter()
         .cloned()
         .collect();
         let prod = graph_g.add_node(("productpage-v1".to_string(), prod_hashmap));
 
-        let ratings_hashmap: IndexMap<u64, String> = [
-            (0, "ratings-v1".to_string()), //service name
-            (1, "0".to_string()), // height
+        let ratings_hashmap: IndexMap<u64, Value> = [
+            (0, Value::Str("ratings-v1".to_string())), //service name
+            (1, Value::Uint(0)), // height
         ]
         .iter()
         .cloned()
         .collect();
         let ratings = graph_g.add_node(("ratings-v1".to_string(), ratings_hashmap));
 
-        let reviews_hashmap: IndexMap<u64, String> = [
-            (0, "reviews-v1".to_string()),
-            (1, "1".to_string()), // height
+        let reviews_hashmap: IndexMap<u64, Value> = [
+            (0, Value::Str("reviews-v1".to_string())),
+            (1, Value::Uint(1)), // height
         ]
         .iter()
         .cloned()
         .collect();
         let reviews = graph_g.add_node(("reviews-v1".to_string(), reviews_hashmap));
 
-        let details_hashmap: IndexMap<u64, String> = [
-            (0, "details-v1".to_string()),
-            (1, "0".to_string()), // height
+        let details_hashmap: IndexMap<u64, Value> = [
+            (0, Value::Str("details-v1".to_string())),
+            (1, Value::Uint(0)), // height
         ]
         .iter()
         .cloned()
         .collect();
         let details = graph_g.add_node(("details-v1".to_string(), details_hashmap));
 
-        graph_g.add_edge(prod, reviews, ());
-        graph_g.add_edge(reviews, ratings, ());
-        graph_g.add_edge(prod, details, ());
+        graph_g.add_edge(prod, reviews, IndexMap::new());
+        graph_g.add_edge(reviews, ratings, IndexMap::new());
+        graph_g.add_edge(prod, details, IndexMap::new());
         return graph_g;
     }
 
@@ -716,9 +1237,9 @@ mod tests {
 
         let details = graph_g.add_node(("details-v1".to_string(), IndexMap::new()));
 
-        graph_g.add_edge(prod, reviews, ());
-        graph_g.add_edge(reviews, ratings, ());
-        graph_g.add_edge(prod, details, ());
+        graph_g.add_edge(prod, reviews, IndexMap::new());
+        graph_g.add_edge(reviews, ratings, IndexMap::new());
+        graph_g.add_edge(prod, details, IndexMap::new());
         return graph_g;
     }
 
@@ -732,12 +1253,12 @@ mod tests {
         let f = graph_g.add_node(("f".to_string(), IndexMap::new()));
         let g = graph_g.add_node(("g".to_string(), IndexMap::new()));
 
-        graph_g.add_edge(a, b, ());
-        graph_g.add_edge(b, c, ());
-        graph_g.add_edge(c, d, ());
-        graph_g.add_edge(d, e, ());
-        graph_g.add_edge(d, f, ());
-        graph_g.add_edge(d, g, ());
+        graph_g.add_edge(a, b, IndexMap::new());
+        graph_g.add_edge(b, c, IndexMap::new());
+        graph_g.add_edge(c, d, IndexMap::new());
+        graph_g.add_edge(d, e, IndexMap::new());
+        graph_g.add_edge(d, f, IndexMap::new());
+        graph_g.add_edge(d, g, IndexMap::new());
         return graph_g;
     }
     // ---------------------- Shamir Tests -------------------------
@@ -796,11 +1317,16 @@ mod tests {
         assert!(s.contains_key(&cb));
 
         assert!(s[&aa].len() == 0);
-        assert!(s[&ba].len() == 0);
-        assert!(s[&ca].len() == 0);
-
-        assert!(s[&bb].len() == 2, "bb len is {:?}", s[&bb].len());
-        assert!(s[&cb].len() == 2, "cb len is {:?}", s[&cb].len());
+        assert!(s[&ab].len() == 0);
+
+        // either node of graph H, with the other left out, maps to a leaf.  All
+        // of graph H does not, since neither node is alone
+        let a_h = get_node_with_id(&graph_h, "a").unwrap();
+        let b_h = get_node_with_id(&graph_h, "b").unwrap();
+        assert!(s[&ba].len() == 1 && s[&ba].contains_key(&b_h), "ba is {:?}", s[&ba]);
+        assert!(s[&ca].len() == 1 && s[&ca].contains_key(&b_h), "ca is {:?}", s[&ca]);
+        assert!(s[&bb].len() == 1 && s[&bb].contains_key(&a_h), "bb is {:?}", s[&bb]);
+        assert!(s[&cb].len() == 1 && s[&cb].contains_key(&a_h), "cb is {:?}", s[&cb]);
     }
 
     #[test]
@@ -897,7 +1423,7 @@ mod tests {
         let mut graph_h : GraphType = Graph::new();
         let a = graph_h.add_node(("a".to_string(), IndexMap::new()));
         let b = graph_h.add_node(("b".to_string(), IndexMap::new()));
-        graph_h.add_edge(a, b, ());
+        graph_h.add_edge(a, b, IndexMap::new());
 
         let mapping_wrapped = find_mapping_shamir_centralized(&graph_g, &graph_h);
         assert!(mapping_wrapped.is_some());
@@ -911,7 +1437,7 @@ mod tests {
         let graph_g = simulation_example();
 
         let mut graph_h : GraphType = Graph::new();
-        let a_hashmap: IndexMap<u64, String> = [(1, "0".to_string())]
+        let a_hashmap: IndexMap<u64, Value> = [(1, Value::Uint(0))]
             .iter()
             .cloned()
             .collect();
@@ -919,12 +1445,606 @@ mod tests {
         let b = graph_h.add_node((String::from("reviews-v1"), IndexMap::new()));
         let c = graph_h.add_node((String::from("ratings-v1"), IndexMap::new()));
 
+        graph_h.add_edge(a, b, IndexMap::new());
+        graph_h.add_edge(b, c, IndexMap::new());
+
+        assert!(find_mapping_shamir_centralized(&graph_g, &graph_h).is_none());
+    }
+
+    #[test]
+    fn test_comparison_properties() {
+        let graph_g = simulation_example();
+
+        // a node of height at least 1 calling a node of height less than 1
+        let mut graph_h : TargetType<Vec<Predicate>> = Graph::new();
+        let a = graph_h.add_node((String::from("a"), vec![Predicate::Compare(1, CompareOp::Ge, Value::Uint(1))]));
+        let b = graph_h.add_node((String::from("b"), vec![Predicate::Compare(1, CompareOp::Lt, Value::Uint(1))]));
         graph_h.add_edge(a, b, ());
-        graph_h.add_edge(b, c, ());
+        assert!(find_mapping_shamir_centralized(&graph_g, &graph_h).is_some());
+
+        let mut graph_h_2 : TargetType<Vec<Predicate>> = Graph::new();
+        let a_2 = graph_h_2.add_node((String::from("a"), vec![Predicate::Compare(1, CompareOp::Gt, Value::Uint(2))]));
+        let b_2 = graph_h_2.add_node((String::from("b"), Vec::new()));
+        graph_h_2.add_edge(a_2, b_2, ());
+        assert!(find_mapping_shamir_centralized(&graph_g, &graph_h_2).is_none());
+    }
+
+    fn workload_chain_graph(workloads: &[&str]) -> GraphType {
+        let mut graph : GraphType = Graph::new();
+        let mut previous = None;
+        for workload in workloads {
+            let properties: IndexMap<u64, Value> = [(0, Value::Str(workload.to_string()))]
+                .iter()
+                .cloned()
+                .collect();
+            let node = graph.add_node((workload.to_string(), properties));
+            if let Some(previous) = previous {
+                graph.add_edge(previous, node, IndexMap::new());
+            }
+            previous = Some(node);
+        }
+        graph
+    }
+
+    fn path_target_graph(hops: Hops) -> TargetType<Vec<Predicate>, Hops> {
+        let mut graph_h : TargetType<Vec<Predicate>, Hops> = Graph::new();
+        let a = graph_h.add_node((String::from("a"), vec![Predicate::Compare(0, CompareOp::Eq, Value::Str("productpage-v1".to_string()))]));
+        let b = graph_h.add_node((String::from("b"), vec![Predicate::Compare(0, CompareOp::Eq, Value::Str("db".to_string()))]));
+        graph_h.add_edge(a, b, hops);
+        graph_h
+    }
+
+    #[test]
+    fn test_path_edges() {
+        let graph_g = workload_chain_graph(&["productpage-v1", "reviews-v1", "ratings-v1", "db"]);
+        let db = get_node_with_id(&graph_g, "db").unwrap();
+
+        let graph_h = path_target_graph(Hops::new(1, Some(3)));
+        let mapping = find_mapping_shamir_centralized(&graph_g, &graph_h);
+        assert!(mapping.is_some());
+        let b = get_node_with_id(&graph_h, "b").unwrap();
+        assert!(mapping.unwrap().contains(&(b, db)));
+
+        assert!(find_mapping_shamir_centralized(&graph_g, &path_target_graph(Hops::new(1, None))).is_some());
+        assert!(find_mapping_shamir_centralized(&graph_g, &path_target_graph(Hops::new(3, Some(3)))).is_some());
+        // db is three calls away, not one or two
+        assert!(find_mapping_shamir_centralized(&graph_g, &path_target_graph(Hops::new(1, Some(2)))).is_none());
+        assert!(find_mapping_shamir_centralized(&graph_g, &path_target_graph(Hops::default())).is_none());
+        // paths only go from caller to callee
+        let reversed = workload_chain_graph(&["db", "reviews-v1", "productpage-v1"]);
+        assert!(find_mapping_shamir_centralized(&reversed, &path_target_graph(Hops::new(1, None))).is_none());
+    }
+
+    #[test]
+    fn test_candidates_are_below() {
+        let graph_g = workload_chain_graph(&["productpage-v1", "reviews-v1", "ratings-v1", "db"]);
+        let reviews = get_node_with_id(&graph_g, "reviews-v1").unwrap();
+        let ratings = get_node_with_id(&graph_g, "ratings-v1").unwrap();
+        let db = get_node_with_id(&graph_g, "db").unwrap();
+        // the caller of reviews-v1 is not a candidate, however far paths reach
+        let graph_h = path_target_graph(Hops::new(1, Some(1)));
+        assert_eq!(candidates_below(reviews, &graph_g, &graph_h), vec![ratings]);
+        let graph_h = path_target_graph(Hops::new(1, None));
+        assert_eq!(candidates_below(reviews, &graph_g, &graph_h), vec![ratings, db]);
+    }
+
+    #[test]
+    fn test_path_edges_below_the_same_call() {
+        // productpage-v1 reaches ratings-v1 and details-v1 through reviews-v1
+        let mut graph_g = workload_chain_graph(&["productpage-v1", "reviews-v1", "ratings-v1"]);
+        let reviews = get_node_with_id(&graph_g, "reviews-v1").unwrap();
+        let mut properties = IndexMap::new();
+        properties.insert(0, Value::Str("details-v1".to_string()));
+        let details = graph_g.add_node(("details-v1".to_string(), properties));
+        graph_g.add_edge(reviews, details, IndexMap::new());
+
+        let workload = |name: &str| vec![Predicate::Compare(0, CompareOp::Eq, Value::Str(name.to_string()))];
+        let paths_target_graph = |hops: Hops| {
+            let mut graph_h : TargetType<Vec<Predicate>, Hops> = Graph::new();
+            let a = graph_h.add_node((String::from("a"), workload("productpage-v1")));
+            let b = graph_h.add_node((String::from("b"), workload("ratings-v1")));
+            let c = graph_h.add_node((String::from("c"), workload("details-v1")));
+            graph_h.add_edge(a, b, hops);
+            graph_h.add_edge(a, c, hops);
+            graph_h
+        };
+        let mut mapping = find_mapping_shamir_centralized(&graph_g, &paths_target_graph(Hops::new(2, Some(2)))).unwrap();
+        mapping.sort();
+        assert_eq!(
+            mapping,
+            vec![
+                (NodeIndex::new(0), get_node_with_id(&graph_g, "productpage-v1").unwrap()),
+                (NodeIndex::new(1), get_node_with_id(&graph_g, "ratings-v1").unwrap()),
+                (NodeIndex::new(2), details),
+            ]
+        );
+        assert!(find_mapping_shamir_centralized(&graph_g, &paths_target_graph(Hops::new(1, None))).is_some());
+        // both are two calls away
+        assert!(find_mapping_shamir_centralized(&graph_g, &paths_target_graph(Hops::new(3, None))).is_none());
+    }
 
+    #[test]
+    fn test_decentralized_path_edges() {
+        let mut set_s: SetSType = IndexMap::new();
+        let graph_h = path_target_graph(Hops::new(2, None));
+        let full_graph_g = workload_chain_graph(&["productpage-v1", "reviews-v1", "db"]);
+
+        // the trace is built from the callee up, as responses come back
+        let mut graph_g : GraphType = Graph::new();
+        let mut previous = None;
+        for node in full_graph_g.node_indices().rev() {
+            let current = graph_g.add_node(full_graph_g.node_weight(node).unwrap().clone());
+            if let Some(previous) = previous {
+                graph_g.add_edge(current, previous, IndexMap::new());
+            }
+            let am_root = node == NodeIndex::new(0);
+            let ret = find_mapping_shamir_decentralized(&graph_g, &graph_h, &mut set_s, current, am_root);
+            assert!(ret.is_some() == am_root);
+            previous = Some(current);
+        }
+    }
+
+    #[test]
+    fn test_edge_properties() {
+        // productpage-v1 got a 503 from reviews-v1, which got a 200 from db
+        let mut graph_g = workload_chain_graph(&["productpage-v1", "reviews-v1", "db"]);
+        for (edge, code) in graph_g.edge_indices().zip(&[503, 200]) {
+            graph_g.edge_weight_mut(edge).unwrap().insert(1, Value::Int(*code));
+        }
+
+        let edge_target_graph = |predicate: Predicate| {
+            let mut graph_h : TargetType<Vec<Predicate>, EdgeFilter> = Graph::new();
+            let a = graph_h.add_node((String::from("a"), Vec::new()));
+            let b = graph_h.add_node((String::from("b"), vec![Predicate::Compare(0, CompareOp::Eq, Value::Str("db".to_string()))]));
+            graph_h.add_edge(a, b, EdgeFilter::new(Hops::default(), vec![predicate]));
+            graph_h
+        };
+        let graph_h = edge_target_graph(Predicate::Compare(1, CompareOp::Eq, Value::Int(200)));
+        assert!(find_mapping_shamir_centralized(&graph_g, &graph_h).is_some());
+        let graph_h = edge_target_graph(Predicate::Compare(1, CompareOp::Ge, Value::Int(500)));
+        assert!(find_mapping_shamir_centralized(&graph_g, &graph_h).is_none());
+        // calls without the property are unknown, and do not match
+        let graph_h = edge_target_graph(Predicate::Compare(2, CompareOp::Eq, Value::Int(1)));
         assert!(find_mapping_shamir_centralized(&graph_g, &graph_h).is_none());
     }
 
+    fn workload_target_graph(
+        workloads: &[&str],
+        edges: &[(usize, usize, bool)],
+    ) -> TargetType<Vec<Predicate>, EdgeFilter> {
+        let mut graph_h : TargetType<Vec<Predicate>, EdgeFilter> = Graph::new();
+        let nodes: Vec<NodeIndex> = workloads
+            .iter()
+            .map(|workload| {
+                let predicate = Predicate::Compare(0, CompareOp::Eq, Value::Str(workload.to_string()));
+                graph_h.add_node((workload.to_string(), vec![predicate]))
+            })
+            .collect();
+        for (caller, callee, undirected) in edges {
+            let mut edge_filter = EdgeFilter::default();
+            edge_filter.undirected = *undirected;
+            graph_h.add_edge(nodes[*caller], nodes[*callee], edge_filter);
+        }
+        graph_h
+    }
+
+    #[test]
+    fn test_undirected_edges() {
+        let graph_g = workload_chain_graph(&["productpage-v1", "reviews-v1", "ratings-v1"]);
+        let reviews = get_node_with_id(&graph_g, "reviews-v1").unwrap();
+        let ratings = get_node_with_id(&graph_g, "ratings-v1").unwrap();
+
+        // an undirected edge matches the call from reviews-v1 to ratings-v1,
+        // though it is written the other way
+        let graph_h = workload_target_graph(&["ratings-v1", "reviews-v1"], &[(0, 1, true)]);
+        let mut mapping = find_mapping_shamir_centralized(&graph_g, &graph_h).unwrap();
+        mapping.sort();
+        assert_eq!(mapping, vec![(NodeIndex::new(0), ratings), (NodeIndex::new(1), reviews)]);
+        let graph_h = workload_target_graph(&["reviews-v1", "ratings-v1"], &[(0, 1, true)]);
+        assert!(find_mapping_shamir_centralized(&graph_g, &graph_h).is_some());
+
+        // a directed edge does not
+        let graph_h = workload_target_graph(&["ratings-v1", "reviews-v1"], &[(0, 1, false)]);
+        assert!(find_mapping_shamir_centralized(&graph_g, &graph_h).is_none());
+        // and nothing calls reviews-v1 from below
+        let graph_h = workload_target_graph(
+            &["productpage-v1", "reviews-v1", "ratings-v1"],
+            &[(0, 1, false), (2, 1, false)],
+        );
+        assert!(find_mapping_shamir_centralized(&graph_g, &graph_h).is_none());
+
+        // the directed edge from reviews-v1 to ratings-v1 puts the root of the
+        // match above reviews-v1, at productpage-v1
+        let graph_h = workload_target_graph(
+            &["reviews-v1", "productpage-v1", "ratings-v1"],
+            &[(0, 1, true), (0, 2, false)],
+        );
+        assert!(find_mapping_shamir_centralized(&graph_g, &graph_h).is_some());
+        let graph_h = workload_target_graph(
+            &["reviews-v1", "productpage-v1", "ratings-v1"],
+            &[(0, 1, true), (2, 0, false)],
+        );
+        assert!(find_mapping_shamir_centralized(&graph_g, &graph_h).is_none());
+    }
+
+    #[test]
+    fn test_decentralized_undirected_edges() {
+        let mut set_s: SetSType = IndexMap::new();
+        let graph_h = workload_target_graph(&["ratings-v1", "reviews-v1"], &[(0, 1, true)]);
+        let full_graph_g =
+            workload_chain_graph(&["frontend", "productpage-v1", "reviews-v1", "ratings-v1"]);
+
+        // the trace is built from the callee up, as responses come back
+        let mut graph_g : GraphType = Graph::new();
+        let mut previous = None;
+        let mut found = false;
+        for node in full_graph_g.node_indices().rev() {
+            let current = graph_g.add_node(full_graph_g.node_weight(node).unwrap().clone());
+            if let Some(previous) = previous {
+                graph_g.add_edge(current, previous, IndexMap::new());
+            }
+            let am_root = node == NodeIndex::new(0);
+            found |= find_mapping_shamir_decentralized(&graph_g, &graph_h, &mut set_s, current, am_root).is_some();
+            previous = Some(current);
+        }
+        assert!(found);
+    }
+
+    fn mark_optional(
+        mut graph_h: TargetType<Vec<Predicate>, EdgeFilter>,
+        edge: usize,
+    ) -> TargetType<Vec<Predicate>, EdgeFilter> {
+        graph_h.edge_weight_mut(EdgeIndex::new(edge)).unwrap().optional = true;
+        graph_h
+    }
+
+    #[test]
+    fn test_optional_edges() {
+        let graph_g = workload_chain_graph(&["productpage-v1", "reviews-v1", "ratings-v1"]);
+        let productpage = get_node_with_id(&graph_g, "productpage-v1").unwrap();
+        let reviews = get_node_with_id(&graph_g, "reviews-v1").unwrap();
+        let ratings = get_node_with_id(&graph_g, "ratings-v1").unwrap();
+
+        // the optional callee is mapped when the trace has it
+        let graph_h = mark_optional(
+            workload_target_graph(&["productpage-v1", "reviews-v1", "ratings-v1"], &[(0, 1, false), (1, 2, false)]),
+            1,
+        );
+        let mut mapping = find_mapping_shamir_centralized(&graph_g, &graph_h).unwrap();
+        mapping.sort();
+        assert_eq!(
+            mapping,
+            vec![(NodeIndex::new(0), productpage), (NodeIndex::new(1), reviews), (NodeIndex::new(2), ratings)]
+        );
+
+        // and left out when it does not
+        let graph_h = mark_optional(
+            workload_target_graph(&["productpage-v1", "reviews-v1", "details-v1"], &[(0, 1, false), (1, 2, false)]),
+            1,
+        );
+        let mut mapping = find_mapping_shamir_centralized(&graph_g, &graph_h).unwrap();
+        mapping.sort();
+        assert_eq!(mapping, vec![(NodeIndex::new(0), productpage), (NodeIndex::new(1), reviews)]);
+        let graph_h = mark_optional(
+            workload_target_graph(&["ratings-v1", "details-v1", "reviews-v1"], &[(0, 1, false), (1, 2, false)]),
+            0,
+        );
+        assert_eq!(
+            find_mapping_shamir_centralized(&graph_g, &graph_h),
+            Some(vec![(NodeIndex::new(0), ratings)])
+        );
+
+        // the rest of the pattern still has to match
+        let graph_h = mark_optional(
+            workload_target_graph(&["productpage-v1", "details-v1", "ratings-v1"], &[(0, 1, false), (1, 2, false)]),
+            1,
+        );
+        assert!(find_mapping_shamir_centralized(&graph_g, &graph_h).is_none());
+
+        // an optional callee does not take the call a mandatory one needs
+        let mut graph_h : TargetType<Vec<Predicate>, EdgeFilter> = Graph::new();
+        let a = graph_h.add_node((String::from("a"), vec![Predicate::Compare(0, CompareOp::Eq, Value::Str("productpage-v1".to_string()))]));
+        let b = graph_h.add_node((String::from("b"), Vec::new()));
+        let c = graph_h.add_node((String::from("c"), vec![Predicate::Compare(0, CompareOp::Eq, Value::Str("reviews-v1".to_string()))]));
+        graph_h.add_edge(a, c, EdgeFilter::new(Hops::default(), Vec::new()).optional());
+        graph_h.add_edge(a, b, EdgeFilter::default());
+        let mut mapping = find_mapping_shamir_centralized(&graph_g, &graph_h).unwrap();
+        mapping.sort();
+        assert_eq!(mapping, vec![(a, productpage), (b, reviews)]);
+    }
+
+    #[test]
+    fn test_decentralized_optional_edges() {
+        let graph_h = mark_optional(
+            workload_target_graph(&["productpage-v1", "reviews-v1", "ratings-v1"], &[(0, 1, false), (1, 2, false)]),
+            1,
+        );
+        for workloads in &[vec!["productpage-v1", "reviews-v1", "ratings-v1"], vec!["productpage-v1", "reviews-v1"]] {
+            let full_graph_g = workload_chain_graph(workloads);
+            let mut set_s: SetSType = IndexMap::new();
+            let mut graph_g : GraphType = Graph::new();
+            let mut previous = None;
+            let mut mapping = None;
+            for node in full_graph_g.node_indices().rev() {
+                let current = graph_g.add_node(full_graph_g.node_weight(node).unwrap().clone());
+                if let Some(previous) = previous {
+                    graph_g.add_edge(current, previous, IndexMap::new());
+                }
+                let am_root = node == NodeIndex::new(0);
+                mapping = find_mapping_shamir_decentralized(&graph_g, &graph_h, &mut set_s, current, am_root);
+                previous = Some(current);
+            }
+            assert_eq!(mapping.unwrap().len(), workloads.len());
+        }
+    }
+
+    fn two_reviews_graph() -> GraphType {
+        let mut graph_g : GraphType = Graph::new();
+        let workload = |name: &str| {
+            let properties: IndexMap<u64, Value> =
+                [(0, Value::Str(name.to_string()))].iter().cloned().collect();
+            (name.to_string(), properties)
+        };
+        let productpage = graph_g.add_node(workload("productpage-v1"));
+        let reviews_1 = graph_g.add_node(workload("reviews-v1"));
+        let reviews_2 = graph_g.add_node(workload("reviews-v2"));
+        graph_g.add_edge(productpage, reviews_1, IndexMap::new());
+        graph_g.add_edge(productpage, reviews_2, IndexMap::new());
+        graph_g
+    }
+
+    #[test]
+    fn test_accepted_mappings() {
+        let graph_g = two_reviews_graph();
+        let mut graph_h : TargetType<Vec<Predicate>, EdgeFilter> = Graph::new();
+        let a = graph_h.add_node((String::from("a"), vec![Predicate::Compare(0, CompareOp::Eq, Value::Str("productpage-v1".to_string()))]));
+        let b = graph_h.add_node((String::from("b"), Vec::new()));
+        graph_h.add_edge(a, b, EdgeFilter::default());
+        // whichever call the matcher maps b to, the other one is tried as well
+        for reviews in &["reviews-v1", "reviews-v2"] {
+            let reviews = get_node_with_id(&graph_g, reviews).unwrap();
+            let mapping =
+                find_accepted_mapping_centralized(&graph_g, &graph_h, |mapping| mapping.contains(&(b, reviews)))
+                    .unwrap();
+            assert!(mapping.contains(&(b, reviews)));
+            assert_eq!(mapping.len(), 2);
+        }
+        // b is never the caller
+        let productpage = get_node_with_id(&graph_g, "productpage-v1").unwrap();
+        assert!(find_accepted_mapping_centralized(&graph_g, &graph_h, |mapping| mapping.contains(&(b, productpage))).is_none());
+
+        // an optional callee is left out when no way of mapping it is accepted
+        let graph_g = workload_chain_graph(&["productpage-v1", "reviews-v1", "ratings-v1"]);
+        let graph_h = mark_optional(
+            workload_target_graph(&["productpage-v1", "reviews-v1", "ratings-v1"], &[(0, 1, false), (1, 2, false)]),
+            1,
+        );
+        let ratings = NodeIndex::new(2);
+        let mapping = find_accepted_mapping_centralized(&graph_g, &graph_h, |mapping| {
+            mapping.iter().all(|(node_h, _)| *node_h != ratings)
+        })
+        .unwrap();
+        assert_eq!(mapping.len(), 2);
+
+        // the parts of a pattern may overlap, unless accept keeps them apart
+        let graph_g = two_reviews_graph();
+        let mut graph_h : TargetType<Vec<Predicate>, EdgeFilter> = Graph::new();
+        let b = graph_h.add_node((String::from("b"), vec![Predicate::Compare(0, CompareOp::Eq, Value::Str("productpage-v1".to_string()))]));
+        let c = graph_h.add_node((String::from("c"), Vec::new()));
+        let d = graph_h.add_node((String::from("d"), vec![Predicate::Compare(0, CompareOp::Eq, Value::Str("productpage-v1".to_string()))]));
+        let e = graph_h.add_node((String::from("e"), Vec::new()));
+        graph_h.add_edge(b, c, EdgeFilter::default());
+        graph_h.add_edge(d, e, EdgeFilter::default());
+        let mapped_to = |mapping: &Vec<(NodeIndex, NodeIndex)>, node_h: NodeIndex| {
+            mapping.iter().find(|(other, _)| *other == node_h).unwrap().1
+        };
+        let mapping = find_accepted_mapping_centralized(&graph_g, &graph_h, |mapping| {
+            mapped_to(mapping, c) != mapped_to(mapping, e)
+        })
+        .unwrap();
+        assert_ne!(mapped_to(&mapping, c), mapped_to(&mapping, e));
+        assert_eq!(mapped_to(&mapping, b), mapped_to(&mapping, d));
+    }
+
+    #[test]
+    fn test_decentralized_accepted_mappings() {
+        let full_graph_g = two_reviews_graph();
+        let mut graph_h : TargetType<Vec<Predicate>, EdgeFilter> = Graph::new();
+        let a = graph_h.add_node((String::from("a"), vec![Predicate::Compare(0, CompareOp::Eq, Value::Str("productpage-v1".to_string()))]));
+        let b = graph_h.add_node((String::from("b"), Vec::new()));
+        graph_h.add_edge(a, b, EdgeFilter::default());
+        for reviews in &["reviews-v1", "reviews-v2"] {
+            // the callees come before the root, which calls both of them
+            let mut graph_g : GraphType = Graph::new();
+            let mut set_s: SetSType = IndexMap::new();
+            let mut mapping = None;
+            for node in full_graph_g.node_indices().rev() {
+                let current = graph_g.add_node(full_graph_g.node_weight(node).unwrap().clone());
+                let am_root = full_graph_g.neighbors_directed(node, Incoming).count() == 0;
+                if am_root {
+                    for callee in graph_g.node_indices().filter(|callee| *callee != current).collect::<Vec<_>>() {
+                        graph_g.add_edge(current, callee, IndexMap::new());
+                    }
+                }
+                let wanted = get_node_with_id(&graph_g, reviews);
+                mapping = find_accepted_mapping_decentralized(&graph_g, &graph_h, &mut set_s, current, am_root, |mapping| {
+                    wanted.map_or(false, |wanted| mapping.contains(&(b, wanted)))
+                });
+            }
+            let wanted = get_node_with_id(&graph_g, reviews).unwrap();
+            assert!(mapping.unwrap().contains(&(b, wanted)));
+        }
+    }
+
+    #[test]
+    fn test_decentralized_accepted_mappings_stay_below() {
+        let full_graph_g = workload_chain_graph(&["productpage-v1", "reviews-v1", "ratings-v1", "db"]);
+        let mut graph_h : TargetType<Vec<Predicate>, EdgeFilter> = Graph::new();
+        let a = graph_h.add_node((String::from("a"), Vec::new()));
+        let b = graph_h.add_node((String::from("b"), Vec::new()));
+        graph_h.add_edge(a, b, EdgeFilter::default());
+        let mut set_s: SetSType = IndexMap::new();
+        let mut graph_g : GraphType = Graph::new();
+        let mut previous = None;
+        let mut mappings = Vec::new();
+        for node in full_graph_g.node_indices().rev() {
+            let current = graph_g.add_node(full_graph_g.node_weight(node).unwrap().clone());
+            if let Some(previous) = previous {
+                graph_g.add_edge(current, previous, IndexMap::new());
+            }
+            let am_root = node == NodeIndex::new(0);
+            let reviews = get_node_with_id(&graph_g, "reviews-v1");
+            mappings.push(find_accepted_mapping_decentralized(&graph_g, &graph_h, &mut set_s, current, am_root, |mapping| {
+                reviews.map_or(false, |reviews| mapping.contains(&(a, reviews)))
+            }));
+            previous = Some(current);
+        }
+        // reviews-v1 only looks below itself, where a can not map to it
+        assert_eq!(mappings[2], None);
+        let reviews = get_node_with_id(&graph_g, "reviews-v1").unwrap();
+        let ratings = get_node_with_id(&graph_g, "ratings-v1").unwrap();
+        let mut mapping = mappings[3].clone().unwrap();
+        mapping.sort();
+        assert_eq!(mapping, vec![(a, reviews), (b, ratings)]);
+    }
+
+    #[test]
+    fn test_accepted_mapping_search_is_bounded() {
+        let graph_g = workload_chain_graph(&["productpage-v1", "reviews-v1", "ratings-v1"]);
+        let mut graph_h : TargetType<Vec<Predicate>, EdgeFilter> = Graph::new();
+        let a = graph_h.add_node((String::from("a"), Vec::new()));
+        let b = graph_h.add_node((String::from("b"), Vec::new()));
+        graph_h.add_edge(a, b, EdgeFilter::default());
+        let ratings = get_node_with_id(&graph_g, "ratings-v1").unwrap();
+        let accept = |mapping: &Vec<(NodeIndex, NodeIndex)>| mapping.contains(&(b, ratings));
+        let search = |budget| {
+            let mut search = MappingSearch {
+                graph_g: &graph_g,
+                graph_h: &graph_h,
+                order: mapping_order(&graph_h),
+                scope: graph_g.node_indices().collect(),
+                accept: &accept,
+                budget,
+            };
+            search.extend(0, &mut Vec::new())
+        };
+        // a is tried at productpage-v1 first, so the accepted mapping takes four placements
+        assert!(!search(3));
+        assert!(search(4));
+    }
+
+    #[test]
+    fn test_mapping_pairs_are_from_h_to_g() {
+        // the trace nodes are at other indices than the target nodes they match
+        let graph_g = workload_chain_graph(&["frontend", "productpage-v1", "reviews-v1", "ratings-v1"]);
+        let mut graph_h : TargetType<Vec<Predicate>, EdgeFilter> = Graph::new();
+        let a = graph_h.add_node((String::from("a"), vec![Predicate::Compare(0, CompareOp::Eq, Value::Str("productpage-v1".to_string()))]));
+        let b = graph_h.add_node((String::from("b"), Vec::new()));
+        graph_h.add_edge(a, b, EdgeFilter::default());
+        let mut mapping = find_mapping_shamir_centralized(&graph_g, &graph_h).unwrap();
+        mapping.sort();
+        let productpage = get_node_with_id(&graph_g, "productpage-v1").unwrap();
+        let reviews = get_node_with_id(&graph_g, "reviews-v1").unwrap();
+        assert_eq!(mapping, vec![(a, productpage), (b, reviews)]);
+    }
+
+    #[test]
+    fn test_mapping_has_every_node() {
+        // nodes below the children of the root are mapped through the S entry
+        // that leaves out their caller
+        let graph_g = workload_chain_graph(&["productpage-v1", "reviews-v1", "ratings-v1", "db"]);
+        let graph_h = workload_target_graph(
+            &["productpage-v1", "reviews-v1", "ratings-v1", "db"],
+            &[(0, 1, false), (1, 2, false), (2, 3, false)],
+        );
+        let mut mapping = find_mapping_shamir_centralized(&graph_g, &graph_h).unwrap();
+        mapping.sort();
+        let expected: Vec<(NodeIndex, NodeIndex)> =
+            graph_g.node_indices().map(|node| (node, node)).collect();
+        assert_eq!(mapping, expected);
+    }
+
+    #[test]
+    fn test_unconnected_components() {
+        let graph_g = workload_chain_graph(&["productpage-v1", "reviews-v1", "ratings-v1", "db"]);
+        let component_target_graph = |first: &str, second: &str| {
+            let mut graph_h : TargetType<Vec<Predicate>, EdgeFilter> = Graph::new();
+            let a = graph_h.add_node((String::from("a"), vec![Predicate::Compare(0, CompareOp::Eq, Value::Str("productpage-v1".to_string()))]));
+            let b = graph_h.add_node((String::from("b"), Vec::new()));
+            let c = graph_h.add_node((String::from("c"), vec![Predicate::Compare(0, CompareOp::Eq, Value::Str(first.to_string()))]));
+            let d = graph_h.add_node((String::from("d"), vec![Predicate::Compare(0, CompareOp::Eq, Value::Str(second.to_string()))]));
+            graph_h.add_edge(a, b, EdgeFilter::default());
+            graph_h.add_edge(c, d, EdgeFilter::default());
+            graph_h
+        };
+        let graph_h = component_target_graph("ratings-v1", "db");
+        let mapping = find_mapping_shamir_centralized(&graph_g, &graph_h).unwrap();
+        let c = get_node_with_id(&graph_h, "c").unwrap();
+        let ratings = get_node_with_id(&graph_g, "ratings-v1").unwrap();
+        assert!(mapping.contains(&(c, ratings)));
+        // both parts have to match
+        let graph_h = component_target_graph("db", "ratings-v1");
+        assert!(find_mapping_shamir_centralized(&graph_g, &graph_h).is_none());
+
+        // the parts may overlap
+        let graph_g = workload_chain_graph(&["productpage-v1", "reviews-v1"]);
+        let graph_h = component_target_graph("productpage-v1", "reviews-v1");
+        let mapping = find_mapping_shamir_centralized(&graph_g, &graph_h).unwrap();
+        let a = get_node_with_id(&graph_h, "a").unwrap();
+        let productpage = get_node_with_id(&graph_g, "productpage-v1").unwrap();
+        assert!(mapping.contains(&(a, productpage)) && mapping.contains(&(c, productpage)));
+
+        // decentralized, the parts are only matched at the root
+        let graph_g = workload_chain_graph(&["productpage-v1", "reviews-v1", "ratings-v1", "db"]);
+        let graph_h = component_target_graph("ratings-v1", "db");
+        let mut set_s: SetSType = IndexMap::new();
+        let reviews = get_node_with_id(&graph_g, "reviews-v1").unwrap();
+        assert!(find_mapping_shamir_decentralized(&graph_g, &graph_h, &mut set_s, reviews, false).is_none());
+        let root = get_node_with_id(&graph_g, "productpage-v1").unwrap();
+        assert!(find_mapping_shamir_decentralized(&graph_g, &graph_h, &mut set_s, root, true).is_some());
+    }
+
+    #[test]
+    fn test_decentralized_unconnected_components() {
+        let workload_node = |name: &str, workload: &str| {
+            (name.to_string(), vec![Predicate::Compare(0, CompareOp::Eq, Value::Str(workload.to_string()))])
+        };
+        // (a:productpage-v1)-->(b:details-v1), (c:reviews-v1)-->(d:ratings-v1)
+        let mut graph_h : TargetType<Vec<Predicate>, EdgeFilter> = Graph::new();
+        let a = graph_h.add_node(workload_node("a", "productpage-v1"));
+        let b = graph_h.add_node(workload_node("b", "details-v1"));
+        let c = graph_h.add_node(workload_node("c", "reviews-v1"));
+        let d = graph_h.add_node(workload_node("d", "ratings-v1"));
+        graph_h.add_edge(a, b, EdgeFilter::default());
+        graph_h.add_edge(c, d, EdgeFilter::default());
+
+        // every service adds itself to the trace graph it is sent, from the leaves up
+        let mut graph_g : GraphType = Graph::new();
+        let mut set_s: SetSType = IndexMap::new();
+        let add_service = |graph_g: &mut GraphType, workload: &str, children: &[NodeIndex]| {
+            let properties: IndexMap<u64, Value> = [(0, Value::Str(workload.to_string()))]
+                .iter()
+                .cloned()
+                .collect();
+            let node = graph_g.add_node((workload.to_string(), properties));
+            for child in children {
+                graph_g.add_edge(node, *child, IndexMap::new());
+            }
+            node
+        };
+        let ratings = add_service(&mut graph_g, "ratings-v1", &[]);
+        assert!(find_mapping_shamir_decentralized(&graph_g, &graph_h, &mut set_s, ratings, false).is_none());
+        // reviews-v1 sees the whole of the second part, but only the root matches the parts
+        let reviews = add_service(&mut graph_g, "reviews-v1", &[ratings]);
+        assert!(find_mapping_shamir_decentralized(&graph_g, &graph_h, &mut set_s, reviews, false).is_none());
+        let details = add_service(&mut graph_g, "details-v1", &[]);
+        assert!(find_mapping_shamir_decentralized(&graph_g, &graph_h, &mut set_s, details, false).is_none());
+        let productpage = add_service(&mut graph_g, "productpage-v1", &[details, reviews]);
+        let mut mapping = find_mapping_shamir_decentralized(&graph_g, &graph_h, &mut set_s, productpage, true).unwrap();
+        mapping.sort();
+        assert_eq!(mapping, vec![(a, productpage), (b, details), (c, reviews), (d, ratings)]);
+    }
+
     #[test]
     fn test_decentralized() {
         let mut set_s: SetSType = IndexMap::new();
@@ -936,12 +2056,12 @@ mod tests {
         assert!(ret.is_none());
 
         let b = graph_g.add_node((String::from("b"), IndexMap::new()));
-        graph_g.add_edge(b, a, ());
+        graph_g.add_edge(b, a, IndexMap::new());
         let ret = find_mapping_shamir_decentralized(&graph_g, &graph_h, &mut set_s, b, false);
         assert!(ret.is_none());
 
         let c = graph_g.add_node((String::from("c"), IndexMap::new()));
-        graph_g.add_edge(c, b, ());
+        graph_g.add_edge(c, b, IndexMap::new());
         let ret = find_mapping_shamir_decentralized(&graph_g, &graph_h, &mut set_s, c, true);
         assert!(ret.is_some());
     }
@@ -953,7 +2073,7 @@ mod tests {
 
         // create graph h
         let mut graph_h : GraphType = Graph::new();
-        let a_hashmap: IndexMap<u64, String> = [(1, "2".to_string())]
+        let a_hashmap: IndexMap<u64, Value> = [(1, Value::Uint(2))]
             .iter()
             .cloned()
             .collect();
@@ -961,14 +2081,14 @@ mod tests {
         let b = graph_h.add_node((String::from("reviews-v1"), IndexMap::new()));
         let c = graph_h.add_node((String::from("ratings-v1"), IndexMap::new()));
 
-        graph_h.add_edge(a, b, ());
-        graph_h.add_edge(b, c, ());
+        graph_h.add_edge(a, b, IndexMap::new());
+        graph_h.add_edge(b, c, IndexMap::new());
 
         //create graph g
         let mut graph_g : GraphType = Graph::new();
-        let ratings_hashmap: IndexMap<u64, String> = [
-            (0, "ratings-v1".to_string()),
-            (1, "0".to_string()),
+        let ratings_hashmap: IndexMap<u64, Value> = [
+            (0, Value::Str("ratings-v1".to_string())),
+            (1, Value::Uint(0)),
         ]
         .iter()
         .cloned()
@@ -977,28 +2097,28 @@ mod tests {
         let ret = find_mapping_shamir_decentralized(&graph_g, &graph_h, &mut set_s, ratings, false);
         assert!(ret.is_none());
 
-        let reviews_hashmap: IndexMap<u64, String> = [
-            (0, "reviews-v1".to_string()),
-            (1, "1".to_string()),
+        let reviews_hashmap: IndexMap<u64, Value> = [
+            (0, Value::Str("reviews-v1".to_string())),
+            (1, Value::Uint(1)),
         ]
         .iter()
         .cloned()
         .collect();
         let reviews = graph_g.add_node(("reviews-v1".to_string(), reviews_hashmap));
-        graph_g.add_edge(reviews, ratings, ());
+        graph_g.add_edge(reviews, ratings, IndexMap::new());
         let ret = find_mapping_shamir_decentralized(&graph_g, &graph_h, &mut set_s, reviews, false);
         assert!(ret.is_none());
 
-        let prod_hashmap: IndexMap<u64, String> = [
-            (0, "productpage-v1".to_string()),
-            (1, "2".to_string()),
+        let prod_hashmap: IndexMap<u64, Value> = [
+            (0, Value::Str("productpage-v1".to_string())),
+            (1, Value::Uint(2)),
         ]
         .iter()
         .cloned()
         .collect();
         let prod = graph_g.add_node(("productpage-v1".to_string(), prod_hashmap));
 
-        graph_g.add_edge(prod, reviews, ());
+        graph_g.add_edge(prod, reviews, IndexMap::new());
         let ret = find_mapping_shamir_decentralized(&graph_g, &graph_h, &mut set_s, prod, true);
         assert!(ret.is_some());
     }
@@ -1010,7 +2130,7 @@ mod tests {
 
         // create graph h
         let mut graph_h : GraphType = Graph::new();
-        let a_hashmap: IndexMap<u64, String> = [(1, "2".to_string())]
+        let a_hashmap: IndexMap<u64, Value> = [(1, Value::Uint(2))]
             .iter()
             .cloned()
             .collect();
@@ -1018,14 +2138,14 @@ mod tests {
         let b = graph_h.add_node((String::from("reviews-v1"), IndexMap::new()));
         let c = graph_h.add_node((String::from("ratings-v1"), IndexMap::new()));
 
-        graph_h.add_edge(a, b, ());
-        graph_h.add_edge(b, c, ());
+        graph_h.add_edge(a, b, IndexMap::new());
+        graph_h.add_edge(b, c, IndexMap::new());
 
         //create graph g
         let mut graph_g : GraphType = Graph::new();
-        let ratings_hashmap: IndexMap<u64, String> = [
-            (0, "ratings-v1".to_string()),
-            (1, "0".to_string()), // height
+        let ratings_hashmap: IndexMap<u64, Value> = [
+            (0, Value::Str("ratings-v1".to_string())),
+            (1, Value::Uint(0)), // height
         ]
         .iter()
         .cloned()
@@ -1034,28 +2154,28 @@ mod tests {
         let ret = find_mapping_shamir_decentralized(&graph_g, &graph_h, &mut set_s, ratings, false);
         assert!(ret.is_none());
 
-        let reviews_hashmap: IndexMap<u64, String> = [
-            (0, "reviews-v1".to_string()),
-            (1, "1".to_string()), //height
+        let reviews_hashmap: IndexMap<u64, Value> = [
+            (0, Value::Str("reviews-v1".to_string())),
+            (1, Value::Uint(1)), //height
         ]
         .iter()
         .cloned()
         .collect();
         let reviews = graph_g.add_node(("reviews-v1".to_string(), reviews_hashmap));
-        graph_g.add_edge(reviews, ratings, ());
+        graph_g.add_edge(reviews, ratings, IndexMap::new());
         let ret = find_mapping_shamir_decentralized(&graph_g, &graph_h, &mut set_s, reviews, false);
         assert!(ret.is_none());
 
-        let prod_hashmap: IndexMap<u64, String> = [
-            (1, "0".to_string()), // WRONG PROPERTY, should make this fail, height
-            (0, "productpage-v1".to_string()),
+        let prod_hashmap: IndexMap<u64, Value> = [
+            (1, Value::Uint(0)), // WRONG PROPERTY, should make this fail, height
+            (0, Value::Str("productpage-v1".to_string())),
         ]
         .iter()
         .cloned()
         .collect();
         let prod = graph_g.add_node(("productpage-v1".to_string(), prod_hashmap));
 
-        graph_g.add_edge(prod, reviews, ());
+        graph_g.add_edge(prod, reviews, IndexMap::new());
         let ret = find_mapping_shamir_decentralized(&graph_g, &graph_h, &mut set_s, prod, true);
         assert!(ret.is_none());
     }
@@ -1090,9 +2210,9 @@ mod tests {
         let mut graph_g : GraphType = Graph::new();
         let a = graph_g.add_node((String::from("a"), IndexMap::new()));
         let b = graph_g.add_node((String::from("b"), IndexMap::new()));
-        graph_g.add_edge(b, a, ());
+        graph_g.add_edge(b, a, IndexMap::new());
         let c = graph_g.add_node((String::from("c"), IndexMap::new()));
-        graph_g.add_edge(c, b, ());
+        graph_g.add_edge(c, b, IndexMap::new());
         bencher.iter(|| find_mapping_shamir_centralized(&graph_g, &graph_h));
     }
 
@@ -1107,11 +2227,11 @@ mod tests {
             find_mapping_shamir_decentralized(&graph_g, &graph_h, &mut set_s, a, false);
 
             let b = graph_g.add_node((String::from("b"), IndexMap::new()));
-            graph_g.add_edge(b, a, ());
+            graph_g.add_edge(b, a, IndexMap::new());
             find_mapping_shamir_decentralized(&graph_g, &graph_h, &mut set_s, b, false);
 
             let c = graph_g.add_node((String::from("c"), IndexMap::new()));
-            graph_g.add_edge(c, b, ());
+            graph_g.add_edge(c, b, IndexMap::new());
             find_mapping_shamir_decentralized(&graph_g, &graph_h, &mut set_s, c, true);
         });
     }
@@ -1,18 +1,41 @@
 use super::graph_utils;
+use super::graph_utils::Value;
 use indexmap::map::IndexMap;
 use indexmap::set::IndexSet;
 use super::iso::SetSType;
 use super::iso::SetSKey;
 use petgraph::Graph;
 use petgraph::graph::NodeIndex;
+use petgraph::Incoming;
 
-use serde::{Deserialize, Serialize};
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize};
 
-#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Clone, Hash)]
+// The version of the format of set_s.  Version 1 was a single S set of (G, H)
+// pairs, version 2 holds one S set per target graph, of (H, G) pairs.
+pub const SET_S_VERSION: u32 = 2;
+
+// the same node indices mean different nodes in the other versions, so those
+// headers are rejected rather than misread
+fn check_set_s_version<'de, D>(deserializer: D) -> Result<u32, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let version = u32::deserialize(deserializer)?;
+    if version != SET_S_VERSION {
+        return Err(D::Error::custom(format!(
+            "set_s is in version {} of its format, but this filter reads version {}",
+            version, SET_S_VERSION
+        )));
+    }
+    Ok(version)
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, Hash)]
 pub struct Property {
     pub entity: String,
     pub property_name: u64,
-    pub value: String,
+    pub value: Value,
 }
 
 impl Property {
@@ -20,11 +43,11 @@ impl Property {
         Property {
             entity: String::new(),
             property_name: 0,
-            value: String::new(),
+            value: Value::Str(String::new()),
         }
     }
 
-    pub fn new(entity: String, property_name: u64, value: String) -> Property {
+    pub fn new(entity: String, property_name: u64, value: Value) -> Property {
         Property {
             entity,
             property_name,
@@ -35,27 +58,39 @@ impl Property {
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct FerriedData {
-    pub set_s: Option<SetSType>,
+    // data written by filters of older builds has no version, so it fails to
+    // parse, as does data of any other version
+    #[serde(deserialize_with = "check_set_s_version")]
+    pub set_s_version: u32,
+    // one S set per target graph, in the order create_target_graphs returns them
+    pub set_s: Option<Vec<SetSType>>,
     pub found_match: bool,
-    pub trace_graph: Graph<(String, IndexMap<u64, String>), ()>,
+    pub trace_graph: Graph<(String, IndexMap<u64, Value>), IndexMap<u64, Value>>,
     pub unassigned_properties: IndexSet<Property>, // entity property value
+    // properties of calls, collected by the caller; the entity is the callee
+    #[serde(default)]
+    pub unassigned_edge_properties: IndexSet<Property>,
 }
 
 impl FerriedData {
     pub fn default() -> FerriedData {
         FerriedData {
+            set_s_version: SET_S_VERSION,
             set_s: None,
             found_match: false,
             trace_graph: Graph::new(),
             unassigned_properties: IndexSet::new(),
+            unassigned_edge_properties: IndexSet::new(),
         }
     }
     pub fn default_distributed() -> FerriedData {
         FerriedData {
-            set_s: Some(IndexMap::new()),
+            set_s_version: SET_S_VERSION,
+            set_s: Some(Vec::new()),
             found_match: false,
             trace_graph: Graph::new(),
             unassigned_properties: IndexSet::new(),
+            unassigned_edge_properties: IndexSet::new(),
         }
     }
 
@@ -72,6 +107,37 @@ impl FerriedData {
             }
         }
         remove_assigned_properties(&mut self.unassigned_properties, &self.trace_graph);
+        self.assign_edge_properties();
+    }
+
+    // a call is the incoming edge of the callee, which only exists once the
+    // caller has added itself to the graph
+    fn assign_edge_properties(&mut self) {
+        let trace_graph = &mut self.trace_graph;
+        self.unassigned_edge_properties.retain(|property| {
+            let callee = match graph_utils::get_node_with_id(trace_graph, &property.entity) {
+                Some(callee) => callee,
+                None => return true,
+            };
+            let caller = match trace_graph.neighbors_directed(callee, Incoming).next() {
+                Some(caller) => caller,
+                None => return true,
+            };
+            let call = trace_graph.find_edge(caller, callee).unwrap();
+            trace_graph
+                .edge_weight_mut(call)
+                .unwrap()
+                .insert(property.property_name, property.value.clone());
+            false
+        });
+    }
+
+    // the name of the node that has not been called by any other node yet
+    pub fn root_name(&self) -> Option<String> {
+        self.trace_graph
+            .node_indices()
+            .find(|node| self.trace_graph.neighbors_directed(*node, Incoming).count() == 0)
+            .map(|node| self.trace_graph.node_weight(node).unwrap().0.clone())
     }
 
     pub fn merge(&mut self, mut other_data: FerriedData) {
@@ -95,8 +161,11 @@ impl FerriedData {
                     let edge1_in_stored_graph =
                         graph_utils::get_node_with_id(&self.trace_graph, edge1_weight)
                             .unwrap();
-                    self.trace_graph
-                        .add_edge(edge0_in_stored_graph, edge1_in_stored_graph, ());
+                    self.trace_graph.add_edge(
+                        edge0_in_stored_graph,
+                        edge1_in_stored_graph,
+                        other_data.trace_graph.edge_weight(edge).unwrap().clone(),
+                    );
                 }
                 None => {
                     log::error!("no edge endpoints found \n");
@@ -123,15 +192,22 @@ impl FerriedData {
                 self.unassigned_properties.insert(property);
             }
         }
+        self.unassigned_edge_properties.extend(other_data.unassigned_edge_properties);
+        self.assign_edge_properties();
         if self.set_s.is_some() && other_data.set_s.is_some() {
             if self.found_match || other_data.found_match {                         
                 self.found_match = true;                                            
-                self.set_s = Some(IndexMap::new()); // don't carry around all that baggage
+                self.set_s = Some(Vec::new()); // don't carry around all that baggage
                 // esp when you've already sent to storage                          
             } else {                                                                
-                merge_set_s(self.set_s.as_mut().unwrap(),
-                            other_data.set_s.as_mut().unwrap(),
-                            &prev_nodes_to_new_nodes);
+                let my_set_s = self.set_s.as_mut().unwrap();
+                let their_set_s = other_data.set_s.as_mut().unwrap();
+                if my_set_s.len() < their_set_s.len() {
+                    my_set_s.resize_with(their_set_s.len(), IndexMap::new);
+                }
+                for (mine, theirs) in my_set_s.iter_mut().zip(their_set_s.iter_mut()) {
+                    merge_set_s(mine, theirs, &prev_nodes_to_new_nodes);
+                }
             }          
 
         }
@@ -140,7 +216,7 @@ impl FerriedData {
 
 
 pub fn remove_assigned_properties(unassigned_properties: &mut IndexSet<Property>,
-                                  graph: &Graph<(String, IndexMap<u64, String>), ()>) {
+                                  graph: &Graph<(String, IndexMap<u64, Value>), IndexMap<u64, Value>>) {
     unassigned_properties.retain(|x|
         graph_utils::get_node_with_id(graph, &x.entity) == None
     );
@@ -176,3 +252,28 @@ pub fn merge_set_s(my_set_s: &mut SetSType,
         my_set_s.insert(new_entry, new_indexmap);                     
     }                                                                   
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_s_version() {
+        let mut data = FerriedData::default_distributed();
+        data.set_s.as_mut().unwrap().push(IndexMap::new());
+        let text = serde_json::to_string(&data).unwrap();
+        let parsed: FerriedData = serde_json::from_str(&text).unwrap();
+        assert_eq!(parsed.set_s, data.set_s);
+
+        // headers without a version are from before set_s held one set per target graph
+        let mut old: serde_json::Value = serde_json::from_str(&text).unwrap();
+        old.as_object_mut().unwrap().remove("set_s_version");
+        old["set_s"] = serde_json::json!({});
+        assert!(serde_json::from_value::<FerriedData>(old).is_err());
+
+        let mut newer: serde_json::Value = serde_json::from_str(&text).unwrap();
+        newer["set_s_version"] = serde_json::json!(SET_S_VERSION + 1);
+        let err = serde_json::from_value::<FerriedData>(newer).unwrap_err();
+        assert!(err.to_string().contains("set_s is in version 3 of its format"));
+    }
+}
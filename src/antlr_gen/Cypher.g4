@@ -260,8 +260,9 @@ oC_NodeLabel
 oC_RangeLiteral
             :  '*' SP? ( oC_IntegerLiteral SP? )? ( '..' SP? ( oC_IntegerLiteral SP? )? )? ;
 
+// Labels name workloads, so allow dashes as in (a:productpage-v1)
 oC_LabelName
-         :  oC_SchemaName ;
+         :  oC_SchemaName ( oC_Dash ( oC_SchemaName | DecimalInteger ) )* ;
 
 oC_RelTypeName
            :  oC_SchemaName ;
@@ -316,7 +317,7 @@ oC_ListOperatorExpression
 IN : ( 'I' | 'i' ) ( 'N' | 'n' )  ;
 
 oC_StringOperatorExpression
-                        :  ( ( SP STARTS SP WITH ) | ( SP ENDS SP WITH ) | ( SP CONTAINS ) ) SP? oC_PropertyOrLabelsExpression ;
+                        :  ( ( SP STARTS SP WITH ) | ( SP ENDS SP WITH ) | ( SP CONTAINS ) | ( SP? '=~' ) ) SP? oC_PropertyOrLabelsExpression ;
 
 STARTS : ( 'S' | 's' ) ( 'T' | 't' ) ( 'A' | 'a' ) ( 'R' | 'r' ) ( 'T' | 't' ) ( 'S' | 's' )  ;
 
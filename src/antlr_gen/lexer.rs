@@ -73,93 +73,94 @@ pub const T__41: isize = 42;
 pub const T__42: isize = 43;
 pub const T__43: isize = 44;
 pub const T__44: isize = 45;
-pub const UNION: isize = 46;
-pub const ALL: isize = 47;
-pub const OPTIONAL: isize = 48;
-pub const MATCH: isize = 49;
-pub const UNWIND: isize = 50;
-pub const AS: isize = 51;
-pub const MERGE: isize = 52;
-pub const ON: isize = 53;
-pub const CREATE: isize = 54;
-pub const SET: isize = 55;
-pub const DETACH: isize = 56;
-pub const DELETE: isize = 57;
-pub const REMOVE: isize = 58;
-pub const CALL: isize = 59;
-pub const YIELD: isize = 60;
-pub const WITH: isize = 61;
-pub const RETURN: isize = 62;
-pub const DISTINCT: isize = 63;
-pub const ORDER: isize = 64;
-pub const BY: isize = 65;
-pub const L_SKIP: isize = 66;
-pub const LIMIT: isize = 67;
-pub const ASCENDING: isize = 68;
-pub const ASC: isize = 69;
-pub const DESCENDING: isize = 70;
-pub const DESC: isize = 71;
-pub const WHERE: isize = 72;
-pub const OR: isize = 73;
-pub const XOR: isize = 74;
-pub const AND: isize = 75;
-pub const NOT: isize = 76;
-pub const IN: isize = 77;
-pub const STARTS: isize = 78;
-pub const ENDS: isize = 79;
-pub const CONTAINS: isize = 80;
-pub const IS: isize = 81;
-pub const NULL: isize = 82;
-pub const COUNT: isize = 83;
-pub const ANY: isize = 84;
-pub const NONE: isize = 85;
-pub const SINGLE: isize = 86;
-pub const TRUE: isize = 87;
-pub const FALSE: isize = 88;
-pub const EXISTS: isize = 89;
-pub const CASE: isize = 90;
-pub const ELSE: isize = 91;
-pub const END: isize = 92;
-pub const WHEN: isize = 93;
-pub const THEN: isize = 94;
-pub const StringLiteral: isize = 95;
-pub const EscapedChar: isize = 96;
-pub const HexInteger: isize = 97;
-pub const DecimalInteger: isize = 98;
-pub const OctalInteger: isize = 99;
-pub const HexLetter: isize = 100;
-pub const HexDigit: isize = 101;
-pub const Digit: isize = 102;
-pub const NonZeroDigit: isize = 103;
-pub const NonZeroOctDigit: isize = 104;
-pub const OctDigit: isize = 105;
-pub const ZeroDigit: isize = 106;
-pub const ExponentDecimalReal: isize = 107;
-pub const RegularDecimalReal: isize = 108;
-pub const CONSTRAINT: isize = 109;
-pub const DO: isize = 110;
-pub const FOR: isize = 111;
-pub const REQUIRE: isize = 112;
-pub const UNIQUE: isize = 113;
-pub const MANDATORY: isize = 114;
-pub const SCALAR: isize = 115;
-pub const OF: isize = 116;
-pub const ADD: isize = 117;
-pub const DROP: isize = 118;
-pub const FILTER: isize = 119;
-pub const EXTRACT: isize = 120;
-pub const UnescapedSymbolicName: isize = 121;
-pub const IdentifierStart: isize = 122;
-pub const IdentifierPart: isize = 123;
-pub const EscapedSymbolicName: isize = 124;
-pub const SP: isize = 125;
-pub const WHITESPACE: isize = 126;
-pub const Comment: isize = 127;
+pub const T__45: isize = 46;
+pub const UNION: isize = 47;
+pub const ALL: isize = 48;
+pub const OPTIONAL: isize = 49;
+pub const MATCH: isize = 50;
+pub const UNWIND: isize = 51;
+pub const AS: isize = 52;
+pub const MERGE: isize = 53;
+pub const ON: isize = 54;
+pub const CREATE: isize = 55;
+pub const SET: isize = 56;
+pub const DETACH: isize = 57;
+pub const DELETE: isize = 58;
+pub const REMOVE: isize = 59;
+pub const CALL: isize = 60;
+pub const YIELD: isize = 61;
+pub const WITH: isize = 62;
+pub const RETURN: isize = 63;
+pub const DISTINCT: isize = 64;
+pub const ORDER: isize = 65;
+pub const BY: isize = 66;
+pub const L_SKIP: isize = 67;
+pub const LIMIT: isize = 68;
+pub const ASCENDING: isize = 69;
+pub const ASC: isize = 70;
+pub const DESCENDING: isize = 71;
+pub const DESC: isize = 72;
+pub const WHERE: isize = 73;
+pub const OR: isize = 74;
+pub const XOR: isize = 75;
+pub const AND: isize = 76;
+pub const NOT: isize = 77;
+pub const IN: isize = 78;
+pub const STARTS: isize = 79;
+pub const ENDS: isize = 80;
+pub const CONTAINS: isize = 81;
+pub const IS: isize = 82;
+pub const NULL: isize = 83;
+pub const COUNT: isize = 84;
+pub const ANY: isize = 85;
+pub const NONE: isize = 86;
+pub const SINGLE: isize = 87;
+pub const TRUE: isize = 88;
+pub const FALSE: isize = 89;
+pub const EXISTS: isize = 90;
+pub const CASE: isize = 91;
+pub const ELSE: isize = 92;
+pub const END: isize = 93;
+pub const WHEN: isize = 94;
+pub const THEN: isize = 95;
+pub const StringLiteral: isize = 96;
+pub const EscapedChar: isize = 97;
+pub const HexInteger: isize = 98;
+pub const DecimalInteger: isize = 99;
+pub const OctalInteger: isize = 100;
+pub const HexLetter: isize = 101;
+pub const HexDigit: isize = 102;
+pub const Digit: isize = 103;
+pub const NonZeroDigit: isize = 104;
+pub const NonZeroOctDigit: isize = 105;
+pub const OctDigit: isize = 106;
+pub const ZeroDigit: isize = 107;
+pub const ExponentDecimalReal: isize = 108;
+pub const RegularDecimalReal: isize = 109;
+pub const CONSTRAINT: isize = 110;
+pub const DO: isize = 111;
+pub const FOR: isize = 112;
+pub const REQUIRE: isize = 113;
+pub const UNIQUE: isize = 114;
+pub const MANDATORY: isize = 115;
+pub const SCALAR: isize = 116;
+pub const OF: isize = 117;
+pub const ADD: isize = 118;
+pub const DROP: isize = 119;
+pub const FILTER: isize = 120;
+pub const EXTRACT: isize = 121;
+pub const UnescapedSymbolicName: isize = 122;
+pub const IdentifierStart: isize = 123;
+pub const IdentifierPart: isize = 124;
+pub const EscapedSymbolicName: isize = 125;
+pub const SP: isize = 126;
+pub const WHITESPACE: isize = 127;
+pub const Comment: isize = 128;
 pub const channelNames: [&'static str; 0 + 2] = ["DEFAULT_TOKEN_CHANNEL", "HIDDEN"];
 
 pub const modeNames: [&'static str; 1] = ["DEFAULT_MODE"];
 
-pub const ruleNames: [&'static str; 147] = [
+pub const ruleNames: [&'static str; 148] = [
     "T__0",
     "T__1",
     "T__2",
@@ -205,6 +206,7 @@ pub const ruleNames: [&'static str; 147] = [
     "T__42",
     "T__43",
     "T__44",
+    "T__45",
     "UNION",
     "ALL",
     "OPTIONAL",
@@ -309,7 +311,7 @@ pub const ruleNames: [&'static str; 147] = [
     "ID_Start",
 ];
 
-pub const _LITERAL_NAMES: [Option<&'static str>; 107] = [
+pub const _LITERAL_NAMES: [Option<&'static str>; 108] = [
     None,
     Some("';'"),
     Some("','"),
@@ -328,6 +330,7 @@ pub const _LITERAL_NAMES: [Option<&'static str>; 107] = [
     Some("'/'"),
     Some("'%'"),
     Some("'^'"),
+    Some("'=~'"),
     Some("'<>'"),
     Some("'<'"),
     Some("'>'"),
@@ -418,7 +421,8 @@ pub const _LITERAL_NAMES: [Option<&'static str>; 107] = [
     None,
     Some("'0'"),
 ];
-pub const _SYMBOLIC_NAMES: [Option<&'static str>; 128] = [
+pub const _SYMBOLIC_NAMES: [Option<&'static str>; 129] = [
+    None,
     None,
     None,
     None,
@@ -692,7 +696,7 @@ lazy_static! {
 
 const _serializedATN: &'static str =
     "\x03\u{608b}\u{a72a}\u{8133}\u{b9ed}\u{417c}\u{3be7}\u{7786}\u{5964}\x02\
-		\u{81}\u{3e1}\x08\x01\x04\x02\x09\x02\x04\x03\x09\x03\x04\x04\x09\x04\x04\
+		\u{82}\u{3e6}\x08\x01\x04\x02\x09\x02\x04\x03\x09\x03\x04\x04\x09\x04\x04\
 		\x05\x09\x05\x04\x06\x09\x06\x04\x07\x09\x07\x04\x08\x09\x08\x04\x09\x09\
 		\x09\x04\x0a\x09\x0a\x04\x0b\x09\x0b\x04\x0c\x09\x0c\x04\x0d\x09\x0d\x04\
 		\x0e\x09\x0e\x04\x0f\x09\x0f\x04\x10\x09\x10\x04\x11\x09\x11\x04\x12\x09\
@@ -726,518 +730,326 @@ const _serializedATN: &'static str =
 		\x09\u{89}\x04\u{8a}\x09\u{8a}\x04\u{8b}\x09\u{8b}\x04\u{8c}\x09\u{8c}\
 		\x04\u{8d}\x09\u{8d}\x04\u{8e}\x09\u{8e}\x04\u{8f}\x09\u{8f}\x04\u{90}\
 		\x09\u{90}\x04\u{91}\x09\u{91}\x04\u{92}\x09\u{92}\x04\u{93}\x09\u{93}\
-		\x04\u{94}\x09\u{94}\x03\x02\x03\x02\x03\x03\x03\x03\x03\x04\x03\x04\x03\
-		\x05\x03\x05\x03\x05\x03\x06\x03\x06\x03\x07\x03\x07\x03\x08\x03\x08\x03\
-		\x09\x03\x09\x03\x0a\x03\x0a\x03\x0b\x03\x0b\x03\x0c\x03\x0c\x03\x0d\x03\
-		\x0d\x03\x0d\x03\x0e\x03\x0e\x03\x0f\x03\x0f\x03\x10\x03\x10\x03\x11\x03\
-		\x11\x03\x12\x03\x12\x03\x13\x03\x13\x03\x13\x03\x14\x03\x14\x03\x15\x03\
-		\x15\x03\x16\x03\x16\x03\x16\x03\x17\x03\x17\x03\x17\x03\x18\x03\x18\x03\
-		\x19\x03\x19\x03\x1a\x03\x1a\x03\x1b\x03\x1b\x03\x1c\x03\x1c\x03\x1d\x03\
-		\x1d\x03\x1e\x03\x1e\x03\x1f\x03\x1f\x03\x20\x03\x20\x03\x21\x03\x21\x03\
-		\x22\x03\x22\x03\x23\x03\x23\x03\x24\x03\x24\x03\x25\x03\x25\x03\x26\x03\
-		\x26\x03\x27\x03\x27\x03\x28\x03\x28\x03\x29\x03\x29\x03\x2a\x03\x2a\x03\
-		\x2b\x03\x2b\x03\x2c\x03\x2c\x03\x2d\x03\x2d\x03\x2e\x03\x2e\x03\x2f\x03\
-		\x2f\x03\x2f\x03\x2f\x03\x2f\x03\x2f\x03\x30\x03\x30\x03\x30\x03\x30\x03\
-		\x31\x03\x31\x03\x31\x03\x31\x03\x31\x03\x31\x03\x31\x03\x31\x03\x31\x03\
-		\x32\x03\x32\x03\x32\x03\x32\x03\x32\x03\x32\x03\x33\x03\x33\x03\x33\x03\
-		\x33\x03\x33\x03\x33\x03\x33\x03\x34\x03\x34\x03\x34\x03\x35\x03\x35\x03\
-		\x35\x03\x35\x03\x35\x03\x35\x03\x36\x03\x36\x03\x36\x03\x37\x03\x37\x03\
-		\x37\x03\x37\x03\x37\x03\x37\x03\x37\x03\x38\x03\x38\x03\x38\x03\x38\x03\
-		\x39\x03\x39\x03\x39\x03\x39\x03\x39\x03\x39\x03\x39\x03\x3a\x03\x3a\x03\
-		\x3a\x03\x3a\x03\x3a\x03\x3a\x03\x3a\x03\x3b\x03\x3b\x03\x3b\x03\x3b\x03\
-		\x3b\x03\x3b\x03\x3b\x03\x3c\x03\x3c\x03\x3c\x03\x3c\x03\x3c\x03\x3d\x03\
-		\x3d\x03\x3d\x03\x3d\x03\x3d\x03\x3d\x03\x3e\x03\x3e\x03\x3e\x03\x3e\x03\
-		\x3e\x03\x3f\x03\x3f\x03\x3f\x03\x3f\x03\x3f\x03\x3f\x03\x3f\x03\x40\x03\
-		\x40\x03\x40\x03\x40\x03\x40\x03\x40\x03\x40\x03\x40\x03\x40\x03\x41\x03\
-		\x41\x03\x41\x03\x41\x03\x41\x03\x41\x03\x42\x03\x42\x03\x42\x03\x43\x03\
-		\x43\x03\x43\x03\x43\x03\x43\x03\x44\x03\x44\x03\x44\x03\x44\x03\x44\x03\
-		\x44\x03\x45\x03\x45\x03\x45\x03\x45\x03\x45\x03\x45\x03\x45\x03\x45\x03\
-		\x45\x03\x45\x03\x46\x03\x46\x03\x46\x03\x46\x03\x47\x03\x47\x03\x47\x03\
-		\x47\x03\x47\x03\x47\x03\x47\x03\x47\x03\x47\x03\x47\x03\x47\x03\x48\x03\
-		\x48\x03\x48\x03\x48\x03\x48\x03\x49\x03\x49\x03\x49\x03\x49\x03\x49\x03\
-		\x49\x03\x4a\x03\x4a\x03\x4a\x03\x4b\x03\x4b\x03\x4b\x03\x4b\x03\x4c\x03\
-		\x4c\x03\x4c\x03\x4c\x03\x4d\x03\x4d\x03\x4d\x03\x4d\x03\x4e\x03\x4e\x03\
-		\x4e\x03\x4f\x03\x4f\x03\x4f\x03\x4f\x03\x4f\x03\x4f\x03\x4f\x03\x50\x03\
-		\x50\x03\x50\x03\x50\x03\x50\x03\x51\x03\x51\x03\x51\x03\x51\x03\x51\x03\
-		\x51\x03\x51\x03\x51\x03\x51\x03\x52\x03\x52\x03\x52\x03\x53\x03\x53\x03\
-		\x53\x03\x53\x03\x53\x03\x54\x03\x54\x03\x54\x03\x54\x03\x54\x03\x54\x03\
-		\x55\x03\x55\x03\x55\x03\x55\x03\x56\x03\x56\x03\x56\x03\x56\x03\x56\x03\
-		\x57\x03\x57\x03\x57\x03\x57\x03\x57\x03\x57\x03\x57\x03\x58\x03\x58\x03\
-		\x58\x03\x58\x03\x58\x03\x59\x03\x59\x03\x59\x03\x59\x03\x59\x03\x59\x03\
-		\x5a\x03\x5a\x03\x5a\x03\x5a\x03\x5a\x03\x5a\x03\x5a\x03\x5b\x03\x5b\x03\
-		\x5b\x03\x5b\x03\x5b\x03\x5c\x03\x5c\x03\x5c\x03\x5c\x03\x5c\x03\x5d\x03\
-		\x5d\x03\x5d\x03\x5d\x03\x5e\x03\x5e\x03\x5e\x03\x5e\x03\x5e\x03\x5f\x03\
-		\x5f\x03\x5f\x03\x5f\x03\x5f\x03\x60\x03\x60\x03\x60\x07\x60\u{29f}\x0a\
-		\x60\x0c\x60\x0e\x60\u{2a2}\x0b\x60\x03\x60\x03\x60\x03\x60\x03\x60\x07\
-		\x60\u{2a8}\x0a\x60\x0c\x60\x0e\x60\u{2ab}\x0b\x60\x03\x60\x05\x60\u{2ae}\
-		\x0a\x60\x03\x61\x03\x61\x03\x61\x03\x61\x03\x61\x03\x61\x03\x61\x03\x61\
-		\x03\x61\x03\x61\x03\x61\x03\x61\x03\x61\x03\x61\x03\x61\x03\x61\x03\x61\
-		\x03\x61\x05\x61\u{2c2}\x0a\x61\x03\x62\x03\x62\x03\x62\x03\x62\x06\x62\
-		\u{2c8}\x0a\x62\x0d\x62\x0e\x62\u{2c9}\x03\x63\x03\x63\x03\x63\x07\x63\
-		\u{2cf}\x0a\x63\x0c\x63\x0e\x63\u{2d2}\x0b\x63\x05\x63\u{2d4}\x0a\x63\x03\
-		\x64\x03\x64\x06\x64\u{2d8}\x0a\x64\x0d\x64\x0e\x64\u{2d9}\x03\x65\x05\
-		\x65\u{2dd}\x0a\x65\x03\x66\x03\x66\x05\x66\u{2e1}\x0a\x66\x03\x67\x03\
-		\x67\x05\x67\u{2e5}\x0a\x67\x03\x68\x03\x68\x05\x68\u{2e9}\x0a\x68\x03\
-		\x69\x03\x69\x03\x6a\x03\x6a\x05\x6a\u{2ef}\x0a\x6a\x03\x6b\x03\x6b\x03\
-		\x6c\x06\x6c\u{2f4}\x0a\x6c\x0d\x6c\x0e\x6c\u{2f5}\x03\x6c\x06\x6c\u{2f9}\
-		\x0a\x6c\x0d\x6c\x0e\x6c\u{2fa}\x03\x6c\x03\x6c\x06\x6c\u{2ff}\x0a\x6c\
-		\x0d\x6c\x0e\x6c\u{300}\x03\x6c\x03\x6c\x06\x6c\u{305}\x0a\x6c\x0d\x6c\
-		\x0e\x6c\u{306}\x05\x6c\u{309}\x0a\x6c\x03\x6c\x03\x6c\x05\x6c\u{30d}\x0a\
-		\x6c\x03\x6c\x06\x6c\u{310}\x0a\x6c\x0d\x6c\x0e\x6c\u{311}\x03\x6d\x07\
-		\x6d\u{315}\x0a\x6d\x0c\x6d\x0e\x6d\u{318}\x0b\x6d\x03\x6d\x03\x6d\x06\
-		\x6d\u{31c}\x0a\x6d\x0d\x6d\x0e\x6d\u{31d}\x03\x6e\x03\x6e\x03\x6e\x03\
-		\x6e\x03\x6e\x03\x6e\x03\x6e\x03\x6e\x03\x6e\x03\x6e\x03\x6e\x03\x6f\x03\
-		\x6f\x03\x6f\x03\x70\x03\x70\x03\x70\x03\x70\x03\x71\x03\x71\x03\x71\x03\
-		\x71\x03\x71\x03\x71\x03\x71\x03\x71\x03\x72\x03\x72\x03\x72\x03\x72\x03\
-		\x72\x03\x72\x03\x72\x03\x73\x03\x73\x03\x73\x03\x73\x03\x73\x03\x73\x03\
-		\x73\x03\x73\x03\x73\x03\x73\x03\x74\x03\x74\x03\x74\x03\x74\x03\x74\x03\
-		\x74\x03\x74\x03\x75\x03\x75\x03\x75\x03\x76\x03\x76\x03\x76\x03\x76\x03\
-		\x77\x03\x77\x03\x77\x03\x77\x03\x77\x03\x78\x03\x78\x03\x78\x03\x78\x03\
-		\x78\x03\x78\x03\x78\x03\x79\x03\x79\x03\x79\x03\x79\x03\x79\x03\x79\x03\
-		\x79\x03\x79\x03\x7a\x03\x7a\x07\x7a\u{36f}\x0a\x7a\x0c\x7a\x0e\x7a\u{372}\
-		\x0b\x7a\x03\x7b\x03\x7b\x05\x7b\u{376}\x0a\x7b\x03\x7c\x03\x7c\x05\x7c\
-		\u{37a}\x0a\x7c\x03\x7d\x03\x7d\x07\x7d\u{37e}\x0a\x7d\x0c\x7d\x0e\x7d\
-		\u{381}\x0b\x7d\x03\x7d\x06\x7d\u{384}\x0a\x7d\x0d\x7d\x0e\x7d\u{385}\x03\
-		\x7e\x06\x7e\u{389}\x0a\x7e\x0d\x7e\x0e\x7e\u{38a}\x03\x7f\x03\x7f\x03\
-		\x7f\x03\x7f\x03\x7f\x03\x7f\x03\x7f\x03\x7f\x03\x7f\x03\x7f\x03\x7f\x03\
-		\x7f\x05\x7f\u{399}\x0a\x7f\x03\u{80}\x03\u{80}\x03\u{80}\x03\u{80}\x03\
-		\u{80}\x03\u{80}\x07\u{80}\u{3a1}\x0a\u{80}\x0c\u{80}\x0e\u{80}\u{3a4}\
-		\x0b\u{80}\x03\u{80}\x03\u{80}\x03\u{80}\x03\u{80}\x03\u{80}\x03\u{80}\
-		\x07\u{80}\u{3ac}\x0a\u{80}\x0c\u{80}\x0e\u{80}\u{3af}\x0b\u{80}\x03\u{80}\
-		\x05\u{80}\u{3b2}\x0a\u{80}\x03\u{80}\x03\u{80}\x05\u{80}\u{3b6}\x0a\u{80}\
-		\x05\u{80}\u{3b8}\x0a\u{80}\x03\u{81}\x03\u{81}\x03\u{82}\x03\u{82}\x03\
-		\u{83}\x03\u{83}\x03\u{84}\x03\u{84}\x03\u{85}\x03\u{85}\x03\u{86}\x03\
-		\u{86}\x03\u{87}\x03\u{87}\x03\u{88}\x03\u{88}\x03\u{89}\x03\u{89}\x03\
-		\u{8a}\x03\u{8a}\x03\u{8b}\x03\u{8b}\x03\u{8c}\x03\u{8c}\x03\u{8d}\x03\
-		\u{8d}\x03\u{8e}\x03\u{8e}\x03\u{8f}\x03\u{8f}\x03\u{90}\x03\u{90}\x03\
-		\u{91}\x03\u{91}\x03\u{92}\x03\u{92}\x03\u{93}\x03\u{93}\x03\u{94}\x03\
-		\u{94}\x02\x02\u{95}\x03\x03\x05\x04\x07\x05\x09\x06\x0b\x07\x0d\x08\x0f\
-		\x09\x11\x0a\x13\x0b\x15\x0c\x17\x0d\x19\x0e\x1b\x0f\x1d\x10\x1f\x11\x21\
-		\x12\x23\x13\x25\x14\x27\x15\x29\x16\x2b\x17\x2d\x18\x2f\x19\x31\x1a\x33\
-		\x1b\x35\x1c\x37\x1d\x39\x1e\x3b\x1f\x3d\x20\x3f\x21\x41\x22\x43\x23\x45\
-		\x24\x47\x25\x49\x26\x4b\x27\x4d\x28\x4f\x29\x51\x2a\x53\x2b\x55\x2c\x57\
-		\x2d\x59\x2e\x5b\x2f\x5d\x30\x5f\x31\x61\x32\x63\x33\x65\x34\x67\x35\x69\
-		\x36\x6b\x37\x6d\x38\x6f\x39\x71\x3a\x73\x3b\x75\x3c\x77\x3d\x79\x3e\x7b\
-		\x3f\x7d\x40\x7f\x41\u{81}\x42\u{83}\x43\u{85}\x44\u{87}\x45\u{89}\x46\
-		\u{8b}\x47\u{8d}\x48\u{8f}\x49\u{91}\x4a\u{93}\x4b\u{95}\x4c\u{97}\x4d\
-		\u{99}\x4e\u{9b}\x4f\u{9d}\x50\u{9f}\x51\u{a1}\x52\u{a3}\x53\u{a5}\x54\
-		\u{a7}\x55\u{a9}\x56\u{ab}\x57\u{ad}\x58\u{af}\x59\u{b1}\x5a\u{b3}\x5b\
-		\u{b5}\x5c\u{b7}\x5d\u{b9}\x5e\u{bb}\x5f\u{bd}\x60\u{bf}\x61\u{c1}\x62\
-		\u{c3}\x63\u{c5}\x64\u{c7}\x65\u{c9}\x66\u{cb}\x67\u{cd}\x68\u{cf}\x69\
-		\u{d1}\x6a\u{d3}\x6b\u{d5}\x6c\u{d7}\x6d\u{d9}\x6e\u{db}\x6f\u{dd}\x70\
-		\u{df}\x71\u{e1}\x72\u{e3}\x73\u{e5}\x74\u{e7}\x75\u{e9}\x76\u{eb}\x77\
-		\u{ed}\x78\u{ef}\x79\u{f1}\x7a\u{f3}\x7b\u{f5}\x7c\u{f7}\x7d\u{f9}\x7e\
-		\u{fb}\x7f\u{fd}\u{80}\u{ff}\u{81}\u{101}\x02\u{103}\x02\u{105}\x02\u{107}\
-		\x02\u{109}\x02\u{10b}\x02\u{10d}\x02\u{10f}\x02\u{111}\x02\u{113}\x02\
-		\u{115}\x02\u{117}\x02\u{119}\x02\u{11b}\x02\u{11d}\x02\u{11f}\x02\u{121}\
-		\x02\u{123}\x02\u{125}\x02\u{127}\x02\x03\x02\x2f\x04\x02\x57\x57\x77\x77\
-		\x04\x02\x50\x50\x70\x70\x04\x02\x4b\x4b\x6b\x6b\x04\x02\x51\x51\x71\x71\
-		\x04\x02\x43\x43\x63\x63\x04\x02\x4e\x4e\x6e\x6e\x04\x02\x52\x52\x72\x72\
-		\x04\x02\x56\x56\x76\x76\x04\x02\x4f\x4f\x6f\x6f\x04\x02\x45\x45\x65\x65\
-		\x04\x02\x4a\x4a\x6a\x6a\x04\x02\x59\x59\x79\x79\x04\x02\x46\x46\x66\x66\
-		\x04\x02\x55\x55\x75\x75\x04\x02\x47\x47\x67\x67\x04\x02\x54\x54\x74\x74\
-		\x04\x02\x49\x49\x69\x69\x04\x02\x58\x58\x78\x78\x04\x02\x5b\x5b\x7b\x7b\
-		\x04\x02\x44\x44\x64\x64\x04\x02\x4d\x4d\x6d\x6d\x04\x02\x5a\x5a\x7a\x7a\
-		\x04\x02\x48\x48\x68\x68\x0f\x02\x24\x24\x29\x29\x44\x44\x48\x48\x50\x50\
-		\x54\x54\x56\x56\x5e\x5e\x64\x64\x68\x68\x70\x70\x74\x74\x76\x76\x04\x02\
-		\x43\x48\x63\x68\x04\x02\x53\x53\x73\x73\x0a\x02\u{a2}\u{a2}\u{1682}\u{1682}\
-		\u{1810}\u{1810}\u{2002}\u{200c}\u{202a}\u{202b}\u{2031}\u{2031}\u{2061}\
-		\u{2061}\u{3002}\u{3002}\x03\x02\x0e\x0e\x03\x02\x62\x62\x03\x02\x20\x20\
-		\x03\x02\x2c\x2c\x04\x02\x29\x29\x5e\x5e\x04\x02\x0c\x0c\x0f\x0f\x03\x02\
-		\x31\x31\x03\x02\x1f\x1f\x03\x02\x1e\x1e\x03\x02\x0f\x0f\x13\x02\x26\x26\
-		\u{a4}\u{a7}\u{591}\u{591}\u{60d}\u{60d}\u{9f4}\u{9f5}\u{9fd}\u{9fd}\u{af3}\
-		\u{af3}\u{bfb}\u{bfb}\u{e41}\u{e41}\u{17dd}\u{17dd}\u{20a2}\u{20c1}\u{a83a}\
-		\u{a83a}\u{fdfe}\u{fdfe}\u{fe6b}\u{fe6b}\u{ff06}\u{ff06}\u{ffe2}\u{ffe3}\
-		\u{ffe7}\u{ffe8}\x03\x02\x22\x22\x08\x02\x61\x61\u{2041}\u{2042}\u{2056}\
-		\u{2056}\u{fe35}\u{fe36}\u{fe4f}\u{fe51}\u{ff41}\u{ff41}\x03\x02\x0b\x0b\
-		\x04\x02\x24\x24\x5e\x5e\x03\x02\x0c\x0c\x03\x02\x0d\x0d\x03\x02\x21\x21\
-		\x04\u{2b3}\x02\x32\x02\x3b\x02\x43\x02\x5c\x02\x61\x02\x61\x02\x63\x02\
-		\x7c\x02\u{ac}\x02\u{ac}\x02\u{b7}\x02\u{b7}\x02\u{b9}\x02\u{b9}\x02\u{bc}\
-		\x02\u{bc}\x02\u{c2}\x02\u{d8}\x02\u{da}\x02\u{f8}\x02\u{fa}\x02\u{2c3}\
-		\x02\u{2c8}\x02\u{2d3}\x02\u{2e2}\x02\u{2e6}\x02\u{2ee}\x02\u{2ee}\x02\
-		\u{2f0}\x02\u{2f0}\x02\u{302}\x02\u{376}\x02\u{378}\x02\u{379}\x02\u{37c}\
-		\x02\u{37f}\x02\u{381}\x02\u{381}\x02\u{388}\x02\u{38c}\x02\u{38e}\x02\
-		\u{38e}\x02\u{390}\x02\u{3a3}\x02\u{3a5}\x02\u{3f7}\x02\u{3f9}\x02\u{483}\
-		\x02\u{485}\x02\u{489}\x02\u{48c}\x02\u{531}\x02\u{533}\x02\u{558}\x02\
-		\u{55b}\x02\u{55b}\x02\u{563}\x02\u{589}\x02\u{593}\x02\u{5bf}\x02\u{5c1}\
-		\x02\u{5c1}\x02\u{5c3}\x02\u{5c4}\x02\u{5c6}\x02\u{5c7}\x02\u{5c9}\x02\
-		\u{5c9}\x02\u{5d2}\x02\u{5ec}\x02\u{5f2}\x02\u{5f4}\x02\u{612}\x02\u{61c}\
-		\x02\u{622}\x02\u{66b}\x02\u{670}\x02\u{6d5}\x02\u{6d7}\x02\u{6de}\x02\
-		\u{6e1}\x02\u{6ea}\x02\u{6ec}\x02\u{6fe}\x02\u{701}\x02\u{701}\x02\u{712}\
-		\x02\u{74c}\x02\u{74f}\x02\u{7b3}\x02\u{7c2}\x02\u{7f7}\x02\u{7fc}\x02\
-		\u{7fc}\x02\u{802}\x02\u{82f}\x02\u{842}\x02\u{85d}\x02\u{862}\x02\u{86c}\
-		\x02\u{8a2}\x02\u{8b6}\x02\u{8b8}\x02\u{8bf}\x02\u{8d6}\x02\u{8e3}\x02\
-		\u{8e5}\x02\u{965}\x02\u{968}\x02\u{971}\x02\u{973}\x02\u{985}\x02\u{987}\
-		\x02\u{98e}\x02\u{991}\x02\u{992}\x02\u{995}\x02\u{9aa}\x02\u{9ac}\x02\
-		\u{9b2}\x02\u{9b4}\x02\u{9b4}\x02\u{9b8}\x02\u{9bb}\x02\u{9be}\x02\u{9c6}\
-		\x02\u{9c9}\x02\u{9ca}\x02\u{9cd}\x02\u{9d0}\x02\u{9d9}\x02\u{9d9}\x02\
-		\u{9de}\x02\u{9df}\x02\u{9e1}\x02\u{9e5}\x02\u{9e8}\x02\u{9f3}\x02\u{9fe}\
-		\x02\u{9fe}\x02\u{a03}\x02\u{a05}\x02\u{a07}\x02\u{a0c}\x02\u{a11}\x02\
-		\u{a12}\x02\u{a15}\x02\u{a2a}\x02\u{a2c}\x02\u{a32}\x02\u{a34}\x02\u{a35}\
-		\x02\u{a37}\x02\u{a38}\x02\u{a3a}\x02\u{a3b}\x02\u{a3e}\x02\u{a3e}\x02\
-		\u{a40}\x02\u{a44}\x02\u{a49}\x02\u{a4a}\x02\u{a4d}\x02\u{a4f}\x02\u{a53}\
-		\x02\u{a53}\x02\u{a5b}\x02\u{a5e}\x02\u{a60}\x02\u{a60}\x02\u{a68}\x02\
-		\u{a77}\x02\u{a83}\x02\u{a85}\x02\u{a87}\x02\u{a8f}\x02\u{a91}\x02\u{a93}\
-		\x02\u{a95}\x02\u{aaa}\x02\u{aac}\x02\u{ab2}\x02\u{ab4}\x02\u{ab5}\x02\
-		\u{ab7}\x02\u{abb}\x02\u{abe}\x02\u{ac7}\x02\u{ac9}\x02\u{acb}\x02\u{acd}\
-		\x02\u{acf}\x02\u{ad2}\x02\u{ad2}\x02\u{ae2}\x02\u{ae5}\x02\u{ae8}\x02\
-		\u{af1}\x02\u{afb}\x02\u{b01}\x02\u{b03}\x02\u{b05}\x02\u{b07}\x02\u{b0e}\
-		\x02\u{b11}\x02\u{b12}\x02\u{b15}\x02\u{b2a}\x02\u{b2c}\x02\u{b32}\x02\
-		\u{b34}\x02\u{b35}\x02\u{b37}\x02\u{b3b}\x02\u{b3e}\x02\u{b46}\x02\u{b49}\
-		\x02\u{b4a}\x02\u{b4d}\x02\u{b4f}\x02\u{b58}\x02\u{b59}\x02\u{b5e}\x02\
-		\u{b5f}\x02\u{b61}\x02\u{b65}\x02\u{b68}\x02\u{b71}\x02\u{b73}\x02\u{b73}\
-		\x02\u{b84}\x02\u{b85}\x02\u{b87}\x02\u{b8c}\x02\u{b90}\x02\u{b92}\x02\
-		\u{b94}\x02\u{b97}\x02\u{b9b}\x02\u{b9c}\x02\u{b9e}\x02\u{b9e}\x02\u{ba0}\
-		\x02\u{ba1}\x02\u{ba5}\x02\u{ba6}\x02\u{baa}\x02\u{bac}\x02\u{bb0}\x02\
-		\u{bbb}\x02\u{bc0}\x02\u{bc4}\x02\u{bc8}\x02\u{bca}\x02\u{bcc}\x02\u{bcf}\
-		\x02\u{bd2}\x02\u{bd2}\x02\u{bd9}\x02\u{bd9}\x02\u{be8}\x02\u{bf1}\x02\
-		\u{c02}\x02\u{c05}\x02\u{c07}\x02\u{c0e}\x02\u{c10}\x02\u{c12}\x02\u{c14}\
-		\x02\u{c2a}\x02\u{c2c}\x02\u{c3b}\x02\u{c3f}\x02\u{c46}\x02\u{c48}\x02\
-		\u{c4a}\x02\u{c4c}\x02\u{c4f}\x02\u{c57}\x02\u{c58}\x02\u{c5a}\x02\u{c5c}\
-		\x02\u{c62}\x02\u{c65}\x02\u{c68}\x02\u{c71}\x02\u{c82}\x02\u{c85}\x02\
-		\u{c87}\x02\u{c8e}\x02\u{c90}\x02\u{c92}\x02\u{c94}\x02\u{caa}\x02\u{cac}\
-		\x02\u{cb5}\x02\u{cb7}\x02\u{cbb}\x02\u{cbe}\x02\u{cc6}\x02\u{cc8}\x02\
-		\u{cca}\x02\u{ccc}\x02\u{ccf}\x02\u{cd7}\x02\u{cd8}\x02\u{ce0}\x02\u{ce0}\
-		\x02\u{ce2}\x02\u{ce5}\x02\u{ce8}\x02\u{cf1}\x02\u{cf3}\x02\u{cf4}\x02\
-		\u{d02}\x02\u{d05}\x02\u{d07}\x02\u{d0e}\x02\u{d10}\x02\u{d12}\x02\u{d14}\
-		\x02\u{d46}\x02\u{d48}\x02\u{d4a}\x02\u{d4c}\x02\u{d50}\x02\u{d56}\x02\
-		\u{d59}\x02\u{d61}\x02\u{d65}\x02\u{d68}\x02\u{d71}\x02\u{d7c}\x02\u{d81}\
-		\x02\u{d84}\x02\u{d85}\x02\u{d87}\x02\u{d98}\x02\u{d9c}\x02\u{db3}\x02\
-		\u{db5}\x02\u{dbd}\x02\u{dbf}\x02\u{dbf}\x02\u{dc2}\x02\u{dc8}\x02\u{dcc}\
-		\x02\u{dcc}\x02\u{dd1}\x02\u{dd6}\x02\u{dd8}\x02\u{dd8}\x02\u{dda}\x02\
-		\u{de1}\x02\u{de8}\x02\u{df1}\x02\u{df4}\x02\u{df5}\x02\u{e03}\x02\u{e3c}\
-		\x02\u{e42}\x02\u{e50}\x02\u{e52}\x02\u{e5b}\x02\u{e83}\x02\u{e84}\x02\
-		\u{e86}\x02\u{e86}\x02\u{e89}\x02\u{e8a}\x02\u{e8c}\x02\u{e8c}\x02\u{e8f}\
-		\x02\u{e8f}\x02\u{e96}\x02\u{e99}\x02\u{e9b}\x02\u{ea1}\x02\u{ea3}\x02\
-		\u{ea5}\x02\u{ea7}\x02\u{ea7}\x02\u{ea9}\x02\u{ea9}\x02\u{eac}\x02\u{ead}\
-		\x02\u{eaf}\x02\u{ebb}\x02\u{ebd}\x02\u{ebf}\x02\u{ec2}\x02\u{ec6}\x02\
-		\u{ec8}\x02\u{ec8}\x02\u{eca}\x02\u{ecf}\x02\u{ed2}\x02\u{edb}\x02\u{ede}\
-		\x02\u{ee1}\x02\u{f02}\x02\u{f02}\x02\u{f1a}\x02\u{f1b}\x02\u{f22}\x02\
-		\u{f2b}\x02\u{f37}\x02\u{f37}\x02\u{f39}\x02\u{f39}\x02\u{f3b}\x02\u{f3b}\
-		\x02\u{f40}\x02\u{f49}\x02\u{f4b}\x02\u{f6e}\x02\u{f73}\x02\u{f86}\x02\
-		\u{f88}\x02\u{f99}\x02\u{f9b}\x02\u{fbe}\x02\u{fc8}\x02\u{fc8}\x02\u{1002}\
-		\x02\u{104b}\x02\u{1052}\x02\u{109f}\x02\u{10a2}\x02\u{10c7}\x02\u{10c9}\
-		\x02\u{10c9}\x02\u{10cf}\x02\u{10cf}\x02\u{10d2}\x02\u{10fc}\x02\u{10fe}\
-		\x02\u{124a}\x02\u{124c}\x02\u{124f}\x02\u{1252}\x02\u{1258}\x02\u{125a}\
-		\x02\u{125a}\x02\u{125c}\x02\u{125f}\x02\u{1262}\x02\u{128a}\x02\u{128c}\
-		\x02\u{128f}\x02\u{1292}\x02\u{12b2}\x02\u{12b4}\x02\u{12b7}\x02\u{12ba}\
-		\x02\u{12c0}\x02\u{12c2}\x02\u{12c2}\x02\u{12c4}\x02\u{12c7}\x02\u{12ca}\
-		\x02\u{12d8}\x02\u{12da}\x02\u{1312}\x02\u{1314}\x02\u{1317}\x02\u{131a}\
-		\x02\u{135c}\x02\u{135f}\x02\u{1361}\x02\u{136b}\x02\u{1373}\x02\u{1382}\
-		\x02\u{1391}\x02\u{13a2}\x02\u{13f7}\x02\u{13fa}\x02\u{13ff}\x02\u{1403}\
-		\x02\u{166e}\x02\u{1671}\x02\u{1681}\x02\u{1683}\x02\u{169c}\x02\u{16a2}\
-		\x02\u{16ec}\x02\u{16f0}\x02\u{16fa}\x02\u{1702}\x02\u{170e}\x02\u{1710}\
-		\x02\u{1716}\x02\u{1722}\x02\u{1736}\x02\u{1742}\x02\u{1755}\x02\u{1762}\
-		\x02\u{176e}\x02\u{1770}\x02\u{1772}\x02\u{1774}\x02\u{1775}\x02\u{1782}\
-		\x02\u{17d5}\x02\u{17d9}\x02\u{17d9}\x02\u{17de}\x02\u{17df}\x02\u{17e2}\
-		\x02\u{17eb}\x02\u{180d}\x02\u{180f}\x02\u{1812}\x02\u{181b}\x02\u{1822}\
-		\x02\u{1879}\x02\u{1882}\x02\u{18ac}\x02\u{18b2}\x02\u{18f7}\x02\u{1902}\
-		\x02\u{1920}\x02\u{1922}\x02\u{192d}\x02\u{1932}\x02\u{193d}\x02\u{1948}\
-		\x02\u{196f}\x02\u{1972}\x02\u{1976}\x02\u{1982}\x02\u{19ad}\x02\u{19b2}\
-		\x02\u{19cb}\x02\u{19d2}\x02\u{19dc}\x02\u{1a02}\x02\u{1a1d}\x02\u{1a22}\
-		\x02\u{1a60}\x02\u{1a62}\x02\u{1a7e}\x02\u{1a81}\x02\u{1a8b}\x02\u{1a92}\
-		\x02\u{1a9b}\x02\u{1aa9}\x02\u{1aa9}\x02\u{1ab2}\x02\u{1abf}\x02\u{1b02}\
-		\x02\u{1b4d}\x02\u{1b52}\x02\u{1b5b}\x02\u{1b6d}\x02\u{1b75}\x02\u{1b82}\
-		\x02\u{1bf5}\x02\u{1c02}\x02\u{1c39}\x02\u{1c42}\x02\u{1c4b}\x02\u{1c4f}\
-		\x02\u{1c7f}\x02\u{1c82}\x02\u{1c8a}\x02\u{1cd2}\x02\u{1cd4}\x02\u{1cd6}\
-		\x02\u{1cfb}\x02\u{1d02}\x02\u{1dfb}\x02\u{1dfd}\x02\u{1f17}\x02\u{1f1a}\
-		\x02\u{1f1f}\x02\u{1f22}\x02\u{1f47}\x02\u{1f4a}\x02\u{1f4f}\x02\u{1f52}\
-		\x02\u{1f59}\x02\u{1f5b}\x02\u{1f5b}\x02\u{1f5d}\x02\u{1f5d}\x02\u{1f5f}\
-		\x02\u{1f5f}\x02\u{1f61}\x02\u{1f7f}\x02\u{1f82}\x02\u{1fb6}\x02\u{1fb8}\
-		\x02\u{1fbe}\x02\u{1fc0}\x02\u{1fc0}\x02\u{1fc4}\x02\u{1fc6}\x02\u{1fc8}\
-		\x02\u{1fce}\x02\u{1fd2}\x02\u{1fd5}\x02\u{1fd8}\x02\u{1fdd}\x02\u{1fe2}\
-		\x02\u{1fee}\x02\u{1ff4}\x02\u{1ff6}\x02\u{1ff8}\x02\u{1ffe}\x02\u{2041}\
-		\x02\u{2042}\x02\u{2056}\x02\u{2056}\x02\u{2073}\x02\u{2073}\x02\u{2081}\
-		\x02\u{2081}\x02\u{2092}\x02\u{209e}\x02\u{20d2}\x02\u{20de}\x02\u{20e3}\
-		\x02\u{20e3}\x02\u{20e7}\x02\u{20f2}\x02\u{2104}\x02\u{2104}\x02\u{2109}\
-		\x02\u{2109}\x02\u{210c}\x02\u{2115}\x02\u{2117}\x02\u{2117}\x02\u{211a}\
-		\x02\u{211f}\x02\u{2126}\x02\u{2126}\x02\u{2128}\x02\u{2128}\x02\u{212a}\
-		\x02\u{212a}\x02\u{212c}\x02\u{213b}\x02\u{213e}\x02\u{2141}\x02\u{2147}\
-		\x02\u{214b}\x02\u{2150}\x02\u{2150}\x02\u{2162}\x02\u{218a}\x02\u{2c02}\
-		\x02\u{2c30}\x02\u{2c32}\x02\u{2c60}\x02\u{2c62}\x02\u{2ce6}\x02\u{2ced}\
-		\x02\u{2cf5}\x02\u{2d02}\x02\u{2d27}\x02\u{2d29}\x02\u{2d29}\x02\u{2d2f}\
-		\x02\u{2d2f}\x02\u{2d32}\x02\u{2d69}\x02\u{2d71}\x02\u{2d71}\x02\u{2d81}\
-		\x02\u{2d98}\x02\u{2da2}\x02\u{2da8}\x02\u{2daa}\x02\u{2db0}\x02\u{2db2}\
-		\x02\u{2db8}\x02\u{2dba}\x02\u{2dc0}\x02\u{2dc2}\x02\u{2dc8}\x02\u{2dca}\
-		\x02\u{2dd0}\x02\u{2dd2}\x02\u{2dd8}\x02\u{2dda}\x02\u{2de0}\x02\u{2de2}\
-		\x02\u{2e01}\x02\u{3007}\x02\u{3009}\x02\u{3023}\x02\u{3031}\x02\u{3033}\
-		\x02\u{3037}\x02\u{303a}\x02\u{303e}\x02\u{3043}\x02\u{3098}\x02\u{309b}\
-		\x02\u{30a1}\x02\u{30a3}\x02\u{30fc}\x02\u{30fe}\x02\u{3101}\x02\u{3107}\
-		\x02\u{3130}\x02\u{3133}\x02\u{3190}\x02\u{31a2}\x02\u{31bc}\x02\u{31f2}\
-		\x02\u{3201}\x02\u{3402}\x02\u{4db7}\x02\u{4e02}\x02\u{9fec}\x02\u{a002}\
-		\x02\u{a48e}\x02\u{a4d2}\x02\u{a4ff}\x02\u{a502}\x02\u{a60e}\x02\u{a612}\
-		\x02\u{a62d}\x02\u{a642}\x02\u{a671}\x02\u{a676}\x02\u{a67f}\x02\u{a681}\
-		\x02\u{a6f3}\x02\u{a719}\x02\u{a721}\x02\u{a724}\x02\u{a78a}\x02\u{a78d}\
-		\x02\u{a7b0}\x02\u{a7b2}\x02\u{a7b9}\x02\u{a7f9}\x02\u{a829}\x02\u{a842}\
-		\x02\u{a875}\x02\u{a882}\x02\u{a8c7}\x02\u{a8d2}\x02\u{a8db}\x02\u{a8e2}\
-		\x02\u{a8f9}\x02\u{a8fd}\x02\u{a8fd}\x02\u{a8ff}\x02\u{a8ff}\x02\u{a902}\
-		\x02\u{a92f}\x02\u{a932}\x02\u{a955}\x02\u{a962}\x02\u{a97e}\x02\u{a982}\
-		\x02\u{a9c2}\x02\u{a9d1}\x02\u{a9db}\x02\u{a9e2}\x02\u{aa00}\x02\u{aa02}\
-		\x02\u{aa38}\x02\u{aa42}\x02\u{aa4f}\x02\u{aa52}\x02\u{aa5b}\x02\u{aa62}\
-		\x02\u{aa78}\x02\u{aa7c}\x02\u{aac4}\x02\u{aadd}\x02\u{aadf}\x02\u{aae2}\
-		\x02\u{aaf1}\x02\u{aaf4}\x02\u{aaf8}\x02\u{ab03}\x02\u{ab08}\x02\u{ab0b}\
-		\x02\u{ab10}\x02\u{ab13}\x02\u{ab18}\x02\u{ab22}\x02\u{ab28}\x02\u{ab2a}\
-		\x02\u{ab30}\x02\u{ab32}\x02\u{ab5c}\x02\u{ab5e}\x02\u{ab67}\x02\u{ab72}\
-		\x02\u{abec}\x02\u{abee}\x02\u{abef}\x02\u{abf2}\x02\u{abfb}\x02\u{ac02}\
-		\x02\u{d7a5}\x02\u{d7b2}\x02\u{d7c8}\x02\u{d7cd}\x02\u{d7fd}\x02\u{f902}\
-		\x02\u{fa6f}\x02\u{fa72}\x02\u{fadb}\x02\u{fb02}\x02\u{fb08}\x02\u{fb15}\
-		\x02\u{fb19}\x02\u{fb1f}\x02\u{fb2a}\x02\u{fb2c}\x02\u{fb38}\x02\u{fb3a}\
-		\x02\u{fb3e}\x02\u{fb40}\x02\u{fb40}\x02\u{fb42}\x02\u{fb43}\x02\u{fb45}\
-		\x02\u{fb46}\x02\u{fb48}\x02\u{fbb3}\x02\u{fbd5}\x02\u{fd3f}\x02\u{fd52}\
-		\x02\u{fd91}\x02\u{fd94}\x02\u{fdc9}\x02\u{fdf2}\x02\u{fdfd}\x02\u{fe02}\
-		\x02\u{fe11}\x02\u{fe22}\x02\u{fe31}\x02\u{fe35}\x02\u{fe36}\x02\u{fe4f}\
-		\x02\u{fe51}\x02\u{fe72}\x02\u{fe76}\x02\u{fe78}\x02\u{fefe}\x02\u{ff12}\
-		\x02\u{ff1b}\x02\u{ff23}\x02\u{ff3c}\x02\u{ff41}\x02\u{ff41}\x02\u{ff43}\
-		\x02\u{ff5c}\x02\u{ff68}\x02\u{ffc0}\x02\u{ffc4}\x02\u{ffc9}\x02\u{ffcc}\
-		\x02\u{ffd1}\x02\u{ffd4}\x02\u{ffd9}\x02\u{ffdc}\x02\u{ffde}\x02\x02\x03\
-		\x0d\x03\x0f\x03\x28\x03\x2a\x03\x3c\x03\x3e\x03\x3f\x03\x41\x03\x4f\x03\
-		\x52\x03\x5f\x03\u{82}\x03\u{fc}\x03\u{142}\x03\u{176}\x03\u{1ff}\x03\u{1ff}\
-		\x03\u{282}\x03\u{29e}\x03\u{2a2}\x03\u{2d2}\x03\u{2e2}\x03\u{2e2}\x03\
-		\u{302}\x03\u{321}\x03\u{32f}\x03\u{34c}\x03\u{352}\x03\u{37c}\x03\u{382}\
-		\x03\u{39f}\x03\u{3a2}\x03\u{3c5}\x03\u{3ca}\x03\u{3d1}\x03\u{3d3}\x03\
-		\u{3d7}\x03\u{402}\x03\u{49f}\x03\u{4a2}\x03\u{4ab}\x03\u{4b2}\x03\u{4d5}\
-		\x03\u{4da}\x03\u{4fd}\x03\u{502}\x03\u{529}\x03\u{532}\x03\u{565}\x03\
-		\u{602}\x03\u{738}\x03\u{742}\x03\u{757}\x03\u{762}\x03\u{769}\x03\u{802}\
-		\x03\u{807}\x03\u{80a}\x03\u{80a}\x03\u{80c}\x03\u{837}\x03\u{839}\x03\
-		\u{83a}\x03\u{83e}\x03\u{83e}\x03\u{841}\x03\u{857}\x03\u{862}\x03\u{878}\
-		\x03\u{882}\x03\u{8a0}\x03\u{8e2}\x03\u{8f4}\x03\u{8f6}\x03\u{8f7}\x03\
-		\u{902}\x03\u{917}\x03\u{922}\x03\u{93b}\x03\u{982}\x03\u{9b9}\x03\u{9c0}\
-		\x03\u{9c1}\x03\u{a02}\x03\u{a05}\x03\u{a07}\x03\u{a08}\x03\u{a0e}\x03\
-		\u{a15}\x03\u{a17}\x03\u{a19}\x03\u{a1b}\x03\u{a35}\x03\u{a3a}\x03\u{a3c}\
-		\x03\u{a41}\x03\u{a41}\x03\u{a62}\x03\u{a7e}\x03\u{a82}\x03\u{a9e}\x03\
-		\u{ac2}\x03\u{ac9}\x03\u{acb}\x03\u{ae8}\x03\u{b02}\x03\u{b37}\x03\u{b42}\
-		\x03\u{b57}\x03\u{b62}\x03\u{b74}\x03\u{b82}\x03\u{b93}\x03\u{c02}\x03\
-		\u{c4a}\x03\u{c82}\x03\u{cb4}\x03\u{cc2}\x03\u{cf4}\x03\u{1002}\x03\u{1048}\
-		\x03\u{1068}\x03\u{1071}\x03\u{1081}\x03\u{10bc}\x03\u{10d2}\x03\u{10ea}\
-		\x03\u{10f2}\x03\u{10fb}\x03\u{1102}\x03\u{1136}\x03\u{1138}\x03\u{1141}\
-		\x03\u{1152}\x03\u{1175}\x03\u{1178}\x03\u{1178}\x03\u{1182}\x03\u{11c6}\
-		\x03\u{11cc}\x03\u{11ce}\x03\u{11d2}\x03\u{11dc}\x03\u{11de}\x03\u{11de}\
-		\x03\u{1202}\x03\u{1213}\x03\u{1215}\x03\u{1239}\x03\u{1240}\x03\u{1240}\
-		\x03\u{1282}\x03\u{1288}\x03\u{128a}\x03\u{128a}\x03\u{128c}\x03\u{128f}\
-		\x03\u{1291}\x03\u{129f}\x03\u{12a1}\x03\u{12aa}\x03\u{12b2}\x03\u{12ec}\
-		\x03\u{12f2}\x03\u{12fb}\x03\u{1302}\x03\u{1305}\x03\u{1307}\x03\u{130e}\
-		\x03\u{1311}\x03\u{1312}\x03\u{1315}\x03\u{132a}\x03\u{132c}\x03\u{1332}\
-		\x03\u{1334}\x03\u{1335}\x03\u{1337}\x03\u{133b}\x03\u{133e}\x03\u{1346}\
-		\x03\u{1349}\x03\u{134a}\x03\u{134d}\x03\u{134f}\x03\u{1352}\x03\u{1352}\
-		\x03\u{1359}\x03\u{1359}\x03\u{135f}\x03\u{1365}\x03\u{1368}\x03\u{136e}\
-		\x03\u{1372}\x03\u{1376}\x03\u{1402}\x03\u{144c}\x03\u{1452}\x03\u{145b}\
-		\x03\u{1482}\x03\u{14c7}\x03\u{14c9}\x03\u{14c9}\x03\u{14d2}\x03\u{14db}\
-		\x03\u{1582}\x03\u{15b7}\x03\u{15ba}\x03\u{15c2}\x03\u{15da}\x03\u{15df}\
-		\x03\u{1602}\x03\u{1642}\x03\u{1646}\x03\u{1646}\x03\u{1652}\x03\u{165b}\
-		\x03\u{1682}\x03\u{16b9}\x03\u{16c2}\x03\u{16cb}\x03\u{1702}\x03\u{171b}\
-		\x03\u{171f}\x03\u{172d}\x03\u{1732}\x03\u{173b}\x03\u{18a2}\x03\u{18eb}\
-		\x03\u{1901}\x03\u{1901}\x03\u{1a02}\x03\u{1a40}\x03\u{1a49}\x03\u{1a49}\
-		\x03\u{1a52}\x03\u{1a85}\x03\u{1a88}\x03\u{1a9b}\x03\u{1ac2}\x03\u{1afa}\
-		\x03\u{1c02}\x03\u{1c0a}\x03\u{1c0c}\x03\u{1c38}\x03\u{1c3a}\x03\u{1c42}\
-		\x03\u{1c52}\x03\u{1c5b}\x03\u{1c74}\x03\u{1c91}\x03\u{1c94}\x03\u{1ca9}\
-		\x03\u{1cab}\x03\u{1cb8}\x03\u{1d02}\x03\u{1d08}\x03\u{1d0a}\x03\u{1d0b}\
-		\x03\u{1d0d}\x03\u{1d38}\x03\u{1d3c}\x03\u{1d3c}\x03\u{1d3e}\x03\u{1d3f}\
-		\x03\u{1d41}\x03\u{1d49}\x03\u{1d52}\x03\u{1d5b}\x03\u{2002}\x03\u{239b}\
-		\x03\u{2402}\x03\u{2470}\x03\u{2482}\x03\u{2545}\x03\u{3002}\x03\u{3430}\
-		\x03\u{4402}\x03\u{4648}\x03\u{6802}\x03\u{6a3a}\x03\u{6a42}\x03\u{6a60}\
-		\x03\u{6a62}\x03\u{6a6b}\x03\u{6ad2}\x03\u{6aef}\x03\u{6af2}\x03\u{6af6}\
-		\x03\u{6b02}\x03\u{6b38}\x03\u{6b42}\x03\u{6b45}\x03\u{6b52}\x03\u{6b5b}\
-		\x03\u{6b65}\x03\u{6b79}\x03\u{6b7f}\x03\u{6b91}\x03\u{6f02}\x03\u{6f46}\
-		\x03\u{6f52}\x03\u{6f80}\x03\u{6f91}\x03\u{6fa1}\x03\u{6fe2}\x03\u{6fe3}\
-		\x03\u{7002}\x03\u{87ee}\x03\u{8802}\x03\u{8af4}\x03\u{b002}\x03\u{b120}\
-		\x03\u{b172}\x03\u{b2fd}\x03\u{bc02}\x03\u{bc6c}\x03\u{bc72}\x03\u{bc7e}\
-		\x03\u{bc82}\x03\u{bc8a}\x03\u{bc92}\x03\u{bc9b}\x03\u{bc9f}\x03\u{bca0}\
-		\x03\u{d167}\x03\u{d16b}\x03\u{d16f}\x03\u{d174}\x03\u{d17d}\x03\u{d184}\
-		\x03\u{d187}\x03\u{d18d}\x03\u{d1ac}\x03\u{d1af}\x03\u{d244}\x03\u{d246}\
-		\x03\u{d402}\x03\u{d456}\x03\u{d458}\x03\u{d49e}\x03\u{d4a0}\x03\u{d4a1}\
-		\x03\u{d4a4}\x03\u{d4a4}\x03\u{d4a7}\x03\u{d4a8}\x03\u{d4ab}\x03\u{d4ae}\
-		\x03\u{d4b0}\x03\u{d4bb}\x03\u{d4bd}\x03\u{d4bd}\x03\u{d4bf}\x03\u{d4c5}\
-		\x03\u{d4c7}\x03\u{d507}\x03\u{d509}\x03\u{d50c}\x03\u{d50f}\x03\u{d516}\
-		\x03\u{d518}\x03\u{d51e}\x03\u{d520}\x03\u{d53b}\x03\u{d53d}\x03\u{d540}\
-		\x03\u{d542}\x03\u{d546}\x03\u{d548}\x03\u{d548}\x03\u{d54c}\x03\u{d552}\
-		\x03\u{d554}\x03\u{d6a7}\x03\u{d6aa}\x03\u{d6c2}\x03\u{d6c4}\x03\u{d6dc}\
-		\x03\u{d6de}\x03\u{d6fc}\x03\u{d6fe}\x03\u{d716}\x03\u{d718}\x03\u{d736}\
-		\x03\u{d738}\x03\u{d750}\x03\u{d752}\x03\u{d770}\x03\u{d772}\x03\u{d78a}\
-		\x03\u{d78c}\x03\u{d7aa}\x03\u{d7ac}\x03\u{d7c4}\x03\u{d7c6}\x03\u{d7cd}\
-		\x03\u{d7d0}\x03\u{10801}\x03\u{10a02}\x03\u{10a38}\x03\u{10a3d}\x03\u{10a6e}\
-		\x03\u{10a77}\x03\u{10a77}\x03\u{10a86}\x03\u{10a86}\x03\u{10a9d}\x03\u{10aa1}\
-		\x03\u{10aa3}\x03\u{10ab1}\x03\u{e002}\x03\u{e008}\x03\u{e00a}\x03\u{e01a}\
-		\x03\u{e01d}\x03\u{e023}\x03\u{e025}\x03\u{e026}\x03\u{e028}\x03\u{e02c}\
-		\x03\u{e802}\x03\u{e8c6}\x03\u{e8d2}\x03\u{e8d8}\x03\u{e902}\x03\u{e94c}\
-		\x03\u{e952}\x03\u{e95b}\x03\u{ee02}\x03\u{ee05}\x03\u{ee07}\x03\u{ee21}\
-		\x03\u{ee23}\x03\u{ee24}\x03\u{ee26}\x03\u{ee26}\x03\u{ee29}\x03\u{ee29}\
-		\x03\u{ee2b}\x03\u{ee34}\x03\u{ee36}\x03\u{ee39}\x03\u{ee3b}\x03\u{ee3b}\
-		\x03\u{ee3d}\x03\u{ee3d}\x03\u{ee44}\x03\u{ee44}\x03\u{ee49}\x03\u{ee49}\
-		\x03\u{ee4b}\x03\u{ee4b}\x03\u{ee4d}\x03\u{ee4d}\x03\u{ee4f}\x03\u{ee51}\
-		\x03\u{ee53}\x03\u{ee54}\x03\u{ee56}\x03\u{ee56}\x03\u{ee59}\x03\u{ee59}\
-		\x03\u{ee5b}\x03\u{ee5b}\x03\u{ee5d}\x03\u{ee5d}\x03\u{ee5f}\x03\u{ee5f}\
-		\x03\u{ee61}\x03\u{ee61}\x03\u{ee63}\x03\u{ee64}\x03\u{ee66}\x03\u{ee66}\
-		\x03\u{ee69}\x03\u{ee6c}\x03\u{ee6e}\x03\u{ee74}\x03\u{ee76}\x03\u{ee79}\
-		\x03\u{ee7b}\x03\u{ee7e}\x03\u{ee80}\x03\u{ee80}\x03\u{ee82}\x03\u{ee8b}\
-		\x03\u{ee8d}\x03\u{ee9d}\x03\u{eea3}\x03\u{eea5}\x03\u{eea7}\x03\u{eeab}\
-		\x03\u{eead}\x03\u{eebd}\x03\x02\x04\u{a6d8}\x04\u{a702}\x04\u{b736}\x04\
-		\u{b742}\x04\u{b81f}\x04\u{b822}\x04\u{cea3}\x04\u{ceb2}\x04\u{ebe2}\x04\
-		\u{f802}\x04\u{fa1f}\x04\u{102}\x10\u{1f1}\x10\u{24b}\x02\x43\x02\x5c\x02\
-		\x63\x02\x7c\x02\u{ac}\x02\u{ac}\x02\u{b7}\x02\u{b7}\x02\u{bc}\x02\u{bc}\
-		\x02\u{c2}\x02\u{d8}\x02\u{da}\x02\u{f8}\x02\u{fa}\x02\u{2c3}\x02\u{2c8}\
-		\x02\u{2d3}\x02\u{2e2}\x02\u{2e6}\x02\u{2ee}\x02\u{2ee}\x02\u{2f0}\x02\
-		\u{2f0}\x02\u{372}\x02\u{376}\x02\u{378}\x02\u{379}\x02\u{37c}\x02\u{37f}\
-		\x02\u{381}\x02\u{381}\x02\u{388}\x02\u{388}\x02\u{38a}\x02\u{38c}\x02\
-		\u{38e}\x02\u{38e}\x02\u{390}\x02\u{3a3}\x02\u{3a5}\x02\u{3f7}\x02\u{3f9}\
-		\x02\u{483}\x02\u{48c}\x02\u{531}\x02\u{533}\x02\u{558}\x02\u{55b}\x02\
-		\u{55b}\x02\u{563}\x02\u{589}\x02\u{5d2}\x02\u{5ec}\x02\u{5f2}\x02\u{5f4}\
-		\x02\u{622}\x02\u{64c}\x02\u{670}\x02\u{671}\x02\u{673}\x02\u{6d5}\x02\
-		\u{6d7}\x02\u{6d7}\x02\u{6e7}\x02\u{6e8}\x02\u{6f0}\x02\u{6f1}\x02\u{6fc}\
-		\x02\u{6fe}\x02\u{701}\x02\u{701}\x02\u{712}\x02\u{712}\x02\u{714}\x02\
-		\u{731}\x02\u{74f}\x02\u{7a7}\x02\u{7b3}\x02\u{7b3}\x02\u{7cc}\x02\u{7ec}\
-		\x02\u{7f6}\x02\u{7f7}\x02\u{7fc}\x02\u{7fc}\x02\u{802}\x02\u{817}\x02\
-		\u{81c}\x02\u{81c}\x02\u{826}\x02\u{826}\x02\u{82a}\x02\u{82a}\x02\u{842}\
-		\x02\u{85a}\x02\u{862}\x02\u{86c}\x02\u{8a2}\x02\u{8b6}\x02\u{8b8}\x02\
-		\u{8bf}\x02\u{906}\x02\u{93b}\x02\u{93f}\x02\u{93f}\x02\u{952}\x02\u{952}\
-		\x02\u{95a}\x02\u{963}\x02\u{973}\x02\u{982}\x02\u{987}\x02\u{98e}\x02\
-		\u{991}\x02\u{992}\x02\u{995}\x02\u{9aa}\x02\u{9ac}\x02\u{9b2}\x02\u{9b4}\
-		\x02\u{9b4}\x02\u{9b8}\x02\u{9bb}\x02\u{9bf}\x02\u{9bf}\x02\u{9d0}\x02\
-		\u{9d0}\x02\u{9de}\x02\u{9df}\x02\u{9e1}\x02\u{9e3}\x02\u{9f2}\x02\u{9f3}\
-		\x02\u{9fe}\x02\u{9fe}\x02\u{a07}\x02\u{a0c}\x02\u{a11}\x02\u{a12}\x02\
-		\u{a15}\x02\u{a2a}\x02\u{a2c}\x02\u{a32}\x02\u{a34}\x02\u{a35}\x02\u{a37}\
-		\x02\u{a38}\x02\u{a3a}\x02\u{a3b}\x02\u{a5b}\x02\u{a5e}\x02\u{a60}\x02\
-		\u{a60}\x02\u{a74}\x02\u{a76}\x02\u{a87}\x02\u{a8f}\x02\u{a91}\x02\u{a93}\
-		\x02\u{a95}\x02\u{aaa}\x02\u{aac}\x02\u{ab2}\x02\u{ab4}\x02\u{ab5}\x02\
-		\u{ab7}\x02\u{abb}\x02\u{abf}\x02\u{abf}\x02\u{ad2}\x02\u{ad2}\x02\u{ae2}\
-		\x02\u{ae3}\x02\u{afb}\x02\u{afb}\x02\u{b07}\x02\u{b0e}\x02\u{b11}\x02\
-		\u{b12}\x02\u{b15}\x02\u{b2a}\x02\u{b2c}\x02\u{b32}\x02\u{b34}\x02\u{b35}\
-		\x02\u{b37}\x02\u{b3b}\x02\u{b3f}\x02\u{b3f}\x02\u{b5e}\x02\u{b5f}\x02\
-		\u{b61}\x02\u{b63}\x02\u{b73}\x02\u{b73}\x02\u{b85}\x02\u{b85}\x02\u{b87}\
+		\x04\u{94}\x09\u{94}\x04\u{95}\x09\u{95}\x03\x02\x03\x02\x03\x03\x03\x03\
+		\x03\x04\x03\x04\x03\x05\x03\x05\x03\x05\x03\x06\x03\x06\x03\x07\x03\x07\
+		\x03\x08\x03\x08\x03\x09\x03\x09\x03\x0a\x03\x0a\x03\x0b\x03\x0b\x03\x0c\
+		\x03\x0c\x03\x0d\x03\x0d\x03\x0d\x03\x0e\x03\x0e\x03\x0f\x03\x0f\x03\x10\
+		\x03\x10\x03\x11\x03\x11\x03\x12\x03\x12\x03\x13\x03\x13\x03\x13\x03\x14\
+		\x03\x14\x03\x14\x03\x15\x03\x15\x03\x16\x03\x16\x03\x17\x03\x17\x03\x17\
+		\x03\x18\x03\x18\x03\x18\x03\x19\x03\x19\x03\x1a\x03\x1a\x03\x1b\x03\x1b\
+		\x03\x1c\x03\x1c\x03\x1d\x03\x1d\x03\x1e\x03\x1e\x03\x1f\x03\x1f\x03\x20\
+		\x03\x20\x03\x21\x03\x21\x03\x22\x03\x22\x03\x23\x03\x23\x03\x24\x03\x24\
+		\x03\x25\x03\x25\x03\x26\x03\x26\x03\x27\x03\x27\x03\x28\x03\x28\x03\x29\
+		\x03\x29\x03\x2a\x03\x2a\x03\x2b\x03\x2b\x03\x2c\x03\x2c\x03\x2d\x03\x2d\
+		\x03\x2e\x03\x2e\x03\x2f\x03\x2f\x03\x30\x03\x30\x03\x30\x03\x30\x03\x30\
+		\x03\x30\x03\x31\x03\x31\x03\x31\x03\x31\x03\x32\x03\x32\x03\x32\x03\x32\
+		\x03\x32\x03\x32\x03\x32\x03\x32\x03\x32\x03\x33\x03\x33\x03\x33\x03\x33\
+		\x03\x33\x03\x33\x03\x34\x03\x34\x03\x34\x03\x34\x03\x34\x03\x34\x03\x34\
+		\x03\x35\x03\x35\x03\x35\x03\x36\x03\x36\x03\x36\x03\x36\x03\x36\x03\x36\
+		\x03\x37\x03\x37\x03\x37\x03\x38\x03\x38\x03\x38\x03\x38\x03\x38\x03\x38\
+		\x03\x38\x03\x39\x03\x39\x03\x39\x03\x39\x03\x3a\x03\x3a\x03\x3a\x03\x3a\
+		\x03\x3a\x03\x3a\x03\x3a\x03\x3b\x03\x3b\x03\x3b\x03\x3b\x03\x3b\x03\x3b\
+		\x03\x3b\x03\x3c\x03\x3c\x03\x3c\x03\x3c\x03\x3c\x03\x3c\x03\x3c\x03\x3d\
+		\x03\x3d\x03\x3d\x03\x3d\x03\x3d\x03\x3e\x03\x3e\x03\x3e\x03\x3e\x03\x3e\
+		\x03\x3e\x03\x3f\x03\x3f\x03\x3f\x03\x3f\x03\x3f\x03\x40\x03\x40\x03\x40\
+		\x03\x40\x03\x40\x03\x40\x03\x40\x03\x41\x03\x41\x03\x41\x03\x41\x03\x41\
+		\x03\x41\x03\x41\x03\x41\x03\x41\x03\x42\x03\x42\x03\x42\x03\x42\x03\x42\
+		\x03\x42\x03\x43\x03\x43\x03\x43\x03\x44\x03\x44\x03\x44\x03\x44\x03\x44\
+		\x03\x45\x03\x45\x03\x45\x03\x45\x03\x45\x03\x45\x03\x46\x03\x46\x03\x46\
+		\x03\x46\x03\x46\x03\x46\x03\x46\x03\x46\x03\x46\x03\x46\x03\x47\x03\x47\
+		\x03\x47\x03\x47\x03\x48\x03\x48\x03\x48\x03\x48\x03\x48\x03\x48\x03\x48\
+		\x03\x48\x03\x48\x03\x48\x03\x48\x03\x49\x03\x49\x03\x49\x03\x49\x03\x49\
+		\x03\x4a\x03\x4a\x03\x4a\x03\x4a\x03\x4a\x03\x4a\x03\x4b\x03\x4b\x03\x4b\
+		\x03\x4c\x03\x4c\x03\x4c\x03\x4c\x03\x4d\x03\x4d\x03\x4d\x03\x4d\x03\x4e\
+		\x03\x4e\x03\x4e\x03\x4e\x03\x4f\x03\x4f\x03\x4f\x03\x50\x03\x50\x03\x50\
+		\x03\x50\x03\x50\x03\x50\x03\x50\x03\x51\x03\x51\x03\x51\x03\x51\x03\x51\
+		\x03\x52\x03\x52\x03\x52\x03\x52\x03\x52\x03\x52\x03\x52\x03\x52\x03\x52\
+		\x03\x53\x03\x53\x03\x53\x03\x54\x03\x54\x03\x54\x03\x54\x03\x54\x03\x55\
+		\x03\x55\x03\x55\x03\x55\x03\x55\x03\x55\x03\x56\x03\x56\x03\x56\x03\x56\
+		\x03\x57\x03\x57\x03\x57\x03\x57\x03\x57\x03\x58\x03\x58\x03\x58\x03\x58\
+		\x03\x58\x03\x58\x03\x58\x03\x59\x03\x59\x03\x59\x03\x59\x03\x59\x03\x5a\
+		\x03\x5a\x03\x5a\x03\x5a\x03\x5a\x03\x5a\x03\x5b\x03\x5b\x03\x5b\x03\x5b\
+		\x03\x5b\x03\x5b\x03\x5b\x03\x5c\x03\x5c\x03\x5c\x03\x5c\x03\x5c\x03\x5d\
+		\x03\x5d\x03\x5d\x03\x5d\x03\x5d\x03\x5e\x03\x5e\x03\x5e\x03\x5e\x03\x5f\
+		\x03\x5f\x03\x5f\x03\x5f\x03\x5f\x03\x60\x03\x60\x03\x60\x03\x60\x03\x60\
+		\x03\x61\x03\x61\x03\x61\x07\x61\u{2a4}\x0a\x61\x0c\x61\x0e\x61\u{2a7}\
+		\x0b\x61\x03\x61\x03\x61\x03\x61\x03\x61\x07\x61\u{2ad}\x0a\x61\x0c\x61\
+		\x0e\x61\u{2b0}\x0b\x61\x03\x61\x05\x61\u{2b3}\x0a\x61\x03\x62\x03\x62\
+		\x03\x62\x03\x62\x03\x62\x03\x62\x03\x62\x03\x62\x03\x62\x03\x62\x03\x62\
+		\x03\x62\x03\x62\x03\x62\x03\x62\x03\x62\x03\x62\x03\x62\x05\x62\u{2c7}\
+		\x0a\x62\x03\x63\x03\x63\x03\x63\x03\x63\x06\x63\u{2cd}\x0a\x63\x0d\x63\
+		\x0e\x63\u{2ce}\x03\x64\x03\x64\x03\x64\x07\x64\u{2d4}\x0a\x64\x0c\x64\
+		\x0e\x64\u{2d7}\x0b\x64\x05\x64\u{2d9}\x0a\x64\x03\x65\x03\x65\x06\x65\
+		\u{2dd}\x0a\x65\x0d\x65\x0e\x65\u{2de}\x03\x66\x05\x66\u{2e2}\x0a\x66\x03\
+		\x67\x03\x67\x05\x67\u{2e6}\x0a\x67\x03\x68\x03\x68\x05\x68\u{2ea}\x0a\
+		\x68\x03\x69\x03\x69\x05\x69\u{2ee}\x0a\x69\x03\x6a\x03\x6a\x03\x6b\x03\
+		\x6b\x05\x6b\u{2f4}\x0a\x6b\x03\x6c\x03\x6c\x03\x6d\x06\x6d\u{2f9}\x0a\
+		\x6d\x0d\x6d\x0e\x6d\u{2fa}\x03\x6d\x06\x6d\u{2fe}\x0a\x6d\x0d\x6d\x0e\
+		\x6d\u{2ff}\x03\x6d\x03\x6d\x06\x6d\u{304}\x0a\x6d\x0d\x6d\x0e\x6d\u{305}\
+		\x03\x6d\x03\x6d\x06\x6d\u{30a}\x0a\x6d\x0d\x6d\x0e\x6d\u{30b}\x05\x6d\
+		\u{30e}\x0a\x6d\x03\x6d\x03\x6d\x05\x6d\u{312}\x0a\x6d\x03\x6d\x06\x6d\
+		\u{315}\x0a\x6d\x0d\x6d\x0e\x6d\u{316}\x03\x6e\x07\x6e\u{31a}\x0a\x6e\x0c\
+		\x6e\x0e\x6e\u{31d}\x0b\x6e\x03\x6e\x03\x6e\x06\x6e\u{321}\x0a\x6e\x0d\
+		\x6e\x0e\x6e\u{322}\x03\x6f\x03\x6f\x03\x6f\x03\x6f\x03\x6f\x03\x6f\x03\
+		\x6f\x03\x6f\x03\x6f\x03\x6f\x03\x6f\x03\x70\x03\x70\x03\x70\x03\x71\x03\
+		\x71\x03\x71\x03\x71\x03\x72\x03\x72\x03\x72\x03\x72\x03\x72\x03\x72\x03\
+		\x72\x03\x72\x03\x73\x03\x73\x03\x73\x03\x73\x03\x73\x03\x73\x03\x73\x03\
+		\x74\x03\x74\x03\x74\x03\x74\x03\x74\x03\x74\x03\x74\x03\x74\x03\x74\x03\
+		\x74\x03\x75\x03\x75\x03\x75\x03\x75\x03\x75\x03\x75\x03\x75\x03\x76\x03\
+		\x76\x03\x76\x03\x77\x03\x77\x03\x77\x03\x77\x03\x78\x03\x78\x03\x78\x03\
+		\x78\x03\x78\x03\x79\x03\x79\x03\x79\x03\x79\x03\x79\x03\x79\x03\x79\x03\
+		\x7a\x03\x7a\x03\x7a\x03\x7a\x03\x7a\x03\x7a\x03\x7a\x03\x7a\x03\x7b\x03\
+		\x7b\x07\x7b\u{374}\x0a\x7b\x0c\x7b\x0e\x7b\u{377}\x0b\x7b\x03\x7c\x03\
+		\x7c\x05\x7c\u{37b}\x0a\x7c\x03\x7d\x03\x7d\x05\x7d\u{37f}\x0a\x7d\x03\
+		\x7e\x03\x7e\x07\x7e\u{383}\x0a\x7e\x0c\x7e\x0e\x7e\u{386}\x0b\x7e\x03\
+		\x7e\x06\x7e\u{389}\x0a\x7e\x0d\x7e\x0e\x7e\u{38a}\x03\x7f\x06\x7f\u{38e}\
+		\x0a\x7f\x0d\x7f\x0e\x7f\u{38f}\x03\u{80}\x03\u{80}\x03\u{80}\x03\u{80}\
+		\x03\u{80}\x03\u{80}\x03\u{80}\x03\u{80}\x03\u{80}\x03\u{80}\x03\u{80}\
+		\x03\u{80}\x05\u{80}\u{39e}\x0a\u{80}\x03\u{81}\x03\u{81}\x03\u{81}\x03\
+		\u{81}\x03\u{81}\x03\u{81}\x07\u{81}\u{3a6}\x0a\u{81}\x0c\u{81}\x0e\u{81}\
+		\u{3a9}\x0b\u{81}\x03\u{81}\x03\u{81}\x03\u{81}\x03\u{81}\x03\u{81}\x03\
+		\u{81}\x07\u{81}\u{3b1}\x0a\u{81}\x0c\u{81}\x0e\u{81}\u{3b4}\x0b\u{81}\
+		\x03\u{81}\x05\u{81}\u{3b7}\x0a\u{81}\x03\u{81}\x03\u{81}\x05\u{81}\u{3bb}\
+		\x0a\u{81}\x05\u{81}\u{3bd}\x0a\u{81}\x03\u{82}\x03\u{82}\x03\u{83}\x03\
+		\u{83}\x03\u{84}\x03\u{84}\x03\u{85}\x03\u{85}\x03\u{86}\x03\u{86}\x03\
+		\u{87}\x03\u{87}\x03\u{88}\x03\u{88}\x03\u{89}\x03\u{89}\x03\u{8a}\x03\
+		\u{8a}\x03\u{8b}\x03\u{8b}\x03\u{8c}\x03\u{8c}\x03\u{8d}\x03\u{8d}\x03\
+		\u{8e}\x03\u{8e}\x03\u{8f}\x03\u{8f}\x03\u{90}\x03\u{90}\x03\u{91}\x03\
+		\u{91}\x03\u{92}\x03\u{92}\x03\u{93}\x03\u{93}\x03\u{94}\x03\u{94}\x03\
+		\u{95}\x03\u{95}\x02\x02\u{96}\x03\x03\x05\x04\x07\x05\x09\x06\x0b\x07\
+		\x0d\x08\x0f\x09\x11\x0a\x13\x0b\x15\x0c\x17\x0d\x19\x0e\x1b\x0f\x1d\x10\
+		\x1f\x11\x21\x12\x23\x13\x25\x14\x27\x15\x29\x16\x2b\x17\x2d\x18\x2f\x19\
+		\x31\x1a\x33\x1b\x35\x1c\x37\x1d\x39\x1e\x3b\x1f\x3d\x20\x3f\x21\x41\x22\
+		\x43\x23\x45\x24\x47\x25\x49\x26\x4b\x27\x4d\x28\x4f\x29\x51\x2a\x53\x2b\
+		\x55\x2c\x57\x2d\x59\x2e\x5b\x2f\x5d\x30\x5f\x31\x61\x32\x63\x33\x65\x34\
+		\x67\x35\x69\x36\x6b\x37\x6d\x38\x6f\x39\x71\x3a\x73\x3b\x75\x3c\x77\x3d\
+		\x79\x3e\x7b\x3f\x7d\x40\x7f\x41\u{81}\x42\u{83}\x43\u{85}\x44\u{87}\x45\
+		\u{89}\x46\u{8b}\x47\u{8d}\x48\u{8f}\x49\u{91}\x4a\u{93}\x4b\u{95}\x4c\
+		\u{97}\x4d\u{99}\x4e\u{9b}\x4f\u{9d}\x50\u{9f}\x51\u{a1}\x52\u{a3}\x53\
+		\u{a5}\x54\u{a7}\x55\u{a9}\x56\u{ab}\x57\u{ad}\x58\u{af}\x59\u{b1}\x5a\
+		\u{b3}\x5b\u{b5}\x5c\u{b7}\x5d\u{b9}\x5e\u{bb}\x5f\u{bd}\x60\u{bf}\x61\
+		\u{c1}\x62\u{c3}\x63\u{c5}\x64\u{c7}\x65\u{c9}\x66\u{cb}\x67\u{cd}\x68\
+		\u{cf}\x69\u{d1}\x6a\u{d3}\x6b\u{d5}\x6c\u{d7}\x6d\u{d9}\x6e\u{db}\x6f\
+		\u{dd}\x70\u{df}\x71\u{e1}\x72\u{e3}\x73\u{e5}\x74\u{e7}\x75\u{e9}\x76\
+		\u{eb}\x77\u{ed}\x78\u{ef}\x79\u{f1}\x7a\u{f3}\x7b\u{f5}\x7c\u{f7}\x7d\
+		\u{f9}\x7e\u{fb}\x7f\u{fd}\u{80}\u{ff}\u{81}\u{101}\u{82}\u{103}\x02\u{105}\
+		\x02\u{107}\x02\u{109}\x02\u{10b}\x02\u{10d}\x02\u{10f}\x02\u{111}\x02\
+		\u{113}\x02\u{115}\x02\u{117}\x02\u{119}\x02\u{11b}\x02\u{11d}\x02\u{11f}\
+		\x02\u{121}\x02\u{123}\x02\u{125}\x02\u{127}\x02\u{129}\x02\x03\x02\x2f\
+		\x04\x02\x57\x57\x77\x77\x04\x02\x50\x50\x70\x70\x04\x02\x4b\x4b\x6b\x6b\
+		\x04\x02\x51\x51\x71\x71\x04\x02\x43\x43\x63\x63\x04\x02\x4e\x4e\x6e\x6e\
+		\x04\x02\x52\x52\x72\x72\x04\x02\x56\x56\x76\x76\x04\x02\x4f\x4f\x6f\x6f\
+		\x04\x02\x45\x45\x65\x65\x04\x02\x4a\x4a\x6a\x6a\x04\x02\x59\x59\x79\x79\
+		\x04\x02\x46\x46\x66\x66\x04\x02\x55\x55\x75\x75\x04\x02\x47\x47\x67\x67\
+		\x04\x02\x54\x54\x74\x74\x04\x02\x49\x49\x69\x69\x04\x02\x58\x58\x78\x78\
+		\x04\x02\x5b\x5b\x7b\x7b\x04\x02\x44\x44\x64\x64\x04\x02\x4d\x4d\x6d\x6d\
+		\x04\x02\x5a\x5a\x7a\x7a\x04\x02\x48\x48\x68\x68\x0f\x02\x24\x24\x29\x29\
+		\x44\x44\x48\x48\x50\x50\x54\x54\x56\x56\x5e\x5e\x64\x64\x68\x68\x70\x70\
+		\x74\x74\x76\x76\x04\x02\x43\x48\x63\x68\x04\x02\x53\x53\x73\x73\x0a\x02\
+		\u{a2}\u{a2}\u{1682}\u{1682}\u{1810}\u{1810}\u{2002}\u{200c}\u{202a}\u{202b}\
+		\u{2031}\u{2031}\u{2061}\u{2061}\u{3002}\u{3002}\x03\x02\x0e\x0e\x03\x02\
+		\x62\x62\x03\x02\x20\x20\x03\x02\x2c\x2c\x04\x02\x29\x29\x5e\x5e\x04\x02\
+		\x0c\x0c\x0f\x0f\x03\x02\x31\x31\x03\x02\x1f\x1f\x03\x02\x1e\x1e\x03\x02\
+		\x0f\x0f\x13\x02\x26\x26\u{a4}\u{a7}\u{591}\u{591}\u{60d}\u{60d}\u{9f4}\
+		\u{9f5}\u{9fd}\u{9fd}\u{af3}\u{af3}\u{bfb}\u{bfb}\u{e41}\u{e41}\u{17dd}\
+		\u{17dd}\u{20a2}\u{20c1}\u{a83a}\u{a83a}\u{fdfe}\u{fdfe}\u{fe6b}\u{fe6b}\
+		\u{ff06}\u{ff06}\u{ffe2}\u{ffe3}\u{ffe7}\u{ffe8}\x03\x02\x22\x22\x08\x02\
+		\x61\x61\u{2041}\u{2042}\u{2056}\u{2056}\u{fe35}\u{fe36}\u{fe4f}\u{fe51}\
+		\u{ff41}\u{ff41}\x03\x02\x0b\x0b\x04\x02\x24\x24\x5e\x5e\x03\x02\x0c\x0c\
+		\x03\x02\x0d\x0d\x03\x02\x21\x21\x04\u{2b3}\x02\x32\x02\x3b\x02\x43\x02\
+		\x5c\x02\x61\x02\x61\x02\x63\x02\x7c\x02\u{ac}\x02\u{ac}\x02\u{b7}\x02\
+		\u{b7}\x02\u{b9}\x02\u{b9}\x02\u{bc}\x02\u{bc}\x02\u{c2}\x02\u{d8}\x02\
+		\u{da}\x02\u{f8}\x02\u{fa}\x02\u{2c3}\x02\u{2c8}\x02\u{2d3}\x02\u{2e2}\
+		\x02\u{2e6}\x02\u{2ee}\x02\u{2ee}\x02\u{2f0}\x02\u{2f0}\x02\u{302}\x02\
+		\u{376}\x02\u{378}\x02\u{379}\x02\u{37c}\x02\u{37f}\x02\u{381}\x02\u{381}\
+		\x02\u{388}\x02\u{38c}\x02\u{38e}\x02\u{38e}\x02\u{390}\x02\u{3a3}\x02\
+		\u{3a5}\x02\u{3f7}\x02\u{3f9}\x02\u{483}\x02\u{485}\x02\u{489}\x02\u{48c}\
+		\x02\u{531}\x02\u{533}\x02\u{558}\x02\u{55b}\x02\u{55b}\x02\u{563}\x02\
+		\u{589}\x02\u{593}\x02\u{5bf}\x02\u{5c1}\x02\u{5c1}\x02\u{5c3}\x02\u{5c4}\
+		\x02\u{5c6}\x02\u{5c7}\x02\u{5c9}\x02\u{5c9}\x02\u{5d2}\x02\u{5ec}\x02\
+		\u{5f2}\x02\u{5f4}\x02\u{612}\x02\u{61c}\x02\u{622}\x02\u{66b}\x02\u{670}\
+		\x02\u{6d5}\x02\u{6d7}\x02\u{6de}\x02\u{6e1}\x02\u{6ea}\x02\u{6ec}\x02\
+		\u{6fe}\x02\u{701}\x02\u{701}\x02\u{712}\x02\u{74c}\x02\u{74f}\x02\u{7b3}\
+		\x02\u{7c2}\x02\u{7f7}\x02\u{7fc}\x02\u{7fc}\x02\u{802}\x02\u{82f}\x02\
+		\u{842}\x02\u{85d}\x02\u{862}\x02\u{86c}\x02\u{8a2}\x02\u{8b6}\x02\u{8b8}\
+		\x02\u{8bf}\x02\u{8d6}\x02\u{8e3}\x02\u{8e5}\x02\u{965}\x02\u{968}\x02\
+		\u{971}\x02\u{973}\x02\u{985}\x02\u{987}\x02\u{98e}\x02\u{991}\x02\u{992}\
+		\x02\u{995}\x02\u{9aa}\x02\u{9ac}\x02\u{9b2}\x02\u{9b4}\x02\u{9b4}\x02\
+		\u{9b8}\x02\u{9bb}\x02\u{9be}\x02\u{9c6}\x02\u{9c9}\x02\u{9ca}\x02\u{9cd}\
+		\x02\u{9d0}\x02\u{9d9}\x02\u{9d9}\x02\u{9de}\x02\u{9df}\x02\u{9e1}\x02\
+		\u{9e5}\x02\u{9e8}\x02\u{9f3}\x02\u{9fe}\x02\u{9fe}\x02\u{a03}\x02\u{a05}\
+		\x02\u{a07}\x02\u{a0c}\x02\u{a11}\x02\u{a12}\x02\u{a15}\x02\u{a2a}\x02\
+		\u{a2c}\x02\u{a32}\x02\u{a34}\x02\u{a35}\x02\u{a37}\x02\u{a38}\x02\u{a3a}\
+		\x02\u{a3b}\x02\u{a3e}\x02\u{a3e}\x02\u{a40}\x02\u{a44}\x02\u{a49}\x02\
+		\u{a4a}\x02\u{a4d}\x02\u{a4f}\x02\u{a53}\x02\u{a53}\x02\u{a5b}\x02\u{a5e}\
+		\x02\u{a60}\x02\u{a60}\x02\u{a68}\x02\u{a77}\x02\u{a83}\x02\u{a85}\x02\
+		\u{a87}\x02\u{a8f}\x02\u{a91}\x02\u{a93}\x02\u{a95}\x02\u{aaa}\x02\u{aac}\
+		\x02\u{ab2}\x02\u{ab4}\x02\u{ab5}\x02\u{ab7}\x02\u{abb}\x02\u{abe}\x02\
+		\u{ac7}\x02\u{ac9}\x02\u{acb}\x02\u{acd}\x02\u{acf}\x02\u{ad2}\x02\u{ad2}\
+		\x02\u{ae2}\x02\u{ae5}\x02\u{ae8}\x02\u{af1}\x02\u{afb}\x02\u{b01}\x02\
+		\u{b03}\x02\u{b05}\x02\u{b07}\x02\u{b0e}\x02\u{b11}\x02\u{b12}\x02\u{b15}\
+		\x02\u{b2a}\x02\u{b2c}\x02\u{b32}\x02\u{b34}\x02\u{b35}\x02\u{b37}\x02\
+		\u{b3b}\x02\u{b3e}\x02\u{b46}\x02\u{b49}\x02\u{b4a}\x02\u{b4d}\x02\u{b4f}\
+		\x02\u{b58}\x02\u{b59}\x02\u{b5e}\x02\u{b5f}\x02\u{b61}\x02\u{b65}\x02\
+		\u{b68}\x02\u{b71}\x02\u{b73}\x02\u{b73}\x02\u{b84}\x02\u{b85}\x02\u{b87}\
 		\x02\u{b8c}\x02\u{b90}\x02\u{b92}\x02\u{b94}\x02\u{b97}\x02\u{b9b}\x02\
 		\u{b9c}\x02\u{b9e}\x02\u{b9e}\x02\u{ba0}\x02\u{ba1}\x02\u{ba5}\x02\u{ba6}\
-		\x02\u{baa}\x02\u{bac}\x02\u{bb0}\x02\u{bbb}\x02\u{bd2}\x02\u{bd2}\x02\
-		\u{c07}\x02\u{c0e}\x02\u{c10}\x02\u{c12}\x02\u{c14}\x02\u{c2a}\x02\u{c2c}\
-		\x02\u{c3b}\x02\u{c3f}\x02\u{c3f}\x02\u{c5a}\x02\u{c5c}\x02\u{c62}\x02\
-		\u{c63}\x02\u{c82}\x02\u{c82}\x02\u{c87}\x02\u{c8e}\x02\u{c90}\x02\u{c92}\
-		\x02\u{c94}\x02\u{caa}\x02\u{cac}\x02\u{cb5}\x02\u{cb7}\x02\u{cbb}\x02\
-		\u{cbf}\x02\u{cbf}\x02\u{ce0}\x02\u{ce0}\x02\u{ce2}\x02\u{ce3}\x02\u{cf3}\
-		\x02\u{cf4}\x02\u{d07}\x02\u{d0e}\x02\u{d10}\x02\u{d12}\x02\u{d14}\x02\
-		\u{d3c}\x02\u{d3f}\x02\u{d3f}\x02\u{d50}\x02\u{d50}\x02\u{d56}\x02\u{d58}\
-		\x02\u{d61}\x02\u{d63}\x02\u{d7c}\x02\u{d81}\x02\u{d87}\x02\u{d98}\x02\
-		\u{d9c}\x02\u{db3}\x02\u{db5}\x02\u{dbd}\x02\u{dbf}\x02\u{dbf}\x02\u{dc2}\
-		\x02\u{dc8}\x02\u{e03}\x02\u{e32}\x02\u{e34}\x02\u{e35}\x02\u{e42}\x02\
-		\u{e48}\x02\u{e83}\x02\u{e84}\x02\u{e86}\x02\u{e86}\x02\u{e89}\x02\u{e8a}\
-		\x02\u{e8c}\x02\u{e8c}\x02\u{e8f}\x02\u{e8f}\x02\u{e96}\x02\u{e99}\x02\
-		\u{e9b}\x02\u{ea1}\x02\u{ea3}\x02\u{ea5}\x02\u{ea7}\x02\u{ea7}\x02\u{ea9}\
-		\x02\u{ea9}\x02\u{eac}\x02\u{ead}\x02\u{eaf}\x02\u{eb2}\x02\u{eb4}\x02\
-		\u{eb5}\x02\u{ebf}\x02\u{ebf}\x02\u{ec2}\x02\u{ec6}\x02\u{ec8}\x02\u{ec8}\
-		\x02\u{ede}\x02\u{ee1}\x02\u{f02}\x02\u{f02}\x02\u{f42}\x02\u{f49}\x02\
-		\u{f4b}\x02\u{f6e}\x02\u{f8a}\x02\u{f8e}\x02\u{1002}\x02\u{102c}\x02\u{1041}\
-		\x02\u{1041}\x02\u{1052}\x02\u{1057}\x02\u{105c}\x02\u{105f}\x02\u{1063}\
-		\x02\u{1063}\x02\u{1067}\x02\u{1068}\x02\u{1070}\x02\u{1072}\x02\u{1077}\
-		\x02\u{1083}\x02\u{1090}\x02\u{1090}\x02\u{10a2}\x02\u{10c7}\x02\u{10c9}\
-		\x02\u{10c9}\x02\u{10cf}\x02\u{10cf}\x02\u{10d2}\x02\u{10fc}\x02\u{10fe}\
-		\x02\u{124a}\x02\u{124c}\x02\u{124f}\x02\u{1252}\x02\u{1258}\x02\u{125a}\
-		\x02\u{125a}\x02\u{125c}\x02\u{125f}\x02\u{1262}\x02\u{128a}\x02\u{128c}\
-		\x02\u{128f}\x02\u{1292}\x02\u{12b2}\x02\u{12b4}\x02\u{12b7}\x02\u{12ba}\
-		\x02\u{12c0}\x02\u{12c2}\x02\u{12c2}\x02\u{12c4}\x02\u{12c7}\x02\u{12ca}\
-		\x02\u{12d8}\x02\u{12da}\x02\u{1312}\x02\u{1314}\x02\u{1317}\x02\u{131a}\
-		\x02\u{135c}\x02\u{1382}\x02\u{1391}\x02\u{13a2}\x02\u{13f7}\x02\u{13fa}\
-		\x02\u{13ff}\x02\u{1403}\x02\u{166e}\x02\u{1671}\x02\u{1681}\x02\u{1683}\
-		\x02\u{169c}\x02\u{16a2}\x02\u{16ec}\x02\u{16f0}\x02\u{16fa}\x02\u{1702}\
-		\x02\u{170e}\x02\u{1710}\x02\u{1713}\x02\u{1722}\x02\u{1733}\x02\u{1742}\
-		\x02\u{1753}\x02\u{1762}\x02\u{176e}\x02\u{1770}\x02\u{1772}\x02\u{1782}\
-		\x02\u{17b5}\x02\u{17d9}\x02\u{17d9}\x02\u{17de}\x02\u{17de}\x02\u{1822}\
-		\x02\u{1879}\x02\u{1882}\x02\u{18aa}\x02\u{18ac}\x02\u{18ac}\x02\u{18b2}\
-		\x02\u{18f7}\x02\u{1902}\x02\u{1920}\x02\u{1952}\x02\u{196f}\x02\u{1972}\
-		\x02\u{1976}\x02\u{1982}\x02\u{19ad}\x02\u{19b2}\x02\u{19cb}\x02\u{1a02}\
-		\x02\u{1a18}\x02\u{1a22}\x02\u{1a56}\x02\u{1aa9}\x02\u{1aa9}\x02\u{1b07}\
-		\x02\u{1b35}\x02\u{1b47}\x02\u{1b4d}\x02\u{1b85}\x02\u{1ba2}\x02\u{1bb0}\
-		\x02\u{1bb1}\x02\u{1bbc}\x02\u{1be7}\x02\u{1c02}\x02\u{1c25}\x02\u{1c4f}\
-		\x02\u{1c51}\x02\u{1c5c}\x02\u{1c7f}\x02\u{1c82}\x02\u{1c8a}\x02\u{1ceb}\
-		\x02\u{1cee}\x02\u{1cf0}\x02\u{1cf3}\x02\u{1cf7}\x02\u{1cf8}\x02\u{1d02}\
-		\x02\u{1dc1}\x02\u{1e02}\x02\u{1f17}\x02\u{1f1a}\x02\u{1f1f}\x02\u{1f22}\
-		\x02\u{1f47}\x02\u{1f4a}\x02\u{1f4f}\x02\u{1f52}\x02\u{1f59}\x02\u{1f5b}\
-		\x02\u{1f5b}\x02\u{1f5d}\x02\u{1f5d}\x02\u{1f5f}\x02\u{1f5f}\x02\u{1f61}\
-		\x02\u{1f7f}\x02\u{1f82}\x02\u{1fb6}\x02\u{1fb8}\x02\u{1fbe}\x02\u{1fc0}\
-		\x02\u{1fc0}\x02\u{1fc4}\x02\u{1fc6}\x02\u{1fc8}\x02\u{1fce}\x02\u{1fd2}\
-		\x02\u{1fd5}\x02\u{1fd8}\x02\u{1fdd}\x02\u{1fe2}\x02\u{1fee}\x02\u{1ff4}\
-		\x02\u{1ff6}\x02\u{1ff8}\x02\u{1ffe}\x02\u{2073}\x02\u{2073}\x02\u{2081}\
-		\x02\u{2081}\x02\u{2092}\x02\u{209e}\x02\u{2104}\x02\u{2104}\x02\u{2109}\
-		\x02\u{2109}\x02\u{210c}\x02\u{2115}\x02\u{2117}\x02\u{2117}\x02\u{211a}\
-		\x02\u{211f}\x02\u{2126}\x02\u{2126}\x02\u{2128}\x02\u{2128}\x02\u{212a}\
-		\x02\u{212a}\x02\u{212c}\x02\u{213b}\x02\u{213e}\x02\u{2141}\x02\u{2147}\
-		\x02\u{214b}\x02\u{2150}\x02\u{2150}\x02\u{2162}\x02\u{218a}\x02\u{2c02}\
-		\x02\u{2c30}\x02\u{2c32}\x02\u{2c60}\x02\u{2c62}\x02\u{2ce6}\x02\u{2ced}\
-		\x02\u{2cf0}\x02\u{2cf4}\x02\u{2cf5}\x02\u{2d02}\x02\u{2d27}\x02\u{2d29}\
-		\x02\u{2d29}\x02\u{2d2f}\x02\u{2d2f}\x02\u{2d32}\x02\u{2d69}\x02\u{2d71}\
-		\x02\u{2d71}\x02\u{2d82}\x02\u{2d98}\x02\u{2da2}\x02\u{2da8}\x02\u{2daa}\
-		\x02\u{2db0}\x02\u{2db2}\x02\u{2db8}\x02\u{2dba}\x02\u{2dc0}\x02\u{2dc2}\
-		\x02\u{2dc8}\x02\u{2dca}\x02\u{2dd0}\x02\u{2dd2}\x02\u{2dd8}\x02\u{2dda}\
-		\x02\u{2de0}\x02\u{3007}\x02\u{3009}\x02\u{3023}\x02\u{302b}\x02\u{3033}\
-		\x02\u{3037}\x02\u{303a}\x02\u{303e}\x02\u{3043}\x02\u{3098}\x02\u{309d}\
-		\x02\u{30a1}\x02\u{30a3}\x02\u{30fc}\x02\u{30fe}\x02\u{3101}\x02\u{3107}\
-		\x02\u{3130}\x02\u{3133}\x02\u{3190}\x02\u{31a2}\x02\u{31bc}\x02\u{31f2}\
-		\x02\u{3201}\x02\u{3402}\x02\u{4db7}\x02\u{4e02}\x02\u{9fec}\x02\u{a002}\
-		\x02\u{a48e}\x02\u{a4d2}\x02\u{a4ff}\x02\u{a502}\x02\u{a60e}\x02\u{a612}\
-		\x02\u{a621}\x02\u{a62c}\x02\u{a62d}\x02\u{a642}\x02\u{a670}\x02\u{a681}\
-		\x02\u{a69f}\x02\u{a6a2}\x02\u{a6f1}\x02\u{a719}\x02\u{a721}\x02\u{a724}\
-		\x02\u{a78a}\x02\u{a78d}\x02\u{a7b0}\x02\u{a7b2}\x02\u{a7b9}\x02\u{a7f9}\
-		\x02\u{a803}\x02\u{a805}\x02\u{a807}\x02\u{a809}\x02\u{a80c}\x02\u{a80e}\
-		\x02\u{a824}\x02\u{a842}\x02\u{a875}\x02\u{a884}\x02\u{a8b5}\x02\u{a8f4}\
-		\x02\u{a8f9}\x02\u{a8fd}\x02\u{a8fd}\x02\u{a8ff}\x02\u{a8ff}\x02\u{a90c}\
-		\x02\u{a927}\x02\u{a932}\x02\u{a948}\x02\u{a962}\x02\u{a97e}\x02\u{a986}\
-		\x02\u{a9b4}\x02\u{a9d1}\x02\u{a9d1}\x02\u{a9e2}\x02\u{a9e6}\x02\u{a9e8}\
-		\x02\u{a9f1}\x02\u{a9fc}\x02\u{aa00}\x02\u{aa02}\x02\u{aa2a}\x02\u{aa42}\
-		\x02\u{aa44}\x02\u{aa46}\x02\u{aa4d}\x02\u{aa62}\x02\u{aa78}\x02\u{aa7c}\
-		\x02\u{aa7c}\x02\u{aa80}\x02\u{aab1}\x02\u{aab3}\x02\u{aab3}\x02\u{aab7}\
-		\x02\u{aab8}\x02\u{aabb}\x02\u{aabf}\x02\u{aac2}\x02\u{aac2}\x02\u{aac4}\
-		\x02\u{aac4}\x02\u{aadd}\x02\u{aadf}\x02\u{aae2}\x02\u{aaec}\x02\u{aaf4}\
-		\x02\u{aaf6}\x02\u{ab03}\x02\u{ab08}\x02\u{ab0b}\x02\u{ab10}\x02\u{ab13}\
-		\x02\u{ab18}\x02\u{ab22}\x02\u{ab28}\x02\u{ab2a}\x02\u{ab30}\x02\u{ab32}\
-		\x02\u{ab5c}\x02\u{ab5e}\x02\u{ab67}\x02\u{ab72}\x02\u{abe4}\x02\u{ac02}\
-		\x02\u{d7a5}\x02\u{d7b2}\x02\u{d7c8}\x02\u{d7cd}\x02\u{d7fd}\x02\u{f902}\
-		\x02\u{fa6f}\x02\u{fa72}\x02\u{fadb}\x02\u{fb02}\x02\u{fb08}\x02\u{fb15}\
-		\x02\u{fb19}\x02\u{fb1f}\x02\u{fb1f}\x02\u{fb21}\x02\u{fb2a}\x02\u{fb2c}\
-		\x02\u{fb38}\x02\u{fb3a}\x02\u{fb3e}\x02\u{fb40}\x02\u{fb40}\x02\u{fb42}\
-		\x02\u{fb43}\x02\u{fb45}\x02\u{fb46}\x02\u{fb48}\x02\u{fbb3}\x02\u{fbd5}\
-		\x02\u{fd3f}\x02\u{fd52}\x02\u{fd91}\x02\u{fd94}\x02\u{fdc9}\x02\u{fdf2}\
-		\x02\u{fdfd}\x02\u{fe72}\x02\u{fe76}\x02\u{fe78}\x02\u{fefe}\x02\u{ff23}\
-		\x02\u{ff3c}\x02\u{ff43}\x02\u{ff5c}\x02\u{ff68}\x02\u{ffc0}\x02\u{ffc4}\
-		\x02\u{ffc9}\x02\u{ffcc}\x02\u{ffd1}\x02\u{ffd4}\x02\u{ffd9}\x02\u{ffdc}\
-		\x02\u{ffde}\x02\x02\x03\x0d\x03\x0f\x03\x28\x03\x2a\x03\x3c\x03\x3e\x03\
-		\x3f\x03\x41\x03\x4f\x03\x52\x03\x5f\x03\u{82}\x03\u{fc}\x03\u{142}\x03\
-		\u{176}\x03\u{282}\x03\u{29e}\x03\u{2a2}\x03\u{2d2}\x03\u{302}\x03\u{321}\
-		\x03\u{32f}\x03\u{34c}\x03\u{352}\x03\u{377}\x03\u{382}\x03\u{39f}\x03\
-		\u{3a2}\x03\u{3c5}\x03\u{3ca}\x03\u{3d1}\x03\u{3d3}\x03\u{3d7}\x03\u{402}\
-		\x03\u{49f}\x03\u{4b2}\x03\u{4d5}\x03\u{4da}\x03\u{4fd}\x03\u{502}\x03\
-		\u{529}\x03\u{532}\x03\u{565}\x03\u{602}\x03\u{738}\x03\u{742}\x03\u{757}\
-		\x03\u{762}\x03\u{769}\x03\u{802}\x03\u{807}\x03\u{80a}\x03\u{80a}\x03\
-		\u{80c}\x03\u{837}\x03\u{839}\x03\u{83a}\x03\u{83e}\x03\u{83e}\x03\u{841}\
-		\x03\u{857}\x03\u{862}\x03\u{878}\x03\u{882}\x03\u{8a0}\x03\u{8e2}\x03\
-		\u{8f4}\x03\u{8f6}\x03\u{8f7}\x03\u{902}\x03\u{917}\x03\u{922}\x03\u{93b}\
-		\x03\u{982}\x03\u{9b9}\x03\u{9c0}\x03\u{9c1}\x03\u{a02}\x03\u{a02}\x03\
-		\u{a12}\x03\u{a15}\x03\u{a17}\x03\u{a19}\x03\u{a1b}\x03\u{a35}\x03\u{a62}\
+		\x02\u{baa}\x02\u{bac}\x02\u{bb0}\x02\u{bbb}\x02\u{bc0}\x02\u{bc4}\x02\
+		\u{bc8}\x02\u{bca}\x02\u{bcc}\x02\u{bcf}\x02\u{bd2}\x02\u{bd2}\x02\u{bd9}\
+		\x02\u{bd9}\x02\u{be8}\x02\u{bf1}\x02\u{c02}\x02\u{c05}\x02\u{c07}\x02\
+		\u{c0e}\x02\u{c10}\x02\u{c12}\x02\u{c14}\x02\u{c2a}\x02\u{c2c}\x02\u{c3b}\
+		\x02\u{c3f}\x02\u{c46}\x02\u{c48}\x02\u{c4a}\x02\u{c4c}\x02\u{c4f}\x02\
+		\u{c57}\x02\u{c58}\x02\u{c5a}\x02\u{c5c}\x02\u{c62}\x02\u{c65}\x02\u{c68}\
+		\x02\u{c71}\x02\u{c82}\x02\u{c85}\x02\u{c87}\x02\u{c8e}\x02\u{c90}\x02\
+		\u{c92}\x02\u{c94}\x02\u{caa}\x02\u{cac}\x02\u{cb5}\x02\u{cb7}\x02\u{cbb}\
+		\x02\u{cbe}\x02\u{cc6}\x02\u{cc8}\x02\u{cca}\x02\u{ccc}\x02\u{ccf}\x02\
+		\u{cd7}\x02\u{cd8}\x02\u{ce0}\x02\u{ce0}\x02\u{ce2}\x02\u{ce5}\x02\u{ce8}\
+		\x02\u{cf1}\x02\u{cf3}\x02\u{cf4}\x02\u{d02}\x02\u{d05}\x02\u{d07}\x02\
+		\u{d0e}\x02\u{d10}\x02\u{d12}\x02\u{d14}\x02\u{d46}\x02\u{d48}\x02\u{d4a}\
+		\x02\u{d4c}\x02\u{d50}\x02\u{d56}\x02\u{d59}\x02\u{d61}\x02\u{d65}\x02\
+		\u{d68}\x02\u{d71}\x02\u{d7c}\x02\u{d81}\x02\u{d84}\x02\u{d85}\x02\u{d87}\
+		\x02\u{d98}\x02\u{d9c}\x02\u{db3}\x02\u{db5}\x02\u{dbd}\x02\u{dbf}\x02\
+		\u{dbf}\x02\u{dc2}\x02\u{dc8}\x02\u{dcc}\x02\u{dcc}\x02\u{dd1}\x02\u{dd6}\
+		\x02\u{dd8}\x02\u{dd8}\x02\u{dda}\x02\u{de1}\x02\u{de8}\x02\u{df1}\x02\
+		\u{df4}\x02\u{df5}\x02\u{e03}\x02\u{e3c}\x02\u{e42}\x02\u{e50}\x02\u{e52}\
+		\x02\u{e5b}\x02\u{e83}\x02\u{e84}\x02\u{e86}\x02\u{e86}\x02\u{e89}\x02\
+		\u{e8a}\x02\u{e8c}\x02\u{e8c}\x02\u{e8f}\x02\u{e8f}\x02\u{e96}\x02\u{e99}\
+		\x02\u{e9b}\x02\u{ea1}\x02\u{ea3}\x02\u{ea5}\x02\u{ea7}\x02\u{ea7}\x02\
+		\u{ea9}\x02\u{ea9}\x02\u{eac}\x02\u{ead}\x02\u{eaf}\x02\u{ebb}\x02\u{ebd}\
+		\x02\u{ebf}\x02\u{ec2}\x02\u{ec6}\x02\u{ec8}\x02\u{ec8}\x02\u{eca}\x02\
+		\u{ecf}\x02\u{ed2}\x02\u{edb}\x02\u{ede}\x02\u{ee1}\x02\u{f02}\x02\u{f02}\
+		\x02\u{f1a}\x02\u{f1b}\x02\u{f22}\x02\u{f2b}\x02\u{f37}\x02\u{f37}\x02\
+		\u{f39}\x02\u{f39}\x02\u{f3b}\x02\u{f3b}\x02\u{f40}\x02\u{f49}\x02\u{f4b}\
+		\x02\u{f6e}\x02\u{f73}\x02\u{f86}\x02\u{f88}\x02\u{f99}\x02\u{f9b}\x02\
+		\u{fbe}\x02\u{fc8}\x02\u{fc8}\x02\u{1002}\x02\u{104b}\x02\u{1052}\x02\u{109f}\
+		\x02\u{10a2}\x02\u{10c7}\x02\u{10c9}\x02\u{10c9}\x02\u{10cf}\x02\u{10cf}\
+		\x02\u{10d2}\x02\u{10fc}\x02\u{10fe}\x02\u{124a}\x02\u{124c}\x02\u{124f}\
+		\x02\u{1252}\x02\u{1258}\x02\u{125a}\x02\u{125a}\x02\u{125c}\x02\u{125f}\
+		\x02\u{1262}\x02\u{128a}\x02\u{128c}\x02\u{128f}\x02\u{1292}\x02\u{12b2}\
+		\x02\u{12b4}\x02\u{12b7}\x02\u{12ba}\x02\u{12c0}\x02\u{12c2}\x02\u{12c2}\
+		\x02\u{12c4}\x02\u{12c7}\x02\u{12ca}\x02\u{12d8}\x02\u{12da}\x02\u{1312}\
+		\x02\u{1314}\x02\u{1317}\x02\u{131a}\x02\u{135c}\x02\u{135f}\x02\u{1361}\
+		\x02\u{136b}\x02\u{1373}\x02\u{1382}\x02\u{1391}\x02\u{13a2}\x02\u{13f7}\
+		\x02\u{13fa}\x02\u{13ff}\x02\u{1403}\x02\u{166e}\x02\u{1671}\x02\u{1681}\
+		\x02\u{1683}\x02\u{169c}\x02\u{16a2}\x02\u{16ec}\x02\u{16f0}\x02\u{16fa}\
+		\x02\u{1702}\x02\u{170e}\x02\u{1710}\x02\u{1716}\x02\u{1722}\x02\u{1736}\
+		\x02\u{1742}\x02\u{1755}\x02\u{1762}\x02\u{176e}\x02\u{1770}\x02\u{1772}\
+		\x02\u{1774}\x02\u{1775}\x02\u{1782}\x02\u{17d5}\x02\u{17d9}\x02\u{17d9}\
+		\x02\u{17de}\x02\u{17df}\x02\u{17e2}\x02\u{17eb}\x02\u{180d}\x02\u{180f}\
+		\x02\u{1812}\x02\u{181b}\x02\u{1822}\x02\u{1879}\x02\u{1882}\x02\u{18ac}\
+		\x02\u{18b2}\x02\u{18f7}\x02\u{1902}\x02\u{1920}\x02\u{1922}\x02\u{192d}\
+		\x02\u{1932}\x02\u{193d}\x02\u{1948}\x02\u{196f}\x02\u{1972}\x02\u{1976}\
+		\x02\u{1982}\x02\u{19ad}\x02\u{19b2}\x02\u{19cb}\x02\u{19d2}\x02\u{19dc}\
+		\x02\u{1a02}\x02\u{1a1d}\x02\u{1a22}\x02\u{1a60}\x02\u{1a62}\x02\u{1a7e}\
+		\x02\u{1a81}\x02\u{1a8b}\x02\u{1a92}\x02\u{1a9b}\x02\u{1aa9}\x02\u{1aa9}\
+		\x02\u{1ab2}\x02\u{1abf}\x02\u{1b02}\x02\u{1b4d}\x02\u{1b52}\x02\u{1b5b}\
+		\x02\u{1b6d}\x02\u{1b75}\x02\u{1b82}\x02\u{1bf5}\x02\u{1c02}\x02\u{1c39}\
+		\x02\u{1c42}\x02\u{1c4b}\x02\u{1c4f}\x02\u{1c7f}\x02\u{1c82}\x02\u{1c8a}\
+		\x02\u{1cd2}\x02\u{1cd4}\x02\u{1cd6}\x02\u{1cfb}\x02\u{1d02}\x02\u{1dfb}\
+		\x02\u{1dfd}\x02\u{1f17}\x02\u{1f1a}\x02\u{1f1f}\x02\u{1f22}\x02\u{1f47}\
+		\x02\u{1f4a}\x02\u{1f4f}\x02\u{1f52}\x02\u{1f59}\x02\u{1f5b}\x02\u{1f5b}\
+		\x02\u{1f5d}\x02\u{1f5d}\x02\u{1f5f}\x02\u{1f5f}\x02\u{1f61}\x02\u{1f7f}\
+		\x02\u{1f82}\x02\u{1fb6}\x02\u{1fb8}\x02\u{1fbe}\x02\u{1fc0}\x02\u{1fc0}\
+		\x02\u{1fc4}\x02\u{1fc6}\x02\u{1fc8}\x02\u{1fce}\x02\u{1fd2}\x02\u{1fd5}\
+		\x02\u{1fd8}\x02\u{1fdd}\x02\u{1fe2}\x02\u{1fee}\x02\u{1ff4}\x02\u{1ff6}\
+		\x02\u{1ff8}\x02\u{1ffe}\x02\u{2041}\x02\u{2042}\x02\u{2056}\x02\u{2056}\
+		\x02\u{2073}\x02\u{2073}\x02\u{2081}\x02\u{2081}\x02\u{2092}\x02\u{209e}\
+		\x02\u{20d2}\x02\u{20de}\x02\u{20e3}\x02\u{20e3}\x02\u{20e7}\x02\u{20f2}\
+		\x02\u{2104}\x02\u{2104}\x02\u{2109}\x02\u{2109}\x02\u{210c}\x02\u{2115}\
+		\x02\u{2117}\x02\u{2117}\x02\u{211a}\x02\u{211f}\x02\u{2126}\x02\u{2126}\
+		\x02\u{2128}\x02\u{2128}\x02\u{212a}\x02\u{212a}\x02\u{212c}\x02\u{213b}\
+		\x02\u{213e}\x02\u{2141}\x02\u{2147}\x02\u{214b}\x02\u{2150}\x02\u{2150}\
+		\x02\u{2162}\x02\u{218a}\x02\u{2c02}\x02\u{2c30}\x02\u{2c32}\x02\u{2c60}\
+		\x02\u{2c62}\x02\u{2ce6}\x02\u{2ced}\x02\u{2cf5}\x02\u{2d02}\x02\u{2d27}\
+		\x02\u{2d29}\x02\u{2d29}\x02\u{2d2f}\x02\u{2d2f}\x02\u{2d32}\x02\u{2d69}\
+		\x02\u{2d71}\x02\u{2d71}\x02\u{2d81}\x02\u{2d98}\x02\u{2da2}\x02\u{2da8}\
+		\x02\u{2daa}\x02\u{2db0}\x02\u{2db2}\x02\u{2db8}\x02\u{2dba}\x02\u{2dc0}\
+		\x02\u{2dc2}\x02\u{2dc8}\x02\u{2dca}\x02\u{2dd0}\x02\u{2dd2}\x02\u{2dd8}\
+		\x02\u{2dda}\x02\u{2de0}\x02\u{2de2}\x02\u{2e01}\x02\u{3007}\x02\u{3009}\
+		\x02\u{3023}\x02\u{3031}\x02\u{3033}\x02\u{3037}\x02\u{303a}\x02\u{303e}\
+		\x02\u{3043}\x02\u{3098}\x02\u{309b}\x02\u{30a1}\x02\u{30a3}\x02\u{30fc}\
+		\x02\u{30fe}\x02\u{3101}\x02\u{3107}\x02\u{3130}\x02\u{3133}\x02\u{3190}\
+		\x02\u{31a2}\x02\u{31bc}\x02\u{31f2}\x02\u{3201}\x02\u{3402}\x02\u{4db7}\
+		\x02\u{4e02}\x02\u{9fec}\x02\u{a002}\x02\u{a48e}\x02\u{a4d2}\x02\u{a4ff}\
+		\x02\u{a502}\x02\u{a60e}\x02\u{a612}\x02\u{a62d}\x02\u{a642}\x02\u{a671}\
+		\x02\u{a676}\x02\u{a67f}\x02\u{a681}\x02\u{a6f3}\x02\u{a719}\x02\u{a721}\
+		\x02\u{a724}\x02\u{a78a}\x02\u{a78d}\x02\u{a7b0}\x02\u{a7b2}\x02\u{a7b9}\
+		\x02\u{a7f9}\x02\u{a829}\x02\u{a842}\x02\u{a875}\x02\u{a882}\x02\u{a8c7}\
+		\x02\u{a8d2}\x02\u{a8db}\x02\u{a8e2}\x02\u{a8f9}\x02\u{a8fd}\x02\u{a8fd}\
+		\x02\u{a8ff}\x02\u{a8ff}\x02\u{a902}\x02\u{a92f}\x02\u{a932}\x02\u{a955}\
+		\x02\u{a962}\x02\u{a97e}\x02\u{a982}\x02\u{a9c2}\x02\u{a9d1}\x02\u{a9db}\
+		\x02\u{a9e2}\x02\u{aa00}\x02\u{aa02}\x02\u{aa38}\x02\u{aa42}\x02\u{aa4f}\
+		\x02\u{aa52}\x02\u{aa5b}\x02\u{aa62}\x02\u{aa78}\x02\u{aa7c}\x02\u{aac4}\
+		\x02\u{aadd}\x02\u{aadf}\x02\u{aae2}\x02\u{aaf1}\x02\u{aaf4}\x02\u{aaf8}\
+		\x02\u{ab03}\x02\u{ab08}\x02\u{ab0b}\x02\u{ab10}\x02\u{ab13}\x02\u{ab18}\
+		\x02\u{ab22}\x02\u{ab28}\x02\u{ab2a}\x02\u{ab30}\x02\u{ab32}\x02\u{ab5c}\
+		\x02\u{ab5e}\x02\u{ab67}\x02\u{ab72}\x02\u{abec}\x02\u{abee}\x02\u{abef}\
+		\x02\u{abf2}\x02\u{abfb}\x02\u{ac02}\x02\u{d7a5}\x02\u{d7b2}\x02\u{d7c8}\
+		\x02\u{d7cd}\x02\u{d7fd}\x02\u{f902}\x02\u{fa6f}\x02\u{fa72}\x02\u{fadb}\
+		\x02\u{fb02}\x02\u{fb08}\x02\u{fb15}\x02\u{fb19}\x02\u{fb1f}\x02\u{fb2a}\
+		\x02\u{fb2c}\x02\u{fb38}\x02\u{fb3a}\x02\u{fb3e}\x02\u{fb40}\x02\u{fb40}\
+		\x02\u{fb42}\x02\u{fb43}\x02\u{fb45}\x02\u{fb46}\x02\u{fb48}\x02\u{fbb3}\
+		\x02\u{fbd5}\x02\u{fd3f}\x02\u{fd52}\x02\u{fd91}\x02\u{fd94}\x02\u{fdc9}\
+		\x02\u{fdf2}\x02\u{fdfd}\x02\u{fe02}\x02\u{fe11}\x02\u{fe22}\x02\u{fe31}\
+		\x02\u{fe35}\x02\u{fe36}\x02\u{fe4f}\x02\u{fe51}\x02\u{fe72}\x02\u{fe76}\
+		\x02\u{fe78}\x02\u{fefe}\x02\u{ff12}\x02\u{ff1b}\x02\u{ff23}\x02\u{ff3c}\
+		\x02\u{ff41}\x02\u{ff41}\x02\u{ff43}\x02\u{ff5c}\x02\u{ff68}\x02\u{ffc0}\
+		\x02\u{ffc4}\x02\u{ffc9}\x02\u{ffcc}\x02\u{ffd1}\x02\u{ffd4}\x02\u{ffd9}\
+		\x02\u{ffdc}\x02\u{ffde}\x02\x02\x03\x0d\x03\x0f\x03\x28\x03\x2a\x03\x3c\
+		\x03\x3e\x03\x3f\x03\x41\x03\x4f\x03\x52\x03\x5f\x03\u{82}\x03\u{fc}\x03\
+		\u{142}\x03\u{176}\x03\u{1ff}\x03\u{1ff}\x03\u{282}\x03\u{29e}\x03\u{2a2}\
+		\x03\u{2d2}\x03\u{2e2}\x03\u{2e2}\x03\u{302}\x03\u{321}\x03\u{32f}\x03\
+		\u{34c}\x03\u{352}\x03\u{37c}\x03\u{382}\x03\u{39f}\x03\u{3a2}\x03\u{3c5}\
+		\x03\u{3ca}\x03\u{3d1}\x03\u{3d3}\x03\u{3d7}\x03\u{402}\x03\u{49f}\x03\
+		\u{4a2}\x03\u{4ab}\x03\u{4b2}\x03\u{4d5}\x03\u{4da}\x03\u{4fd}\x03\u{502}\
+		\x03\u{529}\x03\u{532}\x03\u{565}\x03\u{602}\x03\u{738}\x03\u{742}\x03\
+		\u{757}\x03\u{762}\x03\u{769}\x03\u{802}\x03\u{807}\x03\u{80a}\x03\u{80a}\
+		\x03\u{80c}\x03\u{837}\x03\u{839}\x03\u{83a}\x03\u{83e}\x03\u{83e}\x03\
+		\u{841}\x03\u{857}\x03\u{862}\x03\u{878}\x03\u{882}\x03\u{8a0}\x03\u{8e2}\
+		\x03\u{8f4}\x03\u{8f6}\x03\u{8f7}\x03\u{902}\x03\u{917}\x03\u{922}\x03\
+		\u{93b}\x03\u{982}\x03\u{9b9}\x03\u{9c0}\x03\u{9c1}\x03\u{a02}\x03\u{a05}\
+		\x03\u{a07}\x03\u{a08}\x03\u{a0e}\x03\u{a15}\x03\u{a17}\x03\u{a19}\x03\
+		\u{a1b}\x03\u{a35}\x03\u{a3a}\x03\u{a3c}\x03\u{a41}\x03\u{a41}\x03\u{a62}\
 		\x03\u{a7e}\x03\u{a82}\x03\u{a9e}\x03\u{ac2}\x03\u{ac9}\x03\u{acb}\x03\
-		\u{ae6}\x03\u{b02}\x03\u{b37}\x03\u{b42}\x03\u{b57}\x03\u{b62}\x03\u{b74}\
+		\u{ae8}\x03\u{b02}\x03\u{b37}\x03\u{b42}\x03\u{b57}\x03\u{b62}\x03\u{b74}\
 		\x03\u{b82}\x03\u{b93}\x03\u{c02}\x03\u{c4a}\x03\u{c82}\x03\u{cb4}\x03\
-		\u{cc2}\x03\u{cf4}\x03\u{1005}\x03\u{1039}\x03\u{1085}\x03\u{10b1}\x03\
-		\u{10d2}\x03\u{10ea}\x03\u{1105}\x03\u{1128}\x03\u{1152}\x03\u{1174}\x03\
-		\u{1178}\x03\u{1178}\x03\u{1185}\x03\u{11b4}\x03\u{11c3}\x03\u{11c6}\x03\
-		\u{11dc}\x03\u{11dc}\x03\u{11de}\x03\u{11de}\x03\u{1202}\x03\u{1213}\x03\
-		\u{1215}\x03\u{122d}\x03\u{1282}\x03\u{1288}\x03\u{128a}\x03\u{128a}\x03\
-		\u{128c}\x03\u{128f}\x03\u{1291}\x03\u{129f}\x03\u{12a1}\x03\u{12aa}\x03\
-		\u{12b2}\x03\u{12e0}\x03\u{1307}\x03\u{130e}\x03\u{1311}\x03\u{1312}\x03\
+		\u{cc2}\x03\u{cf4}\x03\u{1002}\x03\u{1048}\x03\u{1068}\x03\u{1071}\x03\
+		\u{1081}\x03\u{10bc}\x03\u{10d2}\x03\u{10ea}\x03\u{10f2}\x03\u{10fb}\x03\
+		\u{1102}\x03\u{1136}\x03\u{1138}\x03\u{1141}\x03\u{1152}\x03\u{1175}\x03\
+		\u{1178}\x03\u{1178}\x03\u{1182}\x03\u{11c6}\x03\u{11cc}\x03\u{11ce}\x03\
+		\u{11d2}\x03\u{11dc}\x03\u{11de}\x03\u{11de}\x03\u{1202}\x03\u{1213}\x03\
+		\u{1215}\x03\u{1239}\x03\u{1240}\x03\u{1240}\x03\u{1282}\x03\u{1288}\x03\
+		\u{128a}\x03\u{128a}\x03\u{128c}\x03\u{128f}\x03\u{1291}\x03\u{129f}\x03\
+		\u{12a1}\x03\u{12aa}\x03\u{12b2}\x03\u{12ec}\x03\u{12f2}\x03\u{12fb}\x03\
+		\u{1302}\x03\u{1305}\x03\u{1307}\x03\u{130e}\x03\u{1311}\x03\u{1312}\x03\
 		\u{1315}\x03\u{132a}\x03\u{132c}\x03\u{1332}\x03\u{1334}\x03\u{1335}\x03\
-		\u{1337}\x03\u{133b}\x03\u{133f}\x03\u{133f}\x03\u{1352}\x03\u{1352}\x03\
-		\u{135f}\x03\u{1363}\x03\u{1402}\x03\u{1436}\x03\u{1449}\x03\u{144c}\x03\
-		\u{1482}\x03\u{14b1}\x03\u{14c6}\x03\u{14c7}\x03\u{14c9}\x03\u{14c9}\x03\
-		\u{1582}\x03\u{15b0}\x03\u{15da}\x03\u{15dd}\x03\u{1602}\x03\u{1631}\x03\
-		\u{1646}\x03\u{1646}\x03\u{1682}\x03\u{16ac}\x03\u{1702}\x03\u{171b}\x03\
-		\u{18a2}\x03\u{18e1}\x03\u{1901}\x03\u{1901}\x03\u{1a02}\x03\u{1a02}\x03\
-		\u{1a0d}\x03\u{1a34}\x03\u{1a3c}\x03\u{1a3c}\x03\u{1a52}\x03\u{1a52}\x03\
-		\u{1a5e}\x03\u{1a85}\x03\u{1a88}\x03\u{1a8b}\x03\u{1ac2}\x03\u{1afa}\x03\
-		\u{1c02}\x03\u{1c0a}\x03\u{1c0c}\x03\u{1c30}\x03\u{1c42}\x03\u{1c42}\x03\
-		\u{1c74}\x03\u{1c91}\x03\u{1d02}\x03\u{1d08}\x03\u{1d0a}\x03\u{1d0b}\x03\
-		\u{1d0d}\x03\u{1d32}\x03\u{1d48}\x03\u{1d48}\x03\u{2002}\x03\u{239b}\x03\
-		\u{2402}\x03\u{2470}\x03\u{2482}\x03\u{2545}\x03\u{3002}\x03\u{3430}\x03\
-		\u{4402}\x03\u{4648}\x03\u{6802}\x03\u{6a3a}\x03\u{6a42}\x03\u{6a60}\x03\
-		\u{6ad2}\x03\u{6aef}\x03\u{6b02}\x03\u{6b31}\x03\u{6b42}\x03\u{6b45}\x03\
-		\u{6b65}\x03\u{6b79}\x03\u{6b7f}\x03\u{6b91}\x03\u{6f02}\x03\u{6f46}\x03\
-		\u{6f52}\x03\u{6f52}\x03\u{6f95}\x03\u{6fa1}\x03\u{6fe2}\x03\u{6fe3}\x03\
-		\u{7002}\x03\u{87ee}\x03\u{8802}\x03\u{8af4}\x03\u{b002}\x03\u{b120}\x03\
-		\u{b172}\x03\u{b2fd}\x03\u{bc02}\x03\u{bc6c}\x03\u{bc72}\x03\u{bc7e}\x03\
-		\u{bc82}\x03\u{bc8a}\x03\u{bc92}\x03\u{bc9b}\x03\u{d402}\x03\u{d456}\x03\
+		\u{1337}\x03\u{133b}\x03\u{133e}\x03\u{1346}\x03\u{1349}\x03\u{134a}\x03\
+		\u{134d}\x03\u{134f}\x03\u{1352}\x03\u{1352}\x03\u{1359}\x03\u{1359}\x03\
+		\u{135f}\x03\u{1365}\x03\u{1368}\x03\u{136e}\x03\u{1372}\x03\u{1376}\x03\
+		\u{1402}\x03\u{144c}\x03\u{1452}\x03\u{145b}\x03\u{1482}\x03\u{14c7}\x03\
+		\u{14c9}\x03\u{14c9}\x03\u{14d2}\x03\u{14db}\x03\u{1582}\x03\u{15b7}\x03\
+		\u{15ba}\x03\u{15c2}\x03\u{15da}\x03\u{15df}\x03\u{1602}\x03\u{1642}\x03\
+		\u{1646}\x03\u{1646}\x03\u{1652}\x03\u{165b}\x03\u{1682}\x03\u{16b9}\x03\
+		\u{16c2}\x03\u{16cb}\x03\u{1702}\x03\u{171b}\x03\u{171f}\x03\u{172d}\x03\
+		\u{1732}\x03\u{173b}\x03\u{18a2}\x03\u{18eb}\x03\u{1901}\x03\u{1901}\x03\
+		\u{1a02}\x03\u{1a40}\x03\u{1a49}\x03\u{1a49}\x03\u{1a52}\x03\u{1a85}\x03\
+		\u{1a88}\x03\u{1a9b}\x03\u{1ac2}\x03\u{1afa}\x03\u{1c02}\x03\u{1c0a}\x03\
+		\u{1c0c}\x03\u{1c38}\x03\u{1c3a}\x03\u{1c42}\x03\u{1c52}\x03\u{1c5b}\x03\
+		\u{1c74}\x03\u{1c91}\x03\u{1c94}\x03\u{1ca9}\x03\u{1cab}\x03\u{1cb8}\x03\
+		\u{1d02}\x03\u{1d08}\x03\u{1d0a}\x03\u{1d0b}\x03\u{1d0d}\x03\u{1d38}\x03\
+		\u{1d3c}\x03\u{1d3c}\x03\u{1d3e}\x03\u{1d3f}\x03\u{1d41}\x03\u{1d49}\x03\
+		\u{1d52}\x03\u{1d5b}\x03\u{2002}\x03\u{239b}\x03\u{2402}\x03\u{2470}\x03\
+		\u{2482}\x03\u{2545}\x03\u{3002}\x03\u{3430}\x03\u{4402}\x03\u{4648}\x03\
+		\u{6802}\x03\u{6a3a}\x03\u{6a42}\x03\u{6a60}\x03\u{6a62}\x03\u{6a6b}\x03\
+		\u{6ad2}\x03\u{6aef}\x03\u{6af2}\x03\u{6af6}\x03\u{6b02}\x03\u{6b38}\x03\
+		\u{6b42}\x03\u{6b45}\x03\u{6b52}\x03\u{6b5b}\x03\u{6b65}\x03\u{6b79}\x03\
+		\u{6b7f}\x03\u{6b91}\x03\u{6f02}\x03\u{6f46}\x03\u{6f52}\x03\u{6f80}\x03\
+		\u{6f91}\x03\u{6fa1}\x03\u{6fe2}\x03\u{6fe3}\x03\u{7002}\x03\u{87ee}\x03\
+		\u{8802}\x03\u{8af4}\x03\u{b002}\x03\u{b120}\x03\u{b172}\x03\u{b2fd}\x03\
+		\u{bc02}\x03\u{bc6c}\x03\u{bc72}\x03\u{bc7e}\x03\u{bc82}\x03\u{bc8a}\x03\
+		\u{bc92}\x03\u{bc9b}\x03\u{bc9f}\x03\u{bca0}\x03\u{d167}\x03\u{d16b}\x03\
+		\u{d16f}\x03\u{d174}\x03\u{d17d}\x03\u{d184}\x03\u{d187}\x03\u{d18d}\x03\
+		\u{d1ac}\x03\u{d1af}\x03\u{d244}\x03\u{d246}\x03\u{d402}\x03\u{d456}\x03\
 		\u{d458}\x03\u{d49e}\x03\u{d4a0}\x03\u{d4a1}\x03\u{d4a4}\x03\u{d4a4}\x03\
 		\u{d4a7}\x03\u{d4a8}\x03\u{d4ab}\x03\u{d4ae}\x03\u{d4b0}\x03\u{d4bb}\x03\
 		\u{d4bd}\x03\u{d4bd}\x03\u{d4bf}\x03\u{d4c5}\x03\u{d4c7}\x03\u{d507}\x03\
@@ -1247,433 +1059,629 @@ const _serializedATN: &'static str =
 		\u{d6aa}\x03\u{d6c2}\x03\u{d6c4}\x03\u{d6dc}\x03\u{d6de}\x03\u{d6fc}\x03\
 		\u{d6fe}\x03\u{d716}\x03\u{d718}\x03\u{d736}\x03\u{d738}\x03\u{d750}\x03\
 		\u{d752}\x03\u{d770}\x03\u{d772}\x03\u{d78a}\x03\u{d78c}\x03\u{d7aa}\x03\
-		\u{d7ac}\x03\u{d7c4}\x03\u{d7c6}\x03\u{d7cd}\x03\u{e802}\x03\u{e8c6}\x03\
-		\u{e902}\x03\u{e945}\x03\u{ee02}\x03\u{ee05}\x03\u{ee07}\x03\u{ee21}\x03\
-		\u{ee23}\x03\u{ee24}\x03\u{ee26}\x03\u{ee26}\x03\u{ee29}\x03\u{ee29}\x03\
-		\u{ee2b}\x03\u{ee34}\x03\u{ee36}\x03\u{ee39}\x03\u{ee3b}\x03\u{ee3b}\x03\
-		\u{ee3d}\x03\u{ee3d}\x03\u{ee44}\x03\u{ee44}\x03\u{ee49}\x03\u{ee49}\x03\
-		\u{ee4b}\x03\u{ee4b}\x03\u{ee4d}\x03\u{ee4d}\x03\u{ee4f}\x03\u{ee51}\x03\
-		\u{ee53}\x03\u{ee54}\x03\u{ee56}\x03\u{ee56}\x03\u{ee59}\x03\u{ee59}\x03\
-		\u{ee5b}\x03\u{ee5b}\x03\u{ee5d}\x03\u{ee5d}\x03\u{ee5f}\x03\u{ee5f}\x03\
-		\u{ee61}\x03\u{ee61}\x03\u{ee63}\x03\u{ee64}\x03\u{ee66}\x03\u{ee66}\x03\
-		\u{ee69}\x03\u{ee6c}\x03\u{ee6e}\x03\u{ee74}\x03\u{ee76}\x03\u{ee79}\x03\
-		\u{ee7b}\x03\u{ee7e}\x03\u{ee80}\x03\u{ee80}\x03\u{ee82}\x03\u{ee8b}\x03\
-		\u{ee8d}\x03\u{ee9d}\x03\u{eea3}\x03\u{eea5}\x03\u{eea7}\x03\u{eeab}\x03\
-		\u{eead}\x03\u{eebd}\x03\x02\x04\u{a6d8}\x04\u{a702}\x04\u{b736}\x04\u{b742}\
-		\x04\u{b81f}\x04\u{b822}\x04\u{cea3}\x04\u{ceb2}\x04\u{ebe2}\x04\u{f802}\
-		\x04\u{fa1f}\x04\u{3fc}\x02\x03\x03\x02\x02\x02\x02\x05\x03\x02\x02\x02\
-		\x02\x07\x03\x02\x02\x02\x02\x09\x03\x02\x02\x02\x02\x0b\x03\x02\x02\x02\
-		\x02\x0d\x03\x02\x02\x02\x02\x0f\x03\x02\x02\x02\x02\x11\x03\x02\x02\x02\
-		\x02\x13\x03\x02\x02\x02\x02\x15\x03\x02\x02\x02\x02\x17\x03\x02\x02\x02\
-		\x02\x19\x03\x02\x02\x02\x02\x1b\x03\x02\x02\x02\x02\x1d\x03\x02\x02\x02\
-		\x02\x1f\x03\x02\x02\x02\x02\x21\x03\x02\x02\x02\x02\x23\x03\x02\x02\x02\
-		\x02\x25\x03\x02\x02\x02\x02\x27\x03\x02\x02\x02\x02\x29\x03\x02\x02\x02\
-		\x02\x2b\x03\x02\x02\x02\x02\x2d\x03\x02\x02\x02\x02\x2f\x03\x02\x02\x02\
-		\x02\x31\x03\x02\x02\x02\x02\x33\x03\x02\x02\x02\x02\x35\x03\x02\x02\x02\
-		\x02\x37\x03\x02\x02\x02\x02\x39\x03\x02\x02\x02\x02\x3b\x03\x02\x02\x02\
-		\x02\x3d\x03\x02\x02\x02\x02\x3f\x03\x02\x02\x02\x02\x41\x03\x02\x02\x02\
-		\x02\x43\x03\x02\x02\x02\x02\x45\x03\x02\x02\x02\x02\x47\x03\x02\x02\x02\
-		\x02\x49\x03\x02\x02\x02\x02\x4b\x03\x02\x02\x02\x02\x4d\x03\x02\x02\x02\
-		\x02\x4f\x03\x02\x02\x02\x02\x51\x03\x02\x02\x02\x02\x53\x03\x02\x02\x02\
-		\x02\x55\x03\x02\x02\x02\x02\x57\x03\x02\x02\x02\x02\x59\x03\x02\x02\x02\
-		\x02\x5b\x03\x02\x02\x02\x02\x5d\x03\x02\x02\x02\x02\x5f\x03\x02\x02\x02\
-		\x02\x61\x03\x02\x02\x02\x02\x63\x03\x02\x02\x02\x02\x65\x03\x02\x02\x02\
-		\x02\x67\x03\x02\x02\x02\x02\x69\x03\x02\x02\x02\x02\x6b\x03\x02\x02\x02\
-		\x02\x6d\x03\x02\x02\x02\x02\x6f\x03\x02\x02\x02\x02\x71\x03\x02\x02\x02\
-		\x02\x73\x03\x02\x02\x02\x02\x75\x03\x02\x02\x02\x02\x77\x03\x02\x02\x02\
-		\x02\x79\x03\x02\x02\x02\x02\x7b\x03\x02\x02\x02\x02\x7d\x03\x02\x02\x02\
-		\x02\x7f\x03\x02\x02\x02\x02\u{81}\x03\x02\x02\x02\x02\u{83}\x03\x02\x02\
-		\x02\x02\u{85}\x03\x02\x02\x02\x02\u{87}\x03\x02\x02\x02\x02\u{89}\x03\
-		\x02\x02\x02\x02\u{8b}\x03\x02\x02\x02\x02\u{8d}\x03\x02\x02\x02\x02\u{8f}\
-		\x03\x02\x02\x02\x02\u{91}\x03\x02\x02\x02\x02\u{93}\x03\x02\x02\x02\x02\
-		\u{95}\x03\x02\x02\x02\x02\u{97}\x03\x02\x02\x02\x02\u{99}\x03\x02\x02\
-		\x02\x02\u{9b}\x03\x02\x02\x02\x02\u{9d}\x03\x02\x02\x02\x02\u{9f}\x03\
-		\x02\x02\x02\x02\u{a1}\x03\x02\x02\x02\x02\u{a3}\x03\x02\x02\x02\x02\u{a5}\
-		\x03\x02\x02\x02\x02\u{a7}\x03\x02\x02\x02\x02\u{a9}\x03\x02\x02\x02\x02\
-		\u{ab}\x03\x02\x02\x02\x02\u{ad}\x03\x02\x02\x02\x02\u{af}\x03\x02\x02\
-		\x02\x02\u{b1}\x03\x02\x02\x02\x02\u{b3}\x03\x02\x02\x02\x02\u{b5}\x03\
-		\x02\x02\x02\x02\u{b7}\x03\x02\x02\x02\x02\u{b9}\x03\x02\x02\x02\x02\u{bb}\
-		\x03\x02\x02\x02\x02\u{bd}\x03\x02\x02\x02\x02\u{bf}\x03\x02\x02\x02\x02\
-		\u{c1}\x03\x02\x02\x02\x02\u{c3}\x03\x02\x02\x02\x02\u{c5}\x03\x02\x02\
-		\x02\x02\u{c7}\x03\x02\x02\x02\x02\u{c9}\x03\x02\x02\x02\x02\u{cb}\x03\
-		\x02\x02\x02\x02\u{cd}\x03\x02\x02\x02\x02\u{cf}\x03\x02\x02\x02\x02\u{d1}\
-		\x03\x02\x02\x02\x02\u{d3}\x03\x02\x02\x02\x02\u{d5}\x03\x02\x02\x02\x02\
-		\u{d7}\x03\x02\x02\x02\x02\u{d9}\x03\x02\x02\x02\x02\u{db}\x03\x02\x02\
-		\x02\x02\u{dd}\x03\x02\x02\x02\x02\u{df}\x03\x02\x02\x02\x02\u{e1}\x03\
-		\x02\x02\x02\x02\u{e3}\x03\x02\x02\x02\x02\u{e5}\x03\x02\x02\x02\x02\u{e7}\
-		\x03\x02\x02\x02\x02\u{e9}\x03\x02\x02\x02\x02\u{eb}\x03\x02\x02\x02\x02\
-		\u{ed}\x03\x02\x02\x02\x02\u{ef}\x03\x02\x02\x02\x02\u{f1}\x03\x02\x02\
-		\x02\x02\u{f3}\x03\x02\x02\x02\x02\u{f5}\x03\x02\x02\x02\x02\u{f7}\x03\
-		\x02\x02\x02\x02\u{f9}\x03\x02\x02\x02\x02\u{fb}\x03\x02\x02\x02\x02\u{fd}\
-		\x03\x02\x02\x02\x02\u{ff}\x03\x02\x02\x02\x03\u{129}\x03\x02\x02\x02\x05\
-		\u{12b}\x03\x02\x02\x02\x07\u{12d}\x03\x02\x02\x02\x09\u{12f}\x03\x02\x02\
-		\x02\x0b\u{132}\x03\x02\x02\x02\x0d\u{134}\x03\x02\x02\x02\x0f\u{136}\x03\
-		\x02\x02\x02\x11\u{138}\x03\x02\x02\x02\x13\u{13a}\x03\x02\x02\x02\x15\
-		\u{13c}\x03\x02\x02\x02\x17\u{13e}\x03\x02\x02\x02\x19\u{140}\x03\x02\x02\
-		\x02\x1b\u{143}\x03\x02\x02\x02\x1d\u{145}\x03\x02\x02\x02\x1f\u{147}\x03\
-		\x02\x02\x02\x21\u{149}\x03\x02\x02\x02\x23\u{14b}\x03\x02\x02\x02\x25\
-		\u{14d}\x03\x02\x02\x02\x27\u{150}\x03\x02\x02\x02\x29\u{152}\x03\x02\x02\
-		\x02\x2b\u{154}\x03\x02\x02\x02\x2d\u{157}\x03\x02\x02\x02\x2f\u{15a}\x03\
-		\x02\x02\x02\x31\u{15c}\x03\x02\x02\x02\x33\u{15e}\x03\x02\x02\x02\x35\
-		\u{160}\x03\x02\x02\x02\x37\u{162}\x03\x02\x02\x02\x39\u{164}\x03\x02\x02\
-		\x02\x3b\u{166}\x03\x02\x02\x02\x3d\u{168}\x03\x02\x02\x02\x3f\u{16a}\x03\
-		\x02\x02\x02\x41\u{16c}\x03\x02\x02\x02\x43\u{16e}\x03\x02\x02\x02\x45\
-		\u{170}\x03\x02\x02\x02\x47\u{172}\x03\x02\x02\x02\x49\u{174}\x03\x02\x02\
-		\x02\x4b\u{176}\x03\x02\x02\x02\x4d\u{178}\x03\x02\x02\x02\x4f\u{17a}\x03\
-		\x02\x02\x02\x51\u{17c}\x03\x02\x02\x02\x53\u{17e}\x03\x02\x02\x02\x55\
-		\u{180}\x03\x02\x02\x02\x57\u{182}\x03\x02\x02\x02\x59\u{184}\x03\x02\x02\
-		\x02\x5b\u{186}\x03\x02\x02\x02\x5d\u{188}\x03\x02\x02\x02\x5f\u{18e}\x03\
-		\x02\x02\x02\x61\u{192}\x03\x02\x02\x02\x63\u{19b}\x03\x02\x02\x02\x65\
-		\u{1a1}\x03\x02\x02\x02\x67\u{1a8}\x03\x02\x02\x02\x69\u{1ab}\x03\x02\x02\
-		\x02\x6b\u{1b1}\x03\x02\x02\x02\x6d\u{1b4}\x03\x02\x02\x02\x6f\u{1bb}\x03\
-		\x02\x02\x02\x71\u{1bf}\x03\x02\x02\x02\x73\u{1c6}\x03\x02\x02\x02\x75\
-		\u{1cd}\x03\x02\x02\x02\x77\u{1d4}\x03\x02\x02\x02\x79\u{1d9}\x03\x02\x02\
-		\x02\x7b\u{1df}\x03\x02\x02\x02\x7d\u{1e4}\x03\x02\x02\x02\x7f\u{1eb}\x03\
-		\x02\x02\x02\u{81}\u{1f4}\x03\x02\x02\x02\u{83}\u{1fa}\x03\x02\x02\x02\
-		\u{85}\u{1fd}\x03\x02\x02\x02\u{87}\u{202}\x03\x02\x02\x02\u{89}\u{208}\
-		\x03\x02\x02\x02\u{8b}\u{212}\x03\x02\x02\x02\u{8d}\u{216}\x03\x02\x02\
-		\x02\u{8f}\u{221}\x03\x02\x02\x02\u{91}\u{226}\x03\x02\x02\x02\u{93}\u{22c}\
-		\x03\x02\x02\x02\u{95}\u{22f}\x03\x02\x02\x02\u{97}\u{233}\x03\x02\x02\
-		\x02\u{99}\u{237}\x03\x02\x02\x02\u{9b}\u{23b}\x03\x02\x02\x02\u{9d}\u{23e}\
-		\x03\x02\x02\x02\u{9f}\u{245}\x03\x02\x02\x02\u{a1}\u{24a}\x03\x02\x02\
-		\x02\u{a3}\u{253}\x03\x02\x02\x02\u{a5}\u{256}\x03\x02\x02\x02\u{a7}\u{25b}\
-		\x03\x02\x02\x02\u{a9}\u{261}\x03\x02\x02\x02\u{ab}\u{265}\x03\x02\x02\
-		\x02\u{ad}\u{26a}\x03\x02\x02\x02\u{af}\u{271}\x03\x02\x02\x02\u{b1}\u{276}\
-		\x03\x02\x02\x02\u{b3}\u{27c}\x03\x02\x02\x02\u{b5}\u{283}\x03\x02\x02\
-		\x02\u{b7}\u{288}\x03\x02\x02\x02\u{b9}\u{28d}\x03\x02\x02\x02\u{bb}\u{291}\
-		\x03\x02\x02\x02\u{bd}\u{296}\x03\x02\x02\x02\u{bf}\u{2ad}\x03\x02\x02\
-		\x02\u{c1}\u{2af}\x03\x02\x02\x02\u{c3}\u{2c3}\x03\x02\x02\x02\u{c5}\u{2d3}\
-		\x03\x02\x02\x02\u{c7}\u{2d5}\x03\x02\x02\x02\u{c9}\u{2dc}\x03\x02\x02\
-		\x02\u{cb}\u{2e0}\x03\x02\x02\x02\u{cd}\u{2e4}\x03\x02\x02\x02\u{cf}\u{2e8}\
-		\x03\x02\x02\x02\u{d1}\u{2ea}\x03\x02\x02\x02\u{d3}\u{2ee}\x03\x02\x02\
-		\x02\u{d5}\u{2f0}\x03\x02\x02\x02\u{d7}\u{308}\x03\x02\x02\x02\u{d9}\u{316}\
-		\x03\x02\x02\x02\u{db}\u{31f}\x03\x02\x02\x02\u{dd}\u{32a}\x03\x02\x02\
-		\x02\u{df}\u{32d}\x03\x02\x02\x02\u{e1}\u{331}\x03\x02\x02\x02\u{e3}\u{339}\
-		\x03\x02\x02\x02\u{e5}\u{340}\x03\x02\x02\x02\u{e7}\u{34a}\x03\x02\x02\
-		\x02\u{e9}\u{351}\x03\x02\x02\x02\u{eb}\u{354}\x03\x02\x02\x02\u{ed}\u{358}\
-		\x03\x02\x02\x02\u{ef}\u{35d}\x03\x02\x02\x02\u{f1}\u{364}\x03\x02\x02\
-		\x02\u{f3}\u{36c}\x03\x02\x02\x02\u{f5}\u{375}\x03\x02\x02\x02\u{f7}\u{379}\
-		\x03\x02\x02\x02\u{f9}\u{383}\x03\x02\x02\x02\u{fb}\u{388}\x03\x02\x02\
-		\x02\u{fd}\u{398}\x03\x02\x02\x02\u{ff}\u{3b7}\x03\x02\x02\x02\u{101}\u{3b9}\
-		\x03\x02\x02\x02\u{103}\u{3bb}\x03\x02\x02\x02\u{105}\u{3bd}\x03\x02\x02\
-		\x02\u{107}\u{3bf}\x03\x02\x02\x02\u{109}\u{3c1}\x03\x02\x02\x02\u{10b}\
-		\u{3c3}\x03\x02\x02\x02\u{10d}\u{3c5}\x03\x02\x02\x02\u{10f}\u{3c7}\x03\
-		\x02\x02\x02\u{111}\u{3c9}\x03\x02\x02\x02\u{113}\u{3cb}\x03\x02\x02\x02\
-		\u{115}\u{3cd}\x03\x02\x02\x02\u{117}\u{3cf}\x03\x02\x02\x02\u{119}\u{3d1}\
-		\x03\x02\x02\x02\u{11b}\u{3d3}\x03\x02\x02\x02\u{11d}\u{3d5}\x03\x02\x02\
-		\x02\u{11f}\u{3d7}\x03\x02\x02\x02\u{121}\u{3d9}\x03\x02\x02\x02\u{123}\
-		\u{3db}\x03\x02\x02\x02\u{125}\u{3dd}\x03\x02\x02\x02\u{127}\u{3df}\x03\
-		\x02\x02\x02\u{129}\u{12a}\x07\x3d\x02\x02\u{12a}\x04\x03\x02\x02\x02\u{12b}\
-		\u{12c}\x07\x2e\x02\x02\u{12c}\x06\x03\x02\x02\x02\u{12d}\u{12e}\x07\x3f\
-		\x02\x02\u{12e}\x08\x03\x02\x02\x02\u{12f}\u{130}\x07\x2d\x02\x02\u{130}\
-		\u{131}\x07\x3f\x02\x02\u{131}\x0a\x03\x02\x02\x02\u{132}\u{133}\x07\x2c\
-		\x02\x02\u{133}\x0c\x03\x02\x02\x02\u{134}\u{135}\x07\x2a\x02\x02\u{135}\
-		\x0e\x03\x02\x02\x02\u{136}\u{137}\x07\x2b\x02\x02\u{137}\x10\x03\x02\x02\
-		\x02\u{138}\u{139}\x07\x5d\x02\x02\u{139}\x12\x03\x02\x02\x02\u{13a}\u{13b}\
-		\x07\x5f\x02\x02\u{13b}\x14\x03\x02\x02\x02\u{13c}\u{13d}\x07\x3c\x02\x02\
-		\u{13d}\x16\x03\x02\x02\x02\u{13e}\u{13f}\x07\x7e\x02\x02\u{13f}\x18\x03\
-		\x02\x02\x02\u{140}\u{141}\x07\x30\x02\x02\u{141}\u{142}\x07\x30\x02\x02\
-		\u{142}\x1a\x03\x02\x02\x02\u{143}\u{144}\x07\x2d\x02\x02\u{144}\x1c\x03\
-		\x02\x02\x02\u{145}\u{146}\x07\x2f\x02\x02\u{146}\x1e\x03\x02\x02\x02\u{147}\
-		\u{148}\x07\x31\x02\x02\u{148}\x20\x03\x02\x02\x02\u{149}\u{14a}\x07\x27\
-		\x02\x02\u{14a}\x22\x03\x02\x02\x02\u{14b}\u{14c}\x07\x60\x02\x02\u{14c}\
-		\x24\x03\x02\x02\x02\u{14d}\u{14e}\x07\x3e\x02\x02\u{14e}\u{14f}\x07\x40\
-		\x02\x02\u{14f}\x26\x03\x02\x02\x02\u{150}\u{151}\x07\x3e\x02\x02\u{151}\
-		\x28\x03\x02\x02\x02\u{152}\u{153}\x07\x40\x02\x02\u{153}\x2a\x03\x02\x02\
-		\x02\u{154}\u{155}\x07\x3e\x02\x02\u{155}\u{156}\x07\x3f\x02\x02\u{156}\
-		\x2c\x03\x02\x02\x02\u{157}\u{158}\x07\x40\x02\x02\u{158}\u{159}\x07\x3f\
-		\x02\x02\u{159}\x2e\x03\x02\x02\x02\u{15a}\u{15b}\x07\x30\x02\x02\u{15b}\
-		\x30\x03\x02\x02\x02\u{15c}\u{15d}\x07\x7d\x02\x02\u{15d}\x32\x03\x02\x02\
-		\x02\u{15e}\u{15f}\x07\x7f\x02\x02\u{15f}\x34\x03\x02\x02\x02\u{160}\u{161}\
-		\x07\x26\x02\x02\u{161}\x36\x03\x02\x02\x02\u{162}\u{163}\x07\u{27ea}\x02\
-		\x02\u{163}\x38\x03\x02\x02\x02\u{164}\u{165}\x07\u{300a}\x02\x02\u{165}\
-		\x3a\x03\x02\x02\x02\u{166}\u{167}\x07\u{fe66}\x02\x02\u{167}\x3c\x03\x02\
-		\x02\x02\u{168}\u{169}\x07\u{ff1e}\x02\x02\u{169}\x3e\x03\x02\x02\x02\u{16a}\
-		\u{16b}\x07\u{27eb}\x02\x02\u{16b}\x40\x03\x02\x02\x02\u{16c}\u{16d}\x07\
-		\u{300b}\x02\x02\u{16d}\x42\x03\x02\x02\x02\u{16e}\u{16f}\x07\u{fe67}\x02\
-		\x02\u{16f}\x44\x03\x02\x02\x02\u{170}\u{171}\x07\u{ff20}\x02\x02\u{171}\
-		\x46\x03\x02\x02\x02\u{172}\u{173}\x07\u{af}\x02\x02\u{173}\x48\x03\x02\
-		\x02\x02\u{174}\u{175}\x07\u{2012}\x02\x02\u{175}\x4a\x03\x02\x02\x02\u{176}\
-		\u{177}\x07\u{2013}\x02\x02\u{177}\x4c\x03\x02\x02\x02\u{178}\u{179}\x07\
-		\u{2014}\x02\x02\u{179}\x4e\x03\x02\x02\x02\u{17a}\u{17b}\x07\u{2015}\x02\
-		\x02\u{17b}\x50\x03\x02\x02\x02\u{17c}\u{17d}\x07\u{2016}\x02\x02\u{17d}\
-		\x52\x03\x02\x02\x02\u{17e}\u{17f}\x07\u{2017}\x02\x02\u{17f}\x54\x03\x02\
-		\x02\x02\u{180}\u{181}\x07\u{2214}\x02\x02\u{181}\x56\x03\x02\x02\x02\u{182}\
-		\u{183}\x07\u{fe5a}\x02\x02\u{183}\x58\x03\x02\x02\x02\u{184}\u{185}\x07\
-		\u{fe65}\x02\x02\u{185}\x5a\x03\x02\x02\x02\u{186}\u{187}\x07\u{ff0f}\x02\
-		\x02\u{187}\x5c\x03\x02\x02\x02\u{188}\u{189}\x09\x02\x02\x02\u{189}\u{18a}\
-		\x09\x03\x02\x02\u{18a}\u{18b}\x09\x04\x02\x02\u{18b}\u{18c}\x09\x05\x02\
-		\x02\u{18c}\u{18d}\x09\x03\x02\x02\u{18d}\x5e\x03\x02\x02\x02\u{18e}\u{18f}\
-		\x09\x06\x02\x02\u{18f}\u{190}\x09\x07\x02\x02\u{190}\u{191}\x09\x07\x02\
-		\x02\u{191}\x60\x03\x02\x02\x02\u{192}\u{193}\x09\x05\x02\x02\u{193}\u{194}\
-		\x09\x08\x02\x02\u{194}\u{195}\x09\x09\x02\x02\u{195}\u{196}\x09\x04\x02\
-		\x02\u{196}\u{197}\x09\x05\x02\x02\u{197}\u{198}\x09\x03\x02\x02\u{198}\
-		\u{199}\x09\x06\x02\x02\u{199}\u{19a}\x09\x07\x02\x02\u{19a}\x62\x03\x02\
-		\x02\x02\u{19b}\u{19c}\x09\x0a\x02\x02\u{19c}\u{19d}\x09\x06\x02\x02\u{19d}\
-		\u{19e}\x09\x09\x02\x02\u{19e}\u{19f}\x09\x0b\x02\x02\u{19f}\u{1a0}\x09\
-		\x0c\x02\x02\u{1a0}\x64\x03\x02\x02\x02\u{1a1}\u{1a2}\x09\x02\x02\x02\u{1a2}\
-		\u{1a3}\x09\x03\x02\x02\u{1a3}\u{1a4}\x09\x0d\x02\x02\u{1a4}\u{1a5}\x09\
-		\x04\x02\x02\u{1a5}\u{1a6}\x09\x03\x02\x02\u{1a6}\u{1a7}\x09\x0e\x02\x02\
-		\u{1a7}\x66\x03\x02\x02\x02\u{1a8}\u{1a9}\x09\x06\x02\x02\u{1a9}\u{1aa}\
-		\x09\x0f\x02\x02\u{1aa}\x68\x03\x02\x02\x02\u{1ab}\u{1ac}\x09\x0a\x02\x02\
-		\u{1ac}\u{1ad}\x09\x10\x02\x02\u{1ad}\u{1ae}\x09\x11\x02\x02\u{1ae}\u{1af}\
-		\x09\x12\x02\x02\u{1af}\u{1b0}\x09\x10\x02\x02\u{1b0}\x6a\x03\x02\x02\x02\
-		\u{1b1}\u{1b2}\x09\x05\x02\x02\u{1b2}\u{1b3}\x09\x03\x02\x02\u{1b3}\x6c\
-		\x03\x02\x02\x02\u{1b4}\u{1b5}\x09\x0b\x02\x02\u{1b5}\u{1b6}\x09\x11\x02\
-		\x02\u{1b6}\u{1b7}\x09\x10\x02\x02\u{1b7}\u{1b8}\x09\x06\x02\x02\u{1b8}\
-		\u{1b9}\x09\x09\x02\x02\u{1b9}\u{1ba}\x09\x10\x02\x02\u{1ba}\x6e\x03\x02\
-		\x02\x02\u{1bb}\u{1bc}\x09\x0f\x02\x02\u{1bc}\u{1bd}\x09\x10\x02\x02\u{1bd}\
-		\u{1be}\x09\x09\x02\x02\u{1be}\x70\x03\x02\x02\x02\u{1bf}\u{1c0}\x09\x0e\
-		\x02\x02\u{1c0}\u{1c1}\x09\x10\x02\x02\u{1c1}\u{1c2}\x09\x09\x02\x02\u{1c2}\
-		\u{1c3}\x09\x06\x02\x02\u{1c3}\u{1c4}\x09\x0b\x02\x02\u{1c4}\u{1c5}\x09\
-		\x0c\x02\x02\u{1c5}\x72\x03\x02\x02\x02\u{1c6}\u{1c7}\x09\x0e\x02\x02\u{1c7}\
-		\u{1c8}\x09\x10\x02\x02\u{1c8}\u{1c9}\x09\x07\x02\x02\u{1c9}\u{1ca}\x09\
-		\x10\x02\x02\u{1ca}\u{1cb}\x09\x09\x02\x02\u{1cb}\u{1cc}\x09\x10\x02\x02\
-		\u{1cc}\x74\x03\x02\x02\x02\u{1cd}\u{1ce}\x09\x11\x02\x02\u{1ce}\u{1cf}\
-		\x09\x10\x02\x02\u{1cf}\u{1d0}\x09\x0a\x02\x02\u{1d0}\u{1d1}\x09\x05\x02\
-		\x02\u{1d1}\u{1d2}\x09\x13\x02\x02\u{1d2}\u{1d3}\x09\x10\x02\x02\u{1d3}\
-		\x76\x03\x02\x02\x02\u{1d4}\u{1d5}\x09\x0b\x02\x02\u{1d5}\u{1d6}\x09\x06\
-		\x02\x02\u{1d6}\u{1d7}\x09\x07\x02\x02\u{1d7}\u{1d8}\x09\x07\x02\x02\u{1d8}\
-		\x78\x03\x02\x02\x02\u{1d9}\u{1da}\x09\x14\x02\x02\u{1da}\u{1db}\x09\x04\
-		\x02\x02\u{1db}\u{1dc}\x09\x10\x02\x02\u{1dc}\u{1dd}\x09\x07\x02\x02\u{1dd}\
-		\u{1de}\x09\x0e\x02\x02\u{1de}\x7a\x03\x02\x02\x02\u{1df}\u{1e0}\x09\x0d\
-		\x02\x02\u{1e0}\u{1e1}\x09\x04\x02\x02\u{1e1}\u{1e2}\x09\x09\x02\x02\u{1e2}\
-		\u{1e3}\x09\x0c\x02\x02\u{1e3}\x7c\x03\x02\x02\x02\u{1e4}\u{1e5}\x09\x11\
-		\x02\x02\u{1e5}\u{1e6}\x09\x10\x02\x02\u{1e6}\u{1e7}\x09\x09\x02\x02\u{1e7}\
-		\u{1e8}\x09\x02\x02\x02\u{1e8}\u{1e9}\x09\x11\x02\x02\u{1e9}\u{1ea}\x09\
-		\x03\x02\x02\u{1ea}\x7e\x03\x02\x02\x02\u{1eb}\u{1ec}\x09\x0e\x02\x02\u{1ec}\
-		\u{1ed}\x09\x04\x02\x02\u{1ed}\u{1ee}\x09\x0f\x02\x02\u{1ee}\u{1ef}\x09\
-		\x09\x02\x02\u{1ef}\u{1f0}\x09\x04\x02\x02\u{1f0}\u{1f1}\x09\x03\x02\x02\
-		\u{1f1}\u{1f2}\x09\x0b\x02\x02\u{1f2}\u{1f3}\x09\x09\x02\x02\u{1f3}\u{80}\
-		\x03\x02\x02\x02\u{1f4}\u{1f5}\x09\x05\x02\x02\u{1f5}\u{1f6}\x09\x11\x02\
-		\x02\u{1f6}\u{1f7}\x09\x0e\x02\x02\u{1f7}\u{1f8}\x09\x10\x02\x02\u{1f8}\
-		\u{1f9}\x09\x11\x02\x02\u{1f9}\u{82}\x03\x02\x02\x02\u{1fa}\u{1fb}\x09\
-		\x15\x02\x02\u{1fb}\u{1fc}\x09\x14\x02\x02\u{1fc}\u{84}\x03\x02\x02\x02\
-		\u{1fd}\u{1fe}\x09\x0f\x02\x02\u{1fe}\u{1ff}\x09\x16\x02\x02\u{1ff}\u{200}\
-		\x09\x04\x02\x02\u{200}\u{201}\x09\x08\x02\x02\u{201}\u{86}\x03\x02\x02\
-		\x02\u{202}\u{203}\x09\x07\x02\x02\u{203}\u{204}\x09\x04\x02\x02\u{204}\
-		\u{205}\x09\x0a\x02\x02\u{205}\u{206}\x09\x04\x02\x02\u{206}\u{207}\x09\
-		\x09\x02\x02\u{207}\u{88}\x03\x02\x02\x02\u{208}\u{209}\x09\x06\x02\x02\
-		\u{209}\u{20a}\x09\x0f\x02\x02\u{20a}\u{20b}\x09\x0b\x02\x02\u{20b}\u{20c}\
-		\x09\x10\x02\x02\u{20c}\u{20d}\x09\x03\x02\x02\u{20d}\u{20e}\x09\x0e\x02\
-		\x02\u{20e}\u{20f}\x09\x04\x02\x02\u{20f}\u{210}\x09\x03\x02\x02\u{210}\
-		\u{211}\x09\x12\x02\x02\u{211}\u{8a}\x03\x02\x02\x02\u{212}\u{213}\x09\
-		\x06\x02\x02\u{213}\u{214}\x09\x0f\x02\x02\u{214}\u{215}\x09\x0b\x02\x02\
-		\u{215}\u{8c}\x03\x02\x02\x02\u{216}\u{217}\x09\x0e\x02\x02\u{217}\u{218}\
-		\x09\x10\x02\x02\u{218}\u{219}\x09\x0f\x02\x02\u{219}\u{21a}\x09\x0b\x02\
-		\x02\u{21a}\u{21b}\x09\x10\x02\x02\u{21b}\u{21c}\x09\x03\x02\x02\u{21c}\
-		\u{21d}\x09\x0e\x02\x02\u{21d}\u{21e}\x09\x04\x02\x02\u{21e}\u{21f}\x09\
-		\x03\x02\x02\u{21f}\u{220}\x09\x12\x02\x02\u{220}\u{8e}\x03\x02\x02\x02\
-		\u{221}\u{222}\x09\x0e\x02\x02\u{222}\u{223}\x09\x10\x02\x02\u{223}\u{224}\
-		\x09\x0f\x02\x02\u{224}\u{225}\x09\x0b\x02\x02\u{225}\u{90}\x03\x02\x02\
-		\x02\u{226}\u{227}\x09\x0d\x02\x02\u{227}\u{228}\x09\x0c\x02\x02\u{228}\
-		\u{229}\x09\x10\x02\x02\u{229}\u{22a}\x09\x11\x02\x02\u{22a}\u{22b}\x09\
-		\x10\x02\x02\u{22b}\u{92}\x03\x02\x02\x02\u{22c}\u{22d}\x09\x05\x02\x02\
-		\u{22d}\u{22e}\x09\x11\x02\x02\u{22e}\u{94}\x03\x02\x02\x02\u{22f}\u{230}\
-		\x09\x17\x02\x02\u{230}\u{231}\x09\x05\x02\x02\u{231}\u{232}\x09\x11\x02\
-		\x02\u{232}\u{96}\x03\x02\x02\x02\u{233}\u{234}\x09\x06\x02\x02\u{234}\
-		\u{235}\x09\x03\x02\x02\u{235}\u{236}\x09\x0e\x02\x02\u{236}\u{98}\x03\
-		\x02\x02\x02\u{237}\u{238}\x09\x03\x02\x02\u{238}\u{239}\x09\x05\x02\x02\
-		\u{239}\u{23a}\x09\x09\x02\x02\u{23a}\u{9a}\x03\x02\x02\x02\u{23b}\u{23c}\
-		\x09\x04\x02\x02\u{23c}\u{23d}\x09\x03\x02\x02\u{23d}\u{9c}\x03\x02\x02\
-		\x02\u{23e}\u{23f}\x09\x0f\x02\x02\u{23f}\u{240}\x09\x09\x02\x02\u{240}\
-		\u{241}\x09\x06\x02\x02\u{241}\u{242}\x09\x11\x02\x02\u{242}\u{243}\x09\
-		\x09\x02\x02\u{243}\u{244}\x09\x0f\x02\x02\u{244}\u{9e}\x03\x02\x02\x02\
-		\u{245}\u{246}\x09\x10\x02\x02\u{246}\u{247}\x09\x03\x02\x02\u{247}\u{248}\
-		\x09\x0e\x02\x02\u{248}\u{249}\x09\x0f\x02\x02\u{249}\u{a0}\x03\x02\x02\
-		\x02\u{24a}\u{24b}\x09\x0b\x02\x02\u{24b}\u{24c}\x09\x05\x02\x02\u{24c}\
-		\u{24d}\x09\x03\x02\x02\u{24d}\u{24e}\x09\x09\x02\x02\u{24e}\u{24f}\x09\
-		\x06\x02\x02\u{24f}\u{250}\x09\x04\x02\x02\u{250}\u{251}\x09\x03\x02\x02\
-		\u{251}\u{252}\x09\x0f\x02\x02\u{252}\u{a2}\x03\x02\x02\x02\u{253}\u{254}\
-		\x09\x04\x02\x02\u{254}\u{255}\x09\x0f\x02\x02\u{255}\u{a4}\x03\x02\x02\
-		\x02\u{256}\u{257}\x09\x03\x02\x02\u{257}\u{258}\x09\x02\x02\x02\u{258}\
-		\u{259}\x09\x07\x02\x02\u{259}\u{25a}\x09\x07\x02\x02\u{25a}\u{a6}\x03\
-		\x02\x02\x02\u{25b}\u{25c}\x09\x0b\x02\x02\u{25c}\u{25d}\x09\x05\x02\x02\
-		\u{25d}\u{25e}\x09\x02\x02\x02\u{25e}\u{25f}\x09\x03\x02\x02\u{25f}\u{260}\
-		\x09\x09\x02\x02\u{260}\u{a8}\x03\x02\x02\x02\u{261}\u{262}\x09\x06\x02\
-		\x02\u{262}\u{263}\x09\x03\x02\x02\u{263}\u{264}\x09\x14\x02\x02\u{264}\
-		\u{aa}\x03\x02\x02\x02\u{265}\u{266}\x09\x03\x02\x02\u{266}\u{267}\x09\
-		\x05\x02\x02\u{267}\u{268}\x09\x03\x02\x02\u{268}\u{269}\x09\x10\x02\x02\
-		\u{269}\u{ac}\x03\x02\x02\x02\u{26a}\u{26b}\x09\x0f\x02\x02\u{26b}\u{26c}\
-		\x09\x04\x02\x02\u{26c}\u{26d}\x09\x03\x02\x02\u{26d}\u{26e}\x09\x12\x02\
-		\x02\u{26e}\u{26f}\x09\x07\x02\x02\u{26f}\u{270}\x09\x10\x02\x02\u{270}\
-		\u{ae}\x03\x02\x02\x02\u{271}\u{272}\x09\x09\x02\x02\u{272}\u{273}\x09\
-		\x11\x02\x02\u{273}\u{274}\x09\x02\x02\x02\u{274}\u{275}\x09\x10\x02\x02\
-		\u{275}\u{b0}\x03\x02\x02\x02\u{276}\u{277}\x09\x18\x02\x02\u{277}\u{278}\
-		\x09\x06\x02\x02\u{278}\u{279}\x09\x07\x02\x02\u{279}\u{27a}\x09\x0f\x02\
-		\x02\u{27a}\u{27b}\x09\x10\x02\x02\u{27b}\u{b2}\x03\x02\x02\x02\u{27c}\
-		\u{27d}\x09\x10\x02\x02\u{27d}\u{27e}\x09\x17\x02\x02\u{27e}\u{27f}\x09\
-		\x04\x02\x02\u{27f}\u{280}\x09\x0f\x02\x02\u{280}\u{281}\x09\x09\x02\x02\
-		\u{281}\u{282}\x09\x0f\x02\x02\u{282}\u{b4}\x03\x02\x02\x02\u{283}\u{284}\
-		\x09\x0b\x02\x02\u{284}\u{285}\x09\x06\x02\x02\u{285}\u{286}\x09\x0f\x02\
-		\x02\u{286}\u{287}\x09\x10\x02\x02\u{287}\u{b6}\x03\x02\x02\x02\u{288}\
-		\u{289}\x09\x10\x02\x02\u{289}\u{28a}\x09\x07\x02\x02\u{28a}\u{28b}\x09\
-		\x0f\x02\x02\u{28b}\u{28c}\x09\x10\x02\x02\u{28c}\u{b8}\x03\x02\x02\x02\
-		\u{28d}\u{28e}\x09\x10\x02\x02\u{28e}\u{28f}\x09\x03\x02\x02\u{28f}\u{290}\
-		\x09\x0e\x02\x02\u{290}\u{ba}\x03\x02\x02\x02\u{291}\u{292}\x09\x0d\x02\
-		\x02\u{292}\u{293}\x09\x0c\x02\x02\u{293}\u{294}\x09\x10\x02\x02\u{294}\
-		\u{295}\x09\x03\x02\x02\u{295}\u{bc}\x03\x02\x02\x02\u{296}\u{297}\x09\
-		\x09\x02\x02\u{297}\u{298}\x09\x0c\x02\x02\u{298}\u{299}\x09\x10\x02\x02\
-		\u{299}\u{29a}\x09\x03\x02\x02\u{29a}\u{be}\x03\x02\x02\x02\u{29b}\u{2a0}\
-		\x07\x24\x02\x02\u{29c}\u{29f}\x05\u{11f}\u{90}\x02\u{29d}\u{29f}\x05\u{c1}\
-		\x61\x02\u{29e}\u{29c}\x03\x02\x02\x02\u{29e}\u{29d}\x03\x02\x02\x02\u{29f}\
-		\u{2a2}\x03\x02\x02\x02\u{2a0}\u{29e}\x03\x02\x02\x02\u{2a0}\u{2a1}\x03\
-		\x02\x02\x02\u{2a1}\u{2a3}\x03\x02\x02\x02\u{2a2}\u{2a0}\x03\x02\x02\x02\
-		\u{2a3}\u{2ae}\x07\x24\x02\x02\u{2a4}\u{2a9}\x07\x29\x02\x02\u{2a5}\u{2a8}\
-		\x05\u{10b}\u{86}\x02\u{2a6}\u{2a8}\x05\u{c1}\x61\x02\u{2a7}\u{2a5}\x03\
-		\x02\x02\x02\u{2a7}\u{2a6}\x03\x02\x02\x02\u{2a8}\u{2ab}\x03\x02\x02\x02\
-		\u{2a9}\u{2a7}\x03\x02\x02\x02\u{2a9}\u{2aa}\x03\x02\x02\x02\u{2aa}\u{2ac}\
-		\x03\x02\x02\x02\u{2ab}\u{2a9}\x03\x02\x02\x02\u{2ac}\u{2ae}\x07\x29\x02\
-		\x02\u{2ad}\u{29b}\x03\x02\x02\x02\u{2ad}\u{2a4}\x03\x02\x02\x02\u{2ae}\
-		\u{c0}\x03\x02\x02\x02\u{2af}\u{2c1}\x07\x5e\x02\x02\u{2b0}\u{2c2}\x09\
-		\x19\x02\x02\u{2b1}\u{2b2}\x09\x02\x02\x02\u{2b2}\u{2b3}\x05\u{cb}\x66\
-		\x02\u{2b3}\u{2b4}\x05\u{cb}\x66\x02\u{2b4}\u{2b5}\x05\u{cb}\x66\x02\u{2b5}\
-		\u{2b6}\x05\u{cb}\x66\x02\u{2b6}\u{2c2}\x03\x02\x02\x02\u{2b7}\u{2b8}\x09\
-		\x02\x02\x02\u{2b8}\u{2b9}\x05\u{cb}\x66\x02\u{2b9}\u{2ba}\x05\u{cb}\x66\
-		\x02\u{2ba}\u{2bb}\x05\u{cb}\x66\x02\u{2bb}\u{2bc}\x05\u{cb}\x66\x02\u{2bc}\
-		\u{2bd}\x05\u{cb}\x66\x02\u{2bd}\u{2be}\x05\u{cb}\x66\x02\u{2be}\u{2bf}\
-		\x05\u{cb}\x66\x02\u{2bf}\u{2c0}\x05\u{cb}\x66\x02\u{2c0}\u{2c2}\x03\x02\
-		\x02\x02\u{2c1}\u{2b0}\x03\x02\x02\x02\u{2c1}\u{2b1}\x03\x02\x02\x02\u{2c1}\
-		\u{2b7}\x03\x02\x02\x02\u{2c2}\u{c2}\x03\x02\x02\x02\u{2c3}\u{2c4}\x07\
-		\x32\x02\x02\u{2c4}\u{2c5}\x07\x7a\x02\x02\u{2c5}\u{2c7}\x03\x02\x02\x02\
-		\u{2c6}\u{2c8}\x05\u{cb}\x66\x02\u{2c7}\u{2c6}\x03\x02\x02\x02\u{2c8}\u{2c9}\
-		\x03\x02\x02\x02\u{2c9}\u{2c7}\x03\x02\x02\x02\u{2c9}\u{2ca}\x03\x02\x02\
-		\x02\u{2ca}\u{c4}\x03\x02\x02\x02\u{2cb}\u{2d4}\x05\u{d5}\x6b\x02\u{2cc}\
-		\u{2d0}\x05\u{cf}\x68\x02\u{2cd}\u{2cf}\x05\u{cd}\x67\x02\u{2ce}\u{2cd}\
-		\x03\x02\x02\x02\u{2cf}\u{2d2}\x03\x02\x02\x02\u{2d0}\u{2ce}\x03\x02\x02\
-		\x02\u{2d0}\u{2d1}\x03\x02\x02\x02\u{2d1}\u{2d4}\x03\x02\x02\x02\u{2d2}\
-		\u{2d0}\x03\x02\x02\x02\u{2d3}\u{2cb}\x03\x02\x02\x02\u{2d3}\u{2cc}\x03\
-		\x02\x02\x02\u{2d4}\u{c6}\x03\x02\x02\x02\u{2d5}\u{2d7}\x05\u{d5}\x6b\x02\
-		\u{2d6}\u{2d8}\x05\u{d3}\x6a\x02\u{2d7}\u{2d6}\x03\x02\x02\x02\u{2d8}\u{2d9}\
-		\x03\x02\x02\x02\u{2d9}\u{2d7}\x03\x02\x02\x02\u{2d9}\u{2da}\x03\x02\x02\
-		\x02\u{2da}\u{c8}\x03\x02\x02\x02\u{2db}\u{2dd}\x09\x1a\x02\x02\u{2dc}\
-		\u{2db}\x03\x02\x02\x02\u{2dd}\u{ca}\x03\x02\x02\x02\u{2de}\u{2e1}\x05\
-		\u{cd}\x67\x02\u{2df}\u{2e1}\x05\u{c9}\x65\x02\u{2e0}\u{2de}\x03\x02\x02\
-		\x02\u{2e0}\u{2df}\x03\x02\x02\x02\u{2e1}\u{cc}\x03\x02\x02\x02\u{2e2}\
-		\u{2e5}\x05\u{d5}\x6b\x02\u{2e3}\u{2e5}\x05\u{cf}\x68\x02\u{2e4}\u{2e2}\
-		\x03\x02\x02\x02\u{2e4}\u{2e3}\x03\x02\x02\x02\u{2e5}\u{ce}\x03\x02\x02\
-		\x02\u{2e6}\u{2e9}\x05\u{d1}\x69\x02\u{2e7}\u{2e9}\x04\x3a\x3b\x02\u{2e8}\
-		\u{2e6}\x03\x02\x02\x02\u{2e8}\u{2e7}\x03\x02\x02\x02\u{2e9}\u{d0}\x03\
-		\x02\x02\x02\u{2ea}\u{2eb}\x04\x33\x39\x02\u{2eb}\u{d2}\x03\x02\x02\x02\
-		\u{2ec}\u{2ef}\x05\u{d5}\x6b\x02\u{2ed}\u{2ef}\x05\u{d1}\x69\x02\u{2ee}\
-		\u{2ec}\x03\x02\x02\x02\u{2ee}\u{2ed}\x03\x02\x02\x02\u{2ef}\u{d4}\x03\
-		\x02\x02\x02\u{2f0}\u{2f1}\x07\x32\x02\x02\u{2f1}\u{d6}\x03\x02\x02\x02\
-		\u{2f2}\u{2f4}\x05\u{cd}\x67\x02\u{2f3}\u{2f2}\x03\x02\x02\x02\u{2f4}\u{2f5}\
-		\x03\x02\x02\x02\u{2f5}\u{2f3}\x03\x02\x02\x02\u{2f5}\u{2f6}\x03\x02\x02\
-		\x02\u{2f6}\u{309}\x03\x02\x02\x02\u{2f7}\u{2f9}\x05\u{cd}\x67\x02\u{2f8}\
-		\u{2f7}\x03\x02\x02\x02\u{2f9}\u{2fa}\x03\x02\x02\x02\u{2fa}\u{2f8}\x03\
-		\x02\x02\x02\u{2fa}\u{2fb}\x03\x02\x02\x02\u{2fb}\u{2fc}\x03\x02\x02\x02\
-		\u{2fc}\u{2fe}\x07\x30\x02\x02\u{2fd}\u{2ff}\x05\u{cd}\x67\x02\u{2fe}\u{2fd}\
-		\x03\x02\x02\x02\u{2ff}\u{300}\x03\x02\x02\x02\u{300}\u{2fe}\x03\x02\x02\
-		\x02\u{300}\u{301}\x03\x02\x02\x02\u{301}\u{309}\x03\x02\x02\x02\u{302}\
-		\u{304}\x07\x30\x02\x02\u{303}\u{305}\x05\u{cd}\x67\x02\u{304}\u{303}\x03\
-		\x02\x02\x02\u{305}\u{306}\x03\x02\x02\x02\u{306}\u{304}\x03\x02\x02\x02\
-		\u{306}\u{307}\x03\x02\x02\x02\u{307}\u{309}\x03\x02\x02\x02\u{308}\u{2f3}\
-		\x03\x02\x02\x02\u{308}\u{2f8}\x03\x02\x02\x02\u{308}\u{302}\x03\x02\x02\
-		\x02\u{309}\u{30a}\x03\x02\x02\x02\u{30a}\u{30c}\x09\x10\x02\x02\u{30b}\
-		\u{30d}\x07\x2f\x02\x02\u{30c}\u{30b}\x03\x02\x02\x02\u{30c}\u{30d}\x03\
-		\x02\x02\x02\u{30d}\u{30f}\x03\x02\x02\x02\u{30e}\u{310}\x05\u{cd}\x67\
-		\x02\u{30f}\u{30e}\x03\x02\x02\x02\u{310}\u{311}\x03\x02\x02\x02\u{311}\
-		\u{30f}\x03\x02\x02\x02\u{311}\u{312}\x03\x02\x02\x02\u{312}\u{d8}\x03\
-		\x02\x02\x02\u{313}\u{315}\x05\u{cd}\x67\x02\u{314}\u{313}\x03\x02\x02\
-		\x02\u{315}\u{318}\x03\x02\x02\x02\u{316}\u{314}\x03\x02\x02\x02\u{316}\
-		\u{317}\x03\x02\x02\x02\u{317}\u{319}\x03\x02\x02\x02\u{318}\u{316}\x03\
-		\x02\x02\x02\u{319}\u{31b}\x07\x30\x02\x02\u{31a}\u{31c}\x05\u{cd}\x67\
-		\x02\u{31b}\u{31a}\x03\x02\x02\x02\u{31c}\u{31d}\x03\x02\x02\x02\u{31d}\
-		\u{31b}\x03\x02\x02\x02\u{31d}\u{31e}\x03\x02\x02\x02\u{31e}\u{da}\x03\
-		\x02\x02\x02\u{31f}\u{320}\x09\x0b\x02\x02\u{320}\u{321}\x09\x05\x02\x02\
-		\u{321}\u{322}\x09\x03\x02\x02\u{322}\u{323}\x09\x0f\x02\x02\u{323}\u{324}\
-		\x09\x09\x02\x02\u{324}\u{325}\x09\x11\x02\x02\u{325}\u{326}\x09\x06\x02\
-		\x02\u{326}\u{327}\x09\x04\x02\x02\u{327}\u{328}\x09\x03\x02\x02\u{328}\
-		\u{329}\x09\x09\x02\x02\u{329}\u{dc}\x03\x02\x02\x02\u{32a}\u{32b}\x09\
-		\x0e\x02\x02\u{32b}\u{32c}\x09\x05\x02\x02\u{32c}\u{de}\x03\x02\x02\x02\
-		\u{32d}\u{32e}\x09\x18\x02\x02\u{32e}\u{32f}\x09\x05\x02\x02\u{32f}\u{330}\
-		\x09\x11\x02\x02\u{330}\u{e0}\x03\x02\x02\x02\u{331}\u{332}\x09\x11\x02\
-		\x02\u{332}\u{333}\x09\x10\x02\x02\u{333}\u{334}\x09\x1b\x02\x02\u{334}\
-		\u{335}\x09\x02\x02\x02\u{335}\u{336}\x09\x04\x02\x02\u{336}\u{337}\x09\
-		\x11\x02\x02\u{337}\u{338}\x09\x10\x02\x02\u{338}\u{e2}\x03\x02\x02\x02\
-		\u{339}\u{33a}\x09\x02\x02\x02\u{33a}\u{33b}\x09\x03\x02\x02\u{33b}\u{33c}\
-		\x09\x04\x02\x02\u{33c}\u{33d}\x09\x1b\x02\x02\u{33d}\u{33e}\x09\x02\x02\
-		\x02\u{33e}\u{33f}\x09\x10\x02\x02\u{33f}\u{e4}\x03\x02\x02\x02\u{340}\
-		\u{341}\x09\x0a\x02\x02\u{341}\u{342}\x09\x06\x02\x02\u{342}\u{343}\x09\
-		\x03\x02\x02\u{343}\u{344}\x09\x0e\x02\x02\u{344}\u{345}\x09\x06\x02\x02\
-		\u{345}\u{346}\x09\x09\x02\x02\u{346}\u{347}\x09\x05\x02\x02\u{347}\u{348}\
-		\x09\x11\x02\x02\u{348}\u{349}\x09\x14\x02\x02\u{349}\u{e6}\x03\x02\x02\
-		\x02\u{34a}\u{34b}\x09\x0f\x02\x02\u{34b}\u{34c}\x09\x0b\x02\x02\u{34c}\
-		\u{34d}\x09\x06\x02\x02\u{34d}\u{34e}\x09\x07\x02\x02\u{34e}\u{34f}\x09\
-		\x06\x02\x02\u{34f}\u{350}\x09\x11\x02\x02\u{350}\u{e8}\x03\x02\x02\x02\
-		\u{351}\u{352}\x09\x05\x02\x02\u{352}\u{353}\x09\x18\x02\x02\u{353}\u{ea}\
-		\x03\x02\x02\x02\u{354}\u{355}\x09\x06\x02\x02\u{355}\u{356}\x09\x0e\x02\
-		\x02\u{356}\u{357}\x09\x0e\x02\x02\u{357}\u{ec}\x03\x02\x02\x02\u{358}\
-		\u{359}\x09\x0e\x02\x02\u{359}\u{35a}\x09\x11\x02\x02\u{35a}\u{35b}\x09\
-		\x05\x02\x02\u{35b}\u{35c}\x09\x08\x02\x02\u{35c}\u{ee}\x03\x02\x02\x02\
-		\u{35d}\u{35e}\x09\x18\x02\x02\u{35e}\u{35f}\x09\x04\x02\x02\u{35f}\u{360}\
-		\x09\x07\x02\x02\u{360}\u{361}\x09\x09\x02\x02\u{361}\u{362}\x09\x10\x02\
-		\x02\u{362}\u{363}\x09\x11\x02\x02\u{363}\u{f0}\x03\x02\x02\x02\u{364}\
-		\u{365}\x09\x10\x02\x02\u{365}\u{366}\x09\x17\x02\x02\u{366}\u{367}\x09\
-		\x09\x02\x02\u{367}\u{368}\x09\x11\x02\x02\u{368}\u{369}\x09\x06\x02\x02\
-		\u{369}\u{36a}\x09\x0b\x02\x02\u{36a}\u{36b}\x09\x09\x02\x02\u{36b}\u{f2}\
-		\x03\x02\x02\x02\u{36c}\u{370}\x05\u{f5}\x7b\x02\u{36d}\u{36f}\x05\u{f7}\
-		\x7c\x02\u{36e}\u{36d}\x03\x02\x02\x02\u{36f}\u{372}\x03\x02\x02\x02\u{370}\
-		\u{36e}\x03\x02\x02\x02\u{370}\u{371}\x03\x02\x02\x02\u{371}\u{f4}\x03\
-		\x02\x02\x02\u{372}\u{370}\x03\x02\x02\x02\u{373}\u{376}\x05\u{127}\u{94}\
-		\x02\u{374}\u{376}\x05\u{11b}\u{8e}\x02\u{375}\u{373}\x03\x02\x02\x02\u{375}\
-		\u{374}\x03\x02\x02\x02\u{376}\u{f6}\x03\x02\x02\x02\u{377}\u{37a}\x05\
-		\u{107}\u{84}\x02\u{378}\u{37a}\x05\u{117}\u{8c}\x02\u{379}\u{377}\x03\
-		\x02\x02\x02\u{379}\u{378}\x03\x02\x02\x02\u{37a}\u{f8}\x03\x02\x02\x02\
-		\u{37b}\u{37f}\x07\x62\x02\x02\u{37c}\u{37e}\x05\u{103}\u{82}\x02\u{37d}\
-		\u{37c}\x03\x02\x02\x02\u{37e}\u{381}\x03\x02\x02\x02\u{37f}\u{37d}\x03\
-		\x02\x02\x02\u{37f}\u{380}\x03\x02\x02\x02\u{380}\u{382}\x03\x02\x02\x02\
-		\u{381}\u{37f}\x03\x02\x02\x02\u{382}\u{384}\x07\x62\x02\x02\u{383}\u{37b}\
-		\x03\x02\x02\x02\u{384}\u{385}\x03\x02\x02\x02\u{385}\u{383}\x03\x02\x02\
-		\x02\u{385}\u{386}\x03\x02\x02\x02\u{386}\u{fa}\x03\x02\x02\x02\u{387}\
-		\u{389}\x05\u{fd}\x7f\x02\u{388}\u{387}\x03\x02\x02\x02\u{389}\u{38a}\x03\
-		\x02\x02\x02\u{38a}\u{388}\x03\x02\x02\x02\u{38a}\u{38b}\x03\x02\x02\x02\
-		\u{38b}\u{fc}\x03\x02\x02\x02\u{38c}\u{399}\x05\u{119}\u{8d}\x02\u{38d}\
-		\u{399}\x05\u{11d}\u{8f}\x02\u{38e}\u{399}\x05\u{121}\u{91}\x02\u{38f}\
-		\u{399}\x05\u{123}\u{92}\x02\u{390}\u{399}\x05\u{101}\u{81}\x02\u{391}\
-		\u{399}\x05\u{115}\u{8b}\x02\u{392}\u{399}\x05\u{113}\u{8a}\x02\u{393}\
-		\u{399}\x05\u{111}\u{89}\x02\u{394}\u{399}\x05\u{105}\u{83}\x02\u{395}\
-		\u{399}\x05\u{125}\u{93}\x02\u{396}\u{399}\x09\x1c\x02\x02\u{397}\u{399}\
-		\x05\u{ff}\u{80}\x02\u{398}\u{38c}\x03\x02\x02\x02\u{398}\u{38d}\x03\x02\
-		\x02\x02\u{398}\u{38e}\x03\x02\x02\x02\u{398}\u{38f}\x03\x02\x02\x02\u{398}\
-		\u{390}\x03\x02\x02\x02\u{398}\u{391}\x03\x02\x02\x02\u{398}\u{392}\x03\
-		\x02\x02\x02\u{398}\u{393}\x03\x02\x02\x02\u{398}\u{394}\x03\x02\x02\x02\
-		\u{398}\u{395}\x03\x02\x02\x02\u{398}\u{396}\x03\x02\x02\x02\u{398}\u{397}\
-		\x03\x02\x02\x02\u{399}\u{fe}\x03\x02\x02\x02\u{39a}\u{39b}\x07\x31\x02\
-		\x02\u{39b}\u{39c}\x07\x2c\x02\x02\u{39c}\u{3a2}\x03\x02\x02\x02\u{39d}\
-		\u{3a1}\x05\u{109}\u{85}\x02\u{39e}\u{39f}\x07\x2c\x02\x02\u{39f}\u{3a1}\
-		\x05\u{10f}\u{88}\x02\u{3a0}\u{39d}\x03\x02\x02\x02\u{3a0}\u{39e}\x03\x02\
-		\x02\x02\u{3a1}\u{3a4}\x03\x02\x02\x02\u{3a2}\u{3a0}\x03\x02\x02\x02\u{3a2}\
-		\u{3a3}\x03\x02\x02\x02\u{3a3}\u{3a5}\x03\x02\x02\x02\u{3a4}\u{3a2}\x03\
-		\x02\x02\x02\u{3a5}\u{3a6}\x07\x2c\x02\x02\u{3a6}\u{3b8}\x07\x31\x02\x02\
-		\u{3a7}\u{3a8}\x07\x31\x02\x02\u{3a8}\u{3a9}\x07\x31\x02\x02\u{3a9}\u{3ad}\
-		\x03\x02\x02\x02\u{3aa}\u{3ac}\x05\u{10d}\u{87}\x02\u{3ab}\u{3aa}\x03\x02\
-		\x02\x02\u{3ac}\u{3af}\x03\x02\x02\x02\u{3ad}\u{3ab}\x03\x02\x02\x02\u{3ad}\
-		\u{3ae}\x03\x02\x02\x02\u{3ae}\u{3b1}\x03\x02\x02\x02\u{3af}\u{3ad}\x03\
-		\x02\x02\x02\u{3b0}\u{3b2}\x05\u{115}\u{8b}\x02\u{3b1}\u{3b0}\x03\x02\x02\
-		\x02\u{3b1}\u{3b2}\x03\x02\x02\x02\u{3b2}\u{3b5}\x03\x02\x02\x02\u{3b3}\
-		\u{3b6}\x05\u{121}\u{91}\x02\u{3b4}\u{3b6}\x07\x02\x02\x03\u{3b5}\u{3b3}\
-		\x03\x02\x02\x02\u{3b5}\u{3b4}\x03\x02\x02\x02\u{3b6}\u{3b8}\x03\x02\x02\
-		\x02\u{3b7}\u{39a}\x03\x02\x02\x02\u{3b7}\u{3a7}\x03\x02\x02\x02\u{3b8}\
-		\u{100}\x03\x02\x02\x02\u{3b9}\u{3ba}\x09\x1d\x02\x02\u{3ba}\u{102}\x03\
-		\x02\x02\x02\u{3bb}\u{3bc}\x0a\x1e\x02\x02\u{3bc}\u{104}\x03\x02\x02\x02\
-		\u{3bd}\u{3be}\x09\x1f\x02\x02\u{3be}\u{106}\x03\x02\x02\x02\u{3bf}\u{3c0}\
-		\x09\x2f\x02\x02\u{3c0}\u{108}\x03\x02\x02\x02\u{3c1}\u{3c2}\x0a\x20\x02\
-		\x02\u{3c2}\u{10a}\x03\x02\x02\x02\u{3c3}\u{3c4}\x0a\x21\x02\x02\u{3c4}\
-		\u{10c}\x03\x02\x02\x02\u{3c5}\u{3c6}\x0a\x22\x02\x02\u{3c6}\u{10e}\x03\
-		\x02\x02\x02\u{3c7}\u{3c8}\x0a\x23\x02\x02\u{3c8}\u{110}\x03\x02\x02\x02\
-		\u{3c9}\u{3ca}\x09\x24\x02\x02\u{3ca}\u{112}\x03\x02\x02\x02\u{3cb}\u{3cc}\
-		\x09\x25\x02\x02\u{3cc}\u{114}\x03\x02\x02\x02\u{3cd}\u{3ce}\x09\x26\x02\
-		\x02\u{3ce}\u{116}\x03\x02\x02\x02\u{3cf}\u{3d0}\x09\x27\x02\x02\u{3d0}\
-		\u{118}\x03\x02\x02\x02\u{3d1}\u{3d2}\x09\x28\x02\x02\u{3d2}\u{11a}\x03\
-		\x02\x02\x02\u{3d3}\u{3d4}\x09\x29\x02\x02\u{3d4}\u{11c}\x03\x02\x02\x02\
-		\u{3d5}\u{3d6}\x09\x2a\x02\x02\u{3d6}\u{11e}\x03\x02\x02\x02\u{3d7}\u{3d8}\
-		\x0a\x2b\x02\x02\u{3d8}\u{120}\x03\x02\x02\x02\u{3d9}\u{3da}\x09\x2c\x02\
-		\x02\u{3da}\u{122}\x03\x02\x02\x02\u{3db}\u{3dc}\x09\x2d\x02\x02\u{3dc}\
-		\u{124}\x03\x02\x02\x02\u{3dd}\u{3de}\x09\x2e\x02\x02\u{3de}\u{126}\x03\
-		\x02\x02\x02\u{3df}\u{3e0}\x09\x30\x02\x02\u{3e0}\u{128}\x03\x02\x02\x02\
-		\x28\x02\u{29e}\u{2a0}\u{2a7}\u{2a9}\u{2ad}\u{2c1}\u{2c9}\u{2d0}\u{2d3}\
-		\u{2d9}\u{2dc}\u{2e0}\u{2e4}\u{2e8}\u{2ee}\u{2f5}\u{2fa}\u{300}\u{306}\
-		\u{308}\u{30c}\u{311}\u{316}\u{31d}\u{370}\u{375}\u{379}\u{37f}\u{385}\
-		\u{38a}\u{398}\u{3a0}\u{3a2}\u{3ad}\u{3b1}\u{3b5}\u{3b7}\x02";
+		\u{d7ac}\x03\u{d7c4}\x03\u{d7c6}\x03\u{d7cd}\x03\u{d7d0}\x03\u{10801}\x03\
+		\u{10a02}\x03\u{10a38}\x03\u{10a3d}\x03\u{10a6e}\x03\u{10a77}\x03\u{10a77}\
+		\x03\u{10a86}\x03\u{10a86}\x03\u{10a9d}\x03\u{10aa1}\x03\u{10aa3}\x03\u{10ab1}\
+		\x03\u{e002}\x03\u{e008}\x03\u{e00a}\x03\u{e01a}\x03\u{e01d}\x03\u{e023}\
+		\x03\u{e025}\x03\u{e026}\x03\u{e028}\x03\u{e02c}\x03\u{e802}\x03\u{e8c6}\
+		\x03\u{e8d2}\x03\u{e8d8}\x03\u{e902}\x03\u{e94c}\x03\u{e952}\x03\u{e95b}\
+		\x03\u{ee02}\x03\u{ee05}\x03\u{ee07}\x03\u{ee21}\x03\u{ee23}\x03\u{ee24}\
+		\x03\u{ee26}\x03\u{ee26}\x03\u{ee29}\x03\u{ee29}\x03\u{ee2b}\x03\u{ee34}\
+		\x03\u{ee36}\x03\u{ee39}\x03\u{ee3b}\x03\u{ee3b}\x03\u{ee3d}\x03\u{ee3d}\
+		\x03\u{ee44}\x03\u{ee44}\x03\u{ee49}\x03\u{ee49}\x03\u{ee4b}\x03\u{ee4b}\
+		\x03\u{ee4d}\x03\u{ee4d}\x03\u{ee4f}\x03\u{ee51}\x03\u{ee53}\x03\u{ee54}\
+		\x03\u{ee56}\x03\u{ee56}\x03\u{ee59}\x03\u{ee59}\x03\u{ee5b}\x03\u{ee5b}\
+		\x03\u{ee5d}\x03\u{ee5d}\x03\u{ee5f}\x03\u{ee5f}\x03\u{ee61}\x03\u{ee61}\
+		\x03\u{ee63}\x03\u{ee64}\x03\u{ee66}\x03\u{ee66}\x03\u{ee69}\x03\u{ee6c}\
+		\x03\u{ee6e}\x03\u{ee74}\x03\u{ee76}\x03\u{ee79}\x03\u{ee7b}\x03\u{ee7e}\
+		\x03\u{ee80}\x03\u{ee80}\x03\u{ee82}\x03\u{ee8b}\x03\u{ee8d}\x03\u{ee9d}\
+		\x03\u{eea3}\x03\u{eea5}\x03\u{eea7}\x03\u{eeab}\x03\u{eead}\x03\u{eebd}\
+		\x03\x02\x04\u{a6d8}\x04\u{a702}\x04\u{b736}\x04\u{b742}\x04\u{b81f}\x04\
+		\u{b822}\x04\u{cea3}\x04\u{ceb2}\x04\u{ebe2}\x04\u{f802}\x04\u{fa1f}\x04\
+		\u{102}\x10\u{1f1}\x10\u{24b}\x02\x43\x02\x5c\x02\x63\x02\x7c\x02\u{ac}\
+		\x02\u{ac}\x02\u{b7}\x02\u{b7}\x02\u{bc}\x02\u{bc}\x02\u{c2}\x02\u{d8}\
+		\x02\u{da}\x02\u{f8}\x02\u{fa}\x02\u{2c3}\x02\u{2c8}\x02\u{2d3}\x02\u{2e2}\
+		\x02\u{2e6}\x02\u{2ee}\x02\u{2ee}\x02\u{2f0}\x02\u{2f0}\x02\u{372}\x02\
+		\u{376}\x02\u{378}\x02\u{379}\x02\u{37c}\x02\u{37f}\x02\u{381}\x02\u{381}\
+		\x02\u{388}\x02\u{388}\x02\u{38a}\x02\u{38c}\x02\u{38e}\x02\u{38e}\x02\
+		\u{390}\x02\u{3a3}\x02\u{3a5}\x02\u{3f7}\x02\u{3f9}\x02\u{483}\x02\u{48c}\
+		\x02\u{531}\x02\u{533}\x02\u{558}\x02\u{55b}\x02\u{55b}\x02\u{563}\x02\
+		\u{589}\x02\u{5d2}\x02\u{5ec}\x02\u{5f2}\x02\u{5f4}\x02\u{622}\x02\u{64c}\
+		\x02\u{670}\x02\u{671}\x02\u{673}\x02\u{6d5}\x02\u{6d7}\x02\u{6d7}\x02\
+		\u{6e7}\x02\u{6e8}\x02\u{6f0}\x02\u{6f1}\x02\u{6fc}\x02\u{6fe}\x02\u{701}\
+		\x02\u{701}\x02\u{712}\x02\u{712}\x02\u{714}\x02\u{731}\x02\u{74f}\x02\
+		\u{7a7}\x02\u{7b3}\x02\u{7b3}\x02\u{7cc}\x02\u{7ec}\x02\u{7f6}\x02\u{7f7}\
+		\x02\u{7fc}\x02\u{7fc}\x02\u{802}\x02\u{817}\x02\u{81c}\x02\u{81c}\x02\
+		\u{826}\x02\u{826}\x02\u{82a}\x02\u{82a}\x02\u{842}\x02\u{85a}\x02\u{862}\
+		\x02\u{86c}\x02\u{8a2}\x02\u{8b6}\x02\u{8b8}\x02\u{8bf}\x02\u{906}\x02\
+		\u{93b}\x02\u{93f}\x02\u{93f}\x02\u{952}\x02\u{952}\x02\u{95a}\x02\u{963}\
+		\x02\u{973}\x02\u{982}\x02\u{987}\x02\u{98e}\x02\u{991}\x02\u{992}\x02\
+		\u{995}\x02\u{9aa}\x02\u{9ac}\x02\u{9b2}\x02\u{9b4}\x02\u{9b4}\x02\u{9b8}\
+		\x02\u{9bb}\x02\u{9bf}\x02\u{9bf}\x02\u{9d0}\x02\u{9d0}\x02\u{9de}\x02\
+		\u{9df}\x02\u{9e1}\x02\u{9e3}\x02\u{9f2}\x02\u{9f3}\x02\u{9fe}\x02\u{9fe}\
+		\x02\u{a07}\x02\u{a0c}\x02\u{a11}\x02\u{a12}\x02\u{a15}\x02\u{a2a}\x02\
+		\u{a2c}\x02\u{a32}\x02\u{a34}\x02\u{a35}\x02\u{a37}\x02\u{a38}\x02\u{a3a}\
+		\x02\u{a3b}\x02\u{a5b}\x02\u{a5e}\x02\u{a60}\x02\u{a60}\x02\u{a74}\x02\
+		\u{a76}\x02\u{a87}\x02\u{a8f}\x02\u{a91}\x02\u{a93}\x02\u{a95}\x02\u{aaa}\
+		\x02\u{aac}\x02\u{ab2}\x02\u{ab4}\x02\u{ab5}\x02\u{ab7}\x02\u{abb}\x02\
+		\u{abf}\x02\u{abf}\x02\u{ad2}\x02\u{ad2}\x02\u{ae2}\x02\u{ae3}\x02\u{afb}\
+		\x02\u{afb}\x02\u{b07}\x02\u{b0e}\x02\u{b11}\x02\u{b12}\x02\u{b15}\x02\
+		\u{b2a}\x02\u{b2c}\x02\u{b32}\x02\u{b34}\x02\u{b35}\x02\u{b37}\x02\u{b3b}\
+		\x02\u{b3f}\x02\u{b3f}\x02\u{b5e}\x02\u{b5f}\x02\u{b61}\x02\u{b63}\x02\
+		\u{b73}\x02\u{b73}\x02\u{b85}\x02\u{b85}\x02\u{b87}\x02\u{b8c}\x02\u{b90}\
+		\x02\u{b92}\x02\u{b94}\x02\u{b97}\x02\u{b9b}\x02\u{b9c}\x02\u{b9e}\x02\
+		\u{b9e}\x02\u{ba0}\x02\u{ba1}\x02\u{ba5}\x02\u{ba6}\x02\u{baa}\x02\u{bac}\
+		\x02\u{bb0}\x02\u{bbb}\x02\u{bd2}\x02\u{bd2}\x02\u{c07}\x02\u{c0e}\x02\
+		\u{c10}\x02\u{c12}\x02\u{c14}\x02\u{c2a}\x02\u{c2c}\x02\u{c3b}\x02\u{c3f}\
+		\x02\u{c3f}\x02\u{c5a}\x02\u{c5c}\x02\u{c62}\x02\u{c63}\x02\u{c82}\x02\
+		\u{c82}\x02\u{c87}\x02\u{c8e}\x02\u{c90}\x02\u{c92}\x02\u{c94}\x02\u{caa}\
+		\x02\u{cac}\x02\u{cb5}\x02\u{cb7}\x02\u{cbb}\x02\u{cbf}\x02\u{cbf}\x02\
+		\u{ce0}\x02\u{ce0}\x02\u{ce2}\x02\u{ce3}\x02\u{cf3}\x02\u{cf4}\x02\u{d07}\
+		\x02\u{d0e}\x02\u{d10}\x02\u{d12}\x02\u{d14}\x02\u{d3c}\x02\u{d3f}\x02\
+		\u{d3f}\x02\u{d50}\x02\u{d50}\x02\u{d56}\x02\u{d58}\x02\u{d61}\x02\u{d63}\
+		\x02\u{d7c}\x02\u{d81}\x02\u{d87}\x02\u{d98}\x02\u{d9c}\x02\u{db3}\x02\
+		\u{db5}\x02\u{dbd}\x02\u{dbf}\x02\u{dbf}\x02\u{dc2}\x02\u{dc8}\x02\u{e03}\
+		\x02\u{e32}\x02\u{e34}\x02\u{e35}\x02\u{e42}\x02\u{e48}\x02\u{e83}\x02\
+		\u{e84}\x02\u{e86}\x02\u{e86}\x02\u{e89}\x02\u{e8a}\x02\u{e8c}\x02\u{e8c}\
+		\x02\u{e8f}\x02\u{e8f}\x02\u{e96}\x02\u{e99}\x02\u{e9b}\x02\u{ea1}\x02\
+		\u{ea3}\x02\u{ea5}\x02\u{ea7}\x02\u{ea7}\x02\u{ea9}\x02\u{ea9}\x02\u{eac}\
+		\x02\u{ead}\x02\u{eaf}\x02\u{eb2}\x02\u{eb4}\x02\u{eb5}\x02\u{ebf}\x02\
+		\u{ebf}\x02\u{ec2}\x02\u{ec6}\x02\u{ec8}\x02\u{ec8}\x02\u{ede}\x02\u{ee1}\
+		\x02\u{f02}\x02\u{f02}\x02\u{f42}\x02\u{f49}\x02\u{f4b}\x02\u{f6e}\x02\
+		\u{f8a}\x02\u{f8e}\x02\u{1002}\x02\u{102c}\x02\u{1041}\x02\u{1041}\x02\
+		\u{1052}\x02\u{1057}\x02\u{105c}\x02\u{105f}\x02\u{1063}\x02\u{1063}\x02\
+		\u{1067}\x02\u{1068}\x02\u{1070}\x02\u{1072}\x02\u{1077}\x02\u{1083}\x02\
+		\u{1090}\x02\u{1090}\x02\u{10a2}\x02\u{10c7}\x02\u{10c9}\x02\u{10c9}\x02\
+		\u{10cf}\x02\u{10cf}\x02\u{10d2}\x02\u{10fc}\x02\u{10fe}\x02\u{124a}\x02\
+		\u{124c}\x02\u{124f}\x02\u{1252}\x02\u{1258}\x02\u{125a}\x02\u{125a}\x02\
+		\u{125c}\x02\u{125f}\x02\u{1262}\x02\u{128a}\x02\u{128c}\x02\u{128f}\x02\
+		\u{1292}\x02\u{12b2}\x02\u{12b4}\x02\u{12b7}\x02\u{12ba}\x02\u{12c0}\x02\
+		\u{12c2}\x02\u{12c2}\x02\u{12c4}\x02\u{12c7}\x02\u{12ca}\x02\u{12d8}\x02\
+		\u{12da}\x02\u{1312}\x02\u{1314}\x02\u{1317}\x02\u{131a}\x02\u{135c}\x02\
+		\u{1382}\x02\u{1391}\x02\u{13a2}\x02\u{13f7}\x02\u{13fa}\x02\u{13ff}\x02\
+		\u{1403}\x02\u{166e}\x02\u{1671}\x02\u{1681}\x02\u{1683}\x02\u{169c}\x02\
+		\u{16a2}\x02\u{16ec}\x02\u{16f0}\x02\u{16fa}\x02\u{1702}\x02\u{170e}\x02\
+		\u{1710}\x02\u{1713}\x02\u{1722}\x02\u{1733}\x02\u{1742}\x02\u{1753}\x02\
+		\u{1762}\x02\u{176e}\x02\u{1770}\x02\u{1772}\x02\u{1782}\x02\u{17b5}\x02\
+		\u{17d9}\x02\u{17d9}\x02\u{17de}\x02\u{17de}\x02\u{1822}\x02\u{1879}\x02\
+		\u{1882}\x02\u{18aa}\x02\u{18ac}\x02\u{18ac}\x02\u{18b2}\x02\u{18f7}\x02\
+		\u{1902}\x02\u{1920}\x02\u{1952}\x02\u{196f}\x02\u{1972}\x02\u{1976}\x02\
+		\u{1982}\x02\u{19ad}\x02\u{19b2}\x02\u{19cb}\x02\u{1a02}\x02\u{1a18}\x02\
+		\u{1a22}\x02\u{1a56}\x02\u{1aa9}\x02\u{1aa9}\x02\u{1b07}\x02\u{1b35}\x02\
+		\u{1b47}\x02\u{1b4d}\x02\u{1b85}\x02\u{1ba2}\x02\u{1bb0}\x02\u{1bb1}\x02\
+		\u{1bbc}\x02\u{1be7}\x02\u{1c02}\x02\u{1c25}\x02\u{1c4f}\x02\u{1c51}\x02\
+		\u{1c5c}\x02\u{1c7f}\x02\u{1c82}\x02\u{1c8a}\x02\u{1ceb}\x02\u{1cee}\x02\
+		\u{1cf0}\x02\u{1cf3}\x02\u{1cf7}\x02\u{1cf8}\x02\u{1d02}\x02\u{1dc1}\x02\
+		\u{1e02}\x02\u{1f17}\x02\u{1f1a}\x02\u{1f1f}\x02\u{1f22}\x02\u{1f47}\x02\
+		\u{1f4a}\x02\u{1f4f}\x02\u{1f52}\x02\u{1f59}\x02\u{1f5b}\x02\u{1f5b}\x02\
+		\u{1f5d}\x02\u{1f5d}\x02\u{1f5f}\x02\u{1f5f}\x02\u{1f61}\x02\u{1f7f}\x02\
+		\u{1f82}\x02\u{1fb6}\x02\u{1fb8}\x02\u{1fbe}\x02\u{1fc0}\x02\u{1fc0}\x02\
+		\u{1fc4}\x02\u{1fc6}\x02\u{1fc8}\x02\u{1fce}\x02\u{1fd2}\x02\u{1fd5}\x02\
+		\u{1fd8}\x02\u{1fdd}\x02\u{1fe2}\x02\u{1fee}\x02\u{1ff4}\x02\u{1ff6}\x02\
+		\u{1ff8}\x02\u{1ffe}\x02\u{2073}\x02\u{2073}\x02\u{2081}\x02\u{2081}\x02\
+		\u{2092}\x02\u{209e}\x02\u{2104}\x02\u{2104}\x02\u{2109}\x02\u{2109}\x02\
+		\u{210c}\x02\u{2115}\x02\u{2117}\x02\u{2117}\x02\u{211a}\x02\u{211f}\x02\
+		\u{2126}\x02\u{2126}\x02\u{2128}\x02\u{2128}\x02\u{212a}\x02\u{212a}\x02\
+		\u{212c}\x02\u{213b}\x02\u{213e}\x02\u{2141}\x02\u{2147}\x02\u{214b}\x02\
+		\u{2150}\x02\u{2150}\x02\u{2162}\x02\u{218a}\x02\u{2c02}\x02\u{2c30}\x02\
+		\u{2c32}\x02\u{2c60}\x02\u{2c62}\x02\u{2ce6}\x02\u{2ced}\x02\u{2cf0}\x02\
+		\u{2cf4}\x02\u{2cf5}\x02\u{2d02}\x02\u{2d27}\x02\u{2d29}\x02\u{2d29}\x02\
+		\u{2d2f}\x02\u{2d2f}\x02\u{2d32}\x02\u{2d69}\x02\u{2d71}\x02\u{2d71}\x02\
+		\u{2d82}\x02\u{2d98}\x02\u{2da2}\x02\u{2da8}\x02\u{2daa}\x02\u{2db0}\x02\
+		\u{2db2}\x02\u{2db8}\x02\u{2dba}\x02\u{2dc0}\x02\u{2dc2}\x02\u{2dc8}\x02\
+		\u{2dca}\x02\u{2dd0}\x02\u{2dd2}\x02\u{2dd8}\x02\u{2dda}\x02\u{2de0}\x02\
+		\u{3007}\x02\u{3009}\x02\u{3023}\x02\u{302b}\x02\u{3033}\x02\u{3037}\x02\
+		\u{303a}\x02\u{303e}\x02\u{3043}\x02\u{3098}\x02\u{309d}\x02\u{30a1}\x02\
+		\u{30a3}\x02\u{30fc}\x02\u{30fe}\x02\u{3101}\x02\u{3107}\x02\u{3130}\x02\
+		\u{3133}\x02\u{3190}\x02\u{31a2}\x02\u{31bc}\x02\u{31f2}\x02\u{3201}\x02\
+		\u{3402}\x02\u{4db7}\x02\u{4e02}\x02\u{9fec}\x02\u{a002}\x02\u{a48e}\x02\
+		\u{a4d2}\x02\u{a4ff}\x02\u{a502}\x02\u{a60e}\x02\u{a612}\x02\u{a621}\x02\
+		\u{a62c}\x02\u{a62d}\x02\u{a642}\x02\u{a670}\x02\u{a681}\x02\u{a69f}\x02\
+		\u{a6a2}\x02\u{a6f1}\x02\u{a719}\x02\u{a721}\x02\u{a724}\x02\u{a78a}\x02\
+		\u{a78d}\x02\u{a7b0}\x02\u{a7b2}\x02\u{a7b9}\x02\u{a7f9}\x02\u{a803}\x02\
+		\u{a805}\x02\u{a807}\x02\u{a809}\x02\u{a80c}\x02\u{a80e}\x02\u{a824}\x02\
+		\u{a842}\x02\u{a875}\x02\u{a884}\x02\u{a8b5}\x02\u{a8f4}\x02\u{a8f9}\x02\
+		\u{a8fd}\x02\u{a8fd}\x02\u{a8ff}\x02\u{a8ff}\x02\u{a90c}\x02\u{a927}\x02\
+		\u{a932}\x02\u{a948}\x02\u{a962}\x02\u{a97e}\x02\u{a986}\x02\u{a9b4}\x02\
+		\u{a9d1}\x02\u{a9d1}\x02\u{a9e2}\x02\u{a9e6}\x02\u{a9e8}\x02\u{a9f1}\x02\
+		\u{a9fc}\x02\u{aa00}\x02\u{aa02}\x02\u{aa2a}\x02\u{aa42}\x02\u{aa44}\x02\
+		\u{aa46}\x02\u{aa4d}\x02\u{aa62}\x02\u{aa78}\x02\u{aa7c}\x02\u{aa7c}\x02\
+		\u{aa80}\x02\u{aab1}\x02\u{aab3}\x02\u{aab3}\x02\u{aab7}\x02\u{aab8}\x02\
+		\u{aabb}\x02\u{aabf}\x02\u{aac2}\x02\u{aac2}\x02\u{aac4}\x02\u{aac4}\x02\
+		\u{aadd}\x02\u{aadf}\x02\u{aae2}\x02\u{aaec}\x02\u{aaf4}\x02\u{aaf6}\x02\
+		\u{ab03}\x02\u{ab08}\x02\u{ab0b}\x02\u{ab10}\x02\u{ab13}\x02\u{ab18}\x02\
+		\u{ab22}\x02\u{ab28}\x02\u{ab2a}\x02\u{ab30}\x02\u{ab32}\x02\u{ab5c}\x02\
+		\u{ab5e}\x02\u{ab67}\x02\u{ab72}\x02\u{abe4}\x02\u{ac02}\x02\u{d7a5}\x02\
+		\u{d7b2}\x02\u{d7c8}\x02\u{d7cd}\x02\u{d7fd}\x02\u{f902}\x02\u{fa6f}\x02\
+		\u{fa72}\x02\u{fadb}\x02\u{fb02}\x02\u{fb08}\x02\u{fb15}\x02\u{fb19}\x02\
+		\u{fb1f}\x02\u{fb1f}\x02\u{fb21}\x02\u{fb2a}\x02\u{fb2c}\x02\u{fb38}\x02\
+		\u{fb3a}\x02\u{fb3e}\x02\u{fb40}\x02\u{fb40}\x02\u{fb42}\x02\u{fb43}\x02\
+		\u{fb45}\x02\u{fb46}\x02\u{fb48}\x02\u{fbb3}\x02\u{fbd5}\x02\u{fd3f}\x02\
+		\u{fd52}\x02\u{fd91}\x02\u{fd94}\x02\u{fdc9}\x02\u{fdf2}\x02\u{fdfd}\x02\
+		\u{fe72}\x02\u{fe76}\x02\u{fe78}\x02\u{fefe}\x02\u{ff23}\x02\u{ff3c}\x02\
+		\u{ff43}\x02\u{ff5c}\x02\u{ff68}\x02\u{ffc0}\x02\u{ffc4}\x02\u{ffc9}\x02\
+		\u{ffcc}\x02\u{ffd1}\x02\u{ffd4}\x02\u{ffd9}\x02\u{ffdc}\x02\u{ffde}\x02\
+		\x02\x03\x0d\x03\x0f\x03\x28\x03\x2a\x03\x3c\x03\x3e\x03\x3f\x03\x41\x03\
+		\x4f\x03\x52\x03\x5f\x03\u{82}\x03\u{fc}\x03\u{142}\x03\u{176}\x03\u{282}\
+		\x03\u{29e}\x03\u{2a2}\x03\u{2d2}\x03\u{302}\x03\u{321}\x03\u{32f}\x03\
+		\u{34c}\x03\u{352}\x03\u{377}\x03\u{382}\x03\u{39f}\x03\u{3a2}\x03\u{3c5}\
+		\x03\u{3ca}\x03\u{3d1}\x03\u{3d3}\x03\u{3d7}\x03\u{402}\x03\u{49f}\x03\
+		\u{4b2}\x03\u{4d5}\x03\u{4da}\x03\u{4fd}\x03\u{502}\x03\u{529}\x03\u{532}\
+		\x03\u{565}\x03\u{602}\x03\u{738}\x03\u{742}\x03\u{757}\x03\u{762}\x03\
+		\u{769}\x03\u{802}\x03\u{807}\x03\u{80a}\x03\u{80a}\x03\u{80c}\x03\u{837}\
+		\x03\u{839}\x03\u{83a}\x03\u{83e}\x03\u{83e}\x03\u{841}\x03\u{857}\x03\
+		\u{862}\x03\u{878}\x03\u{882}\x03\u{8a0}\x03\u{8e2}\x03\u{8f4}\x03\u{8f6}\
+		\x03\u{8f7}\x03\u{902}\x03\u{917}\x03\u{922}\x03\u{93b}\x03\u{982}\x03\
+		\u{9b9}\x03\u{9c0}\x03\u{9c1}\x03\u{a02}\x03\u{a02}\x03\u{a12}\x03\u{a15}\
+		\x03\u{a17}\x03\u{a19}\x03\u{a1b}\x03\u{a35}\x03\u{a62}\x03\u{a7e}\x03\
+		\u{a82}\x03\u{a9e}\x03\u{ac2}\x03\u{ac9}\x03\u{acb}\x03\u{ae6}\x03\u{b02}\
+		\x03\u{b37}\x03\u{b42}\x03\u{b57}\x03\u{b62}\x03\u{b74}\x03\u{b82}\x03\
+		\u{b93}\x03\u{c02}\x03\u{c4a}\x03\u{c82}\x03\u{cb4}\x03\u{cc2}\x03\u{cf4}\
+		\x03\u{1005}\x03\u{1039}\x03\u{1085}\x03\u{10b1}\x03\u{10d2}\x03\u{10ea}\
+		\x03\u{1105}\x03\u{1128}\x03\u{1152}\x03\u{1174}\x03\u{1178}\x03\u{1178}\
+		\x03\u{1185}\x03\u{11b4}\x03\u{11c3}\x03\u{11c6}\x03\u{11dc}\x03\u{11dc}\
+		\x03\u{11de}\x03\u{11de}\x03\u{1202}\x03\u{1213}\x03\u{1215}\x03\u{122d}\
+		\x03\u{1282}\x03\u{1288}\x03\u{128a}\x03\u{128a}\x03\u{128c}\x03\u{128f}\
+		\x03\u{1291}\x03\u{129f}\x03\u{12a1}\x03\u{12aa}\x03\u{12b2}\x03\u{12e0}\
+		\x03\u{1307}\x03\u{130e}\x03\u{1311}\x03\u{1312}\x03\u{1315}\x03\u{132a}\
+		\x03\u{132c}\x03\u{1332}\x03\u{1334}\x03\u{1335}\x03\u{1337}\x03\u{133b}\
+		\x03\u{133f}\x03\u{133f}\x03\u{1352}\x03\u{1352}\x03\u{135f}\x03\u{1363}\
+		\x03\u{1402}\x03\u{1436}\x03\u{1449}\x03\u{144c}\x03\u{1482}\x03\u{14b1}\
+		\x03\u{14c6}\x03\u{14c7}\x03\u{14c9}\x03\u{14c9}\x03\u{1582}\x03\u{15b0}\
+		\x03\u{15da}\x03\u{15dd}\x03\u{1602}\x03\u{1631}\x03\u{1646}\x03\u{1646}\
+		\x03\u{1682}\x03\u{16ac}\x03\u{1702}\x03\u{171b}\x03\u{18a2}\x03\u{18e1}\
+		\x03\u{1901}\x03\u{1901}\x03\u{1a02}\x03\u{1a02}\x03\u{1a0d}\x03\u{1a34}\
+		\x03\u{1a3c}\x03\u{1a3c}\x03\u{1a52}\x03\u{1a52}\x03\u{1a5e}\x03\u{1a85}\
+		\x03\u{1a88}\x03\u{1a8b}\x03\u{1ac2}\x03\u{1afa}\x03\u{1c02}\x03\u{1c0a}\
+		\x03\u{1c0c}\x03\u{1c30}\x03\u{1c42}\x03\u{1c42}\x03\u{1c74}\x03\u{1c91}\
+		\x03\u{1d02}\x03\u{1d08}\x03\u{1d0a}\x03\u{1d0b}\x03\u{1d0d}\x03\u{1d32}\
+		\x03\u{1d48}\x03\u{1d48}\x03\u{2002}\x03\u{239b}\x03\u{2402}\x03\u{2470}\
+		\x03\u{2482}\x03\u{2545}\x03\u{3002}\x03\u{3430}\x03\u{4402}\x03\u{4648}\
+		\x03\u{6802}\x03\u{6a3a}\x03\u{6a42}\x03\u{6a60}\x03\u{6ad2}\x03\u{6aef}\
+		\x03\u{6b02}\x03\u{6b31}\x03\u{6b42}\x03\u{6b45}\x03\u{6b65}\x03\u{6b79}\
+		\x03\u{6b7f}\x03\u{6b91}\x03\u{6f02}\x03\u{6f46}\x03\u{6f52}\x03\u{6f52}\
+		\x03\u{6f95}\x03\u{6fa1}\x03\u{6fe2}\x03\u{6fe3}\x03\u{7002}\x03\u{87ee}\
+		\x03\u{8802}\x03\u{8af4}\x03\u{b002}\x03\u{b120}\x03\u{b172}\x03\u{b2fd}\
+		\x03\u{bc02}\x03\u{bc6c}\x03\u{bc72}\x03\u{bc7e}\x03\u{bc82}\x03\u{bc8a}\
+		\x03\u{bc92}\x03\u{bc9b}\x03\u{d402}\x03\u{d456}\x03\u{d458}\x03\u{d49e}\
+		\x03\u{d4a0}\x03\u{d4a1}\x03\u{d4a4}\x03\u{d4a4}\x03\u{d4a7}\x03\u{d4a8}\
+		\x03\u{d4ab}\x03\u{d4ae}\x03\u{d4b0}\x03\u{d4bb}\x03\u{d4bd}\x03\u{d4bd}\
+		\x03\u{d4bf}\x03\u{d4c5}\x03\u{d4c7}\x03\u{d507}\x03\u{d509}\x03\u{d50c}\
+		\x03\u{d50f}\x03\u{d516}\x03\u{d518}\x03\u{d51e}\x03\u{d520}\x03\u{d53b}\
+		\x03\u{d53d}\x03\u{d540}\x03\u{d542}\x03\u{d546}\x03\u{d548}\x03\u{d548}\
+		\x03\u{d54c}\x03\u{d552}\x03\u{d554}\x03\u{d6a7}\x03\u{d6aa}\x03\u{d6c2}\
+		\x03\u{d6c4}\x03\u{d6dc}\x03\u{d6de}\x03\u{d6fc}\x03\u{d6fe}\x03\u{d716}\
+		\x03\u{d718}\x03\u{d736}\x03\u{d738}\x03\u{d750}\x03\u{d752}\x03\u{d770}\
+		\x03\u{d772}\x03\u{d78a}\x03\u{d78c}\x03\u{d7aa}\x03\u{d7ac}\x03\u{d7c4}\
+		\x03\u{d7c6}\x03\u{d7cd}\x03\u{e802}\x03\u{e8c6}\x03\u{e902}\x03\u{e945}\
+		\x03\u{ee02}\x03\u{ee05}\x03\u{ee07}\x03\u{ee21}\x03\u{ee23}\x03\u{ee24}\
+		\x03\u{ee26}\x03\u{ee26}\x03\u{ee29}\x03\u{ee29}\x03\u{ee2b}\x03\u{ee34}\
+		\x03\u{ee36}\x03\u{ee39}\x03\u{ee3b}\x03\u{ee3b}\x03\u{ee3d}\x03\u{ee3d}\
+		\x03\u{ee44}\x03\u{ee44}\x03\u{ee49}\x03\u{ee49}\x03\u{ee4b}\x03\u{ee4b}\
+		\x03\u{ee4d}\x03\u{ee4d}\x03\u{ee4f}\x03\u{ee51}\x03\u{ee53}\x03\u{ee54}\
+		\x03\u{ee56}\x03\u{ee56}\x03\u{ee59}\x03\u{ee59}\x03\u{ee5b}\x03\u{ee5b}\
+		\x03\u{ee5d}\x03\u{ee5d}\x03\u{ee5f}\x03\u{ee5f}\x03\u{ee61}\x03\u{ee61}\
+		\x03\u{ee63}\x03\u{ee64}\x03\u{ee66}\x03\u{ee66}\x03\u{ee69}\x03\u{ee6c}\
+		\x03\u{ee6e}\x03\u{ee74}\x03\u{ee76}\x03\u{ee79}\x03\u{ee7b}\x03\u{ee7e}\
+		\x03\u{ee80}\x03\u{ee80}\x03\u{ee82}\x03\u{ee8b}\x03\u{ee8d}\x03\u{ee9d}\
+		\x03\u{eea3}\x03\u{eea5}\x03\u{eea7}\x03\u{eeab}\x03\u{eead}\x03\u{eebd}\
+		\x03\x02\x04\u{a6d8}\x04\u{a702}\x04\u{b736}\x04\u{b742}\x04\u{b81f}\x04\
+		\u{b822}\x04\u{cea3}\x04\u{ceb2}\x04\u{ebe2}\x04\u{f802}\x04\u{fa1f}\x04\
+		\u{401}\x02\x03\x03\x02\x02\x02\x02\x05\x03\x02\x02\x02\x02\x07\x03\x02\
+		\x02\x02\x02\x09\x03\x02\x02\x02\x02\x0b\x03\x02\x02\x02\x02\x0d\x03\x02\
+		\x02\x02\x02\x0f\x03\x02\x02\x02\x02\x11\x03\x02\x02\x02\x02\x13\x03\x02\
+		\x02\x02\x02\x15\x03\x02\x02\x02\x02\x17\x03\x02\x02\x02\x02\x19\x03\x02\
+		\x02\x02\x02\x1b\x03\x02\x02\x02\x02\x1d\x03\x02\x02\x02\x02\x1f\x03\x02\
+		\x02\x02\x02\x21\x03\x02\x02\x02\x02\x23\x03\x02\x02\x02\x02\x25\x03\x02\
+		\x02\x02\x02\x27\x03\x02\x02\x02\x02\x29\x03\x02\x02\x02\x02\x2b\x03\x02\
+		\x02\x02\x02\x2d\x03\x02\x02\x02\x02\x2f\x03\x02\x02\x02\x02\x31\x03\x02\
+		\x02\x02\x02\x33\x03\x02\x02\x02\x02\x35\x03\x02\x02\x02\x02\x37\x03\x02\
+		\x02\x02\x02\x39\x03\x02\x02\x02\x02\x3b\x03\x02\x02\x02\x02\x3d\x03\x02\
+		\x02\x02\x02\x3f\x03\x02\x02\x02\x02\x41\x03\x02\x02\x02\x02\x43\x03\x02\
+		\x02\x02\x02\x45\x03\x02\x02\x02\x02\x47\x03\x02\x02\x02\x02\x49\x03\x02\
+		\x02\x02\x02\x4b\x03\x02\x02\x02\x02\x4d\x03\x02\x02\x02\x02\x4f\x03\x02\
+		\x02\x02\x02\x51\x03\x02\x02\x02\x02\x53\x03\x02\x02\x02\x02\x55\x03\x02\
+		\x02\x02\x02\x57\x03\x02\x02\x02\x02\x59\x03\x02\x02\x02\x02\x5b\x03\x02\
+		\x02\x02\x02\x5d\x03\x02\x02\x02\x02\x5f\x03\x02\x02\x02\x02\x61\x03\x02\
+		\x02\x02\x02\x63\x03\x02\x02\x02\x02\x65\x03\x02\x02\x02\x02\x67\x03\x02\
+		\x02\x02\x02\x69\x03\x02\x02\x02\x02\x6b\x03\x02\x02\x02\x02\x6d\x03\x02\
+		\x02\x02\x02\x6f\x03\x02\x02\x02\x02\x71\x03\x02\x02\x02\x02\x73\x03\x02\
+		\x02\x02\x02\x75\x03\x02\x02\x02\x02\x77\x03\x02\x02\x02\x02\x79\x03\x02\
+		\x02\x02\x02\x7b\x03\x02\x02\x02\x02\x7d\x03\x02\x02\x02\x02\x7f\x03\x02\
+		\x02\x02\x02\u{81}\x03\x02\x02\x02\x02\u{83}\x03\x02\x02\x02\x02\u{85}\
+		\x03\x02\x02\x02\x02\u{87}\x03\x02\x02\x02\x02\u{89}\x03\x02\x02\x02\x02\
+		\u{8b}\x03\x02\x02\x02\x02\u{8d}\x03\x02\x02\x02\x02\u{8f}\x03\x02\x02\
+		\x02\x02\u{91}\x03\x02\x02\x02\x02\u{93}\x03\x02\x02\x02\x02\u{95}\x03\
+		\x02\x02\x02\x02\u{97}\x03\x02\x02\x02\x02\u{99}\x03\x02\x02\x02\x02\u{9b}\
+		\x03\x02\x02\x02\x02\u{9d}\x03\x02\x02\x02\x02\u{9f}\x03\x02\x02\x02\x02\
+		\u{a1}\x03\x02\x02\x02\x02\u{a3}\x03\x02\x02\x02\x02\u{a5}\x03\x02\x02\
+		\x02\x02\u{a7}\x03\x02\x02\x02\x02\u{a9}\x03\x02\x02\x02\x02\u{ab}\x03\
+		\x02\x02\x02\x02\u{ad}\x03\x02\x02\x02\x02\u{af}\x03\x02\x02\x02\x02\u{b1}\
+		\x03\x02\x02\x02\x02\u{b3}\x03\x02\x02\x02\x02\u{b5}\x03\x02\x02\x02\x02\
+		\u{b7}\x03\x02\x02\x02\x02\u{b9}\x03\x02\x02\x02\x02\u{bb}\x03\x02\x02\
+		\x02\x02\u{bd}\x03\x02\x02\x02\x02\u{bf}\x03\x02\x02\x02\x02\u{c1}\x03\
+		\x02\x02\x02\x02\u{c3}\x03\x02\x02\x02\x02\u{c5}\x03\x02\x02\x02\x02\u{c7}\
+		\x03\x02\x02\x02\x02\u{c9}\x03\x02\x02\x02\x02\u{cb}\x03\x02\x02\x02\x02\
+		\u{cd}\x03\x02\x02\x02\x02\u{cf}\x03\x02\x02\x02\x02\u{d1}\x03\x02\x02\
+		\x02\x02\u{d3}\x03\x02\x02\x02\x02\u{d5}\x03\x02\x02\x02\x02\u{d7}\x03\
+		\x02\x02\x02\x02\u{d9}\x03\x02\x02\x02\x02\u{db}\x03\x02\x02\x02\x02\u{dd}\
+		\x03\x02\x02\x02\x02\u{df}\x03\x02\x02\x02\x02\u{e1}\x03\x02\x02\x02\x02\
+		\u{e3}\x03\x02\x02\x02\x02\u{e5}\x03\x02\x02\x02\x02\u{e7}\x03\x02\x02\
+		\x02\x02\u{e9}\x03\x02\x02\x02\x02\u{eb}\x03\x02\x02\x02\x02\u{ed}\x03\
+		\x02\x02\x02\x02\u{ef}\x03\x02\x02\x02\x02\u{f1}\x03\x02\x02\x02\x02\u{f3}\
+		\x03\x02\x02\x02\x02\u{f5}\x03\x02\x02\x02\x02\u{f7}\x03\x02\x02\x02\x02\
+		\u{f9}\x03\x02\x02\x02\x02\u{fb}\x03\x02\x02\x02\x02\u{fd}\x03\x02\x02\
+		\x02\x02\u{ff}\x03\x02\x02\x02\x02\u{101}\x03\x02\x02\x02\x03\u{12b}\x03\
+		\x02\x02\x02\x05\u{12d}\x03\x02\x02\x02\x07\u{12f}\x03\x02\x02\x02\x09\
+		\u{131}\x03\x02\x02\x02\x0b\u{134}\x03\x02\x02\x02\x0d\u{136}\x03\x02\x02\
+		\x02\x0f\u{138}\x03\x02\x02\x02\x11\u{13a}\x03\x02\x02\x02\x13\u{13c}\x03\
+		\x02\x02\x02\x15\u{13e}\x03\x02\x02\x02\x17\u{140}\x03\x02\x02\x02\x19\
+		\u{142}\x03\x02\x02\x02\x1b\u{145}\x03\x02\x02\x02\x1d\u{147}\x03\x02\x02\
+		\x02\x1f\u{149}\x03\x02\x02\x02\x21\u{14b}\x03\x02\x02\x02\x23\u{14d}\x03\
+		\x02\x02\x02\x25\u{14f}\x03\x02\x02\x02\x27\u{152}\x03\x02\x02\x02\x29\
+		\u{155}\x03\x02\x02\x02\x2b\u{157}\x03\x02\x02\x02\x2d\u{159}\x03\x02\x02\
+		\x02\x2f\u{15c}\x03\x02\x02\x02\x31\u{15f}\x03\x02\x02\x02\x33\u{161}\x03\
+		\x02\x02\x02\x35\u{163}\x03\x02\x02\x02\x37\u{165}\x03\x02\x02\x02\x39\
+		\u{167}\x03\x02\x02\x02\x3b\u{169}\x03\x02\x02\x02\x3d\u{16b}\x03\x02\x02\
+		\x02\x3f\u{16d}\x03\x02\x02\x02\x41\u{16f}\x03\x02\x02\x02\x43\u{171}\x03\
+		\x02\x02\x02\x45\u{173}\x03\x02\x02\x02\x47\u{175}\x03\x02\x02\x02\x49\
+		\u{177}\x03\x02\x02\x02\x4b\u{179}\x03\x02\x02\x02\x4d\u{17b}\x03\x02\x02\
+		\x02\x4f\u{17d}\x03\x02\x02\x02\x51\u{17f}\x03\x02\x02\x02\x53\u{181}\x03\
+		\x02\x02\x02\x55\u{183}\x03\x02\x02\x02\x57\u{185}\x03\x02\x02\x02\x59\
+		\u{187}\x03\x02\x02\x02\x5b\u{189}\x03\x02\x02\x02\x5d\u{18b}\x03\x02\x02\
+		\x02\x5f\u{18d}\x03\x02\x02\x02\x61\u{193}\x03\x02\x02\x02\x63\u{197}\x03\
+		\x02\x02\x02\x65\u{1a0}\x03\x02\x02\x02\x67\u{1a6}\x03\x02\x02\x02\x69\
+		\u{1ad}\x03\x02\x02\x02\x6b\u{1b0}\x03\x02\x02\x02\x6d\u{1b6}\x03\x02\x02\
+		\x02\x6f\u{1b9}\x03\x02\x02\x02\x71\u{1c0}\x03\x02\x02\x02\x73\u{1c4}\x03\
+		\x02\x02\x02\x75\u{1cb}\x03\x02\x02\x02\x77\u{1d2}\x03\x02\x02\x02\x79\
+		\u{1d9}\x03\x02\x02\x02\x7b\u{1de}\x03\x02\x02\x02\x7d\u{1e4}\x03\x02\x02\
+		\x02\x7f\u{1e9}\x03\x02\x02\x02\u{81}\u{1f0}\x03\x02\x02\x02\u{83}\u{1f9}\
+		\x03\x02\x02\x02\u{85}\u{1ff}\x03\x02\x02\x02\u{87}\u{202}\x03\x02\x02\
+		\x02\u{89}\u{207}\x03\x02\x02\x02\u{8b}\u{20d}\x03\x02\x02\x02\u{8d}\u{217}\
+		\x03\x02\x02\x02\u{8f}\u{21b}\x03\x02\x02\x02\u{91}\u{226}\x03\x02\x02\
+		\x02\u{93}\u{22b}\x03\x02\x02\x02\u{95}\u{231}\x03\x02\x02\x02\u{97}\u{234}\
+		\x03\x02\x02\x02\u{99}\u{238}\x03\x02\x02\x02\u{9b}\u{23c}\x03\x02\x02\
+		\x02\u{9d}\u{240}\x03\x02\x02\x02\u{9f}\u{243}\x03\x02\x02\x02\u{a1}\u{24a}\
+		\x03\x02\x02\x02\u{a3}\u{24f}\x03\x02\x02\x02\u{a5}\u{258}\x03\x02\x02\
+		\x02\u{a7}\u{25b}\x03\x02\x02\x02\u{a9}\u{260}\x03\x02\x02\x02\u{ab}\u{266}\
+		\x03\x02\x02\x02\u{ad}\u{26a}\x03\x02\x02\x02\u{af}\u{26f}\x03\x02\x02\
+		\x02\u{b1}\u{276}\x03\x02\x02\x02\u{b3}\u{27b}\x03\x02\x02\x02\u{b5}\u{281}\
+		\x03\x02\x02\x02\u{b7}\u{288}\x03\x02\x02\x02\u{b9}\u{28d}\x03\x02\x02\
+		\x02\u{bb}\u{292}\x03\x02\x02\x02\u{bd}\u{296}\x03\x02\x02\x02\u{bf}\u{29b}\
+		\x03\x02\x02\x02\u{c1}\u{2b2}\x03\x02\x02\x02\u{c3}\u{2b4}\x03\x02\x02\
+		\x02\u{c5}\u{2c8}\x03\x02\x02\x02\u{c7}\u{2d8}\x03\x02\x02\x02\u{c9}\u{2da}\
+		\x03\x02\x02\x02\u{cb}\u{2e1}\x03\x02\x02\x02\u{cd}\u{2e5}\x03\x02\x02\
+		\x02\u{cf}\u{2e9}\x03\x02\x02\x02\u{d1}\u{2ed}\x03\x02\x02\x02\u{d3}\u{2ef}\
+		\x03\x02\x02\x02\u{d5}\u{2f3}\x03\x02\x02\x02\u{d7}\u{2f5}\x03\x02\x02\
+		\x02\u{d9}\u{30d}\x03\x02\x02\x02\u{db}\u{31b}\x03\x02\x02\x02\u{dd}\u{324}\
+		\x03\x02\x02\x02\u{df}\u{32f}\x03\x02\x02\x02\u{e1}\u{332}\x03\x02\x02\
+		\x02\u{e3}\u{336}\x03\x02\x02\x02\u{e5}\u{33e}\x03\x02\x02\x02\u{e7}\u{345}\
+		\x03\x02\x02\x02\u{e9}\u{34f}\x03\x02\x02\x02\u{eb}\u{356}\x03\x02\x02\
+		\x02\u{ed}\u{359}\x03\x02\x02\x02\u{ef}\u{35d}\x03\x02\x02\x02\u{f1}\u{362}\
+		\x03\x02\x02\x02\u{f3}\u{369}\x03\x02\x02\x02\u{f5}\u{371}\x03\x02\x02\
+		\x02\u{f7}\u{37a}\x03\x02\x02\x02\u{f9}\u{37e}\x03\x02\x02\x02\u{fb}\u{388}\
+		\x03\x02\x02\x02\u{fd}\u{38d}\x03\x02\x02\x02\u{ff}\u{39d}\x03\x02\x02\
+		\x02\u{101}\u{3bc}\x03\x02\x02\x02\u{103}\u{3be}\x03\x02\x02\x02\u{105}\
+		\u{3c0}\x03\x02\x02\x02\u{107}\u{3c2}\x03\x02\x02\x02\u{109}\u{3c4}\x03\
+		\x02\x02\x02\u{10b}\u{3c6}\x03\x02\x02\x02\u{10d}\u{3c8}\x03\x02\x02\x02\
+		\u{10f}\u{3ca}\x03\x02\x02\x02\u{111}\u{3cc}\x03\x02\x02\x02\u{113}\u{3ce}\
+		\x03\x02\x02\x02\u{115}\u{3d0}\x03\x02\x02\x02\u{117}\u{3d2}\x03\x02\x02\
+		\x02\u{119}\u{3d4}\x03\x02\x02\x02\u{11b}\u{3d6}\x03\x02\x02\x02\u{11d}\
+		\u{3d8}\x03\x02\x02\x02\u{11f}\u{3da}\x03\x02\x02\x02\u{121}\u{3dc}\x03\
+		\x02\x02\x02\u{123}\u{3de}\x03\x02\x02\x02\u{125}\u{3e0}\x03\x02\x02\x02\
+		\u{127}\u{3e2}\x03\x02\x02\x02\u{129}\u{3e4}\x03\x02\x02\x02\u{12b}\u{12c}\
+		\x07\x3d\x02\x02\u{12c}\x04\x03\x02\x02\x02\u{12d}\u{12e}\x07\x2e\x02\x02\
+		\u{12e}\x06\x03\x02\x02\x02\u{12f}\u{130}\x07\x3f\x02\x02\u{130}\x08\x03\
+		\x02\x02\x02\u{131}\u{132}\x07\x2d\x02\x02\u{132}\u{133}\x07\x3f\x02\x02\
+		\u{133}\x0a\x03\x02\x02\x02\u{134}\u{135}\x07\x2c\x02\x02\u{135}\x0c\x03\
+		\x02\x02\x02\u{136}\u{137}\x07\x2a\x02\x02\u{137}\x0e\x03\x02\x02\x02\u{138}\
+		\u{139}\x07\x2b\x02\x02\u{139}\x10\x03\x02\x02\x02\u{13a}\u{13b}\x07\x5d\
+		\x02\x02\u{13b}\x12\x03\x02\x02\x02\u{13c}\u{13d}\x07\x5f\x02\x02\u{13d}\
+		\x14\x03\x02\x02\x02\u{13e}\u{13f}\x07\x3c\x02\x02\u{13f}\x16\x03\x02\x02\
+		\x02\u{140}\u{141}\x07\x7e\x02\x02\u{141}\x18\x03\x02\x02\x02\u{142}\u{143}\
+		\x07\x30\x02\x02\u{143}\u{144}\x07\x30\x02\x02\u{144}\x1a\x03\x02\x02\x02\
+		\u{145}\u{146}\x07\x2d\x02\x02\u{146}\x1c\x03\x02\x02\x02\u{147}\u{148}\
+		\x07\x2f\x02\x02\u{148}\x1e\x03\x02\x02\x02\u{149}\u{14a}\x07\x31\x02\x02\
+		\u{14a}\x20\x03\x02\x02\x02\u{14b}\u{14c}\x07\x27\x02\x02\u{14c}\x22\x03\
+		\x02\x02\x02\u{14d}\u{14e}\x07\x60\x02\x02\u{14e}\x24\x03\x02\x02\x02\u{14f}\
+		\u{150}\x07\x3f\x02\x02\u{150}\u{151}\x07\u{80}\x02\x02\u{151}\x26\x03\
+		\x02\x02\x02\u{152}\u{153}\x07\x3e\x02\x02\u{153}\u{154}\x07\x40\x02\x02\
+		\u{154}\x28\x03\x02\x02\x02\u{155}\u{156}\x07\x3e\x02\x02\u{156}\x2a\x03\
+		\x02\x02\x02\u{157}\u{158}\x07\x40\x02\x02\u{158}\x2c\x03\x02\x02\x02\u{159}\
+		\u{15a}\x07\x3e\x02\x02\u{15a}\u{15b}\x07\x3f\x02\x02\u{15b}\x2e\x03\x02\
+		\x02\x02\u{15c}\u{15d}\x07\x40\x02\x02\u{15d}\u{15e}\x07\x3f\x02\x02\u{15e}\
+		\x30\x03\x02\x02\x02\u{15f}\u{160}\x07\x30\x02\x02\u{160}\x32\x03\x02\x02\
+		\x02\u{161}\u{162}\x07\x7d\x02\x02\u{162}\x34\x03\x02\x02\x02\u{163}\u{164}\
+		\x07\x7f\x02\x02\u{164}\x36\x03\x02\x02\x02\u{165}\u{166}\x07\x26\x02\x02\
+		\u{166}\x38\x03\x02\x02\x02\u{167}\u{168}\x07\u{27ea}\x02\x02\u{168}\x3a\
+		\x03\x02\x02\x02\u{169}\u{16a}\x07\u{300a}\x02\x02\u{16a}\x3c\x03\x02\x02\
+		\x02\u{16b}\u{16c}\x07\u{fe66}\x02\x02\u{16c}\x3e\x03\x02\x02\x02\u{16d}\
+		\u{16e}\x07\u{ff1e}\x02\x02\u{16e}\x40\x03\x02\x02\x02\u{16f}\u{170}\x07\
+		\u{27eb}\x02\x02\u{170}\x42\x03\x02\x02\x02\u{171}\u{172}\x07\u{300b}\x02\
+		\x02\u{172}\x44\x03\x02\x02\x02\u{173}\u{174}\x07\u{fe67}\x02\x02\u{174}\
+		\x46\x03\x02\x02\x02\u{175}\u{176}\x07\u{ff20}\x02\x02\u{176}\x48\x03\x02\
+		\x02\x02\u{177}\u{178}\x07\u{af}\x02\x02\u{178}\x4a\x03\x02\x02\x02\u{179}\
+		\u{17a}\x07\u{2012}\x02\x02\u{17a}\x4c\x03\x02\x02\x02\u{17b}\u{17c}\x07\
+		\u{2013}\x02\x02\u{17c}\x4e\x03\x02\x02\x02\u{17d}\u{17e}\x07\u{2014}\x02\
+		\x02\u{17e}\x50\x03\x02\x02\x02\u{17f}\u{180}\x07\u{2015}\x02\x02\u{180}\
+		\x52\x03\x02\x02\x02\u{181}\u{182}\x07\u{2016}\x02\x02\u{182}\x54\x03\x02\
+		\x02\x02\u{183}\u{184}\x07\u{2017}\x02\x02\u{184}\x56\x03\x02\x02\x02\u{185}\
+		\u{186}\x07\u{2214}\x02\x02\u{186}\x58\x03\x02\x02\x02\u{187}\u{188}\x07\
+		\u{fe5a}\x02\x02\u{188}\x5a\x03\x02\x02\x02\u{189}\u{18a}\x07\u{fe65}\x02\
+		\x02\u{18a}\x5c\x03\x02\x02\x02\u{18b}\u{18c}\x07\u{ff0f}\x02\x02\u{18c}\
+		\x5e\x03\x02\x02\x02\u{18d}\u{18e}\x09\x02\x02\x02\u{18e}\u{18f}\x09\x03\
+		\x02\x02\u{18f}\u{190}\x09\x04\x02\x02\u{190}\u{191}\x09\x05\x02\x02\u{191}\
+		\u{192}\x09\x03\x02\x02\u{192}\x60\x03\x02\x02\x02\u{193}\u{194}\x09\x06\
+		\x02\x02\u{194}\u{195}\x09\x07\x02\x02\u{195}\u{196}\x09\x07\x02\x02\u{196}\
+		\x62\x03\x02\x02\x02\u{197}\u{198}\x09\x05\x02\x02\u{198}\u{199}\x09\x08\
+		\x02\x02\u{199}\u{19a}\x09\x09\x02\x02\u{19a}\u{19b}\x09\x04\x02\x02\u{19b}\
+		\u{19c}\x09\x05\x02\x02\u{19c}\u{19d}\x09\x03\x02\x02\u{19d}\u{19e}\x09\
+		\x06\x02\x02\u{19e}\u{19f}\x09\x07\x02\x02\u{19f}\x64\x03\x02\x02\x02\u{1a0}\
+		\u{1a1}\x09\x0a\x02\x02\u{1a1}\u{1a2}\x09\x06\x02\x02\u{1a2}\u{1a3}\x09\
+		\x09\x02\x02\u{1a3}\u{1a4}\x09\x0b\x02\x02\u{1a4}\u{1a5}\x09\x0c\x02\x02\
+		\u{1a5}\x66\x03\x02\x02\x02\u{1a6}\u{1a7}\x09\x02\x02\x02\u{1a7}\u{1a8}\
+		\x09\x03\x02\x02\u{1a8}\u{1a9}\x09\x0d\x02\x02\u{1a9}\u{1aa}\x09\x04\x02\
+		\x02\u{1aa}\u{1ab}\x09\x03\x02\x02\u{1ab}\u{1ac}\x09\x0e\x02\x02\u{1ac}\
+		\x68\x03\x02\x02\x02\u{1ad}\u{1ae}\x09\x06\x02\x02\u{1ae}\u{1af}\x09\x0f\
+		\x02\x02\u{1af}\x6a\x03\x02\x02\x02\u{1b0}\u{1b1}\x09\x0a\x02\x02\u{1b1}\
+		\u{1b2}\x09\x10\x02\x02\u{1b2}\u{1b3}\x09\x11\x02\x02\u{1b3}\u{1b4}\x09\
+		\x12\x02\x02\u{1b4}\u{1b5}\x09\x10\x02\x02\u{1b5}\x6c\x03\x02\x02\x02\u{1b6}\
+		\u{1b7}\x09\x05\x02\x02\u{1b7}\u{1b8}\x09\x03\x02\x02\u{1b8}\x6e\x03\x02\
+		\x02\x02\u{1b9}\u{1ba}\x09\x0b\x02\x02\u{1ba}\u{1bb}\x09\x11\x02\x02\u{1bb}\
+		\u{1bc}\x09\x10\x02\x02\u{1bc}\u{1bd}\x09\x06\x02\x02\u{1bd}\u{1be}\x09\
+		\x09\x02\x02\u{1be}\u{1bf}\x09\x10\x02\x02\u{1bf}\x70\x03\x02\x02\x02\u{1c0}\
+		\u{1c1}\x09\x0f\x02\x02\u{1c1}\u{1c2}\x09\x10\x02\x02\u{1c2}\u{1c3}\x09\
+		\x09\x02\x02\u{1c3}\x72\x03\x02\x02\x02\u{1c4}\u{1c5}\x09\x0e\x02\x02\u{1c5}\
+		\u{1c6}\x09\x10\x02\x02\u{1c6}\u{1c7}\x09\x09\x02\x02\u{1c7}\u{1c8}\x09\
+		\x06\x02\x02\u{1c8}\u{1c9}\x09\x0b\x02\x02\u{1c9}\u{1ca}\x09\x0c\x02\x02\
+		\u{1ca}\x74\x03\x02\x02\x02\u{1cb}\u{1cc}\x09\x0e\x02\x02\u{1cc}\u{1cd}\
+		\x09\x10\x02\x02\u{1cd}\u{1ce}\x09\x07\x02\x02\u{1ce}\u{1cf}\x09\x10\x02\
+		\x02\u{1cf}\u{1d0}\x09\x09\x02\x02\u{1d0}\u{1d1}\x09\x10\x02\x02\u{1d1}\
+		\x76\x03\x02\x02\x02\u{1d2}\u{1d3}\x09\x11\x02\x02\u{1d3}\u{1d4}\x09\x10\
+		\x02\x02\u{1d4}\u{1d5}\x09\x0a\x02\x02\u{1d5}\u{1d6}\x09\x05\x02\x02\u{1d6}\
+		\u{1d7}\x09\x13\x02\x02\u{1d7}\u{1d8}\x09\x10\x02\x02\u{1d8}\x78\x03\x02\
+		\x02\x02\u{1d9}\u{1da}\x09\x0b\x02\x02\u{1da}\u{1db}\x09\x06\x02\x02\u{1db}\
+		\u{1dc}\x09\x07\x02\x02\u{1dc}\u{1dd}\x09\x07\x02\x02\u{1dd}\x7a\x03\x02\
+		\x02\x02\u{1de}\u{1df}\x09\x14\x02\x02\u{1df}\u{1e0}\x09\x04\x02\x02\u{1e0}\
+		\u{1e1}\x09\x10\x02\x02\u{1e1}\u{1e2}\x09\x07\x02\x02\u{1e2}\u{1e3}\x09\
+		\x0e\x02\x02\u{1e3}\x7c\x03\x02\x02\x02\u{1e4}\u{1e5}\x09\x0d\x02\x02\u{1e5}\
+		\u{1e6}\x09\x04\x02\x02\u{1e6}\u{1e7}\x09\x09\x02\x02\u{1e7}\u{1e8}\x09\
+		\x0c\x02\x02\u{1e8}\x7e\x03\x02\x02\x02\u{1e9}\u{1ea}\x09\x11\x02\x02\u{1ea}\
+		\u{1eb}\x09\x10\x02\x02\u{1eb}\u{1ec}\x09\x09\x02\x02\u{1ec}\u{1ed}\x09\
+		\x02\x02\x02\u{1ed}\u{1ee}\x09\x11\x02\x02\u{1ee}\u{1ef}\x09\x03\x02\x02\
+		\u{1ef}\u{80}\x03\x02\x02\x02\u{1f0}\u{1f1}\x09\x0e\x02\x02\u{1f1}\u{1f2}\
+		\x09\x04\x02\x02\u{1f2}\u{1f3}\x09\x0f\x02\x02\u{1f3}\u{1f4}\x09\x09\x02\
+		\x02\u{1f4}\u{1f5}\x09\x04\x02\x02\u{1f5}\u{1f6}\x09\x03\x02\x02\u{1f6}\
+		\u{1f7}\x09\x0b\x02\x02\u{1f7}\u{1f8}\x09\x09\x02\x02\u{1f8}\u{82}\x03\
+		\x02\x02\x02\u{1f9}\u{1fa}\x09\x05\x02\x02\u{1fa}\u{1fb}\x09\x11\x02\x02\
+		\u{1fb}\u{1fc}\x09\x0e\x02\x02\u{1fc}\u{1fd}\x09\x10\x02\x02\u{1fd}\u{1fe}\
+		\x09\x11\x02\x02\u{1fe}\u{84}\x03\x02\x02\x02\u{1ff}\u{200}\x09\x15\x02\
+		\x02\u{200}\u{201}\x09\x14\x02\x02\u{201}\u{86}\x03\x02\x02\x02\u{202}\
+		\u{203}\x09\x0f\x02\x02\u{203}\u{204}\x09\x16\x02\x02\u{204}\u{205}\x09\
+		\x04\x02\x02\u{205}\u{206}\x09\x08\x02\x02\u{206}\u{88}\x03\x02\x02\x02\
+		\u{207}\u{208}\x09\x07\x02\x02\u{208}\u{209}\x09\x04\x02\x02\u{209}\u{20a}\
+		\x09\x0a\x02\x02\u{20a}\u{20b}\x09\x04\x02\x02\u{20b}\u{20c}\x09\x09\x02\
+		\x02\u{20c}\u{8a}\x03\x02\x02\x02\u{20d}\u{20e}\x09\x06\x02\x02\u{20e}\
+		\u{20f}\x09\x0f\x02\x02\u{20f}\u{210}\x09\x0b\x02\x02\u{210}\u{211}\x09\
+		\x10\x02\x02\u{211}\u{212}\x09\x03\x02\x02\u{212}\u{213}\x09\x0e\x02\x02\
+		\u{213}\u{214}\x09\x04\x02\x02\u{214}\u{215}\x09\x03\x02\x02\u{215}\u{216}\
+		\x09\x12\x02\x02\u{216}\u{8c}\x03\x02\x02\x02\u{217}\u{218}\x09\x06\x02\
+		\x02\u{218}\u{219}\x09\x0f\x02\x02\u{219}\u{21a}\x09\x0b\x02\x02\u{21a}\
+		\u{8e}\x03\x02\x02\x02\u{21b}\u{21c}\x09\x0e\x02\x02\u{21c}\u{21d}\x09\
+		\x10\x02\x02\u{21d}\u{21e}\x09\x0f\x02\x02\u{21e}\u{21f}\x09\x0b\x02\x02\
+		\u{21f}\u{220}\x09\x10\x02\x02\u{220}\u{221}\x09\x03\x02\x02\u{221}\u{222}\
+		\x09\x0e\x02\x02\u{222}\u{223}\x09\x04\x02\x02\u{223}\u{224}\x09\x03\x02\
+		\x02\u{224}\u{225}\x09\x12\x02\x02\u{225}\u{90}\x03\x02\x02\x02\u{226}\
+		\u{227}\x09\x0e\x02\x02\u{227}\u{228}\x09\x10\x02\x02\u{228}\u{229}\x09\
+		\x0f\x02\x02\u{229}\u{22a}\x09\x0b\x02\x02\u{22a}\u{92}\x03\x02\x02\x02\
+		\u{22b}\u{22c}\x09\x0d\x02\x02\u{22c}\u{22d}\x09\x0c\x02\x02\u{22d}\u{22e}\
+		\x09\x10\x02\x02\u{22e}\u{22f}\x09\x11\x02\x02\u{22f}\u{230}\x09\x10\x02\
+		\x02\u{230}\u{94}\x03\x02\x02\x02\u{231}\u{232}\x09\x05\x02\x02\u{232}\
+		\u{233}\x09\x11\x02\x02\u{233}\u{96}\x03\x02\x02\x02\u{234}\u{235}\x09\
+		\x17\x02\x02\u{235}\u{236}\x09\x05\x02\x02\u{236}\u{237}\x09\x11\x02\x02\
+		\u{237}\u{98}\x03\x02\x02\x02\u{238}\u{239}\x09\x06\x02\x02\u{239}\u{23a}\
+		\x09\x03\x02\x02\u{23a}\u{23b}\x09\x0e\x02\x02\u{23b}\u{9a}\x03\x02\x02\
+		\x02\u{23c}\u{23d}\x09\x03\x02\x02\u{23d}\u{23e}\x09\x05\x02\x02\u{23e}\
+		\u{23f}\x09\x09\x02\x02\u{23f}\u{9c}\x03\x02\x02\x02\u{240}\u{241}\x09\
+		\x04\x02\x02\u{241}\u{242}\x09\x03\x02\x02\u{242}\u{9e}\x03\x02\x02\x02\
+		\u{243}\u{244}\x09\x0f\x02\x02\u{244}\u{245}\x09\x09\x02\x02\u{245}\u{246}\
+		\x09\x06\x02\x02\u{246}\u{247}\x09\x11\x02\x02\u{247}\u{248}\x09\x09\x02\
+		\x02\u{248}\u{249}\x09\x0f\x02\x02\u{249}\u{a0}\x03\x02\x02\x02\u{24a}\
+		\u{24b}\x09\x10\x02\x02\u{24b}\u{24c}\x09\x03\x02\x02\u{24c}\u{24d}\x09\
+		\x0e\x02\x02\u{24d}\u{24e}\x09\x0f\x02\x02\u{24e}\u{a2}\x03\x02\x02\x02\
+		\u{24f}\u{250}\x09\x0b\x02\x02\u{250}\u{251}\x09\x05\x02\x02\u{251}\u{252}\
+		\x09\x03\x02\x02\u{252}\u{253}\x09\x09\x02\x02\u{253}\u{254}\x09\x06\x02\
+		\x02\u{254}\u{255}\x09\x04\x02\x02\u{255}\u{256}\x09\x03\x02\x02\u{256}\
+		\u{257}\x09\x0f\x02\x02\u{257}\u{a4}\x03\x02\x02\x02\u{258}\u{259}\x09\
+		\x04\x02\x02\u{259}\u{25a}\x09\x0f\x02\x02\u{25a}\u{a6}\x03\x02\x02\x02\
+		\u{25b}\u{25c}\x09\x03\x02\x02\u{25c}\u{25d}\x09\x02\x02\x02\u{25d}\u{25e}\
+		\x09\x07\x02\x02\u{25e}\u{25f}\x09\x07\x02\x02\u{25f}\u{a8}\x03\x02\x02\
+		\x02\u{260}\u{261}\x09\x0b\x02\x02\u{261}\u{262}\x09\x05\x02\x02\u{262}\
+		\u{263}\x09\x02\x02\x02\u{263}\u{264}\x09\x03\x02\x02\u{264}\u{265}\x09\
+		\x09\x02\x02\u{265}\u{aa}\x03\x02\x02\x02\u{266}\u{267}\x09\x06\x02\x02\
+		\u{267}\u{268}\x09\x03\x02\x02\u{268}\u{269}\x09\x14\x02\x02\u{269}\u{ac}\
+		\x03\x02\x02\x02\u{26a}\u{26b}\x09\x03\x02\x02\u{26b}\u{26c}\x09\x05\x02\
+		\x02\u{26c}\u{26d}\x09\x03\x02\x02\u{26d}\u{26e}\x09\x10\x02\x02\u{26e}\
+		\u{ae}\x03\x02\x02\x02\u{26f}\u{270}\x09\x0f\x02\x02\u{270}\u{271}\x09\
+		\x04\x02\x02\u{271}\u{272}\x09\x03\x02\x02\u{272}\u{273}\x09\x12\x02\x02\
+		\u{273}\u{274}\x09\x07\x02\x02\u{274}\u{275}\x09\x10\x02\x02\u{275}\u{b0}\
+		\x03\x02\x02\x02\u{276}\u{277}\x09\x09\x02\x02\u{277}\u{278}\x09\x11\x02\
+		\x02\u{278}\u{279}\x09\x02\x02\x02\u{279}\u{27a}\x09\x10\x02\x02\u{27a}\
+		\u{b2}\x03\x02\x02\x02\u{27b}\u{27c}\x09\x18\x02\x02\u{27c}\u{27d}\x09\
+		\x06\x02\x02\u{27d}\u{27e}\x09\x07\x02\x02\u{27e}\u{27f}\x09\x0f\x02\x02\
+		\u{27f}\u{280}\x09\x10\x02\x02\u{280}\u{b4}\x03\x02\x02\x02\u{281}\u{282}\
+		\x09\x10\x02\x02\u{282}\u{283}\x09\x17\x02\x02\u{283}\u{284}\x09\x04\x02\
+		\x02\u{284}\u{285}\x09\x0f\x02\x02\u{285}\u{286}\x09\x09\x02\x02\u{286}\
+		\u{287}\x09\x0f\x02\x02\u{287}\u{b6}\x03\x02\x02\x02\u{288}\u{289}\x09\
+		\x0b\x02\x02\u{289}\u{28a}\x09\x06\x02\x02\u{28a}\u{28b}\x09\x0f\x02\x02\
+		\u{28b}\u{28c}\x09\x10\x02\x02\u{28c}\u{b8}\x03\x02\x02\x02\u{28d}\u{28e}\
+		\x09\x10\x02\x02\u{28e}\u{28f}\x09\x07\x02\x02\u{28f}\u{290}\x09\x0f\x02\
+		\x02\u{290}\u{291}\x09\x10\x02\x02\u{291}\u{ba}\x03\x02\x02\x02\u{292}\
+		\u{293}\x09\x10\x02\x02\u{293}\u{294}\x09\x03\x02\x02\u{294}\u{295}\x09\
+		\x0e\x02\x02\u{295}\u{bc}\x03\x02\x02\x02\u{296}\u{297}\x09\x0d\x02\x02\
+		\u{297}\u{298}\x09\x0c\x02\x02\u{298}\u{299}\x09\x10\x02\x02\u{299}\u{29a}\
+		\x09\x03\x02\x02\u{29a}\u{be}\x03\x02\x02\x02\u{29b}\u{29c}\x09\x09\x02\
+		\x02\u{29c}\u{29d}\x09\x0c\x02\x02\u{29d}\u{29e}\x09\x10\x02\x02\u{29e}\
+		\u{29f}\x09\x03\x02\x02\u{29f}\u{c0}\x03\x02\x02\x02\u{2a0}\u{2a5}\x07\
+		\x24\x02\x02\u{2a1}\u{2a4}\x05\u{121}\u{91}\x02\u{2a2}\u{2a4}\x05\u{c3}\
+		\x62\x02\u{2a3}\u{2a1}\x03\x02\x02\x02\u{2a3}\u{2a2}\x03\x02\x02\x02\u{2a4}\
+		\u{2a7}\x03\x02\x02\x02\u{2a5}\u{2a3}\x03\x02\x02\x02\u{2a5}\u{2a6}\x03\
+		\x02\x02\x02\u{2a6}\u{2a8}\x03\x02\x02\x02\u{2a7}\u{2a5}\x03\x02\x02\x02\
+		\u{2a8}\u{2b3}\x07\x24\x02\x02\u{2a9}\u{2ae}\x07\x29\x02\x02\u{2aa}\u{2ad}\
+		\x05\u{10d}\u{87}\x02\u{2ab}\u{2ad}\x05\u{c3}\x62\x02\u{2ac}\u{2aa}\x03\
+		\x02\x02\x02\u{2ac}\u{2ab}\x03\x02\x02\x02\u{2ad}\u{2b0}\x03\x02\x02\x02\
+		\u{2ae}\u{2ac}\x03\x02\x02\x02\u{2ae}\u{2af}\x03\x02\x02\x02\u{2af}\u{2b1}\
+		\x03\x02\x02\x02\u{2b0}\u{2ae}\x03\x02\x02\x02\u{2b1}\u{2b3}\x07\x29\x02\
+		\x02\u{2b2}\u{2a0}\x03\x02\x02\x02\u{2b2}\u{2a9}\x03\x02\x02\x02\u{2b3}\
+		\u{c2}\x03\x02\x02\x02\u{2b4}\u{2c6}\x07\x5e\x02\x02\u{2b5}\u{2c7}\x09\
+		\x19\x02\x02\u{2b6}\u{2b7}\x09\x02\x02\x02\u{2b7}\u{2b8}\x05\u{cd}\x67\
+		\x02\u{2b8}\u{2b9}\x05\u{cd}\x67\x02\u{2b9}\u{2ba}\x05\u{cd}\x67\x02\u{2ba}\
+		\u{2bb}\x05\u{cd}\x67\x02\u{2bb}\u{2c7}\x03\x02\x02\x02\u{2bc}\u{2bd}\x09\
+		\x02\x02\x02\u{2bd}\u{2be}\x05\u{cd}\x67\x02\u{2be}\u{2bf}\x05\u{cd}\x67\
+		\x02\u{2bf}\u{2c0}\x05\u{cd}\x67\x02\u{2c0}\u{2c1}\x05\u{cd}\x67\x02\u{2c1}\
+		\u{2c2}\x05\u{cd}\x67\x02\u{2c2}\u{2c3}\x05\u{cd}\x67\x02\u{2c3}\u{2c4}\
+		\x05\u{cd}\x67\x02\u{2c4}\u{2c5}\x05\u{cd}\x67\x02\u{2c5}\u{2c7}\x03\x02\
+		\x02\x02\u{2c6}\u{2b5}\x03\x02\x02\x02\u{2c6}\u{2b6}\x03\x02\x02\x02\u{2c6}\
+		\u{2bc}\x03\x02\x02\x02\u{2c7}\u{c4}\x03\x02\x02\x02\u{2c8}\u{2c9}\x07\
+		\x32\x02\x02\u{2c9}\u{2ca}\x07\x7a\x02\x02\u{2ca}\u{2cc}\x03\x02\x02\x02\
+		\u{2cb}\u{2cd}\x05\u{cd}\x67\x02\u{2cc}\u{2cb}\x03\x02\x02\x02\u{2cd}\u{2ce}\
+		\x03\x02\x02\x02\u{2ce}\u{2cc}\x03\x02\x02\x02\u{2ce}\u{2cf}\x03\x02\x02\
+		\x02\u{2cf}\u{c6}\x03\x02\x02\x02\u{2d0}\u{2d9}\x05\u{d7}\x6c\x02\u{2d1}\
+		\u{2d5}\x05\u{d1}\x69\x02\u{2d2}\u{2d4}\x05\u{cf}\x68\x02\u{2d3}\u{2d2}\
+		\x03\x02\x02\x02\u{2d4}\u{2d7}\x03\x02\x02\x02\u{2d5}\u{2d3}\x03\x02\x02\
+		\x02\u{2d5}\u{2d6}\x03\x02\x02\x02\u{2d6}\u{2d9}\x03\x02\x02\x02\u{2d7}\
+		\u{2d5}\x03\x02\x02\x02\u{2d8}\u{2d0}\x03\x02\x02\x02\u{2d8}\u{2d1}\x03\
+		\x02\x02\x02\u{2d9}\u{c8}\x03\x02\x02\x02\u{2da}\u{2dc}\x05\u{d7}\x6c\x02\
+		\u{2db}\u{2dd}\x05\u{d5}\x6b\x02\u{2dc}\u{2db}\x03\x02\x02\x02\u{2dd}\u{2de}\
+		\x03\x02\x02\x02\u{2de}\u{2dc}\x03\x02\x02\x02\u{2de}\u{2df}\x03\x02\x02\
+		\x02\u{2df}\u{ca}\x03\x02\x02\x02\u{2e0}\u{2e2}\x09\x1a\x02\x02\u{2e1}\
+		\u{2e0}\x03\x02\x02\x02\u{2e2}\u{cc}\x03\x02\x02\x02\u{2e3}\u{2e6}\x05\
+		\u{cf}\x68\x02\u{2e4}\u{2e6}\x05\u{cb}\x66\x02\u{2e5}\u{2e3}\x03\x02\x02\
+		\x02\u{2e5}\u{2e4}\x03\x02\x02\x02\u{2e6}\u{ce}\x03\x02\x02\x02\u{2e7}\
+		\u{2ea}\x05\u{d7}\x6c\x02\u{2e8}\u{2ea}\x05\u{d1}\x69\x02\u{2e9}\u{2e7}\
+		\x03\x02\x02\x02\u{2e9}\u{2e8}\x03\x02\x02\x02\u{2ea}\u{d0}\x03\x02\x02\
+		\x02\u{2eb}\u{2ee}\x05\u{d3}\x6a\x02\u{2ec}\u{2ee}\x04\x3a\x3b\x02\u{2ed}\
+		\u{2eb}\x03\x02\x02\x02\u{2ed}\u{2ec}\x03\x02\x02\x02\u{2ee}\u{d2}\x03\
+		\x02\x02\x02\u{2ef}\u{2f0}\x04\x33\x39\x02\u{2f0}\u{d4}\x03\x02\x02\x02\
+		\u{2f1}\u{2f4}\x05\u{d7}\x6c\x02\u{2f2}\u{2f4}\x05\u{d3}\x6a\x02\u{2f3}\
+		\u{2f1}\x03\x02\x02\x02\u{2f3}\u{2f2}\x03\x02\x02\x02\u{2f4}\u{d6}\x03\
+		\x02\x02\x02\u{2f5}\u{2f6}\x07\x32\x02\x02\u{2f6}\u{d8}\x03\x02\x02\x02\
+		\u{2f7}\u{2f9}\x05\u{cf}\x68\x02\u{2f8}\u{2f7}\x03\x02\x02\x02\u{2f9}\u{2fa}\
+		\x03\x02\x02\x02\u{2fa}\u{2f8}\x03\x02\x02\x02\u{2fa}\u{2fb}\x03\x02\x02\
+		\x02\u{2fb}\u{30e}\x03\x02\x02\x02\u{2fc}\u{2fe}\x05\u{cf}\x68\x02\u{2fd}\
+		\u{2fc}\x03\x02\x02\x02\u{2fe}\u{2ff}\x03\x02\x02\x02\u{2ff}\u{2fd}\x03\
+		\x02\x02\x02\u{2ff}\u{300}\x03\x02\x02\x02\u{300}\u{301}\x03\x02\x02\x02\
+		\u{301}\u{303}\x07\x30\x02\x02\u{302}\u{304}\x05\u{cf}\x68\x02\u{303}\u{302}\
+		\x03\x02\x02\x02\u{304}\u{305}\x03\x02\x02\x02\u{305}\u{303}\x03\x02\x02\
+		\x02\u{305}\u{306}\x03\x02\x02\x02\u{306}\u{30e}\x03\x02\x02\x02\u{307}\
+		\u{309}\x07\x30\x02\x02\u{308}\u{30a}\x05\u{cf}\x68\x02\u{309}\u{308}\x03\
+		\x02\x02\x02\u{30a}\u{30b}\x03\x02\x02\x02\u{30b}\u{309}\x03\x02\x02\x02\
+		\u{30b}\u{30c}\x03\x02\x02\x02\u{30c}\u{30e}\x03\x02\x02\x02\u{30d}\u{2f8}\
+		\x03\x02\x02\x02\u{30d}\u{2fd}\x03\x02\x02\x02\u{30d}\u{307}\x03\x02\x02\
+		\x02\u{30e}\u{30f}\x03\x02\x02\x02\u{30f}\u{311}\x09\x10\x02\x02\u{310}\
+		\u{312}\x07\x2f\x02\x02\u{311}\u{310}\x03\x02\x02\x02\u{311}\u{312}\x03\
+		\x02\x02\x02\u{312}\u{314}\x03\x02\x02\x02\u{313}\u{315}\x05\u{cf}\x68\
+		\x02\u{314}\u{313}\x03\x02\x02\x02\u{315}\u{316}\x03\x02\x02\x02\u{316}\
+		\u{314}\x03\x02\x02\x02\u{316}\u{317}\x03\x02\x02\x02\u{317}\u{da}\x03\
+		\x02\x02\x02\u{318}\u{31a}\x05\u{cf}\x68\x02\u{319}\u{318}\x03\x02\x02\
+		\x02\u{31a}\u{31d}\x03\x02\x02\x02\u{31b}\u{319}\x03\x02\x02\x02\u{31b}\
+		\u{31c}\x03\x02\x02\x02\u{31c}\u{31e}\x03\x02\x02\x02\u{31d}\u{31b}\x03\
+		\x02\x02\x02\u{31e}\u{320}\x07\x30\x02\x02\u{31f}\u{321}\x05\u{cf}\x68\
+		\x02\u{320}\u{31f}\x03\x02\x02\x02\u{321}\u{322}\x03\x02\x02\x02\u{322}\
+		\u{320}\x03\x02\x02\x02\u{322}\u{323}\x03\x02\x02\x02\u{323}\u{dc}\x03\
+		\x02\x02\x02\u{324}\u{325}\x09\x0b\x02\x02\u{325}\u{326}\x09\x05\x02\x02\
+		\u{326}\u{327}\x09\x03\x02\x02\u{327}\u{328}\x09\x0f\x02\x02\u{328}\u{329}\
+		\x09\x09\x02\x02\u{329}\u{32a}\x09\x11\x02\x02\u{32a}\u{32b}\x09\x06\x02\
+		\x02\u{32b}\u{32c}\x09\x04\x02\x02\u{32c}\u{32d}\x09\x03\x02\x02\u{32d}\
+		\u{32e}\x09\x09\x02\x02\u{32e}\u{de}\x03\x02\x02\x02\u{32f}\u{330}\x09\
+		\x0e\x02\x02\u{330}\u{331}\x09\x05\x02\x02\u{331}\u{e0}\x03\x02\x02\x02\
+		\u{332}\u{333}\x09\x18\x02\x02\u{333}\u{334}\x09\x05\x02\x02\u{334}\u{335}\
+		\x09\x11\x02\x02\u{335}\u{e2}\x03\x02\x02\x02\u{336}\u{337}\x09\x11\x02\
+		\x02\u{337}\u{338}\x09\x10\x02\x02\u{338}\u{339}\x09\x1b\x02\x02\u{339}\
+		\u{33a}\x09\x02\x02\x02\u{33a}\u{33b}\x09\x04\x02\x02\u{33b}\u{33c}\x09\
+		\x11\x02\x02\u{33c}\u{33d}\x09\x10\x02\x02\u{33d}\u{e4}\x03\x02\x02\x02\
+		\u{33e}\u{33f}\x09\x02\x02\x02\u{33f}\u{340}\x09\x03\x02\x02\u{340}\u{341}\
+		\x09\x04\x02\x02\u{341}\u{342}\x09\x1b\x02\x02\u{342}\u{343}\x09\x02\x02\
+		\x02\u{343}\u{344}\x09\x10\x02\x02\u{344}\u{e6}\x03\x02\x02\x02\u{345}\
+		\u{346}\x09\x0a\x02\x02\u{346}\u{347}\x09\x06\x02\x02\u{347}\u{348}\x09\
+		\x03\x02\x02\u{348}\u{349}\x09\x0e\x02\x02\u{349}\u{34a}\x09\x06\x02\x02\
+		\u{34a}\u{34b}\x09\x09\x02\x02\u{34b}\u{34c}\x09\x05\x02\x02\u{34c}\u{34d}\
+		\x09\x11\x02\x02\u{34d}\u{34e}\x09\x14\x02\x02\u{34e}\u{e8}\x03\x02\x02\
+		\x02\u{34f}\u{350}\x09\x0f\x02\x02\u{350}\u{351}\x09\x0b\x02\x02\u{351}\
+		\u{352}\x09\x06\x02\x02\u{352}\u{353}\x09\x07\x02\x02\u{353}\u{354}\x09\
+		\x06\x02\x02\u{354}\u{355}\x09\x11\x02\x02\u{355}\u{ea}\x03\x02\x02\x02\
+		\u{356}\u{357}\x09\x05\x02\x02\u{357}\u{358}\x09\x18\x02\x02\u{358}\u{ec}\
+		\x03\x02\x02\x02\u{359}\u{35a}\x09\x06\x02\x02\u{35a}\u{35b}\x09\x0e\x02\
+		\x02\u{35b}\u{35c}\x09\x0e\x02\x02\u{35c}\u{ee}\x03\x02\x02\x02\u{35d}\
+		\u{35e}\x09\x0e\x02\x02\u{35e}\u{35f}\x09\x11\x02\x02\u{35f}\u{360}\x09\
+		\x05\x02\x02\u{360}\u{361}\x09\x08\x02\x02\u{361}\u{f0}\x03\x02\x02\x02\
+		\u{362}\u{363}\x09\x18\x02\x02\u{363}\u{364}\x09\x04\x02\x02\u{364}\u{365}\
+		\x09\x07\x02\x02\u{365}\u{366}\x09\x09\x02\x02\u{366}\u{367}\x09\x10\x02\
+		\x02\u{367}\u{368}\x09\x11\x02\x02\u{368}\u{f2}\x03\x02\x02\x02\u{369}\
+		\u{36a}\x09\x10\x02\x02\u{36a}\u{36b}\x09\x17\x02\x02\u{36b}\u{36c}\x09\
+		\x09\x02\x02\u{36c}\u{36d}\x09\x11\x02\x02\u{36d}\u{36e}\x09\x06\x02\x02\
+		\u{36e}\u{36f}\x09\x0b\x02\x02\u{36f}\u{370}\x09\x09\x02\x02\u{370}\u{f4}\
+		\x03\x02\x02\x02\u{371}\u{375}\x05\u{f7}\x7c\x02\u{372}\u{374}\x05\u{f9}\
+		\x7d\x02\u{373}\u{372}\x03\x02\x02\x02\u{374}\u{377}\x03\x02\x02\x02\u{375}\
+		\u{373}\x03\x02\x02\x02\u{375}\u{376}\x03\x02\x02\x02\u{376}\u{f6}\x03\
+		\x02\x02\x02\u{377}\u{375}\x03\x02\x02\x02\u{378}\u{37b}\x05\u{129}\u{95}\
+		\x02\u{379}\u{37b}\x05\u{11d}\u{8f}\x02\u{37a}\u{378}\x03\x02\x02\x02\u{37a}\
+		\u{379}\x03\x02\x02\x02\u{37b}\u{f8}\x03\x02\x02\x02\u{37c}\u{37f}\x05\
+		\u{109}\u{85}\x02\u{37d}\u{37f}\x05\u{119}\u{8d}\x02\u{37e}\u{37c}\x03\
+		\x02\x02\x02\u{37e}\u{37d}\x03\x02\x02\x02\u{37f}\u{fa}\x03\x02\x02\x02\
+		\u{380}\u{384}\x07\x62\x02\x02\u{381}\u{383}\x05\u{105}\u{83}\x02\u{382}\
+		\u{381}\x03\x02\x02\x02\u{383}\u{386}\x03\x02\x02\x02\u{384}\u{382}\x03\
+		\x02\x02\x02\u{384}\u{385}\x03\x02\x02\x02\u{385}\u{387}\x03\x02\x02\x02\
+		\u{386}\u{384}\x03\x02\x02\x02\u{387}\u{389}\x07\x62\x02\x02\u{388}\u{380}\
+		\x03\x02\x02\x02\u{389}\u{38a}\x03\x02\x02\x02\u{38a}\u{388}\x03\x02\x02\
+		\x02\u{38a}\u{38b}\x03\x02\x02\x02\u{38b}\u{fc}\x03\x02\x02\x02\u{38c}\
+		\u{38e}\x05\u{ff}\u{80}\x02\u{38d}\u{38c}\x03\x02\x02\x02\u{38e}\u{38f}\
+		\x03\x02\x02\x02\u{38f}\u{38d}\x03\x02\x02\x02\u{38f}\u{390}\x03\x02\x02\
+		\x02\u{390}\u{fe}\x03\x02\x02\x02\u{391}\u{39e}\x05\u{11b}\u{8e}\x02\u{392}\
+		\u{39e}\x05\u{11f}\u{90}\x02\u{393}\u{39e}\x05\u{123}\u{92}\x02\u{394}\
+		\u{39e}\x05\u{125}\u{93}\x02\u{395}\u{39e}\x05\u{103}\u{82}\x02\u{396}\
+		\u{39e}\x05\u{117}\u{8c}\x02\u{397}\u{39e}\x05\u{115}\u{8b}\x02\u{398}\
+		\u{39e}\x05\u{113}\u{8a}\x02\u{399}\u{39e}\x05\u{107}\u{84}\x02\u{39a}\
+		\u{39e}\x05\u{127}\u{94}\x02\u{39b}\u{39e}\x09\x1c\x02\x02\u{39c}\u{39e}\
+		\x05\u{101}\u{81}\x02\u{39d}\u{391}\x03\x02\x02\x02\u{39d}\u{392}\x03\x02\
+		\x02\x02\u{39d}\u{393}\x03\x02\x02\x02\u{39d}\u{394}\x03\x02\x02\x02\u{39d}\
+		\u{395}\x03\x02\x02\x02\u{39d}\u{396}\x03\x02\x02\x02\u{39d}\u{397}\x03\
+		\x02\x02\x02\u{39d}\u{398}\x03\x02\x02\x02\u{39d}\u{399}\x03\x02\x02\x02\
+		\u{39d}\u{39a}\x03\x02\x02\x02\u{39d}\u{39b}\x03\x02\x02\x02\u{39d}\u{39c}\
+		\x03\x02\x02\x02\u{39e}\u{100}\x03\x02\x02\x02\u{39f}\u{3a0}\x07\x31\x02\
+		\x02\u{3a0}\u{3a1}\x07\x2c\x02\x02\u{3a1}\u{3a7}\x03\x02\x02\x02\u{3a2}\
+		\u{3a6}\x05\u{10b}\u{86}\x02\u{3a3}\u{3a4}\x07\x2c\x02\x02\u{3a4}\u{3a6}\
+		\x05\u{111}\u{89}\x02\u{3a5}\u{3a2}\x03\x02\x02\x02\u{3a5}\u{3a3}\x03\x02\
+		\x02\x02\u{3a6}\u{3a9}\x03\x02\x02\x02\u{3a7}\u{3a5}\x03\x02\x02\x02\u{3a7}\
+		\u{3a8}\x03\x02\x02\x02\u{3a8}\u{3aa}\x03\x02\x02\x02\u{3a9}\u{3a7}\x03\
+		\x02\x02\x02\u{3aa}\u{3ab}\x07\x2c\x02\x02\u{3ab}\u{3bd}\x07\x31\x02\x02\
+		\u{3ac}\u{3ad}\x07\x31\x02\x02\u{3ad}\u{3ae}\x07\x31\x02\x02\u{3ae}\u{3b2}\
+		\x03\x02\x02\x02\u{3af}\u{3b1}\x05\u{10f}\u{88}\x02\u{3b0}\u{3af}\x03\x02\
+		\x02\x02\u{3b1}\u{3b4}\x03\x02\x02\x02\u{3b2}\u{3b0}\x03\x02\x02\x02\u{3b2}\
+		\u{3b3}\x03\x02\x02\x02\u{3b3}\u{3b6}\x03\x02\x02\x02\u{3b4}\u{3b2}\x03\
+		\x02\x02\x02\u{3b5}\u{3b7}\x05\u{117}\u{8c}\x02\u{3b6}\u{3b5}\x03\x02\x02\
+		\x02\u{3b6}\u{3b7}\x03\x02\x02\x02\u{3b7}\u{3ba}\x03\x02\x02\x02\u{3b8}\
+		\u{3bb}\x05\u{123}\u{92}\x02\u{3b9}\u{3bb}\x07\x02\x02\x03\u{3ba}\u{3b8}\
+		\x03\x02\x02\x02\u{3ba}\u{3b9}\x03\x02\x02\x02\u{3bb}\u{3bd}\x03\x02\x02\
+		\x02\u{3bc}\u{39f}\x03\x02\x02\x02\u{3bc}\u{3ac}\x03\x02\x02\x02\u{3bd}\
+		\u{102}\x03\x02\x02\x02\u{3be}\u{3bf}\x09\x1d\x02\x02\u{3bf}\u{104}\x03\
+		\x02\x02\x02\u{3c0}\u{3c1}\x0a\x1e\x02\x02\u{3c1}\u{106}\x03\x02\x02\x02\
+		\u{3c2}\u{3c3}\x09\x1f\x02\x02\u{3c3}\u{108}\x03\x02\x02\x02\u{3c4}\u{3c5}\
+		\x09\x2f\x02\x02\u{3c5}\u{10a}\x03\x02\x02\x02\u{3c6}\u{3c7}\x0a\x20\x02\
+		\x02\u{3c7}\u{10c}\x03\x02\x02\x02\u{3c8}\u{3c9}\x0a\x21\x02\x02\u{3c9}\
+		\u{10e}\x03\x02\x02\x02\u{3ca}\u{3cb}\x0a\x22\x02\x02\u{3cb}\u{110}\x03\
+		\x02\x02\x02\u{3cc}\u{3cd}\x0a\x23\x02\x02\u{3cd}\u{112}\x03\x02\x02\x02\
+		\u{3ce}\u{3cf}\x09\x24\x02\x02\u{3cf}\u{114}\x03\x02\x02\x02\u{3d0}\u{3d1}\
+		\x09\x25\x02\x02\u{3d1}\u{116}\x03\x02\x02\x02\u{3d2}\u{3d3}\x09\x26\x02\
+		\x02\u{3d3}\u{118}\x03\x02\x02\x02\u{3d4}\u{3d5}\x09\x27\x02\x02\u{3d5}\
+		\u{11a}\x03\x02\x02\x02\u{3d6}\u{3d7}\x09\x28\x02\x02\u{3d7}\u{11c}\x03\
+		\x02\x02\x02\u{3d8}\u{3d9}\x09\x29\x02\x02\u{3d9}\u{11e}\x03\x02\x02\x02\
+		\u{3da}\u{3db}\x09\x2a\x02\x02\u{3db}\u{120}\x03\x02\x02\x02\u{3dc}\u{3dd}\
+		\x0a\x2b\x02\x02\u{3dd}\u{122}\x03\x02\x02\x02\u{3de}\u{3df}\x09\x2c\x02\
+		\x02\u{3df}\u{124}\x03\x02\x02\x02\u{3e0}\u{3e1}\x09\x2d\x02\x02\u{3e1}\
+		\u{126}\x03\x02\x02\x02\u{3e2}\u{3e3}\x09\x2e\x02\x02\u{3e3}\u{128}\x03\
+		\x02\x02\x02\u{3e4}\u{3e5}\x09\x30\x02\x02\u{3e5}\u{12a}\x03\x02\x02\x02\
+		\x28\x02\u{2a3}\u{2a5}\u{2ac}\u{2ae}\u{2b2}\u{2c6}\u{2ce}\u{2d5}\u{2d8}\
+		\u{2de}\u{2e1}\u{2e5}\u{2e9}\u{2ed}\u{2f3}\u{2fa}\u{2ff}\u{305}\u{30b}\
+		\u{30d}\u{311}\u{316}\u{31b}\u{322}\u{375}\u{37a}\u{37e}\u{384}\u{38a}\
+		\u{38f}\u{39d}\u{3a5}\u{3a7}\u{3b2}\u{3b6}\u{3ba}\u{3bc}\x02";
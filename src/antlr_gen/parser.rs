@@ -83,88 +83,89 @@ pub const T__41: isize = 42;
 pub const T__42: isize = 43;
 pub const T__43: isize = 44;
 pub const T__44: isize = 45;
-pub const UNION: isize = 46;
-pub const ALL: isize = 47;
-pub const OPTIONAL: isize = 48;
-pub const MATCH: isize = 49;
-pub const UNWIND: isize = 50;
-pub const AS: isize = 51;
-pub const MERGE: isize = 52;
-pub const ON: isize = 53;
-pub const CREATE: isize = 54;
-pub const SET: isize = 55;
-pub const DETACH: isize = 56;
-pub const DELETE: isize = 57;
-pub const REMOVE: isize = 58;
-pub const CALL: isize = 59;
-pub const YIELD: isize = 60;
-pub const WITH: isize = 61;
-pub const RETURN: isize = 62;
-pub const DISTINCT: isize = 63;
-pub const ORDER: isize = 64;
-pub const BY: isize = 65;
-pub const L_SKIP: isize = 66;
-pub const LIMIT: isize = 67;
-pub const ASCENDING: isize = 68;
-pub const ASC: isize = 69;
-pub const DESCENDING: isize = 70;
-pub const DESC: isize = 71;
-pub const WHERE: isize = 72;
-pub const OR: isize = 73;
-pub const XOR: isize = 74;
-pub const AND: isize = 75;
-pub const NOT: isize = 76;
-pub const IN: isize = 77;
-pub const STARTS: isize = 78;
-pub const ENDS: isize = 79;
-pub const CONTAINS: isize = 80;
-pub const IS: isize = 81;
-pub const NULL: isize = 82;
-pub const COUNT: isize = 83;
-pub const ANY: isize = 84;
-pub const NONE: isize = 85;
-pub const SINGLE: isize = 86;
-pub const TRUE: isize = 87;
-pub const FALSE: isize = 88;
-pub const EXISTS: isize = 89;
-pub const CASE: isize = 90;
-pub const ELSE: isize = 91;
-pub const END: isize = 92;
-pub const WHEN: isize = 93;
-pub const THEN: isize = 94;
-pub const StringLiteral: isize = 95;
-pub const EscapedChar: isize = 96;
-pub const HexInteger: isize = 97;
-pub const DecimalInteger: isize = 98;
-pub const OctalInteger: isize = 99;
-pub const HexLetter: isize = 100;
-pub const HexDigit: isize = 101;
-pub const Digit: isize = 102;
-pub const NonZeroDigit: isize = 103;
-pub const NonZeroOctDigit: isize = 104;
-pub const OctDigit: isize = 105;
-pub const ZeroDigit: isize = 106;
-pub const ExponentDecimalReal: isize = 107;
-pub const RegularDecimalReal: isize = 108;
-pub const CONSTRAINT: isize = 109;
-pub const DO: isize = 110;
-pub const FOR: isize = 111;
-pub const REQUIRE: isize = 112;
-pub const UNIQUE: isize = 113;
-pub const MANDATORY: isize = 114;
-pub const SCALAR: isize = 115;
-pub const OF: isize = 116;
-pub const ADD: isize = 117;
-pub const DROP: isize = 118;
-pub const FILTER: isize = 119;
-pub const EXTRACT: isize = 120;
-pub const UnescapedSymbolicName: isize = 121;
-pub const IdentifierStart: isize = 122;
-pub const IdentifierPart: isize = 123;
-pub const EscapedSymbolicName: isize = 124;
-pub const SP: isize = 125;
-pub const WHITESPACE: isize = 126;
-pub const Comment: isize = 127;
+pub const T__45: isize = 46;
+pub const UNION: isize = 47;
+pub const ALL: isize = 48;
+pub const OPTIONAL: isize = 49;
+pub const MATCH: isize = 50;
+pub const UNWIND: isize = 51;
+pub const AS: isize = 52;
+pub const MERGE: isize = 53;
+pub const ON: isize = 54;
+pub const CREATE: isize = 55;
+pub const SET: isize = 56;
+pub const DETACH: isize = 57;
+pub const DELETE: isize = 58;
+pub const REMOVE: isize = 59;
+pub const CALL: isize = 60;
+pub const YIELD: isize = 61;
+pub const WITH: isize = 62;
+pub const RETURN: isize = 63;
+pub const DISTINCT: isize = 64;
+pub const ORDER: isize = 65;
+pub const BY: isize = 66;
+pub const L_SKIP: isize = 67;
+pub const LIMIT: isize = 68;
+pub const ASCENDING: isize = 69;
+pub const ASC: isize = 70;
+pub const DESCENDING: isize = 71;
+pub const DESC: isize = 72;
+pub const WHERE: isize = 73;
+pub const OR: isize = 74;
+pub const XOR: isize = 75;
+pub const AND: isize = 76;
+pub const NOT: isize = 77;
+pub const IN: isize = 78;
+pub const STARTS: isize = 79;
+pub const ENDS: isize = 80;
+pub const CONTAINS: isize = 81;
+pub const IS: isize = 82;
+pub const NULL: isize = 83;
+pub const COUNT: isize = 84;
+pub const ANY: isize = 85;
+pub const NONE: isize = 86;
+pub const SINGLE: isize = 87;
+pub const TRUE: isize = 88;
+pub const FALSE: isize = 89;
+pub const EXISTS: isize = 90;
+pub const CASE: isize = 91;
+pub const ELSE: isize = 92;
+pub const END: isize = 93;
+pub const WHEN: isize = 94;
+pub const THEN: isize = 95;
+pub const StringLiteral: isize = 96;
+pub const EscapedChar: isize = 97;
+pub const HexInteger: isize = 98;
+pub const DecimalInteger: isize = 99;
+pub const OctalInteger: isize = 100;
+pub const HexLetter: isize = 101;
+pub const HexDigit: isize = 102;
+pub const Digit: isize = 103;
+pub const NonZeroDigit: isize = 104;
+pub const NonZeroOctDigit: isize = 105;
+pub const OctDigit: isize = 106;
+pub const ZeroDigit: isize = 107;
+pub const ExponentDecimalReal: isize = 108;
+pub const RegularDecimalReal: isize = 109;
+pub const CONSTRAINT: isize = 110;
+pub const DO: isize = 111;
+pub const FOR: isize = 112;
+pub const REQUIRE: isize = 113;
+pub const UNIQUE: isize = 114;
+pub const MANDATORY: isize = 115;
+pub const SCALAR: isize = 116;
+pub const OF: isize = 117;
+pub const ADD: isize = 118;
+pub const DROP: isize = 119;
+pub const FILTER: isize = 120;
+pub const EXTRACT: isize = 121;
+pub const UnescapedSymbolicName: isize = 122;
+pub const IdentifierStart: isize = 123;
+pub const IdentifierPart: isize = 124;
+pub const EscapedSymbolicName: isize = 125;
+pub const SP: isize = 126;
+pub const WHITESPACE: isize = 127;
+pub const Comment: isize = 128;
 pub const RULE_oC_Cypher: usize = 0;
 pub const RULE_oC_Statement: usize = 1;
 pub const RULE_oC_Query: usize = 2;
@@ -366,7 +367,7 @@ pub const ruleNames: [&'static str; 99] = [
     "oC_Dash",
 ];
 
-pub const _LITERAL_NAMES: [Option<&'static str>; 107] = [
+pub const _LITERAL_NAMES: [Option<&'static str>; 108] = [
     None,
     Some("';'"),
     Some("','"),
@@ -385,6 +386,7 @@ pub const _LITERAL_NAMES: [Option<&'static str>; 107] = [
     Some("'/'"),
     Some("'%'"),
     Some("'^'"),
+    Some("'=~'"),
     Some("'<>'"),
     Some("'<'"),
     Some("'>'"),
@@ -475,7 +477,8 @@ pub const _LITERAL_NAMES: [Option<&'static str>; 107] = [
     None,
     Some("'0'"),
 ];
-pub const _SYMBOLIC_NAMES: [Option<&'static str>; 128] = [
+pub const _SYMBOLIC_NAMES: [Option<&'static str>; 129] = [
+    None,
     None,
     None,
     None,
@@ -5703,8 +5706,8 @@ where
                 | T__7
                 | T__12
                 | T__13
-                | T__23
-                | T__25
+                | T__24
+                | T__26
                 | ALL
                 | NOT
                 | NULL
@@ -6532,12 +6535,12 @@ where
                         recog.base.set_state(625);
                         _la = recog.base.input.la(1);
                         if {
-                            !(((_la - 68) & !0x3f) == 0
-                                && ((1usize << (_la - 68))
-                                    & ((1usize << (ASCENDING - 68))
-                                        | (1usize << (ASC - 68))
-                                        | (1usize << (DESCENDING - 68))
-                                        | (1usize << (DESC - 68))))
+                            !(((_la - 69) & !0x3f) == 0
+                                && ((1usize << (_la - 69))
+                                    & ((1usize << (ASCENDING - 69))
+                                        | (1usize << (ASC - 69))
+                                        | (1usize << (DESCENDING - 69))
+                                        | (1usize << (DESC - 69))))
                                     != 0)
                         } {
                             recog.err_handler.recover_inline(&mut recog.base)?;
@@ -7476,17 +7479,17 @@ where
                 recog.base.set_state(685);
                 recog.err_handler.sync(&mut recog.base)?;
                 _la = recog.base.input.la(1);
-                if ((_la - 83) & !0x3f) == 0
-                    && ((1usize << (_la - 83))
-                        & ((1usize << (COUNT - 83))
-                            | (1usize << (ANY - 83))
-                            | (1usize << (NONE - 83))
-                            | (1usize << (SINGLE - 83))
-                            | (1usize << (HexLetter - 83))
-                            | (1usize << (FILTER - 83))
-                            | (1usize << (EXTRACT - 83))
-                            | (1usize << (UnescapedSymbolicName - 83))
-                            | (1usize << (EscapedSymbolicName - 83))))
+                if ((_la - 84) & !0x3f) == 0
+                    && ((1usize << (_la - 84))
+                        & ((1usize << (COUNT - 84))
+                            | (1usize << (ANY - 84))
+                            | (1usize << (NONE - 84))
+                            | (1usize << (SINGLE - 84))
+                            | (1usize << (HexLetter - 84))
+                            | (1usize << (FILTER - 84))
+                            | (1usize << (EXTRACT - 84))
+                            | (1usize << (UnescapedSymbolicName - 84))
+                            | (1usize << (EscapedSymbolicName - 84))))
                         != 0
                 {
                     {
@@ -7530,7 +7533,7 @@ where
                 recog.base.set_state(697);
                 recog.err_handler.sync(&mut recog.base)?;
                 _la = recog.base.input.la(1);
-                if _la == T__23 || _la == T__25 {
+                if _la == T__24 || _la == T__26 {
                     {
                         /*InvokeRule oC_Properties*/
                         recog.base.set_state(693);
@@ -8232,17 +8235,17 @@ where
                 recog.base.set_state(781);
                 recog.err_handler.sync(&mut recog.base)?;
                 _la = recog.base.input.la(1);
-                if ((_la - 83) & !0x3f) == 0
-                    && ((1usize << (_la - 83))
-                        & ((1usize << (COUNT - 83))
-                            | (1usize << (ANY - 83))
-                            | (1usize << (NONE - 83))
-                            | (1usize << (SINGLE - 83))
-                            | (1usize << (HexLetter - 83))
-                            | (1usize << (FILTER - 83))
-                            | (1usize << (EXTRACT - 83))
-                            | (1usize << (UnescapedSymbolicName - 83))
-                            | (1usize << (EscapedSymbolicName - 83))))
+                if ((_la - 84) & !0x3f) == 0
+                    && ((1usize << (_la - 84))
+                        & ((1usize << (COUNT - 84))
+                            | (1usize << (ANY - 84))
+                            | (1usize << (NONE - 84))
+                            | (1usize << (SINGLE - 84))
+                            | (1usize << (HexLetter - 84))
+                            | (1usize << (FILTER - 84))
+                            | (1usize << (EXTRACT - 84))
+                            | (1usize << (UnescapedSymbolicName - 84))
+                            | (1usize << (EscapedSymbolicName - 84))))
                         != 0
                 {
                     {
@@ -8297,7 +8300,7 @@ where
                 recog.base.set_state(796);
                 recog.err_handler.sync(&mut recog.base)?;
                 _la = recog.base.input.la(1);
-                if _la == T__23 || _la == T__25 {
+                if _la == T__24 || _la == T__26 {
                     {
                         /*InvokeRule oC_Properties*/
                         recog.base.set_state(792);
@@ -8423,7 +8426,7 @@ where
             recog.base.set_state(802);
             recog.err_handler.sync(&mut recog.base)?;
             match recog.base.input.la(1) {
-                T__23 => {
+                T__24 => {
                     //recog.base.enter_outer_alt(_localctx.clone(), 1);
                     recog.base.enter_outer_alt(None, 1);
                     {
@@ -8433,7 +8436,7 @@ where
                     }
                 }
 
-                T__25 => {
+                T__26 => {
                     //recog.base.enter_outer_alt(_localctx.clone(), 2);
                     recog.base.enter_outer_alt(None, 2);
                     {
@@ -9056,11 +9059,11 @@ where
                 recog.base.set_state(849);
                 recog.err_handler.sync(&mut recog.base)?;
                 _la = recog.base.input.la(1);
-                if ((_la - 97) & !0x3f) == 0
-                    && ((1usize << (_la - 97))
-                        & ((1usize << (HexInteger - 97))
-                            | (1usize << (DecimalInteger - 97))
-                            | (1usize << (OctalInteger - 97))))
+                if ((_la - 98) & !0x3f) == 0
+                    && ((1usize << (_la - 98))
+                        & ((1usize << (HexInteger - 98))
+                            | (1usize << (DecimalInteger - 98))
+                            | (1usize << (OctalInteger - 98))))
                         != 0
                 {
                     {
@@ -9101,11 +9104,11 @@ where
                         recog.base.set_state(859);
                         recog.err_handler.sync(&mut recog.base)?;
                         _la = recog.base.input.la(1);
-                        if ((_la - 97) & !0x3f) == 0
-                            && ((1usize << (_la - 97))
-                                & ((1usize << (HexInteger - 97))
-                                    | (1usize << (DecimalInteger - 97))
-                                    | (1usize << (OctalInteger - 97))))
+                        if ((_la - 98) & !0x3f) == 0
+                            && ((1usize << (_la - 98))
+                                & ((1usize << (HexInteger - 98))
+                                    | (1usize << (DecimalInteger - 98))
+                                    | (1usize << (OctalInteger - 98))))
                                 != 0
                         {
                             {
@@ -9198,11 +9201,44 @@ impl<'input> OC_LabelNameContextExt<'input> {
 pub trait OC_LabelNameContextAttrs<'input>:
     CypherParserContext<'input> + BorrowMut<OC_LabelNameContextExt<'input>>
 {
-    fn oC_SchemaName(&self) -> Option<Rc<OC_SchemaNameContextAll<'input>>>
+    fn oC_SchemaName_all(&self) -> Vec<Rc<OC_SchemaNameContextAll<'input>>>
     where
         Self: Sized,
     {
-        self.child_of_type(0)
+        self.children_of_type()
+    }
+    fn oC_SchemaName(&self, i: usize) -> Option<Rc<OC_SchemaNameContextAll<'input>>>
+    where
+        Self: Sized,
+    {
+        self.child_of_type(i)
+    }
+    fn oC_Dash_all(&self) -> Vec<Rc<OC_DashContextAll<'input>>>
+    where
+        Self: Sized,
+    {
+        self.children_of_type()
+    }
+    fn oC_Dash(&self, i: usize) -> Option<Rc<OC_DashContextAll<'input>>>
+    where
+        Self: Sized,
+    {
+        self.child_of_type(i)
+    }
+    /// Retrieves all `TerminalNode`s corresponding to token DecimalInteger in current rule
+    fn DecimalInteger_all(&self) -> Vec<Rc<TerminalNode<'input, CypherParserContextType>>>
+    where
+        Self: Sized,
+    {
+        self.children_of_type()
+    }
+    /// Retrieves 'i's TerminalNode corresponding to token DecimalInteger, starting from 0.
+    /// Returns `None` if number of children corresponding to token DecimalInteger is less or equal than `i`.
+    fn DecimalInteger(&self, i: usize) -> Option<Rc<TerminalNode<'input, CypherParserContextType>>>
+    where
+        Self: Sized,
+    {
+        self.get_token(DecimalInteger, i)
     }
 }
 
@@ -9222,12 +9258,115 @@ where
             .enter_rule(_localctx.clone(), 94, RULE_oC_LabelName);
         let mut _localctx: Rc<OC_LabelNameContextAll> = _localctx;
         let result: Result<(), ANTLRError> = try {
+            let mut _alt: isize;
             //recog.base.enter_outer_alt(_localctx.clone(), 1);
             recog.base.enter_outer_alt(None, 1);
             {
                 /*InvokeRule oC_SchemaName*/
                 recog.base.set_state(863);
                 recog.oC_SchemaName()?;
+
+                recog.base.set_state(871);
+                recog.err_handler.sync(&mut recog.base)?;
+                _alt = recog.interpreter.adaptive_predict(145, &mut recog.base)?;
+                while _alt != 2 && _alt != INVALID_ALT {
+                    if _alt == 1 {
+                        {
+                            {
+                                /*InvokeRule oC_Dash*/
+                                recog.base.set_state(864);
+                                recog.oC_Dash()?;
+
+                                recog.base.set_state(867);
+                                recog.err_handler.sync(&mut recog.base)?;
+                                match recog.base.input.la(1) {
+                                    UNION
+                                    | ALL
+                                    | OPTIONAL
+                                    | MATCH
+                                    | UNWIND
+                                    | AS
+                                    | MERGE
+                                    | ON
+                                    | CREATE
+                                    | SET
+                                    | DETACH
+                                    | DELETE
+                                    | REMOVE
+                                    | WITH
+                                    | RETURN
+                                    | DISTINCT
+                                    | ORDER
+                                    | BY
+                                    | L_SKIP
+                                    | LIMIT
+                                    | ASCENDING
+                                    | ASC
+                                    | DESCENDING
+                                    | DESC
+                                    | WHERE
+                                    | OR
+                                    | XOR
+                                    | AND
+                                    | NOT
+                                    | IN
+                                    | STARTS
+                                    | ENDS
+                                    | CONTAINS
+                                    | IS
+                                    | NULL
+                                    | COUNT
+                                    | ANY
+                                    | NONE
+                                    | SINGLE
+                                    | TRUE
+                                    | FALSE
+                                    | EXISTS
+                                    | CASE
+                                    | ELSE
+                                    | END
+                                    | WHEN
+                                    | THEN
+                                    | HexLetter
+                                    | CONSTRAINT
+                                    | DO
+                                    | FOR
+                                    | REQUIRE
+                                    | UNIQUE
+                                    | MANDATORY
+                                    | SCALAR
+                                    | OF
+                                    | ADD
+                                    | DROP
+                                    | FILTER
+                                    | EXTRACT
+                                    | UnescapedSymbolicName
+                                    | EscapedSymbolicName => {
+                                        {
+                                            /*InvokeRule oC_SchemaName*/
+                                            recog.base.set_state(865);
+                                            recog.oC_SchemaName()?;
+                                        }
+                                    }
+
+                                    DecimalInteger => {
+                                        recog.base.set_state(866);
+                                        recog
+                                            .base
+                                            .match_token(DecimalInteger, &mut recog.err_handler)?;
+                                    }
+
+                                    _ => Err(ANTLRError::NoAltError(NoViableAltError::new(
+                                        &mut recog.base,
+                                    )))?,
+                                }
+                            }
+                        }
+                    }
+                    recog.base.set_state(873);
+                    recog.err_handler.sync(&mut recog.base)?;
+                    _alt = recog.interpreter.adaptive_predict(145, &mut recog.base)?;
+                }
             }
         };
         match result {
@@ -9329,7 +9468,7 @@ where
             recog.base.enter_outer_alt(None, 1);
             {
                 /*InvokeRule oC_SchemaName*/
-                recog.base.set_state(865);
+                recog.base.set_state(874);
                 recog.oC_SchemaName()?;
             }
         };
@@ -9432,7 +9571,7 @@ where
             recog.base.enter_outer_alt(None, 1);
             {
                 /*InvokeRule oC_OrExpression*/
-                recog.base.set_state(867);
+                recog.base.set_state(876);
                 recog.oC_OrExpression()?;
             }
         };
@@ -9572,34 +9711,34 @@ where
             recog.base.enter_outer_alt(None, 1);
             {
                 /*InvokeRule oC_XorExpression*/
-                recog.base.set_state(869);
+                recog.base.set_state(878);
                 recog.oC_XorExpression()?;
 
-                recog.base.set_state(876);
+                recog.base.set_state(885);
                 recog.err_handler.sync(&mut recog.base)?;
-                _alt = recog.interpreter.adaptive_predict(144, &mut recog.base)?;
+                _alt = recog.interpreter.adaptive_predict(146, &mut recog.base)?;
                 while _alt != 2 && _alt != INVALID_ALT {
                     if _alt == 1 {
                         {
                             {
-                                recog.base.set_state(870);
+                                recog.base.set_state(879);
                                 recog.base.match_token(SP, &mut recog.err_handler)?;
 
-                                recog.base.set_state(871);
+                                recog.base.set_state(880);
                                 recog.base.match_token(OR, &mut recog.err_handler)?;
 
-                                recog.base.set_state(872);
+                                recog.base.set_state(881);
                                 recog.base.match_token(SP, &mut recog.err_handler)?;
 
                                 /*InvokeRule oC_XorExpression*/
-                                recog.base.set_state(873);
+                                recog.base.set_state(882);
                                 recog.oC_XorExpression()?;
                             }
                         }
                     }
-                    recog.base.set_state(878);
+                    recog.base.set_state(887);
                     recog.err_handler.sync(&mut recog.base)?;
-                    _alt = recog.interpreter.adaptive_predict(144, &mut recog.base)?;
+                    _alt = recog.interpreter.adaptive_predict(146, &mut recog.base)?;
                 }
             }
         };
@@ -9741,34 +9880,34 @@ where
             recog.base.enter_outer_alt(None, 1);
             {
                 /*InvokeRule oC_AndExpression*/
-                recog.base.set_state(879);
+                recog.base.set_state(888);
                 recog.oC_AndExpression()?;
 
-                recog.base.set_state(886);
+                recog.base.set_state(895);
                 recog.err_handler.sync(&mut recog.base)?;
-                _alt = recog.interpreter.adaptive_predict(145, &mut recog.base)?;
+                _alt = recog.interpreter.adaptive_predict(147, &mut recog.base)?;
                 while _alt != 2 && _alt != INVALID_ALT {
                     if _alt == 1 {
                         {
                             {
-                                recog.base.set_state(880);
+                                recog.base.set_state(889);
                                 recog.base.match_token(SP, &mut recog.err_handler)?;
 
-                                recog.base.set_state(881);
+                                recog.base.set_state(890);
                                 recog.base.match_token(XOR, &mut recog.err_handler)?;
 
-                                recog.base.set_state(882);
+                                recog.base.set_state(891);
                                 recog.base.match_token(SP, &mut recog.err_handler)?;
 
                                 /*InvokeRule oC_AndExpression*/
-                                recog.base.set_state(883);
+                                recog.base.set_state(892);
                                 recog.oC_AndExpression()?;
                             }
                         }
                     }
-                    recog.base.set_state(888);
+                    recog.base.set_state(897);
                     recog.err_handler.sync(&mut recog.base)?;
-                    _alt = recog.interpreter.adaptive_predict(145, &mut recog.base)?;
+                    _alt = recog.interpreter.adaptive_predict(147, &mut recog.base)?;
                 }
             }
         };
@@ -9910,34 +10049,34 @@ where
             recog.base.enter_outer_alt(None, 1);
             {
                 /*InvokeRule oC_NotExpression*/
-                recog.base.set_state(889);
+                recog.base.set_state(898);
                 recog.oC_NotExpression()?;
 
-                recog.base.set_state(896);
+                recog.base.set_state(905);
                 recog.err_handler.sync(&mut recog.base)?;
-                _alt = recog.interpreter.adaptive_predict(146, &mut recog.base)?;
+                _alt = recog.interpreter.adaptive_predict(148, &mut recog.base)?;
                 while _alt != 2 && _alt != INVALID_ALT {
                     if _alt == 1 {
                         {
                             {
-                                recog.base.set_state(890);
+                                recog.base.set_state(899);
                                 recog.base.match_token(SP, &mut recog.err_handler)?;
 
-                                recog.base.set_state(891);
+                                recog.base.set_state(900);
                                 recog.base.match_token(AND, &mut recog.err_handler)?;
 
-                                recog.base.set_state(892);
+                                recog.base.set_state(901);
                                 recog.base.match_token(SP, &mut recog.err_handler)?;
 
                                 /*InvokeRule oC_NotExpression*/
-                                recog.base.set_state(893);
+                                recog.base.set_state(902);
                                 recog.oC_NotExpression()?;
                             }
                         }
                     }
-                    recog.base.set_state(898);
+                    recog.base.set_state(907);
                     recog.err_handler.sync(&mut recog.base)?;
-                    _alt = recog.interpreter.adaptive_predict(146, &mut recog.base)?;
+                    _alt = recog.interpreter.adaptive_predict(148, &mut recog.base)?;
                 }
             }
         };
@@ -10072,32 +10211,32 @@ where
             //recog.base.enter_outer_alt(_localctx.clone(), 1);
             recog.base.enter_outer_alt(None, 1);
             {
-                recog.base.set_state(905);
+                recog.base.set_state(914);
                 recog.err_handler.sync(&mut recog.base)?;
                 _la = recog.base.input.la(1);
                 while _la == NOT {
                     {
                         {
-                            recog.base.set_state(899);
+                            recog.base.set_state(908);
                             recog.base.match_token(NOT, &mut recog.err_handler)?;
 
-                            recog.base.set_state(901);
+                            recog.base.set_state(910);
                             recog.err_handler.sync(&mut recog.base)?;
                             _la = recog.base.input.la(1);
                             if _la == SP {
                                 {
-                                    recog.base.set_state(900);
+                                    recog.base.set_state(909);
                                     recog.base.match_token(SP, &mut recog.err_handler)?;
                                 }
                             }
                         }
                     }
-                    recog.base.set_state(907);
+                    recog.base.set_state(916);
                     recog.err_handler.sync(&mut recog.base)?;
                     _la = recog.base.input.la(1);
                 }
                 /*InvokeRule oC_ComparisonExpression*/
-                recog.base.set_state(908);
+                recog.base.set_state(917);
                 recog.oC_ComparisonExpression()?;
             }
         };
@@ -10243,35 +10382,35 @@ where
             recog.base.enter_outer_alt(None, 1);
             {
                 /*InvokeRule oC_AddOrSubtractExpression*/
-                recog.base.set_state(910);
+                recog.base.set_state(919);
                 recog.oC_AddOrSubtractExpression()?;
 
-                recog.base.set_state(917);
+                recog.base.set_state(926);
                 recog.err_handler.sync(&mut recog.base)?;
-                _alt = recog.interpreter.adaptive_predict(150, &mut recog.base)?;
+                _alt = recog.interpreter.adaptive_predict(152, &mut recog.base)?;
                 while _alt != 2 && _alt != INVALID_ALT {
                     if _alt == 1 {
                         {
                             {
-                                recog.base.set_state(912);
+                                recog.base.set_state(921);
                                 recog.err_handler.sync(&mut recog.base)?;
                                 _la = recog.base.input.la(1);
                                 if _la == SP {
                                     {
-                                        recog.base.set_state(911);
+                                        recog.base.set_state(920);
                                         recog.base.match_token(SP, &mut recog.err_handler)?;
                                     }
                                 }
 
                                 /*InvokeRule oC_PartialComparisonExpression*/
-                                recog.base.set_state(914);
+                                recog.base.set_state(923);
                                 recog.oC_PartialComparisonExpression()?;
                             }
                         }
                     }
-                    recog.base.set_state(919);
+                    recog.base.set_state(928);
                     recog.err_handler.sync(&mut recog.base)?;
-                    _alt = recog.interpreter.adaptive_predict(150, &mut recog.base)?;
+                    _alt = recog.interpreter.adaptive_predict(152, &mut recog.base)?;
                 }
             }
         };
@@ -10411,44 +10550,44 @@ where
             recog.base.enter_outer_alt(None, 1);
             {
                 /*InvokeRule oC_MultiplyDivideModuloExpression*/
-                recog.base.set_state(920);
+                recog.base.set_state(929);
                 recog.oC_MultiplyDivideModuloExpression()?;
 
-                recog.base.set_state(939);
+                recog.base.set_state(948);
                 recog.err_handler.sync(&mut recog.base)?;
-                _alt = recog.interpreter.adaptive_predict(156, &mut recog.base)?;
+                _alt = recog.interpreter.adaptive_predict(158, &mut recog.base)?;
                 while _alt != 2 && _alt != INVALID_ALT {
                     if _alt == 1 {
                         {
-                            recog.base.set_state(937);
+                            recog.base.set_state(946);
                             recog.err_handler.sync(&mut recog.base)?;
-                            match recog.interpreter.adaptive_predict(155, &mut recog.base)? {
+                            match recog.interpreter.adaptive_predict(157, &mut recog.base)? {
                                 1 => {
                                     {
                                         {
-                                            recog.base.set_state(922);
+                                            recog.base.set_state(931);
                                             recog.err_handler.sync(&mut recog.base)?;
                                             _la = recog.base.input.la(1);
                                             if _la == SP {
                                                 {
-                                                    recog.base.set_state(921);
+                                                    recog.base.set_state(930);
                                                     recog
                                                         .base
                                                         .match_token(SP, &mut recog.err_handler)?;
                                                 }
                                             }
 
-                                            recog.base.set_state(924);
+                                            recog.base.set_state(933);
                                             recog
                                                 .base
                                                 .match_token(T__12, &mut recog.err_handler)?;
 
-                                            recog.base.set_state(926);
+                                            recog.base.set_state(935);
                                             recog.err_handler.sync(&mut recog.base)?;
                                             _la = recog.base.input.la(1);
                                             if _la == SP {
                                                 {
-                                                    recog.base.set_state(925);
+                                                    recog.base.set_state(934);
                                                     recog
                                                         .base
                                                         .match_token(SP, &mut recog.err_handler)?;
@@ -10456,7 +10595,7 @@ where
                                             }
 
                                             /*InvokeRule oC_MultiplyDivideModuloExpression*/
-                                            recog.base.set_state(928);
+                                            recog.base.set_state(937);
                                             recog.oC_MultiplyDivideModuloExpression()?;
                                         }
                                     }
@@ -10464,29 +10603,29 @@ where
                                 2 => {
                                     {
                                         {
-                                            recog.base.set_state(930);
+                                            recog.base.set_state(939);
                                             recog.err_handler.sync(&mut recog.base)?;
                                             _la = recog.base.input.la(1);
                                             if _la == SP {
                                                 {
-                                                    recog.base.set_state(929);
+                                                    recog.base.set_state(938);
                                                     recog
                                                         .base
                                                         .match_token(SP, &mut recog.err_handler)?;
                                                 }
                                             }
 
-                                            recog.base.set_state(932);
+                                            recog.base.set_state(941);
                                             recog
                                                 .base
                                                 .match_token(T__13, &mut recog.err_handler)?;
 
-                                            recog.base.set_state(934);
+                                            recog.base.set_state(943);
                                             recog.err_handler.sync(&mut recog.base)?;
                                             _la = recog.base.input.la(1);
                                             if _la == SP {
                                                 {
-                                                    recog.base.set_state(933);
+                                                    recog.base.set_state(942);
                                                     recog
                                                         .base
                                                         .match_token(SP, &mut recog.err_handler)?;
@@ -10494,7 +10633,7 @@ where
                                             }
 
                                             /*InvokeRule oC_MultiplyDivideModuloExpression*/
-                                            recog.base.set_state(936);
+                                            recog.base.set_state(945);
                                             recog.oC_MultiplyDivideModuloExpression()?;
                                         }
                                     }
@@ -10504,9 +10643,9 @@ where
                             }
                         }
                     }
-                    recog.base.set_state(941);
+                    recog.base.set_state(950);
                     recog.err_handler.sync(&mut recog.base)?;
-                    _alt = recog.interpreter.adaptive_predict(156, &mut recog.base)?;
+                    _alt = recog.interpreter.adaptive_predict(158, &mut recog.base)?;
                 }
             }
         };
@@ -10646,42 +10785,42 @@ where
             recog.base.enter_outer_alt(None, 1);
             {
                 /*InvokeRule oC_PowerOfExpression*/
-                recog.base.set_state(942);
+                recog.base.set_state(951);
                 recog.oC_PowerOfExpression()?;
 
-                recog.base.set_state(969);
+                recog.base.set_state(978);
                 recog.err_handler.sync(&mut recog.base)?;
-                _alt = recog.interpreter.adaptive_predict(164, &mut recog.base)?;
+                _alt = recog.interpreter.adaptive_predict(166, &mut recog.base)?;
                 while _alt != 2 && _alt != INVALID_ALT {
                     if _alt == 1 {
                         {
-                            recog.base.set_state(967);
+                            recog.base.set_state(976);
                             recog.err_handler.sync(&mut recog.base)?;
-                            match recog.interpreter.adaptive_predict(163, &mut recog.base)? {
+                            match recog.interpreter.adaptive_predict(165, &mut recog.base)? {
                                 1 => {
                                     {
                                         {
-                                            recog.base.set_state(944);
+                                            recog.base.set_state(953);
                                             recog.err_handler.sync(&mut recog.base)?;
                                             _la = recog.base.input.la(1);
                                             if _la == SP {
                                                 {
-                                                    recog.base.set_state(943);
+                                                    recog.base.set_state(952);
                                                     recog
                                                         .base
                                                         .match_token(SP, &mut recog.err_handler)?;
                                                 }
                                             }
 
-                                            recog.base.set_state(946);
+                                            recog.base.set_state(955);
                                             recog.base.match_token(T__4, &mut recog.err_handler)?;
 
-                                            recog.base.set_state(948);
+                                            recog.base.set_state(957);
                                             recog.err_handler.sync(&mut recog.base)?;
                                             _la = recog.base.input.la(1);
                                             if _la == SP {
                                                 {
-                                                    recog.base.set_state(947);
+                                                    recog.base.set_state(956);
                                                     recog
                                                         .base
                                                         .match_token(SP, &mut recog.err_handler)?;
@@ -10689,7 +10828,7 @@ where
                                             }
 
                                             /*InvokeRule oC_PowerOfExpression*/
-                                            recog.base.set_state(950);
+                                            recog.base.set_state(959);
                                             recog.oC_PowerOfExpression()?;
                                         }
                                     }
@@ -10697,29 +10836,29 @@ where
                                 2 => {
                                     {
                                         {
-                                            recog.base.set_state(952);
+                                            recog.base.set_state(961);
                                             recog.err_handler.sync(&mut recog.base)?;
                                             _la = recog.base.input.la(1);
                                             if _la == SP {
                                                 {
-                                                    recog.base.set_state(951);
+                                                    recog.base.set_state(960);
                                                     recog
                                                         .base
                                                         .match_token(SP, &mut recog.err_handler)?;
                                                 }
                                             }
 
-                                            recog.base.set_state(954);
+                                            recog.base.set_state(963);
                                             recog
                                                 .base
                                                 .match_token(T__14, &mut recog.err_handler)?;
 
-                                            recog.base.set_state(956);
+                                            recog.base.set_state(965);
                                             recog.err_handler.sync(&mut recog.base)?;
                                             _la = recog.base.input.la(1);
                                             if _la == SP {
                                                 {
-                                                    recog.base.set_state(955);
+                                                    recog.base.set_state(964);
                                                     recog
                                                         .base
                                                         .match_token(SP, &mut recog.err_handler)?;
@@ -10727,7 +10866,7 @@ where
                                             }
 
                                             /*InvokeRule oC_PowerOfExpression*/
-                                            recog.base.set_state(958);
+                                            recog.base.set_state(967);
                                             recog.oC_PowerOfExpression()?;
                                         }
                                     }
@@ -10735,29 +10874,29 @@ where
                                 3 => {
                                     {
                                         {
-                                            recog.base.set_state(960);
+                                            recog.base.set_state(969);
                                             recog.err_handler.sync(&mut recog.base)?;
                                             _la = recog.base.input.la(1);
                                             if _la == SP {
                                                 {
-                                                    recog.base.set_state(959);
+                                                    recog.base.set_state(968);
                                                     recog
                                                         .base
                                                         .match_token(SP, &mut recog.err_handler)?;
                                                 }
                                             }
 
-                                            recog.base.set_state(962);
+                                            recog.base.set_state(971);
                                             recog
                                                 .base
                                                 .match_token(T__15, &mut recog.err_handler)?;
 
-                                            recog.base.set_state(964);
+                                            recog.base.set_state(973);
                                             recog.err_handler.sync(&mut recog.base)?;
                                             _la = recog.base.input.la(1);
                                             if _la == SP {
                                                 {
-                                                    recog.base.set_state(963);
+                                                    recog.base.set_state(972);
                                                     recog
                                                         .base
                                                         .match_token(SP, &mut recog.err_handler)?;
@@ -10765,7 +10904,7 @@ where
                                             }
 
                                             /*InvokeRule oC_PowerOfExpression*/
-                                            recog.base.set_state(966);
+                                            recog.base.set_state(975);
                                             recog.oC_PowerOfExpression()?;
                                         }
                                     }
@@ -10775,9 +10914,9 @@ where
                             }
                         }
                     }
-                    recog.base.set_state(971);
+                    recog.base.set_state(980);
                     recog.err_handler.sync(&mut recog.base)?;
-                    _alt = recog.interpreter.adaptive_predict(164, &mut recog.base)?;
+                    _alt = recog.interpreter.adaptive_predict(166, &mut recog.base)?;
                 }
             }
         };
@@ -10912,48 +11051,48 @@ where
             recog.base.enter_outer_alt(None, 1);
             {
                 /*InvokeRule oC_UnaryAddOrSubtractExpression*/
-                recog.base.set_state(972);
+                recog.base.set_state(981);
                 recog.oC_UnaryAddOrSubtractExpression()?;
 
-                recog.base.set_state(983);
+                recog.base.set_state(992);
                 recog.err_handler.sync(&mut recog.base)?;
-                _alt = recog.interpreter.adaptive_predict(167, &mut recog.base)?;
+                _alt = recog.interpreter.adaptive_predict(169, &mut recog.base)?;
                 while _alt != 2 && _alt != INVALID_ALT {
                     if _alt == 1 {
                         {
                             {
-                                recog.base.set_state(974);
+                                recog.base.set_state(983);
                                 recog.err_handler.sync(&mut recog.base)?;
                                 _la = recog.base.input.la(1);
                                 if _la == SP {
                                     {
-                                        recog.base.set_state(973);
+                                        recog.base.set_state(982);
                                         recog.base.match_token(SP, &mut recog.err_handler)?;
                                     }
                                 }
 
-                                recog.base.set_state(976);
+                                recog.base.set_state(985);
                                 recog.base.match_token(T__16, &mut recog.err_handler)?;
 
-                                recog.base.set_state(978);
+                                recog.base.set_state(987);
                                 recog.err_handler.sync(&mut recog.base)?;
                                 _la = recog.base.input.la(1);
                                 if _la == SP {
                                     {
-                                        recog.base.set_state(977);
+                                        recog.base.set_state(986);
                                         recog.base.match_token(SP, &mut recog.err_handler)?;
                                     }
                                 }
 
                                 /*InvokeRule oC_UnaryAddOrSubtractExpression*/
-                                recog.base.set_state(980);
+                                recog.base.set_state(989);
                                 recog.oC_UnaryAddOrSubtractExpression()?;
                             }
                         }
                     }
-                    recog.base.set_state(985);
+                    recog.base.set_state(994);
                     recog.err_handler.sync(&mut recog.base)?;
-                    _alt = recog.interpreter.adaptive_predict(167, &mut recog.base)?;
+                    _alt = recog.interpreter.adaptive_predict(169, &mut recog.base)?;
                 }
             }
         };
@@ -11085,13 +11224,13 @@ where
             //recog.base.enter_outer_alt(_localctx.clone(), 1);
             recog.base.enter_outer_alt(None, 1);
             {
-                recog.base.set_state(992);
+                recog.base.set_state(1001);
                 recog.err_handler.sync(&mut recog.base)?;
                 _la = recog.base.input.la(1);
                 while _la == T__12 || _la == T__13 {
                     {
                         {
-                            recog.base.set_state(986);
+                            recog.base.set_state(995);
                             _la = recog.base.input.la(1);
                             if !(_la == T__12 || _la == T__13) {
                                 recog.err_handler.recover_inline(&mut recog.base)?;
@@ -11102,23 +11241,23 @@ where
                                 recog.err_handler.report_match(&mut recog.base);
                                 recog.base.consume(&mut recog.err_handler);
                             }
-                            recog.base.set_state(988);
+                            recog.base.set_state(997);
                             recog.err_handler.sync(&mut recog.base)?;
                             _la = recog.base.input.la(1);
                             if _la == SP {
                                 {
-                                    recog.base.set_state(987);
+                                    recog.base.set_state(996);
                                     recog.base.match_token(SP, &mut recog.err_handler)?;
                                 }
                             }
                         }
                     }
-                    recog.base.set_state(994);
+                    recog.base.set_state(1003);
                     recog.err_handler.sync(&mut recog.base)?;
                     _la = recog.base.input.la(1);
                 }
                 /*InvokeRule oC_StringListNullOperatorExpression*/
-                recog.base.set_state(995);
+                recog.base.set_state(1004);
                 recog.oC_StringListNullOperatorExpression()?;
             }
         };
@@ -11285,36 +11424,36 @@ where
             recog.base.enter_outer_alt(None, 1);
             {
                 /*InvokeRule oC_PropertyOrLabelsExpression*/
-                recog.base.set_state(997);
+                recog.base.set_state(1006);
                 recog.oC_PropertyOrLabelsExpression()?;
 
-                recog.base.set_state(1003);
+                recog.base.set_state(1012);
                 recog.err_handler.sync(&mut recog.base)?;
-                _alt = recog.interpreter.adaptive_predict(171, &mut recog.base)?;
+                _alt = recog.interpreter.adaptive_predict(173, &mut recog.base)?;
                 while _alt != 2 && _alt != INVALID_ALT {
                     if _alt == 1 {
                         {
-                            recog.base.set_state(1001);
+                            recog.base.set_state(1010);
                             recog.err_handler.sync(&mut recog.base)?;
-                            match recog.interpreter.adaptive_predict(170, &mut recog.base)? {
+                            match recog.interpreter.adaptive_predict(172, &mut recog.base)? {
                                 1 => {
                                     {
                                         /*InvokeRule oC_StringOperatorExpression*/
-                                        recog.base.set_state(998);
+                                        recog.base.set_state(1007);
                                         recog.oC_StringOperatorExpression()?;
                                     }
                                 }
                                 2 => {
                                     {
                                         /*InvokeRule oC_ListOperatorExpression*/
-                                        recog.base.set_state(999);
+                                        recog.base.set_state(1008);
                                         recog.oC_ListOperatorExpression()?;
                                     }
                                 }
                                 3 => {
                                     {
                                         /*InvokeRule oC_NullOperatorExpression*/
-                                        recog.base.set_state(1000);
+                                        recog.base.set_state(1009);
                                         recog.oC_NullOperatorExpression()?;
                                     }
                                 }
@@ -11323,9 +11462,9 @@ where
                             }
                         }
                     }
-                    recog.base.set_state(1005);
+                    recog.base.set_state(1014);
                     recog.err_handler.sync(&mut recog.base)?;
-                    _alt = recog.interpreter.adaptive_predict(171, &mut recog.base)?;
+                    _alt = recog.interpreter.adaptive_predict(173, &mut recog.base)?;
                 }
             }
         };
@@ -11471,32 +11610,32 @@ where
         let mut _localctx: Rc<OC_ListOperatorExpressionContextAll> = _localctx;
         let mut _la: isize;
         let result: Result<(), ANTLRError> = try {
-            recog.base.set_state(1031);
+            recog.base.set_state(1040);
             recog.err_handler.sync(&mut recog.base)?;
-            match recog.interpreter.adaptive_predict(177, &mut recog.base)? {
+            match recog.interpreter.adaptive_predict(179, &mut recog.base)? {
                 1 => {
                     //recog.base.enter_outer_alt(_localctx.clone(), 1);
                     recog.base.enter_outer_alt(None, 1);
                     {
                         {
-                            recog.base.set_state(1006);
+                            recog.base.set_state(1015);
                             recog.base.match_token(SP, &mut recog.err_handler)?;
 
-                            recog.base.set_state(1007);
+                            recog.base.set_state(1016);
                             recog.base.match_token(IN, &mut recog.err_handler)?;
 
-                            recog.base.set_state(1009);
+                            recog.base.set_state(1018);
                             recog.err_handler.sync(&mut recog.base)?;
                             _la = recog.base.input.la(1);
                             if _la == SP {
                                 {
-                                    recog.base.set_state(1008);
+                                    recog.base.set_state(1017);
                                     recog.base.match_token(SP, &mut recog.err_handler)?;
                                 }
                             }
 
                             /*InvokeRule oC_PropertyOrLabelsExpression*/
-                            recog.base.set_state(1011);
+                            recog.base.set_state(1020);
                             recog.oC_PropertyOrLabelsExpression()?;
                         }
                     }
@@ -11506,24 +11645,24 @@ where
                     recog.base.enter_outer_alt(None, 2);
                     {
                         {
-                            recog.base.set_state(1013);
+                            recog.base.set_state(1022);
                             recog.err_handler.sync(&mut recog.base)?;
                             _la = recog.base.input.la(1);
                             if _la == SP {
                                 {
-                                    recog.base.set_state(1012);
+                                    recog.base.set_state(1021);
                                     recog.base.match_token(SP, &mut recog.err_handler)?;
                                 }
                             }
 
-                            recog.base.set_state(1015);
+                            recog.base.set_state(1024);
                             recog.base.match_token(T__7, &mut recog.err_handler)?;
 
                             /*InvokeRule oC_Expression*/
-                            recog.base.set_state(1016);
+                            recog.base.set_state(1025);
                             recog.oC_Expression()?;
 
-                            recog.base.set_state(1017);
+                            recog.base.set_state(1026);
                             recog.base.match_token(T__8, &mut recog.err_handler)?;
                         }
                     }
@@ -11533,20 +11672,20 @@ where
                     recog.base.enter_outer_alt(None, 3);
                     {
                         {
-                            recog.base.set_state(1020);
+                            recog.base.set_state(1029);
                             recog.err_handler.sync(&mut recog.base)?;
                             _la = recog.base.input.la(1);
                             if _la == SP {
                                 {
-                                    recog.base.set_state(1019);
+                                    recog.base.set_state(1028);
                                     recog.base.match_token(SP, &mut recog.err_handler)?;
                                 }
                             }
 
-                            recog.base.set_state(1022);
+                            recog.base.set_state(1031);
                             recog.base.match_token(T__7, &mut recog.err_handler)?;
 
-                            recog.base.set_state(1024);
+                            recog.base.set_state(1033);
                             recog.err_handler.sync(&mut recog.base)?;
                             _la = recog.base.input.la(1);
                             if (((_la) & !0x3f) == 0
@@ -11555,46 +11694,46 @@ where
                                         | (1usize << T__7)
                                         | (1usize << T__12)
                                         | (1usize << T__13)
-                                        | (1usize << T__23)
-                                        | (1usize << T__25)
+                                        | (1usize << T__24)
+                                        | (1usize << T__26)
                                         | (1usize << ALL)))
                                     != 0)
-                                || (((_la - 76) & !0x3f) == 0
-                                    && ((1usize << (_la - 76))
-                                        & ((1usize << (NOT - 76))
-                                            | (1usize << (NULL - 76))
-                                            | (1usize << (COUNT - 76))
-                                            | (1usize << (ANY - 76))
-                                            | (1usize << (NONE - 76))
-                                            | (1usize << (SINGLE - 76))
-                                            | (1usize << (TRUE - 76))
-                                            | (1usize << (FALSE - 76))
-                                            | (1usize << (EXISTS - 76))
-                                            | (1usize << (CASE - 76))
-                                            | (1usize << (StringLiteral - 76))
-                                            | (1usize << (HexInteger - 76))
-                                            | (1usize << (DecimalInteger - 76))
-                                            | (1usize << (OctalInteger - 76))
-                                            | (1usize << (HexLetter - 76))
-                                            | (1usize << (ExponentDecimalReal - 76))
-                                            | (1usize << (RegularDecimalReal - 76))
-                                            | (1usize << (FILTER - 76))
-                                            | (1usize << (EXTRACT - 76))
-                                            | (1usize << (UnescapedSymbolicName - 76))
-                                            | (1usize << (EscapedSymbolicName - 76))))
+                                || (((_la - 77) & !0x3f) == 0
+                                    && ((1usize << (_la - 77))
+                                        & ((1usize << (NOT - 77))
+                                            | (1usize << (NULL - 77))
+                                            | (1usize << (COUNT - 77))
+                                            | (1usize << (ANY - 77))
+                                            | (1usize << (NONE - 77))
+                                            | (1usize << (SINGLE - 77))
+                                            | (1usize << (TRUE - 77))
+                                            | (1usize << (FALSE - 77))
+                                            | (1usize << (EXISTS - 77))
+                                            | (1usize << (CASE - 77))
+                                            | (1usize << (StringLiteral - 77))
+                                            | (1usize << (HexInteger - 77))
+                                            | (1usize << (DecimalInteger - 77))
+                                            | (1usize << (OctalInteger - 77))
+                                            | (1usize << (HexLetter - 77))
+                                            | (1usize << (ExponentDecimalReal - 77))
+                                            | (1usize << (RegularDecimalReal - 77))
+                                            | (1usize << (FILTER - 77))
+                                            | (1usize << (EXTRACT - 77))
+                                            | (1usize << (UnescapedSymbolicName - 77))
+                                            | (1usize << (EscapedSymbolicName - 77))))
                                         != 0)
                             {
                                 {
                                     /*InvokeRule oC_Expression*/
-                                    recog.base.set_state(1023);
+                                    recog.base.set_state(1032);
                                     recog.oC_Expression()?;
                                 }
                             }
 
-                            recog.base.set_state(1026);
+                            recog.base.set_state(1035);
                             recog.base.match_token(T__11, &mut recog.err_handler)?;
 
-                            recog.base.set_state(1028);
+                            recog.base.set_state(1037);
                             recog.err_handler.sync(&mut recog.base)?;
                             _la = recog.base.input.la(1);
                             if (((_la) & !0x3f) == 0
@@ -11603,43 +11742,43 @@ where
                                         | (1usize << T__7)
                                         | (1usize << T__12)
                                         | (1usize << T__13)
-                                        | (1usize << T__23)
-                                        | (1usize << T__25)
+                                        | (1usize << T__24)
+                                        | (1usize << T__26)
                                         | (1usize << ALL)))
                                     != 0)
-                                || (((_la - 76) & !0x3f) == 0
-                                    && ((1usize << (_la - 76))
-                                        & ((1usize << (NOT - 76))
-                                            | (1usize << (NULL - 76))
-                                            | (1usize << (COUNT - 76))
-                                            | (1usize << (ANY - 76))
-                                            | (1usize << (NONE - 76))
-                                            | (1usize << (SINGLE - 76))
-                                            | (1usize << (TRUE - 76))
-                                            | (1usize << (FALSE - 76))
-                                            | (1usize << (EXISTS - 76))
-                                            | (1usize << (CASE - 76))
-                                            | (1usize << (StringLiteral - 76))
-                                            | (1usize << (HexInteger - 76))
-                                            | (1usize << (DecimalInteger - 76))
-                                            | (1usize << (OctalInteger - 76))
-                                            | (1usize << (HexLetter - 76))
-                                            | (1usize << (ExponentDecimalReal - 76))
-                                            | (1usize << (RegularDecimalReal - 76))
-                                            | (1usize << (FILTER - 76))
-                                            | (1usize << (EXTRACT - 76))
-                                            | (1usize << (UnescapedSymbolicName - 76))
-                                            | (1usize << (EscapedSymbolicName - 76))))
+                                || (((_la - 77) & !0x3f) == 0
+                                    && ((1usize << (_la - 77))
+                                        & ((1usize << (NOT - 77))
+                                            | (1usize << (NULL - 77))
+                                            | (1usize << (COUNT - 77))
+                                            | (1usize << (ANY - 77))
+                                            | (1usize << (NONE - 77))
+                                            | (1usize << (SINGLE - 77))
+                                            | (1usize << (TRUE - 77))
+                                            | (1usize << (FALSE - 77))
+                                            | (1usize << (EXISTS - 77))
+                                            | (1usize << (CASE - 77))
+                                            | (1usize << (StringLiteral - 77))
+                                            | (1usize << (HexInteger - 77))
+                                            | (1usize << (DecimalInteger - 77))
+                                            | (1usize << (OctalInteger - 77))
+                                            | (1usize << (HexLetter - 77))
+                                            | (1usize << (ExponentDecimalReal - 77))
+                                            | (1usize << (RegularDecimalReal - 77))
+                                            | (1usize << (FILTER - 77))
+                                            | (1usize << (EXTRACT - 77))
+                                            | (1usize << (UnescapedSymbolicName - 77))
+                                            | (1usize << (EscapedSymbolicName - 77))))
                                         != 0)
                             {
                                 {
                                     /*InvokeRule oC_Expression*/
-                                    recog.base.set_state(1027);
+                                    recog.base.set_state(1036);
                                     recog.oC_Expression()?;
                                 }
                             }
 
-                            recog.base.set_state(1030);
+                            recog.base.set_state(1039);
                             recog.base.match_token(T__8, &mut recog.err_handler)?;
                         }
                     }
@@ -11805,57 +11944,71 @@ where
             //recog.base.enter_outer_alt(_localctx.clone(), 1);
             recog.base.enter_outer_alt(None, 1);
             {
-                recog.base.set_state(1043);
+                recog.base.set_state(1056);
                 recog.err_handler.sync(&mut recog.base)?;
-                match recog.interpreter.adaptive_predict(178, &mut recog.base)? {
+                match recog.interpreter.adaptive_predict(181, &mut recog.base)? {
                     1 => {
-                        recog.base.set_state(1033);
+                        recog.base.set_state(1042);
                         recog.base.match_token(SP, &mut recog.err_handler)?;
 
-                        recog.base.set_state(1034);
+                        recog.base.set_state(1043);
                         recog.base.match_token(STARTS, &mut recog.err_handler)?;
 
-                        recog.base.set_state(1035);
+                        recog.base.set_state(1044);
                         recog.base.match_token(SP, &mut recog.err_handler)?;
 
-                        recog.base.set_state(1036);
+                        recog.base.set_state(1045);
                         recog.base.match_token(WITH, &mut recog.err_handler)?;
                     }
                     2 => {
-                        recog.base.set_state(1037);
+                        recog.base.set_state(1046);
                         recog.base.match_token(SP, &mut recog.err_handler)?;
 
-                        recog.base.set_state(1038);
+                        recog.base.set_state(1047);
                         recog.base.match_token(ENDS, &mut recog.err_handler)?;
 
-                        recog.base.set_state(1039);
+                        recog.base.set_state(1048);
                         recog.base.match_token(SP, &mut recog.err_handler)?;
 
-                        recog.base.set_state(1040);
+                        recog.base.set_state(1049);
                         recog.base.match_token(WITH, &mut recog.err_handler)?;
                     }
                     3 => {
-                        recog.base.set_state(1041);
+                        recog.base.set_state(1050);
                         recog.base.match_token(SP, &mut recog.err_handler)?;
 
-                        recog.base.set_state(1042);
+                        recog.base.set_state(1051);
                         recog.base.match_token(CONTAINS, &mut recog.err_handler)?;
                     }
+                    4 => {
+                        recog.base.set_state(1053);
+                        recog.err_handler.sync(&mut recog.base)?;
+                        _la = recog.base.input.la(1);
+                        if _la == SP {
+                            {
+                                recog.base.set_state(1052);
+                                recog.base.match_token(SP, &mut recog.err_handler)?;
+                            }
+                        }
+
+                        recog.base.set_state(1055);
+                        recog.base.match_token(T__17, &mut recog.err_handler)?;
+                    }
 
                     _ => {}
                 }
-                recog.base.set_state(1046);
+                recog.base.set_state(1059);
                 recog.err_handler.sync(&mut recog.base)?;
                 _la = recog.base.input.la(1);
                 if _la == SP {
                     {
-                        recog.base.set_state(1045);
+                        recog.base.set_state(1058);
                         recog.base.match_token(SP, &mut recog.err_handler)?;
                     }
                 }
 
                 /*InvokeRule oC_PropertyOrLabelsExpression*/
-                recog.base.set_state(1048);
+                recog.base.set_state(1061);
                 recog.oC_PropertyOrLabelsExpression()?;
             }
         };
@@ -11996,24 +12149,24 @@ where
             .enter_rule(_localctx.clone(), 124, RULE_oC_NullOperatorExpression);
         let mut _localctx: Rc<OC_NullOperatorExpressionContextAll> = _localctx;
         let result: Result<(), ANTLRError> = try {
-            recog.base.set_state(1060);
+            recog.base.set_state(1073);
             recog.err_handler.sync(&mut recog.base)?;
-            match recog.interpreter.adaptive_predict(180, &mut recog.base)? {
+            match recog.interpreter.adaptive_predict(183, &mut recog.base)? {
                 1 => {
                     //recog.base.enter_outer_alt(_localctx.clone(), 1);
                     recog.base.enter_outer_alt(None, 1);
                     {
                         {
-                            recog.base.set_state(1050);
+                            recog.base.set_state(1063);
                             recog.base.match_token(SP, &mut recog.err_handler)?;
 
-                            recog.base.set_state(1051);
+                            recog.base.set_state(1064);
                             recog.base.match_token(IS, &mut recog.err_handler)?;
 
-                            recog.base.set_state(1052);
+                            recog.base.set_state(1065);
                             recog.base.match_token(SP, &mut recog.err_handler)?;
 
-                            recog.base.set_state(1053);
+                            recog.base.set_state(1066);
                             recog.base.match_token(NULL, &mut recog.err_handler)?;
                         }
                     }
@@ -12023,22 +12176,22 @@ where
                     recog.base.enter_outer_alt(None, 2);
                     {
                         {
-                            recog.base.set_state(1054);
+                            recog.base.set_state(1067);
                             recog.base.match_token(SP, &mut recog.err_handler)?;
 
-                            recog.base.set_state(1055);
+                            recog.base.set_state(1068);
                             recog.base.match_token(IS, &mut recog.err_handler)?;
 
-                            recog.base.set_state(1056);
+                            recog.base.set_state(1069);
                             recog.base.match_token(SP, &mut recog.err_handler)?;
 
-                            recog.base.set_state(1057);
+                            recog.base.set_state(1070);
                             recog.base.match_token(NOT, &mut recog.err_handler)?;
 
-                            recog.base.set_state(1058);
+                            recog.base.set_state(1071);
                             recog.base.match_token(SP, &mut recog.err_handler)?;
 
-                            recog.base.set_state(1059);
+                            recog.base.set_state(1072);
                             recog.base.match_token(NULL, &mut recog.err_handler)?;
                         }
                     }
@@ -12193,53 +12346,53 @@ where
             recog.base.enter_outer_alt(None, 1);
             {
                 /*InvokeRule oC_Atom*/
-                recog.base.set_state(1062);
+                recog.base.set_state(1075);
                 recog.oC_Atom()?;
 
-                recog.base.set_state(1069);
+                recog.base.set_state(1082);
                 recog.err_handler.sync(&mut recog.base)?;
-                _alt = recog.interpreter.adaptive_predict(182, &mut recog.base)?;
+                _alt = recog.interpreter.adaptive_predict(185, &mut recog.base)?;
                 while _alt != 2 && _alt != INVALID_ALT {
                     if _alt == 1 {
                         {
                             {
-                                recog.base.set_state(1064);
+                                recog.base.set_state(1077);
                                 recog.err_handler.sync(&mut recog.base)?;
                                 _la = recog.base.input.la(1);
                                 if _la == SP {
                                     {
-                                        recog.base.set_state(1063);
+                                        recog.base.set_state(1076);
                                         recog.base.match_token(SP, &mut recog.err_handler)?;
                                     }
                                 }
 
                                 /*InvokeRule oC_PropertyLookup*/
-                                recog.base.set_state(1066);
+                                recog.base.set_state(1079);
                                 recog.oC_PropertyLookup()?;
                             }
                         }
                     }
-                    recog.base.set_state(1071);
+                    recog.base.set_state(1084);
                     recog.err_handler.sync(&mut recog.base)?;
-                    _alt = recog.interpreter.adaptive_predict(182, &mut recog.base)?;
+                    _alt = recog.interpreter.adaptive_predict(185, &mut recog.base)?;
                 }
-                recog.base.set_state(1076);
+                recog.base.set_state(1089);
                 recog.err_handler.sync(&mut recog.base)?;
-                match recog.interpreter.adaptive_predict(184, &mut recog.base)? {
+                match recog.interpreter.adaptive_predict(187, &mut recog.base)? {
                     x if x == 1 => {
                         {
-                            recog.base.set_state(1073);
+                            recog.base.set_state(1086);
                             recog.err_handler.sync(&mut recog.base)?;
                             _la = recog.base.input.la(1);
                             if _la == SP {
                                 {
-                                    recog.base.set_state(1072);
+                                    recog.base.set_state(1085);
                                     recog.base.match_token(SP, &mut recog.err_handler)?;
                                 }
                             }
 
                             /*InvokeRule oC_NodeLabels*/
-                            recog.base.set_state(1075);
+                            recog.base.set_state(1088);
                             recog.oC_NodeLabels()?;
                         }
                     }
@@ -12449,15 +12602,15 @@ where
         let mut _localctx: Rc<OC_AtomContextAll> = _localctx;
         let mut _la: isize;
         let result: Result<(), ANTLRError> = try {
-            recog.base.set_state(1156);
+            recog.base.set_state(1169);
             recog.err_handler.sync(&mut recog.base)?;
-            match recog.interpreter.adaptive_predict(200, &mut recog.base)? {
+            match recog.interpreter.adaptive_predict(203, &mut recog.base)? {
                 1 => {
                     //recog.base.enter_outer_alt(_localctx.clone(), 1);
                     recog.base.enter_outer_alt(None, 1);
                     {
                         /*InvokeRule oC_Literal*/
-                        recog.base.set_state(1078);
+                        recog.base.set_state(1091);
                         recog.oC_Literal()?;
                     }
                 }
@@ -12466,7 +12619,7 @@ where
                     recog.base.enter_outer_alt(None, 2);
                     {
                         /*InvokeRule oC_Parameter*/
-                        recog.base.set_state(1079);
+                        recog.base.set_state(1092);
                         recog.oC_Parameter()?;
                     }
                 }
@@ -12475,7 +12628,7 @@ where
                     recog.base.enter_outer_alt(None, 3);
                     {
                         /*InvokeRule oC_CaseExpression*/
-                        recog.base.set_state(1080);
+                        recog.base.set_state(1093);
                         recog.oC_CaseExpression()?;
                     }
                 }
@@ -12484,46 +12637,46 @@ where
                     recog.base.enter_outer_alt(None, 4);
                     {
                         {
-                            recog.base.set_state(1081);
+                            recog.base.set_state(1094);
                             recog.base.match_token(COUNT, &mut recog.err_handler)?;
 
-                            recog.base.set_state(1083);
+                            recog.base.set_state(1096);
                             recog.err_handler.sync(&mut recog.base)?;
                             _la = recog.base.input.la(1);
                             if _la == SP {
                                 {
-                                    recog.base.set_state(1082);
+                                    recog.base.set_state(1095);
                                     recog.base.match_token(SP, &mut recog.err_handler)?;
                                 }
                             }
 
-                            recog.base.set_state(1085);
+                            recog.base.set_state(1098);
                             recog.base.match_token(T__5, &mut recog.err_handler)?;
 
-                            recog.base.set_state(1087);
+                            recog.base.set_state(1100);
                             recog.err_handler.sync(&mut recog.base)?;
                             _la = recog.base.input.la(1);
                             if _la == SP {
                                 {
-                                    recog.base.set_state(1086);
+                                    recog.base.set_state(1099);
                                     recog.base.match_token(SP, &mut recog.err_handler)?;
                                 }
                             }
 
-                            recog.base.set_state(1089);
+                            recog.base.set_state(1102);
                             recog.base.match_token(T__4, &mut recog.err_handler)?;
 
-                            recog.base.set_state(1091);
+                            recog.base.set_state(1104);
                             recog.err_handler.sync(&mut recog.base)?;
                             _la = recog.base.input.la(1);
                             if _la == SP {
                                 {
-                                    recog.base.set_state(1090);
+                                    recog.base.set_state(1103);
                                     recog.base.match_token(SP, &mut recog.err_handler)?;
                                 }
                             }
 
-                            recog.base.set_state(1093);
+                            recog.base.set_state(1106);
                             recog.base.match_token(T__6, &mut recog.err_handler)?;
                         }
                     }
@@ -12533,7 +12686,7 @@ where
                     recog.base.enter_outer_alt(None, 5);
                     {
                         /*InvokeRule oC_ListComprehension*/
-                        recog.base.set_state(1094);
+                        recog.base.set_state(1107);
                         recog.oC_ListComprehension()?;
                     }
                 }
@@ -12542,7 +12695,7 @@ where
                     recog.base.enter_outer_alt(None, 6);
                     {
                         /*InvokeRule oC_PatternComprehension*/
-                        recog.base.set_state(1095);
+                        recog.base.set_state(1108);
                         recog.oC_PatternComprehension()?;
                     }
                 }
@@ -12551,47 +12704,47 @@ where
                     recog.base.enter_outer_alt(None, 7);
                     {
                         {
-                            recog.base.set_state(1096);
+                            recog.base.set_state(1109);
                             recog.base.match_token(ALL, &mut recog.err_handler)?;
 
-                            recog.base.set_state(1098);
+                            recog.base.set_state(1111);
                             recog.err_handler.sync(&mut recog.base)?;
                             _la = recog.base.input.la(1);
                             if _la == SP {
                                 {
-                                    recog.base.set_state(1097);
+                                    recog.base.set_state(1110);
                                     recog.base.match_token(SP, &mut recog.err_handler)?;
                                 }
                             }
 
-                            recog.base.set_state(1100);
+                            recog.base.set_state(1113);
                             recog.base.match_token(T__5, &mut recog.err_handler)?;
 
-                            recog.base.set_state(1102);
+                            recog.base.set_state(1115);
                             recog.err_handler.sync(&mut recog.base)?;
                             _la = recog.base.input.la(1);
                             if _la == SP {
                                 {
-                                    recog.base.set_state(1101);
+                                    recog.base.set_state(1114);
                                     recog.base.match_token(SP, &mut recog.err_handler)?;
                                 }
                             }
 
                             /*InvokeRule oC_FilterExpression*/
-                            recog.base.set_state(1104);
+                            recog.base.set_state(1117);
                             recog.oC_FilterExpression()?;
 
-                            recog.base.set_state(1106);
+                            recog.base.set_state(1119);
                             recog.err_handler.sync(&mut recog.base)?;
                             _la = recog.base.input.la(1);
                             if _la == SP {
                                 {
-                                    recog.base.set_state(1105);
+                                    recog.base.set_state(1118);
                                     recog.base.match_token(SP, &mut recog.err_handler)?;
                                 }
                             }
 
-                            recog.base.set_state(1108);
+                            recog.base.set_state(1121);
                             recog.base.match_token(T__6, &mut recog.err_handler)?;
                         }
                     }
@@ -12601,47 +12754,47 @@ where
                     recog.base.enter_outer_alt(None, 8);
                     {
                         {
-                            recog.base.set_state(1110);
+                            recog.base.set_state(1123);
                             recog.base.match_token(ANY, &mut recog.err_handler)?;
 
-                            recog.base.set_state(1112);
+                            recog.base.set_state(1125);
                             recog.err_handler.sync(&mut recog.base)?;
                             _la = recog.base.input.la(1);
                             if _la == SP {
                                 {
-                                    recog.base.set_state(1111);
+                                    recog.base.set_state(1124);
                                     recog.base.match_token(SP, &mut recog.err_handler)?;
                                 }
                             }
 
-                            recog.base.set_state(1114);
+                            recog.base.set_state(1127);
                             recog.base.match_token(T__5, &mut recog.err_handler)?;
 
-                            recog.base.set_state(1116);
+                            recog.base.set_state(1129);
                             recog.err_handler.sync(&mut recog.base)?;
                             _la = recog.base.input.la(1);
                             if _la == SP {
                                 {
-                                    recog.base.set_state(1115);
+                                    recog.base.set_state(1128);
                                     recog.base.match_token(SP, &mut recog.err_handler)?;
                                 }
                             }
 
                             /*InvokeRule oC_FilterExpression*/
-                            recog.base.set_state(1118);
+                            recog.base.set_state(1131);
                             recog.oC_FilterExpression()?;
 
-                            recog.base.set_state(1120);
+                            recog.base.set_state(1133);
                             recog.err_handler.sync(&mut recog.base)?;
                             _la = recog.base.input.la(1);
                             if _la == SP {
                                 {
-                                    recog.base.set_state(1119);
+                                    recog.base.set_state(1132);
                                     recog.base.match_token(SP, &mut recog.err_handler)?;
                                 }
                             }
 
-                            recog.base.set_state(1122);
+                            recog.base.set_state(1135);
                             recog.base.match_token(T__6, &mut recog.err_handler)?;
                         }
                     }
@@ -12651,47 +12804,47 @@ where
                     recog.base.enter_outer_alt(None, 9);
                     {
                         {
-                            recog.base.set_state(1124);
+                            recog.base.set_state(1137);
                             recog.base.match_token(NONE, &mut recog.err_handler)?;
 
-                            recog.base.set_state(1126);
+                            recog.base.set_state(1139);
                             recog.err_handler.sync(&mut recog.base)?;
                             _la = recog.base.input.la(1);
                             if _la == SP {
                                 {
-                                    recog.base.set_state(1125);
+                                    recog.base.set_state(1138);
                                     recog.base.match_token(SP, &mut recog.err_handler)?;
                                 }
                             }
 
-                            recog.base.set_state(1128);
+                            recog.base.set_state(1141);
                             recog.base.match_token(T__5, &mut recog.err_handler)?;
 
-                            recog.base.set_state(1130);
+                            recog.base.set_state(1143);
                             recog.err_handler.sync(&mut recog.base)?;
                             _la = recog.base.input.la(1);
                             if _la == SP {
                                 {
-                                    recog.base.set_state(1129);
+                                    recog.base.set_state(1142);
                                     recog.base.match_token(SP, &mut recog.err_handler)?;
                                 }
                             }
 
                             /*InvokeRule oC_FilterExpression*/
-                            recog.base.set_state(1132);
+                            recog.base.set_state(1145);
                             recog.oC_FilterExpression()?;
 
-                            recog.base.set_state(1134);
+                            recog.base.set_state(1147);
                             recog.err_handler.sync(&mut recog.base)?;
                             _la = recog.base.input.la(1);
                             if _la == SP {
                                 {
-                                    recog.base.set_state(1133);
+                                    recog.base.set_state(1146);
                                     recog.base.match_token(SP, &mut recog.err_handler)?;
                                 }
                             }
 
-                            recog.base.set_state(1136);
+                            recog.base.set_state(1149);
                             recog.base.match_token(T__6, &mut recog.err_handler)?;
                         }
                     }
@@ -12701,47 +12854,47 @@ where
                     recog.base.enter_outer_alt(None, 10);
                     {
                         {
-                            recog.base.set_state(1138);
+                            recog.base.set_state(1151);
                             recog.base.match_token(SINGLE, &mut recog.err_handler)?;
 
-                            recog.base.set_state(1140);
+                            recog.base.set_state(1153);
                             recog.err_handler.sync(&mut recog.base)?;
                             _la = recog.base.input.la(1);
                             if _la == SP {
                                 {
-                                    recog.base.set_state(1139);
+                                    recog.base.set_state(1152);
                                     recog.base.match_token(SP, &mut recog.err_handler)?;
                                 }
                             }
 
-                            recog.base.set_state(1142);
+                            recog.base.set_state(1155);
                             recog.base.match_token(T__5, &mut recog.err_handler)?;
 
-                            recog.base.set_state(1144);
+                            recog.base.set_state(1157);
                             recog.err_handler.sync(&mut recog.base)?;
                             _la = recog.base.input.la(1);
                             if _la == SP {
                                 {
-                                    recog.base.set_state(1143);
+                                    recog.base.set_state(1156);
                                     recog.base.match_token(SP, &mut recog.err_handler)?;
                                 }
                             }
 
                             /*InvokeRule oC_FilterExpression*/
-                            recog.base.set_state(1146);
+                            recog.base.set_state(1159);
                             recog.oC_FilterExpression()?;
 
-                            recog.base.set_state(1148);
+                            recog.base.set_state(1161);
                             recog.err_handler.sync(&mut recog.base)?;
                             _la = recog.base.input.la(1);
                             if _la == SP {
                                 {
-                                    recog.base.set_state(1147);
+                                    recog.base.set_state(1160);
                                     recog.base.match_token(SP, &mut recog.err_handler)?;
                                 }
                             }
 
-                            recog.base.set_state(1150);
+                            recog.base.set_state(1163);
                             recog.base.match_token(T__6, &mut recog.err_handler)?;
                         }
                     }
@@ -12751,7 +12904,7 @@ where
                     recog.base.enter_outer_alt(None, 11);
                     {
                         /*InvokeRule oC_RelationshipsPattern*/
-                        recog.base.set_state(1152);
+                        recog.base.set_state(1165);
                         recog.oC_RelationshipsPattern()?;
                     }
                 }
@@ -12760,7 +12913,7 @@ where
                     recog.base.enter_outer_alt(None, 12);
                     {
                         /*InvokeRule oC_ParenthesizedExpression*/
-                        recog.base.set_state(1153);
+                        recog.base.set_state(1166);
                         recog.oC_ParenthesizedExpression()?;
                     }
                 }
@@ -12769,7 +12922,7 @@ where
                     recog.base.enter_outer_alt(None, 13);
                     {
                         /*InvokeRule oC_FunctionInvocation*/
-                        recog.base.set_state(1154);
+                        recog.base.set_state(1167);
                         recog.oC_FunctionInvocation()?;
                     }
                 }
@@ -12778,7 +12931,7 @@ where
                     recog.base.enter_outer_alt(None, 14);
                     {
                         /*InvokeRule oC_Variable*/
-                        recog.base.set_state(1155);
+                        recog.base.set_state(1168);
                         recog.oC_Variable()?;
                     }
                 }
@@ -12913,7 +13066,7 @@ where
             .enter_rule(_localctx.clone(), 130, RULE_oC_Literal);
         let mut _localctx: Rc<OC_LiteralContextAll> = _localctx;
         let result: Result<(), ANTLRError> = try {
-            recog.base.set_state(1164);
+            recog.base.set_state(1177);
             recog.err_handler.sync(&mut recog.base)?;
             match recog.base.input.la(1) {
                 HexInteger | DecimalInteger | OctalInteger | ExponentDecimalReal
@@ -12922,7 +13075,7 @@ where
                     recog.base.enter_outer_alt(None, 1);
                     {
                         /*InvokeRule oC_NumberLiteral*/
-                        recog.base.set_state(1158);
+                        recog.base.set_state(1171);
                         recog.oC_NumberLiteral()?;
                     }
                 }
@@ -12931,7 +13084,7 @@ where
                     //recog.base.enter_outer_alt(_localctx.clone(), 2);
                     recog.base.enter_outer_alt(None, 2);
                     {
-                        recog.base.set_state(1159);
+                        recog.base.set_state(1172);
                         recog
                             .base
                             .match_token(StringLiteral, &mut recog.err_handler)?;
@@ -12943,7 +13096,7 @@ where
                     recog.base.enter_outer_alt(None, 3);
                     {
                         /*InvokeRule oC_BooleanLiteral*/
-                        recog.base.set_state(1160);
+                        recog.base.set_state(1173);
                         recog.oC_BooleanLiteral()?;
                     }
                 }
@@ -12952,17 +13105,17 @@ where
                     //recog.base.enter_outer_alt(_localctx.clone(), 4);
                     recog.base.enter_outer_alt(None, 4);
                     {
-                        recog.base.set_state(1161);
+                        recog.base.set_state(1174);
                         recog.base.match_token(NULL, &mut recog.err_handler)?;
                     }
                 }
 
-                T__23 => {
+                T__24 => {
                     //recog.base.enter_outer_alt(_localctx.clone(), 5);
                     recog.base.enter_outer_alt(None, 5);
                     {
                         /*InvokeRule oC_MapLiteral*/
-                        recog.base.set_state(1162);
+                        recog.base.set_state(1175);
                         recog.oC_MapLiteral()?;
                     }
                 }
@@ -12972,7 +13125,7 @@ where
                     recog.base.enter_outer_alt(None, 6);
                     {
                         /*InvokeRule oC_ListLiteral*/
-                        recog.base.set_state(1163);
+                        recog.base.set_state(1176);
                         recog.oC_ListLiteral()?;
                     }
                 }
@@ -13093,7 +13246,7 @@ where
             //recog.base.enter_outer_alt(_localctx.clone(), 1);
             recog.base.enter_outer_alt(None, 1);
             {
-                recog.base.set_state(1166);
+                recog.base.set_state(1179);
                 _la = recog.base.input.la(1);
                 if !(_la == TRUE || _la == FALSE) {
                     recog.err_handler.recover_inline(&mut recog.base)?;
@@ -13226,20 +13379,20 @@ where
             //recog.base.enter_outer_alt(_localctx.clone(), 1);
             recog.base.enter_outer_alt(None, 1);
             {
-                recog.base.set_state(1168);
+                recog.base.set_state(1181);
                 recog.base.match_token(T__7, &mut recog.err_handler)?;
 
-                recog.base.set_state(1170);
+                recog.base.set_state(1183);
                 recog.err_handler.sync(&mut recog.base)?;
                 _la = recog.base.input.la(1);
                 if _la == SP {
                     {
-                        recog.base.set_state(1169);
+                        recog.base.set_state(1182);
                         recog.base.match_token(SP, &mut recog.err_handler)?;
                     }
                 }
 
-                recog.base.set_state(1189);
+                recog.base.set_state(1202);
                 recog.err_handler.sync(&mut recog.base)?;
                 _la = recog.base.input.la(1);
                 if (((_la) & !0x3f) == 0
@@ -13248,92 +13401,92 @@ where
                             | (1usize << T__7)
                             | (1usize << T__12)
                             | (1usize << T__13)
-                            | (1usize << T__23)
-                            | (1usize << T__25)
+                            | (1usize << T__24)
+                            | (1usize << T__26)
                             | (1usize << ALL)))
                         != 0)
-                    || (((_la - 76) & !0x3f) == 0
-                        && ((1usize << (_la - 76))
-                            & ((1usize << (NOT - 76))
-                                | (1usize << (NULL - 76))
-                                | (1usize << (COUNT - 76))
-                                | (1usize << (ANY - 76))
-                                | (1usize << (NONE - 76))
-                                | (1usize << (SINGLE - 76))
-                                | (1usize << (TRUE - 76))
-                                | (1usize << (FALSE - 76))
-                                | (1usize << (EXISTS - 76))
-                                | (1usize << (CASE - 76))
-                                | (1usize << (StringLiteral - 76))
-                                | (1usize << (HexInteger - 76))
-                                | (1usize << (DecimalInteger - 76))
-                                | (1usize << (OctalInteger - 76))
-                                | (1usize << (HexLetter - 76))
-                                | (1usize << (ExponentDecimalReal - 76))
-                                | (1usize << (RegularDecimalReal - 76))
-                                | (1usize << (FILTER - 76))
-                                | (1usize << (EXTRACT - 76))
-                                | (1usize << (UnescapedSymbolicName - 76))
-                                | (1usize << (EscapedSymbolicName - 76))))
+                    || (((_la - 77) & !0x3f) == 0
+                        && ((1usize << (_la - 77))
+                            & ((1usize << (NOT - 77))
+                                | (1usize << (NULL - 77))
+                                | (1usize << (COUNT - 77))
+                                | (1usize << (ANY - 77))
+                                | (1usize << (NONE - 77))
+                                | (1usize << (SINGLE - 77))
+                                | (1usize << (TRUE - 77))
+                                | (1usize << (FALSE - 77))
+                                | (1usize << (EXISTS - 77))
+                                | (1usize << (CASE - 77))
+                                | (1usize << (StringLiteral - 77))
+                                | (1usize << (HexInteger - 77))
+                                | (1usize << (DecimalInteger - 77))
+                                | (1usize << (OctalInteger - 77))
+                                | (1usize << (HexLetter - 77))
+                                | (1usize << (ExponentDecimalReal - 77))
+                                | (1usize << (RegularDecimalReal - 77))
+                                | (1usize << (FILTER - 77))
+                                | (1usize << (EXTRACT - 77))
+                                | (1usize << (UnescapedSymbolicName - 77))
+                                | (1usize << (EscapedSymbolicName - 77))))
                             != 0)
                 {
                     {
                         /*InvokeRule oC_Expression*/
-                        recog.base.set_state(1172);
+                        recog.base.set_state(1185);
                         recog.oC_Expression()?;
 
-                        recog.base.set_state(1174);
+                        recog.base.set_state(1187);
                         recog.err_handler.sync(&mut recog.base)?;
                         _la = recog.base.input.la(1);
                         if _la == SP {
                             {
-                                recog.base.set_state(1173);
+                                recog.base.set_state(1186);
                                 recog.base.match_token(SP, &mut recog.err_handler)?;
                             }
                         }
 
-                        recog.base.set_state(1186);
+                        recog.base.set_state(1199);
                         recog.err_handler.sync(&mut recog.base)?;
                         _la = recog.base.input.la(1);
                         while _la == T__1 {
                             {
                                 {
-                                    recog.base.set_state(1176);
+                                    recog.base.set_state(1189);
                                     recog.base.match_token(T__1, &mut recog.err_handler)?;
 
-                                    recog.base.set_state(1178);
+                                    recog.base.set_state(1191);
                                     recog.err_handler.sync(&mut recog.base)?;
                                     _la = recog.base.input.la(1);
                                     if _la == SP {
                                         {
-                                            recog.base.set_state(1177);
+                                            recog.base.set_state(1190);
                                             recog.base.match_token(SP, &mut recog.err_handler)?;
                                         }
                                     }
 
                                     /*InvokeRule oC_Expression*/
-                                    recog.base.set_state(1180);
+                                    recog.base.set_state(1193);
                                     recog.oC_Expression()?;
 
-                                    recog.base.set_state(1182);
+                                    recog.base.set_state(1195);
                                     recog.err_handler.sync(&mut recog.base)?;
                                     _la = recog.base.input.la(1);
                                     if _la == SP {
                                         {
-                                            recog.base.set_state(1181);
+                                            recog.base.set_state(1194);
                                             recog.base.match_token(SP, &mut recog.err_handler)?;
                                         }
                                     }
                                 }
                             }
-                            recog.base.set_state(1188);
+                            recog.base.set_state(1201);
                             recog.err_handler.sync(&mut recog.base)?;
                             _la = recog.base.input.la(1);
                         }
                     }
                 }
 
-                recog.base.set_state(1191);
+                recog.base.set_state(1204);
                 recog.base.match_token(T__8, &mut recog.err_handler)?;
             }
         };
@@ -13453,7 +13606,7 @@ where
         let mut _localctx: Rc<OC_PartialComparisonExpressionContextAll> = _localctx;
         let mut _la: isize;
         let result: Result<(), ANTLRError> = try {
-            recog.base.set_state(1223);
+            recog.base.set_state(1236);
             recog.err_handler.sync(&mut recog.base)?;
             match recog.base.input.la(1) {
                 T__2 => {
@@ -13461,146 +13614,146 @@ where
                     recog.base.enter_outer_alt(None, 1);
                     {
                         {
-                            recog.base.set_state(1193);
+                            recog.base.set_state(1206);
                             recog.base.match_token(T__2, &mut recog.err_handler)?;
 
-                            recog.base.set_state(1195);
+                            recog.base.set_state(1208);
                             recog.err_handler.sync(&mut recog.base)?;
                             _la = recog.base.input.la(1);
                             if _la == SP {
                                 {
-                                    recog.base.set_state(1194);
+                                    recog.base.set_state(1207);
                                     recog.base.match_token(SP, &mut recog.err_handler)?;
                                 }
                             }
 
                             /*InvokeRule oC_AddOrSubtractExpression*/
-                            recog.base.set_state(1197);
+                            recog.base.set_state(1210);
                             recog.oC_AddOrSubtractExpression()?;
                         }
                     }
                 }
 
-                T__17 => {
+                T__18 => {
                     //recog.base.enter_outer_alt(_localctx.clone(), 2);
                     recog.base.enter_outer_alt(None, 2);
                     {
                         {
-                            recog.base.set_state(1198);
-                            recog.base.match_token(T__17, &mut recog.err_handler)?;
+                            recog.base.set_state(1211);
+                            recog.base.match_token(T__18, &mut recog.err_handler)?;
 
-                            recog.base.set_state(1200);
+                            recog.base.set_state(1213);
                             recog.err_handler.sync(&mut recog.base)?;
                             _la = recog.base.input.la(1);
                             if _la == SP {
                                 {
-                                    recog.base.set_state(1199);
+                                    recog.base.set_state(1212);
                                     recog.base.match_token(SP, &mut recog.err_handler)?;
                                 }
                             }
 
                             /*InvokeRule oC_AddOrSubtractExpression*/
-                            recog.base.set_state(1202);
+                            recog.base.set_state(1215);
                             recog.oC_AddOrSubtractExpression()?;
                         }
                     }
                 }
 
-                T__18 => {
+                T__19 => {
                     //recog.base.enter_outer_alt(_localctx.clone(), 3);
                     recog.base.enter_outer_alt(None, 3);
                     {
                         {
-                            recog.base.set_state(1203);
-                            recog.base.match_token(T__18, &mut recog.err_handler)?;
+                            recog.base.set_state(1216);
+                            recog.base.match_token(T__19, &mut recog.err_handler)?;
 
-                            recog.base.set_state(1205);
+                            recog.base.set_state(1218);
                             recog.err_handler.sync(&mut recog.base)?;
                             _la = recog.base.input.la(1);
                             if _la == SP {
                                 {
-                                    recog.base.set_state(1204);
+                                    recog.base.set_state(1217);
                                     recog.base.match_token(SP, &mut recog.err_handler)?;
                                 }
                             }
 
                             /*InvokeRule oC_AddOrSubtractExpression*/
-                            recog.base.set_state(1207);
+                            recog.base.set_state(1220);
                             recog.oC_AddOrSubtractExpression()?;
                         }
                     }
                 }
 
-                T__19 => {
+                T__20 => {
                     //recog.base.enter_outer_alt(_localctx.clone(), 4);
                     recog.base.enter_outer_alt(None, 4);
                     {
                         {
-                            recog.base.set_state(1208);
-                            recog.base.match_token(T__19, &mut recog.err_handler)?;
+                            recog.base.set_state(1221);
+                            recog.base.match_token(T__20, &mut recog.err_handler)?;
 
-                            recog.base.set_state(1210);
+                            recog.base.set_state(1223);
                             recog.err_handler.sync(&mut recog.base)?;
                             _la = recog.base.input.la(1);
                             if _la == SP {
                                 {
-                                    recog.base.set_state(1209);
+                                    recog.base.set_state(1222);
                                     recog.base.match_token(SP, &mut recog.err_handler)?;
                                 }
                             }
 
                             /*InvokeRule oC_AddOrSubtractExpression*/
-                            recog.base.set_state(1212);
+                            recog.base.set_state(1225);
                             recog.oC_AddOrSubtractExpression()?;
                         }
                     }
                 }
 
-                T__20 => {
+                T__21 => {
                     //recog.base.enter_outer_alt(_localctx.clone(), 5);
                     recog.base.enter_outer_alt(None, 5);
                     {
                         {
-                            recog.base.set_state(1213);
-                            recog.base.match_token(T__20, &mut recog.err_handler)?;
+                            recog.base.set_state(1226);
+                            recog.base.match_token(T__21, &mut recog.err_handler)?;
 
-                            recog.base.set_state(1215);
+                            recog.base.set_state(1228);
                             recog.err_handler.sync(&mut recog.base)?;
                             _la = recog.base.input.la(1);
                             if _la == SP {
                                 {
-                                    recog.base.set_state(1214);
+                                    recog.base.set_state(1227);
                                     recog.base.match_token(SP, &mut recog.err_handler)?;
                                 }
                             }
 
                             /*InvokeRule oC_AddOrSubtractExpression*/
-                            recog.base.set_state(1217);
+                            recog.base.set_state(1230);
                             recog.oC_AddOrSubtractExpression()?;
                         }
                     }
                 }
 
-                T__21 => {
+                T__22 => {
                     //recog.base.enter_outer_alt(_localctx.clone(), 6);
                     recog.base.enter_outer_alt(None, 6);
                     {
                         {
-                            recog.base.set_state(1218);
-                            recog.base.match_token(T__21, &mut recog.err_handler)?;
+                            recog.base.set_state(1231);
+                            recog.base.match_token(T__22, &mut recog.err_handler)?;
 
-                            recog.base.set_state(1220);
+                            recog.base.set_state(1233);
                             recog.err_handler.sync(&mut recog.base)?;
                             _la = recog.base.input.la(1);
                             if _la == SP {
                                 {
-                                    recog.base.set_state(1219);
+                                    recog.base.set_state(1232);
                                     recog.base.match_token(SP, &mut recog.err_handler)?;
                                 }
                             }
 
                             /*InvokeRule oC_AddOrSubtractExpression*/
-                            recog.base.set_state(1222);
+                            recog.base.set_state(1235);
                             recog.oC_AddOrSubtractExpression()?;
                         }
                     }
@@ -13734,34 +13887,34 @@ where
             //recog.base.enter_outer_alt(_localctx.clone(), 1);
             recog.base.enter_outer_alt(None, 1);
             {
-                recog.base.set_state(1225);
+                recog.base.set_state(1238);
                 recog.base.match_token(T__5, &mut recog.err_handler)?;
 
-                recog.base.set_state(1227);
+                recog.base.set_state(1240);
                 recog.err_handler.sync(&mut recog.base)?;
                 _la = recog.base.input.la(1);
                 if _la == SP {
                     {
-                        recog.base.set_state(1226);
+                        recog.base.set_state(1239);
                         recog.base.match_token(SP, &mut recog.err_handler)?;
                     }
                 }
 
                 /*InvokeRule oC_Expression*/
-                recog.base.set_state(1229);
+                recog.base.set_state(1242);
                 recog.oC_Expression()?;
 
-                recog.base.set_state(1231);
+                recog.base.set_state(1244);
                 recog.err_handler.sync(&mut recog.base)?;
                 _la = recog.base.input.la(1);
                 if _la == SP {
                     {
-                        recog.base.set_state(1230);
+                        recog.base.set_state(1243);
                         recog.base.match_token(SP, &mut recog.err_handler)?;
                     }
                 }
 
-                recog.base.set_state(1233);
+                recog.base.set_state(1246);
                 recog.base.match_token(T__6, &mut recog.err_handler)?;
             }
         };
@@ -13905,28 +14058,28 @@ where
             recog.base.enter_outer_alt(None, 1);
             {
                 /*InvokeRule oC_NodePattern*/
-                recog.base.set_state(1235);
+                recog.base.set_state(1248);
                 recog.oC_NodePattern()?;
 
-                recog.base.set_state(1240);
+                recog.base.set_state(1253);
                 recog.err_handler.sync(&mut recog.base)?;
                 _alt = 1;
                 loop {
                     match _alt {
                         x if x == 1 => {
                             {
-                                recog.base.set_state(1237);
+                                recog.base.set_state(1250);
                                 recog.err_handler.sync(&mut recog.base)?;
                                 _la = recog.base.input.la(1);
                                 if _la == SP {
                                     {
-                                        recog.base.set_state(1236);
+                                        recog.base.set_state(1249);
                                         recog.base.match_token(SP, &mut recog.err_handler)?;
                                     }
                                 }
 
                                 /*InvokeRule oC_PatternElementChain*/
-                                recog.base.set_state(1239);
+                                recog.base.set_state(1252);
                                 recog.oC_PatternElementChain()?;
                             }
                         }
@@ -13935,9 +14088,9 @@ where
                             &mut recog.base,
                         )))?,
                     }
-                    recog.base.set_state(1242);
+                    recog.base.set_state(1255);
                     recog.err_handler.sync(&mut recog.base)?;
-                    _alt = recog.interpreter.adaptive_predict(218, &mut recog.base)?;
+                    _alt = recog.interpreter.adaptive_predict(221, &mut recog.base)?;
                     if _alt == 2 || _alt == INVALID_ALT {
                         break;
                     }
@@ -14062,26 +14215,26 @@ where
             recog.base.enter_outer_alt(None, 1);
             {
                 /*InvokeRule oC_IdInColl*/
-                recog.base.set_state(1244);
+                recog.base.set_state(1257);
                 recog.oC_IdInColl()?;
 
-                recog.base.set_state(1249);
+                recog.base.set_state(1262);
                 recog.err_handler.sync(&mut recog.base)?;
-                match recog.interpreter.adaptive_predict(220, &mut recog.base)? {
+                match recog.interpreter.adaptive_predict(223, &mut recog.base)? {
                     x if x == 1 => {
                         {
-                            recog.base.set_state(1246);
+                            recog.base.set_state(1259);
                             recog.err_handler.sync(&mut recog.base)?;
                             _la = recog.base.input.la(1);
                             if _la == SP {
                                 {
-                                    recog.base.set_state(1245);
+                                    recog.base.set_state(1258);
                                     recog.base.match_token(SP, &mut recog.err_handler)?;
                                 }
                             }
 
                             /*InvokeRule oC_Where*/
-                            recog.base.set_state(1248);
+                            recog.base.set_state(1261);
                             recog.oC_Where()?;
                         }
                     }
@@ -14216,20 +14369,20 @@ where
             recog.base.enter_outer_alt(None, 1);
             {
                 /*InvokeRule oC_Variable*/
-                recog.base.set_state(1251);
+                recog.base.set_state(1264);
                 recog.oC_Variable()?;
 
-                recog.base.set_state(1252);
+                recog.base.set_state(1265);
                 recog.base.match_token(SP, &mut recog.err_handler)?;
 
-                recog.base.set_state(1253);
+                recog.base.set_state(1266);
                 recog.base.match_token(IN, &mut recog.err_handler)?;
 
-                recog.base.set_state(1254);
+                recog.base.set_state(1267);
                 recog.base.match_token(SP, &mut recog.err_handler)?;
 
                 /*InvokeRule oC_Expression*/
-                recog.base.set_state(1255);
+                recog.base.set_state(1268);
                 recog.oC_Expression()?;
             }
         };
@@ -14374,53 +14527,53 @@ where
             recog.base.enter_outer_alt(None, 1);
             {
                 /*InvokeRule oC_FunctionName*/
-                recog.base.set_state(1257);
+                recog.base.set_state(1270);
                 recog.oC_FunctionName()?;
 
-                recog.base.set_state(1259);
+                recog.base.set_state(1272);
                 recog.err_handler.sync(&mut recog.base)?;
                 _la = recog.base.input.la(1);
                 if _la == SP {
                     {
-                        recog.base.set_state(1258);
+                        recog.base.set_state(1271);
                         recog.base.match_token(SP, &mut recog.err_handler)?;
                     }
                 }
 
-                recog.base.set_state(1261);
+                recog.base.set_state(1274);
                 recog.base.match_token(T__5, &mut recog.err_handler)?;
 
-                recog.base.set_state(1263);
+                recog.base.set_state(1276);
                 recog.err_handler.sync(&mut recog.base)?;
                 _la = recog.base.input.la(1);
                 if _la == SP {
                     {
-                        recog.base.set_state(1262);
+                        recog.base.set_state(1275);
                         recog.base.match_token(SP, &mut recog.err_handler)?;
                     }
                 }
 
-                recog.base.set_state(1269);
+                recog.base.set_state(1282);
                 recog.err_handler.sync(&mut recog.base)?;
                 _la = recog.base.input.la(1);
                 if _la == DISTINCT {
                     {
-                        recog.base.set_state(1265);
+                        recog.base.set_state(1278);
                         recog.base.match_token(DISTINCT, &mut recog.err_handler)?;
 
-                        recog.base.set_state(1267);
+                        recog.base.set_state(1280);
                         recog.err_handler.sync(&mut recog.base)?;
                         _la = recog.base.input.la(1);
                         if _la == SP {
                             {
-                                recog.base.set_state(1266);
+                                recog.base.set_state(1279);
                                 recog.base.match_token(SP, &mut recog.err_handler)?;
                             }
                         }
                     }
                 }
 
-                recog.base.set_state(1288);
+                recog.base.set_state(1301);
                 recog.err_handler.sync(&mut recog.base)?;
                 _la = recog.base.input.la(1);
                 if (((_la) & !0x3f) == 0
@@ -14429,92 +14582,92 @@ where
                             | (1usize << T__7)
                             | (1usize << T__12)
                             | (1usize << T__13)
-                            | (1usize << T__23)
-                            | (1usize << T__25)
+                            | (1usize << T__24)
+                            | (1usize << T__26)
                             | (1usize << ALL)))
                         != 0)
-                    || (((_la - 76) & !0x3f) == 0
-                        && ((1usize << (_la - 76))
-                            & ((1usize << (NOT - 76))
-                                | (1usize << (NULL - 76))
-                                | (1usize << (COUNT - 76))
-                                | (1usize << (ANY - 76))
-                                | (1usize << (NONE - 76))
-                                | (1usize << (SINGLE - 76))
-                                | (1usize << (TRUE - 76))
-                                | (1usize << (FALSE - 76))
-                                | (1usize << (EXISTS - 76))
-                                | (1usize << (CASE - 76))
-                                | (1usize << (StringLiteral - 76))
-                                | (1usize << (HexInteger - 76))
-                                | (1usize << (DecimalInteger - 76))
-                                | (1usize << (OctalInteger - 76))
-                                | (1usize << (HexLetter - 76))
-                                | (1usize << (ExponentDecimalReal - 76))
-                                | (1usize << (RegularDecimalReal - 76))
-                                | (1usize << (FILTER - 76))
-                                | (1usize << (EXTRACT - 76))
-                                | (1usize << (UnescapedSymbolicName - 76))
-                                | (1usize << (EscapedSymbolicName - 76))))
+                    || (((_la - 77) & !0x3f) == 0
+                        && ((1usize << (_la - 77))
+                            & ((1usize << (NOT - 77))
+                                | (1usize << (NULL - 77))
+                                | (1usize << (COUNT - 77))
+                                | (1usize << (ANY - 77))
+                                | (1usize << (NONE - 77))
+                                | (1usize << (SINGLE - 77))
+                                | (1usize << (TRUE - 77))
+                                | (1usize << (FALSE - 77))
+                                | (1usize << (EXISTS - 77))
+                                | (1usize << (CASE - 77))
+                                | (1usize << (StringLiteral - 77))
+                                | (1usize << (HexInteger - 77))
+                                | (1usize << (DecimalInteger - 77))
+                                | (1usize << (OctalInteger - 77))
+                                | (1usize << (HexLetter - 77))
+                                | (1usize << (ExponentDecimalReal - 77))
+                                | (1usize << (RegularDecimalReal - 77))
+                                | (1usize << (FILTER - 77))
+                                | (1usize << (EXTRACT - 77))
+                                | (1usize << (UnescapedSymbolicName - 77))
+                                | (1usize << (EscapedSymbolicName - 77))))
                             != 0)
                 {
                     {
                         /*InvokeRule oC_Expression*/
-                        recog.base.set_state(1271);
+                        recog.base.set_state(1284);
                         recog.oC_Expression()?;
 
-                        recog.base.set_state(1273);
+                        recog.base.set_state(1286);
                         recog.err_handler.sync(&mut recog.base)?;
                         _la = recog.base.input.la(1);
                         if _la == SP {
                             {
-                                recog.base.set_state(1272);
+                                recog.base.set_state(1285);
                                 recog.base.match_token(SP, &mut recog.err_handler)?;
                             }
                         }
 
-                        recog.base.set_state(1285);
+                        recog.base.set_state(1298);
                         recog.err_handler.sync(&mut recog.base)?;
                         _la = recog.base.input.la(1);
                         while _la == T__1 {
                             {
                                 {
-                                    recog.base.set_state(1275);
+                                    recog.base.set_state(1288);
                                     recog.base.match_token(T__1, &mut recog.err_handler)?;
 
-                                    recog.base.set_state(1277);
+                                    recog.base.set_state(1290);
                                     recog.err_handler.sync(&mut recog.base)?;
                                     _la = recog.base.input.la(1);
                                     if _la == SP {
                                         {
-                                            recog.base.set_state(1276);
+                                            recog.base.set_state(1289);
                                             recog.base.match_token(SP, &mut recog.err_handler)?;
                                         }
                                     }
 
                                     /*InvokeRule oC_Expression*/
-                                    recog.base.set_state(1279);
+                                    recog.base.set_state(1292);
                                     recog.oC_Expression()?;
 
-                                    recog.base.set_state(1281);
+                                    recog.base.set_state(1294);
                                     recog.err_handler.sync(&mut recog.base)?;
                                     _la = recog.base.input.la(1);
                                     if _la == SP {
                                         {
-                                            recog.base.set_state(1280);
+                                            recog.base.set_state(1293);
                                             recog.base.match_token(SP, &mut recog.err_handler)?;
                                         }
                                     }
                                 }
                             }
-                            recog.base.set_state(1287);
+                            recog.base.set_state(1300);
                             recog.err_handler.sync(&mut recog.base)?;
                             _la = recog.base.input.la(1);
                         }
                     }
                 }
 
-                recog.base.set_state(1290);
+                recog.base.set_state(1303);
                 recog.base.match_token(T__6, &mut recog.err_handler)?;
             }
         };
@@ -14627,7 +14780,7 @@ where
             .enter_rule(_localctx.clone(), 148, RULE_oC_FunctionName);
         let mut _localctx: Rc<OC_FunctionNameContextAll> = _localctx;
         let result: Result<(), ANTLRError> = try {
-            recog.base.set_state(1296);
+            recog.base.set_state(1309);
             recog.err_handler.sync(&mut recog.base)?;
             match recog.base.input.la(1) {
                 COUNT
@@ -14644,11 +14797,11 @@ where
                     {
                         {
                             /*InvokeRule oC_Namespace*/
-                            recog.base.set_state(1292);
+                            recog.base.set_state(1305);
                             recog.oC_Namespace()?;
 
                             /*InvokeRule oC_SymbolicName*/
-                            recog.base.set_state(1293);
+                            recog.base.set_state(1306);
                             recog.oC_SymbolicName()?;
                         }
                     }
@@ -14658,7 +14811,7 @@ where
                     //recog.base.enter_outer_alt(_localctx.clone(), 2);
                     recog.base.enter_outer_alt(None, 2);
                     {
-                        recog.base.set_state(1295);
+                        recog.base.set_state(1308);
                         recog.base.match_token(EXISTS, &mut recog.err_handler)?;
                     }
                 }
@@ -14807,33 +14960,33 @@ where
             recog.base.enter_outer_alt(None, 1);
             {
                 /*InvokeRule oC_ProcedureName*/
-                recog.base.set_state(1298);
+                recog.base.set_state(1311);
                 recog.oC_ProcedureName()?;
 
-                recog.base.set_state(1300);
+                recog.base.set_state(1313);
                 recog.err_handler.sync(&mut recog.base)?;
                 _la = recog.base.input.la(1);
                 if _la == SP {
                     {
-                        recog.base.set_state(1299);
+                        recog.base.set_state(1312);
                         recog.base.match_token(SP, &mut recog.err_handler)?;
                     }
                 }
 
-                recog.base.set_state(1302);
+                recog.base.set_state(1315);
                 recog.base.match_token(T__5, &mut recog.err_handler)?;
 
-                recog.base.set_state(1304);
+                recog.base.set_state(1317);
                 recog.err_handler.sync(&mut recog.base)?;
                 _la = recog.base.input.la(1);
                 if _la == SP {
                     {
-                        recog.base.set_state(1303);
+                        recog.base.set_state(1316);
                         recog.base.match_token(SP, &mut recog.err_handler)?;
                     }
                 }
 
-                recog.base.set_state(1323);
+                recog.base.set_state(1336);
                 recog.err_handler.sync(&mut recog.base)?;
                 _la = recog.base.input.la(1);
                 if (((_la) & !0x3f) == 0
@@ -14842,92 +14995,92 @@ where
                             | (1usize << T__7)
                             | (1usize << T__12)
                             | (1usize << T__13)
-                            | (1usize << T__23)
-                            | (1usize << T__25)
+                            | (1usize << T__24)
+                            | (1usize << T__26)
                             | (1usize << ALL)))
                         != 0)
-                    || (((_la - 76) & !0x3f) == 0
-                        && ((1usize << (_la - 76))
-                            & ((1usize << (NOT - 76))
-                                | (1usize << (NULL - 76))
-                                | (1usize << (COUNT - 76))
-                                | (1usize << (ANY - 76))
-                                | (1usize << (NONE - 76))
-                                | (1usize << (SINGLE - 76))
-                                | (1usize << (TRUE - 76))
-                                | (1usize << (FALSE - 76))
-                                | (1usize << (EXISTS - 76))
-                                | (1usize << (CASE - 76))
-                                | (1usize << (StringLiteral - 76))
-                                | (1usize << (HexInteger - 76))
-                                | (1usize << (DecimalInteger - 76))
-                                | (1usize << (OctalInteger - 76))
-                                | (1usize << (HexLetter - 76))
-                                | (1usize << (ExponentDecimalReal - 76))
-                                | (1usize << (RegularDecimalReal - 76))
-                                | (1usize << (FILTER - 76))
-                                | (1usize << (EXTRACT - 76))
-                                | (1usize << (UnescapedSymbolicName - 76))
-                                | (1usize << (EscapedSymbolicName - 76))))
+                    || (((_la - 77) & !0x3f) == 0
+                        && ((1usize << (_la - 77))
+                            & ((1usize << (NOT - 77))
+                                | (1usize << (NULL - 77))
+                                | (1usize << (COUNT - 77))
+                                | (1usize << (ANY - 77))
+                                | (1usize << (NONE - 77))
+                                | (1usize << (SINGLE - 77))
+                                | (1usize << (TRUE - 77))
+                                | (1usize << (FALSE - 77))
+                                | (1usize << (EXISTS - 77))
+                                | (1usize << (CASE - 77))
+                                | (1usize << (StringLiteral - 77))
+                                | (1usize << (HexInteger - 77))
+                                | (1usize << (DecimalInteger - 77))
+                                | (1usize << (OctalInteger - 77))
+                                | (1usize << (HexLetter - 77))
+                                | (1usize << (ExponentDecimalReal - 77))
+                                | (1usize << (RegularDecimalReal - 77))
+                                | (1usize << (FILTER - 77))
+                                | (1usize << (EXTRACT - 77))
+                                | (1usize << (UnescapedSymbolicName - 77))
+                                | (1usize << (EscapedSymbolicName - 77))))
                             != 0)
                 {
                     {
                         /*InvokeRule oC_Expression*/
-                        recog.base.set_state(1306);
+                        recog.base.set_state(1319);
                         recog.oC_Expression()?;
 
-                        recog.base.set_state(1308);
+                        recog.base.set_state(1321);
                         recog.err_handler.sync(&mut recog.base)?;
                         _la = recog.base.input.la(1);
                         if _la == SP {
                             {
-                                recog.base.set_state(1307);
+                                recog.base.set_state(1320);
                                 recog.base.match_token(SP, &mut recog.err_handler)?;
                             }
                         }
 
-                        recog.base.set_state(1320);
+                        recog.base.set_state(1333);
                         recog.err_handler.sync(&mut recog.base)?;
                         _la = recog.base.input.la(1);
                         while _la == T__1 {
                             {
                                 {
-                                    recog.base.set_state(1310);
+                                    recog.base.set_state(1323);
                                     recog.base.match_token(T__1, &mut recog.err_handler)?;
 
-                                    recog.base.set_state(1312);
+                                    recog.base.set_state(1325);
                                     recog.err_handler.sync(&mut recog.base)?;
                                     _la = recog.base.input.la(1);
                                     if _la == SP {
                                         {
-                                            recog.base.set_state(1311);
+                                            recog.base.set_state(1324);
                                             recog.base.match_token(SP, &mut recog.err_handler)?;
                                         }
                                     }
 
                                     /*InvokeRule oC_Expression*/
-                                    recog.base.set_state(1314);
+                                    recog.base.set_state(1327);
                                     recog.oC_Expression()?;
 
-                                    recog.base.set_state(1316);
+                                    recog.base.set_state(1329);
                                     recog.err_handler.sync(&mut recog.base)?;
                                     _la = recog.base.input.la(1);
                                     if _la == SP {
                                         {
-                                            recog.base.set_state(1315);
+                                            recog.base.set_state(1328);
                                             recog.base.match_token(SP, &mut recog.err_handler)?;
                                         }
                                     }
                                 }
                             }
-                            recog.base.set_state(1322);
+                            recog.base.set_state(1335);
                             recog.err_handler.sync(&mut recog.base)?;
                             _la = recog.base.input.la(1);
                         }
                     }
                 }
 
-                recog.base.set_state(1325);
+                recog.base.set_state(1338);
                 recog.base.match_token(T__6, &mut recog.err_handler)?;
             }
         };
@@ -15042,7 +15195,7 @@ where
             recog.base.enter_outer_alt(None, 1);
             {
                 /*InvokeRule oC_ProcedureName*/
-                recog.base.set_state(1327);
+                recog.base.set_state(1340);
                 recog.oC_ProcedureName()?;
             }
         };
@@ -15154,7 +15307,7 @@ where
             recog.base.enter_outer_alt(None, 1);
             {
                 /*InvokeRule oC_SymbolicName*/
-                recog.base.set_state(1329);
+                recog.base.set_state(1342);
                 recog.oC_SymbolicName()?;
             }
         };
@@ -15265,11 +15418,11 @@ where
             recog.base.enter_outer_alt(None, 1);
             {
                 /*InvokeRule oC_Namespace*/
-                recog.base.set_state(1331);
+                recog.base.set_state(1344);
                 recog.oC_Namespace()?;
 
                 /*InvokeRule oC_SymbolicName*/
-                recog.base.set_state(1332);
+                recog.base.set_state(1345);
                 recog.oC_SymbolicName()?;
             }
         };
@@ -15377,25 +15530,25 @@ where
             //recog.base.enter_outer_alt(_localctx.clone(), 1);
             recog.base.enter_outer_alt(None, 1);
             {
-                recog.base.set_state(1339);
+                recog.base.set_state(1352);
                 recog.err_handler.sync(&mut recog.base)?;
-                _alt = recog.interpreter.adaptive_predict(238, &mut recog.base)?;
+                _alt = recog.interpreter.adaptive_predict(241, &mut recog.base)?;
                 while _alt != 2 && _alt != INVALID_ALT {
                     if _alt == 1 {
                         {
                             {
                                 /*InvokeRule oC_SymbolicName*/
-                                recog.base.set_state(1334);
+                                recog.base.set_state(1347);
                                 recog.oC_SymbolicName()?;
 
-                                recog.base.set_state(1335);
-                                recog.base.match_token(T__22, &mut recog.err_handler)?;
+                                recog.base.set_state(1348);
+                                recog.base.match_token(T__23, &mut recog.err_handler)?;
                             }
                         }
                     }
-                    recog.base.set_state(1341);
+                    recog.base.set_state(1354);
                     recog.err_handler.sync(&mut recog.base)?;
-                    _alt = recog.interpreter.adaptive_predict(238, &mut recog.base)?;
+                    _alt = recog.interpreter.adaptive_predict(241, &mut recog.base)?;
                 }
             }
         };
@@ -15523,70 +15676,70 @@ where
             //recog.base.enter_outer_alt(_localctx.clone(), 1);
             recog.base.enter_outer_alt(None, 1);
             {
-                recog.base.set_state(1342);
+                recog.base.set_state(1355);
                 recog.base.match_token(T__7, &mut recog.err_handler)?;
 
-                recog.base.set_state(1344);
+                recog.base.set_state(1357);
                 recog.err_handler.sync(&mut recog.base)?;
                 _la = recog.base.input.la(1);
                 if _la == SP {
                     {
-                        recog.base.set_state(1343);
+                        recog.base.set_state(1356);
                         recog.base.match_token(SP, &mut recog.err_handler)?;
                     }
                 }
 
                 /*InvokeRule oC_FilterExpression*/
-                recog.base.set_state(1346);
+                recog.base.set_state(1359);
                 recog.oC_FilterExpression()?;
 
-                recog.base.set_state(1355);
+                recog.base.set_state(1368);
                 recog.err_handler.sync(&mut recog.base)?;
-                match recog.interpreter.adaptive_predict(242, &mut recog.base)? {
+                match recog.interpreter.adaptive_predict(245, &mut recog.base)? {
                     x if x == 1 => {
                         {
-                            recog.base.set_state(1348);
+                            recog.base.set_state(1361);
                             recog.err_handler.sync(&mut recog.base)?;
                             _la = recog.base.input.la(1);
                             if _la == SP {
                                 {
-                                    recog.base.set_state(1347);
+                                    recog.base.set_state(1360);
                                     recog.base.match_token(SP, &mut recog.err_handler)?;
                                 }
                             }
 
-                            recog.base.set_state(1350);
+                            recog.base.set_state(1363);
                             recog.base.match_token(T__10, &mut recog.err_handler)?;
 
-                            recog.base.set_state(1352);
+                            recog.base.set_state(1365);
                             recog.err_handler.sync(&mut recog.base)?;
                             _la = recog.base.input.la(1);
                             if _la == SP {
                                 {
-                                    recog.base.set_state(1351);
+                                    recog.base.set_state(1364);
                                     recog.base.match_token(SP, &mut recog.err_handler)?;
                                 }
                             }
 
                             /*InvokeRule oC_Expression*/
-                            recog.base.set_state(1354);
+                            recog.base.set_state(1367);
                             recog.oC_Expression()?;
                         }
                     }
 
                     _ => {}
                 }
-                recog.base.set_state(1358);
+                recog.base.set_state(1371);
                 recog.err_handler.sync(&mut recog.base)?;
                 _la = recog.base.input.la(1);
                 if _la == SP {
                     {
-                        recog.base.set_state(1357);
+                        recog.base.set_state(1370);
                         recog.base.match_token(SP, &mut recog.err_handler)?;
                     }
                 }
 
-                recog.base.set_state(1360);
+                recog.base.set_state(1373);
                 recog.base.match_token(T__8, &mut recog.err_handler)?;
             }
         };
@@ -15739,59 +15892,59 @@ where
             //recog.base.enter_outer_alt(_localctx.clone(), 1);
             recog.base.enter_outer_alt(None, 1);
             {
-                recog.base.set_state(1362);
+                recog.base.set_state(1375);
                 recog.base.match_token(T__7, &mut recog.err_handler)?;
 
-                recog.base.set_state(1364);
+                recog.base.set_state(1377);
                 recog.err_handler.sync(&mut recog.base)?;
                 _la = recog.base.input.la(1);
                 if _la == SP {
                     {
-                        recog.base.set_state(1363);
+                        recog.base.set_state(1376);
                         recog.base.match_token(SP, &mut recog.err_handler)?;
                     }
                 }
 
-                recog.base.set_state(1374);
+                recog.base.set_state(1387);
                 recog.err_handler.sync(&mut recog.base)?;
                 _la = recog.base.input.la(1);
-                if ((_la - 83) & !0x3f) == 0
-                    && ((1usize << (_la - 83))
-                        & ((1usize << (COUNT - 83))
-                            | (1usize << (ANY - 83))
-                            | (1usize << (NONE - 83))
-                            | (1usize << (SINGLE - 83))
-                            | (1usize << (HexLetter - 83))
-                            | (1usize << (FILTER - 83))
-                            | (1usize << (EXTRACT - 83))
-                            | (1usize << (UnescapedSymbolicName - 83))
-                            | (1usize << (EscapedSymbolicName - 83))))
+                if ((_la - 84) & !0x3f) == 0
+                    && ((1usize << (_la - 84))
+                        & ((1usize << (COUNT - 84))
+                            | (1usize << (ANY - 84))
+                            | (1usize << (NONE - 84))
+                            | (1usize << (SINGLE - 84))
+                            | (1usize << (HexLetter - 84))
+                            | (1usize << (FILTER - 84))
+                            | (1usize << (EXTRACT - 84))
+                            | (1usize << (UnescapedSymbolicName - 84))
+                            | (1usize << (EscapedSymbolicName - 84))))
                         != 0
                 {
                     {
                         /*InvokeRule oC_Variable*/
-                        recog.base.set_state(1366);
+                        recog.base.set_state(1379);
                         recog.oC_Variable()?;
 
-                        recog.base.set_state(1368);
+                        recog.base.set_state(1381);
                         recog.err_handler.sync(&mut recog.base)?;
                         _la = recog.base.input.la(1);
                         if _la == SP {
                             {
-                                recog.base.set_state(1367);
+                                recog.base.set_state(1380);
                                 recog.base.match_token(SP, &mut recog.err_handler)?;
                             }
                         }
 
-                        recog.base.set_state(1370);
+                        recog.base.set_state(1383);
                         recog.base.match_token(T__2, &mut recog.err_handler)?;
 
-                        recog.base.set_state(1372);
+                        recog.base.set_state(1385);
                         recog.err_handler.sync(&mut recog.base)?;
                         _la = recog.base.input.la(1);
                         if _la == SP {
                             {
-                                recog.base.set_state(1371);
+                                recog.base.set_state(1384);
                                 recog.base.match_token(SP, &mut recog.err_handler)?;
                             }
                         }
@@ -15799,81 +15952,81 @@ where
                 }
 
                 /*InvokeRule oC_RelationshipsPattern*/
-                recog.base.set_state(1376);
+                recog.base.set_state(1389);
                 recog.oC_RelationshipsPattern()?;
 
-                recog.base.set_state(1378);
+                recog.base.set_state(1391);
                 recog.err_handler.sync(&mut recog.base)?;
                 _la = recog.base.input.la(1);
                 if _la == SP {
                     {
-                        recog.base.set_state(1377);
+                        recog.base.set_state(1390);
                         recog.base.match_token(SP, &mut recog.err_handler)?;
                     }
                 }
 
-                recog.base.set_state(1388);
+                recog.base.set_state(1401);
                 recog.err_handler.sync(&mut recog.base)?;
                 _la = recog.base.input.la(1);
                 if _la == WHERE {
                     {
-                        recog.base.set_state(1380);
+                        recog.base.set_state(1393);
                         recog.base.match_token(WHERE, &mut recog.err_handler)?;
 
-                        recog.base.set_state(1382);
+                        recog.base.set_state(1395);
                         recog.err_handler.sync(&mut recog.base)?;
                         _la = recog.base.input.la(1);
                         if _la == SP {
                             {
-                                recog.base.set_state(1381);
+                                recog.base.set_state(1394);
                                 recog.base.match_token(SP, &mut recog.err_handler)?;
                             }
                         }
 
                         /*InvokeRule oC_Expression*/
-                        recog.base.set_state(1384);
+                        recog.base.set_state(1397);
                         recog.oC_Expression()?;
 
-                        recog.base.set_state(1386);
+                        recog.base.set_state(1399);
                         recog.err_handler.sync(&mut recog.base)?;
                         _la = recog.base.input.la(1);
                         if _la == SP {
                             {
-                                recog.base.set_state(1385);
+                                recog.base.set_state(1398);
                                 recog.base.match_token(SP, &mut recog.err_handler)?;
                             }
                         }
                     }
                 }
 
-                recog.base.set_state(1390);
+                recog.base.set_state(1403);
                 recog.base.match_token(T__10, &mut recog.err_handler)?;
 
-                recog.base.set_state(1392);
+                recog.base.set_state(1405);
                 recog.err_handler.sync(&mut recog.base)?;
                 _la = recog.base.input.la(1);
                 if _la == SP {
                     {
-                        recog.base.set_state(1391);
+                        recog.base.set_state(1404);
                         recog.base.match_token(SP, &mut recog.err_handler)?;
                     }
                 }
 
                 /*InvokeRule oC_Expression*/
-                recog.base.set_state(1394);
+                recog.base.set_state(1407);
                 recog.oC_Expression()?;
 
-                recog.base.set_state(1396);
+                recog.base.set_state(1409);
                 recog.err_handler.sync(&mut recog.base)?;
                 _la = recog.base.input.la(1);
                 if _la == SP {
                     {
-                        recog.base.set_state(1395);
+                        recog.base.set_state(1408);
                         recog.base.match_token(SP, &mut recog.err_handler)?;
                     }
                 }
 
-                recog.base.set_state(1398);
+                recog.base.set_state(1411);
                 recog.base.match_token(T__8, &mut recog.err_handler)?;
             }
         };
@@ -15986,22 +16139,22 @@ where
             //recog.base.enter_outer_alt(_localctx.clone(), 1);
             recog.base.enter_outer_alt(None, 1);
             {
-                recog.base.set_state(1400);
-                recog.base.match_token(T__22, &mut recog.err_handler)?;
+                recog.base.set_state(1413);
+                recog.base.match_token(T__23, &mut recog.err_handler)?;
 
-                recog.base.set_state(1402);
+                recog.base.set_state(1415);
                 recog.err_handler.sync(&mut recog.base)?;
                 _la = recog.base.input.la(1);
                 if _la == SP {
                     {
-                        recog.base.set_state(1401);
+                        recog.base.set_state(1414);
                         recog.base.match_token(SP, &mut recog.err_handler)?;
                     }
                 }
 
                 {
                     /*InvokeRule oC_PropertyKeyName*/
-                    recog.base.set_state(1404);
+                    recog.base.set_state(1417);
                     recog.oC_PropertyKeyName()?;
                 }
             }
@@ -16165,28 +16318,28 @@ where
             //recog.base.enter_outer_alt(_localctx.clone(), 1);
             recog.base.enter_outer_alt(None, 1);
             {
-                recog.base.set_state(1428);
+                recog.base.set_state(1441);
                 recog.err_handler.sync(&mut recog.base)?;
-                match recog.interpreter.adaptive_predict(260, &mut recog.base)? {
+                match recog.interpreter.adaptive_predict(263, &mut recog.base)? {
                     1 => {
                         {
                             {
-                                recog.base.set_state(1406);
+                                recog.base.set_state(1419);
                                 recog.base.match_token(CASE, &mut recog.err_handler)?;
 
-                                recog.base.set_state(1411);
+                                recog.base.set_state(1424);
                                 recog.err_handler.sync(&mut recog.base)?;
                                 _alt = 1;
                                 loop {
                                     match _alt {
                                         x if x == 1 => {
                                             {
-                                                recog.base.set_state(1408);
+                                                recog.base.set_state(1421);
                                                 recog.err_handler.sync(&mut recog.base)?;
                                                 _la = recog.base.input.la(1);
                                                 if _la == SP {
                                                     {
-                                                        recog.base.set_state(1407);
+                                                        recog.base.set_state(1420);
                                                         recog.base.match_token(
                                                             SP,
                                                             &mut recog.err_handler,
@@ -16195,7 +16348,7 @@ where
                                                 }
 
                                                 /*InvokeRule oC_CaseAlternatives*/
-                                                recog.base.set_state(1410);
+                                                recog.base.set_state(1423);
                                                 recog.oC_CaseAlternatives()?;
                                             }
                                         }
@@ -16204,10 +16357,10 @@ where
                                             &mut recog.base,
                                         )))?,
                                     }
-                                    recog.base.set_state(1413);
+                                    recog.base.set_state(1426);
                                     recog.err_handler.sync(&mut recog.base)?;
                                     _alt =
-                                        recog.interpreter.adaptive_predict(256, &mut recog.base)?;
+                                        recog.interpreter.adaptive_predict(259, &mut recog.base)?;
                                     if _alt == 2 || _alt == INVALID_ALT {
                                         break;
                                     }
@@ -16218,36 +16371,36 @@ where
                     2 => {
                         {
                             {
-                                recog.base.set_state(1415);
+                                recog.base.set_state(1428);
                                 recog.base.match_token(CASE, &mut recog.err_handler)?;
 
-                                recog.base.set_state(1417);
+                                recog.base.set_state(1430);
                                 recog.err_handler.sync(&mut recog.base)?;
                                 _la = recog.base.input.la(1);
                                 if _la == SP {
                                     {
-                                        recog.base.set_state(1416);
+                                        recog.base.set_state(1429);
                                         recog.base.match_token(SP, &mut recog.err_handler)?;
                                     }
                                 }
 
                                 /*InvokeRule oC_Expression*/
-                                recog.base.set_state(1419);
+                                recog.base.set_state(1432);
                                 recog.oC_Expression()?;
 
-                                recog.base.set_state(1424);
+                                recog.base.set_state(1437);
                                 recog.err_handler.sync(&mut recog.base)?;
                                 _alt = 1;
                                 loop {
                                     match _alt {
                                         x if x == 1 => {
                                             {
-                                                recog.base.set_state(1421);
+                                                recog.base.set_state(1434);
                                                 recog.err_handler.sync(&mut recog.base)?;
                                                 _la = recog.base.input.la(1);
                                                 if _la == SP {
                                                     {
-                                                        recog.base.set_state(1420);
+                                                        recog.base.set_state(1433);
                                                         recog.base.match_token(
                                                             SP,
                                                             &mut recog.err_handler,
@@ -16256,7 +16409,7 @@ where
                                                 }
 
                                                 /*InvokeRule oC_CaseAlternatives*/
-                                                recog.base.set_state(1423);
+                                                recog.base.set_state(1436);
                                                 recog.oC_CaseAlternatives()?;
                                             }
                                         }
@@ -16265,10 +16418,10 @@ where
                                             &mut recog.base,
                                         )))?,
                                     }
-                                    recog.base.set_state(1426);
+                                    recog.base.set_state(1439);
                                     recog.err_handler.sync(&mut recog.base)?;
                                     _alt =
-                                        recog.interpreter.adaptive_predict(259, &mut recog.base)?;
+                                        recog.interpreter.adaptive_predict(262, &mut recog.base)?;
                                     if _alt == 2 || _alt == INVALID_ALT {
                                         break;
                                     }
@@ -16279,53 +16432,53 @@ where
 
                     _ => {}
                 }
-                recog.base.set_state(1438);
+                recog.base.set_state(1451);
                 recog.err_handler.sync(&mut recog.base)?;
-                match recog.interpreter.adaptive_predict(263, &mut recog.base)? {
+                match recog.interpreter.adaptive_predict(266, &mut recog.base)? {
                     x if x == 1 => {
                         {
-                            recog.base.set_state(1431);
+                            recog.base.set_state(1444);
                             recog.err_handler.sync(&mut recog.base)?;
                             _la = recog.base.input.la(1);
                             if _la == SP {
                                 {
-                                    recog.base.set_state(1430);
+                                    recog.base.set_state(1443);
                                     recog.base.match_token(SP, &mut recog.err_handler)?;
                                 }
                             }
 
-                            recog.base.set_state(1433);
+                            recog.base.set_state(1446);
                             recog.base.match_token(ELSE, &mut recog.err_handler)?;
 
-                            recog.base.set_state(1435);
+                            recog.base.set_state(1448);
                             recog.err_handler.sync(&mut recog.base)?;
                             _la = recog.base.input.la(1);
                             if _la == SP {
                                 {
-                                    recog.base.set_state(1434);
+                                    recog.base.set_state(1447);
                                     recog.base.match_token(SP, &mut recog.err_handler)?;
                                 }
                             }
 
                             /*InvokeRule oC_Expression*/
-                            recog.base.set_state(1437);
+                            recog.base.set_state(1450);
                             recog.oC_Expression()?;
                         }
                     }
 
                     _ => {}
                 }
-                recog.base.set_state(1441);
+                recog.base.set_state(1454);
                 recog.err_handler.sync(&mut recog.base)?;
                 _la = recog.base.input.la(1);
                 if _la == SP {
                     {
-                        recog.base.set_state(1440);
+                        recog.base.set_state(1453);
                         recog.base.match_token(SP, &mut recog.err_handler)?;
                     }
                 }
 
-                recog.base.set_state(1443);
+                recog.base.set_state(1456);
                 recog.base.match_token(END, &mut recog.err_handler)?;
             }
         };
@@ -16469,48 +16622,48 @@ where
             //recog.base.enter_outer_alt(_localctx.clone(), 1);
             recog.base.enter_outer_alt(None, 1);
             {
-                recog.base.set_state(1445);
+                recog.base.set_state(1458);
                 recog.base.match_token(WHEN, &mut recog.err_handler)?;
 
-                recog.base.set_state(1447);
+                recog.base.set_state(1460);
                 recog.err_handler.sync(&mut recog.base)?;
                 _la = recog.base.input.la(1);
                 if _la == SP {
                     {
-                        recog.base.set_state(1446);
+                        recog.base.set_state(1459);
                         recog.base.match_token(SP, &mut recog.err_handler)?;
                     }
                 }
 
                 /*InvokeRule oC_Expression*/
-                recog.base.set_state(1449);
+                recog.base.set_state(1462);
                 recog.oC_Expression()?;
 
-                recog.base.set_state(1451);
+                recog.base.set_state(1464);
                 recog.err_handler.sync(&mut recog.base)?;
                 _la = recog.base.input.la(1);
                 if _la == SP {
                     {
-                        recog.base.set_state(1450);
+                        recog.base.set_state(1463);
                         recog.base.match_token(SP, &mut recog.err_handler)?;
                     }
                 }
 
-                recog.base.set_state(1453);
+                recog.base.set_state(1466);
                 recog.base.match_token(THEN, &mut recog.err_handler)?;
 
-                recog.base.set_state(1455);
+                recog.base.set_state(1468);
                 recog.err_handler.sync(&mut recog.base)?;
                 _la = recog.base.input.la(1);
                 if _la == SP {
                     {
-                        recog.base.set_state(1454);
+                        recog.base.set_state(1467);
                         recog.base.match_token(SP, &mut recog.err_handler)?;
                     }
                 }
 
                 /*InvokeRule oC_Expression*/
-                recog.base.set_state(1457);
+                recog.base.set_state(1470);
                 recog.oC_Expression()?;
             }
         };
@@ -16611,7 +16764,7 @@ where
             recog.base.enter_outer_alt(None, 1);
             {
                 /*InvokeRule oC_SymbolicName*/
-                recog.base.set_state(1459);
+                recog.base.set_state(1472);
                 recog.oC_SymbolicName()?;
             }
         };
@@ -16718,7 +16871,7 @@ where
             .enter_rule(_localctx.clone(), 172, RULE_oC_NumberLiteral);
         let mut _localctx: Rc<OC_NumberLiteralContextAll> = _localctx;
         let result: Result<(), ANTLRError> = try {
-            recog.base.set_state(1463);
+            recog.base.set_state(1476);
             recog.err_handler.sync(&mut recog.base)?;
             match recog.base.input.la(1) {
                 ExponentDecimalReal | RegularDecimalReal => {
@@ -16726,7 +16879,7 @@ where
                     recog.base.enter_outer_alt(None, 1);
                     {
                         /*InvokeRule oC_DoubleLiteral*/
-                        recog.base.set_state(1461);
+                        recog.base.set_state(1474);
                         recog.oC_DoubleLiteral()?;
                     }
                 }
@@ -16736,7 +16889,7 @@ where
                     recog.base.enter_outer_alt(None, 2);
                     {
                         /*InvokeRule oC_IntegerLiteral*/
-                        recog.base.set_state(1462);
+                        recog.base.set_state(1475);
                         recog.oC_IntegerLiteral()?;
                     }
                 }
@@ -16878,20 +17031,20 @@ where
             //recog.base.enter_outer_alt(_localctx.clone(), 1);
             recog.base.enter_outer_alt(None, 1);
             {
-                recog.base.set_state(1465);
-                recog.base.match_token(T__23, &mut recog.err_handler)?;
+                recog.base.set_state(1478);
+                recog.base.match_token(T__24, &mut recog.err_handler)?;
 
-                recog.base.set_state(1467);
+                recog.base.set_state(1480);
                 recog.err_handler.sync(&mut recog.base)?;
                 _la = recog.base.input.la(1);
                 if _la == SP {
                     {
-                        recog.base.set_state(1466);
+                        recog.base.set_state(1479);
                         recog.base.match_token(SP, &mut recog.err_handler)?;
                     }
                 }
 
-                recog.base.set_state(1502);
+                recog.base.set_state(1515);
                 recog.err_handler.sync(&mut recog.base)?;
                 _la = recog.base.input.la(1);
                 if (((_la) & !0x3f) == 0
@@ -16910,12 +17063,12 @@ where
                             | (1usize << DELETE)
                             | (1usize << REMOVE)
                             | (1usize << WITH)
-                            | (1usize << RETURN)
-                            | (1usize << DISTINCT)))
+                            | (1usize << RETURN)))
                         != 0)
                     || (((_la - 64) & !0x3f) == 0
                         && ((1usize << (_la - 64))
-                            & ((1usize << (ORDER - 64))
+                            & ((1usize << (DISTINCT - 64))
+                                | (1usize << (ORDER - 64))
                                 | (1usize << (BY - 64))
                                 | (1usize << (L_SKIP - 64))
                                 | (1usize << (LIMIT - 64))
@@ -16965,116 +17118,116 @@ where
                 {
                     {
                         /*InvokeRule oC_PropertyKeyName*/
-                        recog.base.set_state(1469);
+                        recog.base.set_state(1482);
                         recog.oC_PropertyKeyName()?;
 
-                        recog.base.set_state(1471);
+                        recog.base.set_state(1484);
                         recog.err_handler.sync(&mut recog.base)?;
                         _la = recog.base.input.la(1);
                         if _la == SP {
                             {
-                                recog.base.set_state(1470);
+                                recog.base.set_state(1483);
                                 recog.base.match_token(SP, &mut recog.err_handler)?;
                             }
                         }
 
-                        recog.base.set_state(1473);
+                        recog.base.set_state(1486);
                         recog.base.match_token(T__9, &mut recog.err_handler)?;
 
-                        recog.base.set_state(1475);
+                        recog.base.set_state(1488);
                         recog.err_handler.sync(&mut recog.base)?;
                         _la = recog.base.input.la(1);
                         if _la == SP {
                             {
-                                recog.base.set_state(1474);
+                                recog.base.set_state(1487);
                                 recog.base.match_token(SP, &mut recog.err_handler)?;
                             }
                         }
 
                         /*InvokeRule oC_Expression*/
-                        recog.base.set_state(1477);
+                        recog.base.set_state(1490);
                         recog.oC_Expression()?;
 
-                        recog.base.set_state(1479);
+                        recog.base.set_state(1492);
                         recog.err_handler.sync(&mut recog.base)?;
                         _la = recog.base.input.la(1);
                         if _la == SP {
                             {
-                                recog.base.set_state(1478);
+                                recog.base.set_state(1491);
                                 recog.base.match_token(SP, &mut recog.err_handler)?;
                             }
                         }
 
-                        recog.base.set_state(1499);
+                        recog.base.set_state(1512);
                         recog.err_handler.sync(&mut recog.base)?;
                         _la = recog.base.input.la(1);
                         while _la == T__1 {
                             {
                                 {
-                                    recog.base.set_state(1481);
+                                    recog.base.set_state(1494);
                                     recog.base.match_token(T__1, &mut recog.err_handler)?;
 
-                                    recog.base.set_state(1483);
+                                    recog.base.set_state(1496);
                                     recog.err_handler.sync(&mut recog.base)?;
                                     _la = recog.base.input.la(1);
                                     if _la == SP {
                                         {
-                                            recog.base.set_state(1482);
+                                            recog.base.set_state(1495);
                                             recog.base.match_token(SP, &mut recog.err_handler)?;
                                         }
                                     }
 
                                     /*InvokeRule oC_PropertyKeyName*/
-                                    recog.base.set_state(1485);
+                                    recog.base.set_state(1498);
                                     recog.oC_PropertyKeyName()?;
 
-                                    recog.base.set_state(1487);
+                                    recog.base.set_state(1500);
                                     recog.err_handler.sync(&mut recog.base)?;
                                     _la = recog.base.input.la(1);
                                     if _la == SP {
                                         {
-                                            recog.base.set_state(1486);
+                                            recog.base.set_state(1499);
                                             recog.base.match_token(SP, &mut recog.err_handler)?;
                                         }
                                     }
 
-                                    recog.base.set_state(1489);
+                                    recog.base.set_state(1502);
                                     recog.base.match_token(T__9, &mut recog.err_handler)?;
 
-                                    recog.base.set_state(1491);
+                                    recog.base.set_state(1504);
                                     recog.err_handler.sync(&mut recog.base)?;
                                     _la = recog.base.input.la(1);
                                     if _la == SP {
                                         {
-                                            recog.base.set_state(1490);
+                                            recog.base.set_state(1503);
                                             recog.base.match_token(SP, &mut recog.err_handler)?;
                                         }
                                     }
 
                                     /*InvokeRule oC_Expression*/
-                                    recog.base.set_state(1493);
+                                    recog.base.set_state(1506);
                                     recog.oC_Expression()?;
 
-                                    recog.base.set_state(1495);
+                                    recog.base.set_state(1508);
                                     recog.err_handler.sync(&mut recog.base)?;
                                     _la = recog.base.input.la(1);
                                     if _la == SP {
                                         {
-                                            recog.base.set_state(1494);
+                                            recog.base.set_state(1507);
                                             recog.base.match_token(SP, &mut recog.err_handler)?;
                                         }
                                     }
                                 }
                             }
-                            recog.base.set_state(1501);
+                            recog.base.set_state(1514);
                             recog.err_handler.sync(&mut recog.base)?;
                             _la = recog.base.input.la(1);
                         }
                     }
                 }
 
-                recog.base.set_state(1504);
-                recog.base.match_token(T__24, &mut recog.err_handler)?;
+                recog.base.set_state(1517);
+                recog.base.match_token(T__25, &mut recog.err_handler)?;
             }
         };
         match result {
@@ -17182,10 +17335,10 @@ where
             //recog.base.enter_outer_alt(_localctx.clone(), 1);
             recog.base.enter_outer_alt(None, 1);
             {
-                recog.base.set_state(1506);
-                recog.base.match_token(T__25, &mut recog.err_handler)?;
+                recog.base.set_state(1519);
+                recog.base.match_token(T__26, &mut recog.err_handler)?;
 
-                recog.base.set_state(1509);
+                recog.base.set_state(1522);
                 recog.err_handler.sync(&mut recog.base)?;
                 match recog.base.input.la(1) {
                     COUNT
@@ -17199,13 +17352,13 @@ where
                     | EscapedSymbolicName => {
                         {
                             /*InvokeRule oC_SymbolicName*/
-                            recog.base.set_state(1507);
+                            recog.base.set_state(1520);
                             recog.oC_SymbolicName()?;
                         }
                     }
 
                     DecimalInteger => {
-                        recog.base.set_state(1508);
+                        recog.base.set_state(1521);
                         recog
                             .base
                             .match_token(DecimalInteger, &mut recog.err_handler)?;
@@ -17351,28 +17504,28 @@ where
             recog.base.enter_outer_alt(None, 1);
             {
                 /*InvokeRule oC_Atom*/
-                recog.base.set_state(1511);
+                recog.base.set_state(1524);
                 recog.oC_Atom()?;
 
-                recog.base.set_state(1516);
+                recog.base.set_state(1529);
                 recog.err_handler.sync(&mut recog.base)?;
                 _alt = 1;
                 loop {
                     match _alt {
                         x if x == 1 => {
                             {
-                                recog.base.set_state(1513);
+                                recog.base.set_state(1526);
                                 recog.err_handler.sync(&mut recog.base)?;
                                 _la = recog.base.input.la(1);
                                 if _la == SP {
                                     {
-                                        recog.base.set_state(1512);
+                                        recog.base.set_state(1525);
                                         recog.base.match_token(SP, &mut recog.err_handler)?;
                                     }
                                 }
 
                                 /*InvokeRule oC_PropertyLookup*/
-                                recog.base.set_state(1515);
+                                recog.base.set_state(1528);
                                 recog.oC_PropertyLookup()?;
                             }
                         }
@@ -17381,9 +17534,9 @@ where
                             &mut recog.base,
                         )))?,
                     }
-                    recog.base.set_state(1518);
+                    recog.base.set_state(1531);
                     recog.err_handler.sync(&mut recog.base)?;
-                    _alt = recog.interpreter.adaptive_predict(281, &mut recog.base)?;
+                    _alt = recog.interpreter.adaptive_predict(284, &mut recog.base)?;
                     if _alt == 2 || _alt == INVALID_ALT {
                         break;
                     }
@@ -17491,7 +17644,7 @@ where
             recog.base.enter_outer_alt(None, 1);
             {
                 /*InvokeRule oC_SchemaName*/
-                recog.base.set_state(1520);
+                recog.base.set_state(1533);
                 recog.oC_SchemaName()?;
             }
         };
@@ -17614,14 +17767,14 @@ where
             //recog.base.enter_outer_alt(_localctx.clone(), 1);
             recog.base.enter_outer_alt(None, 1);
             {
-                recog.base.set_state(1522);
+                recog.base.set_state(1535);
                 _la = recog.base.input.la(1);
                 if {
-                    !(((_la - 97) & !0x3f) == 0
-                        && ((1usize << (_la - 97))
-                            & ((1usize << (HexInteger - 97))
-                                | (1usize << (DecimalInteger - 97))
-                                | (1usize << (OctalInteger - 97))))
+                    !(((_la - 98) & !0x3f) == 0
+                        && ((1usize << (_la - 98))
+                            & ((1usize << (HexInteger - 98))
+                                | (1usize << (DecimalInteger - 98))
+                                | (1usize << (OctalInteger - 98))))
                             != 0)
                 } {
                     recog.err_handler.recover_inline(&mut recog.base)?;
@@ -17745,7 +17898,7 @@ where
             //recog.base.enter_outer_alt(_localctx.clone(), 1);
             recog.base.enter_outer_alt(None, 1);
             {
-                recog.base.set_state(1524);
+                recog.base.set_state(1537);
                 _la = recog.base.input.la(1);
                 if !(_la == ExponentDecimalReal || _la == RegularDecimalReal) {
                     recog.err_handler.recover_inline(&mut recog.base)?;
@@ -17859,7 +18012,7 @@ where
             .enter_rule(_localctx.clone(), 186, RULE_oC_SchemaName);
         let mut _localctx: Rc<OC_SchemaNameContextAll> = _localctx;
         let result: Result<(), ANTLRError> = try {
-            recog.base.set_state(1528);
+            recog.base.set_state(1541);
             recog.err_handler.sync(&mut recog.base)?;
             match recog.base.input.la(1) {
                 COUNT
@@ -17875,7 +18028,7 @@ where
                     recog.base.enter_outer_alt(None, 1);
                     {
                         /*InvokeRule oC_SymbolicName*/
-                        recog.base.set_state(1526);
+                        recog.base.set_state(1539);
                         recog.oC_SymbolicName()?;
                     }
                 }
@@ -17890,7 +18043,7 @@ where
                     recog.base.enter_outer_alt(None, 2);
                     {
                         /*InvokeRule oC_ReservedWord*/
-                        recog.base.set_state(1527);
+                        recog.base.set_state(1540);
                         recog.oC_ReservedWord()?;
                     }
                 }
@@ -18417,7 +18570,7 @@ where
             //recog.base.enter_outer_alt(_localctx.clone(), 1);
             recog.base.enter_outer_alt(None, 1);
             {
-                recog.base.set_state(1530);
+                recog.base.set_state(1543);
                 _la = recog.base.input.la(1);
                 if {
                     !((((_la) & !0x3f) == 0
@@ -18436,12 +18589,12 @@ where
                                 | (1usize << DELETE)
                                 | (1usize << REMOVE)
                                 | (1usize << WITH)
-                                | (1usize << RETURN)
-                                | (1usize << DISTINCT)))
+                                | (1usize << RETURN)))
                             != 0)
                         || (((_la - 64) & !0x3f) == 0
                             && ((1usize << (_la - 64))
-                                & ((1usize << (ORDER - 64))
+                                & ((1usize << (DISTINCT - 64))
+                                    | (1usize << (ORDER - 64))
                                     | (1usize << (BY - 64))
                                     | (1usize << (L_SKIP - 64))
                                     | (1usize << (LIMIT - 64))
@@ -18655,20 +18808,20 @@ where
             //recog.base.enter_outer_alt(_localctx.clone(), 1);
             recog.base.enter_outer_alt(None, 1);
             {
-                recog.base.set_state(1532);
+                recog.base.set_state(1545);
                 _la = recog.base.input.la(1);
                 if {
-                    !(((_la - 83) & !0x3f) == 0
-                        && ((1usize << (_la - 83))
-                            & ((1usize << (COUNT - 83))
-                                | (1usize << (ANY - 83))
-                                | (1usize << (NONE - 83))
-                                | (1usize << (SINGLE - 83))
-                                | (1usize << (HexLetter - 83))
-                                | (1usize << (FILTER - 83))
-                                | (1usize << (EXTRACT - 83))
-                                | (1usize << (UnescapedSymbolicName - 83))
-                                | (1usize << (EscapedSymbolicName - 83))))
+                    !(((_la - 84) & !0x3f) == 0
+                        && ((1usize << (_la - 84))
+                            & ((1usize << (COUNT - 84))
+                                | (1usize << (ANY - 84))
+                                | (1usize << (NONE - 84))
+                                | (1usize << (SINGLE - 84))
+                                | (1usize << (HexLetter - 84))
+                                | (1usize << (FILTER - 84))
+                                | (1usize << (EXTRACT - 84))
+                                | (1usize << (UnescapedSymbolicName - 84))
+                                | (1usize << (EscapedSymbolicName - 84))))
                             != 0)
                 } {
                     recog.err_handler.recover_inline(&mut recog.base)?;
@@ -18776,16 +18929,16 @@ where
             //recog.base.enter_outer_alt(_localctx.clone(), 1);
             recog.base.enter_outer_alt(None, 1);
             {
-                recog.base.set_state(1534);
+                recog.base.set_state(1547);
                 _la = recog.base.input.la(1);
                 if {
                     !(((_la) & !0x3f) == 0
                         && ((1usize << _la)
-                            & ((1usize << T__18)
-                                | (1usize << T__26)
+                            & ((1usize << T__19)
                                 | (1usize << T__27)
                                 | (1usize << T__28)
-                                | (1usize << T__29)))
+                                | (1usize << T__29)
+                                | (1usize << T__30)))
                             != 0)
                 } {
                     recog.err_handler.recover_inline(&mut recog.base)?;
@@ -18893,16 +19046,16 @@ where
             //recog.base.enter_outer_alt(_localctx.clone(), 1);
             recog.base.enter_outer_alt(None, 1);
             {
-                recog.base.set_state(1536);
+                recog.base.set_state(1549);
                 _la = recog.base.input.la(1);
                 if {
                     !(((_la) & !0x3f) == 0
                         && ((1usize << _la)
-                            & ((1usize << T__19)
-                                | (1usize << T__30)
+                            & ((1usize << T__20)
                                 | (1usize << T__31)
                                 | (1usize << T__32)
-                                | (1usize << T__33)))
+                                | (1usize << T__33)
+                                | (1usize << T__34)))
                             != 0)
                 } {
                     recog.err_handler.recover_inline(&mut recog.base)?;
@@ -19004,13 +19157,12 @@ where
             //recog.base.enter_outer_alt(_localctx.clone(), 1);
             recog.base.enter_outer_alt(None, 1);
             {
-                recog.base.set_state(1538);
+                recog.base.set_state(1551);
                 _la = recog.base.input.la(1);
                 if {
                     !(((_la) & !0x3f) == 0
                         && ((1usize << _la)
                             & ((1usize << T__13)
-                                | (1usize << T__34)
                                 | (1usize << T__35)
                                 | (1usize << T__36)
                                 | (1usize << T__37)
@@ -19020,7 +19172,8 @@ where
                                 | (1usize << T__41)
                                 | (1usize << T__42)
                                 | (1usize << T__43)
-                                | (1usize << T__44)))
+                                | (1usize << T__44)
+                                | (1usize << T__45)))
                             != 0)
                 } {
                     recog.err_handler.recover_inline(&mut recog.base)?;
@@ -19063,7 +19216,7 @@ lazy_static! {
 
 const _serializedATN: &'static str =
     "\x03\u{608b}\u{a72a}\u{8133}\u{b9ed}\u{417c}\u{3be7}\u{7786}\u{5964}\x03\
-	\u{81}\u{607}\x04\x02\x09\x02\x04\x03\x09\x03\x04\x04\x09\x04\x04\x05\x09\
+	\u{82}\u{614}\x04\x02\x09\x02\x04\x03\x09\x03\x04\x04\x09\x04\x04\x05\x09\
 	\x05\x04\x06\x09\x06\x04\x07\x09\x07\x04\x08\x09\x08\x04\x09\x09\x09\x04\
 	\x0a\x09\x0a\x04\x0b\x09\x0b\x04\x0c\x09\x0c\x04\x0d\x09\x0d\x04\x0e\x09\
 	\x0e\x04\x0f\x09\x0f\x04\x10\x09\x10\x04\x11\x09\x11\x04\x12\x09\x12\x04\
@@ -19173,828 +19326,838 @@ const _serializedATN: &'static str =
 	\x2f\x03\x2f\x03\x30\x03\x30\x05\x30\u{34e}\x0a\x30\x03\x30\x03\x30\x05\
 	\x30\u{352}\x0a\x30\x05\x30\u{354}\x0a\x30\x03\x30\x03\x30\x05\x30\u{358}\
 	\x0a\x30\x03\x30\x03\x30\x05\x30\u{35c}\x0a\x30\x05\x30\u{35e}\x0a\x30\x05\
-	\x30\u{360}\x0a\x30\x03\x31\x03\x31\x03\x32\x03\x32\x03\x33\x03\x33\x03\
-	\x34\x03\x34\x03\x34\x03\x34\x03\x34\x07\x34\u{36d}\x0a\x34\x0c\x34\x0e\
-	\x34\u{370}\x0b\x34\x03\x35\x03\x35\x03\x35\x03\x35\x03\x35\x07\x35\u{377}\
-	\x0a\x35\x0c\x35\x0e\x35\u{37a}\x0b\x35\x03\x36\x03\x36\x03\x36\x03\x36\
-	\x03\x36\x07\x36\u{381}\x0a\x36\x0c\x36\x0e\x36\u{384}\x0b\x36\x03\x37\x03\
-	\x37\x05\x37\u{388}\x0a\x37\x07\x37\u{38a}\x0a\x37\x0c\x37\x0e\x37\u{38d}\
-	\x0b\x37\x03\x37\x03\x37\x03\x38\x03\x38\x05\x38\u{393}\x0a\x38\x03\x38\
-	\x07\x38\u{396}\x0a\x38\x0c\x38\x0e\x38\u{399}\x0b\x38\x03\x39\x03\x39\x05\
-	\x39\u{39d}\x0a\x39\x03\x39\x03\x39\x05\x39\u{3a1}\x0a\x39\x03\x39\x03\x39\
-	\x05\x39\u{3a5}\x0a\x39\x03\x39\x03\x39\x05\x39\u{3a9}\x0a\x39\x03\x39\x07\
-	\x39\u{3ac}\x0a\x39\x0c\x39\x0e\x39\u{3af}\x0b\x39\x03\x3a\x03\x3a\x05\x3a\
-	\u{3b3}\x0a\x3a\x03\x3a\x03\x3a\x05\x3a\u{3b7}\x0a\x3a\x03\x3a\x03\x3a\x05\
-	\x3a\u{3bb}\x0a\x3a\x03\x3a\x03\x3a\x05\x3a\u{3bf}\x0a\x3a\x03\x3a\x03\x3a\
-	\x05\x3a\u{3c3}\x0a\x3a\x03\x3a\x03\x3a\x05\x3a\u{3c7}\x0a\x3a\x03\x3a\x07\
-	\x3a\u{3ca}\x0a\x3a\x0c\x3a\x0e\x3a\u{3cd}\x0b\x3a\x03\x3b\x03\x3b\x05\x3b\
-	\u{3d1}\x0a\x3b\x03\x3b\x03\x3b\x05\x3b\u{3d5}\x0a\x3b\x03\x3b\x07\x3b\u{3d8}\
-	\x0a\x3b\x0c\x3b\x0e\x3b\u{3db}\x0b\x3b\x03\x3c\x03\x3c\x05\x3c\u{3df}\x0a\
-	\x3c\x07\x3c\u{3e1}\x0a\x3c\x0c\x3c\x0e\x3c\u{3e4}\x0b\x3c\x03\x3c\x03\x3c\
-	\x03\x3d\x03\x3d\x03\x3d\x03\x3d\x07\x3d\u{3ec}\x0a\x3d\x0c\x3d\x0e\x3d\
-	\u{3ef}\x0b\x3d\x03\x3e\x03\x3e\x03\x3e\x05\x3e\u{3f4}\x0a\x3e\x03\x3e\x03\
-	\x3e\x05\x3e\u{3f8}\x0a\x3e\x03\x3e\x03\x3e\x03\x3e\x03\x3e\x03\x3e\x05\
-	\x3e\u{3ff}\x0a\x3e\x03\x3e\x03\x3e\x05\x3e\u{403}\x0a\x3e\x03\x3e\x03\x3e\
-	\x05\x3e\u{407}\x0a\x3e\x03\x3e\x05\x3e\u{40a}\x0a\x3e\x03\x3f\x03\x3f\x03\
-	\x3f\x03\x3f\x03\x3f\x03\x3f\x03\x3f\x03\x3f\x03\x3f\x03\x3f\x05\x3f\u{416}\
-	\x0a\x3f\x03\x3f\x05\x3f\u{419}\x0a\x3f\x03\x3f\x03\x3f\x03\x40\x03\x40\
-	\x03\x40\x03\x40\x03\x40\x03\x40\x03\x40\x03\x40\x03\x40\x03\x40\x05\x40\
-	\u{427}\x0a\x40\x03\x41\x03\x41\x05\x41\u{42b}\x0a\x41\x03\x41\x07\x41\u{42e}\
-	\x0a\x41\x0c\x41\x0e\x41\u{431}\x0b\x41\x03\x41\x05\x41\u{434}\x0a\x41\x03\
-	\x41\x05\x41\u{437}\x0a\x41\x03\x42\x03\x42\x03\x42\x03\x42\x03\x42\x05\
-	\x42\u{43e}\x0a\x42\x03\x42\x03\x42\x05\x42\u{442}\x0a\x42\x03\x42\x03\x42\
-	\x05\x42\u{446}\x0a\x42\x03\x42\x03\x42\x03\x42\x03\x42\x03\x42\x05\x42\
-	\u{44d}\x0a\x42\x03\x42\x03\x42\x05\x42\u{451}\x0a\x42\x03\x42\x03\x42\x05\
-	\x42\u{455}\x0a\x42\x03\x42\x03\x42\x03\x42\x03\x42\x05\x42\u{45b}\x0a\x42\
-	\x03\x42\x03\x42\x05\x42\u{45f}\x0a\x42\x03\x42\x03\x42\x05\x42\u{463}\x0a\
-	\x42\x03\x42\x03\x42\x03\x42\x03\x42\x05\x42\u{469}\x0a\x42\x03\x42\x03\
-	\x42\x05\x42\u{46d}\x0a\x42\x03\x42\x03\x42\x05\x42\u{471}\x0a\x42\x03\x42\
-	\x03\x42\x03\x42\x03\x42\x05\x42\u{477}\x0a\x42\x03\x42\x03\x42\x05\x42\
-	\u{47b}\x0a\x42\x03\x42\x03\x42\x05\x42\u{47f}\x0a\x42\x03\x42\x03\x42\x03\
-	\x42\x03\x42\x03\x42\x03\x42\x05\x42\u{487}\x0a\x42\x03\x43\x03\x43\x03\
-	\x43\x03\x43\x03\x43\x03\x43\x05\x43\u{48f}\x0a\x43\x03\x44\x03\x44\x03\
-	\x45\x03\x45\x05\x45\u{495}\x0a\x45\x03\x45\x03\x45\x05\x45\u{499}\x0a\x45\
-	\x03\x45\x03\x45\x05\x45\u{49d}\x0a\x45\x03\x45\x03\x45\x05\x45\u{4a1}\x0a\
-	\x45\x07\x45\u{4a3}\x0a\x45\x0c\x45\x0e\x45\u{4a6}\x0b\x45\x05\x45\u{4a8}\
-	\x0a\x45\x03\x45\x03\x45\x03\x46\x03\x46\x05\x46\u{4ae}\x0a\x46\x03\x46\
-	\x03\x46\x03\x46\x05\x46\u{4b3}\x0a\x46\x03\x46\x03\x46\x03\x46\x05\x46\
-	\u{4b8}\x0a\x46\x03\x46\x03\x46\x03\x46\x05\x46\u{4bd}\x0a\x46\x03\x46\x03\
-	\x46\x03\x46\x05\x46\u{4c2}\x0a\x46\x03\x46\x03\x46\x03\x46\x05\x46\u{4c7}\
-	\x0a\x46\x03\x46\x05\x46\u{4ca}\x0a\x46\x03\x47\x03\x47\x05\x47\u{4ce}\x0a\
-	\x47\x03\x47\x03\x47\x05\x47\u{4d2}\x0a\x47\x03\x47\x03\x47\x03\x48\x03\
-	\x48\x05\x48\u{4d8}\x0a\x48\x03\x48\x06\x48\u{4db}\x0a\x48\x0d\x48\x0e\x48\
-	\u{4dc}\x03\x49\x03\x49\x05\x49\u{4e1}\x0a\x49\x03\x49\x05\x49\u{4e4}\x0a\
-	\x49\x03\x4a\x03\x4a\x03\x4a\x03\x4a\x03\x4a\x03\x4a\x03\x4b\x03\x4b\x05\
-	\x4b\u{4ee}\x0a\x4b\x03\x4b\x03\x4b\x05\x4b\u{4f2}\x0a\x4b\x03\x4b\x03\x4b\
-	\x05\x4b\u{4f6}\x0a\x4b\x05\x4b\u{4f8}\x0a\x4b\x03\x4b\x03\x4b\x05\x4b\u{4fc}\
-	\x0a\x4b\x03\x4b\x03\x4b\x05\x4b\u{500}\x0a\x4b\x03\x4b\x03\x4b\x05\x4b\
-	\u{504}\x0a\x4b\x07\x4b\u{506}\x0a\x4b\x0c\x4b\x0e\x4b\u{509}\x0b\x4b\x05\
-	\x4b\u{50b}\x0a\x4b\x03\x4b\x03\x4b\x03\x4c\x03\x4c\x03\x4c\x03\x4c\x05\
-	\x4c\u{513}\x0a\x4c\x03\x4d\x03\x4d\x05\x4d\u{517}\x0a\x4d\x03\x4d\x03\x4d\
-	\x05\x4d\u{51b}\x0a\x4d\x03\x4d\x03\x4d\x05\x4d\u{51f}\x0a\x4d\x03\x4d\x03\
-	\x4d\x05\x4d\u{523}\x0a\x4d\x03\x4d\x03\x4d\x05\x4d\u{527}\x0a\x4d\x07\x4d\
-	\u{529}\x0a\x4d\x0c\x4d\x0e\x4d\u{52c}\x0b\x4d\x05\x4d\u{52e}\x0a\x4d\x03\
-	\x4d\x03\x4d\x03\x4e\x03\x4e\x03\x4f\x03\x4f\x03\x50\x03\x50\x03\x50\x03\
-	\x51\x03\x51\x03\x51\x07\x51\u{53c}\x0a\x51\x0c\x51\x0e\x51\u{53f}\x0b\x51\
-	\x03\x52\x03\x52\x05\x52\u{543}\x0a\x52\x03\x52\x03\x52\x05\x52\u{547}\x0a\
-	\x52\x03\x52\x03\x52\x05\x52\u{54b}\x0a\x52\x03\x52\x05\x52\u{54e}\x0a\x52\
-	\x03\x52\x05\x52\u{551}\x0a\x52\x03\x52\x03\x52\x03\x53\x03\x53\x05\x53\
-	\u{557}\x0a\x53\x03\x53\x03\x53\x05\x53\u{55b}\x0a\x53\x03\x53\x03\x53\x05\
-	\x53\u{55f}\x0a\x53\x05\x53\u{561}\x0a\x53\x03\x53\x03\x53\x05\x53\u{565}\
-	\x0a\x53\x03\x53\x03\x53\x05\x53\u{569}\x0a\x53\x03\x53\x03\x53\x05\x53\
-	\u{56d}\x0a\x53\x05\x53\u{56f}\x0a\x53\x03\x53\x03\x53\x05\x53\u{573}\x0a\
-	\x53\x03\x53\x03\x53\x05\x53\u{577}\x0a\x53\x03\x53\x03\x53\x03\x54\x03\
-	\x54\x05\x54\u{57d}\x0a\x54\x03\x54\x03\x54\x03\x55\x03\x55\x05\x55\u{583}\
-	\x0a\x55\x03\x55\x06\x55\u{586}\x0a\x55\x0d\x55\x0e\x55\u{587}\x03\x55\x03\
-	\x55\x05\x55\u{58c}\x0a\x55\x03\x55\x03\x55\x05\x55\u{590}\x0a\x55\x03\x55\
-	\x06\x55\u{593}\x0a\x55\x0d\x55\x0e\x55\u{594}\x05\x55\u{597}\x0a\x55\x03\
-	\x55\x05\x55\u{59a}\x0a\x55\x03\x55\x03\x55\x05\x55\u{59e}\x0a\x55\x03\x55\
-	\x05\x55\u{5a1}\x0a\x55\x03\x55\x05\x55\u{5a4}\x0a\x55\x03\x55\x03\x55\x03\
-	\x56\x03\x56\x05\x56\u{5aa}\x0a\x56\x03\x56\x03\x56\x05\x56\u{5ae}\x0a\x56\
-	\x03\x56\x03\x56\x05\x56\u{5b2}\x0a\x56\x03\x56\x03\x56\x03\x57\x03\x57\
-	\x03\x58\x03\x58\x05\x58\u{5ba}\x0a\x58\x03\x59\x03\x59\x05\x59\u{5be}\x0a\
-	\x59\x03\x59\x03\x59\x05\x59\u{5c2}\x0a\x59\x03\x59\x03\x59\x05\x59\u{5c6}\
-	\x0a\x59\x03\x59\x03\x59\x05\x59\u{5ca}\x0a\x59\x03\x59\x03\x59\x05\x59\
-	\u{5ce}\x0a\x59\x03\x59\x03\x59\x05\x59\u{5d2}\x0a\x59\x03\x59\x03\x59\x05\
-	\x59\u{5d6}\x0a\x59\x03\x59\x03\x59\x05\x59\u{5da}\x0a\x59\x07\x59\u{5dc}\
-	\x0a\x59\x0c\x59\x0e\x59\u{5df}\x0b\x59\x05\x59\u{5e1}\x0a\x59\x03\x59\x03\
-	\x59\x03\x5a\x03\x5a\x03\x5a\x05\x5a\u{5e8}\x0a\x5a\x03\x5b\x03\x5b\x05\
-	\x5b\u{5ec}\x0a\x5b\x03\x5b\x06\x5b\u{5ef}\x0a\x5b\x0d\x5b\x0e\x5b\u{5f0}\
-	\x03\x5c\x03\x5c\x03\x5d\x03\x5d\x03\x5e\x03\x5e\x03\x5f\x03\x5f\x05\x5f\
-	\u{5fb}\x0a\x5f\x03\x60\x03\x60\x03\x61\x03\x61\x03\x62\x03\x62\x03\x63\
-	\x03\x63\x03\x64\x03\x64\x03\x64\x02\x02\x65\x02\x04\x06\x08\x0a\x0c\x0e\
-	\x10\x12\x14\x16\x18\x1a\x1c\x1e\x20\x22\x24\x26\x28\x2a\x2c\x2e\x30\x32\
-	\x34\x36\x38\x3a\x3c\x3e\x40\x42\x44\x46\x48\x4a\x4c\x4e\x50\x52\x54\x56\
-	\x58\x5a\x5c\x5e\x60\x62\x64\x66\x68\x6a\x6c\x6e\x70\x72\x74\x76\x78\x7a\
-	\x7c\x7e\u{80}\u{82}\u{84}\u{86}\u{88}\u{8a}\u{8c}\u{8e}\u{90}\u{92}\u{94}\
-	\u{96}\u{98}\u{9a}\u{9c}\u{9e}\u{a0}\u{a2}\u{a4}\u{a6}\u{a8}\u{aa}\u{ac}\
-	\u{ae}\u{b0}\u{b2}\u{b4}\u{b6}\u{b8}\u{ba}\u{bc}\u{be}\u{c0}\u{c2}\u{c4}\
-	\u{c6}\x02\x0c\x03\x02\x46\x49\x03\x02\x0f\x10\x03\x02\x59\x5a\x03\x02\x63\
-	\x65\x03\x02\x6d\x6e\x06\x02\x30\x3c\x3f\x54\x59\x60\x6f\x78\x06\x02\x55\
-	\x58\x66\x66\x79\x7b\x7e\x7e\x04\x02\x15\x15\x1d\x20\x04\x02\x16\x16\x21\
-	\x24\x04\x02\x10\x10\x25\x2f\x02\u{6de}\x02\u{c9}\x03\x02\x02\x02\x04\u{d7}\
-	\x03\x02\x02\x02\x06\u{db}\x03\x02\x02\x02\x08\u{dd}\x03\x02\x02\x02\x0a\
-	\u{f3}\x03\x02\x02\x02\x0c\u{f7}\x03\x02\x02\x02\x0e\u{11c}\x03\x02\x02\
-	\x02\x10\u{134}\x03\x02\x02\x02\x12\u{13f}\x03\x02\x02\x02\x14\u{144}\x03\
-	\x02\x02\x02\x16\u{148}\x03\x02\x02\x02\x18\u{155}\x03\x02\x02\x02\x1a\u{15f}\
-	\x03\x02\x02\x02\x1c\u{175}\x03\x02\x02\x02\x1e\u{177}\x03\x02\x02\x02\x20\
-	\u{17d}\x03\x02\x02\x02\x22\u{1ad}\x03\x02\x02\x02\x24\u{1b1}\x03\x02\x02\
-	\x02\x26\u{1c5}\x03\x02\x02\x02\x28\u{1d9}\x03\x02\x02\x02\x2a\u{1db}\x03\
-	\x02\x02\x02\x2c\u{1e6}\x03\x02\x02\x02\x2e\u{201}\x03\x02\x02\x02\x30\u{20e}\
-	\x03\x02\x02\x02\x32\u{212}\x03\x02\x02\x02\x34\u{21a}\x03\x02\x02\x02\x36\
-	\u{221}\x03\x02\x02\x02\x38\u{24d}\x03\x02\x02\x02\x3a\u{256}\x03\x02\x02\
-	\x02\x3c\u{258}\x03\x02\x02\x02\x3e\u{267}\x03\x02\x02\x02\x40\u{26b}\x03\
-	\x02\x02\x02\x42\u{26f}\x03\x02\x02\x02\x44\u{276}\x03\x02\x02\x02\x46\u{27a}\
-	\x03\x02\x02\x02\x48\u{293}\x03\x02\x02\x02\x4a\u{295}\x03\x02\x02\x02\x4c\
-	\u{2a5}\x03\x02\x02\x02\x4e\u{2a7}\x03\x02\x02\x02\x50\u{2bf}\x03\x02\x02\
-	\x02\x52\u{305}\x03\x02\x02\x02\x54\u{307}\x03\x02\x02\x02\x56\u{324}\x03\
-	\x02\x02\x02\x58\u{326}\x03\x02\x02\x02\x5a\u{33b}\x03\x02\x02\x02\x5c\u{345}\
-	\x03\x02\x02\x02\x5e\u{34b}\x03\x02\x02\x02\x60\u{361}\x03\x02\x02\x02\x62\
-	\u{363}\x03\x02\x02\x02\x64\u{365}\x03\x02\x02\x02\x66\u{367}\x03\x02\x02\
-	\x02\x68\u{371}\x03\x02\x02\x02\x6a\u{37b}\x03\x02\x02\x02\x6c\u{38b}\x03\
-	\x02\x02\x02\x6e\u{390}\x03\x02\x02\x02\x70\u{39a}\x03\x02\x02\x02\x72\u{3b0}\
-	\x03\x02\x02\x02\x74\u{3ce}\x03\x02\x02\x02\x76\u{3e2}\x03\x02\x02\x02\x78\
-	\u{3e7}\x03\x02\x02\x02\x7a\u{409}\x03\x02\x02\x02\x7c\u{415}\x03\x02\x02\
-	\x02\x7e\u{426}\x03\x02\x02\x02\u{80}\u{428}\x03\x02\x02\x02\u{82}\u{486}\
-	\x03\x02\x02\x02\u{84}\u{48e}\x03\x02\x02\x02\u{86}\u{490}\x03\x02\x02\x02\
-	\u{88}\u{492}\x03\x02\x02\x02\u{8a}\u{4c9}\x03\x02\x02\x02\u{8c}\u{4cb}\
-	\x03\x02\x02\x02\u{8e}\u{4d5}\x03\x02\x02\x02\u{90}\u{4de}\x03\x02\x02\x02\
-	\u{92}\u{4e5}\x03\x02\x02\x02\u{94}\u{4eb}\x03\x02\x02\x02\u{96}\u{512}\
-	\x03\x02\x02\x02\u{98}\u{514}\x03\x02\x02\x02\u{9a}\u{531}\x03\x02\x02\x02\
-	\u{9c}\u{533}\x03\x02\x02\x02\u{9e}\u{535}\x03\x02\x02\x02\u{a0}\u{53d}\
-	\x03\x02\x02\x02\u{a2}\u{540}\x03\x02\x02\x02\u{a4}\u{554}\x03\x02\x02\x02\
-	\u{a6}\u{57a}\x03\x02\x02\x02\u{a8}\u{596}\x03\x02\x02\x02\u{aa}\u{5a7}\
-	\x03\x02\x02\x02\u{ac}\u{5b5}\x03\x02\x02\x02\u{ae}\u{5b9}\x03\x02\x02\x02\
-	\u{b0}\u{5bb}\x03\x02\x02\x02\u{b2}\u{5e4}\x03\x02\x02\x02\u{b4}\u{5e9}\
-	\x03\x02\x02\x02\u{b6}\u{5f2}\x03\x02\x02\x02\u{b8}\u{5f4}\x03\x02\x02\x02\
-	\u{ba}\u{5f6}\x03\x02\x02\x02\u{bc}\u{5fa}\x03\x02\x02\x02\u{be}\u{5fc}\
-	\x03\x02\x02\x02\u{c0}\u{5fe}\x03\x02\x02\x02\u{c2}\u{600}\x03\x02\x02\x02\
-	\u{c4}\u{602}\x03\x02\x02\x02\u{c6}\u{604}\x03\x02\x02\x02\u{c8}\u{ca}\x07\
-	\x7f\x02\x02\u{c9}\u{c8}\x03\x02\x02\x02\u{c9}\u{ca}\x03\x02\x02\x02\u{ca}\
-	\u{cb}\x03\x02\x02\x02\u{cb}\u{d0}\x05\x04\x03\x02\u{cc}\u{ce}\x07\x7f\x02\
-	\x02\u{cd}\u{cc}\x03\x02\x02\x02\u{cd}\u{ce}\x03\x02\x02\x02\u{ce}\u{cf}\
-	\x03\x02\x02\x02\u{cf}\u{d1}\x07\x03\x02\x02\u{d0}\u{cd}\x03\x02\x02\x02\
-	\u{d0}\u{d1}\x03\x02\x02\x02\u{d1}\u{d3}\x03\x02\x02\x02\u{d2}\u{d4}\x07\
-	\x7f\x02\x02\u{d3}\u{d2}\x03\x02\x02\x02\u{d3}\u{d4}\x03\x02\x02\x02\u{d4}\
-	\u{d5}\x03\x02\x02\x02\u{d5}\u{d6}\x07\x02\x02\x03\u{d6}\x03\x03\x02\x02\
-	\x02\u{d7}\u{d8}\x05\x06\x04\x02\u{d8}\x05\x03\x02\x02\x02\u{d9}\u{dc}\x05\
-	\x08\x05\x02\u{da}\u{dc}\x05\x2c\x17\x02\u{db}\u{d9}\x03\x02\x02\x02\u{db}\
-	\u{da}\x03\x02\x02\x02\u{dc}\x07\x03\x02\x02\x02\u{dd}\u{e4}\x05\x0c\x07\
-	\x02\u{de}\u{e0}\x07\x7f\x02\x02\u{df}\u{de}\x03\x02\x02\x02\u{df}\u{e0}\
-	\x03\x02\x02\x02\u{e0}\u{e1}\x03\x02\x02\x02\u{e1}\u{e3}\x05\x0a\x06\x02\
-	\u{e2}\u{df}\x03\x02\x02\x02\u{e3}\u{e6}\x03\x02\x02\x02\u{e4}\u{e2}\x03\
-	\x02\x02\x02\u{e4}\u{e5}\x03\x02\x02\x02\u{e5}\x09\x03\x02\x02\x02\u{e6}\
-	\u{e4}\x03\x02\x02\x02\u{e7}\u{e8}\x07\x30\x02\x02\u{e8}\u{e9}\x07\x7f\x02\
-	\x02\u{e9}\u{eb}\x07\x31\x02\x02\u{ea}\u{ec}\x07\x7f\x02\x02\u{eb}\u{ea}\
-	\x03\x02\x02\x02\u{eb}\u{ec}\x03\x02\x02\x02\u{ec}\u{ed}\x03\x02\x02\x02\
-	\u{ed}\u{f4}\x05\x0c\x07\x02\u{ee}\u{f0}\x07\x30\x02\x02\u{ef}\u{f1}\x07\
-	\x7f\x02\x02\u{f0}\u{ef}\x03\x02\x02\x02\u{f0}\u{f1}\x03\x02\x02\x02\u{f1}\
-	\u{f2}\x03\x02\x02\x02\u{f2}\u{f4}\x05\x0c\x07\x02\u{f3}\u{e7}\x03\x02\x02\
-	\x02\u{f3}\u{ee}\x03\x02\x02\x02\u{f4}\x0b\x03\x02\x02\x02\u{f5}\u{f8}\x05\
-	\x0e\x08\x02\u{f6}\u{f8}\x05\x10\x09\x02\u{f7}\u{f5}\x03\x02\x02\x02\u{f7}\
-	\u{f6}\x03\x02\x02\x02\u{f8}\x0d\x03\x02\x02\x02\u{f9}\u{fb}\x05\x14\x0b\
-	\x02\u{fa}\u{fc}\x07\x7f\x02\x02\u{fb}\u{fa}\x03\x02\x02\x02\u{fb}\u{fc}\
-	\x03\x02\x02\x02\u{fc}\u{fe}\x03\x02\x02\x02\u{fd}\u{f9}\x03\x02\x02\x02\
-	\u{fe}\u{101}\x03\x02\x02\x02\u{ff}\u{fd}\x03\x02\x02\x02\u{ff}\u{100}\x03\
-	\x02\x02\x02\u{100}\u{102}\x03\x02\x02\x02\u{101}\u{ff}\x03\x02\x02\x02\
-	\u{102}\u{11d}\x05\x34\x1b\x02\u{103}\u{105}\x05\x14\x0b\x02\u{104}\u{106}\
-	\x07\x7f\x02\x02\u{105}\u{104}\x03\x02\x02\x02\u{105}\u{106}\x03\x02\x02\
-	\x02\u{106}\u{108}\x03\x02\x02\x02\u{107}\u{103}\x03\x02\x02\x02\u{108}\
-	\u{10b}\x03\x02\x02\x02\u{109}\u{107}\x03\x02\x02\x02\u{109}\u{10a}\x03\
-	\x02\x02\x02\u{10a}\u{10c}\x03\x02\x02\x02\u{10b}\u{109}\x03\x02\x02\x02\
-	\u{10c}\u{113}\x05\x12\x0a\x02\u{10d}\u{10f}\x07\x7f\x02\x02\u{10e}\u{10d}\
-	\x03\x02\x02\x02\u{10e}\u{10f}\x03\x02\x02\x02\u{10f}\u{110}\x03\x02\x02\
-	\x02\u{110}\u{112}\x05\x12\x0a\x02\u{111}\u{10e}\x03\x02\x02\x02\u{112}\
-	\u{115}\x03\x02\x02\x02\u{113}\u{111}\x03\x02\x02\x02\u{113}\u{114}\x03\
-	\x02\x02\x02\u{114}\u{11a}\x03\x02\x02\x02\u{115}\u{113}\x03\x02\x02\x02\
-	\u{116}\u{118}\x07\x7f\x02\x02\u{117}\u{116}\x03\x02\x02\x02\u{117}\u{118}\
-	\x03\x02\x02\x02\u{118}\u{119}\x03\x02\x02\x02\u{119}\u{11b}\x05\x34\x1b\
-	\x02\u{11a}\u{117}\x03\x02\x02\x02\u{11a}\u{11b}\x03\x02\x02\x02\u{11b}\
-	\u{11d}\x03\x02\x02\x02\u{11c}\u{ff}\x03\x02\x02\x02\u{11c}\u{109}\x03\x02\
-	\x02\x02\u{11d}\x0f\x03\x02\x02\x02\u{11e}\u{120}\x05\x14\x0b\x02\u{11f}\
-	\u{121}\x07\x7f\x02\x02\u{120}\u{11f}\x03\x02\x02\x02\u{120}\u{121}\x03\
-	\x02\x02\x02\u{121}\u{123}\x03\x02\x02\x02\u{122}\u{11e}\x03\x02\x02\x02\
-	\u{123}\u{126}\x03\x02\x02\x02\u{124}\u{122}\x03\x02\x02\x02\u{124}\u{125}\
-	\x03\x02\x02\x02\u{125}\u{12d}\x03\x02\x02\x02\u{126}\u{124}\x03\x02\x02\
-	\x02\u{127}\u{129}\x05\x12\x0a\x02\u{128}\u{12a}\x07\x7f\x02\x02\u{129}\
-	\u{128}\x03\x02\x02\x02\u{129}\u{12a}\x03\x02\x02\x02\u{12a}\u{12c}\x03\
-	\x02\x02\x02\u{12b}\u{127}\x03\x02\x02\x02\u{12c}\u{12f}\x03\x02\x02\x02\
-	\u{12d}\u{12b}\x03\x02\x02\x02\u{12d}\u{12e}\x03\x02\x02\x02\u{12e}\u{130}\
-	\x03\x02\x02\x02\u{12f}\u{12d}\x03\x02\x02\x02\u{130}\u{132}\x05\x32\x1a\
-	\x02\u{131}\u{133}\x07\x7f\x02\x02\u{132}\u{131}\x03\x02\x02\x02\u{132}\
-	\u{133}\x03\x02\x02\x02\u{133}\u{135}\x03\x02\x02\x02\u{134}\u{124}\x03\
-	\x02\x02\x02\u{135}\u{136}\x03\x02\x02\x02\u{136}\u{134}\x03\x02\x02\x02\
-	\u{136}\u{137}\x03\x02\x02\x02\u{137}\u{138}\x03\x02\x02\x02\u{138}\u{139}\
-	\x05\x0e\x08\x02\u{139}\x11\x03\x02\x02\x02\u{13a}\u{140}\x05\x1e\x10\x02\
-	\u{13b}\u{140}\x05\x1a\x0e\x02\u{13c}\u{140}\x05\x24\x13\x02\u{13d}\u{140}\
-	\x05\x20\x11\x02\u{13e}\u{140}\x05\x26\x14\x02\u{13f}\u{13a}\x03\x02\x02\
-	\x02\u{13f}\u{13b}\x03\x02\x02\x02\u{13f}\u{13c}\x03\x02\x02\x02\u{13f}\
-	\u{13d}\x03\x02\x02\x02\u{13f}\u{13e}\x03\x02\x02\x02\u{140}\x13\x03\x02\
-	\x02\x02\u{141}\u{145}\x05\x16\x0c\x02\u{142}\u{145}\x05\x18\x0d\x02\u{143}\
-	\u{145}\x05\x2a\x16\x02\u{144}\u{141}\x03\x02\x02\x02\u{144}\u{142}\x03\
-	\x02\x02\x02\u{144}\u{143}\x03\x02\x02\x02\u{145}\x15\x03\x02\x02\x02\u{146}\
-	\u{147}\x07\x32\x02\x02\u{147}\u{149}\x07\x7f\x02\x02\u{148}\u{146}\x03\
-	\x02\x02\x02\u{148}\u{149}\x03\x02\x02\x02\u{149}\u{14a}\x03\x02\x02\x02\
-	\u{14a}\u{14c}\x07\x33\x02\x02\u{14b}\u{14d}\x07\x7f\x02\x02\u{14c}\u{14b}\
-	\x03\x02\x02\x02\u{14c}\u{14d}\x03\x02\x02\x02\u{14d}\u{14e}\x03\x02\x02\
-	\x02\u{14e}\u{153}\x05\x46\x24\x02\u{14f}\u{151}\x07\x7f\x02\x02\u{150}\
-	\u{14f}\x03\x02\x02\x02\u{150}\u{151}\x03\x02\x02\x02\u{151}\u{152}\x03\
-	\x02\x02\x02\u{152}\u{154}\x05\x44\x23\x02\u{153}\u{150}\x03\x02\x02\x02\
-	\u{153}\u{154}\x03\x02\x02\x02\u{154}\x17\x03\x02\x02\x02\u{155}\u{157}\
-	\x07\x34\x02\x02\u{156}\u{158}\x07\x7f\x02\x02\u{157}\u{156}\x03\x02\x02\
-	\x02\u{157}\u{158}\x03\x02\x02\x02\u{158}\u{159}\x03\x02\x02\x02\u{159}\
-	\u{15a}\x05\x64\x33\x02\u{15a}\u{15b}\x07\x7f\x02\x02\u{15b}\u{15c}\x07\
-	\x35\x02\x02\u{15c}\u{15d}\x07\x7f\x02\x02\u{15d}\u{15e}\x05\u{ac}\x57\x02\
-	\u{15e}\x19\x03\x02\x02\x02\u{15f}\u{161}\x07\x36\x02\x02\u{160}\u{162}\
-	\x07\x7f\x02\x02\u{161}\u{160}\x03\x02\x02\x02\u{161}\u{162}\x03\x02\x02\
-	\x02\u{162}\u{163}\x03\x02\x02\x02\u{163}\u{168}\x05\x48\x25\x02\u{164}\
-	\u{165}\x07\x7f\x02\x02\u{165}\u{167}\x05\x1c\x0f\x02\u{166}\u{164}\x03\
-	\x02\x02\x02\u{167}\u{16a}\x03\x02\x02\x02\u{168}\u{166}\x03\x02\x02\x02\
-	\u{168}\u{169}\x03\x02\x02\x02\u{169}\x1b\x03\x02\x02\x02\u{16a}\u{168}\
-	\x03\x02\x02\x02\u{16b}\u{16c}\x07\x37\x02\x02\u{16c}\u{16d}\x07\x7f\x02\
-	\x02\u{16d}\u{16e}\x07\x33\x02\x02\u{16e}\u{16f}\x07\x7f\x02\x02\u{16f}\
-	\u{176}\x05\x20\x11\x02\u{170}\u{171}\x07\x37\x02\x02\u{171}\u{172}\x07\
-	\x7f\x02\x02\u{172}\u{173}\x07\x38\x02\x02\u{173}\u{174}\x07\x7f\x02\x02\
-	\u{174}\u{176}\x05\x20\x11\x02\u{175}\u{16b}\x03\x02\x02\x02\u{175}\u{170}\
-	\x03\x02\x02\x02\u{176}\x1d\x03\x02\x02\x02\u{177}\u{179}\x07\x38\x02\x02\
-	\u{178}\u{17a}\x07\x7f\x02\x02\u{179}\u{178}\x03\x02\x02\x02\u{179}\u{17a}\
-	\x03\x02\x02\x02\u{17a}\u{17b}\x03\x02\x02\x02\u{17b}\u{17c}\x05\x46\x24\
-	\x02\u{17c}\x1f\x03\x02\x02\x02\u{17d}\u{17f}\x07\x39\x02\x02\u{17e}\u{180}\
-	\x07\x7f\x02\x02\u{17f}\u{17e}\x03\x02\x02\x02\u{17f}\u{180}\x03\x02\x02\
-	\x02\u{180}\u{181}\x03\x02\x02\x02\u{181}\u{186}\x05\x22\x12\x02\u{182}\
-	\u{183}\x07\x04\x02\x02\u{183}\u{185}\x05\x22\x12\x02\u{184}\u{182}\x03\
-	\x02\x02\x02\u{185}\u{188}\x03\x02\x02\x02\u{186}\u{184}\x03\x02\x02\x02\
-	\u{186}\u{187}\x03\x02\x02\x02\u{187}\x21\x03\x02\x02\x02\u{188}\u{186}\
-	\x03\x02\x02\x02\u{189}\u{18b}\x05\u{b4}\x5b\x02\u{18a}\u{18c}\x07\x7f\x02\
-	\x02\u{18b}\u{18a}\x03\x02\x02\x02\u{18b}\u{18c}\x03\x02\x02\x02\u{18c}\
-	\u{18d}\x03\x02\x02\x02\u{18d}\u{18f}\x07\x05\x02\x02\u{18e}\u{190}\x07\
-	\x7f\x02\x02\u{18f}\u{18e}\x03\x02\x02\x02\u{18f}\u{190}\x03\x02\x02\x02\
-	\u{190}\u{191}\x03\x02\x02\x02\u{191}\u{192}\x05\x64\x33\x02\u{192}\u{1ae}\
-	\x03\x02\x02\x02\u{193}\u{195}\x05\u{ac}\x57\x02\u{194}\u{196}\x07\x7f\x02\
-	\x02\u{195}\u{194}\x03\x02\x02\x02\u{195}\u{196}\x03\x02\x02\x02\u{196}\
-	\u{197}\x03\x02\x02\x02\u{197}\u{199}\x07\x05\x02\x02\u{198}\u{19a}\x07\
-	\x7f\x02\x02\u{199}\u{198}\x03\x02\x02\x02\u{199}\u{19a}\x03\x02\x02\x02\
-	\u{19a}\u{19b}\x03\x02\x02\x02\u{19b}\u{19c}\x05\x64\x33\x02\u{19c}\u{1ae}\
-	\x03\x02\x02\x02\u{19d}\u{19f}\x05\u{ac}\x57\x02\u{19e}\u{1a0}\x07\x7f\x02\
-	\x02\u{19f}\u{19e}\x03\x02\x02\x02\u{19f}\u{1a0}\x03\x02\x02\x02\u{1a0}\
-	\u{1a1}\x03\x02\x02\x02\u{1a1}\u{1a3}\x07\x06\x02\x02\u{1a2}\u{1a4}\x07\
-	\x7f\x02\x02\u{1a3}\u{1a2}\x03\x02\x02\x02\u{1a3}\u{1a4}\x03\x02\x02\x02\
-	\u{1a4}\u{1a5}\x03\x02\x02\x02\u{1a5}\u{1a6}\x05\x64\x33\x02\u{1a6}\u{1ae}\
-	\x03\x02\x02\x02\u{1a7}\u{1a9}\x05\u{ac}\x57\x02\u{1a8}\u{1aa}\x07\x7f\x02\
-	\x02\u{1a9}\u{1a8}\x03\x02\x02\x02\u{1a9}\u{1aa}\x03\x02\x02\x02\u{1aa}\
-	\u{1ab}\x03\x02\x02\x02\u{1ab}\u{1ac}\x05\x5a\x2e\x02\u{1ac}\u{1ae}\x03\
-	\x02\x02\x02\u{1ad}\u{189}\x03\x02\x02\x02\u{1ad}\u{193}\x03\x02\x02\x02\
-	\u{1ad}\u{19d}\x03\x02\x02\x02\u{1ad}\u{1a7}\x03\x02\x02\x02\u{1ae}\x23\
-	\x03\x02\x02\x02\u{1af}\u{1b0}\x07\x3a\x02\x02\u{1b0}\u{1b2}\x07\x7f\x02\
-	\x02\u{1b1}\u{1af}\x03\x02\x02\x02\u{1b1}\u{1b2}\x03\x02\x02\x02\u{1b2}\
-	\u{1b3}\x03\x02\x02\x02\u{1b3}\u{1b5}\x07\x3b\x02\x02\u{1b4}\u{1b6}\x07\
-	\x7f\x02\x02\u{1b5}\u{1b4}\x03\x02\x02\x02\u{1b5}\u{1b6}\x03\x02\x02\x02\
-	\u{1b6}\u{1b7}\x03\x02\x02\x02\u{1b7}\u{1c2}\x05\x64\x33\x02\u{1b8}\u{1ba}\
-	\x07\x7f\x02\x02\u{1b9}\u{1b8}\x03\x02\x02\x02\u{1b9}\u{1ba}\x03\x02\x02\
-	\x02\u{1ba}\u{1bb}\x03\x02\x02\x02\u{1bb}\u{1bd}\x07\x04\x02\x02\u{1bc}\
-	\u{1be}\x07\x7f\x02\x02\u{1bd}\u{1bc}\x03\x02\x02\x02\u{1bd}\u{1be}\x03\
-	\x02\x02\x02\u{1be}\u{1bf}\x03\x02\x02\x02\u{1bf}\u{1c1}\x05\x64\x33\x02\
-	\u{1c0}\u{1b9}\x03\x02\x02\x02\u{1c1}\u{1c4}\x03\x02\x02\x02\u{1c2}\u{1c0}\
-	\x03\x02\x02\x02\u{1c2}\u{1c3}\x03\x02\x02\x02\u{1c3}\x25\x03\x02\x02\x02\
-	\u{1c4}\u{1c2}\x03\x02\x02\x02\u{1c5}\u{1c6}\x07\x3c\x02\x02\u{1c6}\u{1c7}\
-	\x07\x7f\x02\x02\u{1c7}\u{1d2}\x05\x28\x15\x02\u{1c8}\u{1ca}\x07\x7f\x02\
-	\x02\u{1c9}\u{1c8}\x03\x02\x02\x02\u{1c9}\u{1ca}\x03\x02\x02\x02\u{1ca}\
-	\u{1cb}\x03\x02\x02\x02\u{1cb}\u{1cd}\x07\x04\x02\x02\u{1cc}\u{1ce}\x07\
-	\x7f\x02\x02\u{1cd}\u{1cc}\x03\x02\x02\x02\u{1cd}\u{1ce}\x03\x02\x02\x02\
-	\u{1ce}\u{1cf}\x03\x02\x02\x02\u{1cf}\u{1d1}\x05\x28\x15\x02\u{1d0}\u{1c9}\
-	\x03\x02\x02\x02\u{1d1}\u{1d4}\x03\x02\x02\x02\u{1d2}\u{1d0}\x03\x02\x02\
-	\x02\u{1d2}\u{1d3}\x03\x02\x02\x02\u{1d3}\x27\x03\x02\x02\x02\u{1d4}\u{1d2}\
-	\x03\x02\x02\x02\u{1d5}\u{1d6}\x05\u{ac}\x57\x02\u{1d6}\u{1d7}\x05\x5a\x2e\
-	\x02\u{1d7}\u{1da}\x03\x02\x02\x02\u{1d8}\u{1da}\x05\u{b4}\x5b\x02\u{1d9}\
-	\u{1d5}\x03\x02\x02\x02\u{1d9}\u{1d8}\x03\x02\x02\x02\u{1da}\x29\x03\x02\
-	\x02\x02\u{1db}\u{1dc}\x07\x3d\x02\x02\u{1dc}\u{1dd}\x07\x7f\x02\x02\u{1dd}\
-	\u{1e4}\x05\u{98}\x4d\x02\u{1de}\u{1e0}\x07\x7f\x02\x02\u{1df}\u{1de}\x03\
-	\x02\x02\x02\u{1df}\u{1e0}\x03\x02\x02\x02\u{1e0}\u{1e1}\x03\x02\x02\x02\
-	\u{1e1}\u{1e2}\x07\x3e\x02\x02\u{1e2}\u{1e3}\x07\x7f\x02\x02\u{1e3}\u{1e5}\
-	\x05\x2e\x18\x02\u{1e4}\u{1df}\x03\x02\x02\x02\u{1e4}\u{1e5}\x03\x02\x02\
-	\x02\u{1e5}\x2b\x03\x02\x02\x02\u{1e6}\u{1e7}\x07\x3d\x02\x02\u{1e7}\u{1ea}\
-	\x07\x7f\x02\x02\u{1e8}\u{1eb}\x05\u{98}\x4d\x02\u{1e9}\u{1eb}\x05\u{9a}\
-	\x4e\x02\u{1ea}\u{1e8}\x03\x02\x02\x02\u{1ea}\u{1e9}\x03\x02\x02\x02\u{1eb}\
-	\u{1f0}\x03\x02\x02\x02\u{1ec}\u{1ed}\x07\x7f\x02\x02\u{1ed}\u{1ee}\x07\
-	\x3e\x02\x02\u{1ee}\u{1ef}\x07\x7f\x02\x02\u{1ef}\u{1f1}\x05\x2e\x18\x02\
-	\u{1f0}\u{1ec}\x03\x02\x02\x02\u{1f0}\u{1f1}\x03\x02\x02\x02\u{1f1}\x2d\
-	\x03\x02\x02\x02\u{1f2}\u{202}\x07\x07\x02\x02\u{1f3}\u{1fe}\x05\x30\x19\
-	\x02\u{1f4}\u{1f6}\x07\x7f\x02\x02\u{1f5}\u{1f4}\x03\x02\x02\x02\u{1f5}\
-	\u{1f6}\x03\x02\x02\x02\u{1f6}\u{1f7}\x03\x02\x02\x02\u{1f7}\u{1f9}\x07\
-	\x04\x02\x02\u{1f8}\u{1fa}\x07\x7f\x02\x02\u{1f9}\u{1f8}\x03\x02\x02\x02\
-	\u{1f9}\u{1fa}\x03\x02\x02\x02\u{1fa}\u{1fb}\x03\x02\x02\x02\u{1fb}\u{1fd}\
-	\x05\x30\x19\x02\u{1fc}\u{1f5}\x03\x02\x02\x02\u{1fd}\u{200}\x03\x02\x02\
-	\x02\u{1fe}\u{1fc}\x03\x02\x02\x02\u{1fe}\u{1ff}\x03\x02\x02\x02\u{1ff}\
-	\u{202}\x03\x02\x02\x02\u{200}\u{1fe}\x03\x02\x02\x02\u{201}\u{1f2}\x03\
-	\x02\x02\x02\u{201}\u{1f3}\x03\x02\x02\x02\u{202}\u{207}\x03\x02\x02\x02\
-	\u{203}\u{205}\x07\x7f\x02\x02\u{204}\u{203}\x03\x02\x02\x02\u{204}\u{205}\
-	\x03\x02\x02\x02\u{205}\u{206}\x03\x02\x02\x02\u{206}\u{208}\x05\x44\x23\
-	\x02\u{207}\u{204}\x03\x02\x02\x02\u{207}\u{208}\x03\x02\x02\x02\u{208}\
-	\x2f\x03\x02\x02\x02\u{209}\u{20a}\x05\u{9c}\x4f\x02\u{20a}\u{20b}\x07\x7f\
-	\x02\x02\u{20b}\u{20c}\x07\x35\x02\x02\u{20c}\u{20d}\x07\x7f\x02\x02\u{20d}\
-	\u{20f}\x03\x02\x02\x02\u{20e}\u{209}\x03\x02\x02\x02\u{20e}\u{20f}\x03\
-	\x02\x02\x02\u{20f}\u{210}\x03\x02\x02\x02\u{210}\u{211}\x05\u{ac}\x57\x02\
-	\u{211}\x31\x03\x02\x02\x02\u{212}\u{213}\x07\x3f\x02\x02\u{213}\u{218}\
-	\x05\x36\x1c\x02\u{214}\u{216}\x07\x7f\x02\x02\u{215}\u{214}\x03\x02\x02\
-	\x02\u{215}\u{216}\x03\x02\x02\x02\u{216}\u{217}\x03\x02\x02\x02\u{217}\
-	\u{219}\x05\x44\x23\x02\u{218}\u{215}\x03\x02\x02\x02\u{218}\u{219}\x03\
-	\x02\x02\x02\u{219}\x33\x03\x02\x02\x02\u{21a}\u{21b}\x07\x40\x02\x02\u{21b}\
-	\u{21c}\x05\x36\x1c\x02\u{21c}\x35\x03\x02\x02\x02\u{21d}\u{21f}\x07\x7f\
-	\x02\x02\u{21e}\u{21d}\x03\x02\x02\x02\u{21e}\u{21f}\x03\x02\x02\x02\u{21f}\
-	\u{220}\x03\x02\x02\x02\u{220}\u{222}\x07\x41\x02\x02\u{221}\u{21e}\x03\
-	\x02\x02\x02\u{221}\u{222}\x03\x02\x02\x02\u{222}\u{223}\x03\x02\x02\x02\
-	\u{223}\u{224}\x07\x7f\x02\x02\u{224}\u{227}\x05\x38\x1d\x02\u{225}\u{226}\
-	\x07\x7f\x02\x02\u{226}\u{228}\x05\x3c\x1f\x02\u{227}\u{225}\x03\x02\x02\
-	\x02\u{227}\u{228}\x03\x02\x02\x02\u{228}\u{22b}\x03\x02\x02\x02\u{229}\
-	\u{22a}\x07\x7f\x02\x02\u{22a}\u{22c}\x05\x3e\x20\x02\u{22b}\u{229}\x03\
-	\x02\x02\x02\u{22b}\u{22c}\x03\x02\x02\x02\u{22c}\u{22f}\x03\x02\x02\x02\
-	\u{22d}\u{22e}\x07\x7f\x02\x02\u{22e}\u{230}\x05\x40\x21\x02\u{22f}\u{22d}\
-	\x03\x02\x02\x02\u{22f}\u{230}\x03\x02\x02\x02\u{230}\x37\x03\x02\x02\x02\
-	\u{231}\u{23c}\x07\x07\x02\x02\u{232}\u{234}\x07\x7f\x02\x02\u{233}\u{232}\
-	\x03\x02\x02\x02\u{233}\u{234}\x03\x02\x02\x02\u{234}\u{235}\x03\x02\x02\
-	\x02\u{235}\u{237}\x07\x04\x02\x02\u{236}\u{238}\x07\x7f\x02\x02\u{237}\
-	\u{236}\x03\x02\x02\x02\u{237}\u{238}\x03\x02\x02\x02\u{238}\u{239}\x03\
-	\x02\x02\x02\u{239}\u{23b}\x05\x3a\x1e\x02\u{23a}\u{233}\x03\x02\x02\x02\
-	\u{23b}\u{23e}\x03\x02\x02\x02\u{23c}\u{23a}\x03\x02\x02\x02\u{23c}\u{23d}\
-	\x03\x02\x02\x02\u{23d}\u{24e}\x03\x02\x02\x02\u{23e}\u{23c}\x03\x02\x02\
-	\x02\u{23f}\u{24a}\x05\x3a\x1e\x02\u{240}\u{242}\x07\x7f\x02\x02\u{241}\
-	\u{240}\x03\x02\x02\x02\u{241}\u{242}\x03\x02\x02\x02\u{242}\u{243}\x03\
-	\x02\x02\x02\u{243}\u{245}\x07\x04\x02\x02\u{244}\u{246}\x07\x7f\x02\x02\
-	\u{245}\u{244}\x03\x02\x02\x02\u{245}\u{246}\x03\x02\x02\x02\u{246}\u{247}\
-	\x03\x02\x02\x02\u{247}\u{249}\x05\x3a\x1e\x02\u{248}\u{241}\x03\x02\x02\
-	\x02\u{249}\u{24c}\x03\x02\x02\x02\u{24a}\u{248}\x03\x02\x02\x02\u{24a}\
-	\u{24b}\x03\x02\x02\x02\u{24b}\u{24e}\x03\x02\x02\x02\u{24c}\u{24a}\x03\
-	\x02\x02\x02\u{24d}\u{231}\x03\x02\x02\x02\u{24d}\u{23f}\x03\x02\x02\x02\
-	\u{24e}\x39\x03\x02\x02\x02\u{24f}\u{250}\x05\x64\x33\x02\u{250}\u{251}\
-	\x07\x7f\x02\x02\u{251}\u{252}\x07\x35\x02\x02\u{252}\u{253}\x07\x7f\x02\
-	\x02\u{253}\u{254}\x05\u{ac}\x57\x02\u{254}\u{257}\x03\x02\x02\x02\u{255}\
-	\u{257}\x05\x64\x33\x02\u{256}\u{24f}\x03\x02\x02\x02\u{256}\u{255}\x03\
-	\x02\x02\x02\u{257}\x3b\x03\x02\x02\x02\u{258}\u{259}\x07\x42\x02\x02\u{259}\
-	\u{25a}\x07\x7f\x02\x02\u{25a}\u{25b}\x07\x43\x02\x02\u{25b}\u{25c}\x07\
-	\x7f\x02\x02\u{25c}\u{264}\x05\x42\x22\x02\u{25d}\u{25f}\x07\x04\x02\x02\
-	\u{25e}\u{260}\x07\x7f\x02\x02\u{25f}\u{25e}\x03\x02\x02\x02\u{25f}\u{260}\
-	\x03\x02\x02\x02\u{260}\u{261}\x03\x02\x02\x02\u{261}\u{263}\x05\x42\x22\
-	\x02\u{262}\u{25d}\x03\x02\x02\x02\u{263}\u{266}\x03\x02\x02\x02\u{264}\
-	\u{262}\x03\x02\x02\x02\u{264}\u{265}\x03\x02\x02\x02\u{265}\x3d\x03\x02\
-	\x02\x02\u{266}\u{264}\x03\x02\x02\x02\u{267}\u{268}\x07\x44\x02\x02\u{268}\
-	\u{269}\x07\x7f\x02\x02\u{269}\u{26a}\x05\x64\x33\x02\u{26a}\x3f\x03\x02\
-	\x02\x02\u{26b}\u{26c}\x07\x45\x02\x02\u{26c}\u{26d}\x07\x7f\x02\x02\u{26d}\
+	\x30\u{360}\x0a\x30\x03\x31\x03\x31\x03\x31\x03\x31\x05\x31\u{366}\x0a\x31\
+	\x07\x31\u{368}\x0a\x31\x0c\x31\x0e\x31\u{36b}\x0b\x31\x03\x32\x03\x32\x03\
+	\x33\x03\x33\x03\x34\x03\x34\x03\x34\x03\x34\x03\x34\x07\x34\u{376}\x0a\
+	\x34\x0c\x34\x0e\x34\u{379}\x0b\x34\x03\x35\x03\x35\x03\x35\x03\x35\x03\
+	\x35\x07\x35\u{380}\x0a\x35\x0c\x35\x0e\x35\u{383}\x0b\x35\x03\x36\x03\x36\
+	\x03\x36\x03\x36\x03\x36\x07\x36\u{38a}\x0a\x36\x0c\x36\x0e\x36\u{38d}\x0b\
+	\x36\x03\x37\x03\x37\x05\x37\u{391}\x0a\x37\x07\x37\u{393}\x0a\x37\x0c\x37\
+	\x0e\x37\u{396}\x0b\x37\x03\x37\x03\x37\x03\x38\x03\x38\x05\x38\u{39c}\x0a\
+	\x38\x03\x38\x07\x38\u{39f}\x0a\x38\x0c\x38\x0e\x38\u{3a2}\x0b\x38\x03\x39\
+	\x03\x39\x05\x39\u{3a6}\x0a\x39\x03\x39\x03\x39\x05\x39\u{3aa}\x0a\x39\x03\
+	\x39\x03\x39\x05\x39\u{3ae}\x0a\x39\x03\x39\x03\x39\x05\x39\u{3b2}\x0a\x39\
+	\x03\x39\x07\x39\u{3b5}\x0a\x39\x0c\x39\x0e\x39\u{3b8}\x0b\x39\x03\x3a\x03\
+	\x3a\x05\x3a\u{3bc}\x0a\x3a\x03\x3a\x03\x3a\x05\x3a\u{3c0}\x0a\x3a\x03\x3a\
+	\x03\x3a\x05\x3a\u{3c4}\x0a\x3a\x03\x3a\x03\x3a\x05\x3a\u{3c8}\x0a\x3a\x03\
+	\x3a\x03\x3a\x05\x3a\u{3cc}\x0a\x3a\x03\x3a\x03\x3a\x05\x3a\u{3d0}\x0a\x3a\
+	\x03\x3a\x07\x3a\u{3d3}\x0a\x3a\x0c\x3a\x0e\x3a\u{3d6}\x0b\x3a\x03\x3b\x03\
+	\x3b\x05\x3b\u{3da}\x0a\x3b\x03\x3b\x03\x3b\x05\x3b\u{3de}\x0a\x3b\x03\x3b\
+	\x07\x3b\u{3e1}\x0a\x3b\x0c\x3b\x0e\x3b\u{3e4}\x0b\x3b\x03\x3c\x03\x3c\x05\
+	\x3c\u{3e8}\x0a\x3c\x07\x3c\u{3ea}\x0a\x3c\x0c\x3c\x0e\x3c\u{3ed}\x0b\x3c\
+	\x03\x3c\x03\x3c\x03\x3d\x03\x3d\x03\x3d\x03\x3d\x07\x3d\u{3f5}\x0a\x3d\
+	\x0c\x3d\x0e\x3d\u{3f8}\x0b\x3d\x03\x3e\x03\x3e\x03\x3e\x05\x3e\u{3fd}\x0a\
+	\x3e\x03\x3e\x03\x3e\x05\x3e\u{401}\x0a\x3e\x03\x3e\x03\x3e\x03\x3e\x03\
+	\x3e\x03\x3e\x05\x3e\u{408}\x0a\x3e\x03\x3e\x03\x3e\x05\x3e\u{40c}\x0a\x3e\
+	\x03\x3e\x03\x3e\x05\x3e\u{410}\x0a\x3e\x03\x3e\x05\x3e\u{413}\x0a\x3e\x03\
+	\x3f\x03\x3f\x03\x3f\x03\x3f\x03\x3f\x03\x3f\x03\x3f\x03\x3f\x03\x3f\x03\
+	\x3f\x03\x3f\x05\x3f\u{420}\x0a\x3f\x03\x3f\x05\x3f\u{423}\x0a\x3f\x03\x3f\
+	\x05\x3f\u{426}\x0a\x3f\x03\x3f\x03\x3f\x03\x40\x03\x40\x03\x40\x03\x40\
+	\x03\x40\x03\x40\x03\x40\x03\x40\x03\x40\x03\x40\x05\x40\u{434}\x0a\x40\
+	\x03\x41\x03\x41\x05\x41\u{438}\x0a\x41\x03\x41\x07\x41\u{43b}\x0a\x41\x0c\
+	\x41\x0e\x41\u{43e}\x0b\x41\x03\x41\x05\x41\u{441}\x0a\x41\x03\x41\x05\x41\
+	\u{444}\x0a\x41\x03\x42\x03\x42\x03\x42\x03\x42\x03\x42\x05\x42\u{44b}\x0a\
+	\x42\x03\x42\x03\x42\x05\x42\u{44f}\x0a\x42\x03\x42\x03\x42\x05\x42\u{453}\
+	\x0a\x42\x03\x42\x03\x42\x03\x42\x03\x42\x03\x42\x05\x42\u{45a}\x0a\x42\
+	\x03\x42\x03\x42\x05\x42\u{45e}\x0a\x42\x03\x42\x03\x42\x05\x42\u{462}\x0a\
+	\x42\x03\x42\x03\x42\x03\x42\x03\x42\x05\x42\u{468}\x0a\x42\x03\x42\x03\
+	\x42\x05\x42\u{46c}\x0a\x42\x03\x42\x03\x42\x05\x42\u{470}\x0a\x42\x03\x42\
+	\x03\x42\x03\x42\x03\x42\x05\x42\u{476}\x0a\x42\x03\x42\x03\x42\x05\x42\
+	\u{47a}\x0a\x42\x03\x42\x03\x42\x05\x42\u{47e}\x0a\x42\x03\x42\x03\x42\x03\
+	\x42\x03\x42\x05\x42\u{484}\x0a\x42\x03\x42\x03\x42\x05\x42\u{488}\x0a\x42\
+	\x03\x42\x03\x42\x05\x42\u{48c}\x0a\x42\x03\x42\x03\x42\x03\x42\x03\x42\
+	\x03\x42\x03\x42\x05\x42\u{494}\x0a\x42\x03\x43\x03\x43\x03\x43\x03\x43\
+	\x03\x43\x03\x43\x05\x43\u{49c}\x0a\x43\x03\x44\x03\x44\x03\x45\x03\x45\
+	\x05\x45\u{4a2}\x0a\x45\x03\x45\x03\x45\x05\x45\u{4a6}\x0a\x45\x03\x45\x03\
+	\x45\x05\x45\u{4aa}\x0a\x45\x03\x45\x03\x45\x05\x45\u{4ae}\x0a\x45\x07\x45\
+	\u{4b0}\x0a\x45\x0c\x45\x0e\x45\u{4b3}\x0b\x45\x05\x45\u{4b5}\x0a\x45\x03\
+	\x45\x03\x45\x03\x46\x03\x46\x05\x46\u{4bb}\x0a\x46\x03\x46\x03\x46\x03\
+	\x46\x05\x46\u{4c0}\x0a\x46\x03\x46\x03\x46\x03\x46\x05\x46\u{4c5}\x0a\x46\
+	\x03\x46\x03\x46\x03\x46\x05\x46\u{4ca}\x0a\x46\x03\x46\x03\x46\x03\x46\
+	\x05\x46\u{4cf}\x0a\x46\x03\x46\x03\x46\x03\x46\x05\x46\u{4d4}\x0a\x46\x03\
+	\x46\x05\x46\u{4d7}\x0a\x46\x03\x47\x03\x47\x05\x47\u{4db}\x0a\x47\x03\x47\
+	\x03\x47\x05\x47\u{4df}\x0a\x47\x03\x47\x03\x47\x03\x48\x03\x48\x05\x48\
+	\u{4e5}\x0a\x48\x03\x48\x06\x48\u{4e8}\x0a\x48\x0d\x48\x0e\x48\u{4e9}\x03\
+	\x49\x03\x49\x05\x49\u{4ee}\x0a\x49\x03\x49\x05\x49\u{4f1}\x0a\x49\x03\x4a\
+	\x03\x4a\x03\x4a\x03\x4a\x03\x4a\x03\x4a\x03\x4b\x03\x4b\x05\x4b\u{4fb}\
+	\x0a\x4b\x03\x4b\x03\x4b\x05\x4b\u{4ff}\x0a\x4b\x03\x4b\x03\x4b\x05\x4b\
+	\u{503}\x0a\x4b\x05\x4b\u{505}\x0a\x4b\x03\x4b\x03\x4b\x05\x4b\u{509}\x0a\
+	\x4b\x03\x4b\x03\x4b\x05\x4b\u{50d}\x0a\x4b\x03\x4b\x03\x4b\x05\x4b\u{511}\
+	\x0a\x4b\x07\x4b\u{513}\x0a\x4b\x0c\x4b\x0e\x4b\u{516}\x0b\x4b\x05\x4b\u{518}\
+	\x0a\x4b\x03\x4b\x03\x4b\x03\x4c\x03\x4c\x03\x4c\x03\x4c\x05\x4c\u{520}\
+	\x0a\x4c\x03\x4d\x03\x4d\x05\x4d\u{524}\x0a\x4d\x03\x4d\x03\x4d\x05\x4d\
+	\u{528}\x0a\x4d\x03\x4d\x03\x4d\x05\x4d\u{52c}\x0a\x4d\x03\x4d\x03\x4d\x05\
+	\x4d\u{530}\x0a\x4d\x03\x4d\x03\x4d\x05\x4d\u{534}\x0a\x4d\x07\x4d\u{536}\
+	\x0a\x4d\x0c\x4d\x0e\x4d\u{539}\x0b\x4d\x05\x4d\u{53b}\x0a\x4d\x03\x4d\x03\
+	\x4d\x03\x4e\x03\x4e\x03\x4f\x03\x4f\x03\x50\x03\x50\x03\x50\x03\x51\x03\
+	\x51\x03\x51\x07\x51\u{549}\x0a\x51\x0c\x51\x0e\x51\u{54c}\x0b\x51\x03\x52\
+	\x03\x52\x05\x52\u{550}\x0a\x52\x03\x52\x03\x52\x05\x52\u{554}\x0a\x52\x03\
+	\x52\x03\x52\x05\x52\u{558}\x0a\x52\x03\x52\x05\x52\u{55b}\x0a\x52\x03\x52\
+	\x05\x52\u{55e}\x0a\x52\x03\x52\x03\x52\x03\x53\x03\x53\x05\x53\u{564}\x0a\
+	\x53\x03\x53\x03\x53\x05\x53\u{568}\x0a\x53\x03\x53\x03\x53\x05\x53\u{56c}\
+	\x0a\x53\x05\x53\u{56e}\x0a\x53\x03\x53\x03\x53\x05\x53\u{572}\x0a\x53\x03\
+	\x53\x03\x53\x05\x53\u{576}\x0a\x53\x03\x53\x03\x53\x05\x53\u{57a}\x0a\x53\
+	\x05\x53\u{57c}\x0a\x53\x03\x53\x03\x53\x05\x53\u{580}\x0a\x53\x03\x53\x03\
+	\x53\x05\x53\u{584}\x0a\x53\x03\x53\x03\x53\x03\x54\x03\x54\x05\x54\u{58a}\
+	\x0a\x54\x03\x54\x03\x54\x03\x55\x03\x55\x05\x55\u{590}\x0a\x55\x03\x55\
+	\x06\x55\u{593}\x0a\x55\x0d\x55\x0e\x55\u{594}\x03\x55\x03\x55\x05\x55\u{599}\
+	\x0a\x55\x03\x55\x03\x55\x05\x55\u{59d}\x0a\x55\x03\x55\x06\x55\u{5a0}\x0a\
+	\x55\x0d\x55\x0e\x55\u{5a1}\x05\x55\u{5a4}\x0a\x55\x03\x55\x05\x55\u{5a7}\
+	\x0a\x55\x03\x55\x03\x55\x05\x55\u{5ab}\x0a\x55\x03\x55\x05\x55\u{5ae}\x0a\
+	\x55\x03\x55\x05\x55\u{5b1}\x0a\x55\x03\x55\x03\x55\x03\x56\x03\x56\x05\
+	\x56\u{5b7}\x0a\x56\x03\x56\x03\x56\x05\x56\u{5bb}\x0a\x56\x03\x56\x03\x56\
+	\x05\x56\u{5bf}\x0a\x56\x03\x56\x03\x56\x03\x57\x03\x57\x03\x58\x03\x58\
+	\x05\x58\u{5c7}\x0a\x58\x03\x59\x03\x59\x05\x59\u{5cb}\x0a\x59\x03\x59\x03\
+	\x59\x05\x59\u{5cf}\x0a\x59\x03\x59\x03\x59\x05\x59\u{5d3}\x0a\x59\x03\x59\
+	\x03\x59\x05\x59\u{5d7}\x0a\x59\x03\x59\x03\x59\x05\x59\u{5db}\x0a\x59\x03\
+	\x59\x03\x59\x05\x59\u{5df}\x0a\x59\x03\x59\x03\x59\x05\x59\u{5e3}\x0a\x59\
+	\x03\x59\x03\x59\x05\x59\u{5e7}\x0a\x59\x07\x59\u{5e9}\x0a\x59\x0c\x59\x0e\
+	\x59\u{5ec}\x0b\x59\x05\x59\u{5ee}\x0a\x59\x03\x59\x03\x59\x03\x5a\x03\x5a\
+	\x03\x5a\x05\x5a\u{5f5}\x0a\x5a\x03\x5b\x03\x5b\x05\x5b\u{5f9}\x0a\x5b\x03\
+	\x5b\x06\x5b\u{5fc}\x0a\x5b\x0d\x5b\x0e\x5b\u{5fd}\x03\x5c\x03\x5c\x03\x5d\
+	\x03\x5d\x03\x5e\x03\x5e\x03\x5f\x03\x5f\x05\x5f\u{608}\x0a\x5f\x03\x60\
+	\x03\x60\x03\x61\x03\x61\x03\x62\x03\x62\x03\x63\x03\x63\x03\x64\x03\x64\
+	\x03\x64\x02\x02\x65\x02\x04\x06\x08\x0a\x0c\x0e\x10\x12\x14\x16\x18\x1a\
+	\x1c\x1e\x20\x22\x24\x26\x28\x2a\x2c\x2e\x30\x32\x34\x36\x38\x3a\x3c\x3e\
+	\x40\x42\x44\x46\x48\x4a\x4c\x4e\x50\x52\x54\x56\x58\x5a\x5c\x5e\x60\x62\
+	\x64\x66\x68\x6a\x6c\x6e\x70\x72\x74\x76\x78\x7a\x7c\x7e\u{80}\u{82}\u{84}\
+	\u{86}\u{88}\u{8a}\u{8c}\u{8e}\u{90}\u{92}\u{94}\u{96}\u{98}\u{9a}\u{9c}\
+	\u{9e}\u{a0}\u{a2}\u{a4}\u{a6}\u{a8}\u{aa}\u{ac}\u{ae}\u{b0}\u{b2}\u{b4}\
+	\u{b6}\u{b8}\u{ba}\u{bc}\u{be}\u{c0}\u{c2}\u{c4}\u{c6}\x02\x0c\x03\x02\x47\
+	\x4a\x03\x02\x0f\x10\x03\x02\x5a\x5b\x03\x02\x64\x66\x03\x02\x6e\x6f\x06\
+	\x02\x31\x3d\x40\x55\x5a\x61\x70\x79\x06\x02\x56\x59\x67\x67\x7a\x7c\x7f\
+	\x7f\x04\x02\x16\x16\x1e\x21\x04\x02\x17\x17\x22\x25\x04\x02\x10\x10\x26\
+	\x30\x02\u{6ef}\x02\u{c9}\x03\x02\x02\x02\x04\u{d7}\x03\x02\x02\x02\x06\
+	\u{db}\x03\x02\x02\x02\x08\u{dd}\x03\x02\x02\x02\x0a\u{f3}\x03\x02\x02\x02\
+	\x0c\u{f7}\x03\x02\x02\x02\x0e\u{11c}\x03\x02\x02\x02\x10\u{134}\x03\x02\
+	\x02\x02\x12\u{13f}\x03\x02\x02\x02\x14\u{144}\x03\x02\x02\x02\x16\u{148}\
+	\x03\x02\x02\x02\x18\u{155}\x03\x02\x02\x02\x1a\u{15f}\x03\x02\x02\x02\x1c\
+	\u{175}\x03\x02\x02\x02\x1e\u{177}\x03\x02\x02\x02\x20\u{17d}\x03\x02\x02\
+	\x02\x22\u{1ad}\x03\x02\x02\x02\x24\u{1b1}\x03\x02\x02\x02\x26\u{1c5}\x03\
+	\x02\x02\x02\x28\u{1d9}\x03\x02\x02\x02\x2a\u{1db}\x03\x02\x02\x02\x2c\u{1e6}\
+	\x03\x02\x02\x02\x2e\u{201}\x03\x02\x02\x02\x30\u{20e}\x03\x02\x02\x02\x32\
+	\u{212}\x03\x02\x02\x02\x34\u{21a}\x03\x02\x02\x02\x36\u{221}\x03\x02\x02\
+	\x02\x38\u{24d}\x03\x02\x02\x02\x3a\u{256}\x03\x02\x02\x02\x3c\u{258}\x03\
+	\x02\x02\x02\x3e\u{267}\x03\x02\x02\x02\x40\u{26b}\x03\x02\x02\x02\x42\u{26f}\
+	\x03\x02\x02\x02\x44\u{276}\x03\x02\x02\x02\x46\u{27a}\x03\x02\x02\x02\x48\
+	\u{293}\x03\x02\x02\x02\x4a\u{295}\x03\x02\x02\x02\x4c\u{2a5}\x03\x02\x02\
+	\x02\x4e\u{2a7}\x03\x02\x02\x02\x50\u{2bf}\x03\x02\x02\x02\x52\u{305}\x03\
+	\x02\x02\x02\x54\u{307}\x03\x02\x02\x02\x56\u{324}\x03\x02\x02\x02\x58\u{326}\
+	\x03\x02\x02\x02\x5a\u{33b}\x03\x02\x02\x02\x5c\u{345}\x03\x02\x02\x02\x5e\
+	\u{34b}\x03\x02\x02\x02\x60\u{361}\x03\x02\x02\x02\x62\u{36c}\x03\x02\x02\
+	\x02\x64\u{36e}\x03\x02\x02\x02\x66\u{370}\x03\x02\x02\x02\x68\u{37a}\x03\
+	\x02\x02\x02\x6a\u{384}\x03\x02\x02\x02\x6c\u{394}\x03\x02\x02\x02\x6e\u{399}\
+	\x03\x02\x02\x02\x70\u{3a3}\x03\x02\x02\x02\x72\u{3b9}\x03\x02\x02\x02\x74\
+	\u{3d7}\x03\x02\x02\x02\x76\u{3eb}\x03\x02\x02\x02\x78\u{3f0}\x03\x02\x02\
+	\x02\x7a\u{412}\x03\x02\x02\x02\x7c\u{422}\x03\x02\x02\x02\x7e\u{433}\x03\
+	\x02\x02\x02\u{80}\u{435}\x03\x02\x02\x02\u{82}\u{493}\x03\x02\x02\x02\u{84}\
+	\u{49b}\x03\x02\x02\x02\u{86}\u{49d}\x03\x02\x02\x02\u{88}\u{49f}\x03\x02\
+	\x02\x02\u{8a}\u{4d6}\x03\x02\x02\x02\u{8c}\u{4d8}\x03\x02\x02\x02\u{8e}\
+	\u{4e2}\x03\x02\x02\x02\u{90}\u{4eb}\x03\x02\x02\x02\u{92}\u{4f2}\x03\x02\
+	\x02\x02\u{94}\u{4f8}\x03\x02\x02\x02\u{96}\u{51f}\x03\x02\x02\x02\u{98}\
+	\u{521}\x03\x02\x02\x02\u{9a}\u{53e}\x03\x02\x02\x02\u{9c}\u{540}\x03\x02\
+	\x02\x02\u{9e}\u{542}\x03\x02\x02\x02\u{a0}\u{54a}\x03\x02\x02\x02\u{a2}\
+	\u{54d}\x03\x02\x02\x02\u{a4}\u{561}\x03\x02\x02\x02\u{a6}\u{587}\x03\x02\
+	\x02\x02\u{a8}\u{5a3}\x03\x02\x02\x02\u{aa}\u{5b4}\x03\x02\x02\x02\u{ac}\
+	\u{5c2}\x03\x02\x02\x02\u{ae}\u{5c6}\x03\x02\x02\x02\u{b0}\u{5c8}\x03\x02\
+	\x02\x02\u{b2}\u{5f1}\x03\x02\x02\x02\u{b4}\u{5f6}\x03\x02\x02\x02\u{b6}\
+	\u{5ff}\x03\x02\x02\x02\u{b8}\u{601}\x03\x02\x02\x02\u{ba}\u{603}\x03\x02\
+	\x02\x02\u{bc}\u{607}\x03\x02\x02\x02\u{be}\u{609}\x03\x02\x02\x02\u{c0}\
+	\u{60b}\x03\x02\x02\x02\u{c2}\u{60d}\x03\x02\x02\x02\u{c4}\u{60f}\x03\x02\
+	\x02\x02\u{c6}\u{611}\x03\x02\x02\x02\u{c8}\u{ca}\x07\u{80}\x02\x02\u{c9}\
+	\u{c8}\x03\x02\x02\x02\u{c9}\u{ca}\x03\x02\x02\x02\u{ca}\u{cb}\x03\x02\x02\
+	\x02\u{cb}\u{d0}\x05\x04\x03\x02\u{cc}\u{ce}\x07\u{80}\x02\x02\u{cd}\u{cc}\
+	\x03\x02\x02\x02\u{cd}\u{ce}\x03\x02\x02\x02\u{ce}\u{cf}\x03\x02\x02\x02\
+	\u{cf}\u{d1}\x07\x03\x02\x02\u{d0}\u{cd}\x03\x02\x02\x02\u{d0}\u{d1}\x03\
+	\x02\x02\x02\u{d1}\u{d3}\x03\x02\x02\x02\u{d2}\u{d4}\x07\u{80}\x02\x02\u{d3}\
+	\u{d2}\x03\x02\x02\x02\u{d3}\u{d4}\x03\x02\x02\x02\u{d4}\u{d5}\x03\x02\x02\
+	\x02\u{d5}\u{d6}\x07\x02\x02\x03\u{d6}\x03\x03\x02\x02\x02\u{d7}\u{d8}\x05\
+	\x06\x04\x02\u{d8}\x05\x03\x02\x02\x02\u{d9}\u{dc}\x05\x08\x05\x02\u{da}\
+	\u{dc}\x05\x2c\x17\x02\u{db}\u{d9}\x03\x02\x02\x02\u{db}\u{da}\x03\x02\x02\
+	\x02\u{dc}\x07\x03\x02\x02\x02\u{dd}\u{e4}\x05\x0c\x07\x02\u{de}\u{e0}\x07\
+	\u{80}\x02\x02\u{df}\u{de}\x03\x02\x02\x02\u{df}\u{e0}\x03\x02\x02\x02\u{e0}\
+	\u{e1}\x03\x02\x02\x02\u{e1}\u{e3}\x05\x0a\x06\x02\u{e2}\u{df}\x03\x02\x02\
+	\x02\u{e3}\u{e6}\x03\x02\x02\x02\u{e4}\u{e2}\x03\x02\x02\x02\u{e4}\u{e5}\
+	\x03\x02\x02\x02\u{e5}\x09\x03\x02\x02\x02\u{e6}\u{e4}\x03\x02\x02\x02\u{e7}\
+	\u{e8}\x07\x31\x02\x02\u{e8}\u{e9}\x07\u{80}\x02\x02\u{e9}\u{eb}\x07\x32\
+	\x02\x02\u{ea}\u{ec}\x07\u{80}\x02\x02\u{eb}\u{ea}\x03\x02\x02\x02\u{eb}\
+	\u{ec}\x03\x02\x02\x02\u{ec}\u{ed}\x03\x02\x02\x02\u{ed}\u{f4}\x05\x0c\x07\
+	\x02\u{ee}\u{f0}\x07\x31\x02\x02\u{ef}\u{f1}\x07\u{80}\x02\x02\u{f0}\u{ef}\
+	\x03\x02\x02\x02\u{f0}\u{f1}\x03\x02\x02\x02\u{f1}\u{f2}\x03\x02\x02\x02\
+	\u{f2}\u{f4}\x05\x0c\x07\x02\u{f3}\u{e7}\x03\x02\x02\x02\u{f3}\u{ee}\x03\
+	\x02\x02\x02\u{f4}\x0b\x03\x02\x02\x02\u{f5}\u{f8}\x05\x0e\x08\x02\u{f6}\
+	\u{f8}\x05\x10\x09\x02\u{f7}\u{f5}\x03\x02\x02\x02\u{f7}\u{f6}\x03\x02\x02\
+	\x02\u{f8}\x0d\x03\x02\x02\x02\u{f9}\u{fb}\x05\x14\x0b\x02\u{fa}\u{fc}\x07\
+	\u{80}\x02\x02\u{fb}\u{fa}\x03\x02\x02\x02\u{fb}\u{fc}\x03\x02\x02\x02\u{fc}\
+	\u{fe}\x03\x02\x02\x02\u{fd}\u{f9}\x03\x02\x02\x02\u{fe}\u{101}\x03\x02\
+	\x02\x02\u{ff}\u{fd}\x03\x02\x02\x02\u{ff}\u{100}\x03\x02\x02\x02\u{100}\
+	\u{102}\x03\x02\x02\x02\u{101}\u{ff}\x03\x02\x02\x02\u{102}\u{11d}\x05\x34\
+	\x1b\x02\u{103}\u{105}\x05\x14\x0b\x02\u{104}\u{106}\x07\u{80}\x02\x02\u{105}\
+	\u{104}\x03\x02\x02\x02\u{105}\u{106}\x03\x02\x02\x02\u{106}\u{108}\x03\
+	\x02\x02\x02\u{107}\u{103}\x03\x02\x02\x02\u{108}\u{10b}\x03\x02\x02\x02\
+	\u{109}\u{107}\x03\x02\x02\x02\u{109}\u{10a}\x03\x02\x02\x02\u{10a}\u{10c}\
+	\x03\x02\x02\x02\u{10b}\u{109}\x03\x02\x02\x02\u{10c}\u{113}\x05\x12\x0a\
+	\x02\u{10d}\u{10f}\x07\u{80}\x02\x02\u{10e}\u{10d}\x03\x02\x02\x02\u{10e}\
+	\u{10f}\x03\x02\x02\x02\u{10f}\u{110}\x03\x02\x02\x02\u{110}\u{112}\x05\
+	\x12\x0a\x02\u{111}\u{10e}\x03\x02\x02\x02\u{112}\u{115}\x03\x02\x02\x02\
+	\u{113}\u{111}\x03\x02\x02\x02\u{113}\u{114}\x03\x02\x02\x02\u{114}\u{11a}\
+	\x03\x02\x02\x02\u{115}\u{113}\x03\x02\x02\x02\u{116}\u{118}\x07\u{80}\x02\
+	\x02\u{117}\u{116}\x03\x02\x02\x02\u{117}\u{118}\x03\x02\x02\x02\u{118}\
+	\u{119}\x03\x02\x02\x02\u{119}\u{11b}\x05\x34\x1b\x02\u{11a}\u{117}\x03\
+	\x02\x02\x02\u{11a}\u{11b}\x03\x02\x02\x02\u{11b}\u{11d}\x03\x02\x02\x02\
+	\u{11c}\u{ff}\x03\x02\x02\x02\u{11c}\u{109}\x03\x02\x02\x02\u{11d}\x0f\x03\
+	\x02\x02\x02\u{11e}\u{120}\x05\x14\x0b\x02\u{11f}\u{121}\x07\u{80}\x02\x02\
+	\u{120}\u{11f}\x03\x02\x02\x02\u{120}\u{121}\x03\x02\x02\x02\u{121}\u{123}\
+	\x03\x02\x02\x02\u{122}\u{11e}\x03\x02\x02\x02\u{123}\u{126}\x03\x02\x02\
+	\x02\u{124}\u{122}\x03\x02\x02\x02\u{124}\u{125}\x03\x02\x02\x02\u{125}\
+	\u{12d}\x03\x02\x02\x02\u{126}\u{124}\x03\x02\x02\x02\u{127}\u{129}\x05\
+	\x12\x0a\x02\u{128}\u{12a}\x07\u{80}\x02\x02\u{129}\u{128}\x03\x02\x02\x02\
+	\u{129}\u{12a}\x03\x02\x02\x02\u{12a}\u{12c}\x03\x02\x02\x02\u{12b}\u{127}\
+	\x03\x02\x02\x02\u{12c}\u{12f}\x03\x02\x02\x02\u{12d}\u{12b}\x03\x02\x02\
+	\x02\u{12d}\u{12e}\x03\x02\x02\x02\u{12e}\u{130}\x03\x02\x02\x02\u{12f}\
+	\u{12d}\x03\x02\x02\x02\u{130}\u{132}\x05\x32\x1a\x02\u{131}\u{133}\x07\
+	\u{80}\x02\x02\u{132}\u{131}\x03\x02\x02\x02\u{132}\u{133}\x03\x02\x02\x02\
+	\u{133}\u{135}\x03\x02\x02\x02\u{134}\u{124}\x03\x02\x02\x02\u{135}\u{136}\
+	\x03\x02\x02\x02\u{136}\u{134}\x03\x02\x02\x02\u{136}\u{137}\x03\x02\x02\
+	\x02\u{137}\u{138}\x03\x02\x02\x02\u{138}\u{139}\x05\x0e\x08\x02\u{139}\
+	\x11\x03\x02\x02\x02\u{13a}\u{140}\x05\x1e\x10\x02\u{13b}\u{140}\x05\x1a\
+	\x0e\x02\u{13c}\u{140}\x05\x24\x13\x02\u{13d}\u{140}\x05\x20\x11\x02\u{13e}\
+	\u{140}\x05\x26\x14\x02\u{13f}\u{13a}\x03\x02\x02\x02\u{13f}\u{13b}\x03\
+	\x02\x02\x02\u{13f}\u{13c}\x03\x02\x02\x02\u{13f}\u{13d}\x03\x02\x02\x02\
+	\u{13f}\u{13e}\x03\x02\x02\x02\u{140}\x13\x03\x02\x02\x02\u{141}\u{145}\
+	\x05\x16\x0c\x02\u{142}\u{145}\x05\x18\x0d\x02\u{143}\u{145}\x05\x2a\x16\
+	\x02\u{144}\u{141}\x03\x02\x02\x02\u{144}\u{142}\x03\x02\x02\x02\u{144}\
+	\u{143}\x03\x02\x02\x02\u{145}\x15\x03\x02\x02\x02\u{146}\u{147}\x07\x33\
+	\x02\x02\u{147}\u{149}\x07\u{80}\x02\x02\u{148}\u{146}\x03\x02\x02\x02\u{148}\
+	\u{149}\x03\x02\x02\x02\u{149}\u{14a}\x03\x02\x02\x02\u{14a}\u{14c}\x07\
+	\x34\x02\x02\u{14b}\u{14d}\x07\u{80}\x02\x02\u{14c}\u{14b}\x03\x02\x02\x02\
+	\u{14c}\u{14d}\x03\x02\x02\x02\u{14d}\u{14e}\x03\x02\x02\x02\u{14e}\u{153}\
+	\x05\x46\x24\x02\u{14f}\u{151}\x07\u{80}\x02\x02\u{150}\u{14f}\x03\x02\x02\
+	\x02\u{150}\u{151}\x03\x02\x02\x02\u{151}\u{152}\x03\x02\x02\x02\u{152}\
+	\u{154}\x05\x44\x23\x02\u{153}\u{150}\x03\x02\x02\x02\u{153}\u{154}\x03\
+	\x02\x02\x02\u{154}\x17\x03\x02\x02\x02\u{155}\u{157}\x07\x35\x02\x02\u{156}\
+	\u{158}\x07\u{80}\x02\x02\u{157}\u{156}\x03\x02\x02\x02\u{157}\u{158}\x03\
+	\x02\x02\x02\u{158}\u{159}\x03\x02\x02\x02\u{159}\u{15a}\x05\x64\x33\x02\
+	\u{15a}\u{15b}\x07\u{80}\x02\x02\u{15b}\u{15c}\x07\x36\x02\x02\u{15c}\u{15d}\
+	\x07\u{80}\x02\x02\u{15d}\u{15e}\x05\u{ac}\x57\x02\u{15e}\x19\x03\x02\x02\
+	\x02\u{15f}\u{161}\x07\x37\x02\x02\u{160}\u{162}\x07\u{80}\x02\x02\u{161}\
+	\u{160}\x03\x02\x02\x02\u{161}\u{162}\x03\x02\x02\x02\u{162}\u{163}\x03\
+	\x02\x02\x02\u{163}\u{168}\x05\x48\x25\x02\u{164}\u{165}\x07\u{80}\x02\x02\
+	\u{165}\u{167}\x05\x1c\x0f\x02\u{166}\u{164}\x03\x02\x02\x02\u{167}\u{16a}\
+	\x03\x02\x02\x02\u{168}\u{166}\x03\x02\x02\x02\u{168}\u{169}\x03\x02\x02\
+	\x02\u{169}\x1b\x03\x02\x02\x02\u{16a}\u{168}\x03\x02\x02\x02\u{16b}\u{16c}\
+	\x07\x38\x02\x02\u{16c}\u{16d}\x07\u{80}\x02\x02\u{16d}\u{16e}\x07\x34\x02\
+	\x02\u{16e}\u{16f}\x07\u{80}\x02\x02\u{16f}\u{176}\x05\x20\x11\x02\u{170}\
+	\u{171}\x07\x38\x02\x02\u{171}\u{172}\x07\u{80}\x02\x02\u{172}\u{173}\x07\
+	\x39\x02\x02\u{173}\u{174}\x07\u{80}\x02\x02\u{174}\u{176}\x05\x20\x11\x02\
+	\u{175}\u{16b}\x03\x02\x02\x02\u{175}\u{170}\x03\x02\x02\x02\u{176}\x1d\
+	\x03\x02\x02\x02\u{177}\u{179}\x07\x39\x02\x02\u{178}\u{17a}\x07\u{80}\x02\
+	\x02\u{179}\u{178}\x03\x02\x02\x02\u{179}\u{17a}\x03\x02\x02\x02\u{17a}\
+	\u{17b}\x03\x02\x02\x02\u{17b}\u{17c}\x05\x46\x24\x02\u{17c}\x1f\x03\x02\
+	\x02\x02\u{17d}\u{17f}\x07\x3a\x02\x02\u{17e}\u{180}\x07\u{80}\x02\x02\u{17f}\
+	\u{17e}\x03\x02\x02\x02\u{17f}\u{180}\x03\x02\x02\x02\u{180}\u{181}\x03\
+	\x02\x02\x02\u{181}\u{186}\x05\x22\x12\x02\u{182}\u{183}\x07\x04\x02\x02\
+	\u{183}\u{185}\x05\x22\x12\x02\u{184}\u{182}\x03\x02\x02\x02\u{185}\u{188}\
+	\x03\x02\x02\x02\u{186}\u{184}\x03\x02\x02\x02\u{186}\u{187}\x03\x02\x02\
+	\x02\u{187}\x21\x03\x02\x02\x02\u{188}\u{186}\x03\x02\x02\x02\u{189}\u{18b}\
+	\x05\u{b4}\x5b\x02\u{18a}\u{18c}\x07\u{80}\x02\x02\u{18b}\u{18a}\x03\x02\
+	\x02\x02\u{18b}\u{18c}\x03\x02\x02\x02\u{18c}\u{18d}\x03\x02\x02\x02\u{18d}\
+	\u{18f}\x07\x05\x02\x02\u{18e}\u{190}\x07\u{80}\x02\x02\u{18f}\u{18e}\x03\
+	\x02\x02\x02\u{18f}\u{190}\x03\x02\x02\x02\u{190}\u{191}\x03\x02\x02\x02\
+	\u{191}\u{192}\x05\x64\x33\x02\u{192}\u{1ae}\x03\x02\x02\x02\u{193}\u{195}\
+	\x05\u{ac}\x57\x02\u{194}\u{196}\x07\u{80}\x02\x02\u{195}\u{194}\x03\x02\
+	\x02\x02\u{195}\u{196}\x03\x02\x02\x02\u{196}\u{197}\x03\x02\x02\x02\u{197}\
+	\u{199}\x07\x05\x02\x02\u{198}\u{19a}\x07\u{80}\x02\x02\u{199}\u{198}\x03\
+	\x02\x02\x02\u{199}\u{19a}\x03\x02\x02\x02\u{19a}\u{19b}\x03\x02\x02\x02\
+	\u{19b}\u{19c}\x05\x64\x33\x02\u{19c}\u{1ae}\x03\x02\x02\x02\u{19d}\u{19f}\
+	\x05\u{ac}\x57\x02\u{19e}\u{1a0}\x07\u{80}\x02\x02\u{19f}\u{19e}\x03\x02\
+	\x02\x02\u{19f}\u{1a0}\x03\x02\x02\x02\u{1a0}\u{1a1}\x03\x02\x02\x02\u{1a1}\
+	\u{1a3}\x07\x06\x02\x02\u{1a2}\u{1a4}\x07\u{80}\x02\x02\u{1a3}\u{1a2}\x03\
+	\x02\x02\x02\u{1a3}\u{1a4}\x03\x02\x02\x02\u{1a4}\u{1a5}\x03\x02\x02\x02\
+	\u{1a5}\u{1a6}\x05\x64\x33\x02\u{1a6}\u{1ae}\x03\x02\x02\x02\u{1a7}\u{1a9}\
+	\x05\u{ac}\x57\x02\u{1a8}\u{1aa}\x07\u{80}\x02\x02\u{1a9}\u{1a8}\x03\x02\
+	\x02\x02\u{1a9}\u{1aa}\x03\x02\x02\x02\u{1aa}\u{1ab}\x03\x02\x02\x02\u{1ab}\
+	\u{1ac}\x05\x5a\x2e\x02\u{1ac}\u{1ae}\x03\x02\x02\x02\u{1ad}\u{189}\x03\
+	\x02\x02\x02\u{1ad}\u{193}\x03\x02\x02\x02\u{1ad}\u{19d}\x03\x02\x02\x02\
+	\u{1ad}\u{1a7}\x03\x02\x02\x02\u{1ae}\x23\x03\x02\x02\x02\u{1af}\u{1b0}\
+	\x07\x3b\x02\x02\u{1b0}\u{1b2}\x07\u{80}\x02\x02\u{1b1}\u{1af}\x03\x02\x02\
+	\x02\u{1b1}\u{1b2}\x03\x02\x02\x02\u{1b2}\u{1b3}\x03\x02\x02\x02\u{1b3}\
+	\u{1b5}\x07\x3c\x02\x02\u{1b4}\u{1b6}\x07\u{80}\x02\x02\u{1b5}\u{1b4}\x03\
+	\x02\x02\x02\u{1b5}\u{1b6}\x03\x02\x02\x02\u{1b6}\u{1b7}\x03\x02\x02\x02\
+	\u{1b7}\u{1c2}\x05\x64\x33\x02\u{1b8}\u{1ba}\x07\u{80}\x02\x02\u{1b9}\u{1b8}\
+	\x03\x02\x02\x02\u{1b9}\u{1ba}\x03\x02\x02\x02\u{1ba}\u{1bb}\x03\x02\x02\
+	\x02\u{1bb}\u{1bd}\x07\x04\x02\x02\u{1bc}\u{1be}\x07\u{80}\x02\x02\u{1bd}\
+	\u{1bc}\x03\x02\x02\x02\u{1bd}\u{1be}\x03\x02\x02\x02\u{1be}\u{1bf}\x03\
+	\x02\x02\x02\u{1bf}\u{1c1}\x05\x64\x33\x02\u{1c0}\u{1b9}\x03\x02\x02\x02\
+	\u{1c1}\u{1c4}\x03\x02\x02\x02\u{1c2}\u{1c0}\x03\x02\x02\x02\u{1c2}\u{1c3}\
+	\x03\x02\x02\x02\u{1c3}\x25\x03\x02\x02\x02\u{1c4}\u{1c2}\x03\x02\x02\x02\
+	\u{1c5}\u{1c6}\x07\x3d\x02\x02\u{1c6}\u{1c7}\x07\u{80}\x02\x02\u{1c7}\u{1d2}\
+	\x05\x28\x15\x02\u{1c8}\u{1ca}\x07\u{80}\x02\x02\u{1c9}\u{1c8}\x03\x02\x02\
+	\x02\u{1c9}\u{1ca}\x03\x02\x02\x02\u{1ca}\u{1cb}\x03\x02\x02\x02\u{1cb}\
+	\u{1cd}\x07\x04\x02\x02\u{1cc}\u{1ce}\x07\u{80}\x02\x02\u{1cd}\u{1cc}\x03\
+	\x02\x02\x02\u{1cd}\u{1ce}\x03\x02\x02\x02\u{1ce}\u{1cf}\x03\x02\x02\x02\
+	\u{1cf}\u{1d1}\x05\x28\x15\x02\u{1d0}\u{1c9}\x03\x02\x02\x02\u{1d1}\u{1d4}\
+	\x03\x02\x02\x02\u{1d2}\u{1d0}\x03\x02\x02\x02\u{1d2}\u{1d3}\x03\x02\x02\
+	\x02\u{1d3}\x27\x03\x02\x02\x02\u{1d4}\u{1d2}\x03\x02\x02\x02\u{1d5}\u{1d6}\
+	\x05\u{ac}\x57\x02\u{1d6}\u{1d7}\x05\x5a\x2e\x02\u{1d7}\u{1da}\x03\x02\x02\
+	\x02\u{1d8}\u{1da}\x05\u{b4}\x5b\x02\u{1d9}\u{1d5}\x03\x02\x02\x02\u{1d9}\
+	\u{1d8}\x03\x02\x02\x02\u{1da}\x29\x03\x02\x02\x02\u{1db}\u{1dc}\x07\x3e\
+	\x02\x02\u{1dc}\u{1dd}\x07\u{80}\x02\x02\u{1dd}\u{1e4}\x05\u{98}\x4d\x02\
+	\u{1de}\u{1e0}\x07\u{80}\x02\x02\u{1df}\u{1de}\x03\x02\x02\x02\u{1df}\u{1e0}\
+	\x03\x02\x02\x02\u{1e0}\u{1e1}\x03\x02\x02\x02\u{1e1}\u{1e2}\x07\x3f\x02\
+	\x02\u{1e2}\u{1e3}\x07\u{80}\x02\x02\u{1e3}\u{1e5}\x05\x2e\x18\x02\u{1e4}\
+	\u{1df}\x03\x02\x02\x02\u{1e4}\u{1e5}\x03\x02\x02\x02\u{1e5}\x2b\x03\x02\
+	\x02\x02\u{1e6}\u{1e7}\x07\x3e\x02\x02\u{1e7}\u{1ea}\x07\u{80}\x02\x02\u{1e8}\
+	\u{1eb}\x05\u{98}\x4d\x02\u{1e9}\u{1eb}\x05\u{9a}\x4e\x02\u{1ea}\u{1e8}\
+	\x03\x02\x02\x02\u{1ea}\u{1e9}\x03\x02\x02\x02\u{1eb}\u{1f0}\x03\x02\x02\
+	\x02\u{1ec}\u{1ed}\x07\u{80}\x02\x02\u{1ed}\u{1ee}\x07\x3f\x02\x02\u{1ee}\
+	\u{1ef}\x07\u{80}\x02\x02\u{1ef}\u{1f1}\x05\x2e\x18\x02\u{1f0}\u{1ec}\x03\
+	\x02\x02\x02\u{1f0}\u{1f1}\x03\x02\x02\x02\u{1f1}\x2d\x03\x02\x02\x02\u{1f2}\
+	\u{202}\x07\x07\x02\x02\u{1f3}\u{1fe}\x05\x30\x19\x02\u{1f4}\u{1f6}\x07\
+	\u{80}\x02\x02\u{1f5}\u{1f4}\x03\x02\x02\x02\u{1f5}\u{1f6}\x03\x02\x02\x02\
+	\u{1f6}\u{1f7}\x03\x02\x02\x02\u{1f7}\u{1f9}\x07\x04\x02\x02\u{1f8}\u{1fa}\
+	\x07\u{80}\x02\x02\u{1f9}\u{1f8}\x03\x02\x02\x02\u{1f9}\u{1fa}\x03\x02\x02\
+	\x02\u{1fa}\u{1fb}\x03\x02\x02\x02\u{1fb}\u{1fd}\x05\x30\x19\x02\u{1fc}\
+	\u{1f5}\x03\x02\x02\x02\u{1fd}\u{200}\x03\x02\x02\x02\u{1fe}\u{1fc}\x03\
+	\x02\x02\x02\u{1fe}\u{1ff}\x03\x02\x02\x02\u{1ff}\u{202}\x03\x02\x02\x02\
+	\u{200}\u{1fe}\x03\x02\x02\x02\u{201}\u{1f2}\x03\x02\x02\x02\u{201}\u{1f3}\
+	\x03\x02\x02\x02\u{202}\u{207}\x03\x02\x02\x02\u{203}\u{205}\x07\u{80}\x02\
+	\x02\u{204}\u{203}\x03\x02\x02\x02\u{204}\u{205}\x03\x02\x02\x02\u{205}\
+	\u{206}\x03\x02\x02\x02\u{206}\u{208}\x05\x44\x23\x02\u{207}\u{204}\x03\
+	\x02\x02\x02\u{207}\u{208}\x03\x02\x02\x02\u{208}\x2f\x03\x02\x02\x02\u{209}\
+	\u{20a}\x05\u{9c}\x4f\x02\u{20a}\u{20b}\x07\u{80}\x02\x02\u{20b}\u{20c}\
+	\x07\x36\x02\x02\u{20c}\u{20d}\x07\u{80}\x02\x02\u{20d}\u{20f}\x03\x02\x02\
+	\x02\u{20e}\u{209}\x03\x02\x02\x02\u{20e}\u{20f}\x03\x02\x02\x02\u{20f}\
+	\u{210}\x03\x02\x02\x02\u{210}\u{211}\x05\u{ac}\x57\x02\u{211}\x31\x03\x02\
+	\x02\x02\u{212}\u{213}\x07\x40\x02\x02\u{213}\u{218}\x05\x36\x1c\x02\u{214}\
+	\u{216}\x07\u{80}\x02\x02\u{215}\u{214}\x03\x02\x02\x02\u{215}\u{216}\x03\
+	\x02\x02\x02\u{216}\u{217}\x03\x02\x02\x02\u{217}\u{219}\x05\x44\x23\x02\
+	\u{218}\u{215}\x03\x02\x02\x02\u{218}\u{219}\x03\x02\x02\x02\u{219}\x33\
+	\x03\x02\x02\x02\u{21a}\u{21b}\x07\x41\x02\x02\u{21b}\u{21c}\x05\x36\x1c\
+	\x02\u{21c}\x35\x03\x02\x02\x02\u{21d}\u{21f}\x07\u{80}\x02\x02\u{21e}\u{21d}\
+	\x03\x02\x02\x02\u{21e}\u{21f}\x03\x02\x02\x02\u{21f}\u{220}\x03\x02\x02\
+	\x02\u{220}\u{222}\x07\x42\x02\x02\u{221}\u{21e}\x03\x02\x02\x02\u{221}\
+	\u{222}\x03\x02\x02\x02\u{222}\u{223}\x03\x02\x02\x02\u{223}\u{224}\x07\
+	\u{80}\x02\x02\u{224}\u{227}\x05\x38\x1d\x02\u{225}\u{226}\x07\u{80}\x02\
+	\x02\u{226}\u{228}\x05\x3c\x1f\x02\u{227}\u{225}\x03\x02\x02\x02\u{227}\
+	\u{228}\x03\x02\x02\x02\u{228}\u{22b}\x03\x02\x02\x02\u{229}\u{22a}\x07\
+	\u{80}\x02\x02\u{22a}\u{22c}\x05\x3e\x20\x02\u{22b}\u{229}\x03\x02\x02\x02\
+	\u{22b}\u{22c}\x03\x02\x02\x02\u{22c}\u{22f}\x03\x02\x02\x02\u{22d}\u{22e}\
+	\x07\u{80}\x02\x02\u{22e}\u{230}\x05\x40\x21\x02\u{22f}\u{22d}\x03\x02\x02\
+	\x02\u{22f}\u{230}\x03\x02\x02\x02\u{230}\x37\x03\x02\x02\x02\u{231}\u{23c}\
+	\x07\x07\x02\x02\u{232}\u{234}\x07\u{80}\x02\x02\u{233}\u{232}\x03\x02\x02\
+	\x02\u{233}\u{234}\x03\x02\x02\x02\u{234}\u{235}\x03\x02\x02\x02\u{235}\
+	\u{237}\x07\x04\x02\x02\u{236}\u{238}\x07\u{80}\x02\x02\u{237}\u{236}\x03\
+	\x02\x02\x02\u{237}\u{238}\x03\x02\x02\x02\u{238}\u{239}\x03\x02\x02\x02\
+	\u{239}\u{23b}\x05\x3a\x1e\x02\u{23a}\u{233}\x03\x02\x02\x02\u{23b}\u{23e}\
+	\x03\x02\x02\x02\u{23c}\u{23a}\x03\x02\x02\x02\u{23c}\u{23d}\x03\x02\x02\
+	\x02\u{23d}\u{24e}\x03\x02\x02\x02\u{23e}\u{23c}\x03\x02\x02\x02\u{23f}\
+	\u{24a}\x05\x3a\x1e\x02\u{240}\u{242}\x07\u{80}\x02\x02\u{241}\u{240}\x03\
+	\x02\x02\x02\u{241}\u{242}\x03\x02\x02\x02\u{242}\u{243}\x03\x02\x02\x02\
+	\u{243}\u{245}\x07\x04\x02\x02\u{244}\u{246}\x07\u{80}\x02\x02\u{245}\u{244}\
+	\x03\x02\x02\x02\u{245}\u{246}\x03\x02\x02\x02\u{246}\u{247}\x03\x02\x02\
+	\x02\u{247}\u{249}\x05\x3a\x1e\x02\u{248}\u{241}\x03\x02\x02\x02\u{249}\
+	\u{24c}\x03\x02\x02\x02\u{24a}\u{248}\x03\x02\x02\x02\u{24a}\u{24b}\x03\
+	\x02\x02\x02\u{24b}\u{24e}\x03\x02\x02\x02\u{24c}\u{24a}\x03\x02\x02\x02\
+	\u{24d}\u{231}\x03\x02\x02\x02\u{24d}\u{23f}\x03\x02\x02\x02\u{24e}\x39\
+	\x03\x02\x02\x02\u{24f}\u{250}\x05\x64\x33\x02\u{250}\u{251}\x07\u{80}\x02\
+	\x02\u{251}\u{252}\x07\x36\x02\x02\u{252}\u{253}\x07\u{80}\x02\x02\u{253}\
+	\u{254}\x05\u{ac}\x57\x02\u{254}\u{257}\x03\x02\x02\x02\u{255}\u{257}\x05\
+	\x64\x33\x02\u{256}\u{24f}\x03\x02\x02\x02\u{256}\u{255}\x03\x02\x02\x02\
+	\u{257}\x3b\x03\x02\x02\x02\u{258}\u{259}\x07\x43\x02\x02\u{259}\u{25a}\
+	\x07\u{80}\x02\x02\u{25a}\u{25b}\x07\x44\x02\x02\u{25b}\u{25c}\x07\u{80}\
+	\x02\x02\u{25c}\u{264}\x05\x42\x22\x02\u{25d}\u{25f}\x07\x04\x02\x02\u{25e}\
+	\u{260}\x07\u{80}\x02\x02\u{25f}\u{25e}\x03\x02\x02\x02\u{25f}\u{260}\x03\
+	\x02\x02\x02\u{260}\u{261}\x03\x02\x02\x02\u{261}\u{263}\x05\x42\x22\x02\
+	\u{262}\u{25d}\x03\x02\x02\x02\u{263}\u{266}\x03\x02\x02\x02\u{264}\u{262}\
+	\x03\x02\x02\x02\u{264}\u{265}\x03\x02\x02\x02\u{265}\x3d\x03\x02\x02\x02\
+	\u{266}\u{264}\x03\x02\x02\x02\u{267}\u{268}\x07\x45\x02\x02\u{268}\u{269}\
+	\x07\u{80}\x02\x02\u{269}\u{26a}\x05\x64\x33\x02\u{26a}\x3f\x03\x02\x02\
+	\x02\u{26b}\u{26c}\x07\x46\x02\x02\u{26c}\u{26d}\x07\u{80}\x02\x02\u{26d}\
 	\u{26e}\x05\x64\x33\x02\u{26e}\x41\x03\x02\x02\x02\u{26f}\u{274}\x05\x64\
-	\x33\x02\u{270}\u{272}\x07\x7f\x02\x02\u{271}\u{270}\x03\x02\x02\x02\u{271}\
+	\x33\x02\u{270}\u{272}\x07\u{80}\x02\x02\u{271}\u{270}\x03\x02\x02\x02\u{271}\
 	\u{272}\x03\x02\x02\x02\u{272}\u{273}\x03\x02\x02\x02\u{273}\u{275}\x09\
 	\x02\x02\x02\u{274}\u{271}\x03\x02\x02\x02\u{274}\u{275}\x03\x02\x02\x02\
-	\u{275}\x43\x03\x02\x02\x02\u{276}\u{277}\x07\x4a\x02\x02\u{277}\u{278}\
-	\x07\x7f\x02\x02\u{278}\u{279}\x05\x64\x33\x02\u{279}\x45\x03\x02\x02\x02\
-	\u{27a}\u{285}\x05\x48\x25\x02\u{27b}\u{27d}\x07\x7f\x02\x02\u{27c}\u{27b}\
-	\x03\x02\x02\x02\u{27c}\u{27d}\x03\x02\x02\x02\u{27d}\u{27e}\x03\x02\x02\
-	\x02\u{27e}\u{280}\x07\x04\x02\x02\u{27f}\u{281}\x07\x7f\x02\x02\u{280}\
-	\u{27f}\x03\x02\x02\x02\u{280}\u{281}\x03\x02\x02\x02\u{281}\u{282}\x03\
-	\x02\x02\x02\u{282}\u{284}\x05\x48\x25\x02\u{283}\u{27c}\x03\x02\x02\x02\
-	\u{284}\u{287}\x03\x02\x02\x02\u{285}\u{283}\x03\x02\x02\x02\u{285}\u{286}\
-	\x03\x02\x02\x02\u{286}\x47\x03\x02\x02\x02\u{287}\u{285}\x03\x02\x02\x02\
-	\u{288}\u{28a}\x05\u{ac}\x57\x02\u{289}\u{28b}\x07\x7f\x02\x02\u{28a}\u{289}\
-	\x03\x02\x02\x02\u{28a}\u{28b}\x03\x02\x02\x02\u{28b}\u{28c}\x03\x02\x02\
-	\x02\u{28c}\u{28e}\x07\x05\x02\x02\u{28d}\u{28f}\x07\x7f\x02\x02\u{28e}\
-	\u{28d}\x03\x02\x02\x02\u{28e}\u{28f}\x03\x02\x02\x02\u{28f}\u{290}\x03\
-	\x02\x02\x02\u{290}\u{291}\x05\x4a\x26\x02\u{291}\u{294}\x03\x02\x02\x02\
-	\u{292}\u{294}\x05\x4a\x26\x02\u{293}\u{288}\x03\x02\x02\x02\u{293}\u{292}\
-	\x03\x02\x02\x02\u{294}\x49\x03\x02\x02\x02\u{295}\u{296}\x05\x4c\x27\x02\
-	\u{296}\x4b\x03\x02\x02\x02\u{297}\u{29e}\x05\x4e\x28\x02\u{298}\u{29a}\
-	\x07\x7f\x02\x02\u{299}\u{298}\x03\x02\x02\x02\u{299}\u{29a}\x03\x02\x02\
-	\x02\u{29a}\u{29b}\x03\x02\x02\x02\u{29b}\u{29d}\x05\x50\x29\x02\u{29c}\
-	\u{299}\x03\x02\x02\x02\u{29d}\u{2a0}\x03\x02\x02\x02\u{29e}\u{29c}\x03\
-	\x02\x02\x02\u{29e}\u{29f}\x03\x02\x02\x02\u{29f}\u{2a6}\x03\x02\x02\x02\
-	\u{2a0}\u{29e}\x03\x02\x02\x02\u{2a1}\u{2a2}\x07\x08\x02\x02\u{2a2}\u{2a3}\
-	\x05\x4c\x27\x02\u{2a3}\u{2a4}\x07\x09\x02\x02\u{2a4}\u{2a6}\x03\x02\x02\
-	\x02\u{2a5}\u{297}\x03\x02\x02\x02\u{2a5}\u{2a1}\x03\x02\x02\x02\u{2a6}\
-	\x4d\x03\x02\x02\x02\u{2a7}\u{2a9}\x07\x08\x02\x02\u{2a8}\u{2aa}\x07\x7f\
-	\x02\x02\u{2a9}\u{2a8}\x03\x02\x02\x02\u{2a9}\u{2aa}\x03\x02\x02\x02\u{2aa}\
-	\u{2af}\x03\x02\x02\x02\u{2ab}\u{2ad}\x05\u{ac}\x57\x02\u{2ac}\u{2ae}\x07\
-	\x7f\x02\x02\u{2ad}\u{2ac}\x03\x02\x02\x02\u{2ad}\u{2ae}\x03\x02\x02\x02\
-	\u{2ae}\u{2b0}\x03\x02\x02\x02\u{2af}\u{2ab}\x03\x02\x02\x02\u{2af}\u{2b0}\
-	\x03\x02\x02\x02\u{2b0}\u{2b5}\x03\x02\x02\x02\u{2b1}\u{2b3}\x05\x5a\x2e\
-	\x02\u{2b2}\u{2b4}\x07\x7f\x02\x02\u{2b3}\u{2b2}\x03\x02\x02\x02\u{2b3}\
-	\u{2b4}\x03\x02\x02\x02\u{2b4}\u{2b6}\x03\x02\x02\x02\u{2b5}\u{2b1}\x03\
-	\x02\x02\x02\u{2b5}\u{2b6}\x03\x02\x02\x02\u{2b6}\u{2bb}\x03\x02\x02\x02\
-	\u{2b7}\u{2b9}\x05\x56\x2c\x02\u{2b8}\u{2ba}\x07\x7f\x02\x02\u{2b9}\u{2b8}\
-	\x03\x02\x02\x02\u{2b9}\u{2ba}\x03\x02\x02\x02\u{2ba}\u{2bc}\x03\x02\x02\
-	\x02\u{2bb}\u{2b7}\x03\x02\x02\x02\u{2bb}\u{2bc}\x03\x02\x02\x02\u{2bc}\
-	\u{2bd}\x03\x02\x02\x02\u{2bd}\u{2be}\x07\x09\x02\x02\u{2be}\x4f\x03\x02\
-	\x02\x02\u{2bf}\u{2c1}\x05\x52\x2a\x02\u{2c0}\u{2c2}\x07\x7f\x02\x02\u{2c1}\
-	\u{2c0}\x03\x02\x02\x02\u{2c1}\u{2c2}\x03\x02\x02\x02\u{2c2}\u{2c3}\x03\
-	\x02\x02\x02\u{2c3}\u{2c4}\x05\x4e\x28\x02\u{2c4}\x51\x03\x02\x02\x02\u{2c5}\
-	\u{2c7}\x05\u{c2}\x62\x02\u{2c6}\u{2c8}\x07\x7f\x02\x02\u{2c7}\u{2c6}\x03\
-	\x02\x02\x02\u{2c7}\u{2c8}\x03\x02\x02\x02\u{2c8}\u{2c9}\x03\x02\x02\x02\
-	\u{2c9}\u{2cb}\x05\u{c6}\x64\x02\u{2ca}\u{2cc}\x07\x7f\x02\x02\u{2cb}\u{2ca}\
-	\x03\x02\x02\x02\u{2cb}\u{2cc}\x03\x02\x02\x02\u{2cc}\u{2ce}\x03\x02\x02\
-	\x02\u{2cd}\u{2cf}\x05\x54\x2b\x02\u{2ce}\u{2cd}\x03\x02\x02\x02\u{2ce}\
-	\u{2cf}\x03\x02\x02\x02\u{2cf}\u{2d1}\x03\x02\x02\x02\u{2d0}\u{2d2}\x07\
-	\x7f\x02\x02\u{2d1}\u{2d0}\x03\x02\x02\x02\u{2d1}\u{2d2}\x03\x02\x02\x02\
-	\u{2d2}\u{2d3}\x03\x02\x02\x02\u{2d3}\u{2d5}\x05\u{c6}\x64\x02\u{2d4}\u{2d6}\
-	\x07\x7f\x02\x02\u{2d5}\u{2d4}\x03\x02\x02\x02\u{2d5}\u{2d6}\x03\x02\x02\
-	\x02\u{2d6}\u{2d7}\x03\x02\x02\x02\u{2d7}\u{2d8}\x05\u{c4}\x63\x02\u{2d8}\
-	\u{306}\x03\x02\x02\x02\u{2d9}\u{2db}\x05\u{c2}\x62\x02\u{2da}\u{2dc}\x07\
-	\x7f\x02\x02\u{2db}\u{2da}\x03\x02\x02\x02\u{2db}\u{2dc}\x03\x02\x02\x02\
-	\u{2dc}\u{2dd}\x03\x02\x02\x02\u{2dd}\u{2df}\x05\u{c6}\x64\x02\u{2de}\u{2e0}\
-	\x07\x7f\x02\x02\u{2df}\u{2de}\x03\x02\x02\x02\u{2df}\u{2e0}\x03\x02\x02\
-	\x02\u{2e0}\u{2e2}\x03\x02\x02\x02\u{2e1}\u{2e3}\x05\x54\x2b\x02\u{2e2}\
-	\u{2e1}\x03\x02\x02\x02\u{2e2}\u{2e3}\x03\x02\x02\x02\u{2e3}\u{2e5}\x03\
-	\x02\x02\x02\u{2e4}\u{2e6}\x07\x7f\x02\x02\u{2e5}\u{2e4}\x03\x02\x02\x02\
-	\u{2e5}\u{2e6}\x03\x02\x02\x02\u{2e6}\u{2e7}\x03\x02\x02\x02\u{2e7}\u{2e8}\
-	\x05\u{c6}\x64\x02\u{2e8}\u{306}\x03\x02\x02\x02\u{2e9}\u{2eb}\x05\u{c6}\
-	\x64\x02\u{2ea}\u{2ec}\x07\x7f\x02\x02\u{2eb}\u{2ea}\x03\x02\x02\x02\u{2eb}\
-	\u{2ec}\x03\x02\x02\x02\u{2ec}\u{2ee}\x03\x02\x02\x02\u{2ed}\u{2ef}\x05\
-	\x54\x2b\x02\u{2ee}\u{2ed}\x03\x02\x02\x02\u{2ee}\u{2ef}\x03\x02\x02\x02\
-	\u{2ef}\u{2f1}\x03\x02\x02\x02\u{2f0}\u{2f2}\x07\x7f\x02\x02\u{2f1}\u{2f0}\
-	\x03\x02\x02\x02\u{2f1}\u{2f2}\x03\x02\x02\x02\u{2f2}\u{2f3}\x03\x02\x02\
-	\x02\u{2f3}\u{2f5}\x05\u{c6}\x64\x02\u{2f4}\u{2f6}\x07\x7f\x02\x02\u{2f5}\
-	\u{2f4}\x03\x02\x02\x02\u{2f5}\u{2f6}\x03\x02\x02\x02\u{2f6}\u{2f7}\x03\
-	\x02\x02\x02\u{2f7}\u{2f8}\x05\u{c4}\x63\x02\u{2f8}\u{306}\x03\x02\x02\x02\
-	\u{2f9}\u{2fb}\x05\u{c6}\x64\x02\u{2fa}\u{2fc}\x07\x7f\x02\x02\u{2fb}\u{2fa}\
-	\x03\x02\x02\x02\u{2fb}\u{2fc}\x03\x02\x02\x02\u{2fc}\u{2fe}\x03\x02\x02\
-	\x02\u{2fd}\u{2ff}\x05\x54\x2b\x02\u{2fe}\u{2fd}\x03\x02\x02\x02\u{2fe}\
-	\u{2ff}\x03\x02\x02\x02\u{2ff}\u{301}\x03\x02\x02\x02\u{300}\u{302}\x07\
-	\x7f\x02\x02\u{301}\u{300}\x03\x02\x02\x02\u{301}\u{302}\x03\x02\x02\x02\
-	\u{302}\u{303}\x03\x02\x02\x02\u{303}\u{304}\x05\u{c6}\x64\x02\u{304}\u{306}\
-	\x03\x02\x02\x02\u{305}\u{2c5}\x03\x02\x02\x02\u{305}\u{2d9}\x03\x02\x02\
-	\x02\u{305}\u{2e9}\x03\x02\x02\x02\u{305}\u{2f9}\x03\x02\x02\x02\u{306}\
-	\x53\x03\x02\x02\x02\u{307}\u{309}\x07\x0a\x02\x02\u{308}\u{30a}\x07\x7f\
-	\x02\x02\u{309}\u{308}\x03\x02\x02\x02\u{309}\u{30a}\x03\x02\x02\x02\u{30a}\
-	\u{30f}\x03\x02\x02\x02\u{30b}\u{30d}\x05\u{ac}\x57\x02\u{30c}\u{30e}\x07\
-	\x7f\x02\x02\u{30d}\u{30c}\x03\x02\x02\x02\u{30d}\u{30e}\x03\x02\x02\x02\
-	\u{30e}\u{310}\x03\x02\x02\x02\u{30f}\u{30b}\x03\x02\x02\x02\u{30f}\u{310}\
-	\x03\x02\x02\x02\u{310}\u{315}\x03\x02\x02\x02\u{311}\u{313}\x05\x58\x2d\
-	\x02\u{312}\u{314}\x07\x7f\x02\x02\u{313}\u{312}\x03\x02\x02\x02\u{313}\
-	\u{314}\x03\x02\x02\x02\u{314}\u{316}\x03\x02\x02\x02\u{315}\u{311}\x03\
-	\x02\x02\x02\u{315}\u{316}\x03\x02\x02\x02\u{316}\u{318}\x03\x02\x02\x02\
-	\u{317}\u{319}\x05\x5e\x30\x02\u{318}\u{317}\x03\x02\x02\x02\u{318}\u{319}\
-	\x03\x02\x02\x02\u{319}\u{31e}\x03\x02\x02\x02\u{31a}\u{31c}\x05\x56\x2c\
-	\x02\u{31b}\u{31d}\x07\x7f\x02\x02\u{31c}\u{31b}\x03\x02\x02\x02\u{31c}\
-	\u{31d}\x03\x02\x02\x02\u{31d}\u{31f}\x03\x02\x02\x02\u{31e}\u{31a}\x03\
-	\x02\x02\x02\u{31e}\u{31f}\x03\x02\x02\x02\u{31f}\u{320}\x03\x02\x02\x02\
-	\u{320}\u{321}\x07\x0b\x02\x02\u{321}\x55\x03\x02\x02\x02\u{322}\u{325}\
-	\x05\u{b0}\x59\x02\u{323}\u{325}\x05\u{b2}\x5a\x02\u{324}\u{322}\x03\x02\
-	\x02\x02\u{324}\u{323}\x03\x02\x02\x02\u{325}\x57\x03\x02\x02\x02\u{326}\
-	\u{328}\x07\x0c\x02\x02\u{327}\u{329}\x07\x7f\x02\x02\u{328}\u{327}\x03\
-	\x02\x02\x02\u{328}\u{329}\x03\x02\x02\x02\u{329}\u{32a}\x03\x02\x02\x02\
-	\u{32a}\u{338}\x05\x62\x32\x02\u{32b}\u{32d}\x07\x7f\x02\x02\u{32c}\u{32b}\
-	\x03\x02\x02\x02\u{32c}\u{32d}\x03\x02\x02\x02\u{32d}\u{32e}\x03\x02\x02\
-	\x02\u{32e}\u{330}\x07\x0d\x02\x02\u{32f}\u{331}\x07\x0c\x02\x02\u{330}\
-	\u{32f}\x03\x02\x02\x02\u{330}\u{331}\x03\x02\x02\x02\u{331}\u{333}\x03\
-	\x02\x02\x02\u{332}\u{334}\x07\x7f\x02\x02\u{333}\u{332}\x03\x02\x02\x02\
-	\u{333}\u{334}\x03\x02\x02\x02\u{334}\u{335}\x03\x02\x02\x02\u{335}\u{337}\
-	\x05\x62\x32\x02\u{336}\u{32c}\x03\x02\x02\x02\u{337}\u{33a}\x03\x02\x02\
-	\x02\u{338}\u{336}\x03\x02\x02\x02\u{338}\u{339}\x03\x02\x02\x02\u{339}\
-	\x59\x03\x02\x02\x02\u{33a}\u{338}\x03\x02\x02\x02\u{33b}\u{342}\x05\x5c\
-	\x2f\x02\u{33c}\u{33e}\x07\x7f\x02\x02\u{33d}\u{33c}\x03\x02\x02\x02\u{33d}\
-	\u{33e}\x03\x02\x02\x02\u{33e}\u{33f}\x03\x02\x02\x02\u{33f}\u{341}\x05\
-	\x5c\x2f\x02\u{340}\u{33d}\x03\x02\x02\x02\u{341}\u{344}\x03\x02\x02\x02\
-	\u{342}\u{340}\x03\x02\x02\x02\u{342}\u{343}\x03\x02\x02\x02\u{343}\x5b\
-	\x03\x02\x02\x02\u{344}\u{342}\x03\x02\x02\x02\u{345}\u{347}\x07\x0c\x02\
-	\x02\u{346}\u{348}\x07\x7f\x02\x02\u{347}\u{346}\x03\x02\x02\x02\u{347}\
-	\u{348}\x03\x02\x02\x02\u{348}\u{349}\x03\x02\x02\x02\u{349}\u{34a}\x05\
-	\x60\x31\x02\u{34a}\x5d\x03\x02\x02\x02\u{34b}\u{34d}\x07\x07\x02\x02\u{34c}\
-	\u{34e}\x07\x7f\x02\x02\u{34d}\u{34c}\x03\x02\x02\x02\u{34d}\u{34e}\x03\
-	\x02\x02\x02\u{34e}\u{353}\x03\x02\x02\x02\u{34f}\u{351}\x05\u{b8}\x5d\x02\
-	\u{350}\u{352}\x07\x7f\x02\x02\u{351}\u{350}\x03\x02\x02\x02\u{351}\u{352}\
-	\x03\x02\x02\x02\u{352}\u{354}\x03\x02\x02\x02\u{353}\u{34f}\x03\x02\x02\
-	\x02\u{353}\u{354}\x03\x02\x02\x02\u{354}\u{35f}\x03\x02\x02\x02\u{355}\
-	\u{357}\x07\x0e\x02\x02\u{356}\u{358}\x07\x7f\x02\x02\u{357}\u{356}\x03\
-	\x02\x02\x02\u{357}\u{358}\x03\x02\x02\x02\u{358}\u{35d}\x03\x02\x02\x02\
-	\u{359}\u{35b}\x05\u{b8}\x5d\x02\u{35a}\u{35c}\x07\x7f\x02\x02\u{35b}\u{35a}\
-	\x03\x02\x02\x02\u{35b}\u{35c}\x03\x02\x02\x02\u{35c}\u{35e}\x03\x02\x02\
-	\x02\u{35d}\u{359}\x03\x02\x02\x02\u{35d}\u{35e}\x03\x02\x02\x02\u{35e}\
-	\u{360}\x03\x02\x02\x02\u{35f}\u{355}\x03\x02\x02\x02\u{35f}\u{360}\x03\
-	\x02\x02\x02\u{360}\x5f\x03\x02\x02\x02\u{361}\u{362}\x05\u{bc}\x5f\x02\
-	\u{362}\x61\x03\x02\x02\x02\u{363}\u{364}\x05\u{bc}\x5f\x02\u{364}\x63\x03\
-	\x02\x02\x02\u{365}\u{366}\x05\x66\x34\x02\u{366}\x65\x03\x02\x02\x02\u{367}\
-	\u{36e}\x05\x68\x35\x02\u{368}\u{369}\x07\x7f\x02\x02\u{369}\u{36a}\x07\
-	\x4b\x02\x02\u{36a}\u{36b}\x07\x7f\x02\x02\u{36b}\u{36d}\x05\x68\x35\x02\
-	\u{36c}\u{368}\x03\x02\x02\x02\u{36d}\u{370}\x03\x02\x02\x02\u{36e}\u{36c}\
-	\x03\x02\x02\x02\u{36e}\u{36f}\x03\x02\x02\x02\u{36f}\x67\x03\x02\x02\x02\
-	\u{370}\u{36e}\x03\x02\x02\x02\u{371}\u{378}\x05\x6a\x36\x02\u{372}\u{373}\
-	\x07\x7f\x02\x02\u{373}\u{374}\x07\x4c\x02\x02\u{374}\u{375}\x07\x7f\x02\
-	\x02\u{375}\u{377}\x05\x6a\x36\x02\u{376}\u{372}\x03\x02\x02\x02\u{377}\
-	\u{37a}\x03\x02\x02\x02\u{378}\u{376}\x03\x02\x02\x02\u{378}\u{379}\x03\
-	\x02\x02\x02\u{379}\x69\x03\x02\x02\x02\u{37a}\u{378}\x03\x02\x02\x02\u{37b}\
-	\u{382}\x05\x6c\x37\x02\u{37c}\u{37d}\x07\x7f\x02\x02\u{37d}\u{37e}\x07\
-	\x4d\x02\x02\u{37e}\u{37f}\x07\x7f\x02\x02\u{37f}\u{381}\x05\x6c\x37\x02\
-	\u{380}\u{37c}\x03\x02\x02\x02\u{381}\u{384}\x03\x02\x02\x02\u{382}\u{380}\
-	\x03\x02\x02\x02\u{382}\u{383}\x03\x02\x02\x02\u{383}\x6b\x03\x02\x02\x02\
-	\u{384}\u{382}\x03\x02\x02\x02\u{385}\u{387}\x07\x4e\x02\x02\u{386}\u{388}\
-	\x07\x7f\x02\x02\u{387}\u{386}\x03\x02\x02\x02\u{387}\u{388}\x03\x02\x02\
-	\x02\u{388}\u{38a}\x03\x02\x02\x02\u{389}\u{385}\x03\x02\x02\x02\u{38a}\
-	\u{38d}\x03\x02\x02\x02\u{38b}\u{389}\x03\x02\x02\x02\u{38b}\u{38c}\x03\
-	\x02\x02\x02\u{38c}\u{38e}\x03\x02\x02\x02\u{38d}\u{38b}\x03\x02\x02\x02\
-	\u{38e}\u{38f}\x05\x6e\x38\x02\u{38f}\x6d\x03\x02\x02\x02\u{390}\u{397}\
-	\x05\x70\x39\x02\u{391}\u{393}\x07\x7f\x02\x02\u{392}\u{391}\x03\x02\x02\
-	\x02\u{392}\u{393}\x03\x02\x02\x02\u{393}\u{394}\x03\x02\x02\x02\u{394}\
-	\u{396}\x05\u{8a}\x46\x02\u{395}\u{392}\x03\x02\x02\x02\u{396}\u{399}\x03\
-	\x02\x02\x02\u{397}\u{395}\x03\x02\x02\x02\u{397}\u{398}\x03\x02\x02\x02\
-	\u{398}\x6f\x03\x02\x02\x02\u{399}\u{397}\x03\x02\x02\x02\u{39a}\u{3ad}\
-	\x05\x72\x3a\x02\u{39b}\u{39d}\x07\x7f\x02\x02\u{39c}\u{39b}\x03\x02\x02\
-	\x02\u{39c}\u{39d}\x03\x02\x02\x02\u{39d}\u{39e}\x03\x02\x02\x02\u{39e}\
-	\u{3a0}\x07\x0f\x02\x02\u{39f}\u{3a1}\x07\x7f\x02\x02\u{3a0}\u{39f}\x03\
-	\x02\x02\x02\u{3a0}\u{3a1}\x03\x02\x02\x02\u{3a1}\u{3a2}\x03\x02\x02\x02\
-	\u{3a2}\u{3ac}\x05\x72\x3a\x02\u{3a3}\u{3a5}\x07\x7f\x02\x02\u{3a4}\u{3a3}\
-	\x03\x02\x02\x02\u{3a4}\u{3a5}\x03\x02\x02\x02\u{3a5}\u{3a6}\x03\x02\x02\
-	\x02\u{3a6}\u{3a8}\x07\x10\x02\x02\u{3a7}\u{3a9}\x07\x7f\x02\x02\u{3a8}\
-	\u{3a7}\x03\x02\x02\x02\u{3a8}\u{3a9}\x03\x02\x02\x02\u{3a9}\u{3aa}\x03\
-	\x02\x02\x02\u{3aa}\u{3ac}\x05\x72\x3a\x02\u{3ab}\u{39c}\x03\x02\x02\x02\
-	\u{3ab}\u{3a4}\x03\x02\x02\x02\u{3ac}\u{3af}\x03\x02\x02\x02\u{3ad}\u{3ab}\
-	\x03\x02\x02\x02\u{3ad}\u{3ae}\x03\x02\x02\x02\u{3ae}\x71\x03\x02\x02\x02\
-	\u{3af}\u{3ad}\x03\x02\x02\x02\u{3b0}\u{3cb}\x05\x74\x3b\x02\u{3b1}\u{3b3}\
-	\x07\x7f\x02\x02\u{3b2}\u{3b1}\x03\x02\x02\x02\u{3b2}\u{3b3}\x03\x02\x02\
-	\x02\u{3b3}\u{3b4}\x03\x02\x02\x02\u{3b4}\u{3b6}\x07\x07\x02\x02\u{3b5}\
-	\u{3b7}\x07\x7f\x02\x02\u{3b6}\u{3b5}\x03\x02\x02\x02\u{3b6}\u{3b7}\x03\
-	\x02\x02\x02\u{3b7}\u{3b8}\x03\x02\x02\x02\u{3b8}\u{3ca}\x05\x74\x3b\x02\
-	\u{3b9}\u{3bb}\x07\x7f\x02\x02\u{3ba}\u{3b9}\x03\x02\x02\x02\u{3ba}\u{3bb}\
-	\x03\x02\x02\x02\u{3bb}\u{3bc}\x03\x02\x02\x02\u{3bc}\u{3be}\x07\x11\x02\
-	\x02\u{3bd}\u{3bf}\x07\x7f\x02\x02\u{3be}\u{3bd}\x03\x02\x02\x02\u{3be}\
-	\u{3bf}\x03\x02\x02\x02\u{3bf}\u{3c0}\x03\x02\x02\x02\u{3c0}\u{3ca}\x05\
-	\x74\x3b\x02\u{3c1}\u{3c3}\x07\x7f\x02\x02\u{3c2}\u{3c1}\x03\x02\x02\x02\
-	\u{3c2}\u{3c3}\x03\x02\x02\x02\u{3c3}\u{3c4}\x03\x02\x02\x02\u{3c4}\u{3c6}\
-	\x07\x12\x02\x02\u{3c5}\u{3c7}\x07\x7f\x02\x02\u{3c6}\u{3c5}\x03\x02\x02\
-	\x02\u{3c6}\u{3c7}\x03\x02\x02\x02\u{3c7}\u{3c8}\x03\x02\x02\x02\u{3c8}\
-	\u{3ca}\x05\x74\x3b\x02\u{3c9}\u{3b2}\x03\x02\x02\x02\u{3c9}\u{3ba}\x03\
-	\x02\x02\x02\u{3c9}\u{3c2}\x03\x02\x02\x02\u{3ca}\u{3cd}\x03\x02\x02\x02\
-	\u{3cb}\u{3c9}\x03\x02\x02\x02\u{3cb}\u{3cc}\x03\x02\x02\x02\u{3cc}\x73\
-	\x03\x02\x02\x02\u{3cd}\u{3cb}\x03\x02\x02\x02\u{3ce}\u{3d9}\x05\x76\x3c\
-	\x02\u{3cf}\u{3d1}\x07\x7f\x02\x02\u{3d0}\u{3cf}\x03\x02\x02\x02\u{3d0}\
-	\u{3d1}\x03\x02\x02\x02\u{3d1}\u{3d2}\x03\x02\x02\x02\u{3d2}\u{3d4}\x07\
-	\x13\x02\x02\u{3d3}\u{3d5}\x07\x7f\x02\x02\u{3d4}\u{3d3}\x03\x02\x02\x02\
-	\u{3d4}\u{3d5}\x03\x02\x02\x02\u{3d5}\u{3d6}\x03\x02\x02\x02\u{3d6}\u{3d8}\
-	\x05\x76\x3c\x02\u{3d7}\u{3d0}\x03\x02\x02\x02\u{3d8}\u{3db}\x03\x02\x02\
-	\x02\u{3d9}\u{3d7}\x03\x02\x02\x02\u{3d9}\u{3da}\x03\x02\x02\x02\u{3da}\
-	\x75\x03\x02\x02\x02\u{3db}\u{3d9}\x03\x02\x02\x02\u{3dc}\u{3de}\x09\x03\
-	\x02\x02\u{3dd}\u{3df}\x07\x7f\x02\x02\u{3de}\u{3dd}\x03\x02\x02\x02\u{3de}\
-	\u{3df}\x03\x02\x02\x02\u{3df}\u{3e1}\x03\x02\x02\x02\u{3e0}\u{3dc}\x03\
-	\x02\x02\x02\u{3e1}\u{3e4}\x03\x02\x02\x02\u{3e2}\u{3e0}\x03\x02\x02\x02\
-	\u{3e2}\u{3e3}\x03\x02\x02\x02\u{3e3}\u{3e5}\x03\x02\x02\x02\u{3e4}\u{3e2}\
-	\x03\x02\x02\x02\u{3e5}\u{3e6}\x05\x78\x3d\x02\u{3e6}\x77\x03\x02\x02\x02\
-	\u{3e7}\u{3ed}\x05\u{80}\x41\x02\u{3e8}\u{3ec}\x05\x7c\x3f\x02\u{3e9}\u{3ec}\
-	\x05\x7a\x3e\x02\u{3ea}\u{3ec}\x05\x7e\x40\x02\u{3eb}\u{3e8}\x03\x02\x02\
-	\x02\u{3eb}\u{3e9}\x03\x02\x02\x02\u{3eb}\u{3ea}\x03\x02\x02\x02\u{3ec}\
-	\u{3ef}\x03\x02\x02\x02\u{3ed}\u{3eb}\x03\x02\x02\x02\u{3ed}\u{3ee}\x03\
-	\x02\x02\x02\u{3ee}\x79\x03\x02\x02\x02\u{3ef}\u{3ed}\x03\x02\x02\x02\u{3f0}\
-	\u{3f1}\x07\x7f\x02\x02\u{3f1}\u{3f3}\x07\x4f\x02\x02\u{3f2}\u{3f4}\x07\
-	\x7f\x02\x02\u{3f3}\u{3f2}\x03\x02\x02\x02\u{3f3}\u{3f4}\x03\x02\x02\x02\
-	\u{3f4}\u{3f5}\x03\x02\x02\x02\u{3f5}\u{40a}\x05\u{80}\x41\x02\u{3f6}\u{3f8}\
-	\x07\x7f\x02\x02\u{3f7}\u{3f6}\x03\x02\x02\x02\u{3f7}\u{3f8}\x03\x02\x02\
-	\x02\u{3f8}\u{3f9}\x03\x02\x02\x02\u{3f9}\u{3fa}\x07\x0a\x02\x02\u{3fa}\
-	\u{3fb}\x05\x64\x33\x02\u{3fb}\u{3fc}\x07\x0b\x02\x02\u{3fc}\u{40a}\x03\
-	\x02\x02\x02\u{3fd}\u{3ff}\x07\x7f\x02\x02\u{3fe}\u{3fd}\x03\x02\x02\x02\
-	\u{3fe}\u{3ff}\x03\x02\x02\x02\u{3ff}\u{400}\x03\x02\x02\x02\u{400}\u{402}\
-	\x07\x0a\x02\x02\u{401}\u{403}\x05\x64\x33\x02\u{402}\u{401}\x03\x02\x02\
-	\x02\u{402}\u{403}\x03\x02\x02\x02\u{403}\u{404}\x03\x02\x02\x02\u{404}\
-	\u{406}\x07\x0e\x02\x02\u{405}\u{407}\x05\x64\x33\x02\u{406}\u{405}\x03\
-	\x02\x02\x02\u{406}\u{407}\x03\x02\x02\x02\u{407}\u{408}\x03\x02\x02\x02\
-	\u{408}\u{40a}\x07\x0b\x02\x02\u{409}\u{3f0}\x03\x02\x02\x02\u{409}\u{3f7}\
-	\x03\x02\x02\x02\u{409}\u{3fe}\x03\x02\x02\x02\u{40a}\x7b\x03\x02\x02\x02\
-	\u{40b}\u{40c}\x07\x7f\x02\x02\u{40c}\u{40d}\x07\x50\x02\x02\u{40d}\u{40e}\
-	\x07\x7f\x02\x02\u{40e}\u{416}\x07\x3f\x02\x02\u{40f}\u{410}\x07\x7f\x02\
-	\x02\u{410}\u{411}\x07\x51\x02\x02\u{411}\u{412}\x07\x7f\x02\x02\u{412}\
-	\u{416}\x07\x3f\x02\x02\u{413}\u{414}\x07\x7f\x02\x02\u{414}\u{416}\x07\
-	\x52\x02\x02\u{415}\u{40b}\x03\x02\x02\x02\u{415}\u{40f}\x03\x02\x02\x02\
-	\u{415}\u{413}\x03\x02\x02\x02\u{416}\u{418}\x03\x02\x02\x02\u{417}\u{419}\
-	\x07\x7f\x02\x02\u{418}\u{417}\x03\x02\x02\x02\u{418}\u{419}\x03\x02\x02\
-	\x02\u{419}\u{41a}\x03\x02\x02\x02\u{41a}\u{41b}\x05\u{80}\x41\x02\u{41b}\
-	\x7d\x03\x02\x02\x02\u{41c}\u{41d}\x07\x7f\x02\x02\u{41d}\u{41e}\x07\x53\
-	\x02\x02\u{41e}\u{41f}\x07\x7f\x02\x02\u{41f}\u{427}\x07\x54\x02\x02\u{420}\
-	\u{421}\x07\x7f\x02\x02\u{421}\u{422}\x07\x53\x02\x02\u{422}\u{423}\x07\
-	\x7f\x02\x02\u{423}\u{424}\x07\x4e\x02\x02\u{424}\u{425}\x07\x7f\x02\x02\
-	\u{425}\u{427}\x07\x54\x02\x02\u{426}\u{41c}\x03\x02\x02\x02\u{426}\u{420}\
-	\x03\x02\x02\x02\u{427}\x7f\x03\x02\x02\x02\u{428}\u{42f}\x05\u{82}\x42\
-	\x02\u{429}\u{42b}\x07\x7f\x02\x02\u{42a}\u{429}\x03\x02\x02\x02\u{42a}\
-	\u{42b}\x03\x02\x02\x02\u{42b}\u{42c}\x03\x02\x02\x02\u{42c}\u{42e}\x05\
-	\u{a6}\x54\x02\u{42d}\u{42a}\x03\x02\x02\x02\u{42e}\u{431}\x03\x02\x02\x02\
-	\u{42f}\u{42d}\x03\x02\x02\x02\u{42f}\u{430}\x03\x02\x02\x02\u{430}\u{436}\
-	\x03\x02\x02\x02\u{431}\u{42f}\x03\x02\x02\x02\u{432}\u{434}\x07\x7f\x02\
-	\x02\u{433}\u{432}\x03\x02\x02\x02\u{433}\u{434}\x03\x02\x02\x02\u{434}\
-	\u{435}\x03\x02\x02\x02\u{435}\u{437}\x05\x5a\x2e\x02\u{436}\u{433}\x03\
-	\x02\x02\x02\u{436}\u{437}\x03\x02\x02\x02\u{437}\u{81}\x03\x02\x02\x02\
-	\u{438}\u{487}\x05\u{84}\x43\x02\u{439}\u{487}\x05\u{b2}\x5a\x02\u{43a}\
-	\u{487}\x05\u{a8}\x55\x02\u{43b}\u{43d}\x07\x55\x02\x02\u{43c}\u{43e}\x07\
-	\x7f\x02\x02\u{43d}\u{43c}\x03\x02\x02\x02\u{43d}\u{43e}\x03\x02\x02\x02\
-	\u{43e}\u{43f}\x03\x02\x02\x02\u{43f}\u{441}\x07\x08\x02\x02\u{440}\u{442}\
-	\x07\x7f\x02\x02\u{441}\u{440}\x03\x02\x02\x02\u{441}\u{442}\x03\x02\x02\
-	\x02\u{442}\u{443}\x03\x02\x02\x02\u{443}\u{445}\x07\x07\x02\x02\u{444}\
-	\u{446}\x07\x7f\x02\x02\u{445}\u{444}\x03\x02\x02\x02\u{445}\u{446}\x03\
-	\x02\x02\x02\u{446}\u{447}\x03\x02\x02\x02\u{447}\u{487}\x07\x09\x02\x02\
-	\u{448}\u{487}\x05\u{a2}\x52\x02\u{449}\u{487}\x05\u{a4}\x53\x02\u{44a}\
-	\u{44c}\x07\x31\x02\x02\u{44b}\u{44d}\x07\x7f\x02\x02\u{44c}\u{44b}\x03\
-	\x02\x02\x02\u{44c}\u{44d}\x03\x02\x02\x02\u{44d}\u{44e}\x03\x02\x02\x02\
-	\u{44e}\u{450}\x07\x08\x02\x02\u{44f}\u{451}\x07\x7f\x02\x02\u{450}\u{44f}\
-	\x03\x02\x02\x02\u{450}\u{451}\x03\x02\x02\x02\u{451}\u{452}\x03\x02\x02\
-	\x02\u{452}\u{454}\x05\u{90}\x49\x02\u{453}\u{455}\x07\x7f\x02\x02\u{454}\
-	\u{453}\x03\x02\x02\x02\u{454}\u{455}\x03\x02\x02\x02\u{455}\u{456}\x03\
-	\x02\x02\x02\u{456}\u{457}\x07\x09\x02\x02\u{457}\u{487}\x03\x02\x02\x02\
-	\u{458}\u{45a}\x07\x56\x02\x02\u{459}\u{45b}\x07\x7f\x02\x02\u{45a}\u{459}\
-	\x03\x02\x02\x02\u{45a}\u{45b}\x03\x02\x02\x02\u{45b}\u{45c}\x03\x02\x02\
-	\x02\u{45c}\u{45e}\x07\x08\x02\x02\u{45d}\u{45f}\x07\x7f\x02\x02\u{45e}\
-	\u{45d}\x03\x02\x02\x02\u{45e}\u{45f}\x03\x02\x02\x02\u{45f}\u{460}\x03\
-	\x02\x02\x02\u{460}\u{462}\x05\u{90}\x49\x02\u{461}\u{463}\x07\x7f\x02\x02\
-	\u{462}\u{461}\x03\x02\x02\x02\u{462}\u{463}\x03\x02\x02\x02\u{463}\u{464}\
-	\x03\x02\x02\x02\u{464}\u{465}\x07\x09\x02\x02\u{465}\u{487}\x03\x02\x02\
-	\x02\u{466}\u{468}\x07\x57\x02\x02\u{467}\u{469}\x07\x7f\x02\x02\u{468}\
-	\u{467}\x03\x02\x02\x02\u{468}\u{469}\x03\x02\x02\x02\u{469}\u{46a}\x03\
-	\x02\x02\x02\u{46a}\u{46c}\x07\x08\x02\x02\u{46b}\u{46d}\x07\x7f\x02\x02\
-	\u{46c}\u{46b}\x03\x02\x02\x02\u{46c}\u{46d}\x03\x02\x02\x02\u{46d}\u{46e}\
-	\x03\x02\x02\x02\u{46e}\u{470}\x05\u{90}\x49\x02\u{46f}\u{471}\x07\x7f\x02\
-	\x02\u{470}\u{46f}\x03\x02\x02\x02\u{470}\u{471}\x03\x02\x02\x02\u{471}\
-	\u{472}\x03\x02\x02\x02\u{472}\u{473}\x07\x09\x02\x02\u{473}\u{487}\x03\
-	\x02\x02\x02\u{474}\u{476}\x07\x58\x02\x02\u{475}\u{477}\x07\x7f\x02\x02\
-	\u{476}\u{475}\x03\x02\x02\x02\u{476}\u{477}\x03\x02\x02\x02\u{477}\u{478}\
-	\x03\x02\x02\x02\u{478}\u{47a}\x07\x08\x02\x02\u{479}\u{47b}\x07\x7f\x02\
-	\x02\u{47a}\u{479}\x03\x02\x02\x02\u{47a}\u{47b}\x03\x02\x02\x02\u{47b}\
-	\u{47c}\x03\x02\x02\x02\u{47c}\u{47e}\x05\u{90}\x49\x02\u{47d}\u{47f}\x07\
-	\x7f\x02\x02\u{47e}\u{47d}\x03\x02\x02\x02\u{47e}\u{47f}\x03\x02\x02\x02\
-	\u{47f}\u{480}\x03\x02\x02\x02\u{480}\u{481}\x07\x09\x02\x02\u{481}\u{487}\
-	\x03\x02\x02\x02\u{482}\u{487}\x05\u{8e}\x48\x02\u{483}\u{487}\x05\u{8c}\
-	\x47\x02\u{484}\u{487}\x05\u{94}\x4b\x02\u{485}\u{487}\x05\u{ac}\x57\x02\
-	\u{486}\u{438}\x03\x02\x02\x02\u{486}\u{439}\x03\x02\x02\x02\u{486}\u{43a}\
-	\x03\x02\x02\x02\u{486}\u{43b}\x03\x02\x02\x02\u{486}\u{448}\x03\x02\x02\
-	\x02\u{486}\u{449}\x03\x02\x02\x02\u{486}\u{44a}\x03\x02\x02\x02\u{486}\
-	\u{458}\x03\x02\x02\x02\u{486}\u{466}\x03\x02\x02\x02\u{486}\u{474}\x03\
-	\x02\x02\x02\u{486}\u{482}\x03\x02\x02\x02\u{486}\u{483}\x03\x02\x02\x02\
-	\u{486}\u{484}\x03\x02\x02\x02\u{486}\u{485}\x03\x02\x02\x02\u{487}\u{83}\
-	\x03\x02\x02\x02\u{488}\u{48f}\x05\u{ae}\x58\x02\u{489}\u{48f}\x07\x61\x02\
-	\x02\u{48a}\u{48f}\x05\u{86}\x44\x02\u{48b}\u{48f}\x07\x54\x02\x02\u{48c}\
-	\u{48f}\x05\u{b0}\x59\x02\u{48d}\u{48f}\x05\u{88}\x45\x02\u{48e}\u{488}\
-	\x03\x02\x02\x02\u{48e}\u{489}\x03\x02\x02\x02\u{48e}\u{48a}\x03\x02\x02\
-	\x02\u{48e}\u{48b}\x03\x02\x02\x02\u{48e}\u{48c}\x03\x02\x02\x02\u{48e}\
-	\u{48d}\x03\x02\x02\x02\u{48f}\u{85}\x03\x02\x02\x02\u{490}\u{491}\x09\x04\
-	\x02\x02\u{491}\u{87}\x03\x02\x02\x02\u{492}\u{494}\x07\x0a\x02\x02\u{493}\
-	\u{495}\x07\x7f\x02\x02\u{494}\u{493}\x03\x02\x02\x02\u{494}\u{495}\x03\
-	\x02\x02\x02\u{495}\u{4a7}\x03\x02\x02\x02\u{496}\u{498}\x05\x64\x33\x02\
-	\u{497}\u{499}\x07\x7f\x02\x02\u{498}\u{497}\x03\x02\x02\x02\u{498}\u{499}\
-	\x03\x02\x02\x02\u{499}\u{4a4}\x03\x02\x02\x02\u{49a}\u{49c}\x07\x04\x02\
-	\x02\u{49b}\u{49d}\x07\x7f\x02\x02\u{49c}\u{49b}\x03\x02\x02\x02\u{49c}\
-	\u{49d}\x03\x02\x02\x02\u{49d}\u{49e}\x03\x02\x02\x02\u{49e}\u{4a0}\x05\
-	\x64\x33\x02\u{49f}\u{4a1}\x07\x7f\x02\x02\u{4a0}\u{49f}\x03\x02\x02\x02\
-	\u{4a0}\u{4a1}\x03\x02\x02\x02\u{4a1}\u{4a3}\x03\x02\x02\x02\u{4a2}\u{49a}\
-	\x03\x02\x02\x02\u{4a3}\u{4a6}\x03\x02\x02\x02\u{4a4}\u{4a2}\x03\x02\x02\
-	\x02\u{4a4}\u{4a5}\x03\x02\x02\x02\u{4a5}\u{4a8}\x03\x02\x02\x02\u{4a6}\
-	\u{4a4}\x03\x02\x02\x02\u{4a7}\u{496}\x03\x02\x02\x02\u{4a7}\u{4a8}\x03\
-	\x02\x02\x02\u{4a8}\u{4a9}\x03\x02\x02\x02\u{4a9}\u{4aa}\x07\x0b\x02\x02\
-	\u{4aa}\u{89}\x03\x02\x02\x02\u{4ab}\u{4ad}\x07\x05\x02\x02\u{4ac}\u{4ae}\
-	\x07\x7f\x02\x02\u{4ad}\u{4ac}\x03\x02\x02\x02\u{4ad}\u{4ae}\x03\x02\x02\
-	\x02\u{4ae}\u{4af}\x03\x02\x02\x02\u{4af}\u{4ca}\x05\x70\x39\x02\u{4b0}\
-	\u{4b2}\x07\x14\x02\x02\u{4b1}\u{4b3}\x07\x7f\x02\x02\u{4b2}\u{4b1}\x03\
-	\x02\x02\x02\u{4b2}\u{4b3}\x03\x02\x02\x02\u{4b3}\u{4b4}\x03\x02\x02\x02\
-	\u{4b4}\u{4ca}\x05\x70\x39\x02\u{4b5}\u{4b7}\x07\x15\x02\x02\u{4b6}\u{4b8}\
-	\x07\x7f\x02\x02\u{4b7}\u{4b6}\x03\x02\x02\x02\u{4b7}\u{4b8}\x03\x02\x02\
-	\x02\u{4b8}\u{4b9}\x03\x02\x02\x02\u{4b9}\u{4ca}\x05\x70\x39\x02\u{4ba}\
-	\u{4bc}\x07\x16\x02\x02\u{4bb}\u{4bd}\x07\x7f\x02\x02\u{4bc}\u{4bb}\x03\
-	\x02\x02\x02\u{4bc}\u{4bd}\x03\x02\x02\x02\u{4bd}\u{4be}\x03\x02\x02\x02\
-	\u{4be}\u{4ca}\x05\x70\x39\x02\u{4bf}\u{4c1}\x07\x17\x02\x02\u{4c0}\u{4c2}\
-	\x07\x7f\x02\x02\u{4c1}\u{4c0}\x03\x02\x02\x02\u{4c1}\u{4c2}\x03\x02\x02\
-	\x02\u{4c2}\u{4c3}\x03\x02\x02\x02\u{4c3}\u{4ca}\x05\x70\x39\x02\u{4c4}\
-	\u{4c6}\x07\x18\x02\x02\u{4c5}\u{4c7}\x07\x7f\x02\x02\u{4c6}\u{4c5}\x03\
-	\x02\x02\x02\u{4c6}\u{4c7}\x03\x02\x02\x02\u{4c7}\u{4c8}\x03\x02\x02\x02\
-	\u{4c8}\u{4ca}\x05\x70\x39\x02\u{4c9}\u{4ab}\x03\x02\x02\x02\u{4c9}\u{4b0}\
-	\x03\x02\x02\x02\u{4c9}\u{4b5}\x03\x02\x02\x02\u{4c9}\u{4ba}\x03\x02\x02\
-	\x02\u{4c9}\u{4bf}\x03\x02\x02\x02\u{4c9}\u{4c4}\x03\x02\x02\x02\u{4ca}\
-	\u{8b}\x03\x02\x02\x02\u{4cb}\u{4cd}\x07\x08\x02\x02\u{4cc}\u{4ce}\x07\x7f\
-	\x02\x02\u{4cd}\u{4cc}\x03\x02\x02\x02\u{4cd}\u{4ce}\x03\x02\x02\x02\u{4ce}\
-	\u{4cf}\x03\x02\x02\x02\u{4cf}\u{4d1}\x05\x64\x33\x02\u{4d0}\u{4d2}\x07\
-	\x7f\x02\x02\u{4d1}\u{4d0}\x03\x02\x02\x02\u{4d1}\u{4d2}\x03\x02\x02\x02\
-	\u{4d2}\u{4d3}\x03\x02\x02\x02\u{4d3}\u{4d4}\x07\x09\x02\x02\u{4d4}\u{8d}\
-	\x03\x02\x02\x02\u{4d5}\u{4da}\x05\x4e\x28\x02\u{4d6}\u{4d8}\x07\x7f\x02\
-	\x02\u{4d7}\u{4d6}\x03\x02\x02\x02\u{4d7}\u{4d8}\x03\x02\x02\x02\u{4d8}\
-	\u{4d9}\x03\x02\x02\x02\u{4d9}\u{4db}\x05\x50\x29\x02\u{4da}\u{4d7}\x03\
-	\x02\x02\x02\u{4db}\u{4dc}\x03\x02\x02\x02\u{4dc}\u{4da}\x03\x02\x02\x02\
-	\u{4dc}\u{4dd}\x03\x02\x02\x02\u{4dd}\u{8f}\x03\x02\x02\x02\u{4de}\u{4e3}\
-	\x05\u{92}\x4a\x02\u{4df}\u{4e1}\x07\x7f\x02\x02\u{4e0}\u{4df}\x03\x02\x02\
-	\x02\u{4e0}\u{4e1}\x03\x02\x02\x02\u{4e1}\u{4e2}\x03\x02\x02\x02\u{4e2}\
-	\u{4e4}\x05\x44\x23\x02\u{4e3}\u{4e0}\x03\x02\x02\x02\u{4e3}\u{4e4}\x03\
-	\x02\x02\x02\u{4e4}\u{91}\x03\x02\x02\x02\u{4e5}\u{4e6}\x05\u{ac}\x57\x02\
-	\u{4e6}\u{4e7}\x07\x7f\x02\x02\u{4e7}\u{4e8}\x07\x4f\x02\x02\u{4e8}\u{4e9}\
-	\x07\x7f\x02\x02\u{4e9}\u{4ea}\x05\x64\x33\x02\u{4ea}\u{93}\x03\x02\x02\
-	\x02\u{4eb}\u{4ed}\x05\u{96}\x4c\x02\u{4ec}\u{4ee}\x07\x7f\x02\x02\u{4ed}\
-	\u{4ec}\x03\x02\x02\x02\u{4ed}\u{4ee}\x03\x02\x02\x02\u{4ee}\u{4ef}\x03\
-	\x02\x02\x02\u{4ef}\u{4f1}\x07\x08\x02\x02\u{4f0}\u{4f2}\x07\x7f\x02\x02\
-	\u{4f1}\u{4f0}\x03\x02\x02\x02\u{4f1}\u{4f2}\x03\x02\x02\x02\u{4f2}\u{4f7}\
-	\x03\x02\x02\x02\u{4f3}\u{4f5}\x07\x41\x02\x02\u{4f4}\u{4f6}\x07\x7f\x02\
-	\x02\u{4f5}\u{4f4}\x03\x02\x02\x02\u{4f5}\u{4f6}\x03\x02\x02\x02\u{4f6}\
-	\u{4f8}\x03\x02\x02\x02\u{4f7}\u{4f3}\x03\x02\x02\x02\u{4f7}\u{4f8}\x03\
-	\x02\x02\x02\u{4f8}\u{50a}\x03\x02\x02\x02\u{4f9}\u{4fb}\x05\x64\x33\x02\
-	\u{4fa}\u{4fc}\x07\x7f\x02\x02\u{4fb}\u{4fa}\x03\x02\x02\x02\u{4fb}\u{4fc}\
-	\x03\x02\x02\x02\u{4fc}\u{507}\x03\x02\x02\x02\u{4fd}\u{4ff}\x07\x04\x02\
-	\x02\u{4fe}\u{500}\x07\x7f\x02\x02\u{4ff}\u{4fe}\x03\x02\x02\x02\u{4ff}\
-	\u{500}\x03\x02\x02\x02\u{500}\u{501}\x03\x02\x02\x02\u{501}\u{503}\x05\
-	\x64\x33\x02\u{502}\u{504}\x07\x7f\x02\x02\u{503}\u{502}\x03\x02\x02\x02\
-	\u{503}\u{504}\x03\x02\x02\x02\u{504}\u{506}\x03\x02\x02\x02\u{505}\u{4fd}\
-	\x03\x02\x02\x02\u{506}\u{509}\x03\x02\x02\x02\u{507}\u{505}\x03\x02\x02\
-	\x02\u{507}\u{508}\x03\x02\x02\x02\u{508}\u{50b}\x03\x02\x02\x02\u{509}\
-	\u{507}\x03\x02\x02\x02\u{50a}\u{4f9}\x03\x02\x02\x02\u{50a}\u{50b}\x03\
-	\x02\x02\x02\u{50b}\u{50c}\x03\x02\x02\x02\u{50c}\u{50d}\x07\x09\x02\x02\
-	\u{50d}\u{95}\x03\x02\x02\x02\u{50e}\u{50f}\x05\u{a0}\x51\x02\u{50f}\u{510}\
-	\x05\u{c0}\x61\x02\u{510}\u{513}\x03\x02\x02\x02\u{511}\u{513}\x07\x5b\x02\
-	\x02\u{512}\u{50e}\x03\x02\x02\x02\u{512}\u{511}\x03\x02\x02\x02\u{513}\
-	\u{97}\x03\x02\x02\x02\u{514}\u{516}\x05\u{9e}\x50\x02\u{515}\u{517}\x07\
-	\x7f\x02\x02\u{516}\u{515}\x03\x02\x02\x02\u{516}\u{517}\x03\x02\x02\x02\
-	\u{517}\u{518}\x03\x02\x02\x02\u{518}\u{51a}\x07\x08\x02\x02\u{519}\u{51b}\
-	\x07\x7f\x02\x02\u{51a}\u{519}\x03\x02\x02\x02\u{51a}\u{51b}\x03\x02\x02\
-	\x02\u{51b}\u{52d}\x03\x02\x02\x02\u{51c}\u{51e}\x05\x64\x33\x02\u{51d}\
-	\u{51f}\x07\x7f\x02\x02\u{51e}\u{51d}\x03\x02\x02\x02\u{51e}\u{51f}\x03\
-	\x02\x02\x02\u{51f}\u{52a}\x03\x02\x02\x02\u{520}\u{522}\x07\x04\x02\x02\
-	\u{521}\u{523}\x07\x7f\x02\x02\u{522}\u{521}\x03\x02\x02\x02\u{522}\u{523}\
-	\x03\x02\x02\x02\u{523}\u{524}\x03\x02\x02\x02\u{524}\u{526}\x05\x64\x33\
-	\x02\u{525}\u{527}\x07\x7f\x02\x02\u{526}\u{525}\x03\x02\x02\x02\u{526}\
-	\u{527}\x03\x02\x02\x02\u{527}\u{529}\x03\x02\x02\x02\u{528}\u{520}\x03\
-	\x02\x02\x02\u{529}\u{52c}\x03\x02\x02\x02\u{52a}\u{528}\x03\x02\x02\x02\
-	\u{52a}\u{52b}\x03\x02\x02\x02\u{52b}\u{52e}\x03\x02\x02\x02\u{52c}\u{52a}\
-	\x03\x02\x02\x02\u{52d}\u{51c}\x03\x02\x02\x02\u{52d}\u{52e}\x03\x02\x02\
-	\x02\u{52e}\u{52f}\x03\x02\x02\x02\u{52f}\u{530}\x07\x09\x02\x02\u{530}\
-	\u{99}\x03\x02\x02\x02\u{531}\u{532}\x05\u{9e}\x50\x02\u{532}\u{9b}\x03\
-	\x02\x02\x02\u{533}\u{534}\x05\u{c0}\x61\x02\u{534}\u{9d}\x03\x02\x02\x02\
-	\u{535}\u{536}\x05\u{a0}\x51\x02\u{536}\u{537}\x05\u{c0}\x61\x02\u{537}\
-	\u{9f}\x03\x02\x02\x02\u{538}\u{539}\x05\u{c0}\x61\x02\u{539}\u{53a}\x07\
-	\x19\x02\x02\u{53a}\u{53c}\x03\x02\x02\x02\u{53b}\u{538}\x03\x02\x02\x02\
-	\u{53c}\u{53f}\x03\x02\x02\x02\u{53d}\u{53b}\x03\x02\x02\x02\u{53d}\u{53e}\
-	\x03\x02\x02\x02\u{53e}\u{a1}\x03\x02\x02\x02\u{53f}\u{53d}\x03\x02\x02\
-	\x02\u{540}\u{542}\x07\x0a\x02\x02\u{541}\u{543}\x07\x7f\x02\x02\u{542}\
-	\u{541}\x03\x02\x02\x02\u{542}\u{543}\x03\x02\x02\x02\u{543}\u{544}\x03\
-	\x02\x02\x02\u{544}\u{54d}\x05\u{90}\x49\x02\u{545}\u{547}\x07\x7f\x02\x02\
-	\u{546}\u{545}\x03\x02\x02\x02\u{546}\u{547}\x03\x02\x02\x02\u{547}\u{548}\
-	\x03\x02\x02\x02\u{548}\u{54a}\x07\x0d\x02\x02\u{549}\u{54b}\x07\x7f\x02\
-	\x02\u{54a}\u{549}\x03\x02\x02\x02\u{54a}\u{54b}\x03\x02\x02\x02\u{54b}\
-	\u{54c}\x03\x02\x02\x02\u{54c}\u{54e}\x05\x64\x33\x02\u{54d}\u{546}\x03\
-	\x02\x02\x02\u{54d}\u{54e}\x03\x02\x02\x02\u{54e}\u{550}\x03\x02\x02\x02\
-	\u{54f}\u{551}\x07\x7f\x02\x02\u{550}\u{54f}\x03\x02\x02\x02\u{550}\u{551}\
-	\x03\x02\x02\x02\u{551}\u{552}\x03\x02\x02\x02\u{552}\u{553}\x07\x0b\x02\
-	\x02\u{553}\u{a3}\x03\x02\x02\x02\u{554}\u{556}\x07\x0a\x02\x02\u{555}\u{557}\
-	\x07\x7f\x02\x02\u{556}\u{555}\x03\x02\x02\x02\u{556}\u{557}\x03\x02\x02\
-	\x02\u{557}\u{560}\x03\x02\x02\x02\u{558}\u{55a}\x05\u{ac}\x57\x02\u{559}\
-	\u{55b}\x07\x7f\x02\x02\u{55a}\u{559}\x03\x02\x02\x02\u{55a}\u{55b}\x03\
-	\x02\x02\x02\u{55b}\u{55c}\x03\x02\x02\x02\u{55c}\u{55e}\x07\x05\x02\x02\
-	\u{55d}\u{55f}\x07\x7f\x02\x02\u{55e}\u{55d}\x03\x02\x02\x02\u{55e}\u{55f}\
-	\x03\x02\x02\x02\u{55f}\u{561}\x03\x02\x02\x02\u{560}\u{558}\x03\x02\x02\
-	\x02\u{560}\u{561}\x03\x02\x02\x02\u{561}\u{562}\x03\x02\x02\x02\u{562}\
-	\u{564}\x05\u{8e}\x48\x02\u{563}\u{565}\x07\x7f\x02\x02\u{564}\u{563}\x03\
-	\x02\x02\x02\u{564}\u{565}\x03\x02\x02\x02\u{565}\u{56e}\x03\x02\x02\x02\
-	\u{566}\u{568}\x07\x4a\x02\x02\u{567}\u{569}\x07\x7f\x02\x02\u{568}\u{567}\
-	\x03\x02\x02\x02\u{568}\u{569}\x03\x02\x02\x02\u{569}\u{56a}\x03\x02\x02\
-	\x02\u{56a}\u{56c}\x05\x64\x33\x02\u{56b}\u{56d}\x07\x7f\x02\x02\u{56c}\
-	\u{56b}\x03\x02\x02\x02\u{56c}\u{56d}\x03\x02\x02\x02\u{56d}\u{56f}\x03\
-	\x02\x02\x02\u{56e}\u{566}\x03\x02\x02\x02\u{56e}\u{56f}\x03\x02\x02\x02\
-	\u{56f}\u{570}\x03\x02\x02\x02\u{570}\u{572}\x07\x0d\x02\x02\u{571}\u{573}\
-	\x07\x7f\x02\x02\u{572}\u{571}\x03\x02\x02\x02\u{572}\u{573}\x03\x02\x02\
-	\x02\u{573}\u{574}\x03\x02\x02\x02\u{574}\u{576}\x05\x64\x33\x02\u{575}\
-	\u{577}\x07\x7f\x02\x02\u{576}\u{575}\x03\x02\x02\x02\u{576}\u{577}\x03\
-	\x02\x02\x02\u{577}\u{578}\x03\x02\x02\x02\u{578}\u{579}\x07\x0b\x02\x02\
-	\u{579}\u{a5}\x03\x02\x02\x02\u{57a}\u{57c}\x07\x19\x02\x02\u{57b}\u{57d}\
-	\x07\x7f\x02\x02\u{57c}\u{57b}\x03\x02\x02\x02\u{57c}\u{57d}\x03\x02\x02\
-	\x02\u{57d}\u{57e}\x03\x02\x02\x02\u{57e}\u{57f}\x05\u{b6}\x5c\x02\u{57f}\
-	\u{a7}\x03\x02\x02\x02\u{580}\u{585}\x07\x5c\x02\x02\u{581}\u{583}\x07\x7f\
-	\x02\x02\u{582}\u{581}\x03\x02\x02\x02\u{582}\u{583}\x03\x02\x02\x02\u{583}\
-	\u{584}\x03\x02\x02\x02\u{584}\u{586}\x05\u{aa}\x56\x02\u{585}\u{582}\x03\
-	\x02\x02\x02\u{586}\u{587}\x03\x02\x02\x02\u{587}\u{585}\x03\x02\x02\x02\
-	\u{587}\u{588}\x03\x02\x02\x02\u{588}\u{597}\x03\x02\x02\x02\u{589}\u{58b}\
-	\x07\x5c\x02\x02\u{58a}\u{58c}\x07\x7f\x02\x02\u{58b}\u{58a}\x03\x02\x02\
-	\x02\u{58b}\u{58c}\x03\x02\x02\x02\u{58c}\u{58d}\x03\x02\x02\x02\u{58d}\
-	\u{592}\x05\x64\x33\x02\u{58e}\u{590}\x07\x7f\x02\x02\u{58f}\u{58e}\x03\
-	\x02\x02\x02\u{58f}\u{590}\x03\x02\x02\x02\u{590}\u{591}\x03\x02\x02\x02\
-	\u{591}\u{593}\x05\u{aa}\x56\x02\u{592}\u{58f}\x03\x02\x02\x02\u{593}\u{594}\
-	\x03\x02\x02\x02\u{594}\u{592}\x03\x02\x02\x02\u{594}\u{595}\x03\x02\x02\
-	\x02\u{595}\u{597}\x03\x02\x02\x02\u{596}\u{580}\x03\x02\x02\x02\u{596}\
-	\u{589}\x03\x02\x02\x02\u{597}\u{5a0}\x03\x02\x02\x02\u{598}\u{59a}\x07\
-	\x7f\x02\x02\u{599}\u{598}\x03\x02\x02\x02\u{599}\u{59a}\x03\x02\x02\x02\
-	\u{59a}\u{59b}\x03\x02\x02\x02\u{59b}\u{59d}\x07\x5d\x02\x02\u{59c}\u{59e}\
-	\x07\x7f\x02\x02\u{59d}\u{59c}\x03\x02\x02\x02\u{59d}\u{59e}\x03\x02\x02\
-	\x02\u{59e}\u{59f}\x03\x02\x02\x02\u{59f}\u{5a1}\x05\x64\x33\x02\u{5a0}\
-	\u{599}\x03\x02\x02\x02\u{5a0}\u{5a1}\x03\x02\x02\x02\u{5a1}\u{5a3}\x03\
-	\x02\x02\x02\u{5a2}\u{5a4}\x07\x7f\x02\x02\u{5a3}\u{5a2}\x03\x02\x02\x02\
-	\u{5a3}\u{5a4}\x03\x02\x02\x02\u{5a4}\u{5a5}\x03\x02\x02\x02\u{5a5}\u{5a6}\
-	\x07\x5e\x02\x02\u{5a6}\u{a9}\x03\x02\x02\x02\u{5a7}\u{5a9}\x07\x5f\x02\
-	\x02\u{5a8}\u{5aa}\x07\x7f\x02\x02\u{5a9}\u{5a8}\x03\x02\x02\x02\u{5a9}\
-	\u{5aa}\x03\x02\x02\x02\u{5aa}\u{5ab}\x03\x02\x02\x02\u{5ab}\u{5ad}\x05\
-	\x64\x33\x02\u{5ac}\u{5ae}\x07\x7f\x02\x02\u{5ad}\u{5ac}\x03\x02\x02\x02\
-	\u{5ad}\u{5ae}\x03\x02\x02\x02\u{5ae}\u{5af}\x03\x02\x02\x02\u{5af}\u{5b1}\
-	\x07\x60\x02\x02\u{5b0}\u{5b2}\x07\x7f\x02\x02\u{5b1}\u{5b0}\x03\x02\x02\
-	\x02\u{5b1}\u{5b2}\x03\x02\x02\x02\u{5b2}\u{5b3}\x03\x02\x02\x02\u{5b3}\
-	\u{5b4}\x05\x64\x33\x02\u{5b4}\u{ab}\x03\x02\x02\x02\u{5b5}\u{5b6}\x05\u{c0}\
-	\x61\x02\u{5b6}\u{ad}\x03\x02\x02\x02\u{5b7}\u{5ba}\x05\u{ba}\x5e\x02\u{5b8}\
-	\u{5ba}\x05\u{b8}\x5d\x02\u{5b9}\u{5b7}\x03\x02\x02\x02\u{5b9}\u{5b8}\x03\
-	\x02\x02\x02\u{5ba}\u{af}\x03\x02\x02\x02\u{5bb}\u{5bd}\x07\x1a\x02\x02\
-	\u{5bc}\u{5be}\x07\x7f\x02\x02\u{5bd}\u{5bc}\x03\x02\x02\x02\u{5bd}\u{5be}\
-	\x03\x02\x02\x02\u{5be}\u{5e0}\x03\x02\x02\x02\u{5bf}\u{5c1}\x05\u{b6}\x5c\
-	\x02\u{5c0}\u{5c2}\x07\x7f\x02\x02\u{5c1}\u{5c0}\x03\x02\x02\x02\u{5c1}\
-	\u{5c2}\x03\x02\x02\x02\u{5c2}\u{5c3}\x03\x02\x02\x02\u{5c3}\u{5c5}\x07\
-	\x0c\x02\x02\u{5c4}\u{5c6}\x07\x7f\x02\x02\u{5c5}\u{5c4}\x03\x02\x02\x02\
-	\u{5c5}\u{5c6}\x03\x02\x02\x02\u{5c6}\u{5c7}\x03\x02\x02\x02\u{5c7}\u{5c9}\
-	\x05\x64\x33\x02\u{5c8}\u{5ca}\x07\x7f\x02\x02\u{5c9}\u{5c8}\x03\x02\x02\
-	\x02\u{5c9}\u{5ca}\x03\x02\x02\x02\u{5ca}\u{5dd}\x03\x02\x02\x02\u{5cb}\
-	\u{5cd}\x07\x04\x02\x02\u{5cc}\u{5ce}\x07\x7f\x02\x02\u{5cd}\u{5cc}\x03\
-	\x02\x02\x02\u{5cd}\u{5ce}\x03\x02\x02\x02\u{5ce}\u{5cf}\x03\x02\x02\x02\
-	\u{5cf}\u{5d1}\x05\u{b6}\x5c\x02\u{5d0}\u{5d2}\x07\x7f\x02\x02\u{5d1}\u{5d0}\
-	\x03\x02\x02\x02\u{5d1}\u{5d2}\x03\x02\x02\x02\u{5d2}\u{5d3}\x03\x02\x02\
-	\x02\u{5d3}\u{5d5}\x07\x0c\x02\x02\u{5d4}\u{5d6}\x07\x7f\x02\x02\u{5d5}\
-	\u{5d4}\x03\x02\x02\x02\u{5d5}\u{5d6}\x03\x02\x02\x02\u{5d6}\u{5d7}\x03\
-	\x02\x02\x02\u{5d7}\u{5d9}\x05\x64\x33\x02\u{5d8}\u{5da}\x07\x7f\x02\x02\
-	\u{5d9}\u{5d8}\x03\x02\x02\x02\u{5d9}\u{5da}\x03\x02\x02\x02\u{5da}\u{5dc}\
-	\x03\x02\x02\x02\u{5db}\u{5cb}\x03\x02\x02\x02\u{5dc}\u{5df}\x03\x02\x02\
-	\x02\u{5dd}\u{5db}\x03\x02\x02\x02\u{5dd}\u{5de}\x03\x02\x02\x02\u{5de}\
-	\u{5e1}\x03\x02\x02\x02\u{5df}\u{5dd}\x03\x02\x02\x02\u{5e0}\u{5bf}\x03\
-	\x02\x02\x02\u{5e0}\u{5e1}\x03\x02\x02\x02\u{5e1}\u{5e2}\x03\x02\x02\x02\
-	\u{5e2}\u{5e3}\x07\x1b\x02\x02\u{5e3}\u{b1}\x03\x02\x02\x02\u{5e4}\u{5e7}\
-	\x07\x1c\x02\x02\u{5e5}\u{5e8}\x05\u{c0}\x61\x02\u{5e6}\u{5e8}\x07\x64\x02\
-	\x02\u{5e7}\u{5e5}\x03\x02\x02\x02\u{5e7}\u{5e6}\x03\x02\x02\x02\u{5e8}\
-	\u{b3}\x03\x02\x02\x02\u{5e9}\u{5ee}\x05\u{82}\x42\x02\u{5ea}\u{5ec}\x07\
-	\x7f\x02\x02\u{5eb}\u{5ea}\x03\x02\x02\x02\u{5eb}\u{5ec}\x03\x02\x02\x02\
-	\u{5ec}\u{5ed}\x03\x02\x02\x02\u{5ed}\u{5ef}\x05\u{a6}\x54\x02\u{5ee}\u{5eb}\
-	\x03\x02\x02\x02\u{5ef}\u{5f0}\x03\x02\x02\x02\u{5f0}\u{5ee}\x03\x02\x02\
-	\x02\u{5f0}\u{5f1}\x03\x02\x02\x02\u{5f1}\u{b5}\x03\x02\x02\x02\u{5f2}\u{5f3}\
-	\x05\u{bc}\x5f\x02\u{5f3}\u{b7}\x03\x02\x02\x02\u{5f4}\u{5f5}\x09\x05\x02\
-	\x02\u{5f5}\u{b9}\x03\x02\x02\x02\u{5f6}\u{5f7}\x09\x06\x02\x02\u{5f7}\u{bb}\
-	\x03\x02\x02\x02\u{5f8}\u{5fb}\x05\u{c0}\x61\x02\u{5f9}\u{5fb}\x05\u{be}\
-	\x60\x02\u{5fa}\u{5f8}\x03\x02\x02\x02\u{5fa}\u{5f9}\x03\x02\x02\x02\u{5fb}\
-	\u{bd}\x03\x02\x02\x02\u{5fc}\u{5fd}\x09\x07\x02\x02\u{5fd}\u{bf}\x03\x02\
-	\x02\x02\u{5fe}\u{5ff}\x09\x08\x02\x02\u{5ff}\u{c1}\x03\x02\x02\x02\u{600}\
-	\u{601}\x09\x09\x02\x02\u{601}\u{c3}\x03\x02\x02\x02\u{602}\u{603}\x09\x0a\
-	\x02\x02\u{603}\u{c5}\x03\x02\x02\x02\u{604}\u{605}\x09\x0b\x02\x02\u{605}\
-	\u{c7}\x03\x02\x02\x02\u{11d}\u{c9}\u{cd}\u{d0}\u{d3}\u{db}\u{df}\u{e4}\
+	\u{275}\x43\x03\x02\x02\x02\u{276}\u{277}\x07\x4b\x02\x02\u{277}\u{278}\
+	\x07\u{80}\x02\x02\u{278}\u{279}\x05\x64\x33\x02\u{279}\x45\x03\x02\x02\
+	\x02\u{27a}\u{285}\x05\x48\x25\x02\u{27b}\u{27d}\x07\u{80}\x02\x02\u{27c}\
+	\u{27b}\x03\x02\x02\x02\u{27c}\u{27d}\x03\x02\x02\x02\u{27d}\u{27e}\x03\
+	\x02\x02\x02\u{27e}\u{280}\x07\x04\x02\x02\u{27f}\u{281}\x07\u{80}\x02\x02\
+	\u{280}\u{27f}\x03\x02\x02\x02\u{280}\u{281}\x03\x02\x02\x02\u{281}\u{282}\
+	\x03\x02\x02\x02\u{282}\u{284}\x05\x48\x25\x02\u{283}\u{27c}\x03\x02\x02\
+	\x02\u{284}\u{287}\x03\x02\x02\x02\u{285}\u{283}\x03\x02\x02\x02\u{285}\
+	\u{286}\x03\x02\x02\x02\u{286}\x47\x03\x02\x02\x02\u{287}\u{285}\x03\x02\
+	\x02\x02\u{288}\u{28a}\x05\u{ac}\x57\x02\u{289}\u{28b}\x07\u{80}\x02\x02\
+	\u{28a}\u{289}\x03\x02\x02\x02\u{28a}\u{28b}\x03\x02\x02\x02\u{28b}\u{28c}\
+	\x03\x02\x02\x02\u{28c}\u{28e}\x07\x05\x02\x02\u{28d}\u{28f}\x07\u{80}\x02\
+	\x02\u{28e}\u{28d}\x03\x02\x02\x02\u{28e}\u{28f}\x03\x02\x02\x02\u{28f}\
+	\u{290}\x03\x02\x02\x02\u{290}\u{291}\x05\x4a\x26\x02\u{291}\u{294}\x03\
+	\x02\x02\x02\u{292}\u{294}\x05\x4a\x26\x02\u{293}\u{288}\x03\x02\x02\x02\
+	\u{293}\u{292}\x03\x02\x02\x02\u{294}\x49\x03\x02\x02\x02\u{295}\u{296}\
+	\x05\x4c\x27\x02\u{296}\x4b\x03\x02\x02\x02\u{297}\u{29e}\x05\x4e\x28\x02\
+	\u{298}\u{29a}\x07\u{80}\x02\x02\u{299}\u{298}\x03\x02\x02\x02\u{299}\u{29a}\
+	\x03\x02\x02\x02\u{29a}\u{29b}\x03\x02\x02\x02\u{29b}\u{29d}\x05\x50\x29\
+	\x02\u{29c}\u{299}\x03\x02\x02\x02\u{29d}\u{2a0}\x03\x02\x02\x02\u{29e}\
+	\u{29c}\x03\x02\x02\x02\u{29e}\u{29f}\x03\x02\x02\x02\u{29f}\u{2a6}\x03\
+	\x02\x02\x02\u{2a0}\u{29e}\x03\x02\x02\x02\u{2a1}\u{2a2}\x07\x08\x02\x02\
+	\u{2a2}\u{2a3}\x05\x4c\x27\x02\u{2a3}\u{2a4}\x07\x09\x02\x02\u{2a4}\u{2a6}\
+	\x03\x02\x02\x02\u{2a5}\u{297}\x03\x02\x02\x02\u{2a5}\u{2a1}\x03\x02\x02\
+	\x02\u{2a6}\x4d\x03\x02\x02\x02\u{2a7}\u{2a9}\x07\x08\x02\x02\u{2a8}\u{2aa}\
+	\x07\u{80}\x02\x02\u{2a9}\u{2a8}\x03\x02\x02\x02\u{2a9}\u{2aa}\x03\x02\x02\
+	\x02\u{2aa}\u{2af}\x03\x02\x02\x02\u{2ab}\u{2ad}\x05\u{ac}\x57\x02\u{2ac}\
+	\u{2ae}\x07\u{80}\x02\x02\u{2ad}\u{2ac}\x03\x02\x02\x02\u{2ad}\u{2ae}\x03\
+	\x02\x02\x02\u{2ae}\u{2b0}\x03\x02\x02\x02\u{2af}\u{2ab}\x03\x02\x02\x02\
+	\u{2af}\u{2b0}\x03\x02\x02\x02\u{2b0}\u{2b5}\x03\x02\x02\x02\u{2b1}\u{2b3}\
+	\x05\x5a\x2e\x02\u{2b2}\u{2b4}\x07\u{80}\x02\x02\u{2b3}\u{2b2}\x03\x02\x02\
+	\x02\u{2b3}\u{2b4}\x03\x02\x02\x02\u{2b4}\u{2b6}\x03\x02\x02\x02\u{2b5}\
+	\u{2b1}\x03\x02\x02\x02\u{2b5}\u{2b6}\x03\x02\x02\x02\u{2b6}\u{2bb}\x03\
+	\x02\x02\x02\u{2b7}\u{2b9}\x05\x56\x2c\x02\u{2b8}\u{2ba}\x07\u{80}\x02\x02\
+	\u{2b9}\u{2b8}\x03\x02\x02\x02\u{2b9}\u{2ba}\x03\x02\x02\x02\u{2ba}\u{2bc}\
+	\x03\x02\x02\x02\u{2bb}\u{2b7}\x03\x02\x02\x02\u{2bb}\u{2bc}\x03\x02\x02\
+	\x02\u{2bc}\u{2bd}\x03\x02\x02\x02\u{2bd}\u{2be}\x07\x09\x02\x02\u{2be}\
+	\x4f\x03\x02\x02\x02\u{2bf}\u{2c1}\x05\x52\x2a\x02\u{2c0}\u{2c2}\x07\u{80}\
+	\x02\x02\u{2c1}\u{2c0}\x03\x02\x02\x02\u{2c1}\u{2c2}\x03\x02\x02\x02\u{2c2}\
+	\u{2c3}\x03\x02\x02\x02\u{2c3}\u{2c4}\x05\x4e\x28\x02\u{2c4}\x51\x03\x02\
+	\x02\x02\u{2c5}\u{2c7}\x05\u{c2}\x62\x02\u{2c6}\u{2c8}\x07\u{80}\x02\x02\
+	\u{2c7}\u{2c6}\x03\x02\x02\x02\u{2c7}\u{2c8}\x03\x02\x02\x02\u{2c8}\u{2c9}\
+	\x03\x02\x02\x02\u{2c9}\u{2cb}\x05\u{c6}\x64\x02\u{2ca}\u{2cc}\x07\u{80}\
+	\x02\x02\u{2cb}\u{2ca}\x03\x02\x02\x02\u{2cb}\u{2cc}\x03\x02\x02\x02\u{2cc}\
+	\u{2ce}\x03\x02\x02\x02\u{2cd}\u{2cf}\x05\x54\x2b\x02\u{2ce}\u{2cd}\x03\
+	\x02\x02\x02\u{2ce}\u{2cf}\x03\x02\x02\x02\u{2cf}\u{2d1}\x03\x02\x02\x02\
+	\u{2d0}\u{2d2}\x07\u{80}\x02\x02\u{2d1}\u{2d0}\x03\x02\x02\x02\u{2d1}\u{2d2}\
+	\x03\x02\x02\x02\u{2d2}\u{2d3}\x03\x02\x02\x02\u{2d3}\u{2d5}\x05\u{c6}\x64\
+	\x02\u{2d4}\u{2d6}\x07\u{80}\x02\x02\u{2d5}\u{2d4}\x03\x02\x02\x02\u{2d5}\
+	\u{2d6}\x03\x02\x02\x02\u{2d6}\u{2d7}\x03\x02\x02\x02\u{2d7}\u{2d8}\x05\
+	\u{c4}\x63\x02\u{2d8}\u{306}\x03\x02\x02\x02\u{2d9}\u{2db}\x05\u{c2}\x62\
+	\x02\u{2da}\u{2dc}\x07\u{80}\x02\x02\u{2db}\u{2da}\x03\x02\x02\x02\u{2db}\
+	\u{2dc}\x03\x02\x02\x02\u{2dc}\u{2dd}\x03\x02\x02\x02\u{2dd}\u{2df}\x05\
+	\u{c6}\x64\x02\u{2de}\u{2e0}\x07\u{80}\x02\x02\u{2df}\u{2de}\x03\x02\x02\
+	\x02\u{2df}\u{2e0}\x03\x02\x02\x02\u{2e0}\u{2e2}\x03\x02\x02\x02\u{2e1}\
+	\u{2e3}\x05\x54\x2b\x02\u{2e2}\u{2e1}\x03\x02\x02\x02\u{2e2}\u{2e3}\x03\
+	\x02\x02\x02\u{2e3}\u{2e5}\x03\x02\x02\x02\u{2e4}\u{2e6}\x07\u{80}\x02\x02\
+	\u{2e5}\u{2e4}\x03\x02\x02\x02\u{2e5}\u{2e6}\x03\x02\x02\x02\u{2e6}\u{2e7}\
+	\x03\x02\x02\x02\u{2e7}\u{2e8}\x05\u{c6}\x64\x02\u{2e8}\u{306}\x03\x02\x02\
+	\x02\u{2e9}\u{2eb}\x05\u{c6}\x64\x02\u{2ea}\u{2ec}\x07\u{80}\x02\x02\u{2eb}\
+	\u{2ea}\x03\x02\x02\x02\u{2eb}\u{2ec}\x03\x02\x02\x02\u{2ec}\u{2ee}\x03\
+	\x02\x02\x02\u{2ed}\u{2ef}\x05\x54\x2b\x02\u{2ee}\u{2ed}\x03\x02\x02\x02\
+	\u{2ee}\u{2ef}\x03\x02\x02\x02\u{2ef}\u{2f1}\x03\x02\x02\x02\u{2f0}\u{2f2}\
+	\x07\u{80}\x02\x02\u{2f1}\u{2f0}\x03\x02\x02\x02\u{2f1}\u{2f2}\x03\x02\x02\
+	\x02\u{2f2}\u{2f3}\x03\x02\x02\x02\u{2f3}\u{2f5}\x05\u{c6}\x64\x02\u{2f4}\
+	\u{2f6}\x07\u{80}\x02\x02\u{2f5}\u{2f4}\x03\x02\x02\x02\u{2f5}\u{2f6}\x03\
+	\x02\x02\x02\u{2f6}\u{2f7}\x03\x02\x02\x02\u{2f7}\u{2f8}\x05\u{c4}\x63\x02\
+	\u{2f8}\u{306}\x03\x02\x02\x02\u{2f9}\u{2fb}\x05\u{c6}\x64\x02\u{2fa}\u{2fc}\
+	\x07\u{80}\x02\x02\u{2fb}\u{2fa}\x03\x02\x02\x02\u{2fb}\u{2fc}\x03\x02\x02\
+	\x02\u{2fc}\u{2fe}\x03\x02\x02\x02\u{2fd}\u{2ff}\x05\x54\x2b\x02\u{2fe}\
+	\u{2fd}\x03\x02\x02\x02\u{2fe}\u{2ff}\x03\x02\x02\x02\u{2ff}\u{301}\x03\
+	\x02\x02\x02\u{300}\u{302}\x07\u{80}\x02\x02\u{301}\u{300}\x03\x02\x02\x02\
+	\u{301}\u{302}\x03\x02\x02\x02\u{302}\u{303}\x03\x02\x02\x02\u{303}\u{304}\
+	\x05\u{c6}\x64\x02\u{304}\u{306}\x03\x02\x02\x02\u{305}\u{2c5}\x03\x02\x02\
+	\x02\u{305}\u{2d9}\x03\x02\x02\x02\u{305}\u{2e9}\x03\x02\x02\x02\u{305}\
+	\u{2f9}\x03\x02\x02\x02\u{306}\x53\x03\x02\x02\x02\u{307}\u{309}\x07\x0a\
+	\x02\x02\u{308}\u{30a}\x07\u{80}\x02\x02\u{309}\u{308}\x03\x02\x02\x02\u{309}\
+	\u{30a}\x03\x02\x02\x02\u{30a}\u{30f}\x03\x02\x02\x02\u{30b}\u{30d}\x05\
+	\u{ac}\x57\x02\u{30c}\u{30e}\x07\u{80}\x02\x02\u{30d}\u{30c}\x03\x02\x02\
+	\x02\u{30d}\u{30e}\x03\x02\x02\x02\u{30e}\u{310}\x03\x02\x02\x02\u{30f}\
+	\u{30b}\x03\x02\x02\x02\u{30f}\u{310}\x03\x02\x02\x02\u{310}\u{315}\x03\
+	\x02\x02\x02\u{311}\u{313}\x05\x58\x2d\x02\u{312}\u{314}\x07\u{80}\x02\x02\
+	\u{313}\u{312}\x03\x02\x02\x02\u{313}\u{314}\x03\x02\x02\x02\u{314}\u{316}\
+	\x03\x02\x02\x02\u{315}\u{311}\x03\x02\x02\x02\u{315}\u{316}\x03\x02\x02\
+	\x02\u{316}\u{318}\x03\x02\x02\x02\u{317}\u{319}\x05\x5e\x30\x02\u{318}\
+	\u{317}\x03\x02\x02\x02\u{318}\u{319}\x03\x02\x02\x02\u{319}\u{31e}\x03\
+	\x02\x02\x02\u{31a}\u{31c}\x05\x56\x2c\x02\u{31b}\u{31d}\x07\u{80}\x02\x02\
+	\u{31c}\u{31b}\x03\x02\x02\x02\u{31c}\u{31d}\x03\x02\x02\x02\u{31d}\u{31f}\
+	\x03\x02\x02\x02\u{31e}\u{31a}\x03\x02\x02\x02\u{31e}\u{31f}\x03\x02\x02\
+	\x02\u{31f}\u{320}\x03\x02\x02\x02\u{320}\u{321}\x07\x0b\x02\x02\u{321}\
+	\x55\x03\x02\x02\x02\u{322}\u{325}\x05\u{b0}\x59\x02\u{323}\u{325}\x05\u{b2}\
+	\x5a\x02\u{324}\u{322}\x03\x02\x02\x02\u{324}\u{323}\x03\x02\x02\x02\u{325}\
+	\x57\x03\x02\x02\x02\u{326}\u{328}\x07\x0c\x02\x02\u{327}\u{329}\x07\u{80}\
+	\x02\x02\u{328}\u{327}\x03\x02\x02\x02\u{328}\u{329}\x03\x02\x02\x02\u{329}\
+	\u{32a}\x03\x02\x02\x02\u{32a}\u{338}\x05\x62\x32\x02\u{32b}\u{32d}\x07\
+	\u{80}\x02\x02\u{32c}\u{32b}\x03\x02\x02\x02\u{32c}\u{32d}\x03\x02\x02\x02\
+	\u{32d}\u{32e}\x03\x02\x02\x02\u{32e}\u{330}\x07\x0d\x02\x02\u{32f}\u{331}\
+	\x07\x0c\x02\x02\u{330}\u{32f}\x03\x02\x02\x02\u{330}\u{331}\x03\x02\x02\
+	\x02\u{331}\u{333}\x03\x02\x02\x02\u{332}\u{334}\x07\u{80}\x02\x02\u{333}\
+	\u{332}\x03\x02\x02\x02\u{333}\u{334}\x03\x02\x02\x02\u{334}\u{335}\x03\
+	\x02\x02\x02\u{335}\u{337}\x05\x62\x32\x02\u{336}\u{32c}\x03\x02\x02\x02\
+	\u{337}\u{33a}\x03\x02\x02\x02\u{338}\u{336}\x03\x02\x02\x02\u{338}\u{339}\
+	\x03\x02\x02\x02\u{339}\x59\x03\x02\x02\x02\u{33a}\u{338}\x03\x02\x02\x02\
+	\u{33b}\u{342}\x05\x5c\x2f\x02\u{33c}\u{33e}\x07\u{80}\x02\x02\u{33d}\u{33c}\
+	\x03\x02\x02\x02\u{33d}\u{33e}\x03\x02\x02\x02\u{33e}\u{33f}\x03\x02\x02\
+	\x02\u{33f}\u{341}\x05\x5c\x2f\x02\u{340}\u{33d}\x03\x02\x02\x02\u{341}\
+	\u{344}\x03\x02\x02\x02\u{342}\u{340}\x03\x02\x02\x02\u{342}\u{343}\x03\
+	\x02\x02\x02\u{343}\x5b\x03\x02\x02\x02\u{344}\u{342}\x03\x02\x02\x02\u{345}\
+	\u{347}\x07\x0c\x02\x02\u{346}\u{348}\x07\u{80}\x02\x02\u{347}\u{346}\x03\
+	\x02\x02\x02\u{347}\u{348}\x03\x02\x02\x02\u{348}\u{349}\x03\x02\x02\x02\
+	\u{349}\u{34a}\x05\x60\x31\x02\u{34a}\x5d\x03\x02\x02\x02\u{34b}\u{34d}\
+	\x07\x07\x02\x02\u{34c}\u{34e}\x07\u{80}\x02\x02\u{34d}\u{34c}\x03\x02\x02\
+	\x02\u{34d}\u{34e}\x03\x02\x02\x02\u{34e}\u{353}\x03\x02\x02\x02\u{34f}\
+	\u{351}\x05\u{b8}\x5d\x02\u{350}\u{352}\x07\u{80}\x02\x02\u{351}\u{350}\
+	\x03\x02\x02\x02\u{351}\u{352}\x03\x02\x02\x02\u{352}\u{354}\x03\x02\x02\
+	\x02\u{353}\u{34f}\x03\x02\x02\x02\u{353}\u{354}\x03\x02\x02\x02\u{354}\
+	\u{35f}\x03\x02\x02\x02\u{355}\u{357}\x07\x0e\x02\x02\u{356}\u{358}\x07\
+	\u{80}\x02\x02\u{357}\u{356}\x03\x02\x02\x02\u{357}\u{358}\x03\x02\x02\x02\
+	\u{358}\u{35d}\x03\x02\x02\x02\u{359}\u{35b}\x05\u{b8}\x5d\x02\u{35a}\u{35c}\
+	\x07\u{80}\x02\x02\u{35b}\u{35a}\x03\x02\x02\x02\u{35b}\u{35c}\x03\x02\x02\
+	\x02\u{35c}\u{35e}\x03\x02\x02\x02\u{35d}\u{359}\x03\x02\x02\x02\u{35d}\
+	\u{35e}\x03\x02\x02\x02\u{35e}\u{360}\x03\x02\x02\x02\u{35f}\u{355}\x03\
+	\x02\x02\x02\u{35f}\u{360}\x03\x02\x02\x02\u{360}\x5f\x03\x02\x02\x02\u{361}\
+	\u{369}\x05\u{bc}\x5f\x02\u{362}\u{365}\x05\u{c6}\x64\x02\u{363}\u{366}\
+	\x05\u{bc}\x5f\x02\u{364}\u{366}\x07\x65\x02\x02\u{365}\u{363}\x03\x02\x02\
+	\x02\u{365}\u{364}\x03\x02\x02\x02\u{366}\u{368}\x03\x02\x02\x02\u{367}\
+	\u{362}\x03\x02\x02\x02\u{368}\u{36b}\x03\x02\x02\x02\u{369}\u{367}\x03\
+	\x02\x02\x02\u{369}\u{36a}\x03\x02\x02\x02\u{36a}\x61\x03\x02\x02\x02\u{36b}\
+	\u{369}\x03\x02\x02\x02\u{36c}\u{36d}\x05\u{bc}\x5f\x02\u{36d}\x63\x03\x02\
+	\x02\x02\u{36e}\u{36f}\x05\x66\x34\x02\u{36f}\x65\x03\x02\x02\x02\u{370}\
+	\u{377}\x05\x68\x35\x02\u{371}\u{372}\x07\u{80}\x02\x02\u{372}\u{373}\x07\
+	\x4c\x02\x02\u{373}\u{374}\x07\u{80}\x02\x02\u{374}\u{376}\x05\x68\x35\x02\
+	\u{375}\u{371}\x03\x02\x02\x02\u{376}\u{379}\x03\x02\x02\x02\u{377}\u{375}\
+	\x03\x02\x02\x02\u{377}\u{378}\x03\x02\x02\x02\u{378}\x67\x03\x02\x02\x02\
+	\u{379}\u{377}\x03\x02\x02\x02\u{37a}\u{381}\x05\x6a\x36\x02\u{37b}\u{37c}\
+	\x07\u{80}\x02\x02\u{37c}\u{37d}\x07\x4d\x02\x02\u{37d}\u{37e}\x07\u{80}\
+	\x02\x02\u{37e}\u{380}\x05\x6a\x36\x02\u{37f}\u{37b}\x03\x02\x02\x02\u{380}\
+	\u{383}\x03\x02\x02\x02\u{381}\u{37f}\x03\x02\x02\x02\u{381}\u{382}\x03\
+	\x02\x02\x02\u{382}\x69\x03\x02\x02\x02\u{383}\u{381}\x03\x02\x02\x02\u{384}\
+	\u{38b}\x05\x6c\x37\x02\u{385}\u{386}\x07\u{80}\x02\x02\u{386}\u{387}\x07\
+	\x4e\x02\x02\u{387}\u{388}\x07\u{80}\x02\x02\u{388}\u{38a}\x05\x6c\x37\x02\
+	\u{389}\u{385}\x03\x02\x02\x02\u{38a}\u{38d}\x03\x02\x02\x02\u{38b}\u{389}\
+	\x03\x02\x02\x02\u{38b}\u{38c}\x03\x02\x02\x02\u{38c}\x6b\x03\x02\x02\x02\
+	\u{38d}\u{38b}\x03\x02\x02\x02\u{38e}\u{390}\x07\x4f\x02\x02\u{38f}\u{391}\
+	\x07\u{80}\x02\x02\u{390}\u{38f}\x03\x02\x02\x02\u{390}\u{391}\x03\x02\x02\
+	\x02\u{391}\u{393}\x03\x02\x02\x02\u{392}\u{38e}\x03\x02\x02\x02\u{393}\
+	\u{396}\x03\x02\x02\x02\u{394}\u{392}\x03\x02\x02\x02\u{394}\u{395}\x03\
+	\x02\x02\x02\u{395}\u{397}\x03\x02\x02\x02\u{396}\u{394}\x03\x02\x02\x02\
+	\u{397}\u{398}\x05\x6e\x38\x02\u{398}\x6d\x03\x02\x02\x02\u{399}\u{3a0}\
+	\x05\x70\x39\x02\u{39a}\u{39c}\x07\u{80}\x02\x02\u{39b}\u{39a}\x03\x02\x02\
+	\x02\u{39b}\u{39c}\x03\x02\x02\x02\u{39c}\u{39d}\x03\x02\x02\x02\u{39d}\
+	\u{39f}\x05\u{8a}\x46\x02\u{39e}\u{39b}\x03\x02\x02\x02\u{39f}\u{3a2}\x03\
+	\x02\x02\x02\u{3a0}\u{39e}\x03\x02\x02\x02\u{3a0}\u{3a1}\x03\x02\x02\x02\
+	\u{3a1}\x6f\x03\x02\x02\x02\u{3a2}\u{3a0}\x03\x02\x02\x02\u{3a3}\u{3b6}\
+	\x05\x72\x3a\x02\u{3a4}\u{3a6}\x07\u{80}\x02\x02\u{3a5}\u{3a4}\x03\x02\x02\
+	\x02\u{3a5}\u{3a6}\x03\x02\x02\x02\u{3a6}\u{3a7}\x03\x02\x02\x02\u{3a7}\
+	\u{3a9}\x07\x0f\x02\x02\u{3a8}\u{3aa}\x07\u{80}\x02\x02\u{3a9}\u{3a8}\x03\
+	\x02\x02\x02\u{3a9}\u{3aa}\x03\x02\x02\x02\u{3aa}\u{3ab}\x03\x02\x02\x02\
+	\u{3ab}\u{3b5}\x05\x72\x3a\x02\u{3ac}\u{3ae}\x07\u{80}\x02\x02\u{3ad}\u{3ac}\
+	\x03\x02\x02\x02\u{3ad}\u{3ae}\x03\x02\x02\x02\u{3ae}\u{3af}\x03\x02\x02\
+	\x02\u{3af}\u{3b1}\x07\x10\x02\x02\u{3b0}\u{3b2}\x07\u{80}\x02\x02\u{3b1}\
+	\u{3b0}\x03\x02\x02\x02\u{3b1}\u{3b2}\x03\x02\x02\x02\u{3b2}\u{3b3}\x03\
+	\x02\x02\x02\u{3b3}\u{3b5}\x05\x72\x3a\x02\u{3b4}\u{3a5}\x03\x02\x02\x02\
+	\u{3b4}\u{3ad}\x03\x02\x02\x02\u{3b5}\u{3b8}\x03\x02\x02\x02\u{3b6}\u{3b4}\
+	\x03\x02\x02\x02\u{3b6}\u{3b7}\x03\x02\x02\x02\u{3b7}\x71\x03\x02\x02\x02\
+	\u{3b8}\u{3b6}\x03\x02\x02\x02\u{3b9}\u{3d4}\x05\x74\x3b\x02\u{3ba}\u{3bc}\
+	\x07\u{80}\x02\x02\u{3bb}\u{3ba}\x03\x02\x02\x02\u{3bb}\u{3bc}\x03\x02\x02\
+	\x02\u{3bc}\u{3bd}\x03\x02\x02\x02\u{3bd}\u{3bf}\x07\x07\x02\x02\u{3be}\
+	\u{3c0}\x07\u{80}\x02\x02\u{3bf}\u{3be}\x03\x02\x02\x02\u{3bf}\u{3c0}\x03\
+	\x02\x02\x02\u{3c0}\u{3c1}\x03\x02\x02\x02\u{3c1}\u{3d3}\x05\x74\x3b\x02\
+	\u{3c2}\u{3c4}\x07\u{80}\x02\x02\u{3c3}\u{3c2}\x03\x02\x02\x02\u{3c3}\u{3c4}\
+	\x03\x02\x02\x02\u{3c4}\u{3c5}\x03\x02\x02\x02\u{3c5}\u{3c7}\x07\x11\x02\
+	\x02\u{3c6}\u{3c8}\x07\u{80}\x02\x02\u{3c7}\u{3c6}\x03\x02\x02\x02\u{3c7}\
+	\u{3c8}\x03\x02\x02\x02\u{3c8}\u{3c9}\x03\x02\x02\x02\u{3c9}\u{3d3}\x05\
+	\x74\x3b\x02\u{3ca}\u{3cc}\x07\u{80}\x02\x02\u{3cb}\u{3ca}\x03\x02\x02\x02\
+	\u{3cb}\u{3cc}\x03\x02\x02\x02\u{3cc}\u{3cd}\x03\x02\x02\x02\u{3cd}\u{3cf}\
+	\x07\x12\x02\x02\u{3ce}\u{3d0}\x07\u{80}\x02\x02\u{3cf}\u{3ce}\x03\x02\x02\
+	\x02\u{3cf}\u{3d0}\x03\x02\x02\x02\u{3d0}\u{3d1}\x03\x02\x02\x02\u{3d1}\
+	\u{3d3}\x05\x74\x3b\x02\u{3d2}\u{3bb}\x03\x02\x02\x02\u{3d2}\u{3c3}\x03\
+	\x02\x02\x02\u{3d2}\u{3cb}\x03\x02\x02\x02\u{3d3}\u{3d6}\x03\x02\x02\x02\
+	\u{3d4}\u{3d2}\x03\x02\x02\x02\u{3d4}\u{3d5}\x03\x02\x02\x02\u{3d5}\x73\
+	\x03\x02\x02\x02\u{3d6}\u{3d4}\x03\x02\x02\x02\u{3d7}\u{3e2}\x05\x76\x3c\
+	\x02\u{3d8}\u{3da}\x07\u{80}\x02\x02\u{3d9}\u{3d8}\x03\x02\x02\x02\u{3d9}\
+	\u{3da}\x03\x02\x02\x02\u{3da}\u{3db}\x03\x02\x02\x02\u{3db}\u{3dd}\x07\
+	\x13\x02\x02\u{3dc}\u{3de}\x07\u{80}\x02\x02\u{3dd}\u{3dc}\x03\x02\x02\x02\
+	\u{3dd}\u{3de}\x03\x02\x02\x02\u{3de}\u{3df}\x03\x02\x02\x02\u{3df}\u{3e1}\
+	\x05\x76\x3c\x02\u{3e0}\u{3d9}\x03\x02\x02\x02\u{3e1}\u{3e4}\x03\x02\x02\
+	\x02\u{3e2}\u{3e0}\x03\x02\x02\x02\u{3e2}\u{3e3}\x03\x02\x02\x02\u{3e3}\
+	\x75\x03\x02\x02\x02\u{3e4}\u{3e2}\x03\x02\x02\x02\u{3e5}\u{3e7}\x09\x03\
+	\x02\x02\u{3e6}\u{3e8}\x07\u{80}\x02\x02\u{3e7}\u{3e6}\x03\x02\x02\x02\u{3e7}\
+	\u{3e8}\x03\x02\x02\x02\u{3e8}\u{3ea}\x03\x02\x02\x02\u{3e9}\u{3e5}\x03\
+	\x02\x02\x02\u{3ea}\u{3ed}\x03\x02\x02\x02\u{3eb}\u{3e9}\x03\x02\x02\x02\
+	\u{3eb}\u{3ec}\x03\x02\x02\x02\u{3ec}\u{3ee}\x03\x02\x02\x02\u{3ed}\u{3eb}\
+	\x03\x02\x02\x02\u{3ee}\u{3ef}\x05\x78\x3d\x02\u{3ef}\x77\x03\x02\x02\x02\
+	\u{3f0}\u{3f6}\x05\u{80}\x41\x02\u{3f1}\u{3f5}\x05\x7c\x3f\x02\u{3f2}\u{3f5}\
+	\x05\x7a\x3e\x02\u{3f3}\u{3f5}\x05\x7e\x40\x02\u{3f4}\u{3f1}\x03\x02\x02\
+	\x02\u{3f4}\u{3f2}\x03\x02\x02\x02\u{3f4}\u{3f3}\x03\x02\x02\x02\u{3f5}\
+	\u{3f8}\x03\x02\x02\x02\u{3f6}\u{3f4}\x03\x02\x02\x02\u{3f6}\u{3f7}\x03\
+	\x02\x02\x02\u{3f7}\x79\x03\x02\x02\x02\u{3f8}\u{3f6}\x03\x02\x02\x02\u{3f9}\
+	\u{3fa}\x07\u{80}\x02\x02\u{3fa}\u{3fc}\x07\x50\x02\x02\u{3fb}\u{3fd}\x07\
+	\u{80}\x02\x02\u{3fc}\u{3fb}\x03\x02\x02\x02\u{3fc}\u{3fd}\x03\x02\x02\x02\
+	\u{3fd}\u{3fe}\x03\x02\x02\x02\u{3fe}\u{413}\x05\u{80}\x41\x02\u{3ff}\u{401}\
+	\x07\u{80}\x02\x02\u{400}\u{3ff}\x03\x02\x02\x02\u{400}\u{401}\x03\x02\x02\
+	\x02\u{401}\u{402}\x03\x02\x02\x02\u{402}\u{403}\x07\x0a\x02\x02\u{403}\
+	\u{404}\x05\x64\x33\x02\u{404}\u{405}\x07\x0b\x02\x02\u{405}\u{413}\x03\
+	\x02\x02\x02\u{406}\u{408}\x07\u{80}\x02\x02\u{407}\u{406}\x03\x02\x02\x02\
+	\u{407}\u{408}\x03\x02\x02\x02\u{408}\u{409}\x03\x02\x02\x02\u{409}\u{40b}\
+	\x07\x0a\x02\x02\u{40a}\u{40c}\x05\x64\x33\x02\u{40b}\u{40a}\x03\x02\x02\
+	\x02\u{40b}\u{40c}\x03\x02\x02\x02\u{40c}\u{40d}\x03\x02\x02\x02\u{40d}\
+	\u{40f}\x07\x0e\x02\x02\u{40e}\u{410}\x05\x64\x33\x02\u{40f}\u{40e}\x03\
+	\x02\x02\x02\u{40f}\u{410}\x03\x02\x02\x02\u{410}\u{411}\x03\x02\x02\x02\
+	\u{411}\u{413}\x07\x0b\x02\x02\u{412}\u{3f9}\x03\x02\x02\x02\u{412}\u{400}\
+	\x03\x02\x02\x02\u{412}\u{407}\x03\x02\x02\x02\u{413}\x7b\x03\x02\x02\x02\
+	\u{414}\u{415}\x07\u{80}\x02\x02\u{415}\u{416}\x07\x51\x02\x02\u{416}\u{417}\
+	\x07\u{80}\x02\x02\u{417}\u{423}\x07\x40\x02\x02\u{418}\u{419}\x07\u{80}\
+	\x02\x02\u{419}\u{41a}\x07\x52\x02\x02\u{41a}\u{41b}\x07\u{80}\x02\x02\u{41b}\
+	\u{423}\x07\x40\x02\x02\u{41c}\u{41d}\x07\u{80}\x02\x02\u{41d}\u{423}\x07\
+	\x53\x02\x02\u{41e}\u{420}\x07\u{80}\x02\x02\u{41f}\u{41e}\x03\x02\x02\x02\
+	\u{41f}\u{420}\x03\x02\x02\x02\u{420}\u{421}\x03\x02\x02\x02\u{421}\u{423}\
+	\x07\x14\x02\x02\u{422}\u{414}\x03\x02\x02\x02\u{422}\u{418}\x03\x02\x02\
+	\x02\u{422}\u{41c}\x03\x02\x02\x02\u{422}\u{41f}\x03\x02\x02\x02\u{423}\
+	\u{425}\x03\x02\x02\x02\u{424}\u{426}\x07\u{80}\x02\x02\u{425}\u{424}\x03\
+	\x02\x02\x02\u{425}\u{426}\x03\x02\x02\x02\u{426}\u{427}\x03\x02\x02\x02\
+	\u{427}\u{428}\x05\u{80}\x41\x02\u{428}\x7d\x03\x02\x02\x02\u{429}\u{42a}\
+	\x07\u{80}\x02\x02\u{42a}\u{42b}\x07\x54\x02\x02\u{42b}\u{42c}\x07\u{80}\
+	\x02\x02\u{42c}\u{434}\x07\x55\x02\x02\u{42d}\u{42e}\x07\u{80}\x02\x02\u{42e}\
+	\u{42f}\x07\x54\x02\x02\u{42f}\u{430}\x07\u{80}\x02\x02\u{430}\u{431}\x07\
+	\x4f\x02\x02\u{431}\u{432}\x07\u{80}\x02\x02\u{432}\u{434}\x07\x55\x02\x02\
+	\u{433}\u{429}\x03\x02\x02\x02\u{433}\u{42d}\x03\x02\x02\x02\u{434}\x7f\
+	\x03\x02\x02\x02\u{435}\u{43c}\x05\u{82}\x42\x02\u{436}\u{438}\x07\u{80}\
+	\x02\x02\u{437}\u{436}\x03\x02\x02\x02\u{437}\u{438}\x03\x02\x02\x02\u{438}\
+	\u{439}\x03\x02\x02\x02\u{439}\u{43b}\x05\u{a6}\x54\x02\u{43a}\u{437}\x03\
+	\x02\x02\x02\u{43b}\u{43e}\x03\x02\x02\x02\u{43c}\u{43a}\x03\x02\x02\x02\
+	\u{43c}\u{43d}\x03\x02\x02\x02\u{43d}\u{443}\x03\x02\x02\x02\u{43e}\u{43c}\
+	\x03\x02\x02\x02\u{43f}\u{441}\x07\u{80}\x02\x02\u{440}\u{43f}\x03\x02\x02\
+	\x02\u{440}\u{441}\x03\x02\x02\x02\u{441}\u{442}\x03\x02\x02\x02\u{442}\
+	\u{444}\x05\x5a\x2e\x02\u{443}\u{440}\x03\x02\x02\x02\u{443}\u{444}\x03\
+	\x02\x02\x02\u{444}\u{81}\x03\x02\x02\x02\u{445}\u{494}\x05\u{84}\x43\x02\
+	\u{446}\u{494}\x05\u{b2}\x5a\x02\u{447}\u{494}\x05\u{a8}\x55\x02\u{448}\
+	\u{44a}\x07\x56\x02\x02\u{449}\u{44b}\x07\u{80}\x02\x02\u{44a}\u{449}\x03\
+	\x02\x02\x02\u{44a}\u{44b}\x03\x02\x02\x02\u{44b}\u{44c}\x03\x02\x02\x02\
+	\u{44c}\u{44e}\x07\x08\x02\x02\u{44d}\u{44f}\x07\u{80}\x02\x02\u{44e}\u{44d}\
+	\x03\x02\x02\x02\u{44e}\u{44f}\x03\x02\x02\x02\u{44f}\u{450}\x03\x02\x02\
+	\x02\u{450}\u{452}\x07\x07\x02\x02\u{451}\u{453}\x07\u{80}\x02\x02\u{452}\
+	\u{451}\x03\x02\x02\x02\u{452}\u{453}\x03\x02\x02\x02\u{453}\u{454}\x03\
+	\x02\x02\x02\u{454}\u{494}\x07\x09\x02\x02\u{455}\u{494}\x05\u{a2}\x52\x02\
+	\u{456}\u{494}\x05\u{a4}\x53\x02\u{457}\u{459}\x07\x32\x02\x02\u{458}\u{45a}\
+	\x07\u{80}\x02\x02\u{459}\u{458}\x03\x02\x02\x02\u{459}\u{45a}\x03\x02\x02\
+	\x02\u{45a}\u{45b}\x03\x02\x02\x02\u{45b}\u{45d}\x07\x08\x02\x02\u{45c}\
+	\u{45e}\x07\u{80}\x02\x02\u{45d}\u{45c}\x03\x02\x02\x02\u{45d}\u{45e}\x03\
+	\x02\x02\x02\u{45e}\u{45f}\x03\x02\x02\x02\u{45f}\u{461}\x05\u{90}\x49\x02\
+	\u{460}\u{462}\x07\u{80}\x02\x02\u{461}\u{460}\x03\x02\x02\x02\u{461}\u{462}\
+	\x03\x02\x02\x02\u{462}\u{463}\x03\x02\x02\x02\u{463}\u{464}\x07\x09\x02\
+	\x02\u{464}\u{494}\x03\x02\x02\x02\u{465}\u{467}\x07\x57\x02\x02\u{466}\
+	\u{468}\x07\u{80}\x02\x02\u{467}\u{466}\x03\x02\x02\x02\u{467}\u{468}\x03\
+	\x02\x02\x02\u{468}\u{469}\x03\x02\x02\x02\u{469}\u{46b}\x07\x08\x02\x02\
+	\u{46a}\u{46c}\x07\u{80}\x02\x02\u{46b}\u{46a}\x03\x02\x02\x02\u{46b}\u{46c}\
+	\x03\x02\x02\x02\u{46c}\u{46d}\x03\x02\x02\x02\u{46d}\u{46f}\x05\u{90}\x49\
+	\x02\u{46e}\u{470}\x07\u{80}\x02\x02\u{46f}\u{46e}\x03\x02\x02\x02\u{46f}\
+	\u{470}\x03\x02\x02\x02\u{470}\u{471}\x03\x02\x02\x02\u{471}\u{472}\x07\
+	\x09\x02\x02\u{472}\u{494}\x03\x02\x02\x02\u{473}\u{475}\x07\x58\x02\x02\
+	\u{474}\u{476}\x07\u{80}\x02\x02\u{475}\u{474}\x03\x02\x02\x02\u{475}\u{476}\
+	\x03\x02\x02\x02\u{476}\u{477}\x03\x02\x02\x02\u{477}\u{479}\x07\x08\x02\
+	\x02\u{478}\u{47a}\x07\u{80}\x02\x02\u{479}\u{478}\x03\x02\x02\x02\u{479}\
+	\u{47a}\x03\x02\x02\x02\u{47a}\u{47b}\x03\x02\x02\x02\u{47b}\u{47d}\x05\
+	\u{90}\x49\x02\u{47c}\u{47e}\x07\u{80}\x02\x02\u{47d}\u{47c}\x03\x02\x02\
+	\x02\u{47d}\u{47e}\x03\x02\x02\x02\u{47e}\u{47f}\x03\x02\x02\x02\u{47f}\
+	\u{480}\x07\x09\x02\x02\u{480}\u{494}\x03\x02\x02\x02\u{481}\u{483}\x07\
+	\x59\x02\x02\u{482}\u{484}\x07\u{80}\x02\x02\u{483}\u{482}\x03\x02\x02\x02\
+	\u{483}\u{484}\x03\x02\x02\x02\u{484}\u{485}\x03\x02\x02\x02\u{485}\u{487}\
+	\x07\x08\x02\x02\u{486}\u{488}\x07\u{80}\x02\x02\u{487}\u{486}\x03\x02\x02\
+	\x02\u{487}\u{488}\x03\x02\x02\x02\u{488}\u{489}\x03\x02\x02\x02\u{489}\
+	\u{48b}\x05\u{90}\x49\x02\u{48a}\u{48c}\x07\u{80}\x02\x02\u{48b}\u{48a}\
+	\x03\x02\x02\x02\u{48b}\u{48c}\x03\x02\x02\x02\u{48c}\u{48d}\x03\x02\x02\
+	\x02\u{48d}\u{48e}\x07\x09\x02\x02\u{48e}\u{494}\x03\x02\x02\x02\u{48f}\
+	\u{494}\x05\u{8e}\x48\x02\u{490}\u{494}\x05\u{8c}\x47\x02\u{491}\u{494}\
+	\x05\u{94}\x4b\x02\u{492}\u{494}\x05\u{ac}\x57\x02\u{493}\u{445}\x03\x02\
+	\x02\x02\u{493}\u{446}\x03\x02\x02\x02\u{493}\u{447}\x03\x02\x02\x02\u{493}\
+	\u{448}\x03\x02\x02\x02\u{493}\u{455}\x03\x02\x02\x02\u{493}\u{456}\x03\
+	\x02\x02\x02\u{493}\u{457}\x03\x02\x02\x02\u{493}\u{465}\x03\x02\x02\x02\
+	\u{493}\u{473}\x03\x02\x02\x02\u{493}\u{481}\x03\x02\x02\x02\u{493}\u{48f}\
+	\x03\x02\x02\x02\u{493}\u{490}\x03\x02\x02\x02\u{493}\u{491}\x03\x02\x02\
+	\x02\u{493}\u{492}\x03\x02\x02\x02\u{494}\u{83}\x03\x02\x02\x02\u{495}\u{49c}\
+	\x05\u{ae}\x58\x02\u{496}\u{49c}\x07\x62\x02\x02\u{497}\u{49c}\x05\u{86}\
+	\x44\x02\u{498}\u{49c}\x07\x55\x02\x02\u{499}\u{49c}\x05\u{b0}\x59\x02\u{49a}\
+	\u{49c}\x05\u{88}\x45\x02\u{49b}\u{495}\x03\x02\x02\x02\u{49b}\u{496}\x03\
+	\x02\x02\x02\u{49b}\u{497}\x03\x02\x02\x02\u{49b}\u{498}\x03\x02\x02\x02\
+	\u{49b}\u{499}\x03\x02\x02\x02\u{49b}\u{49a}\x03\x02\x02\x02\u{49c}\u{85}\
+	\x03\x02\x02\x02\u{49d}\u{49e}\x09\x04\x02\x02\u{49e}\u{87}\x03\x02\x02\
+	\x02\u{49f}\u{4a1}\x07\x0a\x02\x02\u{4a0}\u{4a2}\x07\u{80}\x02\x02\u{4a1}\
+	\u{4a0}\x03\x02\x02\x02\u{4a1}\u{4a2}\x03\x02\x02\x02\u{4a2}\u{4b4}\x03\
+	\x02\x02\x02\u{4a3}\u{4a5}\x05\x64\x33\x02\u{4a4}\u{4a6}\x07\u{80}\x02\x02\
+	\u{4a5}\u{4a4}\x03\x02\x02\x02\u{4a5}\u{4a6}\x03\x02\x02\x02\u{4a6}\u{4b1}\
+	\x03\x02\x02\x02\u{4a7}\u{4a9}\x07\x04\x02\x02\u{4a8}\u{4aa}\x07\u{80}\x02\
+	\x02\u{4a9}\u{4a8}\x03\x02\x02\x02\u{4a9}\u{4aa}\x03\x02\x02\x02\u{4aa}\
+	\u{4ab}\x03\x02\x02\x02\u{4ab}\u{4ad}\x05\x64\x33\x02\u{4ac}\u{4ae}\x07\
+	\u{80}\x02\x02\u{4ad}\u{4ac}\x03\x02\x02\x02\u{4ad}\u{4ae}\x03\x02\x02\x02\
+	\u{4ae}\u{4b0}\x03\x02\x02\x02\u{4af}\u{4a7}\x03\x02\x02\x02\u{4b0}\u{4b3}\
+	\x03\x02\x02\x02\u{4b1}\u{4af}\x03\x02\x02\x02\u{4b1}\u{4b2}\x03\x02\x02\
+	\x02\u{4b2}\u{4b5}\x03\x02\x02\x02\u{4b3}\u{4b1}\x03\x02\x02\x02\u{4b4}\
+	\u{4a3}\x03\x02\x02\x02\u{4b4}\u{4b5}\x03\x02\x02\x02\u{4b5}\u{4b6}\x03\
+	\x02\x02\x02\u{4b6}\u{4b7}\x07\x0b\x02\x02\u{4b7}\u{89}\x03\x02\x02\x02\
+	\u{4b8}\u{4ba}\x07\x05\x02\x02\u{4b9}\u{4bb}\x07\u{80}\x02\x02\u{4ba}\u{4b9}\
+	\x03\x02\x02\x02\u{4ba}\u{4bb}\x03\x02\x02\x02\u{4bb}\u{4bc}\x03\x02\x02\
+	\x02\u{4bc}\u{4d7}\x05\x70\x39\x02\u{4bd}\u{4bf}\x07\x15\x02\x02\u{4be}\
+	\u{4c0}\x07\u{80}\x02\x02\u{4bf}\u{4be}\x03\x02\x02\x02\u{4bf}\u{4c0}\x03\
+	\x02\x02\x02\u{4c0}\u{4c1}\x03\x02\x02\x02\u{4c1}\u{4d7}\x05\x70\x39\x02\
+	\u{4c2}\u{4c4}\x07\x16\x02\x02\u{4c3}\u{4c5}\x07\u{80}\x02\x02\u{4c4}\u{4c3}\
+	\x03\x02\x02\x02\u{4c4}\u{4c5}\x03\x02\x02\x02\u{4c5}\u{4c6}\x03\x02\x02\
+	\x02\u{4c6}\u{4d7}\x05\x70\x39\x02\u{4c7}\u{4c9}\x07\x17\x02\x02\u{4c8}\
+	\u{4ca}\x07\u{80}\x02\x02\u{4c9}\u{4c8}\x03\x02\x02\x02\u{4c9}\u{4ca}\x03\
+	\x02\x02\x02\u{4ca}\u{4cb}\x03\x02\x02\x02\u{4cb}\u{4d7}\x05\x70\x39\x02\
+	\u{4cc}\u{4ce}\x07\x18\x02\x02\u{4cd}\u{4cf}\x07\u{80}\x02\x02\u{4ce}\u{4cd}\
+	\x03\x02\x02\x02\u{4ce}\u{4cf}\x03\x02\x02\x02\u{4cf}\u{4d0}\x03\x02\x02\
+	\x02\u{4d0}\u{4d7}\x05\x70\x39\x02\u{4d1}\u{4d3}\x07\x19\x02\x02\u{4d2}\
+	\u{4d4}\x07\u{80}\x02\x02\u{4d3}\u{4d2}\x03\x02\x02\x02\u{4d3}\u{4d4}\x03\
+	\x02\x02\x02\u{4d4}\u{4d5}\x03\x02\x02\x02\u{4d5}\u{4d7}\x05\x70\x39\x02\
+	\u{4d6}\u{4b8}\x03\x02\x02\x02\u{4d6}\u{4bd}\x03\x02\x02\x02\u{4d6}\u{4c2}\
+	\x03\x02\x02\x02\u{4d6}\u{4c7}\x03\x02\x02\x02\u{4d6}\u{4cc}\x03\x02\x02\
+	\x02\u{4d6}\u{4d1}\x03\x02\x02\x02\u{4d7}\u{8b}\x03\x02\x02\x02\u{4d8}\u{4da}\
+	\x07\x08\x02\x02\u{4d9}\u{4db}\x07\u{80}\x02\x02\u{4da}\u{4d9}\x03\x02\x02\
+	\x02\u{4da}\u{4db}\x03\x02\x02\x02\u{4db}\u{4dc}\x03\x02\x02\x02\u{4dc}\
+	\u{4de}\x05\x64\x33\x02\u{4dd}\u{4df}\x07\u{80}\x02\x02\u{4de}\u{4dd}\x03\
+	\x02\x02\x02\u{4de}\u{4df}\x03\x02\x02\x02\u{4df}\u{4e0}\x03\x02\x02\x02\
+	\u{4e0}\u{4e1}\x07\x09\x02\x02\u{4e1}\u{8d}\x03\x02\x02\x02\u{4e2}\u{4e7}\
+	\x05\x4e\x28\x02\u{4e3}\u{4e5}\x07\u{80}\x02\x02\u{4e4}\u{4e3}\x03\x02\x02\
+	\x02\u{4e4}\u{4e5}\x03\x02\x02\x02\u{4e5}\u{4e6}\x03\x02\x02\x02\u{4e6}\
+	\u{4e8}\x05\x50\x29\x02\u{4e7}\u{4e4}\x03\x02\x02\x02\u{4e8}\u{4e9}\x03\
+	\x02\x02\x02\u{4e9}\u{4e7}\x03\x02\x02\x02\u{4e9}\u{4ea}\x03\x02\x02\x02\
+	\u{4ea}\u{8f}\x03\x02\x02\x02\u{4eb}\u{4f0}\x05\u{92}\x4a\x02\u{4ec}\u{4ee}\
+	\x07\u{80}\x02\x02\u{4ed}\u{4ec}\x03\x02\x02\x02\u{4ed}\u{4ee}\x03\x02\x02\
+	\x02\u{4ee}\u{4ef}\x03\x02\x02\x02\u{4ef}\u{4f1}\x05\x44\x23\x02\u{4f0}\
+	\u{4ed}\x03\x02\x02\x02\u{4f0}\u{4f1}\x03\x02\x02\x02\u{4f1}\u{91}\x03\x02\
+	\x02\x02\u{4f2}\u{4f3}\x05\u{ac}\x57\x02\u{4f3}\u{4f4}\x07\u{80}\x02\x02\
+	\u{4f4}\u{4f5}\x07\x50\x02\x02\u{4f5}\u{4f6}\x07\u{80}\x02\x02\u{4f6}\u{4f7}\
+	\x05\x64\x33\x02\u{4f7}\u{93}\x03\x02\x02\x02\u{4f8}\u{4fa}\x05\u{96}\x4c\
+	\x02\u{4f9}\u{4fb}\x07\u{80}\x02\x02\u{4fa}\u{4f9}\x03\x02\x02\x02\u{4fa}\
+	\u{4fb}\x03\x02\x02\x02\u{4fb}\u{4fc}\x03\x02\x02\x02\u{4fc}\u{4fe}\x07\
+	\x08\x02\x02\u{4fd}\u{4ff}\x07\u{80}\x02\x02\u{4fe}\u{4fd}\x03\x02\x02\x02\
+	\u{4fe}\u{4ff}\x03\x02\x02\x02\u{4ff}\u{504}\x03\x02\x02\x02\u{500}\u{502}\
+	\x07\x42\x02\x02\u{501}\u{503}\x07\u{80}\x02\x02\u{502}\u{501}\x03\x02\x02\
+	\x02\u{502}\u{503}\x03\x02\x02\x02\u{503}\u{505}\x03\x02\x02\x02\u{504}\
+	\u{500}\x03\x02\x02\x02\u{504}\u{505}\x03\x02\x02\x02\u{505}\u{517}\x03\
+	\x02\x02\x02\u{506}\u{508}\x05\x64\x33\x02\u{507}\u{509}\x07\u{80}\x02\x02\
+	\u{508}\u{507}\x03\x02\x02\x02\u{508}\u{509}\x03\x02\x02\x02\u{509}\u{514}\
+	\x03\x02\x02\x02\u{50a}\u{50c}\x07\x04\x02\x02\u{50b}\u{50d}\x07\u{80}\x02\
+	\x02\u{50c}\u{50b}\x03\x02\x02\x02\u{50c}\u{50d}\x03\x02\x02\x02\u{50d}\
+	\u{50e}\x03\x02\x02\x02\u{50e}\u{510}\x05\x64\x33\x02\u{50f}\u{511}\x07\
+	\u{80}\x02\x02\u{510}\u{50f}\x03\x02\x02\x02\u{510}\u{511}\x03\x02\x02\x02\
+	\u{511}\u{513}\x03\x02\x02\x02\u{512}\u{50a}\x03\x02\x02\x02\u{513}\u{516}\
+	\x03\x02\x02\x02\u{514}\u{512}\x03\x02\x02\x02\u{514}\u{515}\x03\x02\x02\
+	\x02\u{515}\u{518}\x03\x02\x02\x02\u{516}\u{514}\x03\x02\x02\x02\u{517}\
+	\u{506}\x03\x02\x02\x02\u{517}\u{518}\x03\x02\x02\x02\u{518}\u{519}\x03\
+	\x02\x02\x02\u{519}\u{51a}\x07\x09\x02\x02\u{51a}\u{95}\x03\x02\x02\x02\
+	\u{51b}\u{51c}\x05\u{a0}\x51\x02\u{51c}\u{51d}\x05\u{c0}\x61\x02\u{51d}\
+	\u{520}\x03\x02\x02\x02\u{51e}\u{520}\x07\x5c\x02\x02\u{51f}\u{51b}\x03\
+	\x02\x02\x02\u{51f}\u{51e}\x03\x02\x02\x02\u{520}\u{97}\x03\x02\x02\x02\
+	\u{521}\u{523}\x05\u{9e}\x50\x02\u{522}\u{524}\x07\u{80}\x02\x02\u{523}\
+	\u{522}\x03\x02\x02\x02\u{523}\u{524}\x03\x02\x02\x02\u{524}\u{525}\x03\
+	\x02\x02\x02\u{525}\u{527}\x07\x08\x02\x02\u{526}\u{528}\x07\u{80}\x02\x02\
+	\u{527}\u{526}\x03\x02\x02\x02\u{527}\u{528}\x03\x02\x02\x02\u{528}\u{53a}\
+	\x03\x02\x02\x02\u{529}\u{52b}\x05\x64\x33\x02\u{52a}\u{52c}\x07\u{80}\x02\
+	\x02\u{52b}\u{52a}\x03\x02\x02\x02\u{52b}\u{52c}\x03\x02\x02\x02\u{52c}\
+	\u{537}\x03\x02\x02\x02\u{52d}\u{52f}\x07\x04\x02\x02\u{52e}\u{530}\x07\
+	\u{80}\x02\x02\u{52f}\u{52e}\x03\x02\x02\x02\u{52f}\u{530}\x03\x02\x02\x02\
+	\u{530}\u{531}\x03\x02\x02\x02\u{531}\u{533}\x05\x64\x33\x02\u{532}\u{534}\
+	\x07\u{80}\x02\x02\u{533}\u{532}\x03\x02\x02\x02\u{533}\u{534}\x03\x02\x02\
+	\x02\u{534}\u{536}\x03\x02\x02\x02\u{535}\u{52d}\x03\x02\x02\x02\u{536}\
+	\u{539}\x03\x02\x02\x02\u{537}\u{535}\x03\x02\x02\x02\u{537}\u{538}\x03\
+	\x02\x02\x02\u{538}\u{53b}\x03\x02\x02\x02\u{539}\u{537}\x03\x02\x02\x02\
+	\u{53a}\u{529}\x03\x02\x02\x02\u{53a}\u{53b}\x03\x02\x02\x02\u{53b}\u{53c}\
+	\x03\x02\x02\x02\u{53c}\u{53d}\x07\x09\x02\x02\u{53d}\u{99}\x03\x02\x02\
+	\x02\u{53e}\u{53f}\x05\u{9e}\x50\x02\u{53f}\u{9b}\x03\x02\x02\x02\u{540}\
+	\u{541}\x05\u{c0}\x61\x02\u{541}\u{9d}\x03\x02\x02\x02\u{542}\u{543}\x05\
+	\u{a0}\x51\x02\u{543}\u{544}\x05\u{c0}\x61\x02\u{544}\u{9f}\x03\x02\x02\
+	\x02\u{545}\u{546}\x05\u{c0}\x61\x02\u{546}\u{547}\x07\x1a\x02\x02\u{547}\
+	\u{549}\x03\x02\x02\x02\u{548}\u{545}\x03\x02\x02\x02\u{549}\u{54c}\x03\
+	\x02\x02\x02\u{54a}\u{548}\x03\x02\x02\x02\u{54a}\u{54b}\x03\x02\x02\x02\
+	\u{54b}\u{a1}\x03\x02\x02\x02\u{54c}\u{54a}\x03\x02\x02\x02\u{54d}\u{54f}\
+	\x07\x0a\x02\x02\u{54e}\u{550}\x07\u{80}\x02\x02\u{54f}\u{54e}\x03\x02\x02\
+	\x02\u{54f}\u{550}\x03\x02\x02\x02\u{550}\u{551}\x03\x02\x02\x02\u{551}\
+	\u{55a}\x05\u{90}\x49\x02\u{552}\u{554}\x07\u{80}\x02\x02\u{553}\u{552}\
+	\x03\x02\x02\x02\u{553}\u{554}\x03\x02\x02\x02\u{554}\u{555}\x03\x02\x02\
+	\x02\u{555}\u{557}\x07\x0d\x02\x02\u{556}\u{558}\x07\u{80}\x02\x02\u{557}\
+	\u{556}\x03\x02\x02\x02\u{557}\u{558}\x03\x02\x02\x02\u{558}\u{559}\x03\
+	\x02\x02\x02\u{559}\u{55b}\x05\x64\x33\x02\u{55a}\u{553}\x03\x02\x02\x02\
+	\u{55a}\u{55b}\x03\x02\x02\x02\u{55b}\u{55d}\x03\x02\x02\x02\u{55c}\u{55e}\
+	\x07\u{80}\x02\x02\u{55d}\u{55c}\x03\x02\x02\x02\u{55d}\u{55e}\x03\x02\x02\
+	\x02\u{55e}\u{55f}\x03\x02\x02\x02\u{55f}\u{560}\x07\x0b\x02\x02\u{560}\
+	\u{a3}\x03\x02\x02\x02\u{561}\u{563}\x07\x0a\x02\x02\u{562}\u{564}\x07\u{80}\
+	\x02\x02\u{563}\u{562}\x03\x02\x02\x02\u{563}\u{564}\x03\x02\x02\x02\u{564}\
+	\u{56d}\x03\x02\x02\x02\u{565}\u{567}\x05\u{ac}\x57\x02\u{566}\u{568}\x07\
+	\u{80}\x02\x02\u{567}\u{566}\x03\x02\x02\x02\u{567}\u{568}\x03\x02\x02\x02\
+	\u{568}\u{569}\x03\x02\x02\x02\u{569}\u{56b}\x07\x05\x02\x02\u{56a}\u{56c}\
+	\x07\u{80}\x02\x02\u{56b}\u{56a}\x03\x02\x02\x02\u{56b}\u{56c}\x03\x02\x02\
+	\x02\u{56c}\u{56e}\x03\x02\x02\x02\u{56d}\u{565}\x03\x02\x02\x02\u{56d}\
+	\u{56e}\x03\x02\x02\x02\u{56e}\u{56f}\x03\x02\x02\x02\u{56f}\u{571}\x05\
+	\u{8e}\x48\x02\u{570}\u{572}\x07\u{80}\x02\x02\u{571}\u{570}\x03\x02\x02\
+	\x02\u{571}\u{572}\x03\x02\x02\x02\u{572}\u{57b}\x03\x02\x02\x02\u{573}\
+	\u{575}\x07\x4b\x02\x02\u{574}\u{576}\x07\u{80}\x02\x02\u{575}\u{574}\x03\
+	\x02\x02\x02\u{575}\u{576}\x03\x02\x02\x02\u{576}\u{577}\x03\x02\x02\x02\
+	\u{577}\u{579}\x05\x64\x33\x02\u{578}\u{57a}\x07\u{80}\x02\x02\u{579}\u{578}\
+	\x03\x02\x02\x02\u{579}\u{57a}\x03\x02\x02\x02\u{57a}\u{57c}\x03\x02\x02\
+	\x02\u{57b}\u{573}\x03\x02\x02\x02\u{57b}\u{57c}\x03\x02\x02\x02\u{57c}\
+	\u{57d}\x03\x02\x02\x02\u{57d}\u{57f}\x07\x0d\x02\x02\u{57e}\u{580}\x07\
+	\u{80}\x02\x02\u{57f}\u{57e}\x03\x02\x02\x02\u{57f}\u{580}\x03\x02\x02\x02\
+	\u{580}\u{581}\x03\x02\x02\x02\u{581}\u{583}\x05\x64\x33\x02\u{582}\u{584}\
+	\x07\u{80}\x02\x02\u{583}\u{582}\x03\x02\x02\x02\u{583}\u{584}\x03\x02\x02\
+	\x02\u{584}\u{585}\x03\x02\x02\x02\u{585}\u{586}\x07\x0b\x02\x02\u{586}\
+	\u{a5}\x03\x02\x02\x02\u{587}\u{589}\x07\x1a\x02\x02\u{588}\u{58a}\x07\u{80}\
+	\x02\x02\u{589}\u{588}\x03\x02\x02\x02\u{589}\u{58a}\x03\x02\x02\x02\u{58a}\
+	\u{58b}\x03\x02\x02\x02\u{58b}\u{58c}\x05\u{b6}\x5c\x02\u{58c}\u{a7}\x03\
+	\x02\x02\x02\u{58d}\u{592}\x07\x5d\x02\x02\u{58e}\u{590}\x07\u{80}\x02\x02\
+	\u{58f}\u{58e}\x03\x02\x02\x02\u{58f}\u{590}\x03\x02\x02\x02\u{590}\u{591}\
+	\x03\x02\x02\x02\u{591}\u{593}\x05\u{aa}\x56\x02\u{592}\u{58f}\x03\x02\x02\
+	\x02\u{593}\u{594}\x03\x02\x02\x02\u{594}\u{592}\x03\x02\x02\x02\u{594}\
+	\u{595}\x03\x02\x02\x02\u{595}\u{5a4}\x03\x02\x02\x02\u{596}\u{598}\x07\
+	\x5d\x02\x02\u{597}\u{599}\x07\u{80}\x02\x02\u{598}\u{597}\x03\x02\x02\x02\
+	\u{598}\u{599}\x03\x02\x02\x02\u{599}\u{59a}\x03\x02\x02\x02\u{59a}\u{59f}\
+	\x05\x64\x33\x02\u{59b}\u{59d}\x07\u{80}\x02\x02\u{59c}\u{59b}\x03\x02\x02\
+	\x02\u{59c}\u{59d}\x03\x02\x02\x02\u{59d}\u{59e}\x03\x02\x02\x02\u{59e}\
+	\u{5a0}\x05\u{aa}\x56\x02\u{59f}\u{59c}\x03\x02\x02\x02\u{5a0}\u{5a1}\x03\
+	\x02\x02\x02\u{5a1}\u{59f}\x03\x02\x02\x02\u{5a1}\u{5a2}\x03\x02\x02\x02\
+	\u{5a2}\u{5a4}\x03\x02\x02\x02\u{5a3}\u{58d}\x03\x02\x02\x02\u{5a3}\u{596}\
+	\x03\x02\x02\x02\u{5a4}\u{5ad}\x03\x02\x02\x02\u{5a5}\u{5a7}\x07\u{80}\x02\
+	\x02\u{5a6}\u{5a5}\x03\x02\x02\x02\u{5a6}\u{5a7}\x03\x02\x02\x02\u{5a7}\
+	\u{5a8}\x03\x02\x02\x02\u{5a8}\u{5aa}\x07\x5e\x02\x02\u{5a9}\u{5ab}\x07\
+	\u{80}\x02\x02\u{5aa}\u{5a9}\x03\x02\x02\x02\u{5aa}\u{5ab}\x03\x02\x02\x02\
+	\u{5ab}\u{5ac}\x03\x02\x02\x02\u{5ac}\u{5ae}\x05\x64\x33\x02\u{5ad}\u{5a6}\
+	\x03\x02\x02\x02\u{5ad}\u{5ae}\x03\x02\x02\x02\u{5ae}\u{5b0}\x03\x02\x02\
+	\x02\u{5af}\u{5b1}\x07\u{80}\x02\x02\u{5b0}\u{5af}\x03\x02\x02\x02\u{5b0}\
+	\u{5b1}\x03\x02\x02\x02\u{5b1}\u{5b2}\x03\x02\x02\x02\u{5b2}\u{5b3}\x07\
+	\x5f\x02\x02\u{5b3}\u{a9}\x03\x02\x02\x02\u{5b4}\u{5b6}\x07\x60\x02\x02\
+	\u{5b5}\u{5b7}\x07\u{80}\x02\x02\u{5b6}\u{5b5}\x03\x02\x02\x02\u{5b6}\u{5b7}\
+	\x03\x02\x02\x02\u{5b7}\u{5b8}\x03\x02\x02\x02\u{5b8}\u{5ba}\x05\x64\x33\
+	\x02\u{5b9}\u{5bb}\x07\u{80}\x02\x02\u{5ba}\u{5b9}\x03\x02\x02\x02\u{5ba}\
+	\u{5bb}\x03\x02\x02\x02\u{5bb}\u{5bc}\x03\x02\x02\x02\u{5bc}\u{5be}\x07\
+	\x61\x02\x02\u{5bd}\u{5bf}\x07\u{80}\x02\x02\u{5be}\u{5bd}\x03\x02\x02\x02\
+	\u{5be}\u{5bf}\x03\x02\x02\x02\u{5bf}\u{5c0}\x03\x02\x02\x02\u{5c0}\u{5c1}\
+	\x05\x64\x33\x02\u{5c1}\u{ab}\x03\x02\x02\x02\u{5c2}\u{5c3}\x05\u{c0}\x61\
+	\x02\u{5c3}\u{ad}\x03\x02\x02\x02\u{5c4}\u{5c7}\x05\u{ba}\x5e\x02\u{5c5}\
+	\u{5c7}\x05\u{b8}\x5d\x02\u{5c6}\u{5c4}\x03\x02\x02\x02\u{5c6}\u{5c5}\x03\
+	\x02\x02\x02\u{5c7}\u{af}\x03\x02\x02\x02\u{5c8}\u{5ca}\x07\x1b\x02\x02\
+	\u{5c9}\u{5cb}\x07\u{80}\x02\x02\u{5ca}\u{5c9}\x03\x02\x02\x02\u{5ca}\u{5cb}\
+	\x03\x02\x02\x02\u{5cb}\u{5ed}\x03\x02\x02\x02\u{5cc}\u{5ce}\x05\u{b6}\x5c\
+	\x02\u{5cd}\u{5cf}\x07\u{80}\x02\x02\u{5ce}\u{5cd}\x03\x02\x02\x02\u{5ce}\
+	\u{5cf}\x03\x02\x02\x02\u{5cf}\u{5d0}\x03\x02\x02\x02\u{5d0}\u{5d2}\x07\
+	\x0c\x02\x02\u{5d1}\u{5d3}\x07\u{80}\x02\x02\u{5d2}\u{5d1}\x03\x02\x02\x02\
+	\u{5d2}\u{5d3}\x03\x02\x02\x02\u{5d3}\u{5d4}\x03\x02\x02\x02\u{5d4}\u{5d6}\
+	\x05\x64\x33\x02\u{5d5}\u{5d7}\x07\u{80}\x02\x02\u{5d6}\u{5d5}\x03\x02\x02\
+	\x02\u{5d6}\u{5d7}\x03\x02\x02\x02\u{5d7}\u{5ea}\x03\x02\x02\x02\u{5d8}\
+	\u{5da}\x07\x04\x02\x02\u{5d9}\u{5db}\x07\u{80}\x02\x02\u{5da}\u{5d9}\x03\
+	\x02\x02\x02\u{5da}\u{5db}\x03\x02\x02\x02\u{5db}\u{5dc}\x03\x02\x02\x02\
+	\u{5dc}\u{5de}\x05\u{b6}\x5c\x02\u{5dd}\u{5df}\x07\u{80}\x02\x02\u{5de}\
+	\u{5dd}\x03\x02\x02\x02\u{5de}\u{5df}\x03\x02\x02\x02\u{5df}\u{5e0}\x03\
+	\x02\x02\x02\u{5e0}\u{5e2}\x07\x0c\x02\x02\u{5e1}\u{5e3}\x07\u{80}\x02\x02\
+	\u{5e2}\u{5e1}\x03\x02\x02\x02\u{5e2}\u{5e3}\x03\x02\x02\x02\u{5e3}\u{5e4}\
+	\x03\x02\x02\x02\u{5e4}\u{5e6}\x05\x64\x33\x02\u{5e5}\u{5e7}\x07\u{80}\x02\
+	\x02\u{5e6}\u{5e5}\x03\x02\x02\x02\u{5e6}\u{5e7}\x03\x02\x02\x02\u{5e7}\
+	\u{5e9}\x03\x02\x02\x02\u{5e8}\u{5d8}\x03\x02\x02\x02\u{5e9}\u{5ec}\x03\
+	\x02\x02\x02\u{5ea}\u{5e8}\x03\x02\x02\x02\u{5ea}\u{5eb}\x03\x02\x02\x02\
+	\u{5eb}\u{5ee}\x03\x02\x02\x02\u{5ec}\u{5ea}\x03\x02\x02\x02\u{5ed}\u{5cc}\
+	\x03\x02\x02\x02\u{5ed}\u{5ee}\x03\x02\x02\x02\u{5ee}\u{5ef}\x03\x02\x02\
+	\x02\u{5ef}\u{5f0}\x07\x1c\x02\x02\u{5f0}\u{b1}\x03\x02\x02\x02\u{5f1}\u{5f4}\
+	\x07\x1d\x02\x02\u{5f2}\u{5f5}\x05\u{c0}\x61\x02\u{5f3}\u{5f5}\x07\x65\x02\
+	\x02\u{5f4}\u{5f2}\x03\x02\x02\x02\u{5f4}\u{5f3}\x03\x02\x02\x02\u{5f5}\
+	\u{b3}\x03\x02\x02\x02\u{5f6}\u{5fb}\x05\u{82}\x42\x02\u{5f7}\u{5f9}\x07\
+	\u{80}\x02\x02\u{5f8}\u{5f7}\x03\x02\x02\x02\u{5f8}\u{5f9}\x03\x02\x02\x02\
+	\u{5f9}\u{5fa}\x03\x02\x02\x02\u{5fa}\u{5fc}\x05\u{a6}\x54\x02\u{5fb}\u{5f8}\
+	\x03\x02\x02\x02\u{5fc}\u{5fd}\x03\x02\x02\x02\u{5fd}\u{5fb}\x03\x02\x02\
+	\x02\u{5fd}\u{5fe}\x03\x02\x02\x02\u{5fe}\u{b5}\x03\x02\x02\x02\u{5ff}\u{600}\
+	\x05\u{bc}\x5f\x02\u{600}\u{b7}\x03\x02\x02\x02\u{601}\u{602}\x09\x05\x02\
+	\x02\u{602}\u{b9}\x03\x02\x02\x02\u{603}\u{604}\x09\x06\x02\x02\u{604}\u{bb}\
+	\x03\x02\x02\x02\u{605}\u{608}\x05\u{c0}\x61\x02\u{606}\u{608}\x05\u{be}\
+	\x60\x02\u{607}\u{605}\x03\x02\x02\x02\u{607}\u{606}\x03\x02\x02\x02\u{608}\
+	\u{bd}\x03\x02\x02\x02\u{609}\u{60a}\x09\x07\x02\x02\u{60a}\u{bf}\x03\x02\
+	\x02\x02\u{60b}\u{60c}\x09\x08\x02\x02\u{60c}\u{c1}\x03\x02\x02\x02\u{60d}\
+	\u{60e}\x09\x09\x02\x02\u{60e}\u{c3}\x03\x02\x02\x02\u{60f}\u{610}\x09\x0a\
+	\x02\x02\u{610}\u{c5}\x03\x02\x02\x02\u{611}\u{612}\x09\x0b\x02\x02\u{612}\
+	\u{c7}\x03\x02\x02\x02\u{120}\u{c9}\u{cd}\u{d0}\u{d3}\u{db}\u{df}\u{e4}\
 	\u{eb}\u{f0}\u{f3}\u{f7}\u{fb}\u{ff}\u{105}\u{109}\u{10e}\u{113}\u{117}\
 	\u{11a}\u{11c}\u{120}\u{124}\u{129}\u{12d}\u{132}\u{136}\u{13f}\u{144}\u{148}\
 	\u{14c}\u{150}\u{153}\u{157}\u{161}\u{168}\u{175}\u{179}\u{17f}\u{186}\u{18b}\
@@ -20007,17 +20170,17 @@ const _serializedATN: &'static str =
 	\u{2d1}\u{2d5}\u{2db}\u{2df}\u{2e2}\u{2e5}\u{2eb}\u{2ee}\u{2f1}\u{2f5}\u{2fb}\
 	\u{2fe}\u{301}\u{305}\u{309}\u{30d}\u{30f}\u{313}\u{315}\u{318}\u{31c}\u{31e}\
 	\u{324}\u{328}\u{32c}\u{330}\u{333}\u{338}\u{33d}\u{342}\u{347}\u{34d}\u{351}\
-	\u{353}\u{357}\u{35b}\u{35d}\u{35f}\u{36e}\u{378}\u{382}\u{387}\u{38b}\u{392}\
-	\u{397}\u{39c}\u{3a0}\u{3a4}\u{3a8}\u{3ab}\u{3ad}\u{3b2}\u{3b6}\u{3ba}\u{3be}\
-	\u{3c2}\u{3c6}\u{3c9}\u{3cb}\u{3d0}\u{3d4}\u{3d9}\u{3de}\u{3e2}\u{3eb}\u{3ed}\
-	\u{3f3}\u{3f7}\u{3fe}\u{402}\u{406}\u{409}\u{415}\u{418}\u{426}\u{42a}\u{42f}\
-	\u{433}\u{436}\u{43d}\u{441}\u{445}\u{44c}\u{450}\u{454}\u{45a}\u{45e}\u{462}\
-	\u{468}\u{46c}\u{470}\u{476}\u{47a}\u{47e}\u{486}\u{48e}\u{494}\u{498}\u{49c}\
-	\u{4a0}\u{4a4}\u{4a7}\u{4ad}\u{4b2}\u{4b7}\u{4bc}\u{4c1}\u{4c6}\u{4c9}\u{4cd}\
-	\u{4d1}\u{4d7}\u{4dc}\u{4e0}\u{4e3}\u{4ed}\u{4f1}\u{4f5}\u{4f7}\u{4fb}\u{4ff}\
-	\u{503}\u{507}\u{50a}\u{512}\u{516}\u{51a}\u{51e}\u{522}\u{526}\u{52a}\u{52d}\
-	\u{53d}\u{542}\u{546}\u{54a}\u{54d}\u{550}\u{556}\u{55a}\u{55e}\u{560}\u{564}\
-	\u{568}\u{56c}\u{56e}\u{572}\u{576}\u{57c}\u{582}\u{587}\u{58b}\u{58f}\u{594}\
-	\u{596}\u{599}\u{59d}\u{5a0}\u{5a3}\u{5a9}\u{5ad}\u{5b1}\u{5b9}\u{5bd}\u{5c1}\
-	\u{5c5}\u{5c9}\u{5cd}\u{5d1}\u{5d5}\u{5d9}\u{5dd}\u{5e0}\u{5e7}\u{5eb}\u{5f0}\
-	\u{5fa}";
+	\u{353}\u{357}\u{35b}\u{35d}\u{35f}\u{365}\u{369}\u{377}\u{381}\u{38b}\u{390}\
+	\u{394}\u{39b}\u{3a0}\u{3a5}\u{3a9}\u{3ad}\u{3b1}\u{3b4}\u{3b6}\u{3bb}\u{3bf}\
+	\u{3c3}\u{3c7}\u{3cb}\u{3cf}\u{3d2}\u{3d4}\u{3d9}\u{3dd}\u{3e2}\u{3e7}\u{3eb}\
+	\u{3f4}\u{3f6}\u{3fc}\u{400}\u{407}\u{40b}\u{40f}\u{412}\u{41f}\u{422}\u{425}\
+	\u{433}\u{437}\u{43c}\u{440}\u{443}\u{44a}\u{44e}\u{452}\u{459}\u{45d}\u{461}\
+	\u{467}\u{46b}\u{46f}\u{475}\u{479}\u{47d}\u{483}\u{487}\u{48b}\u{493}\u{49b}\
+	\u{4a1}\u{4a5}\u{4a9}\u{4ad}\u{4b1}\u{4b4}\u{4ba}\u{4bf}\u{4c4}\u{4c9}\u{4ce}\
+	\u{4d3}\u{4d6}\u{4da}\u{4de}\u{4e4}\u{4e9}\u{4ed}\u{4f0}\u{4fa}\u{4fe}\u{502}\
+	\u{504}\u{508}\u{50c}\u{510}\u{514}\u{517}\u{51f}\u{523}\u{527}\u{52b}\u{52f}\
+	\u{533}\u{537}\u{53a}\u{54a}\u{54f}\u{553}\u{557}\u{55a}\u{55d}\u{563}\u{567}\
+	\u{56b}\u{56d}\u{571}\u{575}\u{579}\u{57b}\u{57f}\u{583}\u{589}\u{58f}\u{594}\
+	\u{598}\u{59c}\u{5a1}\u{5a3}\u{5a6}\u{5aa}\u{5ad}\u{5b0}\u{5b6}\u{5ba}\u{5be}\
+	\u{5c6}\u{5ca}\u{5ce}\u{5d2}\u{5d6}\u{5da}\u{5de}\u{5e2}\u{5e6}\u{5ea}\u{5ed}\
+	\u{5f4}\u{5f8}\u{5fd}\u{607}";
@@ -1,10 +1,25 @@
+use super::diagnostics::CompileError;
+use super::diagnostics::CompileResult;
+use super::ir::unquote;
+use super::ir::Aggregate;
+use super::ir::ArithmeticOp;
+use super::ir::AttributeFilter;
+use super::ir::CompareOp;
+use super::ir::Hops;
+use super::ir::Predicate;
 use super::ir::Property;
+use super::ir::StructuralFilter;
+use super::ir::UdfArg;
+use super::ir::UdfCall;
+use super::ir::Value;
+use super::ir::VisitorResults;
 use indexmap::IndexMap;
 use indexmap::IndexSet;
 use regex::Regex;
 use serde::Serialize;
 use std::str::FromStr;
 use strum_macros::EnumString;
+use utils::graph::graph_utils::ValueType;
 
 /********************************/
 // Helper structs
@@ -29,6 +44,8 @@ pub struct ScalarUdf {
     pub leaf_func: String,
     pub mid_func: String,
     pub func_impl: String,
+    // the type leaf_func returns, if its signature declares one
+    pub return_type: Option<String>,
 }
 
 #[derive(Serialize, Debug, Clone)]
@@ -39,6 +56,14 @@ pub struct AggregationUdf {
     pub exec_func: String,
     pub struct_name: String,
     pub func_impl: String,
+    // the key the result is stored and sent to storage under
+    pub key: String,
+    // whether the filter sends a group along with each value
+    pub grouped: bool,
+    // whether the filter sends the values of several aggregations in one object
+    pub keyed: bool,
+    // the type of the values it aggregates, from the optional arg_type header
+    pub arg_type: Option<String>,
 }
 
 #[derive(Serialize)]
@@ -47,20 +72,28 @@ pub struct CodeStruct {
     pub root_id: String,
     // code blocks used in incoming requests to collect properties
     pub collect_properties_blocks: Vec<String>,
+    // code blocks used when a call returns, to collect properties of the call
+    pub collect_edge_properties_blocks: Vec<String>,
     // map of numbers to properties in order to compress the messages
     pub id_to_property: IndexMap<String, u64>,
     // code blocks in outgoing responses, after matching
     pub response_blocks: Vec<String>,
+    // code blocks that check a mapping of the target graph while matching
+    pub expression_filter_blocks: Vec<String>,
     // code blocks to create target graph
     pub target_blocks: Vec<String>,
     // code blocks to be used in outgoing responses, to compute UDF before matching
     pub udf_blocks: Vec<String>,
+    // code blocks in outgoing responses, to drop properties no node can match
+    pub local_filter_blocks: Vec<String>,
     // code blocks to be used in outgoing responses, to compute UDF before matching
     pub trace_lvl_prop_blocks: Vec<String>,
     // where we store udf implementations
     pub scalar_udf_table: IndexMap<String, ScalarUdf>,
-    // where we store udf implementations
+    // the aggregations of the query, keyed by the key each one is stored under
     pub aggregation_udf_table: IndexMap<String, AggregationUdf>,
+    // the implementations of the aggregation UDFs, once for each UDF
+    pub aggregation_udf_impls: IndexSet<String>,
 }
 
 impl CodeStruct {
@@ -68,13 +101,17 @@ impl CodeStruct {
         CodeStruct {
             root_id: root_id.to_string(),
             collect_properties_blocks: Vec::new(),
+            collect_edge_properties_blocks: Vec::new(),
             id_to_property: IndexMap::default(),
             response_blocks: Vec::new(),
+            expression_filter_blocks: Vec::new(),
             target_blocks: Vec::new(),
             udf_blocks: Vec::new(),
+            local_filter_blocks: Vec::new(),
             trace_lvl_prop_blocks: Vec::new(),
             scalar_udf_table: IndexMap::default(),
             aggregation_udf_table: IndexMap::default(),
+            aggregation_udf_impls: IndexSet::default(),
         }
     }
 }
@@ -84,46 +121,253 @@ pub enum ScalarOrAggregationUdf {
     AggregationUdf(AggregationUdf),
 }
 
-pub fn parse_udf(udf: String) -> ScalarOrAggregationUdf {
+/// Returns the type a function in the UDF implementation returns, e.g. u32 for
+/// `fn leaf_height(graph: &Graph<...>) -> u32 {`.
+fn return_type(func_impl: &str, func: &str) -> Option<String> {
+    let re = Regex::new(&format!(
+        r"fn\s+{}\s*\([^{{]*->\s*(?P<return_type>[^{{\s]+)\s*\{{",
+        regex::escape(func)
+    ))
+    .unwrap();
+    re.captures(func_impl)
+        .map(|rc| rc.name("return_type").unwrap().as_str().to_string())
+}
+
+fn udf_type(type_str: &str) -> CompileResult<UdfType> {
+    UdfType::from_str(type_str).map_err(|_| {
+        CompileError::new(format!(
+            "Unknown udf_type {:?}, expected Scalar or Aggregation",
+            type_str
+        ))
+    })
+}
+
+pub fn parse_udf(udf: String) -> CompileResult<ScalarOrAggregationUdf> {
     let scalar_re = Regex::new(
             r".*udf_type:\s+(?P<udf_type>\w+)\n.*leaf_func:\s+(?P<leaf_func>\w+)\n.*mid_func:\s+(?P<mid_func>\w+)\n.*id:\s+(?P<id>\w+)",
         ).unwrap();
 
     if let Some(rc) = scalar_re.captures(&udf) {
-        let udf_type = UdfType::from_str(rc.name("udf_type").unwrap().as_str()).unwrap();
+        let udf_type = udf_type(rc.name("udf_type").unwrap().as_str())?;
         let leaf_func = String::from(rc.name("leaf_func").unwrap().as_str());
         let mid_func = String::from(rc.name("mid_func").unwrap().as_str());
         let id = String::from(rc.name("id").unwrap().as_str());
+        let return_type = return_type(&udf, &leaf_func);
 
-        return ScalarOrAggregationUdf::ScalarUdf(ScalarUdf {
+        return Ok(ScalarOrAggregationUdf::ScalarUdf(ScalarUdf {
             udf_type,
             leaf_func,
             mid_func,
             func_impl: udf,
+            return_type,
             id,
-        });
+        }));
     }
     let aggr_re = Regex::new(
-            r".*udf_type:\s+(?P<udf_type>\w+)\n.*init_func:\s+(?P<init_func>\w+)\n.*exec_func:\s+(?P<exec_func>\w+)\n.*struct_name:\s+(?P<struct_name>\w+)\n.*id:\s+(?P<id>\w+)",
+            r".*udf_type:\s+(?P<udf_type>\w+)\n.*init_func:\s+(?P<init_func>\w+)\n.*exec_func:\s+(?P<exec_func>\w+)\n.*struct_name:\s+(?P<struct_name>\w+)\n.*id:\s+(?P<id>\w+)(\n.*arg_type:\s+(?P<arg_type>\w+))?",
         ).unwrap();
     if let Some(rc) = aggr_re.captures(&udf) {
-        let udf_type = UdfType::from_str(rc.name("udf_type").unwrap().as_str()).unwrap();
+        let udf_type = udf_type(rc.name("udf_type").unwrap().as_str())?;
         let init_func = String::from(rc.name("init_func").unwrap().as_str());
         let exec_func = String::from(rc.name("exec_func").unwrap().as_str());
         let struct_name = String::from(rc.name("struct_name").unwrap().as_str());
         let id = String::from(rc.name("id").unwrap().as_str());
+        let arg_type = rc
+            .name("arg_type")
+            .map(|arg_type| arg_type.as_str().to_string());
 
-        return ScalarOrAggregationUdf::AggregationUdf(AggregationUdf {
+        return Ok(ScalarOrAggregationUdf::AggregationUdf(AggregationUdf {
             udf_type,
             init_func,
             exec_func,
             struct_name,
             func_impl: udf,
+            key: id.clone(),
+            grouped: false,
+            keyed: false,
+            arg_type,
             id,
-        });
+        }));
+    }
+    Err(CompileError::new(format!(
+        "Unable to parse input udf {:?}",
+        udf
+    )))
+}
+
+/// The state of the aggregation filter, with one entry for each aggregation of
+/// the query.  Aggregations of the same UDF keep separate state, so the entries
+/// are keyed by the key each aggregation is stored under.
+pub fn aggregation_state(
+    aggregates: &[Aggregate],
+    aggregation_udf_table: &IndexMap<String, AggregationUdf>,
+) -> CompileResult<IndexMap<String, AggregationUdf>> {
+    let mut state = IndexMap::new();
+    for agg in aggregates {
+        let mut udf = match aggregation_udf_table.get(&agg.udf_reference.id) {
+            Some(udf) => udf.clone(),
+            None => {
+                return Err(CompileError::new(format!(
+                    "Aggregation UDF {:?} is not defined, pass its implementation with --udf",
+                    agg.udf_reference.id
+                )))
+            }
+        };
+        udf.key = agg.key();
+        udf.grouped = !agg.group_by.is_empty();
+        udf.keyed = aggregates.len() > 1;
+        state.insert(agg.key(), udf);
+    }
+    Ok(state)
+}
+
+/// Parses the UDF implementations passed with --udf into tables of scalar and
+/// aggregation UDFs, keyed by id.
+pub fn udf_tables(
+    udfs: &[String],
+) -> CompileResult<(
+    IndexMap<String, ScalarUdf>,
+    IndexMap<String, AggregationUdf>,
+)> {
+    let mut scalar_udf_table = IndexMap::new();
+    let mut aggregation_udf_table = IndexMap::new();
+    for udf in udfs {
+        log::debug!("UDF: {:?}", udf);
+        match parse_udf(udf.clone())? {
+            ScalarOrAggregationUdf::ScalarUdf(udf) => {
+                scalar_udf_table.insert(udf.id.clone(), udf);
+            }
+            ScalarOrAggregationUdf::AggregationUdf(udf) => {
+                aggregation_udf_table.insert(udf.id.clone(), udf);
+            }
+        }
+    }
+    Ok((scalar_udf_table, aggregation_udf_table))
+}
+
+/// The envoy properties a query can refer to, along with their types.
+// TODO: dynamically retrieve this from https://www.envoyproxy.io/docs/envoy/latest/intro/arch_overview/advanced/attributes
+pub fn envoy_property_types() -> IndexMap<&'static str, &'static str> {
+    [
+        ("request.path", "String"),
+        ("request.url_path", "String"),
+        ("request.host", "String"),
+        ("request.scheme", "String"),
+        ("request.method", "String"),
+        ("request.headers", "Map"),
+        ("request.referer", "String"),
+        ("request.useragent", "String"),
+        ("request.time", "Timestamp"),
+        ("request.id", "String"),
+        ("request.protocol", "String"),
+        ("request.duration", "Duration"),
+        ("request.size", "int"),
+        ("request.total_size", "int"),
+        ("response.code", "int"),
+        ("response.code_details", "String"),
+        ("response.flags", "int"),
+        ("response.grpc_status", "int"),
+        ("response.headers", "Map"),
+        ("response.trailers", "Map"),
+        ("response.size", "int"),
+        ("response.total_size", "int"),
+        ("source.address", "String"),
+        ("source.port", "int"),
+        ("destination.address", "String"),
+        ("destination.port", "int"),
+        ("connection.id", "u64"),
+        ("connection.mlts", "bool"), // More strings here
+        ("upstream.port", "int"),
+        ("metadata", "metadata"), // and more strings here
+        ("filter_state", "Map"),
+        ("node", "Node"),
+        ("cluster_metadata", "metadata"),
+        ("listener_direction", "int"),
+        ("listener_metadata", "metadata"),
+        ("route_metadata", "metadata"),
+        ("upstream_host_metadata", "metadata"),
+        ("node.metadata.WORKLOAD_NAME", "String"),
+    ]
+    .iter()
+    .cloned()
+    .collect()
+}
+
+/// The properties the simulator gives its filters, along with their types.
+pub fn simulator_property_types() -> IndexMap<&'static str, &'static str> {
+    [
+        ("request.total_size", "int"),
+        ("request.time", "Timestamp"),
+        ("response.total_size", "int"),
+        ("node.metadata.WORKLOAD_NAME", "String"),
+    ]
+    .iter()
+    .cloned()
+    .collect()
+}
+
+/// The kind of value stored for each property and scalar UDF result, keyed
+/// like id_to_property.
+pub fn value_types(
+    id_to_property: &IndexMap<String, u64>,
+    scalar_udf_table: &IndexMap<String, ScalarUdf>,
+    property_to_type: &IndexMap<&str, &str>,
+) -> IndexMap<String, ValueType> {
+    id_to_property
+        .keys()
+        .map(|property| {
+            let type_name = match scalar_udf_table.get(property) {
+                Some(udf) => udf.return_type.as_deref(),
+                None => property_to_type.get(property.as_str()).cloned(),
+            };
+            let value_type = type_name
+                .and_then(ValueType::from_type_name)
+                .unwrap_or(ValueType::Str);
+            (property.clone(), value_type)
+        })
+        .collect()
+}
+
+/// Renders the value of an attribute filter as the Rust expression that builds
+/// the equivalent utils::graph::graph_utils::Value, e.g. `Value::Int(500)`.
+/// Integers take the type of the property they are compared to, and values
+/// the property can never hold are errors.  The prefix is as in predicate_to_code.
+pub fn value_to_code(
+    attr_filter: &AttributeFilter,
+    value_types: &IndexMap<String, ValueType>,
+    prefix: &str,
+) -> CompileResult<String> {
+    let property = attr_filter.property.trim_start_matches('.');
+    let value_type = value_types.get(property).cloned().unwrap_or(ValueType::Str);
+    let code = match (&attr_filter.value, value_type) {
+        (Value::Str(value), ValueType::Str) => Some(format!("{:?}.to_string()", value)),
+        (Value::Bool(value), ValueType::Bool) => Some(value.to_string()),
+        (Value::Int(value), ValueType::Int) => Some(value.to_string()),
+        (Value::Int(value), ValueType::Uint)
+        | (Value::Int(value), ValueType::Duration)
+        | (Value::Int(value), ValueType::Timestamp)
+            if *value >= 0 =>
+        {
+            Some(value.to_string())
+        }
+        (Value::Int(value), ValueType::Float) => Some(format!("{:?}", *value as f64)),
+        (Value::Float(value), ValueType::Float) => Some(format!("{:?}", value)),
+        (Value::Duration(value), ValueType::Duration) => Some(value.to_string()),
+        (Value::Parameter(name), _) => return Err(unbound_parameter(name)),
+        _ => None,
+    };
+    match code {
+        Some(code) => Ok(format!(
+            "{prefix}Value::{value_type:?}({code})",
+            prefix = prefix,
+            value_type = value_type,
+            code = code
+        )),
+        None => Err(CompileError::new(format!(
+            "{}.{} holds {:?} values, and can not be compared to {}",
+            attr_filter.node, property, value_type, attr_filter.value
+        ))),
     }
-    log::error!("Unable to parse input udf {:?}", udf);
-    std::process::exit(1);
 }
 
 pub fn assign_id_to_property(
@@ -147,3 +391,702 @@ pub fn assign_id_to_property(
     }
     id_to_property
 }
+
+/// Returns the id a property or scalar UDF result is stored under.  A query
+/// can only refer to the envoy properties and the scalar UDFs it is given.
+pub fn property_id(id_to_property: &IndexMap<String, u64>, property: &str) -> CompileResult<u64> {
+    match id_to_property.get(property) {
+        Some(id) => Ok(*id),
+        None => Err(CompileError::new(format!(
+            "Unknown property or scalar UDF {:?}",
+            property
+        ))),
+    }
+}
+
+/// Orders the UDF calls so that every call comes after the calls in its
+/// arguments, which are computed first.  Each UDF is computed once per node,
+/// so only one call per UDF is kept.
+pub fn udf_calls_in_dependency_order(udf_calls: &IndexSet<UdfCall>) -> CompileResult<Vec<UdfCall>> {
+    fn visit(call: &UdfCall, ordered: &mut IndexMap<String, UdfCall>) -> CompileResult<()> {
+        for arg in &call.args {
+            if let UdfArg::UdfCall(arg_call) = arg {
+                visit(arg_call, ordered)?;
+            }
+        }
+        if let Some(seen) = ordered.get(&call.id) {
+            if seen.signature() != call.signature() {
+                return Err(CompileError::new(format!(
+                    "UDF {:?} is called with different arguments in {:?} and {:?}",
+                    call.id,
+                    seen.to_ref_str(),
+                    call.to_ref_str()
+                )));
+            }
+            return Ok(());
+        }
+        ordered.insert(call.id.clone(), call.clone());
+        Ok(())
+    }
+    let mut ordered = IndexMap::new();
+    for call in udf_calls {
+        visit(call, &mut ordered)?;
+    }
+    Ok(ordered.into_iter().map(|(_, call)| call).collect())
+}
+
+/// Renders the values passed to a scalar UDF in addition to the trace graph,
+/// e.g. `, my_height_value.as_ref()?.to_string(), "x".to_string()`.  The node
+/// itself is not passed, properties are read from the node the UDF is computed
+/// on with property_at_node, and UDF results are the values computed before.
+/// UDFs are given the text of each value, whatever its type.  The code runs in
+/// a closure returning an Option, so a missing value leaves the UDF unset.
+pub fn udf_args_to_code(
+    call: &UdfCall,
+    scalar_udf_table: &IndexMap<String, ScalarUdf>,
+    id_to_property: &IndexMap<String, u64>,
+    property_at_node: &dyn Fn(u64) -> String,
+) -> CompileResult<String> {
+    let mut code = String::new();
+    for arg in &call.args {
+        match arg {
+            UdfArg::Property(prop) if prop.members.is_empty() => continue,
+            UdfArg::Property(prop) => {
+                code.push_str(", ");
+                code.push_str(&property_at_node(property_id(
+                    id_to_property,
+                    &prop.to_dot_string(),
+                )?));
+            }
+            UdfArg::UdfCall(arg_call) => {
+                if !scalar_udf_table.contains_key(&arg_call.id) {
+                    return Err(CompileError::new(format!(
+                        "Only scalar UDFs can be passed to {:?}, but {:?} is not one",
+                        call.id, arg_call.id
+                    )));
+                }
+                code.push_str(&format!(", my_{}_value.as_ref()?.to_string()", arg_call.id));
+            }
+            UdfArg::Literal(literal) => {
+                let literal = unquote(literal).unwrap_or_else(|| literal.clone());
+                code.push_str(&format!(", {:?}.to_string()", literal));
+            }
+            UdfArg::Parameter(name) => return Err(unbound_parameter(name)),
+        }
+    }
+    Ok(code)
+}
+
+/// Wraps the Rust expression computing the result of a scalar UDF into the
+/// Value it is stored as, which depends on the type the UDF returns.
+/// The prefix is as in predicate_to_code.
+pub fn udf_result_to_code(result: &str, value_type: ValueType, prefix: &str) -> String {
+    match value_type {
+        ValueType::Str => format!("{}Value::Str({}.to_string())", prefix, result),
+        ValueType::Bool => format!("{}Value::Bool({})", prefix, result),
+        ValueType::Int => format!("{}Value::Int({} as i64)", prefix, result),
+        ValueType::Float => format!("{}Value::Float({} as f64)", prefix, result),
+        _ => format!("{}Value::{:?}({} as u64)", prefix, value_type, result),
+    }
+}
+
+/// Returns the single node a WHERE conjunct applies to, which is "trace" for
+/// trace-level conjuncts.  Conjuncts are evaluated on one node at a time, so a
+/// conjunct comparing attributes of several nodes cannot be compiled.
+pub fn predicate_node(predicate: &Predicate) -> CompileResult<String> {
+    let nodes = predicate.nodes();
+    if nodes.len() != 1 {
+        return Err(CompileError::new(format!(
+            "Predicates may only refer to one node, but {:?} refers to {:?}",
+            predicate, nodes
+        )));
+    }
+    Ok(nodes[0].clone())
+}
+
+/// Renders a predicate as the Rust expression that builds the equivalent
+/// utils::graph::graph_utils::Predicate.  The prefix is how the generated code
+/// refers to the graph_utils module, e.g. "graph_utils::" or "".
+pub fn predicate_to_code(
+    predicate: &Predicate,
+    id_to_property: &IndexMap<String, u64>,
+    value_types: &IndexMap<String, ValueType>,
+    prefix: &str,
+) -> CompileResult<String> {
+    let operands_to_code = |operands: &[Predicate]| -> CompileResult<String> {
+        let mut code = String::new();
+        for operand in operands {
+            code.push_str(&predicate_to_code(
+                operand,
+                id_to_property,
+                value_types,
+                prefix,
+            )?);
+            code.push_str(", ");
+        }
+        Ok(code)
+    };
+    Ok(match predicate {
+        Predicate::Attribute(attr_filter) => format!(
+            "{prefix}Predicate::Compare({id}, {prefix}CompareOp::{op:?}, {value})",
+            prefix = prefix,
+            id = property_id(id_to_property, attr_filter.property.trim_start_matches('.'))?,
+            op = attr_filter.op,
+            value = value_to_code(attr_filter, value_types, prefix)?
+        ),
+        Predicate::Exists(existence_filter) => format!(
+            "{prefix}Predicate::Exists({id})",
+            prefix = prefix,
+            id = property_id(
+                id_to_property,
+                existence_filter.property.trim_start_matches('.')
+            )?
+        ),
+        Predicate::And(operands) => format!(
+            "{}Predicate::And(vec![{}])",
+            prefix,
+            operands_to_code(operands)?
+        ),
+        Predicate::Or(operands) => format!(
+            "{}Predicate::Or(vec![{}])",
+            prefix,
+            operands_to_code(operands)?
+        ),
+        Predicate::Xor(operands) => format!(
+            "{}Predicate::Xor(vec![{}])",
+            prefix,
+            operands_to_code(operands)?
+        ),
+        Predicate::Not(operand) => format!(
+            "{}Predicate::Not(Box::new({}))",
+            prefix,
+            predicate_to_code(operand, id_to_property, value_types, prefix)?
+        ),
+    })
+}
+
+/// Renders the weights of the edges of a structural filter that are not plain
+/// direct calls: edges that span several calls, edges whose relationship
+/// variable has WHERE conjuncts, as in (a)-[r]->(b) WHERE r.response.code = 200,
+/// and the edges OPTIONAL MATCH clauses attach their nodes with.
+/// The prefix is as in predicate_to_code.
+pub fn edge_filters_to_code(
+    struct_filter: &StructuralFilter,
+    attr_filters: &[Predicate],
+    id_to_property: &IndexMap<String, u64>,
+    value_types: &IndexMap<String, ValueType>,
+    prefix: &str,
+) -> CompileResult<Vec<String>> {
+    let mut edge_filters: IndexMap<(String, String), (Hops, Vec<String>, bool)> = IndexMap::new();
+    for (edge, hops) in &struct_filter.edge_hops {
+        edge_filters.insert(edge.clone(), (*hops, Vec::new(), false));
+    }
+    let direct = Hops {
+        min: 1,
+        max: Some(1),
+    };
+    for predicate in attr_filters {
+        if let Some(edge) = struct_filter.relationships.get(&predicate_node(predicate)?) {
+            edge_filters
+                .entry(edge.clone())
+                .or_insert((direct, Vec::new(), false))
+                .1
+                .push(predicate_to_code(
+                    predicate,
+                    id_to_property,
+                    value_types,
+                    prefix,
+                )?);
+        }
+    }
+    for edge in struct_filter.optional_attachments() {
+        edge_filters
+            .entry(edge)
+            .or_insert((direct, Vec::new(), false))
+            .2 = true;
+    }
+    Ok(edge_filters
+        .iter()
+        .map(|(edge, (hops, predicates, optional))| {
+            format!(
+                " (\"{edge1}\".to_string(), \"{edge2}\".to_string(), {prefix}EdgeFilter::new({prefix}Hops::new({min}, {max:?}), vec!({predicates})){optional} ), ",
+                edge1 = edge.0,
+                edge2 = edge.1,
+                prefix = prefix,
+                min = hops.min,
+                max = hops.max,
+                predicates = predicates.join(", "),
+                optional = if *optional { ".optional()" } else { "" }
+            )
+        })
+        .collect())
+}
+
+/// The workloads a WHERE conjunct pins its node to, as in
+/// b.node.metadata.WORKLOAD_NAME = 'reviews-v1' or an IN list of names.
+fn pinned_by(predicate: &Predicate) -> Option<IndexSet<String>> {
+    match predicate {
+        Predicate::Attribute(attr_filter)
+            if attr_filter.property.trim_start_matches('.') == "node.metadata.WORKLOAD_NAME"
+                && attr_filter.op == CompareOp::Eq =>
+        {
+            match &attr_filter.value {
+                Value::Str(workload) => {
+                    let mut workloads = IndexSet::new();
+                    workloads.insert(workload.clone());
+                    Some(workloads)
+                }
+                _ => None,
+            }
+        }
+        Predicate::Or(operands) => {
+            let mut workloads = IndexSet::new();
+            for operand in operands {
+                workloads.extend(pinned_by(operand)?);
+            }
+            Some(workloads)
+        }
+        _ => None,
+    }
+}
+
+/// The workloads a node of the pattern is pinned to by its WHERE conjuncts, over
+/// all UNION branches with the node.  None if the node may be any service.
+pub fn pinned_workloads(query_data: &VisitorResults, node: &str) -> Option<IndexSet<String>> {
+    let mut workloads = IndexSet::new();
+    let mut found = false;
+    for (branch, struct_filter) in query_data.struct_filters.iter().enumerate() {
+        if !struct_filter.vertices.contains(node) {
+            continue;
+        }
+        let branch_workloads = query_data
+            .branch_attr_filters(branch)
+            .iter()
+            .filter(|predicate| {
+                let nodes = predicate.nodes();
+                nodes.len() == 1 && nodes.contains(node)
+            })
+            .find_map(pinned_by)?;
+        workloads.extend(branch_workloads);
+        found = true;
+    }
+    if found {
+        Some(workloads)
+    } else {
+        None
+    }
+}
+
+/// Works out which properties a service has to collect, so that nothing is
+/// ferried that no node of the pattern reads.  A property is collected once
+/// however many nodes read it, and one that is only read by nodes pinned to
+/// workloads, or by the trace, is only collected by those workloads or by the
+/// root.  The workload name of a node is never collected, since the node is
+/// added with it.  Returns the properties with the condition on the current
+/// service under which each is collected, None if every service collects it.
+/// The workload is how the generated code names the current service, and the
+/// callee the service it called, for the properties of calls.
+pub fn needed_properties(
+    query_data: &VisitorResults,
+    properties: &IndexSet<Property>,
+    scalar_udf_table: &IndexMap<String, ScalarUdf>,
+    workload: &str,
+    callee: &str,
+) -> Vec<(Property, Option<String>)> {
+    fn udf_arg_properties(call: &UdfCall, properties: &mut IndexSet<String>) {
+        for arg in &call.args {
+            match arg {
+                UdfArg::Property(prop) => {
+                    properties.insert(prop.to_dot_string());
+                }
+                UdfArg::UdfCall(arg_call) => udf_arg_properties(arg_call, properties),
+                _ => {}
+            }
+        }
+    }
+    // scalar UDFs are computed by every service, from its own properties, while
+    // aggregation UDFs only see the values sent to storage
+    let mut udf_args = IndexSet::new();
+    for call in &query_data.udf_calls {
+        if scalar_udf_table.contains_key(&call.id) {
+            udf_arg_properties(call, &mut udf_args);
+        }
+    }
+    let is_one_of = |service: &str, workloads: Option<IndexSet<String>>| {
+        workloads.map(|workloads| {
+            let checks: Vec<String> = workloads
+                .iter()
+                .map(|name| format!("{} == {:?}", service, name))
+                .collect();
+            if checks.len() == 1 {
+                checks[0].clone()
+            } else {
+                format!("({})", checks.join(" || "))
+            }
+        })
+    };
+
+    let mut needed: IndexMap<String, (Property, Option<IndexSet<String>>)> = IndexMap::new();
+    for property in properties {
+        let dot_str = property.to_dot_string();
+        if property.members.is_empty() || scalar_udf_table.contains_key(&dot_str) {
+            continue;
+        }
+        let condition = if let Some(edge) = query_data.relationship(&property.parent) {
+            // the caller collects the properties of the call
+            match (
+                is_one_of(workload, pinned_workloads(query_data, &edge.0)),
+                is_one_of(callee, pinned_workloads(query_data, &edge.1)),
+            ) {
+                (Some(caller), Some(callee)) => Some(format!("{} && {}", caller, callee)),
+                (caller, callee) => caller.or(callee),
+            }
+        } else if dot_str == "node.metadata.WORKLOAD_NAME" {
+            continue;
+        } else if property.parent == "trace" {
+            // the root reads the properties of the trace from its own node
+            is_one_of(
+                workload,
+                Some(std::iter::once(query_data.root_id.clone()).collect()),
+            )
+        } else {
+            is_one_of(workload, pinned_workloads(query_data, &property.parent))
+        };
+        let condition = if udf_args.contains(&dot_str) {
+            None
+        } else {
+            condition
+        };
+        let entry = needed
+            .entry(dot_str)
+            .or_insert_with(|| (property.clone(), Some(IndexSet::new())));
+        entry.1 = match (entry.1.take(), condition) {
+            (Some(mut conditions), Some(condition)) => {
+                conditions.insert(condition);
+                Some(conditions)
+            }
+            _ => None,
+        };
+    }
+    needed
+        .into_iter()
+        .map(|(_, (property, conditions))| {
+            let conditions = conditions
+                .map(|conditions| conditions.into_iter().collect::<Vec<_>>().join(" || "));
+            (property, conditions)
+        })
+        .collect()
+}
+
+/// The WHERE conjuncts of each node of the pattern, over all UNION branches.
+/// Conjuncts on a node only read the properties and UDF results of the service
+/// it is matched to, which that service already has once its response goes
+/// out, so it can check them itself.  Returns None if some node has no
+/// conjuncts, since then any service may be that node.
+pub fn local_filters(query_data: &VisitorResults) -> CompileResult<Option<Vec<Vec<Predicate>>>> {
+    let mut local_filters = Vec::new();
+    for (branch, struct_filter) in query_data.struct_filters.iter().enumerate() {
+        let attr_filters = query_data.branch_attr_filters(branch);
+        for vertex in &struct_filter.vertices {
+            let mut vertex_filters = Vec::new();
+            for predicate in &attr_filters {
+                if &predicate_node(predicate)? == vertex {
+                    vertex_filters.push(predicate.clone());
+                }
+            }
+            if vertex_filters.is_empty() {
+                return Ok(None);
+            }
+            local_filters.push(vertex_filters);
+        }
+    }
+    Ok(Some(local_filters))
+}
+
+/// The ids of the envoy properties that are only collected for the nodes of the
+/// pattern, which a service that can not be any of these nodes does not need
+/// to ferry.  The workload name, trace-level properties, UDF results and
+/// properties checked with IS NULL are kept: the root reads its trace-level
+/// properties from its own node, UDFs are computed from the results of the
+/// children, and dropping a property would make IS NULL hold.
+pub fn node_property_ids(
+    query_data: &VisitorResults,
+    id_to_property: &IndexMap<String, u64>,
+    scalar_udf_table: &IndexMap<String, ScalarUdf>,
+) -> CompileResult<Vec<u64>> {
+    let mut kept: IndexSet<String> = query_data
+        .attr_filters
+        .iter()
+        .flat_map(|predicate| predicate.properties(true))
+        .collect();
+    kept.extend(
+        query_data
+            .properties
+            .iter()
+            .filter(|property| property.parent == "trace")
+            .map(|property| property.to_dot_string()),
+    );
+    let mut ids = IndexSet::new();
+    for property in &query_data.properties {
+        let dot_str = property.to_dot_string();
+        if property.parent == "trace"
+            || query_data.relationship(&property.parent).is_some()
+            || property.members.is_empty()
+            || scalar_udf_table.contains_key(&dot_str)
+            || kept.contains(&dot_str)
+        {
+            continue;
+        }
+        let id = property_id(id_to_property, &dot_str)?;
+        // the workload name is added with the node itself
+        if id != 0 {
+            ids.insert(id);
+        }
+    }
+    let mut ids: Vec<u64> = ids.into_iter().collect();
+    ids.sort_unstable();
+    Ok(ids)
+}
+
+/// Drops the collected properties of the current service before its response
+/// goes out, if it satisfies the conjuncts of none of the nodes of the pattern.
+/// The root could not match the service to any node, so it would never read
+/// them.  The workload is how the generated code names the current service,
+/// and the prefix is as in predicate_to_code.
+pub fn make_local_filter_blocks(
+    query_data: &VisitorResults,
+    id_to_property: &IndexMap<String, u64>,
+    value_types: &IndexMap<String, ValueType>,
+    scalar_udf_table: &IndexMap<String, ScalarUdf>,
+    workload: &str,
+    prefix: &str,
+) -> CompileResult<Vec<String>> {
+    let local_filters = match local_filters(query_data)? {
+        Some(local_filters) => local_filters,
+        None => return Ok(Vec::new()),
+    };
+    let ids = node_property_ids(query_data, id_to_property, scalar_udf_table)?;
+    if ids.is_empty() {
+        return Ok(Vec::new());
+    }
+    let mut filters_code = String::new();
+    for vertex_filters in &local_filters {
+        filters_code.push_str("vec!(");
+        for predicate in vertex_filters {
+            filters_code.push_str(&predicate_to_code(
+                predicate,
+                id_to_property,
+                value_types,
+                prefix,
+            )?);
+            filters_code.push_str(", ");
+        }
+        filters_code.push_str("), ");
+    }
+    Ok(vec![format!(
+        "
+        // a service that satisfies the filters of no node can not be matched,
+        // so the properties collected for the nodes are not ferried
+        let node = {prefix}get_node_with_id(&fd.trace_graph, {workload}).unwrap();
+        let local_filters: Vec<Vec<{prefix}Predicate>> = vec!({filters});
+        let properties = &fd.trace_graph.node_weight(node).unwrap().1;
+        if !local_filters
+            .iter()
+            .any(|filters| filters.iter().all(|filter| filter.evaluate(properties)))
+        {{
+            fd.trace_graph
+                .node_weight_mut(node)
+                .unwrap()
+                .1
+                .retain(|id, _| ![{ids}].contains(id));
+        }}
+        ",
+        prefix = prefix,
+        workload = workload,
+        filters = filters_code,
+        ids = ids
+            .iter()
+            .map(|id| id.to_string())
+            .collect::<Vec<String>>()
+            .join(", ")
+    )])
+}
+
+/// The variable the block given to make_optional_block assigns the value to.
+pub fn present_variable(target: &str) -> String {
+    format!("{}_present", target)
+}
+
+/// Assigns None to the target variable, an Option<String>, instead of running
+/// the block when the mapping leaves out one of the given nodes, as it does for
+/// nodes added by an OPTIONAL MATCH that the trace does not have.  Otherwise the
+/// block assigns the variable of present_variable(target), which is the value.
+pub fn make_optional_block(nodes: &[String], block: &str, target: &str) -> String {
+    let missing = nodes
+        .iter()
+        .map(|node| {
+            format!(
+                "!mapping.iter().any(|map| target_graph.node_weight(map.0).unwrap().0 == \"{node}\")",
+                node = node
+            )
+        })
+        .collect::<Vec<String>>()
+        .join(" || ");
+    format!(
+        "if {missing} {{
+            {target} = None;
+        }} else {{
+            let {present}: String;
+            {block}
+            {target} = Some({present});
+        }}\n",
+        missing = missing,
+        block = block,
+        present = present_variable(target),
+        target = target
+    )
+}
+
+/// Declares a variable and runs the block that assigns it, in a scope of its
+/// own so that the locals of blocks run one after another do not clash.  A
+/// nullable variable is an Option<String>, as make_optional_block assigns.
+pub fn make_binding_block(variable: &str, block: &str, nullable: bool) -> String {
+    format!(
+        "let {variable}: {value_type};
+        {{
+            {block}
+        }}\n",
+        variable = variable,
+        value_type = if nullable { "Option<String>" } else { "String" },
+        block = block
+    )
+}
+
+/// Assigns a value that may be null to the target variable as JSON, so that
+/// storage can tell a missing value from the string "null".  The block assigns
+/// the nullable variable.
+pub fn make_json_value_block(block: &str, variable: &str, target: &str) -> String {
+    format!(
+        "{binding}{target} = serde_json::Value::from({variable}).to_string();\n",
+        binding = make_binding_block(variable, block, true),
+        variable = variable,
+        target = target
+    )
+}
+
+/// Assigns a value that may be null to the target variable, or has no value
+/// stored when it is null, since aggregations skip missing values.  The block
+/// assigns the nullable variable.
+pub fn make_skip_null_block(block: &str, variable: &str, target: &str) -> String {
+    format!(
+        "{binding}{target} = match {variable} {{
+            Some(value) => value,
+            None => {{
+                // there is nothing to aggregate
+                return None;
+            }}
+        }};\n",
+        binding = make_binding_block(variable, block, true),
+        variable = variable,
+        target = target
+    )
+}
+
+/// Assigns to the target variable the JSON object that holds each field's
+/// variable under the field's name, e.g. the columns of a record.  Nullable
+/// variables that are None are null.
+pub fn make_record_block(fields: &[(String, String)], target: &str) -> String {
+    let mut record_block = format!("let mut {}_record = serde_json::Map::new();\n", target);
+    for (name, variable) in fields {
+        record_block.push_str(&format!(
+            "{target}_record.insert({name:?}.to_string(), serde_json::Value::from({variable}));\n",
+            target = target,
+            name = name,
+            variable = variable
+        ));
+    }
+    record_block.push_str(&format!(
+        "{target} = serde_json::Value::Object({target}_record).to_string();\n",
+        target = target
+    ));
+    record_block
+}
+
+/// The error for a parameter that is left in the query when code is generated,
+/// because bind_parameters was not given a value for it.
+pub fn unbound_parameter(name: &str) -> CompileError {
+    CompileError::new(format!(
+        "Parameter ${} has no value. Pass it with --param {}=<value>",
+        name, name
+    ))
+}
+
+/// Assigns a literal from the query to the target variable, with string
+/// literals unquoted.
+pub fn make_literal_block(literal: &str, target: &str) -> String {
+    let literal = unquote(literal).unwrap_or_else(|| literal.to_string());
+    format!("{} = {:?}.to_string();\n", target, literal)
+}
+
+/// The variables the operands of a binary expression computing the target
+/// variable are assigned to.
+pub fn operand_variables(target: &str) -> (String, String) {
+    (format!("{}_lhs", target), format!("{}_rhs", target))
+}
+
+/// Assigns the result of an arithmetic expression to the target variable.  The
+/// operand blocks assign the variables of operand_variables.  The prefix is
+/// how the generated code refers to the graph_utils module, as in
+/// predicate_to_code.
+pub fn make_arithmetic_block(
+    op: ArithmeticOp,
+    left_block: &str,
+    right_block: &str,
+    prefix: &str,
+    target: &str,
+) -> String {
+    let (lhs, rhs) = operand_variables(target);
+    format!(
+        "{left}{right}{target} = match {prefix}ArithmeticOp::{op:?}.apply(&{lhs}, &{rhs}) {{
+            Some(result) => result,
+            None => {{
+                log::error!(\"Could not compute {{}} {symbol} {{}}\", {lhs}, {rhs});
+                return None;
+            }}
+        }};\n",
+        left = make_binding_block(&lhs, left_block, false),
+        right = make_binding_block(&rhs, right_block, false),
+        target = target,
+        lhs = lhs,
+        rhs = rhs,
+        prefix = prefix,
+        op = op,
+        symbol = op.symbol()
+    )
+}
+
+/// Checks an expression filter against a mapping of the target graph.  The
+/// matcher only accepts mappings that satisfy it, so the value is stored for
+/// one that does.  The operand blocks assign the variables of
+/// operand_variables("filter").
+pub fn make_expression_filter_block(
+    op: CompareOp,
+    left_block: &str,
+    right_block: &str,
+    prefix: &str,
+) -> String {
+    let (lhs, rhs) = operand_variables("filter");
+    format!(
+        "{{
+            {left}{right}if !{prefix}CompareOp::{op:?}.compare(&{lhs}, &{rhs}) {{
+                return None;
+            }}
+        }}\n",
+        left = make_binding_block(&lhs, left_block, false),
+        right = make_binding_block(&rhs, right_block, false),
+        lhs = lhs,
+        rhs = rhs,
+        prefix = prefix,
+        op = op
+    )
+}
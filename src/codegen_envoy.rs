@@ -1,12 +1,36 @@
+use super::codegen_common::aggregation_state;
 use super::codegen_common::assign_id_to_property;
-use super::codegen_common::parse_udf;
+use super::codegen_common::edge_filters_to_code;
+use super::codegen_common::envoy_property_types;
+use super::codegen_common::make_arithmetic_block;
+use super::codegen_common::make_binding_block;
+use super::codegen_common::make_expression_filter_block;
+use super::codegen_common::make_json_value_block;
+use super::codegen_common::make_literal_block;
+use super::codegen_common::make_local_filter_blocks;
+use super::codegen_common::make_optional_block;
+use super::codegen_common::make_record_block;
+use super::codegen_common::make_skip_null_block;
+use super::codegen_common::needed_properties;
+use super::codegen_common::operand_variables;
+use super::codegen_common::predicate_node;
+use super::codegen_common::predicate_to_code;
+use super::codegen_common::present_variable;
+use super::codegen_common::property_id;
+use super::codegen_common::udf_args_to_code;
+use super::codegen_common::udf_calls_in_dependency_order;
+use super::codegen_common::udf_result_to_code;
+use super::codegen_common::udf_tables;
+use super::codegen_common::unbound_parameter;
+use super::codegen_common::value_types;
 use super::codegen_common::AggregationUdf;
 use super::codegen_common::CodeStruct;
-use super::codegen_common::ScalarOrAggregationUdf;
 use super::codegen_common::ScalarUdf;
+use super::diagnostics::CompileError;
+use super::diagnostics::CompileResult;
 use super::ir::Aggregate;
-use super::ir::AttributeFilter;
 use super::ir::IrReturnEnum;
+use super::ir::Predicate;
 use super::ir::Property;
 use super::ir::PropertyOrUDF;
 use super::ir::StructuralFilter;
@@ -14,76 +38,128 @@ use super::ir::UdfCall;
 use super::ir::VisitorResults;
 use indexmap::IndexMap;
 use indexmap::IndexSet;
+use utils::graph::graph_utils::ValueType;
 
 /********************************/
 // Code Generation
 /********************************/
 
-fn make_struct_filter_blocks(
-    attr_filters: &[AttributeFilter],
-    struct_filters: &[StructuralFilter],
+/// Declares the vertices, edges and node filters of one target graph.
+fn make_target_graph_blocks(
+    attr_filters: &[Predicate],
+    struct_filter: &StructuralFilter,
     id_to_property: &IndexMap<String, u64>,
-) -> Vec<String> {
+    value_types: &IndexMap<String, ValueType>,
+) -> CompileResult<Vec<String>> {
     let mut target_blocks = Vec::new();
-    for struct_filter in struct_filters {
-        target_blocks.push(" let vertices = vec!( ".to_string());
-        for vertex in &struct_filter.vertices {
-            target_blocks.push(format!("\"{vertex}\".to_string(),", vertex = vertex));
-        }
-        target_blocks.push(" );\n".to_string());
-
-        target_blocks.push("        let edges = vec!( ".to_string());
-        for edge in &struct_filter.edges {
-            target_blocks.push(format!(
-                " (\"{edge1}\".to_string(), \"{edge2}\".to_string() ), ",
-                edge1 = edge.0,
-                edge2 = edge.1
-            ));
-        }
-        target_blocks.push(" );\n".to_string());
+    target_blocks.push(" let vertices = vec!( ".to_string());
+    for vertex in &struct_filter.vertices {
+        target_blocks.push(format!("\"{vertex}\".to_string(),", vertex = vertex));
+    }
+    target_blocks.push(" );\n".to_string());
+
+    target_blocks.push("        let edges = vec!( ".to_string());
+    for edge in &struct_filter.edges {
+        target_blocks.push(format!(
+            " (\"{edge1}\".to_string(), \"{edge2}\".to_string() ), ",
+            edge1 = edge.0,
+            edge2 = edge.1
+        ));
+    }
+    target_blocks.push(" );\n".to_string());
 
-        let ids_to_prop_block = "        let mut ids_to_properties: IndexMap<String, IndexMap<u64, String>> = IndexMap::new();\n".to_string();
-        target_blocks.push(ids_to_prop_block);
+    target_blocks.push("        let undirected_edges = vec!( ".to_string());
+    for edge in &struct_filter.undirected_edges {
+        target_blocks.push(format!(
+            " (\"{edge1}\".to_string(), \"{edge2}\".to_string() ), ",
+            edge1 = edge.0,
+            edge2 = edge.1
+        ));
+    }
+    target_blocks.push(" );\n".to_string());
 
-        for vertex in &struct_filter.vertices {
-            let ids_to_properties_hashmap_init = format!(
-                "        ids_to_properties.insert(\"{node}\".to_string(), IndexMap::new());\n",
-                node = vertex
+    target_blocks.push("        let edge_hops = vec!( ".to_string());
+    target_blocks.extend(edge_filters_to_code(
+        struct_filter,
+        attr_filters,
+        id_to_property,
+        value_types,
+        "",
+    )?);
+    target_blocks.push(" );\n".to_string());
+
+    let ids_to_filters_block =
+        "        let mut ids_to_filters: IndexMap<String, Vec<Predicate>> = IndexMap::new();\n"
+            .to_string();
+    target_blocks.push(ids_to_filters_block);
+
+    for vertex in &struct_filter.vertices {
+        let ids_to_filters_init = format!(
+            "        ids_to_filters.insert(\"{node}\".to_string(), Vec::new());\n",
+            node = vertex
+        );
+        target_blocks.push(ids_to_filters_init);
+    }
+    for predicate in attr_filters {
+        let node = predicate_node(predicate)?;
+        // conjuncts on relationships are part of the edge filters
+        if struct_filter.vertices.contains(&node) {
+            let get_filters = format!(
+                "        let {node}_filters = ids_to_filters.get_mut(\"{node}\").unwrap();\n",
+                node = node
             );
-            target_blocks.push(ids_to_properties_hashmap_init);
-        }
-        for property_filter in attr_filters {
-            if property_filter.node != "trace" {
-                let mut property_name_without_period = property_filter.property.clone();
-                if property_name_without_period.starts_with('.') {
-                    property_name_without_period.remove(0);
-                }
-                let get_hashmap = format!(
-                    "        let mut {node}_hashmap = ids_to_properties.get_mut(\"{node}\").unwrap();\n",
-                    node = property_filter.node
-                );
-                target_blocks.push(get_hashmap);
-                let fill_in_hashmap = format!("        {node}_hashmap.insert({property_name}, \"{property_value}\".to_string());\n",
-                                                   node=property_filter.node,
-                                                   property_name=id_to_property[&property_name_without_period],
-                                                   property_value=property_filter.value);
-                target_blocks.push(fill_in_hashmap);
-            }
+            target_blocks.push(get_filters);
+            let fill_in_filters = format!(
+                "        {node}_filters.push({predicate});\n",
+                node = node,
+                predicate = predicate_to_code(predicate, id_to_property, value_types, "")?
+            );
+            target_blocks.push(fill_in_filters);
         }
-        let make_graph =
-            "        return generate_target_graph(vertices, edges, ids_to_properties);\n"
-                .to_string();
+    }
+    Ok(target_blocks)
+}
 
-        target_blocks.push(make_graph);
+fn make_struct_filter_blocks(
+    query_data: &VisitorResults,
+    id_to_property: &IndexMap<String, u64>,
+    value_types: &IndexMap<String, ValueType>,
+) -> CompileResult<Vec<String>> {
+    let mut graphs = Vec::new();
+    for (branch, struct_filter) in query_data.struct_filters.iter().enumerate() {
+        let attr_filters = query_data.branch_attr_filters(branch);
+        graphs.push(make_target_graph_blocks(
+            &attr_filters,
+            struct_filter,
+            id_to_property,
+            value_types,
+        )?);
+    }
+    let mut target_blocks = Vec::new();
+    if graphs.len() <= 1 {
+        for graph_blocks in graphs {
+            target_blocks.extend(graph_blocks);
+            target_blocks.push("        return generate_filtered_target_graph(vertices, edges, undirected_edges, edge_hops, ids_to_filters).into_iter().collect();\n".to_string());
+        }
+        return Ok(target_blocks);
     }
-    target_blocks
+    // one target graph for each UNION branch
+    target_blocks.push("        let mut target_graphs = Vec::new();\n".to_string());
+    for graph_blocks in graphs {
+        target_blocks.push("        {\n".to_string());
+        target_blocks.extend(graph_blocks);
+        target_blocks.push("        if let Some(target_graph) = generate_filtered_target_graph(vertices, edges, undirected_edges, edge_hops, ids_to_filters) {\n            target_graphs.push(target_graph);\n        }\n        }\n".to_string());
+    }
+    target_blocks.push("        return target_graphs;\n".to_string());
+    Ok(target_blocks)
 }
 
 fn make_attr_filter_blocks(
     root_id: &str,
-    attr_filters: &[AttributeFilter],
+    attr_filters: &[Predicate],
     id_to_property: &IndexMap<String, u64>,
-) -> Vec<String> {
+    value_types: &IndexMap<String, ValueType>,
+) -> CompileResult<Vec<String>> {
     // TODO: does the numbering of properties work here?
     // for everything except trace level attributes, the UDF/envoy property
     // collection will make the attribute filtering happen at the same time as
@@ -97,17 +173,12 @@ fn make_attr_filter_blocks(
     let init_trace_prop_str = "        let mut trace_prop_str : String;\n".to_string();
     trace_lvl_prop_blocks.push(init_trace_prop_str);
 
-    for attr_filter in attr_filters {
-        if attr_filter.node == "trace" {
-            let mut prop = attr_filter.property.clone();
-            if prop.starts_with('.') {
-                prop.remove(0);
-            }
+    for predicate in attr_filters {
+        if predicate_node(predicate)? == "trace" {
             let trace_filter_block = format!(
                 "
                 let root_node = get_node_with_id(&fd.trace_graph, \"{root_id}\").unwrap();
-                if ! ( fd.trace_graph.node_weight(root_node).unwrap().1.contains_key({prop_name}) &&
-                    fd.trace_graph.node_weight(root_node).unwrap().1[{prop_name}] == \"{value}\" ){{
+                if !{predicate}.evaluate(&fd.trace_graph.node_weight(root_node).unwrap().1) {{
                     // TODO:  replace fd
                     match serde_json::to_string(&fd) {{
                         Ok(fd_str) => {{
@@ -122,8 +193,7 @@ fn make_attr_filter_blocks(
                 }}
                 ",
                 root_id = root_id,
-                prop_name = id_to_property[&prop],
-                value = attr_filter.value
+                predicate = predicate_to_code(predicate, id_to_property, value_types, "")?
             );
             trace_lvl_prop_blocks.push(trace_filter_block);
         }
@@ -131,7 +201,7 @@ fn make_attr_filter_blocks(
 
     let end_root_block = "       }".to_string();
     trace_lvl_prop_blocks.push(end_root_block);
-    trace_lvl_prop_blocks
+    Ok(trace_lvl_prop_blocks)
 }
 
 #[allow(dead_code)]
@@ -150,26 +220,37 @@ fn make_storage_rpc_value_from_trace(
     entity: String,
     property: &str,
     id_to_property: &IndexMap<String, u64>,
-) -> String {
-    format!(
+    target: &str,
+) -> CompileResult<String> {
+    Ok(format!(
         "let trace_node_idx = get_node_with_id(&fd.trace_graph, \"{node_id}\");
         if trace_node_idx.is_none() {{
            log::error!(\"Node {node_id} not found\");
                 return None;
         }}
-        let ret = &fd.trace_graph.node_weight(trace_node_idx.unwrap()).unwrap().1[{prop}];\n
-        value = ret.to_string();\n",
+        let ret = match fd.trace_graph.node_weight(trace_node_idx.unwrap()).unwrap().1.get(&{prop}) {{
+            Some(ret) => ret,
+            None => {{
+                // we have not yet collected the return property
+                log::error!(\"Missing return property {property_name}\");
+                return None;
+            }}
+        }};
+        {target} = ret.to_string();\n",
         node_id = entity,
-        prop = id_to_property[property]
-    )
+        prop = property_id(id_to_property, property)?,
+        property_name = property,
+        target = target
+    ))
 }
 
 fn make_storage_rpc_value_from_target(
     entity: &str,
     property: &str,
     id_to_property: &IndexMap<String, u64>,
-) -> String {
-    format!(
+    target: &str,
+) -> CompileResult<String> {
+    Ok(format!(
         "let node_ptr = get_node_with_id(target_graph, \"{node_id}\");
         if node_ptr.is_none() {{
            log::error!(\"Node {node_id} not found\");
@@ -199,89 +280,286 @@ fn make_storage_rpc_value_from_target(
             log::error!(\"Missing return property {property_name}\");
             return None;
         }}
-        let ret = &stored_data.trace_graph.node_weight(trace_node_idx).unwrap().1[ {property} ];\n
-        value = ret.to_string();\n",
+        let ret = &stored_data.trace_graph.node_weight(trace_node_idx).unwrap().1[&{property}];\n
+        {target} = ret.to_string();\n",
         node_id = entity,
-        property = id_to_property[property],
-        property_name = property
-    )
+        property = property_id(id_to_property, property)?,
+        property_name = property,
+        target = target
+    ))
+}
+
+fn make_storage_rpc_value_from_edge(
+    relationship: &str,
+    edge: &(String, String),
+    property: &str,
+    id_to_property: &IndexMap<String, u64>,
+    target: &str,
+) -> CompileResult<String> {
+    Ok(format!(
+        "let mut caller_idx_opt = None;
+        let mut callee_idx_opt = None;
+        for map in mapping {{
+            if target_graph.node_weight(map.0).unwrap().0 == \"{caller}\" {{
+                caller_idx_opt = Some(map.1);
+            }}
+            if target_graph.node_weight(map.0).unwrap().0 == \"{callee}\" {{
+                callee_idx_opt = Some(map.1);
+            }}
+        }}
+        if caller_idx_opt.is_none() || callee_idx_opt.is_none() {{
+            log::error!(\"Relationship {relationship} not found.\");
+            return None;
+        }}
+        let (caller_idx, callee_idx) = (caller_idx_opt.unwrap(), callee_idx_opt.unwrap());
+        // an undirected relationship may have matched a call either way
+        let call_opt = stored_data
+            .trace_graph
+            .find_edge(caller_idx, callee_idx)
+            .or_else(|| stored_data.trace_graph.find_edge(callee_idx, caller_idx));
+        if call_opt.is_none() {{
+            log::error!(\"Call {relationship} not found.\");
+            return None;
+        }}
+        let call = stored_data.trace_graph.edge_weight(call_opt.unwrap()).unwrap();
+        if !call.contains_key(&{property}) {{
+            // we have not yet collected the return property
+            log::error!(\"Missing return property {property_name}\");
+            return None;
+        }}
+        {target} = call[{property}].to_string();\n",
+        relationship = relationship,
+        caller = edge.0,
+        callee = edge.1,
+        property = property_id(id_to_property, property)?,
+        property_name = property,
+        target = target
+    ))
 }
 
+/// Assigns the value of the item to the target variable.  Items that read the
+/// nodes an OPTIONAL MATCH adds are null for traces without them, so their
+/// variable is an Option<String>.
 fn make_return_block(
     entity_ref: &PropertyOrUDF,
     query_data: &VisitorResults,
     id_to_property: &IndexMap<String, u64>,
-) -> String {
+    target: &str,
+) -> CompileResult<String> {
+    let nodes = query_data.item_optional_nodes(entity_ref);
+    if nodes.is_empty() {
+        return make_value_block(entity_ref, query_data, id_to_property, target);
+    }
+    Ok(make_optional_block(
+        &nodes,
+        &make_value_block(
+            entity_ref,
+            query_data,
+            id_to_property,
+            &present_variable(target),
+        )?,
+        target,
+    ))
+}
+
+/// Assigns the value of the item to the target variable, assuming the mapping
+/// has all the nodes it reads.
+fn make_value_block(
+    entity_ref: &PropertyOrUDF,
+    query_data: &VisitorResults,
+    id_to_property: &IndexMap<String, u64>,
+    target: &str,
+) -> CompileResult<String> {
     match entity_ref {
         PropertyOrUDF::Property(prop) => match prop.parent.as_str() {
             "trace" => make_storage_rpc_value_from_trace(
                 query_data.root_id.clone(),
                 &prop.to_dot_string(),
                 id_to_property,
+                target,
             ),
+            relationship if query_data.relationship(relationship).is_some() => {
+                make_storage_rpc_value_from_edge(
+                    relationship,
+                    query_data.relationship(relationship).unwrap(),
+                    &prop.to_dot_string(),
+                    id_to_property,
+                    target,
+                )
+            }
             _ => make_storage_rpc_value_from_target(
                 &prop.parent,
                 &prop.to_dot_string(),
                 id_to_property,
+                target,
             ),
         },
         PropertyOrUDF::UdfCall(call) => {
-            // Because of quirky design we need to get the first arg
-            if call.args.len() != 1 {
-                panic!("We currently only implement very specific arguments for UDFs!");
+            // the result is stored as a property of the node the call is applied to
+            let nodes = call.nodes();
+            if nodes.len() != 1 {
+                return Err(CompileError::new(format!(
+                    "UDF call {:?} has to refer to exactly one node, but refers to {:?}",
+                    call.to_ref_str(),
+                    nodes
+                )));
             }
-            let node = &call.args[0];
+            let node = &nodes[0];
             match node.as_str() {
                 "trace" => make_storage_rpc_value_from_trace(
                     query_data.root_id.clone(),
                     &call.id,
                     id_to_property,
+                    target,
                 ),
-                _ => make_storage_rpc_value_from_target(node, &call.id, id_to_property),
+                _ => make_storage_rpc_value_from_target(node, &call.id, id_to_property, target),
             }
         }
+        PropertyOrUDF::Literal(literal) => Ok(make_literal_block(literal, target)),
+        PropertyOrUDF::Parameter(name) => Err(unbound_parameter(name)),
+        PropertyOrUDF::Arithmetic(arithmetic) => {
+            let (lhs, rhs) = operand_variables(target);
+            Ok(make_arithmetic_block(
+                arithmetic.op,
+                &make_value_block(&arithmetic.left, query_data, id_to_property, &lhs)?,
+                &make_value_block(&arithmetic.right, query_data, id_to_property, &rhs)?,
+                "",
+                target,
+            ))
+        }
+    }
+}
+
+/// Assigns the record of the columns to the target variable.  Each column is
+/// bound to a variable of its own first, named after the target.
+fn make_record_return_block(
+    columns: &[(String, PropertyOrUDF)],
+    query_data: &VisitorResults,
+    id_to_property: &IndexMap<String, u64>,
+    target: &str,
+) -> CompileResult<String> {
+    let mut record_block = String::new();
+    let mut fields = Vec::new();
+    for (idx, (name, column)) in columns.iter().enumerate() {
+        let variable = format!("{}_{}", target, idx);
+        record_block.push_str(&make_binding_block(
+            &variable,
+            &make_return_block(column, query_data, id_to_property, &variable)?,
+            query_data.may_be_null(column),
+        ));
+        fields.push((name.clone(), variable));
     }
+    record_block.push_str(&make_record_block(&fields, target));
+    Ok(record_block)
 }
 
 fn make_aggr_block(
     agg: &Aggregate,
     query_data: &VisitorResults,
     id_to_property: &IndexMap<String, u64>,
-) -> String {
-    let mut to_return = String::new();
-    for arg in &agg.args {
-        to_return.push_str(&make_return_block(arg, query_data, id_to_property));
+    target: &str,
+) -> CompileResult<String> {
+    if agg.group_by.is_empty() {
+        return make_return_block(&agg.args[0], query_data, id_to_property, target);
     }
-    to_return
+    // grouped aggregations send the group along with the value
+    let group = format!("{}_group", target);
+    let instance = format!("{}_instance", target);
+    Ok(format!(
+        "let {group}: String;\n{group_block}{instance_block}{record_block}",
+        group = group,
+        group_block = make_record_return_block(&agg.group_by, query_data, id_to_property, &group)?,
+        instance_block = make_binding_block(
+            &instance,
+            &make_return_block(&agg.args[0], query_data, id_to_property, &instance)?,
+            query_data.may_be_null(&agg.args[0])
+        ),
+        record_block = make_record_block(
+            &[
+                ("group".to_string(), group.clone()),
+                ("value".to_string(), instance.clone())
+            ],
+            target
+        )
+    ))
 }
 
+/// Several aggregations send their values to storage as one object, keyed by
+/// the key each aggregation is stored under.
+fn make_aggrs_block(
+    aggs: &[Aggregate],
+    query_data: &VisitorResults,
+    id_to_property: &IndexMap<String, u64>,
+    target: &str,
+) -> CompileResult<String> {
+    if let [agg] = aggs {
+        if agg.group_by.is_empty() && query_data.may_be_null(&agg.args[0]) {
+            let instance = format!("{}_instance", target);
+            return Ok(make_skip_null_block(
+                &make_aggr_block(agg, query_data, id_to_property, &instance)?,
+                &instance,
+                target,
+            ));
+        }
+        return make_aggr_block(agg, query_data, id_to_property, target);
+    }
+    let mut aggrs_block = String::new();
+    let mut fields = Vec::new();
+    for (idx, agg) in aggs.iter().enumerate() {
+        let variable = format!("{}_{}", target, idx);
+        // ungrouped values that are missing are sent as null
+        aggrs_block.push_str(&make_binding_block(
+            &variable,
+            &make_aggr_block(agg, query_data, id_to_property, &variable)?,
+            agg.group_by.is_empty() && query_data.may_be_null(&agg.args[0]),
+        ));
+        fields.push((agg.key(), variable));
+    }
+    aggrs_block.push_str(&make_record_block(&fields, target));
+    Ok(aggrs_block)
+}
+
+/// Collects the properties into the given set of unassigned properties of fd,
+/// with entity as the code naming the node they belong to.  The properties of a
+/// call are collected by the caller, but belong to the callee.  Each property
+/// is only collected under its condition, as worked out by needed_properties.
 fn generate_property_blocks(
-    properties: &IndexSet<Property>,
-    scalar_udf_table: &IndexMap<String, ScalarUdf>,
+    properties: &[(Property, Option<String>)],
     property_to_type: &IndexMap<&str, &str>,
     id_to_property: &IndexMap<String, u64>,
-) -> Vec<String> {
-    // TODO:  here, we can have duplicates because they have different entities,
-    // but we still just need to collect one version of the property
+    unassigned: &str,
+    entity: &str,
+) -> CompileResult<Vec<String>> {
     let mut property_blocks = Vec::new();
-    for property in properties {
-        // There is nothing to fetch so ignore.
-        // TODO: What do we actually need here?  this is no longer relevant because we don't return traces, right?
-        if property.members.is_empty() || scalar_udf_table.contains_key(&property.to_dot_string()) {
-            continue;
+    for (property, condition) in properties {
+        if let Some(condition) = condition {
+            property_blocks.push(format!(
+                "
+             if {condition} {{
+            ",
+                condition = condition
+            ));
         }
-        // Now collect the property
+        // Now collect the property, unless it is not available for this request
         let get_prop_block = format!(
             "
-             let property = http_headers
-                            .get_property({property}.to_vec())
-                            .ok_or_else(|| format!(\"Failed to retrieve property {property_str}.\"))?;
+             if let Some(property) = http_headers.get_property({property}.to_vec()) {{
             ",
-            property=property.as_vec_str(),
-            property_str = property.to_dot_string());
+            property = property.as_vec_str()
+        );
         property_blocks.push(get_prop_block);
         let dot_str = property.to_dot_string();
-        match property_to_type[dot_str.as_str()] {
+        let property_type = match property_to_type.get(dot_str.as_str()) {
+            Some(property_type) => *property_type,
+            None => {
+                return Err(CompileError::new(format!(
+                    "Unknown envoy property {:?}",
+                    dot_str
+                )))
+            }
+        };
+        let id = property_id(id_to_property, &dot_str)?;
+        match property_type {
             "int" => {
                 let cast_block = format!(
                     "let mut byte_array = [0u8; 8];                                      
@@ -289,13 +567,15 @@ fn generate_property_blocks(
                     *place = *element;                                              
                 }}                                                                   
                 let int_val = i64::from_ne_bytes(byte_array);                       
-                fd.unassigned_properties.insert(Property::new(
-                    http_headers.workload_name.to_string(), 
+                fd.{unassigned}.insert(Property::new(
+                    {entity}, 
                     {property},
-                    int_val.to_string() 
+                    Value::Int(int_val)
                 ));
                 ",
-                    property = id_to_property[&dot_str]
+                    property = id,
+                    unassigned = unassigned,
+                    entity = entity
                 );
                 property_blocks.push(cast_block.to_string());
             }
@@ -306,13 +586,15 @@ fn generate_property_blocks(
                     *place = *element;                                              
                 }}                                                                   
                 let int_val = u64::from_ne_bytes(byte_array);                       
-                fd.unassigned_properties.insert(Property::new(
-                    http_headers.workload_name.to_string(), 
+                fd.{unassigned}.insert(Property::new(
+                    {entity}, 
                     {property},
-                    int_val.to_string() 
+                    Value::Uint(int_val)
                 ));
                 ",
-                    property = id_to_property[&dot_str]
+                    property = id,
+                    unassigned = unassigned,
+                    entity = entity
                 );
                 property_blocks.push(cast_block.to_string());
             }
@@ -329,13 +611,15 @@ fn generate_property_blocks(
                 if int_val != 0 {{
                     bool_val = true;
                 }}
-                fd.unassigned_properties.insert(Property::new(
-                    http_headers.workload_name.to_string(), 
+                fd.{unassigned}.insert(Property::new(
+                    {entity}, 
                     {property},
-                    bool_val.to_string() 
+                    Value::Bool(bool_val)
                 ));
                 ",
-                    property = id_to_property[&dot_str]
+                    property = id,
+                    unassigned = unassigned,
+                    entity = entity
                 );
                 property_blocks.push(cast_block.to_string());
             }
@@ -350,13 +634,15 @@ fn generate_property_blocks(
                     *place = *element;                                              
                 }}                                                                   
                 let int_val = u64::from_ne_bytes(byte_array);                       
-                fd.unassigned_properties.insert(Property::new(
-                    http_headers.workload_name.to_string(), 
+                fd.{unassigned}.insert(Property::new(
+                    {entity}, 
                     {property},
-                    int_val.to_string() 
+                    Value::Timestamp(int_val)
                 ));
                 ",
-                    property = id_to_property[&dot_str]
+                    property = id,
+                    unassigned = unassigned,
+                    entity = entity
                 );
                 property_blocks.push(cast_block.to_string());
             }
@@ -368,13 +654,15 @@ fn generate_property_blocks(
                     *place = *element;                                              
                 }}                                                                   
                 let int_val = u64::from_ne_bytes(byte_array);                       
-                fd.unassigned_properties.insert(Property::new(
-                    http_headers.workload_name.to_string(), 
+                fd.{unassigned}.insert(Property::new(
+                    {entity}, 
                     {property},
-                    int_val.to_string() 
+                    Value::Duration(int_val)
                 ));
                 ",
-                    property = id_to_property[&dot_str]
+                    property = id,
+                    unassigned = unassigned,
+                    entity = entity
                 );
                 property_blocks.push(cast_block.to_string());
             }
@@ -392,22 +680,42 @@ fn generate_property_blocks(
                     "
                      match std::str::from_utf8(&property) {{
                         Ok(property_str_) => {{
-                            fd.unassigned_properties.insert(Property::new(
-                                http_headers.workload_name.to_string(), 
+                            fd.{unassigned}.insert(Property::new(
+                                {entity}, 
                                 {property},
-                                property_str_.to_string()
+                                Value::Str(property_str_.to_string())
                             ));
                         }}
                         Err(e) => {{ return Err(e.to_string()); }}
                     }};
                 ",
-                    property = id_to_property[&dot_str]
+                    property = id,
+                    unassigned = unassigned,
+                    entity = entity
                 );
                 property_blocks.push(cast_block.to_string());
             }
         }
+        // a missing property is not stored, which WHERE clauses check with IS NULL
+        let missing_prop_block = format!(
+            "
+             }} else {{
+                log::debug!(\"Property {property_str} is not available.\");
+             }}
+            ",
+            property_str = dot_str
+        );
+        property_blocks.push(missing_prop_block);
+        if condition.is_some() {
+            property_blocks.push(
+                "
+             }
+            "
+                .to_string(),
+            );
+        }
     }
-    property_blocks
+    Ok(property_blocks)
 }
 
 fn generate_udf_blocks(
@@ -415,157 +723,220 @@ fn generate_udf_blocks(
     aggregation_udf_table: &IndexMap<String, AggregationUdf>,
     udf_calls: &IndexSet<UdfCall>,
     id_to_property: &IndexMap<String, u64>,
-) -> Vec<String> {
+    value_types: &IndexMap<String, ValueType>,
+) -> CompileResult<Vec<String>> {
     let mut udf_blocks = Vec::new();
-    for call in udf_calls {
+    for call in udf_calls_in_dependency_order(udf_calls)? {
         if aggregation_udf_table.contains_key(&call.id) {
             // TODO: Aggregations are handled separately, where do they go?
             continue;
         }
         if !scalar_udf_table.contains_key(&call.id) {
-            log::error!("ID {:?} not found in the scalar UDF map!", call.id);
-            std::process::exit(1);
+            return Err(CompileError::new(format!(
+                "UDF {:?} is not defined, pass its implementation with --udf",
+                call.id
+            )));
         }
+        let id = property_id(id_to_property, &call.id)?;
+        // the values of property arguments are read from the current node
+        let property_at_node = |id| {
+            format!(
+                "fd.trace_graph.node_weight(node).unwrap().1.get(&{})?.to_string()",
+                id
+            )
+        };
+        let args = udf_args_to_code(&call, scalar_udf_table, id_to_property, &property_at_node)?;
+        let udf = &scalar_udf_table[&call.id];
         let get_udf_vals = format!(
-            "let my_{name}_value;
-            let child_iterator = fd.trace_graph.neighbors_directed(
-                get_node_with_id(&fd.trace_graph, &http_headers.workload_name).unwrap(),
-                petgraph::Outgoing);
+            "// None if a property the UDF is passed or a value of a child is missing
+        let my_{name}_value = (|| {{
+            let node = get_node_with_id(&fd.trace_graph, &http_headers.workload_name).unwrap();
+            let child_iterator = fd.trace_graph.neighbors_directed(node, petgraph::Outgoing);
             let mut child_values = Vec::new();
             for child in child_iterator {{
-                child_values.push(fd.trace_graph.node_weight(child).unwrap().1[{id}].clone());
+                child_values.push(fd.trace_graph.node_weight(child).unwrap().1.get(&{id})?.to_string());
             }}
             if child_values.len() == 0 {{
-                my_{name}_value = {leaf_func}(&fd.trace_graph).to_string();
+                Some({leaf_result})
             }} else {{
-                my_{name}_value = {mid_func}(&fd.trace_graph, child_values).to_string();
+                Some({mid_result})
             }}
-
+        }})();
         ",
-            id = id_to_property[&call.id],
+            id = id,
             name = call.id,
-            leaf_func = scalar_udf_table[&call.id].leaf_func,
-            mid_func = scalar_udf_table[&call.id].mid_func
+            leaf_result = udf_result_to_code(
+                &format!("{}(&fd.trace_graph{})", udf.leaf_func, args),
+                value_types[&call.id],
+                ""
+            ),
+            mid_result = udf_result_to_code(
+                &format!("{}(&fd.trace_graph, child_values{})", udf.mid_func, args),
+                value_types[&call.id],
+                ""
+            )
         );
         udf_blocks.push(get_udf_vals);
 
         let save_udf_vals = format!(
             "
-        let node = get_node_with_id(&fd.trace_graph, &http_headers.workload_name).unwrap();
-        // if we already have the property, don't add it
-        if !( fd.trace_graph.node_weight(node).unwrap().1.contains_key(&{id}) &&
-               fd.trace_graph.node_weight(node).unwrap().1[{id}] == my_{name}_value ) {{
-           fd.trace_graph.node_weight_mut(node).unwrap().1.insert(
-               {id}, my_{name}_value);
+        if let Some(value) = &my_{name}_value {{
+            let node = get_node_with_id(&fd.trace_graph, &http_headers.workload_name).unwrap();
+            // if we already have the property, don't add it
+            if fd.trace_graph.node_weight(node).unwrap().1.get(&{id}) != Some(value) {{
+                fd.trace_graph.node_weight_mut(node).unwrap().1.insert({id}, value.clone());
+            }}
         }}
         ",
-            id = id_to_property[&call.id],
+            id = id,
             name = call.id
         );
         udf_blocks.push(save_udf_vals);
     }
-    udf_blocks
+    Ok(udf_blocks)
 }
 
-pub fn generate_code_blocks(query_data: VisitorResults, udf_paths: Vec<String>) -> CodeStruct {
-    // TODO: dynamically retrieve this from https://www.envoyproxy.io/docs/envoy/latest/intro/arch_overview/advanced/attributes
-
-    let property_to_type: IndexMap<&str, &str> = [
-        ("request.path", "String"),
-        ("request.url_path", "String"),
-        ("request.host", "String"),
-        ("request.scheme", "String"),
-        ("request.method", "String"),
-        ("request.headers", "Map"),
-        ("request.referer", "String"),
-        ("request.useragent", "String"),
-        ("request.time", "Timestamp"),
-        ("request.id", "String"),
-        ("request.protocol", "String"),
-        ("request.duration", "Duration"),
-        ("request.size", "int"),
-        ("request.total_size", "int"),
-        ("response.code", "int"),
-        ("response.code_details", "String"),
-        ("response.flags", "int"),
-        ("response.grpc_status", "int"),
-        ("response.headers", "Map"),
-        ("response.trailers", "Map"),
-        ("response.size", "int"),
-        ("response.total_size", "int"),
-        ("source.address", "String"),
-        ("source.port", "int"),
-        ("destination.address", "String"),
-        ("destination.port", "int"),
-        ("connection.id", "u64"),
-        ("connection.mlts", "bool"), // More strings here
-        ("upstream.port", "int"),
-        ("metadata", "metadata"), // and more strings here
-        ("filter_state", "Map"),
-        ("node", "Node"),
-        ("cluster_metadata", "metadata"),
-        ("listener_direction", "int"),
-        ("listener_metadata", "metadata"),
-        ("route_metadata", "metadata"),
-        ("upstream_host_metadata", "metadata"),
-        ("node.metadata.WORKLOAD_NAME", "String"),
-    ]
-    .iter()
-    .cloned()
-    .collect();
+pub fn generate_code_blocks(
+    query_data: VisitorResults,
+    udf_paths: Vec<String>,
+) -> CompileResult<CodeStruct> {
+    let property_to_type = envoy_property_types();
     let mut code_struct = CodeStruct::new(&query_data.root_id);
 
-    let mut scalar_udf_table: IndexMap<String, ScalarUdf> = IndexMap::new();
-    // where we store udf implementations
-    let mut aggregation_udf_table: IndexMap<String, AggregationUdf> = IndexMap::new();
-    for udf_path in udf_paths {
-        match parse_udf(udf_path) {
-            ScalarOrAggregationUdf::ScalarUdf(udf) => {
-                scalar_udf_table.insert(udf.id.clone(), udf);
-            }
-            ScalarOrAggregationUdf::AggregationUdf(udf) => {
-                aggregation_udf_table.insert(udf.id.clone(), udf);
-            }
-        }
-    }
+    let (scalar_udf_table, aggregation_udf_table) = udf_tables(&udf_paths)?;
     code_struct.id_to_property = assign_id_to_property(&query_data.properties, &scalar_udf_table);
+    let value_types = value_types(
+        &code_struct.id_to_property,
+        &scalar_udf_table,
+        &property_to_type,
+    );
 
-    // all the properties we collect
+    // all the properties we collect, for nodes and for the calls between them
+    let (edge_properties, node_properties): (IndexSet<Property>, IndexSet<Property>) = query_data
+        .properties
+        .iter()
+        .cloned()
+        .partition(|property| query_data.relationship(&property.parent).is_some());
     code_struct.collect_properties_blocks = generate_property_blocks(
-        &query_data.properties,
-        &scalar_udf_table,
+        &needed_properties(
+            &query_data,
+            &node_properties,
+            &scalar_udf_table,
+            "http_headers.workload_name",
+            "callee",
+        ),
         &property_to_type,
         &code_struct.id_to_property,
-    );
+        "unassigned_properties",
+        "http_headers.workload_name.to_string()",
+    )?;
+    code_struct.collect_edge_properties_blocks = generate_property_blocks(
+        &needed_properties(
+            &query_data,
+            &edge_properties,
+            &scalar_udf_table,
+            "http_headers.workload_name",
+            "callee",
+        ),
+        &property_to_type,
+        &code_struct.id_to_property,
+        "unassigned_edge_properties",
+        "callee.clone()",
+    )?;
+    if !code_struct.collect_edge_properties_blocks.is_empty() {
+        // the response of the callee holds the trace graph rooted at the callee
+        code_struct.collect_edge_properties_blocks.insert(
+            0,
+            "let callee = match fd.root_name() {
+                Some(callee) => callee,
+                None => return Ok(()),
+            };"
+            .to_string(),
+        );
+    }
     code_struct.udf_blocks = generate_udf_blocks(
         &scalar_udf_table,
         &aggregation_udf_table,
         &query_data.udf_calls,
         &code_struct.id_to_property,
-    );
-    code_struct.target_blocks = make_struct_filter_blocks(
-        &query_data.attr_filters,
-        &query_data.struct_filters,
+        &value_types,
+    )?;
+    code_struct.local_filter_blocks = make_local_filter_blocks(
+        &query_data,
         &code_struct.id_to_property,
-    );
+        &value_types,
+        &scalar_udf_table,
+        "&http_headers.workload_name",
+        "",
+    )?;
+    code_struct.target_blocks =
+        make_struct_filter_blocks(&query_data, &code_struct.id_to_property, &value_types)?;
     code_struct.trace_lvl_prop_blocks = make_attr_filter_blocks(
         &query_data.root_id,
         &query_data.attr_filters,
         &code_struct.id_to_property,
-    );
+        &value_types,
+    )?;
 
     let resp_block = match query_data.return_expr {
-        IrReturnEnum::PropertyOrUDF(ref entity_ref) => {
-            make_return_block(entity_ref, &query_data, &code_struct.id_to_property)
+        IrReturnEnum::PropertyOrUDF(ref entity_ref) if !query_data.may_be_null(entity_ref) => {
+            make_return_block(
+                entity_ref,
+                &query_data,
+                &code_struct.id_to_property,
+                "value",
+            )
+        }
+        // a value that may be missing is sent as JSON, null if it is
+        IrReturnEnum::PropertyOrUDF(ref entity_ref) => Ok(make_json_value_block(
+            &make_return_block(
+                entity_ref,
+                &query_data,
+                &code_struct.id_to_property,
+                "nullable_value",
+            )?,
+            "nullable_value",
+            "value",
+        )),
+        IrReturnEnum::Aggregate(ref aggs) => {
+            code_struct.aggregation_udf_table = aggregation_state(aggs, &aggregation_udf_table)?;
+            make_aggrs_block(aggs, &query_data, &code_struct.id_to_property, "value")
         }
-        IrReturnEnum::Aggregate(ref agg) => {
-            make_aggr_block(agg, &query_data, &code_struct.id_to_property)
+        IrReturnEnum::Record(ref columns) => {
+            make_record_return_block(columns, &query_data, &code_struct.id_to_property, "value")
         }
-    };
+    }?;
+    // expression filters need the whole mapping, so the matcher checks them on every
+    // mapping it tries.  A mapping without a node they read is not accepted
+    let (filter_lhs, filter_rhs) = operand_variables("filter");
+    for expression_filter in &query_data.expression_filters {
+        code_struct
+            .expression_filter_blocks
+            .push(make_expression_filter_block(
+                expression_filter.op,
+                &make_value_block(
+                    &expression_filter.left,
+                    &query_data,
+                    &code_struct.id_to_property,
+                    &filter_lhs,
+                )?,
+                &make_value_block(
+                    &expression_filter.right,
+                    &query_data,
+                    &code_struct.id_to_property,
+                    &filter_rhs,
+                )?,
+                "",
+            ));
+    }
     code_struct.response_blocks.push(resp_block);
-    code_struct.aggregation_udf_table = aggregation_udf_table;
+    code_struct.aggregation_udf_impls = code_struct
+        .aggregation_udf_table
+        .values()
+        .map(|udf| udf.func_impl.clone())
+        .collect();
     code_struct.scalar_udf_table = scalar_udf_table;
-    code_struct
+    Ok(code_struct)
 }
 
 #[cfg(test)]
@@ -573,6 +944,7 @@ mod tests {
     use super::*;
     use crate::antlr_gen::lexer::CypherLexer;
     use crate::antlr_gen::parser::CypherParser;
+    use crate::to_ir::resolve_aggregates;
     use crate::to_ir::visit_result;
     use antlr_rust::common_token_stream::CommonTokenStream;
     use antlr_rust::token_factory::CommonTokenFactory;
@@ -597,6 +969,13 @@ mod tests {
     }
     ";
 
+    static SCALE: &str = "
+    // udf_type: Scalar
+    // leaf_func: leaf_scale
+    // mid_func: mid_scale
+    // id: scale
+    ";
+
     static AVG: &str = "
     // udf_type: Aggregation
     // init_func: init
@@ -630,7 +1009,10 @@ mod tests {
         let token_source = CommonTokenStream::new(_lexer);
         let mut parser = CypherParser::new(token_source);
         let result = parser.oC_Cypher().expect("parsed unsuccessfully");
-        visit_result(result, "".to_string())
+        let mut query_data = visit_result(result, "".to_string()).unwrap();
+        let (_, aggregation_udf_table) = udf_tables(&[AVG.to_string()]).unwrap();
+        resolve_aggregates(&mut query_data, &aggregation_udf_table).unwrap();
+        query_data
     }
 
     #[test]
@@ -638,7 +1020,7 @@ mod tests {
         let result =
             get_codegen_from_query("MATCH (a) -[]-> (b {})-[]->(c) RETURN a.count".to_string());
         assert!(!result.struct_filters.is_empty());
-        let _codegen = generate_code_blocks(result, [COUNT.to_string()].to_vec());
+        let _codegen = generate_code_blocks(result, [COUNT.to_string()].to_vec()).unwrap();
     }
 
     #[test]
@@ -647,7 +1029,7 @@ mod tests {
             "MATCH (a) -[]-> (b {})-[]->(c) RETURN a.node.metadata.WORKLOAD_NAME".to_string(),
         );
         assert!(!result.struct_filters.is_empty());
-        let _codegen = generate_code_blocks(result, [COUNT.to_string()].to_vec());
+        let _codegen = generate_code_blocks(result, [COUNT.to_string()].to_vec()).unwrap();
     }
     #[test]
     fn get_group_by() {
@@ -656,7 +1038,7 @@ mod tests {
         );
         assert!(!result.struct_filters.is_empty());
         // Do not throw an error parsing this expression.
-        let _codegen = generate_code_blocks(result, [COUNT.to_string()].to_vec());
+        let _codegen = generate_code_blocks(result, [COUNT.to_string()].to_vec()).unwrap();
     }
 
     #[test]
@@ -667,7 +1049,360 @@ mod tests {
         assert!(!result.struct_filters.is_empty());
         assert!(!result.attr_filters.is_empty());
         // Do not throw an error parsing this expression.
-        let _codegen = generate_code_blocks(result, [AVG.to_string()].to_vec());
+        let _codegen = generate_code_blocks(result, [AVG.to_string()].to_vec()).unwrap();
+    }
+
+    #[test]
+    fn test_where_comparison() {
+        let result = get_codegen_from_query(
+            "MATCH (a) -[]-> (b) WHERE b.response.code >= 500 AND trace.request.total_size < 100 RETURN a.request.total_size".to_string(),
+        );
+        let codegen = generate_code_blocks(result, Vec::new()).unwrap();
+        assert!(codegen
+            .target_blocks
+            .iter()
+            .any(|block| block.contains("CompareOp::Ge, Value::Int(500)")));
+        assert!(codegen
+            .trace_lvl_prop_blocks
+            .iter()
+            .any(|block| block.contains("CompareOp::Lt, Value::Int(100)")));
+    }
+
+    #[test]
+    fn test_where_value_type_mismatch() {
+        let result = get_codegen_from_query(
+            "MATCH (a) -[]-> (b) WHERE b.response.code = '500' RETURN a.request.total_size"
+                .to_string(),
+        );
+        let err = generate_code_blocks(result, Vec::new()).err().unwrap();
+        assert_eq!(
+            err.message,
+            "b.response.code holds Int values, and can not be compared to \"500\""
+        );
+        let result = get_codegen_from_query(
+            "MATCH (a) -[]-> (b) WHERE b.request.time >= -1 RETURN a.request.total_size"
+                .to_string(),
+        );
+        assert!(generate_code_blocks(result, Vec::new()).is_err());
+    }
+
+    #[test]
+    fn test_unbound_parameters() {
+        // parameters left in the query are errors, wherever they are
+        for (query, name) in &[
+            (
+                "MATCH (a) -[]-> (b) WHERE b.response.code = $code RETURN a.request.total_size",
+                "code",
+            ),
+            (
+                "MATCH (a) -[]-> (b) RETURN a.request.total_size * $scale",
+                "scale",
+            ),
+        ] {
+            let result = get_codegen_from_query(query.to_string());
+            let err = generate_code_blocks(result, Vec::new()).err().unwrap();
+            assert_eq!(
+                err.message,
+                format!(
+                    "Parameter ${} has no value. Pass it with --param {}=<value>",
+                    name, name
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn test_where_or() {
+        let result = get_codegen_from_query(
+            "MATCH (a) -[]-> (b) WHERE b.node.metadata.WORKLOAD_NAME = 'reviews-v2' OR b.node.metadata.WORKLOAD_NAME = 'reviews-v3' RETURN a.request.total_size".to_string(),
+        );
+        let codegen = generate_code_blocks(result, Vec::new()).unwrap();
+        assert!(codegen.target_blocks.iter().any(|block| block.contains(
+            "b_filters.push(Predicate::Or(vec![Predicate::Compare(0, CompareOp::Eq, Value::Str(\"reviews-v2\".to_string())), Predicate::Compare(0, CompareOp::Eq, Value::Str(\"reviews-v3\".to_string())), ]));"
+        )));
+    }
+
+    #[test]
+    fn test_where_in_and_is_null() {
+        let result = get_codegen_from_query(
+            "MATCH (a) -[]-> (b) WHERE b.node.metadata.WORKLOAD_NAME IN ['reviews-v2', 'reviews-v3'] AND a.response.grpc_status IS NOT NULL AND trace.response.grpc_status IS NULL RETURN a.request.total_size".to_string(),
+        );
+        let codegen = generate_code_blocks(result, Vec::new()).unwrap();
+        assert!(codegen.target_blocks.iter().any(|block| block.contains(
+            "b_filters.push(Predicate::Or(vec![Predicate::Compare(0, CompareOp::Eq, Value::Str(\"reviews-v2\".to_string())), Predicate::Compare(0, CompareOp::Eq, Value::Str(\"reviews-v3\".to_string())), ]));"
+        )));
+        assert!(codegen
+            .target_blocks
+            .iter()
+            .any(|block| block.contains("a_filters.push(Predicate::Exists(1));")));
+        assert!(codegen
+            .trace_lvl_prop_blocks
+            .iter()
+            .any(|block| block.contains("Predicate::Not(Box::new(Predicate::Exists(1)))")));
+    }
+
+    #[test]
+    fn test_edge_directions() {
+        let result = get_codegen_from_query(
+            "MATCH (a) <-- (b) -- (c) RETURN a.request.total_size".to_string(),
+        );
+        let codegen = generate_code_blocks(result, Vec::new()).unwrap();
+        assert!(codegen
+            .target_blocks
+            .iter()
+            .any(|block| block.contains("(\"b\".to_string(), \"a\".to_string() )")));
+        assert!(codegen
+            .target_blocks
+            .iter()
+            .any(|block| block.contains("let undirected_edges = vec!(")));
+        assert!(codegen
+            .target_blocks
+            .iter()
+            .any(|block| block.contains("(\"b\".to_string(), \"c\".to_string() )")));
+    }
+
+    #[test]
+    fn test_variable_length_paths() {
+        let result = get_codegen_from_query(
+            "MATCH (b) <-[*1..3]- (a) <-[*]- (c) RETURN a.request.total_size".to_string(),
+        );
+        let codegen = generate_code_blocks(result, Vec::new()).unwrap();
+        assert!(codegen.target_blocks.iter().any(|block| block
+            .contains("(\"a\".to_string(), \"b\".to_string(), EdgeFilter::new(Hops::new(1, Some(3)), vec!()) )")));
+        assert!(codegen.target_blocks.iter().any(|block| block.contains(
+            "(\"c\".to_string(), \"a\".to_string(), EdgeFilter::new(Hops::new(1, None), vec!()) )"
+        )));
+    }
+
+    #[test]
+    fn test_relationship_variables() {
+        let result = get_codegen_from_query(
+            "MATCH (a)-[r]->(b) WHERE r.response.code = 503 RETURN r.request.duration".to_string(),
+        );
+        let codegen = generate_code_blocks(result, Vec::new()).unwrap();
+        let target_code = codegen.target_blocks.join("");
+        assert!(target_code.contains(
+            "(\"a\".to_string(), \"b\".to_string(), EdgeFilter::new(Hops::new(1, Some(1)), vec!(Predicate::Compare("
+        ));
+        assert!(!target_code.contains("r_filters"));
+        // properties of the call are collected for the callee, not the current node
+        assert!(codegen.collect_properties_blocks.is_empty());
+        assert!(codegen
+            .collect_edge_properties_blocks
+            .iter()
+            .any(|block| block.contains("fd.unassigned_edge_properties.insert(")));
+        assert!(codegen
+            .response_blocks
+            .iter()
+            .any(|block| block.contains(".find_edge(caller_idx, callee_idx)")));
+    }
+
+    #[test]
+    fn test_optional_match() {
+        let result = get_codegen_from_query(
+            "MATCH (a)-->(b) OPTIONAL MATCH (b)-->(c) WHERE c.response.code = 500 RETURN c.request.path".to_string(),
+        );
+        let codegen = generate_code_blocks(result, Vec::new()).unwrap();
+        let target_code = codegen.target_blocks.join("");
+        // a single target graph, whose call to c the matcher may leave out
+        assert_eq!(
+            target_code
+                .matches("generate_filtered_target_graph(")
+                .count(),
+            1
+        );
+        assert_eq!(target_code.matches("c_filters.push(").count(), 1);
+        assert!(target_code.contains(
+            "(\"b\".to_string(), \"c\".to_string(), EdgeFilter::new(Hops::new(1, Some(1)), vec!()).optional() )"
+        ));
+        // the value is sent as JSON, which is null for traces without c
+        let response_code = codegen.response_blocks.join("");
+        assert!(response_code.contains(
+            "if !mapping.iter().any(|map| target_graph.node_weight(map.0).unwrap().0 == \"c\") {"
+        ));
+        assert!(response_code.contains("nullable_value = None;"));
+        assert!(
+            response_code.contains("value = serde_json::Value::from(nullable_value).to_string();")
+        );
+
+        // aggregations have nothing to store for traces without c
+        let result = get_codegen_from_query(
+            "MATCH (a)-->(b) OPTIONAL MATCH (b)-->(c) RETURN avg(c.request.total_size)".to_string(),
+        );
+        let codegen = generate_code_blocks(result, [AVG.to_string()].to_vec()).unwrap();
+        let response_code = codegen.response_blocks.join("");
+        assert!(response_code.contains("value_instance = None;"));
+        assert!(response_code.contains("// there is nothing to aggregate"));
+    }
+
+    #[test]
+    fn test_union() {
+        let result = get_codegen_from_query(
+            "MATCH (a)-->(b) WHERE b.response.code = 500 RETURN a.request.path UNION MATCH (a)-->(b)-->(c) WHERE c.response.code = 503 RETURN a.request.path".to_string(),
+        );
+        let codegen = generate_code_blocks(result, Vec::new()).unwrap();
+        let target_code = codegen.target_blocks.join("");
+        assert!(target_code.contains("let mut target_graphs = Vec::new();"));
+        assert_eq!(
+            target_code
+                .matches("generate_filtered_target_graph(")
+                .count(),
+            2
+        );
+        // each branch only filters its own target graph
+        assert_eq!(target_code.matches("b_filters.push(").count(), 1);
+        assert_eq!(target_code.matches("c_filters.push(").count(), 1);
+    }
+
+    #[test]
+    fn test_local_filters() {
+        let result = get_codegen_from_query(
+            "MATCH (a)-->(b) WHERE a.node.metadata.WORKLOAD_NAME = 'productpage-v1' AND b.response.code = 500 AND b.request.path IS NOT NULL RETURN b.request.total_size".to_string(),
+        );
+        let codegen = generate_code_blocks(result, Vec::new()).unwrap();
+        let local_code = codegen.local_filter_blocks.join("");
+        assert!(local_code.contains("vec!(vec!(Predicate::Compare(0, CompareOp::Eq, Value::Str(\"productpage-v1\".to_string())), ), "));
+        // the workload name and the property checked with IS NOT NULL are kept
+        let mut dropped = vec![
+            codegen.id_to_property["response.code"],
+            codegen.id_to_property["request.total_size"],
+        ];
+        dropped.sort_unstable();
+        assert!(local_code.contains(&format!(
+            ".retain(|id, _| ![{}, {}].contains(id));",
+            dropped[0], dropped[1]
+        )));
+
+        // any service may be a node without filters
+        let result = get_codegen_from_query(
+            "MATCH (a)-->(b) WHERE b.response.code = 500 RETURN a.request.total_size".to_string(),
+        );
+        let codegen = generate_code_blocks(result, Vec::new()).unwrap();
+        assert!(codegen.local_filter_blocks.is_empty());
+    }
+
+    #[test]
+    fn test_needed_properties() {
+        let result = get_codegen_from_query(
+            "MATCH (a)-[r]->(b) WHERE b.node.metadata.WORKLOAD_NAME IN ['reviews-v1', 'reviews-v2'] AND b.response.code = 500 AND trace.request.total_size > 10 RETURN a.request.total_size, b.request.path, r.response.code".to_string(),
+        );
+        let codegen = generate_code_blocks(result, Vec::new()).unwrap();
+        let collect_code = codegen.collect_properties_blocks.join("");
+        // every node already holds its workload name
+        assert!(!collect_code.contains("\"WORKLOAD_NAME\""));
+        // the trace and a read the same property, which any service may need
+        assert_eq!(
+            collect_code
+                .matches("vec![\"request\", \"total_size\", ]")
+                .count(),
+            1
+        );
+        // only the workloads b is pinned to collect its properties
+        assert_eq!(
+            collect_code
+                .matches("if (http_headers.workload_name == \"reviews-v1\" || http_headers.workload_name == \"reviews-v2\") {")
+                .count(),
+            2
+        );
+        assert!(codegen
+            .collect_edge_properties_blocks
+            .iter()
+            .any(|block| block
+                .contains("if (callee == \"reviews-v1\" || callee == \"reviews-v2\") {")));
+
+        // an aggregation UDF is computed from the values sent to storage, so
+        // its arguments are only collected by the nodes that read them
+        let result = get_codegen_from_query(
+            "MATCH (a) -[]-> (b) RETURN trace.request.total_size, avg(trace.request.total_size)"
+                .to_string(),
+        );
+        let codegen = generate_code_blocks(result, [AVG.to_string()].to_vec()).unwrap();
+        assert!(codegen
+            .collect_properties_blocks
+            .join("")
+            .contains("if http_headers.workload_name == \"\" {"));
+    }
+
+    #[test]
+    fn test_return_record() {
+        let result = get_codegen_from_query(
+            "MATCH (a) -[]-> (b) RETURN a.request.total_size, b.response.code".to_string(),
+        );
+        let codegen = generate_code_blocks(result, Vec::new()).unwrap();
+        assert!(codegen
+            .response_blocks
+            .iter()
+            .any(|block| block.contains("value = serde_json::Value::Object(value_record)")));
+        // each column is bound once, instead of shadowing value
+        assert!(codegen.response_blocks.iter().any(|block| block
+            .contains("value_record.insert(\"b.response.code\".to_string(), serde_json::Value::String(value_1));")));
+        assert!(!codegen
+            .response_blocks
+            .iter()
+            .any(|block| block.contains("let value: String;")));
+    }
+
+    #[test]
+    fn test_string_predicates() {
+        let result = get_codegen_from_query(
+            "MATCH (a) -[]-> (b) WHERE b.request.path STARTS WITH '/api/v2' AND trace.request.useragent =~ '.*curl.*' RETURN a.request.total_size".to_string(),
+        );
+        let codegen = generate_code_blocks(result, Vec::new()).unwrap();
+        assert!(codegen
+            .target_blocks
+            .iter()
+            .any(|block| block.contains("CompareOp::StartsWith, \"/api/v2\"")));
+        assert!(codegen
+            .trace_lvl_prop_blocks
+            .iter()
+            .any(|block| block.contains("CompareOp::RegexMatch, \".*curl.*\"")));
+    }
+
+    #[test]
+    fn test_arithmetic_expressions() {
+        let result = get_codegen_from_query(
+            "MATCH (a) -[]-> (b) WHERE b.response.total_size - a.request.total_size > 100 RETURN b.response.total_size - a.request.total_size".to_string(),
+        );
+        let codegen = generate_code_blocks(result, Vec::new()).unwrap();
+        // the filter is checked while matching, and the value computed afterwards
+        assert_eq!(codegen.expression_filter_blocks.len(), 1);
+        assert!(codegen.expression_filter_blocks[0]
+            .contains("if !CompareOp::Gt.compare(&filter_lhs, &filter_rhs)"));
+        assert!(codegen.expression_filter_blocks[0].contains(
+            "filter_lhs = match ArithmeticOp::Sub.apply(&filter_lhs_lhs, &filter_lhs_rhs)"
+        ));
+        assert_eq!(codegen.response_blocks.len(), 1);
+        assert!(codegen.response_blocks[0]
+            .contains("value = match ArithmeticOp::Sub.apply(&value_lhs, &value_rhs)"));
+    }
+
+    #[test]
+    fn test_nested_udf_args() {
+        let result = get_codegen_from_query(
+            "MATCH (a) -[]-> (b) RETURN scale(count(a), a.request.total_size, '2')".to_string(),
+        );
+        let codegen =
+            generate_code_blocks(result, vec![SCALE.to_string(), COUNT.to_string()]).unwrap();
+        let count_idx = codegen
+            .udf_blocks
+            .iter()
+            .position(|block| block.contains("let my_count_value = (|| {"))
+            .unwrap();
+        let scale_idx = codegen
+            .udf_blocks
+            .iter()
+            .position(|block| {
+                block.contains(
+                    "leaf_scale(&fd.trace_graph, my_count_value.as_ref()?.to_string(), fd.trace_graph.node_weight(node)",
+                )
+            })
+            .unwrap();
+        // count has to be computed before it is passed to scale
+        assert!(count_idx < scale_idx);
+        // a property missing at the node leaves scale unset instead of panicking
+        assert!(
+            codegen.udf_blocks[scale_idx].contains(".1.get(&1)?.to_string(), \"2\".to_string())")
+        );
     }
 
     #[test]
@@ -677,7 +1412,59 @@ mod tests {
                 .to_string(),
         );
         // Do not throw an error parsing this expression.
-        let codegen = generate_code_blocks(result, [AVG.to_string()].to_vec());
+        let codegen = generate_code_blocks(result, [AVG.to_string()].to_vec()).unwrap();
         assert!(codegen.aggregation_udf_table.keys().count() == 1);
     }
+
+    #[test]
+    fn test_aggr_alias() {
+        let result = get_codegen_from_query(
+            "MATCH (a) -[]-> (b)-[]->(c) RETURN a.request.total_size, avg(a.request.total_size) AS mean_size"
+                .to_string(),
+        );
+        let codegen = generate_code_blocks(result, [AVG.to_string()].to_vec()).unwrap();
+        assert_eq!(codegen.aggregation_udf_table["mean_size"].key, "mean_size");
+    }
+
+    #[test]
+    fn test_aggr_same_udf() {
+        let result = get_codegen_from_query(
+            "MATCH (a) -[]-> (b)-[]->(c) RETURN avg(a.request.total_size) AS p, avg(b.request.total_size) AS q"
+                .to_string(),
+        );
+        let codegen = generate_code_blocks(result, [AVG.to_string()].to_vec()).unwrap();
+        // each aggregation keeps its own state, but the UDF is implemented once
+        let keys: Vec<&String> = codegen.aggregation_udf_table.keys().collect();
+        assert_eq!(keys, vec!["p", "q"]);
+        assert!(codegen
+            .aggregation_udf_table
+            .values()
+            .all(|udf| udf.id == "avg" && udf.keyed && !udf.grouped));
+        assert_eq!(codegen.aggregation_udf_impls.len(), 1);
+        // both values are sent to storage in one object
+        assert!(codegen
+            .response_blocks
+            .iter()
+            .any(|block| block.contains("record.insert(\"p\".to_string()")
+                && block.contains("record.insert(\"q\".to_string()")));
+    }
+
+    #[test]
+    fn test_aggr_group_by() {
+        let result = get_codegen_from_query(
+            "MATCH (a) -[]-> (b)-[]->(c) RETURN a.node.metadata.WORKLOAD_NAME, avg(a.request.total_size)"
+                .to_string(),
+        );
+        let codegen = generate_code_blocks(result, [AVG.to_string()].to_vec()).unwrap();
+        assert!(codegen.aggregation_udf_table["avg"].grouped);
+        assert!(codegen
+            .response_blocks
+            .iter()
+            .any(|block| block.contains("record.insert(\"group\".to_string()")));
+        assert!(codegen
+            .response_blocks
+            .iter()
+            .any(|block| block
+                .contains("record.insert(\"a.node.metadata.WORKLOAD_NAME\".to_string()")));
+    }
 }
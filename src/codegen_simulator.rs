@@ -1,12 +1,36 @@
+use super::codegen_common::aggregation_state;
 use super::codegen_common::assign_id_to_property;
-use super::codegen_common::parse_udf;
+use super::codegen_common::edge_filters_to_code;
+use super::codegen_common::make_arithmetic_block;
+use super::codegen_common::make_binding_block;
+use super::codegen_common::make_expression_filter_block;
+use super::codegen_common::make_json_value_block;
+use super::codegen_common::make_literal_block;
+use super::codegen_common::make_local_filter_blocks;
+use super::codegen_common::make_optional_block;
+use super::codegen_common::make_record_block;
+use super::codegen_common::make_skip_null_block;
+use super::codegen_common::needed_properties;
+use super::codegen_common::operand_variables;
+use super::codegen_common::predicate_node;
+use super::codegen_common::predicate_to_code;
+use super::codegen_common::present_variable;
+use super::codegen_common::property_id;
+use super::codegen_common::simulator_property_types;
+use super::codegen_common::udf_args_to_code;
+use super::codegen_common::udf_calls_in_dependency_order;
+use super::codegen_common::udf_result_to_code;
+use super::codegen_common::udf_tables;
+use super::codegen_common::unbound_parameter;
+use super::codegen_common::value_types;
 use super::codegen_common::AggregationUdf;
 use super::codegen_common::CodeStruct;
-use super::codegen_common::ScalarOrAggregationUdf;
 use super::codegen_common::ScalarUdf;
+use super::diagnostics::CompileError;
+use super::diagnostics::CompileResult;
 use super::ir::Aggregate;
-use super::ir::AttributeFilter;
 use super::ir::IrReturnEnum;
+use super::ir::Predicate;
 use super::ir::Property;
 use super::ir::PropertyOrUDF;
 use super::ir::StructuralFilter;
@@ -14,74 +38,127 @@ use super::ir::UdfCall;
 use super::ir::VisitorResults;
 use indexmap::map::IndexMap;
 use indexmap::IndexSet;
+use utils::graph::graph_utils::ValueType;
 
 /********************************/
 // Code Generation
 /********************************/
 
-fn make_struct_filter_blocks(
-    attr_filters: &[AttributeFilter],
-    struct_filters: &[StructuralFilter],
+/// Declares the vertices, edges and node filters of one target graph.
+fn make_target_graph_blocks(
+    attr_filters: &[Predicate],
+    struct_filter: &StructuralFilter,
     id_to_property: &IndexMap<String, u64>,
-) -> Vec<String> {
+    value_types: &IndexMap<String, ValueType>,
+) -> CompileResult<Vec<String>> {
     let mut target_blocks = Vec::new();
-
-    for struct_filter in struct_filters {
-        target_blocks.push(" let vertices = vec!( ".to_string());
-        for vertex in &struct_filter.vertices {
-            target_blocks.push(format!("\"{vertex}\".to_string(),", vertex = vertex));
-        }
-        target_blocks.push(" );\n".to_string());
-
-        target_blocks.push("        let edges = vec!( ".to_string());
-        for edge in &struct_filter.edges {
-            target_blocks.push(format!(
-                " (\"{edge1}\".to_string(), \"{edge2}\".to_string() ), ",
-                edge1 = edge.0,
-                edge2 = edge.1
-            ));
-        }
-        target_blocks.push(" );\n".to_string());
-
-        let ids_to_prop_block = "        let mut ids_to_properties: IndexMap<String, IndexMap<u64, String>> = IndexMap::new();\n".to_string();
-        target_blocks.push(ids_to_prop_block);
-
-        for vertex in &struct_filter.vertices {
-            let ids_to_properties_hashmap_init = format!(
-                "        ids_to_properties.insert(\"{node}\".to_string(), IndexMap::new());\n",
-                node = vertex
+    target_blocks.push(" let vertices = vec!( ".to_string());
+    for vertex in &struct_filter.vertices {
+        target_blocks.push(format!("\"{vertex}\".to_string(),", vertex = vertex));
+    }
+    target_blocks.push(" );\n".to_string());
+
+    target_blocks.push("        let edges = vec!( ".to_string());
+    for edge in &struct_filter.edges {
+        target_blocks.push(format!(
+            " (\"{edge1}\".to_string(), \"{edge2}\".to_string() ), ",
+            edge1 = edge.0,
+            edge2 = edge.1
+        ));
+    }
+    target_blocks.push(" );\n".to_string());
+
+    target_blocks.push("        let undirected_edges = vec!( ".to_string());
+    for edge in &struct_filter.undirected_edges {
+        target_blocks.push(format!(
+            " (\"{edge1}\".to_string(), \"{edge2}\".to_string() ), ",
+            edge1 = edge.0,
+            edge2 = edge.1
+        ));
+    }
+    target_blocks.push(" );\n".to_string());
+
+    target_blocks.push("        let edge_hops = vec!( ".to_string());
+    target_blocks.extend(edge_filters_to_code(
+        struct_filter,
+        attr_filters,
+        id_to_property,
+        value_types,
+        "graph_utils::",
+    )?);
+    target_blocks.push(" );\n".to_string());
+
+    let ids_to_filters_block = "        let mut ids_to_filters: IndexMap<String, Vec<graph_utils::Predicate>> = IndexMap::new();\n".to_string();
+    target_blocks.push(ids_to_filters_block);
+
+    for vertex in &struct_filter.vertices {
+        let ids_to_filters_init = format!(
+            "        ids_to_filters.insert(\"{node}\".to_string(), Vec::new());\n",
+            node = vertex
+        );
+        target_blocks.push(ids_to_filters_init);
+    }
+    for predicate in attr_filters {
+        let node = predicate_node(predicate)?;
+        // conjuncts on relationships are part of the edge filters
+        if struct_filter.vertices.contains(&node) {
+            let get_filters = format!(
+                "        let {node}_filters = ids_to_filters.get_mut(\"{node}\").unwrap();\n",
+                node = node
             );
-            target_blocks.push(ids_to_properties_hashmap_init);
+            target_blocks.push(get_filters);
+            let fill_in_filters = format!(
+                "        {node}_filters.push({predicate});\n",
+                node = node,
+                predicate =
+                    predicate_to_code(predicate, id_to_property, value_types, "graph_utils::")?
+            );
+            target_blocks.push(fill_in_filters);
         }
-        for property_filter in attr_filters {
-            if property_filter.node != "trace" {
-                let mut property_name_without_period = property_filter.property.clone();
-                if property_name_without_period.starts_with('.') {
-                    property_name_without_period.remove(0);
-                }
-                let get_hashmap = format!(
-                    "        let mut {node}_hashmap = ids_to_properties.get_mut(\"{node}\").unwrap();\n",
-                    node = property_filter.node
-                );
-                target_blocks.push(get_hashmap);
-                let fill_in_hashmap = format!("        {node}_hashmap.insert({property_name}, \"{property_value}\".to_string());\n",
-                                               node=property_filter.node,
-                                               property_name=id_to_property[&property_name_without_period],
-                                               property_value=property_filter.value);
-                target_blocks.push(fill_in_hashmap);
-            }
+    }
+    Ok(target_blocks)
+}
+
+fn make_struct_filter_blocks(
+    query_data: &VisitorResults,
+    id_to_property: &IndexMap<String, u64>,
+    value_types: &IndexMap<String, ValueType>,
+) -> CompileResult<Vec<String>> {
+    let mut graphs = Vec::new();
+    for (branch, struct_filter) in query_data.struct_filters.iter().enumerate() {
+        let attr_filters = query_data.branch_attr_filters(branch);
+        graphs.push(make_target_graph_blocks(
+            &attr_filters,
+            struct_filter,
+            id_to_property,
+            value_types,
+        )?);
+    }
+    let mut target_blocks = Vec::new();
+    if graphs.len() <= 1 {
+        for graph_blocks in graphs {
+            target_blocks.extend(graph_blocks);
+            target_blocks.push("        return graph_utils::generate_filtered_target_graph(vertices, edges, undirected_edges, edge_hops, ids_to_filters).into_iter().collect();\n".to_string());
         }
-        let make_graph = "        return graph_utils::generate_target_graph(vertices, edges, ids_to_properties);\n".to_string();
-        target_blocks.push(make_graph);
+        return Ok(target_blocks);
+    }
+    // one target graph for each UNION branch
+    target_blocks.push("        let mut target_graphs = Vec::new();\n".to_string());
+    for graph_blocks in graphs {
+        target_blocks.push("        {\n".to_string());
+        target_blocks.extend(graph_blocks);
+        target_blocks.push("        if let Some(target_graph) = graph_utils::generate_filtered_target_graph(vertices, edges, undirected_edges, edge_hops, ids_to_filters) {\n            target_graphs.push(target_graph);\n        }\n        }\n".to_string());
     }
-    target_blocks
+    target_blocks.push("        return target_graphs;\n".to_string());
+    Ok(target_blocks)
 }
 
 fn make_attr_filter_blocks(
     root_id: &str,
-    attr_filters: &[AttributeFilter],
+    attr_filters: &[Predicate],
     id_to_property: &IndexMap<String, u64>,
-) -> Vec<String> {
+    value_types: &IndexMap<String, ValueType>,
+) -> CompileResult<Vec<String>> {
     // for everything except trace level attributes, the UDF/envoy property
     // collection will make the attribute filtering happen at the same time as
     // the struct filtering.  This is not the case for trace-level attributes
@@ -94,17 +171,12 @@ fn make_attr_filter_blocks(
     let init_trace_prop_str = "        let mut trace_prop_str : String;\n".to_string();
     trace_lvl_prop_blocks.push(init_trace_prop_str);
 
-    for attr_filter in attr_filters {
-        if attr_filter.node == "trace" {
-            let mut prop = attr_filter.property.clone();
-            if prop.starts_with('.') {
-                prop.remove(0);
-            }
+    for predicate in attr_filters {
+        if predicate_node(predicate)? == "trace" {
             let trace_filter_block = format!(
                 "
             let root_node = graph_utils::get_node_with_id(&fd.trace_graph, \"{root_id}\").unwrap();
-            if ! ( fd.trace_graph.node_weight(root_node).unwrap().1.contains_key(&{prop_name}) &&
-                fd.trace_graph.node_weight(root_node).unwrap().1[{prop_name}] == \"{value}\" ){{
+            if !{predicate}.evaluate(&fd.trace_graph.node_weight(root_node).unwrap().1) {{
                 // TODO:  replace fd
                 match serde_json::to_string(&fd) {{
                     Ok(fd_str) => {{
@@ -119,8 +191,8 @@ fn make_attr_filter_blocks(
             }}
             ",
                 root_id = root_id,
-                prop_name = id_to_property[&prop],
-                value = attr_filter.value
+                predicate =
+                    predicate_to_code(predicate, id_to_property, value_types, "graph_utils::")?
             );
             trace_lvl_prop_blocks.push(trace_filter_block);
         }
@@ -128,7 +200,7 @@ fn make_attr_filter_blocks(
 
     let end_root_block = "       }".to_string();
     trace_lvl_prop_blocks.push(end_root_block);
-    trace_lvl_prop_blocks
+    Ok(trace_lvl_prop_blocks)
 }
 
 #[allow(dead_code)]
@@ -146,27 +218,36 @@ fn make_storage_rpc_value_from_trace(
     entity: String,
     property: &str,
     id_to_property: &IndexMap<String, u64>,
-) -> String {
-    format!(
+    target: &str,
+) -> CompileResult<String> {
+    Ok(format!(
         "let trace_node_index = graph_utils::get_node_with_id(&fd.trace_graph, \"{node_id}\");
     if trace_node_index.is_none() {{
        log::warn!(\"Node {node_id} not found\");
             return None;
     }}
-    let mut ret = &fd.trace_graph.node_weight(trace_node_index.unwrap()).unwrap().1[ {prop} ];\n
-    value = ret.to_string();\n",
+    let ret = match fd.trace_graph.node_weight(trace_node_index.unwrap()).unwrap().1.get(&{prop}) {{
+        Some(ret) => ret,
+        None => {{
+            // we have not yet collected the return property
+            return None;
+        }}
+    }};
+    {target} = ret.to_string();\n",
         node_id = entity,
-        prop = id_to_property[property],
-    )
+        prop = property_id(id_to_property, property)?,
+        target = target
+    ))
 }
 
 fn make_storage_rpc_value_from_target(
     entity: &str,
     property: &str,
     id_to_property: &IndexMap<String, u64>,
-) -> String {
+    target: &str,
+) -> CompileResult<String> {
     print!("property: {:?}", property);
-    format!(
+    Ok(format!(
     "let node_ptr = graph_utils::get_node_with_id(target_graph, \"{node_id}\");
     if node_ptr.is_none() {{
        log::warn!(\"Node {node_id} not found\");
@@ -183,85 +264,283 @@ fn make_storage_rpc_value_from_target(
         // we have not yet collected the return property or have a mapping error
         return None;
     }}
-    let mut ret = &fd.trace_graph.node_weight(trace_node_index.unwrap()).unwrap().1[ {prop} ];\n
-    value = ret.to_string();\n",
+    let mut ret = &fd.trace_graph.node_weight(trace_node_index.unwrap()).unwrap().1[&{prop}];\n
+    {target} = ret.to_string();\n",
             node_id = entity,
-            prop = id_to_property[property]
-    )
+            prop = property_id(id_to_property, property)?,
+            target = target
+    ))
+}
+
+fn make_storage_rpc_value_from_edge(
+    relationship: &str,
+    edge: &(String, String),
+    property: &str,
+    id_to_property: &IndexMap<String, u64>,
+    target: &str,
+) -> CompileResult<String> {
+    Ok(format!(
+    "let mut caller_index = None;
+    let mut callee_index = None;
+    for map in mapping {{
+        if target_graph.node_weight(map.0).unwrap().0 == \"{caller}\" {{
+            caller_index = Some(map.1);
+        }}
+        if target_graph.node_weight(map.0).unwrap().0 == \"{callee}\" {{
+            callee_index = Some(map.1);
+        }}
+    }}
+    if caller_index == None || callee_index == None {{
+        log::warn!(\"Relationship {relationship} not found\");
+        return None;
+    }}
+    // an undirected relationship may have matched a call either way
+    let call_index = fd.trace_graph.find_edge(caller_index.unwrap(), callee_index.unwrap())
+        .or_else(|| fd.trace_graph.find_edge(callee_index.unwrap(), caller_index.unwrap()));
+    if call_index == None || !&fd.trace_graph.edge_weight(call_index.unwrap()).unwrap().contains_key(&{prop}) {{
+        // we have not yet collected the return property or have a mapping error
+        return None;
+    }}
+    let ret = &fd.trace_graph.edge_weight(call_index.unwrap()).unwrap()[ {prop} ];\n
+    {target} = ret.to_string();\n",
+            relationship = relationship,
+            caller = edge.0,
+            callee = edge.1,
+            prop = property_id(id_to_property, property)?,
+            target = target
+    ))
 }
 
+/// Assigns the value of the item to the target variable.  Items that read the
+/// nodes an OPTIONAL MATCH adds are null for traces without them, so their
+/// variable is an Option<String>.
 fn make_return_block(
     entity_ref: &PropertyOrUDF,
     query_data: &VisitorResults,
     id_to_property: &IndexMap<String, u64>,
-) -> String {
+    target: &str,
+) -> CompileResult<String> {
+    let nodes = query_data.item_optional_nodes(entity_ref);
+    if nodes.is_empty() {
+        return make_value_block(entity_ref, query_data, id_to_property, target);
+    }
+    Ok(make_optional_block(
+        &nodes,
+        &make_value_block(
+            entity_ref,
+            query_data,
+            id_to_property,
+            &present_variable(target),
+        )?,
+        target,
+    ))
+}
+
+/// Assigns the value of the item to the target variable, assuming the mapping
+/// has all the nodes it reads.
+fn make_value_block(
+    entity_ref: &PropertyOrUDF,
+    query_data: &VisitorResults,
+    id_to_property: &IndexMap<String, u64>,
+    target: &str,
+) -> CompileResult<String> {
     match entity_ref {
         PropertyOrUDF::Property(prop) => match prop.parent.as_str() {
             "trace" => make_storage_rpc_value_from_trace(
                 query_data.root_id.clone(),
                 &prop.to_dot_string(),
                 id_to_property,
+                target,
             ),
+            relationship if query_data.relationship(relationship).is_some() => {
+                make_storage_rpc_value_from_edge(
+                    relationship,
+                    query_data.relationship(relationship).unwrap(),
+                    &prop.to_dot_string(),
+                    id_to_property,
+                    target,
+                )
+            }
             _ => make_storage_rpc_value_from_target(
                 &prop.parent,
                 &prop.to_dot_string(),
                 id_to_property,
+                target,
             ),
         },
         PropertyOrUDF::UdfCall(call) => {
-            // Because of quirky design we need to get the first arg
-            if call.args.len() != 1 {
-                panic!("We currently only implement very specific arguments for UDFs!");
+            // the result is stored as a property of the node the call is applied to
+            let nodes = call.nodes();
+            if nodes.len() != 1 {
+                return Err(CompileError::new(format!(
+                    "UDF call {:?} has to refer to exactly one node, but refers to {:?}",
+                    call.to_ref_str(),
+                    nodes
+                )));
             }
-            let node = &call.args[0];
+            let node = &nodes[0];
             match node.as_str() {
                 "trace" => make_storage_rpc_value_from_trace(
                     query_data.root_id.clone(),
                     &call.id,
                     id_to_property,
+                    target,
                 ),
-                _ => make_storage_rpc_value_from_target(node, &call.id, id_to_property),
+                _ => make_storage_rpc_value_from_target(node, &call.id, id_to_property, target),
             }
         }
+        PropertyOrUDF::Literal(literal) => Ok(make_literal_block(literal, target)),
+        PropertyOrUDF::Parameter(name) => Err(unbound_parameter(name)),
+        PropertyOrUDF::Arithmetic(arithmetic) => {
+            let (lhs, rhs) = operand_variables(target);
+            Ok(make_arithmetic_block(
+                arithmetic.op,
+                &make_value_block(&arithmetic.left, query_data, id_to_property, &lhs)?,
+                &make_value_block(&arithmetic.right, query_data, id_to_property, &rhs)?,
+                "graph_utils::",
+                target,
+            ))
+        }
     }
 }
 
+/// Assigns the record of the columns to the target variable.  Each column is
+/// bound to a variable of its own first, named after the target.
+fn make_record_return_block(
+    columns: &[(String, PropertyOrUDF)],
+    query_data: &VisitorResults,
+    id_to_property: &IndexMap<String, u64>,
+    target: &str,
+) -> CompileResult<String> {
+    let mut record_block = String::new();
+    let mut fields = Vec::new();
+    for (idx, (name, column)) in columns.iter().enumerate() {
+        let variable = format!("{}_{}", target, idx);
+        record_block.push_str(&make_binding_block(
+            &variable,
+            &make_return_block(column, query_data, id_to_property, &variable)?,
+            query_data.may_be_null(column),
+        ));
+        fields.push((name.clone(), variable));
+    }
+    record_block.push_str(&make_record_block(&fields, target));
+    Ok(record_block)
+}
+
 fn make_aggr_block(
     agg: &Aggregate,
     query_data: &VisitorResults,
     id_to_property: &IndexMap<String, u64>,
-) -> String {
-    let mut to_return = String::new();
-    for arg in &agg.args {
-        to_return.push_str(&make_return_block(arg, query_data, id_to_property));
+    target: &str,
+) -> CompileResult<String> {
+    if agg.group_by.is_empty() {
+        return make_return_block(&agg.args[0], query_data, id_to_property, target);
+    }
+    // grouped aggregations send the group along with the value
+    let group = format!("{}_group", target);
+    let instance = format!("{}_instance", target);
+    Ok(format!(
+        "let {group}: String;\n{group_block}{instance_block}{record_block}",
+        group = group,
+        group_block = make_record_return_block(&agg.group_by, query_data, id_to_property, &group)?,
+        instance_block = make_binding_block(
+            &instance,
+            &make_return_block(&agg.args[0], query_data, id_to_property, &instance)?,
+            query_data.may_be_null(&agg.args[0])
+        ),
+        record_block = make_record_block(
+            &[
+                ("group".to_string(), group.clone()),
+                ("value".to_string(), instance.clone())
+            ],
+            target
+        )
+    ))
+}
+
+/// Several aggregations send their values to storage as one object, keyed by
+/// the key each aggregation is stored under.
+fn make_aggrs_block(
+    aggs: &[Aggregate],
+    query_data: &VisitorResults,
+    id_to_property: &IndexMap<String, u64>,
+    target: &str,
+) -> CompileResult<String> {
+    if let [agg] = aggs {
+        if agg.group_by.is_empty() && query_data.may_be_null(&agg.args[0]) {
+            let instance = format!("{}_instance", target);
+            return Ok(make_skip_null_block(
+                &make_aggr_block(agg, query_data, id_to_property, &instance)?,
+                &instance,
+                target,
+            ));
+        }
+        return make_aggr_block(agg, query_data, id_to_property, target);
+    }
+    let mut aggrs_block = String::new();
+    let mut fields = Vec::new();
+    for (idx, agg) in aggs.iter().enumerate() {
+        let variable = format!("{}_{}", target, idx);
+        // ungrouped values that are missing are sent as null
+        aggrs_block.push_str(&make_binding_block(
+            &variable,
+            &make_aggr_block(agg, query_data, id_to_property, &variable)?,
+            agg.group_by.is_empty() && query_data.may_be_null(&agg.args[0]),
+        ));
+        fields.push((agg.key(), variable));
     }
-    to_return
+    aggrs_block.push_str(&make_record_block(&fields, target));
+    Ok(aggrs_block)
 }
 
+/// Collects the properties into the given set of unassigned properties of fd,
+/// with entity as the code naming the node they belong to.  The properties of a
+/// call are collected by the caller, but belong to the callee.  Each property
+/// is only collected under its condition, as worked out by needed_properties.
 fn generate_property_blocks(
-    properties: &IndexSet<Property>,
-    scalar_udf_table: &IndexMap<String, ScalarUdf>,
+    properties: &[(Property, Option<String>)],
     id_to_property: &IndexMap<String, u64>,
-) -> Vec<String> {
+    value_types: &IndexMap<String, ValueType>,
+    unassigned: &str,
+    entity: &str,
+) -> CompileResult<Vec<String>> {
     let mut property_blocks = Vec::new();
-    // some "properties" are created by UDFs, and if so, shouldn't be collected here
-    for property in properties {
-        if property.members.is_empty() || scalar_udf_table.contains_key(&property.to_dot_string()) {
-            continue;
+    for (property, condition) in properties {
+        if let Some(condition) = condition {
+            property_blocks.push(format!("if {} {{\n            ", condition));
         }
+        // a missing property is not stored, which WHERE clauses check with IS NULL,
+        // and neither is one whose value has the wrong type
+        let dot_str = property.to_dot_string();
         let get_prop_block = format!(
-            "prop_tuple = Property::new(filter.whoami.as_ref().unwrap().to_string(),
+            "if let Some(value) = filter.filter_state.get(\"{property_name}\") {{
+                if let Some(value) = graph_utils::ValueType::{value_type:?}.parse(value) {{
+                prop_tuple = Property::new({entity},
                                                    {property},
-                                                   filter.filter_state[\"{property_name}\"].clone());
+                                                   value);
                                             ",
-            property = id_to_property[&property.to_dot_string()],
-            property_name = property.to_dot_string()
+            entity = entity,
+            property = property_id(id_to_property, &dot_str)?,
+            property_name = dot_str,
+            value_type = value_types[&dot_str]
+        );
+        let insert_hdr_block = format!(
+            "fd.{unassigned}.insert(prop_tuple);
+                }} else {{
+                    log::error!(\"Property {property_name} is not a {value_type:?} value\");
+                }}
+            }}",
+            unassigned = unassigned,
+            property_name = dot_str,
+            value_type = value_types[&dot_str]
         );
-        let insert_hdr_block = "fd.unassigned_properties.insert(prop_tuple);".to_string();
         property_blocks.push(get_prop_block);
         property_blocks.push(insert_hdr_block);
+        if condition.is_some() {
+            property_blocks.push("\n            }".to_string());
+        }
     }
-    property_blocks
+    Ok(property_blocks)
 }
 
 fn generate_udf_blocks(
@@ -269,106 +548,213 @@ fn generate_udf_blocks(
     aggregation_udf_table: &IndexMap<String, AggregationUdf>,
     udf_calls: &IndexSet<UdfCall>,
     id_to_property: &IndexMap<String, u64>,
-) -> Vec<String> {
+    value_types: &IndexMap<String, ValueType>,
+) -> CompileResult<Vec<String>> {
     let mut udf_blocks = Vec::new();
-    for call in udf_calls {
+    for call in udf_calls_in_dependency_order(udf_calls)? {
         if aggregation_udf_table.contains_key(&call.id) {
             continue;
         }
         if !scalar_udf_table.contains_key(&call.id) {
-            log::error!("ID {:?} not found in the scalar UDF map!", call.id);
-            std::process::exit(1);
+            return Err(CompileError::new(format!(
+                "UDF {:?} is not defined, pass its implementation with --udf",
+                call.id
+            )));
         }
+        let id = property_id(id_to_property, &call.id)?;
+        // the values of property arguments are read from the current node
+        let property_at_node = |id| {
+            format!(
+                "fd.trace_graph.node_weight(node).unwrap().1.get(&{})?.to_string()",
+                id
+            )
+        };
+        let args = udf_args_to_code(&call, scalar_udf_table, id_to_property, &property_at_node)?;
+        let udf = &scalar_udf_table[&call.id];
         let get_udf_vals = format!(
-            "let my_{name}_value;
-            let child_iterator = fd.trace_graph.neighbors_directed(
-                graph_utils::get_node_with_id(&fd.trace_graph, filter.whoami.as_ref().unwrap()).unwrap(),
-                petgraph::Outgoing);
+            "// None if a property the UDF is passed or a value of a child is missing
+        let my_{name}_value = (|| {{
+            let node = graph_utils::get_node_with_id(&fd.trace_graph, filter.whoami.as_ref().unwrap()).unwrap();
+            let child_iterator = fd.trace_graph.neighbors_directed(node, petgraph::Outgoing);
             let mut child_values = Vec::new();
             for child in child_iterator {{
-                child_values.push(fd.trace_graph.node_weight(child).unwrap().1[&{id}].clone());
+                child_values.push(fd.trace_graph.node_weight(child).unwrap().1.get(&{id})?.to_string());
             }}
             if child_values.len() == 0 {{
-                my_{name}_value = {leaf_func}(&fd.trace_graph).to_string();
+                Some({leaf_result})
             }} else {{
-                my_{name}_value = {mid_func}(&fd.trace_graph, child_values).to_string();
+                Some({mid_result})
             }}
-
+        }})();
         ",
-            id = id_to_property[&call.id],
+            id = id,
             name = call.id,
-            leaf_func = scalar_udf_table[&call.id].leaf_func,
-            mid_func = scalar_udf_table[&call.id].mid_func
+            leaf_result = udf_result_to_code(
+                &format!("{}(&fd.trace_graph{})", udf.leaf_func, args),
+                value_types[&call.id],
+                "graph_utils::"
+            ),
+            mid_result = udf_result_to_code(
+                &format!("{}(&fd.trace_graph, child_values{})", udf.mid_func, args),
+                value_types[&call.id],
+                "graph_utils::"
+            )
         );
         udf_blocks.push(get_udf_vals);
 
         let save_udf_vals = format!("
-        let node = graph_utils::get_node_with_id(&fd.trace_graph, filter.whoami.as_ref().unwrap()).unwrap();
-        // if we already have the property, don't add it
-        if !( fd.trace_graph.node_weight(node).unwrap().1.contains_key(&{id}) &&
-               fd.trace_graph.node_weight(node).unwrap().1[{id}] == my_{call}_value ) {{
-           fd.trace_graph.node_weight_mut(node).unwrap().1.insert(
-               {id}, my_{call}_value);
+        if let Some(value) = &my_{call}_value {{
+            let node = graph_utils::get_node_with_id(&fd.trace_graph, filter.whoami.as_ref().unwrap()).unwrap();
+            // if we already have the property, don't add it
+            if fd.trace_graph.node_weight(node).unwrap().1.get(&{id}) != Some(value) {{
+                fd.trace_graph.node_weight_mut(node).unwrap().1.insert({id}, value.clone());
+            }}
         }}
-        ", id=id_to_property[&call.id], call=call.id);
+        ", id = id, call=call.id);
 
         udf_blocks.push(save_udf_vals);
     }
-    udf_blocks
+    Ok(udf_blocks)
 }
 
-pub fn generate_code_blocks(query_data: VisitorResults, udf_paths: Vec<String>) -> CodeStruct {
+pub fn generate_code_blocks(
+    query_data: VisitorResults,
+    udf_paths: Vec<String>,
+) -> CompileResult<CodeStruct> {
     let mut code_struct = CodeStruct::new(&query_data.root_id);
-    let mut scalar_udf_table: IndexMap<String, ScalarUdf> = IndexMap::new();
-    // where we store udf implementations
-    let mut aggregation_udf_table: IndexMap<String, AggregationUdf> = IndexMap::new();
-    for udf_path in udf_paths {
-        log::debug!("UDF: {:?}", udf_path);
-        match parse_udf(udf_path) {
-            ScalarOrAggregationUdf::ScalarUdf(udf) => {
-                scalar_udf_table.insert(udf.id.clone(), udf);
-            }
-            ScalarOrAggregationUdf::AggregationUdf(udf) => {
-                aggregation_udf_table.insert(udf.id.clone(), udf);
-            }
-        }
-    }
+    let (scalar_udf_table, aggregation_udf_table) = udf_tables(&udf_paths)?;
     code_struct.id_to_property = assign_id_to_property(&query_data.properties, &scalar_udf_table);
-    // all the properties we collect
-    code_struct.collect_properties_blocks = generate_property_blocks(
-        &query_data.properties,
-        &scalar_udf_table,
+    let value_types = value_types(
         &code_struct.id_to_property,
+        &scalar_udf_table,
+        &simulator_property_types(),
     );
+    // all the properties we collect, for nodes and for the calls between them
+    let (edge_properties, node_properties): (IndexSet<Property>, IndexSet<Property>) = query_data
+        .properties
+        .iter()
+        .cloned()
+        .partition(|property| query_data.relationship(&property.parent).is_some());
+    code_struct.collect_properties_blocks = generate_property_blocks(
+        &needed_properties(
+            &query_data,
+            &node_properties,
+            &scalar_udf_table,
+            "filter.whoami.as_ref().unwrap()",
+            "callee",
+        ),
+        &code_struct.id_to_property,
+        &value_types,
+        "unassigned_properties",
+        "filter.whoami.as_ref().unwrap().to_string()",
+    )?;
+    code_struct.collect_edge_properties_blocks = generate_property_blocks(
+        &needed_properties(
+            &query_data,
+            &edge_properties,
+            &scalar_udf_table,
+            "filter.whoami.as_ref().unwrap()",
+            "callee",
+        ),
+        &code_struct.id_to_property,
+        &value_types,
+        "unassigned_edge_properties",
+        "callee.clone()",
+    )?;
+    if !code_struct.collect_edge_properties_blocks.is_empty() {
+        // the response of the callee holds the trace graph rooted at the callee
+        code_struct.collect_edge_properties_blocks.insert(
+            0,
+            "let callee = match fd.root_name() {
+                Some(callee) => callee,
+                None => return,
+            };"
+            .to_string(),
+        );
+    }
     code_struct.udf_blocks = generate_udf_blocks(
         &scalar_udf_table,
         &aggregation_udf_table,
         &query_data.udf_calls,
         &code_struct.id_to_property,
-    );
-    code_struct.target_blocks = make_struct_filter_blocks(
-        &query_data.attr_filters,
-        &query_data.struct_filters,
+        &value_types,
+    )?;
+    code_struct.local_filter_blocks = make_local_filter_blocks(
+        &query_data,
         &code_struct.id_to_property,
-    );
+        &value_types,
+        &scalar_udf_table,
+        "filter.whoami.as_ref().unwrap()",
+        "graph_utils::",
+    )?;
+    code_struct.target_blocks =
+        make_struct_filter_blocks(&query_data, &code_struct.id_to_property, &value_types)?;
     code_struct.trace_lvl_prop_blocks = make_attr_filter_blocks(
         &query_data.root_id,
         &query_data.attr_filters,
         &code_struct.id_to_property,
-    );
+        &value_types,
+    )?;
 
     let resp_block = match query_data.return_expr {
-        IrReturnEnum::PropertyOrUDF(ref entity_ref) => {
-            make_return_block(entity_ref, &query_data, &code_struct.id_to_property)
+        IrReturnEnum::PropertyOrUDF(ref entity_ref) if !query_data.may_be_null(entity_ref) => {
+            make_return_block(
+                entity_ref,
+                &query_data,
+                &code_struct.id_to_property,
+                "value",
+            )
+        }
+        // a value that may be missing is sent as JSON, null if it is
+        IrReturnEnum::PropertyOrUDF(ref entity_ref) => Ok(make_json_value_block(
+            &make_return_block(
+                entity_ref,
+                &query_data,
+                &code_struct.id_to_property,
+                "nullable_value",
+            )?,
+            "nullable_value",
+            "value",
+        )),
+        IrReturnEnum::Aggregate(ref aggs) => {
+            code_struct.aggregation_udf_table = aggregation_state(aggs, &aggregation_udf_table)?;
+            make_aggrs_block(aggs, &query_data, &code_struct.id_to_property, "value")
         }
-        IrReturnEnum::Aggregate(ref agg) => {
-            make_aggr_block(agg, &query_data, &code_struct.id_to_property)
+        IrReturnEnum::Record(ref columns) => {
+            make_record_return_block(columns, &query_data, &code_struct.id_to_property, "value")
         }
-    };
+    }?;
+    // expression filters need the whole mapping, so the matcher checks them on every
+    // mapping it tries.  A mapping without a node they read is not accepted
+    let (filter_lhs, filter_rhs) = operand_variables("filter");
+    for expression_filter in &query_data.expression_filters {
+        code_struct
+            .expression_filter_blocks
+            .push(make_expression_filter_block(
+                expression_filter.op,
+                &make_value_block(
+                    &expression_filter.left,
+                    &query_data,
+                    &code_struct.id_to_property,
+                    &filter_lhs,
+                )?,
+                &make_value_block(
+                    &expression_filter.right,
+                    &query_data,
+                    &code_struct.id_to_property,
+                    &filter_rhs,
+                )?,
+                "graph_utils::",
+            ));
+    }
     code_struct.response_blocks.push(resp_block);
-    code_struct.aggregation_udf_table = aggregation_udf_table;
+    code_struct.aggregation_udf_impls = code_struct
+        .aggregation_udf_table
+        .values()
+        .map(|udf| udf.func_impl.clone())
+        .collect();
     code_struct.scalar_udf_table = scalar_udf_table;
-    code_struct
+    Ok(code_struct)
 }
 
 #[cfg(test)]
@@ -376,6 +762,7 @@ mod tests {
     use super::*;
     use crate::antlr_gen::lexer::CypherLexer;
     use crate::antlr_gen::parser::CypherParser;
+    use crate::to_ir::resolve_aggregates;
     use crate::to_ir::visit_result;
     use antlr_rust::common_token_stream::CommonTokenStream;
     use antlr_rust::token_factory::CommonTokenFactory;
@@ -433,7 +820,10 @@ mod tests {
         let token_source = CommonTokenStream::new(_lexer);
         let mut parser = CypherParser::new(token_source);
         let result = parser.oC_Cypher().expect("parsed unsuccessfully");
-        visit_result(result, "".to_string())
+        let mut query_data = visit_result(result, "".to_string()).unwrap();
+        let (_, aggregation_udf_table) = udf_tables(&[AVG.to_string()]).unwrap();
+        resolve_aggregates(&mut query_data, &aggregation_udf_table).unwrap();
+        query_data
     }
 
     #[test]
@@ -441,7 +831,7 @@ mod tests {
         let result =
             get_codegen_from_query("MATCH (a) -[]-> (b {})-[]->(c) RETURN count(a)".to_string());
         assert!(!result.struct_filters.is_empty());
-        let _codegen = generate_code_blocks(result, [COUNT.to_string()].to_vec());
+        let _codegen = generate_code_blocks(result, [COUNT.to_string()].to_vec()).unwrap();
     }
 
     #[test]
@@ -450,7 +840,7 @@ mod tests {
             "MATCH (a) -[]-> (b {})-[]->(c) RETURN a.node.metadata.WORKLOAD_NAME".to_string(),
         );
         assert!(!result.struct_filters.is_empty());
-        let _codegen = generate_code_blocks(result, [COUNT.to_string()].to_vec());
+        let _codegen = generate_code_blocks(result, [COUNT.to_string()].to_vec()).unwrap();
     }
 
     #[test]
@@ -459,7 +849,7 @@ mod tests {
             "MATCH (a) WHERE a.node.metadata.WORKLOAD_NAME = 'productpage-v1' RETURN a.request.total_size, count(a.request.total_size)".to_string(),
         );
         assert!(!result.struct_filters.is_empty());
-        let _codegen = generate_code_blocks(result, [COUNT.to_string()].to_vec());
+        let _codegen = generate_code_blocks(result, [COUNT.to_string()].to_vec()).unwrap();
         assert!(!_codegen.target_blocks.is_empty());
     }
 
@@ -469,7 +859,7 @@ mod tests {
             "MATCH (a) -[]-> (b)-[]->(c) WHERE b.node.metadata.WORKLOAD_NAME = 'reviews-v1' AND trace.request.total_size = 1 RETURN a.request.total_size, avg(a.request.total_size)".to_string(),
         );
         assert!(!result.struct_filters.is_empty());
-        let _codegen = generate_code_blocks(result, [AVG.to_string()].to_vec());
+        let _codegen = generate_code_blocks(result, [AVG.to_string()].to_vec()).unwrap();
     }
 
     #[test]
@@ -478,7 +868,8 @@ mod tests {
             "MATCH (a) -[]-> (b)-[]->(c) RETURN a.request.total_size, avg(a.request.total_size)"
                 .to_string(),
         );
-        let _codegen = generate_code_blocks(result, [COUNT.to_string(), AVG.to_string()].to_vec());
+        let _codegen =
+            generate_code_blocks(result, [COUNT.to_string(), AVG.to_string()].to_vec()).unwrap();
         assert!(_codegen.aggregation_udf_table.keys().count() == 1);
     }
 }
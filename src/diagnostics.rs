@@ -0,0 +1,179 @@
+use std::error::Error;
+use std::fmt;
+
+/// Where an error occurred in the query.  Lines start at 1 and columns at 0, as
+/// in the tokens of the ANTLR lexer.  The length is counted in characters.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Span {
+    pub line: usize,
+    pub column: usize,
+    pub len: usize,
+}
+
+/// An error in the user's input, i.e. the query, its parameters or the UDFs.
+/// Errors found while lowering the query point at the part of the query they
+/// refer to.  Bugs in the compiler itself still panic.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CompileError {
+    pub message: String,
+    pub span: Option<Span>,
+}
+
+pub type CompileResult<T> = Result<T, CompileError>;
+
+impl CompileError {
+    pub fn new(message: String) -> Self {
+        CompileError {
+            message,
+            span: None,
+        }
+    }
+
+    pub fn at(span: Span, message: String) -> Self {
+        CompileError {
+            message,
+            span: Some(span),
+        }
+    }
+
+    /// Renders the error the way rustc does, quoting the line of the query it
+    /// refers to and underlining the span:
+    ///
+    /// ```text
+    /// Unsupported direction in "<-[]->": a call can not go both ways
+    ///  --> query.cql:1:11
+    ///   |
+    /// 1 | MATCH (a) <-[]-> (b) RETURN a.request.total_size
+    ///   |           ^^^^^^
+    /// ```
+    pub fn render(&self, source: &str, file_name: &str) -> String {
+        let span = match self.span {
+            Some(span) => span,
+            None => return self.message.clone(),
+        };
+        let mut rendered = format!(
+            "{}\n --> {}:{}:{}",
+            self.message,
+            file_name,
+            span.line,
+            span.column + 1
+        );
+        let line = match source.lines().nth(span.line.saturating_sub(1)) {
+            Some(line) => line,
+            None => return rendered,
+        };
+        let line_num = span.line.to_string();
+        let gutter = " ".repeat(line_num.len());
+        // the underline stops at the end of the line
+        let line_len = line.chars().count();
+        let column = span.column.min(line_len);
+        let len = span.len.min(line_len - column).max(1);
+        rendered.push_str(&format!(
+            "\n{gutter} |\n{line_num} | {line}\n{gutter} | {pad}{carets}",
+            gutter = gutter,
+            line_num = line_num,
+            line = line,
+            pad = " ".repeat(column),
+            carets = "^".repeat(len)
+        ));
+        rendered
+    }
+
+    /// Renders the error against the query, so that it can be returned from main.
+    pub fn report(&self, source: &str, file_name: &str) -> Report {
+        Report(self.render(source, file_name))
+    }
+}
+
+impl fmt::Display for CompileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.span {
+            Some(span) => write!(
+                f,
+                "{} (line {}, column {})",
+                self.message,
+                span.line,
+                span.column + 1
+            ),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+impl Error for CompileError {}
+
+/// A rendered CompileError.  Rust prints the errors returned from main with
+/// Debug, so Debug prints the rendered text as is.
+pub struct Report(String);
+
+impl Report {
+    /// A report for an error that does not refer to a part of the query, such
+    /// as a file that can not be read.
+    pub fn new(message: String) -> Self {
+        Report(message)
+    }
+}
+
+impl fmt::Debug for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Error for Report {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let query = "MATCH (a) -[]-> (b)\nWHERE a.x = 1 XOR b.y\nRETURN a.x";
+        let error = CompileError::at(
+            Span {
+                line: 2,
+                column: 6,
+                len: 15,
+            },
+            "Unsupported predicate".to_string(),
+        );
+        assert_eq!(
+            error.render(query, "query.cql"),
+            "Unsupported predicate
+ --> query.cql:2:7
+  |
+2 | WHERE a.x = 1 XOR b.y
+  |       ^^^^^^^^^^^^^^^"
+        );
+        assert_eq!(
+            error.to_string(),
+            "Unsupported predicate (line 2, column 7)"
+        );
+
+        // spans running past the end of the line are cut off
+        let error = CompileError::at(
+            Span {
+                line: 3,
+                column: 7,
+                len: 20,
+            },
+            "Unsupported return".to_string(),
+        );
+        assert!(error
+            .render(query, "query.cql")
+            .ends_with("3 | RETURN a.x\n  |        ^^^"));
+
+        // errors without a span are just the message
+        let error = CompileError::new("Unable to parse input udf".to_string());
+        assert_eq!(
+            error.render(query, "query.cql"),
+            "Unable to parse input udf"
+        );
+    }
+}
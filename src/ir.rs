@@ -1,60 +1,466 @@
 /***********************************/
 // IR Structs
 /***********************************/
-use indexmap::IndexSet;
-use serde::Serialize;
+use super::diagnostics::Span;
+use indexmap::{IndexMap, IndexSet};
+use regex::Regex;
+use serde::{Serialize, Serializer};
+use std::fmt;
+// the generated filters evaluate these, so the IR uses them as they are
+pub use utils::graph::graph_utils::{ArithmeticOp, CompareOp, Hops};
 
-#[derive(Clone, Debug, Serialize)]
+// JSON only has string keys, so maps keyed by an edge are written as a list
+// of (edge, value) pairs.
+fn serialize_edge_map<T, S>(
+    map: &IndexMap<(String, String), T>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    T: Serialize,
+    S: Serializer,
+{
+    serializer.collect_seq(map.iter())
+}
+
+#[derive(Clone, Debug, Serialize, PartialEq)]
 pub struct StructuralFilter {
     pub vertices: IndexSet<String>,
+    // (caller, callee) pairs
     pub edges: IndexSet<(String, String)>,
+    // pairs where either node may be the caller, written as (a)--(b)
+    pub undirected_edges: IndexSet<(String, String)>,
+    // edges that span more than one call, written as (a)-[*1..3]->(b)
+    #[serde(serialize_with = "serialize_edge_map")]
+    pub edge_hops: IndexMap<(String, String), Hops>,
+    // relationship variables, as in (a)-[r]->(b), and the edge each one names
+    pub relationships: IndexMap<String, (String, String)>,
+    // the vertices and edges added by an OPTIONAL MATCH, with the index of that
+    // OPTIONAL MATCH.  A trace may match without them.
+    pub optional_vertices: IndexMap<String, usize>,
+    #[serde(serialize_with = "serialize_edge_map")]
+    pub optional_edges: IndexMap<(String, String), usize>,
 }
 impl Default for StructuralFilter {
     fn default() -> Self {
         StructuralFilter {
             vertices: IndexSet::new(),
             edges: IndexSet::new(),
+            undirected_edges: IndexSet::new(),
+            edge_hops: IndexMap::new(),
+            relationships: IndexMap::new(),
+            optional_vertices: IndexMap::new(),
+            optional_edges: IndexMap::new(),
         }
     }
 }
 
-#[derive(Clone, Debug, Serialize, PartialEq, Eq)]
+impl StructuralFilter {
+    /// The indices of the OPTIONAL MATCH clauses that add to this filter.
+    pub fn optional_parts(&self) -> IndexSet<usize> {
+        self.optional_vertices
+            .values()
+            .chain(self.optional_edges.values())
+            .cloned()
+            .collect()
+    }
+
+    /// The calls each OPTIONAL MATCH attaches its nodes with, from a node matched
+    /// before it.  A trace matches with or without what is below them.
+    pub fn optional_attachments(&self) -> Vec<(String, String)> {
+        self.optional_edges
+            .iter()
+            .filter(|(edge, part)| self.optional_vertices.get(&edge.0) != Some(part))
+            .map(|(edge, _)| edge.clone())
+            .collect()
+    }
+}
+
+/// A value a property is compared to, as written in the query.  Quoted values
+/// are strings, so 5 and '5' are different values.  Integers take the type of
+/// the property they are compared to when code is generated, e.g. response.code
+/// holds signed integers and request.duration holds durations.
+#[derive(Clone, Debug, Serialize, PartialEq)]
+pub enum Value {
+    Int(i64),
+    Float(f64),
+    Bool(bool),
+    Str(String),
+    // in nanoseconds, written as duration('10ms')
+    Duration(u64),
+    // a $parameter, without the $, until it is bound to a value
+    Parameter(String),
+}
+
+/// The units of duration literals, with their length in nanoseconds, from the
+/// longest to the shortest.
+const DURATION_UNITS: [(&str, u64); 6] = [
+    ("h", 3_600_000_000_000),
+    ("m", 60_000_000_000),
+    ("s", 1_000_000_000),
+    ("ms", 1_000_000),
+    ("us", 1_000),
+    ("ns", 1),
+];
+
+/// Parses a duration such as 10ms or 1.5s into nanoseconds.
+pub fn parse_duration(text: &str) -> Option<u64> {
+    let split = text.find(|c: char| !c.is_ascii_digit() && c != '.')?;
+    let (amount, unit) = text.split_at(split);
+    let (_, nanos) = DURATION_UNITS.iter().find(|(name, _)| *name == unit)?;
+    if let Ok(amount) = amount.parse::<u64>() {
+        return amount.checked_mul(*nanos);
+    }
+    let duration = amount.parse::<f64>().ok()? * *nanos as f64;
+    if duration < u64::MAX as f64 {
+        Some(duration.round() as u64)
+    } else {
+        None
+    }
+}
+
+/// The text of a string literal as written in the query, e.g. 'O\'Brien' is
+/// O'Brien: the enclosing quotes are removed and escape sequences are decoded.
+/// None if the text is not a string literal.
+pub fn unquote(text: &str) -> Option<String> {
+    let quote = text.chars().next().filter(|c| *c == '\'' || *c == '"')?;
+    if text.len() < 2 || !text.ends_with(quote) {
+        return None;
+    }
+    let mut unquoted = String::new();
+    let mut chars = text[1..text.len() - 1].chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unquoted.push(c);
+            continue;
+        }
+        match chars.next()? {
+            'b' | 'B' => unquoted.push('\u{8}'),
+            'f' | 'F' => unquoted.push('\u{c}'),
+            'n' | 'N' => unquoted.push('\n'),
+            'r' | 'R' => unquoted.push('\r'),
+            't' | 'T' => unquoted.push('\t'),
+            'u' | 'U' => {
+                // \uXXXX or \UXXXXXXXX
+                let digits: String = chars
+                    .clone()
+                    .take_while(|c| c.is_ascii_hexdigit())
+                    .take(8)
+                    .collect();
+                let len = if digits.len() >= 8 { 8 } else { 4 };
+                let code = u32::from_str_radix(digits.get(..len)?, 16).ok()?;
+                unquoted.push(std::char::from_u32(code)?);
+                for _ in 0..len {
+                    chars.next();
+                }
+            }
+            escaped => unquoted.push(escaped),
+        }
+    }
+    Some(unquoted)
+}
+
+impl Value {
+    /// Parses a literal, e.g. 500, 0.5, true, 'reviews-v1' or duration('10ms').
+    pub fn from_literal(text: &str) -> Option<Self> {
+        if let Some(string) = unquote(text) {
+            return Some(Value::Str(string));
+        }
+        if text.eq_ignore_ascii_case("true") || text.eq_ignore_ascii_case("false") {
+            return Some(Value::Bool(text.eq_ignore_ascii_case("true")));
+        }
+        if let Some(duration) = text
+            .get(.."duration(".len())
+            .filter(|function| function.eq_ignore_ascii_case("duration("))
+            .and_then(|_| text["duration(".len()..].strip_suffix(')'))
+        {
+            return parse_duration(&unquote(duration.trim())?).map(Value::Duration);
+        }
+        if let Ok(value) = text.parse() {
+            return Some(Value::Int(value));
+        }
+        // digits only, so that names such as inf are not numbers
+        if !text.starts_with(|c: char| c.is_ascii_digit() || c == '.' || c == '-') {
+            return None;
+        }
+        text.parse()
+            .ok()
+            .filter(|value: &f64| value.is_finite())
+            .map(Value::Float)
+    }
+
+    /// Parses the value of a parameter given on the command line.  Values that
+    /// are not literals, such as --param workload=reviews-v1, are strings.
+    pub fn from_param(text: &str) -> Self {
+        Value::from_literal(text).unwrap_or_else(|| Value::Str(text.to_string()))
+    }
+}
+
+impl Default for Value {
+    fn default() -> Self {
+        Value::Str(String::new())
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Value::Int(value) => write!(f, "{}", value),
+            // 1.0 and not 1, which would be an integer
+            Value::Float(value) => write!(f, "{:?}", value),
+            Value::Bool(value) => write!(f, "{}", value),
+            Value::Str(value) => write!(f, "{:?}", value),
+            Value::Duration(0) => write!(f, "duration('0s')"),
+            Value::Duration(nanos) => {
+                let (unit, unit_nanos) = DURATION_UNITS
+                    .iter()
+                    .find(|(_, unit_nanos)| nanos % unit_nanos == 0)
+                    .unwrap();
+                write!(f, "duration('{}{}')", nanos / unit_nanos, unit)
+            }
+            Value::Parameter(name) => write!(f, "${}", name),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, PartialEq)]
 pub struct AttributeFilter {
     pub node: String,
     pub property: String,
-    pub value: String,
+    pub op: CompareOp,
+    pub value: Value,
 }
 impl Default for AttributeFilter {
     fn default() -> Self {
         AttributeFilter {
             node: String::new(),
             property: String::new(),
-            value: String::new(),
+            op: CompareOp::default(),
+            value: Value::default(),
         }
     }
 }
 
 impl AttributeFilter {
+    /// Checks that the pattern of a =~ comparison is a regular expression, so
+    /// that invalid patterns are reported by the compiler and not by the filters.
+    pub fn check_regex(&self) -> Result<(), String> {
+        match (&self.op, &self.value) {
+            (CompareOp::RegexMatch, Value::Str(pattern)) => Regex::new(pattern)
+                .map(|_| ())
+                .map_err(|err| format!("Invalid regular expression {:?}: {}", pattern, err)),
+            _ => Ok(()),
+        }
+    }
+
     #[allow(dead_code)]
-    pub fn insert_values(&mut self, node: String, property: String, value: String) {
+    pub fn insert_values(&mut self, node: String, property: String, value: Value) {
         self.node = node;
         self.property = property;
         self.value = value;
     }
 }
+/// A check that a property was collected, as in a.response.grpc_status IS NOT NULL.
+#[derive(Clone, Debug, Serialize, PartialEq, Eq)]
+pub struct ExistenceFilter {
+    pub node: String,
+    pub property: String,
+}
+
+/// A boolean combination of attribute filters, as written in a WHERE clause.
+/// IS NULL is the negation of Exists.
+#[derive(Clone, Debug, Serialize, PartialEq)]
+pub enum Predicate {
+    Attribute(AttributeFilter),
+    Exists(ExistenceFilter),
+    And(Vec<Predicate>),
+    Or(Vec<Predicate>),
+    Xor(Vec<Predicate>),
+    Not(Box<Predicate>),
+}
+
+impl Predicate {
+    /// The nodes (or "trace") whose attributes this predicate reads.
+    pub fn nodes(&self) -> IndexSet<String> {
+        let mut nodes = IndexSet::new();
+        self.collect_nodes(&mut nodes);
+        nodes
+    }
+
+    /// All attribute filters at the leaves of this predicate.
+    pub fn attributes_mut(&mut self) -> Vec<&mut AttributeFilter> {
+        match self {
+            Predicate::Attribute(attr_filter) => vec![attr_filter],
+            Predicate::Exists(_) => Vec::new(),
+            Predicate::And(operands) | Predicate::Or(operands) | Predicate::Xor(operands) => {
+                operands
+                    .iter_mut()
+                    .flat_map(|operand| operand.attributes_mut())
+                    .collect()
+            }
+            Predicate::Not(operand) => operand.attributes_mut(),
+        }
+    }
+
+    /// The properties (or scalar UDFs) this predicate reads.  With
+    /// existence_only, only those checked with IS NULL or IS NOT NULL.
+    pub fn properties(&self, existence_only: bool) -> IndexSet<String> {
+        match self {
+            Predicate::Attribute(attr_filter) if !existence_only => {
+                let mut properties = IndexSet::new();
+                properties.insert(attr_filter.property.trim_start_matches('.').to_string());
+                properties
+            }
+            Predicate::Attribute(_) => IndexSet::new(),
+            Predicate::Exists(existence_filter) => {
+                let mut properties = IndexSet::new();
+                properties.insert(
+                    existence_filter
+                        .property
+                        .trim_start_matches('.')
+                        .to_string(),
+                );
+                properties
+            }
+            Predicate::And(operands) | Predicate::Or(operands) | Predicate::Xor(operands) => {
+                operands
+                    .iter()
+                    .flat_map(|operand| operand.properties(existence_only))
+                    .collect()
+            }
+            Predicate::Not(operand) => operand.properties(existence_only),
+        }
+    }
+
+    fn collect_nodes(&self, nodes: &mut IndexSet<String>) {
+        match self {
+            Predicate::Attribute(attr_filter) => {
+                nodes.insert(attr_filter.node.clone());
+            }
+            Predicate::Exists(existence_filter) => {
+                nodes.insert(existence_filter.node.clone());
+            }
+            Predicate::And(operands) | Predicate::Or(operands) | Predicate::Xor(operands) => {
+                for operand in operands {
+                    operand.collect_nodes(nodes);
+                }
+            }
+            Predicate::Not(operand) => operand.collect_nodes(nodes),
+        }
+    }
+}
+
+/// A WHERE conjunct comparing expressions, e.g. b.response.total_size - a.request.total_size > 100.
+/// Unlike attribute filters, these may read several nodes, so they are checked once the
+/// trace has been mapped to the target graph.
+#[derive(Clone, Debug, Serialize, PartialEq, Eq)]
+pub struct ExpressionFilter {
+    pub left: PropertyOrUDF,
+    pub op: CompareOp,
+    pub right: PropertyOrUDF,
+}
+
 #[derive(Clone, Debug, Serialize)]
 pub struct IrReturn {
     pub entity: String,
     pub property: String,
 }
 
+/// Where the properties and UDF calls of a query are written, keyed by their
+/// to_ref_str, so that errors found after lowering can point at them.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Spans(IndexMap<String, Span>);
+
+impl Spans {
+    /// Records where something is written, unless it was written before.
+    pub fn insert(&mut self, ref_str: String, span: Span) {
+        self.0.entry(ref_str).or_insert(span);
+    }
+
+    /// Where something is first written in the query.
+    pub fn get(&self, ref_str: &str) -> Option<Span> {
+        self.0.get(ref_str).copied()
+    }
+}
+
+#[derive(Debug, Serialize, PartialEq)]
 pub struct VisitorResults {
     pub root_id: String,
     pub struct_filters: Vec<StructuralFilter>,
-    pub attr_filters: Vec<AttributeFilter>,
+    // the top level conjuncts of the WHERE clause
+    pub attr_filters: Vec<Predicate>,
+    // the UNION branch of each attribute filter, which is also the index of the
+    // structural filter it applies to
+    pub attr_filter_branches: Vec<usize>,
+    // the top level conjuncts of the WHERE clause that compare expressions
+    pub expression_filters: Vec<ExpressionFilter>,
     pub return_expr: IrReturnEnum,
     pub properties: IndexSet<Property>,
     pub udf_calls: IndexSet<UdfCall>,
+    // the names of the $parameters used in the query, without the $
+    pub parameters: IndexSet<String>,
+    #[serde(skip)]
+    pub spans: Spans,
+}
+
+impl VisitorResults {
+    /// The same results without where things are written, which is not part of
+    /// what a query means, so that queries written differently compare equal.
+    pub fn without_spans(self) -> Self {
+        VisitorResults {
+            spans: Spans::default(),
+            ..self
+        }
+    }
+
+    /// The attribute filters of one UNION branch.
+    pub fn branch_attr_filters(&self, branch: usize) -> Vec<Predicate> {
+        self.attr_filters
+            .iter()
+            .zip(&self.attr_filter_branches)
+            .filter(|(_, attr_filter_branch)| **attr_filter_branch == branch)
+            .map(|(predicate, _)| predicate.clone())
+            .collect()
+    }
+
+    /// The (caller, callee) edge a relationship variable names, if the variable is one.
+    pub fn relationship(&self, variable: &str) -> Option<&(String, String)> {
+        self.struct_filters
+            .iter()
+            .find_map(|struct_filter| struct_filter.relationships.get(variable))
+    }
+
+    /// The nodes that have to be in the matched pattern for a variable to have
+    /// a value.  A variable added by an OPTIONAL MATCH is null without them.
+    pub fn optional_nodes(&self, variable: &str) -> Vec<String> {
+        let mut nodes = Vec::new();
+        for struct_filter in &self.struct_filters {
+            let endpoints = match struct_filter.relationships.get(variable) {
+                Some(edge) => vec![edge.0.clone(), edge.1.clone()],
+                None => vec![variable.to_string()],
+            };
+            nodes.extend(
+                endpoints
+                    .into_iter()
+                    .filter(|node| struct_filter.optional_vertices.contains_key(node)),
+            );
+        }
+        nodes
+    }
+
+    /// The nodes that have to be in the matched pattern for an item to have a
+    /// value, through any of the variables it reads.
+    pub fn item_optional_nodes(&self, item: &PropertyOrUDF) -> Vec<String> {
+        let mut nodes = IndexSet::new();
+        for variable in item.nodes() {
+            nodes.extend(self.optional_nodes(&variable));
+        }
+        nodes.into_iter().collect()
+    }
+
+    /// Whether an item is null for traces without the nodes an OPTIONAL MATCH adds.
+    pub fn may_be_null(&self, item: &PropertyOrUDF) -> bool {
+        !self.item_optional_nodes(item).is_empty()
+    }
 }
 
 pub trait Expression {}
@@ -62,29 +468,70 @@ pub trait Expression {}
 #[derive(Clone, Debug, Eq, PartialEq, Hash, Serialize)]
 pub struct UdfCall {
     pub id: String,
-    //TODO: Args may also be UDF calls
-    pub args: Vec<String>,
+    pub args: Vec<UdfArg>,
 }
 
-/*
-//TODO: what do to with this?
 impl UdfCall {
+    /// The call as written in the query, e.g. "height(a)"
     pub fn to_ref_str(&self) -> String {
-        // TODO: Make this a little bit less stringbuildery
-        let mut udf_str = self.id.clone();
-        udf_str.push('(');
-        if let Some((last, front)) = self.args.split_last() {
-            for arg in front {
-                udf_str.push_str(&arg);
-                udf_str.push_str(", ");
+        let args: Vec<String> = self.args.iter().map(|arg| arg.to_ref_str()).collect();
+        format!("{}({})", self.id, args.join(", "))
+    }
+
+    /// The call without the nodes it is applied to, e.g. "f(request.total_size, height())"
+    /// for f(a.request.total_size, height(a)).  Calls of a UDF are computed once per
+    /// node, so calls with the same signature compute the same values.
+    pub fn signature(&self) -> String {
+        let args: Vec<String> = self
+            .args
+            .iter()
+            .map(|arg| match arg {
+                UdfArg::Property(prop) => prop.to_dot_string(),
+                UdfArg::UdfCall(call) => call.signature(),
+                UdfArg::Literal(literal) => literal.clone(),
+                UdfArg::Parameter(name) => format!("${}", name),
+            })
+            .collect();
+        format!("{}({})", self.id, args.join(", "))
+    }
+
+    /// The nodes (or "trace") this call reads, including through nested calls.
+    pub fn nodes(&self) -> IndexSet<String> {
+        let mut nodes = IndexSet::new();
+        for arg in &self.args {
+            match arg {
+                UdfArg::Property(prop) => {
+                    nodes.insert(prop.parent.clone());
+                }
+                UdfArg::UdfCall(call) => nodes.extend(call.nodes()),
+                UdfArg::Literal(_) | UdfArg::Parameter(_) => {}
             }
-            udf_str.push_str(last);
         }
-        udf_str.push(')');
-        udf_str
+        nodes
+    }
+}
+
+/// An argument of a UDF call.  A property without members is the node itself,
+/// as in height(a).  Literals are kept as written, including their quotes.
+/// Parameters are replaced by literals once they are bound.
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Serialize)]
+pub enum UdfArg {
+    Property(Property),
+    UdfCall(UdfCall),
+    Literal(String),
+    Parameter(String),
+}
+
+impl UdfArg {
+    pub fn to_ref_str(&self) -> String {
+        match self {
+            UdfArg::Property(prop) => prop.to_ref_str(),
+            UdfArg::UdfCall(call) => call.to_ref_str(),
+            UdfArg::Literal(literal) => literal.clone(),
+            UdfArg::Parameter(name) => format!("${}", name),
+        }
     }
 }
-*/
 
 impl Expression for UdfCall {}
 
@@ -118,6 +565,13 @@ impl Property {
         }
         udf_str
     }
+    /// The property as written in the query, e.g. "a.request.total_size"
+    pub fn to_ref_str(&self) -> String {
+        if self.members.is_empty() {
+            return self.parent.clone();
+        }
+        format!("{}.{}", self.parent, self.to_dot_string())
+    }
 }
 impl Expression for Property {}
 impl Default for Property {
@@ -129,16 +583,32 @@ impl Default for Property {
     }
 }
 
-#[derive(Clone, Debug, Hash, PartialEq)]
+#[derive(Clone, Debug, Hash, PartialEq, Serialize)]
 pub struct Aggregate {
     pub udf_reference: UdfCall,
+    // the arguments of the aggregation function, which are sent to storage
     pub args: Vec<PropertyOrUDF>,
+    // the name given with AS, which is the key the result is stored under
+    pub alias: Option<String>,
+    // (column name, item) pairs of the other return items, which key the aggregation
+    pub group_by: Vec<(String, PropertyOrUDF)>,
 }
 impl Aggregate {
     pub fn new_with_items(udf_reference: UdfCall, args: Vec<PropertyOrUDF>) -> Self {
         Aggregate {
             udf_reference,
             args,
+            alias: None,
+            group_by: Vec::new(),
+        }
+    }
+
+    /// The key the aggregation result is stored under, which is the UDF id
+    /// unless the aggregate was named with AS.
+    pub fn key(&self) -> String {
+        match &self.alias {
+            Some(alias) => alias.clone(),
+            None => self.udf_reference.id.clone(),
         }
     }
 }
@@ -147,6 +617,61 @@ impl Aggregate {
 pub enum PropertyOrUDF {
     Property(Property),
     UdfCall(UdfCall),
+    // kept as written, including quotes
+    Literal(String),
+    // a $parameter, without the $, until it is bound to a literal
+    Parameter(String),
+    Arithmetic(Arithmetic),
+}
+
+impl PropertyOrUDF {
+    pub fn to_ref_str(&self) -> String {
+        match self {
+            PropertyOrUDF::Property(prop) => prop.to_ref_str(),
+            PropertyOrUDF::UdfCall(call) => call.to_ref_str(),
+            PropertyOrUDF::Literal(literal) => literal.clone(),
+            PropertyOrUDF::Parameter(name) => format!("${}", name),
+            PropertyOrUDF::Arithmetic(arithmetic) => arithmetic.to_ref_str(),
+        }
+    }
+
+    /// The nodes (or "trace") this item reads.
+    pub fn nodes(&self) -> IndexSet<String> {
+        match self {
+            PropertyOrUDF::Property(prop) => vec![prop.parent.clone()].into_iter().collect(),
+            PropertyOrUDF::UdfCall(call) => call.nodes(),
+            PropertyOrUDF::Literal(_) | PropertyOrUDF::Parameter(_) => IndexSet::new(),
+            PropertyOrUDF::Arithmetic(arithmetic) => {
+                let mut nodes = arithmetic.left.nodes();
+                nodes.extend(arithmetic.right.nodes());
+                nodes
+            }
+        }
+    }
+}
+
+/// A binary arithmetic expression, e.g. b.response.total_size - a.request.total_size
+#[derive(Clone, Debug, Hash, PartialEq, Eq, Serialize)]
+pub struct Arithmetic {
+    pub op: ArithmeticOp,
+    pub left: Box<PropertyOrUDF>,
+    pub right: Box<PropertyOrUDF>,
+}
+
+impl Arithmetic {
+    /// The expression as written in the query, with nested expressions in parentheses.
+    pub fn to_ref_str(&self) -> String {
+        let operand_str = |operand: &PropertyOrUDF| match operand {
+            PropertyOrUDF::Arithmetic(arithmetic) => format!("({})", arithmetic.to_ref_str()),
+            _ => operand.to_ref_str(),
+        };
+        format!(
+            "{} {} {}",
+            operand_str(&self.left),
+            self.op.symbol(),
+            operand_str(&self.right)
+        )
+    }
 }
 
 impl Default for PropertyOrUDF {
@@ -155,10 +680,13 @@ impl Default for PropertyOrUDF {
     }
 }
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize)]
 pub enum IrReturnEnum {
-    Aggregate(Aggregate),
+    // the aggregations of a RETURN, which all share the same group_by
+    Aggregate(Vec<Aggregate>),
     PropertyOrUDF(PropertyOrUDF),
+    // (column name, item) pairs, which are sent to storage as one record
+    Record(Vec<(String, PropertyOrUDF)>),
 }
 
 impl Default for IrReturnEnum {
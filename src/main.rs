@@ -3,17 +3,31 @@ mod antlr_gen;
 mod codegen_common;
 mod codegen_envoy;
 mod codegen_simulator;
+mod diagnostics;
 mod ir;
+mod semantic;
+mod to_cypher;
 mod to_ir;
 
+use crate::codegen_common::envoy_property_types;
+use crate::codegen_common::simulator_property_types;
+use crate::codegen_common::udf_tables;
 use crate::codegen_common::CodeStruct;
+use crate::diagnostics::CompileError;
+use crate::diagnostics::Report;
+use crate::ir::VisitorResults;
 use antlr_gen::lexer::CypherLexer;
+use antlr_gen::lexer::WHITESPACE;
 use antlr_gen::parser::CypherParser;
 use antlr_rust::common_token_stream::CommonTokenStream;
+use antlr_rust::token::Token;
+use antlr_rust::token::TOKEN_EOF;
 use antlr_rust::token_factory::CommonTokenFactory;
 use antlr_rust::InputStream;
-use clap::{App, Arg};
+use antlr_rust::TokenSource;
+use clap::{App, AppSettings, Arg, SubCommand};
 use handlebars::Handlebars;
+use indexmap::IndexMap;
 use std::fs;
 use std::fs::File;
 use std::io::prelude::*;
@@ -29,28 +43,155 @@ use std::path::{Path, PathBuf};
  * @output_filename: where the output is written
  */
 // TODO: make this trait more concrete
-fn write_to_handlebars(code_gen: &CodeStruct, template_path: PathBuf, output_filename: PathBuf) {
+fn write_to_handlebars(
+    code_gen: &CodeStruct,
+    template_path: PathBuf,
+    output_filename: PathBuf,
+) -> Result<(), Report> {
     let display = template_path.display();
-    let mut template_file = match File::open(&template_path) {
-        Err(msg) => panic!("Failed to open {}: {}", display, msg),
-        Ok(file) => file,
-    };
+    let mut template_file = File::open(&template_path)
+        .map_err(|msg| Report::new(format!("Failed to open {}: {}", display, msg)))?;
 
     let mut template_str = String::new();
-    match template_file.read_to_string(&mut template_str) {
-        Err(msg) => panic!("Failed to read {}: {}", display, msg),
-        Ok(_) => log::info!("Successfully read {}", display),
-    }
+    template_file
+        .read_to_string(&mut template_str)
+        .map_err(|msg| Report::new(format!("Failed to read {}: {}", display, msg)))?;
+    log::info!("Successfully read {}", display);
 
     let handlebars = Handlebars::new();
 
     let output = handlebars
         .render_template(&template_str, &code_gen)
-        .expect("handlebar render failed");
+        .map_err(|msg| Report::new(format!("Failed to render {}: {}", display, msg)))?;
 
     log::info!("Writing output to: {:?}", output_filename);
-    let mut file = File::create(output_filename).expect("file create failed.");
-    file.write_all(output.as_bytes()).expect("write failed");
+    write_file(&output_filename, &output)
+}
+
+/* Writes the generated output to a file.
+ * Arguments:
+ * @output_filename: where the output is written
+ * @output: the text to write
+ */
+fn write_file(output_filename: &Path, output: &str) -> Result<(), Report> {
+    let mut file = File::create(output_filename).map_err(|msg| {
+        Report::new(format!(
+            "Failed to create {}: {}",
+            output_filename.display(),
+            msg
+        ))
+    })?;
+    file.write_all(output.as_bytes()).map_err(|msg| {
+        Report::new(format!(
+            "Failed to write {}: {}",
+            output_filename.display(),
+            msg
+        ))
+    })
+}
+
+/* Writes the IR of a query as JSON, together with the tables the code
+ * generator derives from it.
+ * Arguments:
+ * @ir: the serialized VisitorResults of the query
+ * @code_gen: the code_gen object generated from the same IR
+ * @output_filename: where the output is written
+ */
+fn write_ir(
+    ir: serde_json::Value,
+    code_gen: &CodeStruct,
+    output_filename: PathBuf,
+) -> Result<(), Report> {
+    let output = serde_json::json!({
+        "ir": ir,
+        "id_to_property": code_gen.id_to_property,
+        "scalar_udf_table": code_gen.scalar_udf_table,
+        "aggregation_udf_table": code_gen.aggregation_udf_table,
+    });
+    let output = serde_json::to_string_pretty(&output)
+        .map_err(|msg| Report::new(format!("Failed to serialize the IR: {}", msg)))?;
+
+    log::info!("Writing IR to: {:?}", output_filename);
+    write_file(&output_filename, &output)
+}
+
+/* Parses a query and builds its IR.  Errors are reported against the query text.
+ * Arguments:
+ * @query: the text of the query
+ * @query_file: the file the query was read from, for error messages
+ * @root_id: the root node of the query
+ */
+fn parse_query(
+    query: &str,
+    query_file: &str,
+    root_id: &str,
+) -> Result<VisitorResults, Box<dyn std::error::Error>> {
+    let tf = CommonTokenFactory::default();
+    let query_stream = InputStream::new_owned(query.to_string().into_boxed_str());
+    let lexer = CypherLexer::new_with_token_factory(query_stream, &tf);
+    let token_source = CommonTokenStream::new(lexer);
+    let mut parser = CypherParser::new(token_source);
+    let result = parser.oC_Cypher()?;
+    let visitor_results = to_ir::visit_result(result, root_id.to_string())
+        .map_err(|err| err.report(query, query_file))?;
+    Ok(visitor_results)
+}
+
+/* Whether a query has comments.  The lexer treats them as whitespace, so they
+ * are not in the IR.
+ * Arguments:
+ * @query: the text of the query
+ */
+fn has_comments(query: &str) -> bool {
+    let tf = CommonTokenFactory::default();
+    let query_stream = InputStream::new_owned(query.to_string().into_boxed_str());
+    let mut lexer = CypherLexer::new_with_token_factory(query_stream, &tf);
+    loop {
+        let token = lexer.next_token();
+        match token.get_token_type() {
+            TOKEN_EOF => return false,
+            // the only whitespace starting with a / is // or /*
+            WHITESPACE if token.get_text().starts_with('/') => return true,
+            _ => {}
+        }
+    }
+}
+
+/* Rewrites query files in place in the normalized form of to_cypher.  Files
+ * with comments are refused, since the normalized form would drop them, and so
+ * are files whose normalized query would not mean the same.
+ * Arguments:
+ * @query_files: the .cql files to format
+ */
+fn format_queries<'a>(
+    query_files: impl Iterator<Item = &'a str>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    for query_file in query_files {
+        let query = fs::read_to_string(query_file)?;
+        if has_comments(&query) {
+            return Err(Report::new(format!(
+                "{} has comments, which formatting would drop. Leaving it as is",
+                query_file
+            ))
+            .into());
+        }
+        let visitor_results = parse_query(&query, query_file, "")?;
+        let formatted = to_cypher::to_cypher(&visitor_results);
+        if parse_query(&formatted, query_file, "")?.without_spans()
+            != visitor_results.without_spans()
+        {
+            return Err(Report::new(format!(
+                "Formatting {} would change its meaning. Leaving it as is",
+                query_file
+            ))
+            .into());
+        }
+        if formatted != query.trim_end() {
+            log::info!("Formatted {}", query_file);
+            fs::write(query_file, formatted + "\n")?;
+        }
+    }
+    Ok(())
 }
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -70,6 +211,17 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let app = App::new("Dynamic Tracing");
     let matches = app
         .clone()
+        .setting(AppSettings::SubcommandsNegateReqs)
+        .subcommand(
+            SubCommand::with_name("fmt")
+                .about("Rewrites .cql query files in place in a normalized form")
+                .arg(
+                    Arg::with_name("files")
+                        .multiple(true)
+                        .required(true)
+                        .value_name("FILE"),
+                ),
+        )
         .arg(
             Arg::with_name("query")
                 .short("q")
@@ -120,9 +272,27 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                 .default_value(def_filter_dir.to_str().unwrap())
                 .help("Location and name of the output file."),
         )
+        .arg(
+            Arg::with_name("emit_ir")
+                .long("emit-ir")
+                .value_name("IR_FILE")
+                .help("Writes the IR of the query to this file as JSON, instead of generating a filter"),
+        )
+        .arg(
+            Arg::with_name("param")
+                .short("p")
+                .long("param")
+                .multiple(true)
+                .number_of_values(1)
+                .value_name("NAME=VALUE")
+                .help("Sets the value of a $parameter used in the query"),
+        )
         .get_matches();
 
-    let tf = CommonTokenFactory::default();
+    if let Some(fmt_matches) = matches.subcommand_matches("fmt") {
+        return format_queries(fmt_matches.values_of("files").unwrap());
+    }
+
     // Read query from file specified by command line argument.
     // Clap ensures that all of these are valid so we can safely unwrap
     let query_file = matches.value_of("query").unwrap();
@@ -133,30 +303,37 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let mut udfs = Vec::new();
     if let Some(udf_files) = matches.values_of("udf") {
         for udf_file in udf_files {
-            let udf = match std::fs::read_to_string(udf_file) {
-                Ok(udf_str) => udf_str,
-                Err(err) => {
-                    log::error!("Failed to parse UDF file as string: {:?}", err);
-                    std::process::exit(-1);
+            let udf = std::fs::read_to_string(udf_file).map_err(|err| {
+                Report::new(format!("Failed to read UDF file {}: {}", udf_file, err))
+            })?;
+            udfs.push(udf);
+        }
+    }
+    // Collect query parameters
+    let mut params = IndexMap::new();
+    if let Some(param_args) = matches.values_of("param") {
+        for param_arg in param_args {
+            let (name, value) = match param_arg.find('=') {
+                Some(idx) if idx > 0 => (&param_arg[..idx], &param_arg[idx + 1..]),
+                _ => {
+                    return Err(Report::new(format!(
+                        "Expected --param NAME=VALUE, got {:?}",
+                        param_arg
+                    ))
+                    .into())
                 }
             };
-            udfs.push(udf);
+            let name = name.trim_start_matches('$');
+            if params.insert(name.to_string(), value.to_string()).is_some() {
+                return Err(
+                    Report::new(format!("Parameter ${} is given more than once", name)).into(),
+                );
+            }
         }
     }
     // Start the parsing phase
-    let query = match fs::read_to_string(query_file) {
-        Ok(query_str) => query_str,
-        Err(err) => {
-            log::error!("Failed to parse query file as string: {:?}", err);
-            std::process::exit(-1);
-        }
-    };
-    let query_stream = InputStream::new_owned(query.into_boxed_str());
-    let lexer = CypherLexer::new_with_token_factory(query_stream, &tf);
-    let token_source = CommonTokenStream::new(lexer);
-    let mut parser = CypherParser::new(token_source);
-    let result = parser.oC_Cypher()?;
-
+    let query = fs::read_to_string(query_file)
+        .map_err(|err| Report::new(format!("Failed to read query file {}: {}", query_file, err)))?;
     // The aggregation filter is relative to the filter directory
     let filter_parent = match matches.is_present("distributed") {
         true => distributed_filter_dir.parent(),
@@ -167,45 +344,68 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         None => PathBuf::new(),
     };
 
-    let visitor_results = to_ir::visit_result(result, root_id.to_string());
+    let report = |err: CompileError| err.report(&query, query_file);
+    let mut visitor_results = parse_query(&query, query_file, root_id)?;
+    to_ir::bind_parameters(&mut visitor_results, &params).map_err(&report)?;
+    // catch mistakes in the query before generating code for it
+    let (scalar_udf_table, aggregation_udf_table) = udf_tables(&udfs).map_err(&report)?;
+    to_ir::resolve_aggregates(&mut visitor_results, &aggregation_udf_table).map_err(&report)?;
+    // each backend gives its filters different properties
+    let property_to_type = match comp_mode {
+        "sim" => simulator_property_types(),
+        _ => envoy_property_types(),
+    };
+    semantic::check(
+        &visitor_results,
+        &scalar_udf_table,
+        &aggregation_udf_table,
+        &property_to_type,
+    )
+    .map_err(&report)?;
+    // the code generators consume the IR, so serialize it first
+    let ir = match matches.value_of("emit_ir") {
+        Some(ir_out) => Some((
+            PathBuf::from(ir_out),
+            serde_json::to_value(&visitor_results)?,
+        )),
+        None => None,
+    };
     let filter_str: &str;
     let filter_agg_str: &str;
-    match comp_mode {
+    let codegen_object = match comp_mode {
         "sim" => {
-            let codegen_object = codegen_simulator::generate_code_blocks(visitor_results, udfs);
             filter_str = match matches.is_present("distributed") {
                 true => "simulation_filter_distributed.rs.handlebars",
                 false => "simulation_filter.rs.handlebars",
             };
             filter_agg_str = "simulation_filter_aggregation.rs.handlebars";
-            write_to_handlebars(&codegen_object, template_dir.join(filter_str), filter_out);
-            write_to_handlebars(
-                &codegen_object,
-                template_dir.join(filter_agg_str),
-                agg_filter_out,
-            );
+            codegen_simulator::generate_code_blocks(visitor_results, udfs).map_err(&report)?
         }
         "envoy" => {
-            let codegen_object = codegen_envoy::generate_code_blocks(visitor_results, udfs);
             filter_str = match matches.is_present("distributed") {
                 true => "distributed_envoy_filter.rs.handlebars",
                 false => "envoy_filter.rs.handlebars",
             };
             filter_agg_str = "envoy_filter_aggregation.rs.handlebars";
-            write_to_handlebars(&codegen_object, template_dir.join(filter_str), filter_out);
-            write_to_handlebars(
-                &codegen_object,
-                template_dir.join(filter_agg_str),
-                agg_filter_out,
-            );
+            codegen_envoy::generate_code_blocks(visitor_results, udfs).map_err(&report)?
         }
         _ => {
-            log::error!(
+            return Err(Report::new(format!(
                 "{:?} is not a valid compilation mode. Valid modes are: sim, envoy",
                 comp_mode
-            );
-            std::process::exit(-1);
+            ))
+            .into())
         }
+    };
+    if let Some((ir_out, ir)) = ir {
+        write_ir(ir, &codegen_object, ir_out)?;
+        return Ok(());
     }
+    write_to_handlebars(&codegen_object, template_dir.join(filter_str), filter_out)?;
+    write_to_handlebars(
+        &codegen_object,
+        template_dir.join(filter_agg_str),
+        agg_filter_out,
+    )?;
     Ok(())
 }
@@ -0,0 +1,431 @@
+use super::codegen_common::AggregationUdf;
+use super::codegen_common::ScalarUdf;
+use super::diagnostics::CompileError;
+use super::diagnostics::CompileResult;
+use super::ir::IrReturnEnum;
+use super::ir::PropertyOrUDF;
+use super::ir::Value;
+use super::ir::VisitorResults;
+use indexmap::IndexMap;
+use indexmap::IndexSet;
+use utils::graph::graph_utils::ValueType;
+
+/********************************/
+// Semantic Analysis
+/********************************/
+
+/// Whether a UDF taking values of the expected type can be given values of the
+/// other.  Every value can be passed as a string, and a number only as a number
+/// that holds it, so an unsigned integer can be passed as a signed one and an
+/// integer as a float, but not the other way around.
+fn accepts(expected: ValueType, other: ValueType) -> bool {
+    match (expected, other) {
+        (ValueType::Str, _) => true,
+        (ValueType::Int, ValueType::Uint) => true,
+        (ValueType::Float, ValueType::Int) | (ValueType::Float, ValueType::Uint) => true,
+        (expected, other) => expected == other,
+    }
+}
+
+/// Checks that the query only uses what the compiler can generate code for, so
+/// that mistakes in the query are reported before any code is generated:
+/// every variable has to be bound by MATCH, every UDF has to be implemented
+/// and applied to nodes,
+/// every property has to be an envoy property and aggregation UDFs have to
+/// accept the values they are given.
+pub fn check(
+    query_data: &VisitorResults,
+    scalar_udf_table: &IndexMap<String, ScalarUdf>,
+    aggregation_udf_table: &IndexMap<String, AggregationUdf>,
+    property_to_type: &IndexMap<&str, &str>,
+) -> CompileResult<()> {
+    check_variables(query_data)?;
+    check_udf_calls(query_data, scalar_udf_table, aggregation_udf_table)?;
+    check_properties(query_data, scalar_udf_table, property_to_type)?;
+    check_aggregate(
+        query_data,
+        scalar_udf_table,
+        aggregation_udf_table,
+        property_to_type,
+    )
+}
+
+/// An error about something written in the query, which points at where it is
+/// written if the parser recorded it.
+fn error_at(query_data: &VisitorResults, ref_str: &str, message: String) -> CompileError {
+    match query_data.spans.get(ref_str) {
+        Some(span) => CompileError::at(span, message),
+        None => CompileError::new(message),
+    }
+}
+
+/// The items computed for the RETURN clause.
+fn return_items(return_expr: &IrReturnEnum) -> Vec<&PropertyOrUDF> {
+    match return_expr {
+        IrReturnEnum::PropertyOrUDF(item) => vec![item],
+        IrReturnEnum::Aggregate(aggs) => aggs
+            .iter()
+            .flat_map(|agg| {
+                agg.args
+                    .iter()
+                    .chain(agg.group_by.iter().map(|(_, item)| item))
+            })
+            .collect(),
+        IrReturnEnum::Record(columns) => columns.iter().map(|(_, item)| item).collect(),
+    }
+}
+
+/// Variables are bound by the MATCH clause, except for "trace", which is the
+/// trace as a whole.
+fn check_variables(query_data: &VisitorResults) -> CompileResult<()> {
+    let mut bound: IndexSet<&str> = IndexSet::new();
+    bound.insert("trace");
+    for struct_filter in &query_data.struct_filters {
+        bound.extend(struct_filter.vertices.iter().map(|vertex| vertex.as_str()));
+        bound.extend(struct_filter.relationships.keys().map(|name| name.as_str()));
+    }
+    let mut uses = Vec::new();
+    for property in &query_data.properties {
+        uses.push((property.parent.clone(), property.to_ref_str()));
+    }
+    for call in &query_data.udf_calls {
+        for node in call.nodes() {
+            uses.push((node, call.to_ref_str()));
+        }
+    }
+    // returning a node itself, as in RETURN a, does not read any property
+    for item in return_items(&query_data.return_expr) {
+        for node in item.nodes() {
+            uses.push((node, item.to_ref_str()));
+        }
+    }
+    for (variable, used_in) in uses {
+        if !bound.contains(variable.as_str()) {
+            return Err(error_at(
+                query_data,
+                &used_in,
+                format!(
+                    "Variable {:?} in {:?} is not bound by MATCH",
+                    variable, used_in
+                ),
+            ));
+        }
+    }
+    Ok(())
+}
+
+fn check_udf_calls(
+    query_data: &VisitorResults,
+    scalar_udf_table: &IndexMap<String, ScalarUdf>,
+    aggregation_udf_table: &IndexMap<String, AggregationUdf>,
+) -> CompileResult<()> {
+    for call in &query_data.udf_calls {
+        if !scalar_udf_table.contains_key(&call.id) && !aggregation_udf_table.contains_key(&call.id)
+        {
+            return Err(error_at(
+                query_data,
+                &call.to_ref_str(),
+                format!(
+                    "UDF {:?} is not defined, pass its implementation with --udf",
+                    call.id
+                ),
+            ));
+        }
+        // scalar UDFs are computed over the trace graph below a node
+        if scalar_udf_table.contains_key(&call.id) {
+            if let Some(relationship) = call
+                .nodes()
+                .into_iter()
+                .find(|node| query_data.relationship(node).is_some())
+            {
+                return Err(error_at(
+                    query_data,
+                    &call.to_ref_str(),
+                    format!(
+                        "UDF {:?} is applied to relationship {:?} in {:?}, but UDFs can only be applied to nodes",
+                        call.id,
+                        relationship,
+                        call.to_ref_str()
+                    ),
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+fn check_properties(
+    query_data: &VisitorResults,
+    scalar_udf_table: &IndexMap<String, ScalarUdf>,
+    property_to_type: &IndexMap<&str, &str>,
+) -> CompileResult<()> {
+    for property in &query_data.properties {
+        let dot_str = property.to_dot_string();
+        // the results of scalar UDFs are stored as properties, too
+        if property.members.is_empty() || scalar_udf_table.contains_key(&dot_str) {
+            continue;
+        }
+        if !property_to_type.contains_key(dot_str.as_str()) {
+            return Err(error_at(
+                query_data,
+                &property.to_ref_str(),
+                format!(
+                    "Unknown property {:?} in {:?}, which the filters of this backend can not read",
+                    dot_str,
+                    property.to_ref_str()
+                ),
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// The kind of value an item evaluates to, if it is known.
+fn value_type(
+    item: &PropertyOrUDF,
+    scalar_udf_table: &IndexMap<String, ScalarUdf>,
+    property_to_type: &IndexMap<&str, &str>,
+) -> Option<ValueType> {
+    match item {
+        PropertyOrUDF::Property(prop) => match scalar_udf_table.get(&prop.to_dot_string()) {
+            Some(udf) => ValueType::from_type_name(udf.return_type.as_ref()?),
+            None => ValueType::from_type_name(property_to_type.get(prop.to_dot_string().as_str())?),
+        },
+        PropertyOrUDF::UdfCall(call) => {
+            ValueType::from_type_name(scalar_udf_table.get(&call.id)?.return_type.as_ref()?)
+        }
+        PropertyOrUDF::Literal(literal) => match Value::from_literal(literal)? {
+            Value::Int(_) => Some(ValueType::Int),
+            Value::Float(_) => Some(ValueType::Float),
+            Value::Bool(_) => Some(ValueType::Bool),
+            Value::Str(_) => Some(ValueType::Str),
+            Value::Duration(_) => Some(ValueType::Duration),
+            Value::Parameter(_) => None,
+        },
+        PropertyOrUDF::Parameter(_) => None,
+        // as in ArithmeticOp::apply, integers give an integer, which may be
+        // negative, and anything else a float
+        PropertyOrUDF::Arithmetic(arithmetic) => {
+            let left = value_type(&arithmetic.left, scalar_udf_table, property_to_type)?;
+            let right = value_type(&arithmetic.right, scalar_udf_table, property_to_type)?;
+            match (left, right) {
+                (ValueType::Int, ValueType::Int)
+                | (ValueType::Int, ValueType::Uint)
+                | (ValueType::Uint, ValueType::Int)
+                | (ValueType::Uint, ValueType::Uint) => Some(ValueType::Int),
+                (left, right) if left.is_numeric() && right.is_numeric() => Some(ValueType::Float),
+                _ => None,
+            }
+        }
+    }
+}
+
+/// Aggregation UDFs may declare the type of the values they aggregate with an
+/// arg_type header, e.g. `// arg_type: i64`.
+fn check_aggregate(
+    query_data: &VisitorResults,
+    scalar_udf_table: &IndexMap<String, ScalarUdf>,
+    aggregation_udf_table: &IndexMap<String, AggregationUdf>,
+    property_to_type: &IndexMap<&str, &str>,
+) -> CompileResult<()> {
+    let aggs = match &query_data.return_expr {
+        IrReturnEnum::Aggregate(aggs) => aggs,
+        _ => return Ok(()),
+    };
+    for agg in aggs {
+        let arg_type = match aggregation_udf_table
+            .get(&agg.udf_reference.id)
+            .and_then(|udf| udf.arg_type.as_ref())
+        {
+            Some(arg_type) => arg_type,
+            None => continue,
+        };
+        let expected = match ValueType::from_type_name(arg_type) {
+            Some(expected) => expected,
+            None => continue,
+        };
+        for arg in &agg.args {
+            if let Some(kind) = value_type(arg, scalar_udf_table, property_to_type) {
+                if !accepts(expected, kind) {
+                    return Err(error_at(
+                        query_data,
+                        &arg.to_ref_str(),
+                        format!(
+                            "Aggregation UDF {:?} takes {} values, but {:?} is a {:?}",
+                            agg.udf_reference.id,
+                            arg_type,
+                            arg.to_ref_str(),
+                            kind
+                        ),
+                    ));
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::antlr_gen::lexer::CypherLexer;
+    use crate::antlr_gen::parser::CypherParser;
+    use crate::codegen_common::envoy_property_types;
+    use crate::codegen_common::simulator_property_types;
+    use crate::codegen_common::udf_tables;
+    use crate::diagnostics::Span;
+    use crate::to_ir::resolve_aggregates;
+    use crate::to_ir::visit_result;
+    use antlr_rust::common_token_stream::CommonTokenStream;
+    use antlr_rust::token_factory::CommonTokenFactory;
+    use antlr_rust::InputStream;
+
+    static HEIGHT: &str = "
+    // udf_type: Scalar
+    // leaf_func: leaf_height
+    // mid_func: mid_height
+    // id: height
+
+    fn leaf_height(_graph: &Graph<(String, IndexMap<u64, Value>), IndexMap<u64, Value>>) -> u32 {
+        return 0;
+    }
+
+    fn mid_height(_graph: &Graph<(String, IndexMap<u64, Value>), IndexMap<u64, Value>>, children_responses: Vec<String>) -> u32 {
+        return 1;
+    }
+    ";
+
+    static AVG: &str = "
+    // udf_type: Aggregation
+    // init_func: new
+    // exec_func: execute
+    // struct_name: Avg
+    // id: avg
+    // arg_type: i64
+    ";
+
+    fn check_query(input: &str) -> CompileResult<()> {
+        check_query_with(input, &envoy_property_types())
+    }
+
+    fn check_query_with(input: &str, property_to_type: &IndexMap<&str, &str>) -> CompileResult<()> {
+        let tf = CommonTokenFactory::default();
+        let query_stream = InputStream::new_owned(input.to_string().into_boxed_str());
+        let lexer = CypherLexer::new_with_token_factory(query_stream, &tf);
+        let token_source = CommonTokenStream::new(lexer);
+        let mut parser = CypherParser::new(token_source);
+        let result = parser.oC_Cypher().expect("parsed unsuccessfully");
+        let mut query_data = visit_result(result, "productpage-v1".to_string())?;
+        let (scalar_udf_table, aggregation_udf_table) =
+            udf_tables(&[HEIGHT.to_string(), AVG.to_string()])?;
+        resolve_aggregates(&mut query_data, &aggregation_udf_table)?;
+        check(
+            &query_data,
+            &scalar_udf_table,
+            &aggregation_udf_table,
+            property_to_type,
+        )
+    }
+
+    #[test]
+    fn test_valid_queries() {
+        check_query("MATCH (a) -[]-> (b) WHERE b.response.code = 200 RETURN height(a)").unwrap();
+        check_query(
+            "MATCH (a) -[]-> (b) WHERE trace.request.total_size = 1 RETURN a.request.path, avg(a.request.total_size)",
+        )
+        .unwrap();
+        check_query("MATCH (a) -[]-> (b) RETURN a.request.path, avg(height(a))").unwrap();
+        check_query("MATCH (a) -[r]-> (b) WHERE r.response.code = 200 RETURN r.request.duration")
+            .unwrap();
+    }
+
+    #[test]
+    fn test_unbound_variables() {
+        let err =
+            check_query("MATCH (a) -[]-> (b) WHERE c.response.code = 200 RETURN a.request.path")
+                .unwrap_err();
+        assert_eq!(
+            err.message,
+            "Variable \"c\" in \"c.response.code\" is not bound by MATCH"
+        );
+        let err = check_query("MATCH (a) -[]-> (b) RETURN height(c)").unwrap_err();
+        assert_eq!(
+            err.message,
+            "Variable \"c\" in \"height(c)\" is not bound by MATCH"
+        );
+        // errors point at the part of the query they are about
+        assert_eq!(
+            err.span,
+            Some(Span {
+                line: 1,
+                column: 27,
+                len: 9
+            })
+        );
+    }
+
+    #[test]
+    fn test_undefined_udfs() {
+        let err = check_query("MATCH (a) -[]-> (b) RETURN depth(a)").unwrap_err();
+        assert_eq!(
+            err.message,
+            "UDF \"depth\" is not defined, pass its implementation with --udf"
+        );
+    }
+
+    #[test]
+    fn test_udfs_on_relationships() {
+        let err = check_query("MATCH (a) -[r]-> (b) RETURN height(r)").unwrap_err();
+        assert_eq!(
+            err.message,
+            "UDF \"height\" is applied to relationship \"r\" in \"height(r)\", but UDFs can only be applied to nodes"
+        );
+    }
+
+    #[test]
+    fn test_unknown_properties() {
+        let err = check_query("MATCH (a) -[]-> (b) RETURN a.request.sise").unwrap_err();
+        assert_eq!(
+            err.message,
+            "Unknown property \"request.sise\" in \"a.request.sise\", which the filters of this backend can not read"
+        );
+        // the simulator provides fewer properties than envoy
+        assert!(check_query_with(
+            "MATCH (a) -[]-> (b) RETURN a.request.path",
+            &simulator_property_types()
+        )
+        .is_err());
+        check_query_with(
+            "MATCH (a) -[]-> (b) RETURN a.request.total_size",
+            &simulator_property_types(),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_aggregate_arg_types() {
+        let err =
+            check_query("MATCH (a) -[]-> (b) RETURN a.request.total_size, avg(a.request.path)")
+                .unwrap_err();
+        assert_eq!(
+            err.message,
+            "Aggregation UDF \"avg\" takes i64 values, but \"a.request.path\" is a Str"
+        );
+        // integers are given to UDFs taking integers or floats, but floats are
+        // not given to UDFs taking integers
+        check_query("MATCH (a) -[]-> (b) RETURN avg(a.request.total_size - b.request.total_size)")
+            .unwrap();
+        check_query("MATCH (a) -[]-> (b) RETURN avg(height(a) * 2)").unwrap();
+        let err =
+            check_query("MATCH (a) -[]-> (b) RETURN avg(a.request.total_size * 0.5)").unwrap_err();
+        assert_eq!(
+            err.message,
+            "Aggregation UDF \"avg\" takes i64 values, but \"a.request.total_size * 0.5\" is a Float"
+        );
+        let err = check_query("MATCH (a) -[]-> (b) RETURN avg(a.request.duration)").unwrap_err();
+        assert_eq!(
+            err.message,
+            "Aggregation UDF \"avg\" takes i64 values, but \"a.request.duration\" is a Duration"
+        );
+    }
+}
@@ -0,0 +1,576 @@
+/***********************************/
+// Printer:  turns the IR back into a normalized Cypher query
+/***********************************/
+use super::ir::*;
+use indexmap::{IndexMap, IndexSet};
+
+/// A node of a pattern, and the call printed after it, e.g. (a)<-[r]-(b).  A node
+/// that starts a part of the pattern on its own, as in MATCH (a), has no call.
+struct Step {
+    left: String,
+    // the relationship and the node on the right
+    call: Option<(String, String)>,
+}
+
+/// Backticks a label unless it is a workload name the grammar accepts as is,
+/// e.g. reviews-v1.
+fn label_to_cypher(label: &str) -> String {
+    let is_name = |name: &str| {
+        name.chars()
+            .next()
+            .map_or(false, |c| c.is_ascii_alphabetic())
+            && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+    };
+    let mut names = label.split('-');
+    let is_label_name = names.next().map_or(false, is_name)
+        && names.all(|name| is_name(name) || name.parse::<u64>().is_ok());
+    match is_label_name {
+        true => label.to_string(),
+        false => format!("`{}`", label),
+    }
+}
+
+/// Quotes a string the way it is written in a query, escaping the quote,
+/// backslashes and control characters.
+fn quote(string: &str, quote: char) -> String {
+    let mut quoted = quote.to_string();
+    for c in string.chars() {
+        match c {
+            '\\' => quoted.push_str("\\\\"),
+            '\u{8}' => quoted.push_str("\\b"),
+            '\u{c}' => quoted.push_str("\\f"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            c if c == quote => {
+                quoted.push('\\');
+                quoted.push(c);
+            }
+            c => quoted.push(c),
+        }
+    }
+    quoted.push(quote);
+    quoted
+}
+
+/// Prints a value the way it is written in a query.  Strings are single quoted,
+/// unless they contain a single quote and no double quote.
+fn value_to_cypher(value: &Value) -> String {
+    match value {
+        Value::Str(value) if value.contains('\'') && !value.contains('"') => quote(value, '"'),
+        Value::Str(value) => quote(value, '\''),
+        _ => value.to_string(),
+    }
+}
+
+fn hops_to_cypher(hops: &Hops) -> String {
+    match (hops.min, hops.max) {
+        (1, None) => "*".to_string(),
+        (min, Some(max)) if min == max => format!("*{}", min),
+        (min, None) => format!("*{}..", min),
+        (min, Some(max)) => format!("*{}..{}", min, max),
+    }
+}
+
+/// How tightly a predicate binds.  Operands of AND, OR and XOR that do not bind
+/// more tightly than their parent are parenthesized.
+fn precedence(predicate: &Predicate) -> u8 {
+    match predicate {
+        Predicate::Or(_) => 0,
+        Predicate::Xor(_) => 1,
+        Predicate::And(_) => AND_PRECEDENCE,
+        Predicate::Not(_) | Predicate::Attribute(_) | Predicate::Exists(_) => 3,
+    }
+}
+
+const AND_PRECEDENCE: u8 = 2;
+
+struct Printer<'a> {
+    visitor_results: &'a VisitorResults,
+}
+
+impl<'a> Printer<'a> {
+    /// The property or UDF result a filter reads, e.g. a.response.code or height(a).
+    /// Filters on a UDF store its id as the property.
+    fn filter_operand(&self, node: &str, property: &str) -> String {
+        if property.starts_with('.') {
+            return format!("{}{}", node, property);
+        }
+        match self
+            .visitor_results
+            .udf_calls
+            .iter()
+            .find(|call| call.id == property && call.nodes().into_iter().eq(vec![node.to_string()]))
+        {
+            Some(call) => call.to_ref_str(),
+            None => format!("{}({})", property, node),
+        }
+    }
+
+    fn predicate_to_cypher(&self, predicate: &Predicate) -> String {
+        let join = |operands: &Vec<Predicate>, op: &str| {
+            operands
+                .iter()
+                .map(|operand| self.operand_to_cypher(operand, precedence(predicate)))
+                .collect::<Vec<String>>()
+                .join(op)
+        };
+        match predicate {
+            Predicate::Attribute(attr_filter) => format!(
+                "{} {} {}",
+                self.filter_operand(&attr_filter.node, &attr_filter.property),
+                attr_filter.op.symbol(),
+                value_to_cypher(&attr_filter.value)
+            ),
+            Predicate::Exists(exists) => format!(
+                "{} IS NOT NULL",
+                self.filter_operand(&exists.node, &exists.property)
+            ),
+            Predicate::Not(operand) => match &**operand {
+                Predicate::Exists(exists) => format!(
+                    "{} IS NULL",
+                    self.filter_operand(&exists.node, &exists.property)
+                ),
+                _ => format!(
+                    "NOT {}",
+                    self.operand_to_cypher(operand, precedence(predicate))
+                ),
+            },
+            Predicate::And(operands) => join(operands, " AND "),
+            Predicate::Or(operands) => join(operands, " OR "),
+            Predicate::Xor(operands) => join(operands, " XOR "),
+        }
+    }
+
+    fn operand_to_cypher(&self, operand: &Predicate, parent_precedence: u8) -> String {
+        let operand_str = self.predicate_to_cypher(operand);
+        match operand {
+            Predicate::And(_) | Predicate::Or(_) | Predicate::Xor(_)
+                if precedence(operand) <= parent_precedence =>
+            {
+                format!("({})", operand_str)
+            }
+            _ => operand_str,
+        }
+    }
+
+    /// A WHERE clause with the conjuncts in the order they are stored.
+    fn where_to_cypher(
+        &self,
+        predicates: &[Predicate],
+        expressions: &[ExpressionFilter],
+    ) -> String {
+        let mut conjuncts: Vec<String> = match (predicates, expressions) {
+            ([predicate], []) => vec![self.predicate_to_cypher(predicate)],
+            _ => predicates
+                .iter()
+                .map(|predicate| self.operand_to_cypher(predicate, AND_PRECEDENCE))
+                .collect(),
+        };
+        for expression in expressions {
+            conjuncts.push(format!(
+                "{} {} {}",
+                expression.left.to_ref_str(),
+                expression.op.symbol(),
+                expression.right.to_ref_str()
+            ));
+        }
+        format!("WHERE {}", conjuncts.join(" AND "))
+    }
+
+    /// Filters on the workload of a node that were written as a label, as in
+    /// (a:productpage-v1).  Unlike a WHERE clause, a label does not read the
+    /// property, so they are the ones whose property the query never reads.
+    fn label(&self, predicate: &Predicate) -> Option<(String, String)> {
+        match predicate {
+            Predicate::Attribute(AttributeFilter {
+                node,
+                property,
+                op: CompareOp::Eq,
+                value: Value::Str(workload_name),
+            }) if property == ".node.metadata.WORKLOAD_NAME" => {
+                let read = Property {
+                    parent: node.clone(),
+                    members: vec![
+                        "node".to_string(),
+                        "metadata".to_string(),
+                        "WORKLOAD_NAME".to_string(),
+                    ],
+                };
+                if self.visitor_results.properties.contains(&read) {
+                    return None;
+                }
+                Some((node.clone(), workload_name.clone()))
+            }
+            _ => None,
+        }
+    }
+
+    fn relationship_to_cypher(
+        &self,
+        struct_filter: &StructuralFilter,
+        edge: &(String, String),
+        directed: bool,
+        left: &str,
+    ) -> String {
+        let variable = struct_filter
+            .relationships
+            .iter()
+            .find(|(_, relationship_edge)| *relationship_edge == edge)
+            .map(|(name, _)| name.clone())
+            .unwrap_or_default();
+        let hops = struct_filter
+            .edge_hops
+            .get(edge)
+            .map(hops_to_cypher)
+            .unwrap_or_default();
+        match (directed, edge.0 == left) {
+            (false, _) => format!("-[{}{}]-", variable, hops),
+            (true, true) => format!("-[{}{}]->", variable, hops),
+            (true, false) => format!("<-[{}{}]-", variable, hops),
+        }
+    }
+
+    /// Prints the pattern of one MATCH or OPTIONAL MATCH, which adds `vertices` and
+    /// `edges` to the `bound` nodes of the clauses before it.  A node is printed only
+    /// after the nodes added before it, so parsing the pattern adds them in the same
+    /// order.  Calls that continue from the last node are chained, as in
+    /// (a)-[]->(b)-[]->(c).
+    fn pattern_to_cypher(
+        &self,
+        struct_filter: &StructuralFilter,
+        vertices: &[String],
+        edges: &[((String, String), bool)],
+        bound: &IndexSet<String>,
+        labels: &IndexMap<String, Vec<String>>,
+    ) -> String {
+        // directed calls are printed from the node printed first, undirected ones
+        // in the order they were written in
+        let step = |(edge, directed): &((String, String), bool), from: &str| {
+            let (left, right) = match *directed && edge.0 != from {
+                true => (edge.1.clone(), edge.0.clone()),
+                false => (edge.0.clone(), edge.1.clone()),
+            };
+            let relationship = self.relationship_to_cypher(struct_filter, edge, *directed, &left);
+            Step {
+                left,
+                call: Some((relationship, right)),
+            }
+        };
+        let mut seen = bound.clone();
+        let mut remaining = edges.to_vec();
+        let mut steps = Vec::new();
+        for (i, vertex) in vertices.iter().enumerate() {
+            let to_seen: Vec<_> = remaining
+                .iter()
+                .filter(|((caller, callee), _)| {
+                    (caller == vertex && seen.contains(callee))
+                        || (callee == vertex && seen.contains(caller))
+                })
+                .cloned()
+                .collect();
+            if to_seen.is_empty() && !seen.contains(vertex) {
+                // a new part of the pattern starts at this node, and may continue
+                // to the node added after it
+                let next = vertices.get(i + 1).filter(|next| !seen.contains(*next));
+                let position = remaining.iter().position(|((caller, callee), _)| {
+                    (caller == vertex && Some(callee) == next)
+                        || (callee == vertex && Some(caller) == next)
+                });
+                match position {
+                    Some(position) => {
+                        steps.push(step(&remaining.remove(position), vertex));
+                        seen.insert(next.unwrap().clone());
+                    }
+                    None => steps.push(Step {
+                        left: vertex.clone(),
+                        call: None,
+                    }),
+                }
+            }
+            for edge in to_seen {
+                remaining.retain(|remaining_edge| *remaining_edge != edge);
+                // once the node is printed, the call may continue from it
+                let ends_at_vertex = match steps.last() {
+                    Some(Step {
+                        call: Some((_, right)),
+                        ..
+                    }) => right == vertex,
+                    _ => false,
+                };
+                let from = if seen.contains(vertex) && ends_at_vertex {
+                    vertex.clone()
+                } else if (edge.0).0 == *vertex {
+                    (edge.0).1.clone()
+                } else {
+                    (edge.0).0.clone()
+                };
+                steps.push(step(&edge, &from));
+                seen.insert(vertex.clone());
+            }
+            seen.insert(vertex.clone());
+        }
+        // calls between nodes of earlier clauses, which OPTIONAL MATCH does not allow
+        for edge in &remaining {
+            steps.push(step(edge, &(edge.0).0));
+        }
+
+        let mut labeled = IndexSet::new();
+        let mut node_to_cypher = |node: &str| {
+            let mut node_str = format!("({}", node);
+            if !bound.contains(node) && labeled.insert(node.to_string()) {
+                for label in labels.get(node).into_iter().flatten() {
+                    node_str.push(':');
+                    node_str.push_str(&label_to_cypher(label));
+                }
+            }
+            node_str.push(')');
+            node_str
+        };
+        let mut parts: Vec<String> = Vec::new();
+        let mut last: Option<&str> = None;
+        for step in &steps {
+            if last != Some(step.left.as_str()) {
+                parts.push(node_to_cypher(&step.left));
+            }
+            last = None;
+            if let Some((relationship, right)) = &step.call {
+                let part = parts.last_mut().unwrap();
+                part.push_str(relationship);
+                part.push_str(&node_to_cypher(right));
+                last = Some(right);
+            }
+        }
+        parts.join(", ")
+    }
+
+    fn return_to_cypher(&self) -> String {
+        let column_to_cypher = |(name, item): &(String, PropertyOrUDF)| {
+            let item_str = item.to_ref_str();
+            match *name == item_str {
+                true => item_str,
+                false => format!("{} AS {}", item_str, name),
+            }
+        };
+        let items: Vec<String> = match &self.visitor_results.return_expr {
+            IrReturnEnum::PropertyOrUDF(item) => vec![item.to_ref_str()],
+            IrReturnEnum::Record(columns) => columns.iter().map(column_to_cypher).collect(),
+            IrReturnEnum::Aggregate(aggregates) => {
+                // the aggregations share the group, which is listed first
+                let mut items: Vec<String> = aggregates[0]
+                    .group_by
+                    .iter()
+                    .map(column_to_cypher)
+                    .collect();
+                for aggregate in aggregates {
+                    let call = aggregate.udf_reference.to_ref_str();
+                    items.push(match &aggregate.alias {
+                        Some(alias) => format!("{} AS {}", call, alias),
+                        None => call,
+                    });
+                }
+                items
+            }
+        };
+        format!("RETURN {}", items.join(", "))
+    }
+
+    /// One branch of a UNION: its MATCH, the OPTIONAL MATCH clauses that extend
+    /// it, and the RETURN.  Conditions are printed with the clause whose nodes
+    /// they refer to.
+    fn branch_to_cypher(&self, branch: usize) -> String {
+        let struct_filter = &self.visitor_results.struct_filters[branch];
+        let optional_parts: Vec<usize> = struct_filter.optional_parts().into_iter().collect();
+
+        // the variables of each OPTIONAL MATCH
+        let part_variables = |part: usize| -> IndexSet<String> {
+            let vertices = struct_filter
+                .optional_vertices
+                .iter()
+                .filter(|(_, vertex_part)| **vertex_part == part)
+                .map(|(vertex, _)| vertex.clone());
+            let relationships = struct_filter
+                .relationships
+                .iter()
+                .filter(|(_, edge)| struct_filter.optional_edges.get(*edge) == Some(&part))
+                .map(|(name, _)| name.clone());
+            vertices.chain(relationships).collect()
+        };
+        let mut labels: IndexMap<String, Vec<String>> = IndexMap::new();
+        let mut conditions: IndexMap<Option<usize>, Vec<Predicate>> = IndexMap::new();
+        for predicate in self.visitor_results.branch_attr_filters(branch) {
+            if let Some((node, label)) = self.label(&predicate) {
+                labels.entry(node).or_default().push(label);
+                continue;
+            }
+            let nodes = predicate.nodes();
+            let part = optional_parts
+                .iter()
+                .find(|part| nodes.is_subset(&part_variables(**part)))
+                .cloned();
+            conditions.entry(part).or_default().push(predicate);
+        }
+
+        let clause_edges = |part: Option<usize>| -> Vec<((String, String), bool)> {
+            let directed = struct_filter.edges.iter().map(|edge| (edge.clone(), true));
+            let undirected = struct_filter
+                .undirected_edges
+                .iter()
+                .map(|edge| (edge.clone(), false));
+            directed
+                .chain(undirected)
+                .filter(|(edge, _)| struct_filter.optional_edges.get(edge).cloned() == part)
+                .collect()
+        };
+        let clause_vertices = |part: Option<usize>| -> Vec<String> {
+            struct_filter
+                .vertices
+                .iter()
+                .filter(|vertex| struct_filter.optional_vertices.get(*vertex).cloned() == part)
+                .cloned()
+                .collect()
+        };
+
+        let mut clauses = Vec::new();
+        let mut bound = IndexSet::new();
+        let vertices = clause_vertices(None);
+        clauses.push(format!(
+            "MATCH {}",
+            self.pattern_to_cypher(
+                struct_filter,
+                &vertices,
+                &clause_edges(None),
+                &bound,
+                &labels
+            )
+        ));
+        // comparisons between expressions are not supported in a UNION
+        let expressions: &[ExpressionFilter] = match branch {
+            0 => &self.visitor_results.expression_filters,
+            _ => &[],
+        };
+        let predicates = conditions.get(&None).cloned().unwrap_or_default();
+        if !predicates.is_empty() || !expressions.is_empty() {
+            clauses.push(self.where_to_cypher(&predicates, expressions));
+        }
+        bound.extend(vertices);
+        for part in optional_parts {
+            let vertices = clause_vertices(Some(part));
+            clauses.push(format!(
+                "OPTIONAL MATCH {}",
+                self.pattern_to_cypher(
+                    struct_filter,
+                    &vertices,
+                    &clause_edges(Some(part)),
+                    &bound,
+                    &labels
+                )
+            ));
+            if let Some(predicates) = conditions.get(&Some(part)) {
+                clauses.push(self.where_to_cypher(predicates, &[]));
+            }
+            bound.extend(vertices);
+        }
+        clauses.push(self.return_to_cypher());
+        clauses.join(" ")
+    }
+}
+
+/// Prints the query the IR was built from in a normalized form: keywords are upper
+/// case, every call is written as -[]->, and labels and inline properties other than
+/// workload names become WHERE conditions.  Parsing the printed query yields the same
+/// IR, so the IR has to be printed before its parameters are bound.
+pub fn to_cypher(visitor_results: &VisitorResults) -> String {
+    let printer = Printer { visitor_results };
+    (0..visitor_results.struct_filters.len())
+        .map(|branch| printer.branch_to_cypher(branch))
+        .collect::<Vec<String>>()
+        .join(" UNION ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::antlr_gen::lexer::CypherLexer;
+    use crate::antlr_gen::parser::CypherParser;
+    use crate::to_ir::visit_result;
+    use antlr_rust::common_token_stream::CommonTokenStream;
+    use antlr_rust::token_factory::CommonTokenFactory;
+    use antlr_rust::InputStream;
+
+    fn to_ir(input: &str) -> VisitorResults {
+        let tf = CommonTokenFactory::default();
+        let query_stream = InputStream::new_owned(input.to_string().into_boxed_str());
+        let lexer = CypherLexer::new_with_token_factory(query_stream, &tf);
+        let token_source = CommonTokenStream::new(lexer);
+        let mut parser = CypherParser::new(token_source);
+        let result = parser.oC_Cypher().expect("parsed unsuccessfully");
+        // where things are written differs between a query and its printed form
+        visit_result(result, "a".to_string())
+            .unwrap()
+            .without_spans()
+    }
+
+    #[test]
+    fn test_normalized_query() {
+        let query_data = to_ir(
+            "match (a) -[]-> (b {})-->(c) where b.node.metadata.WORKLOAD_NAME = \"reviews-v1\" and trace.request.total_size = 1 return a.request.total_size, avg(a.request.total_size)",
+        );
+        assert_eq!(
+            to_cypher(&query_data),
+            "MATCH (a)-[]->(b)-[]->(c) WHERE b.node.metadata.WORKLOAD_NAME = 'reviews-v1' AND trace.request.total_size = 1 RETURN a.request.total_size, avg(a.request.total_size)"
+        );
+
+        let query_data = to_ir(
+            "MATCH (b:`reviews-v1`)<-[*1..3]-(a {service_name: 'productpage-v1'}) WHERE NOT (a.response.code = 200 OR a.response.code = 201) RETURN b.request.path AS path",
+        );
+        assert_eq!(
+            to_cypher(&query_data),
+            "MATCH (b:reviews-v1)<-[*1..3]-(a) WHERE a.service_name = 'productpage-v1' AND NOT (a.response.code = 200 OR a.response.code = 201) RETURN b.request.path AS path"
+        );
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let queries = vec![
+            "MATCH (a) -[]-> (b {} )-[]->(c) RETURN height(a)",
+            "MATCH (a {service_name: \"productpage-v1\"}) RETURN a.request_size, hist(a.request_size)",
+            "MATCH (a:productpage-v1)-->(b:`reviews-v1`) RETURN a.request.total_size",
+            "MATCH (a)<--(b)-->(c)--(d) RETURN a.request.total_size",
+            "MATCH (b) <-[*1..3]- (a) <-[*]- (c) <-[*2]- (d) RETURN a.request.total_size",
+            "MATCH (a)-[r]->(b) WHERE r.response.code = 503 RETURN r.request.duration",
+            "MATCH (a)-->(b), (c)-->(d) MATCH (d)-->(e) WHERE e.response.code = 500 RETURN a.request.path",
+            "MATCH (a)-->(b) OPTIONAL MATCH (b)-[r]->(c:`ratings-v1`) WHERE r.response.code = 500 OR r.response.code IS NULL RETURN a.request.path, r.response.code",
+            "MATCH (a) -[]-> (b) WHERE (b.node.metadata.WORKLOAD_NAME = 'reviews-v2' OR b.node.metadata.WORKLOAD_NAME = 'reviews-v3') AND NOT a.response.code = 200 RETURN a.workload_name",
+            "MATCH (a) -[]-> (b) WHERE a.x = 1 XOR (a.y = 2 AND NOT NOT a.z IS NOT NULL) OR a.request.path =~ '.*api.*' RETURN a.workload_name",
+            "MATCH (a) -[]-> (b) WHERE a.response.total_size > $threshold AND b.response.code IN [$code, 404] RETURN a.response.total_size * $scale",
+            "MATCH (a) -[]-> (b) WHERE b.response.total_size - a.request.total_size > 100 AND height(a) = 2 RETURN a.request.path, count(a.request.path) AS requests",
+"MATCH (a) WHERE a.response.code > 0.5 AND a.request.duration < duration('1500us') RETURN a.request.path",
+                        r#"MATCH (a) WHERE a.request.path = 'O\'Brien' AND a.request.host = "a\"b'c\\d\te" RETURN a.request.path"#,
+            "MATCH (a)-->(b) WHERE b.node.metadata.WORKLOAD_NAME = 'reviews-v1' RETURN a.request.path UNION MATCH (a)-->(b)-->(c) WHERE c.node.metadata.WORKLOAD_NAME = 'reviews-v3' RETURN a.request.path",
+        ];
+        for query in queries {
+            let query_data = to_ir(query);
+            let printed = to_cypher(&query_data);
+            assert_eq!(
+                to_ir(&printed),
+                query_data,
+                "{} was printed as {}",
+                query,
+                printed
+            );
+            // the nodes are added in the same order
+            for (struct_filter, printed_filter) in query_data
+                .struct_filters
+                .iter()
+                .zip(&to_ir(&printed).struct_filters)
+            {
+                assert!(struct_filter
+                    .vertices
+                    .iter()
+                    .eq(printed_filter.vertices.iter()));
+            }
+            assert_eq!(to_cypher(&to_ir(&printed)), printed);
+        }
+    }
+}
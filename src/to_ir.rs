@@ -1,23 +1,60 @@
 use super::antlr_gen::parser::*;
 use super::antlr_gen::visitor::CypherVisitor;
+use super::codegen_common::AggregationUdf;
+use super::diagnostics::CompileError;
+use super::diagnostics::CompileResult;
+use super::diagnostics::Span;
 use super::ir::IrReturnEnum;
 use super::ir::PropertyOrUDF;
 use super::ir::*;
+use antlr_rust::parser_rule_context::ParserRuleContext;
+use antlr_rust::token::Token;
 use antlr_rust::tree::ParseTree;
 use antlr_rust::tree::ParseTreeVisitor;
 use antlr_rust::tree::TerminalNode;
 use antlr_rust::tree::Tree;
 use antlr_rust::tree::Visitable;
+use indexmap::IndexMap;
 use indexmap::IndexSet;
-use std::process;
 use std::rc::Rc;
 
+/// The part of the query a rule matched, which errors point at.
+fn span_of<'i, T: ParserRuleContext<'i> + ?Sized>(ctx: &T) -> Span {
+    let start = ctx.start();
+    Span {
+        line: start.get_line() as usize,
+        column: start.get_column() as usize,
+        len: ctx.get_text().chars().count(),
+    }
+}
+
+fn error_at<'i, T: ParserRuleContext<'i> + ?Sized>(ctx: &T, message: String) -> CompileError {
+    CompileError::at(span_of(ctx), message)
+}
+
+/// The error for an atom the compiler does not support in this position.
+fn unsupported_atom(atom: &OC_AtomContext<'_>, what: &str) -> CompileError {
+    error_at(
+        atom,
+        format!(
+            "Unsupported {} {:?}. Has type {:?}",
+            what,
+            atom.get_text(),
+            ruleNames[atom.get_child(0).unwrap().get_rule_index()]
+        ),
+    )
+}
+
 /***********************************/
 // FilterVisitor:  visits tree and fills out structural and property filters
 /***********************************/
 pub struct PropertyAndUdfVisitor {
     properties: IndexSet<Property>,
     udf_calls: IndexSet<UdfCall>,
+    parameters: IndexSet<String>,
+    spans: Spans,
+    // the first error found
+    error: Option<CompileError>,
 }
 
 impl Default for PropertyAndUdfVisitor {
@@ -25,6 +62,9 @@ impl Default for PropertyAndUdfVisitor {
         PropertyAndUdfVisitor {
             properties: IndexSet::new(),
             udf_calls: IndexSet::new(),
+            parameters: IndexSet::new(),
+            spans: Spans::default(),
+            error: None,
         }
     }
 }
@@ -34,30 +74,48 @@ impl<'i> ParseTreeVisitor<'i, CypherParserContextType> for PropertyAndUdfVisitor
 }
 
 impl<'i> CypherVisitor<'i> for PropertyAndUdfVisitor {
-    fn visit_oC_FunctionInvocation(&mut self, func: &OC_FunctionInvocationContext<'i>) {
-        let udf_name: String;
-        if let Some(udf_name_) = func.oC_FunctionName() {
-            udf_name = udf_name_.get_text();
-        } else {
-            panic!("Compiler Bug: Missing UDF name.")
+    fn visit_oC_NodePattern(&mut self, ctx: &OC_NodePatternContext<'i>) {
+        if let Some(variable) = ctx.oC_Variable() {
+            let inline_properties = match inline_properties(ctx) {
+                Ok(inline_properties) => inline_properties,
+                Err(err) => {
+                    self.error.get_or_insert(err);
+                    return;
+                }
+            };
+            for (property, _) in inline_properties {
+                let property = Property {
+                    parent: variable.get_text(),
+                    members: property
+                        .split('.')
+                        .map(|member| member.to_string())
+                        .collect(),
+                };
+                self.spans.insert(property.to_ref_str(), span_of(ctx));
+                self.properties.insert(property);
+            }
         }
-        let mut udf_args = vec![];
-        // TODO: We should not allocate a property visitor for each call
+        self.visit_children(ctx)
+    }
+
+    fn visit_oC_FunctionInvocation(&mut self, func: &OC_FunctionInvocationContext<'i>) {
+        // the properties and calls in the arguments are needed first
         for arg in func.oC_Expression_all() {
             arg.accept(self);
-            // TODO: These can be complicated expressions
-            // For now we expect a simple entity reference
-            udf_args.push(arg.get_text());
-        }
-        log::debug!(
-            "Storing UDF with name: {:?} and args {:?}",
-            udf_name,
-            udf_args
-        );
-        self.udf_calls.insert(UdfCall {
-            id: udf_name,
-            args: udf_args,
-        });
+        }
+        match udf_call(func) {
+            Ok(call) => {
+                self.spans.insert(call.to_ref_str(), span_of(func));
+                self.udf_calls.insert(call);
+            }
+            Err(err) => {
+                self.error.get_or_insert(err);
+            }
+        }
+    }
+
+    fn visit_oC_Parameter(&mut self, ctx: &OC_ParameterContext<'i>) {
+        self.parameters.insert(parameter_name(ctx));
     }
 
     fn visit_oC_PropertyOrLabelsExpression(
@@ -77,32 +135,376 @@ impl<'i> CypherVisitor<'i> for PropertyAndUdfVisitor {
         } else if let Some(var) = atom.oC_Literal() {
             // TODO:  Literals are values, not property. How do we treat them?
             log::debug!("Storing literal: {:?}", var);
+            // lists may contain parameters
+            self.visit_children(prop);
+            return;
+        } else if atom.oC_Parameter().is_some() || atom.oC_ParenthesizedExpression().is_some() {
+            self.visit_children(prop);
             return;
         } else {
-            log::error!(
-                "Unsupported expression {:?}. Has type {:?}",
-                atom.get_text(),
-                ruleNames[atom.get_child(0).unwrap().get_rule_index()]
-            );
-            process::exit(1);
+            self.error
+                .get_or_insert(unsupported_atom(&atom, "expression"));
+            return;
         }
 
-        let mut property_vec = vec![];
-        for property in prop.oC_PropertyLookup_all() {
-            if let Some(prop_key) = property.oC_PropertyKeyName() {
-                property_vec.push(prop_key.get_text());
-            } else {
-                panic!("Expected identifer to follow property notation.")
+        let property_vec = match property_members(prop) {
+            Ok(property_vec) => property_vec,
+            Err(err) => {
+                self.error.get_or_insert(err);
+                return;
             }
-        }
+        };
+        let property = Property {
+            parent: entity,
+            members: property_vec,
+        };
+        self.spans.insert(property.to_ref_str(), span_of(prop));
         // if it's 0, that means you likely just found a variable inside a UDF, like height(c)
-        if !property_vec.is_empty() {
-            self.properties.insert(Property {
-                parent: entity,
-                members: property_vec,
-            });
+        if !property.members.is_empty() {
+            self.properties.insert(property);
+        }
+    }
+}
+
+/***********************************/
+// UdfArgVisitor:  turns the arguments of a UDF call into UdfArgs
+/***********************************/
+
+struct UdfArgVisitor {
+    args: Vec<UdfArg>,
+    // the first error found
+    error: Option<CompileError>,
+}
+
+impl Default for UdfArgVisitor {
+    fn default() -> Self {
+        UdfArgVisitor {
+            args: Vec::new(),
+            error: None,
+        }
+    }
+}
+
+impl<'i> ParseTreeVisitor<'i, CypherParserContextType> for UdfArgVisitor {
+    fn visit_terminal(&mut self, _node: &TerminalNode<'i, CypherParserContextType>) {}
+}
+
+impl<'i> CypherVisitor<'i> for UdfArgVisitor {
+    fn visit_oC_PropertyOrLabelsExpression(
+        &mut self,
+        prop: &OC_PropertyOrLabelsExpressionContext<'i>,
+    ) {
+        let atom = prop.oC_Atom().unwrap();
+        if let Some(func) = atom.oC_FunctionInvocation() {
+            match udf_call(&func) {
+                Ok(call) => self.args.push(UdfArg::UdfCall(call)),
+                Err(err) => {
+                    self.error.get_or_insert(err);
+                }
+            }
+        } else if let Some(var) = atom.oC_Variable() {
+            match property_members(prop) {
+                Ok(property_vec) => self.args.push(UdfArg::Property(Property {
+                    parent: var.get_text(),
+                    members: property_vec,
+                })),
+                Err(err) => {
+                    self.error.get_or_insert(err);
+                }
+            }
+        } else if let Some(literal) = atom.oC_Literal() {
+            self.args.push(UdfArg::Literal(literal.get_text()));
+        } else if let Some(parameter) = atom.oC_Parameter() {
+            self.args
+                .push(UdfArg::Parameter(parameter_name(&parameter)));
+        } else {
+            self.error
+                .get_or_insert(unsupported_atom(&atom, "UDF argument"));
+        }
+    }
+}
+
+/// Builds the UDF call for a function invocation, including the calls in its arguments.
+fn udf_call(func: &OC_FunctionInvocationContext<'_>) -> CompileResult<UdfCall> {
+    let udf_name = match func.oC_FunctionName() {
+        Some(udf_name) => udf_name.get_text(),
+        None => {
+            return Err(error_at(
+                func,
+                format!("Expected a function name in {:?}", func.get_text()),
+            ))
+        }
+    };
+    let mut udf_args = vec![];
+    for arg in func.oC_Expression_all() {
+        let mut arg_visitor = UdfArgVisitor::default();
+        arg.accept(&mut arg_visitor);
+        if let Some(err) = arg_visitor.error {
+            return Err(err);
+        }
+        // an argument like a.x + 1 yields several args
+        if arg_visitor.args.len() != 1 {
+            return Err(error_at(
+                &*arg,
+                format!(
+                    "Unsupported argument {:?} of UDF {:?}",
+                    arg.get_text(),
+                    udf_name
+                ),
+            ));
+        }
+        udf_args.append(&mut arg_visitor.args);
+    }
+    log::debug!(
+        "Storing UDF with name: {:?} and args {:?}",
+        udf_name,
+        udf_args
+    );
+    Ok(UdfCall {
+        id: udf_name,
+        args: udf_args,
+    })
+}
+
+/// Collects the $parameters in a part of the query.
+#[derive(Default)]
+struct ParameterVisitor {
+    parameters: Vec<String>,
+}
+
+impl<'i> ParseTreeVisitor<'i, CypherParserContextType> for ParameterVisitor {
+    fn visit_terminal(&mut self, _node: &TerminalNode<'i, CypherParserContextType>) {}
+}
+
+impl<'i> CypherVisitor<'i> for ParameterVisitor {
+    fn visit_oC_Parameter(&mut self, ctx: &OC_ParameterContext<'i>) {
+        self.parameters.push(parameter_name(ctx));
+    }
+}
+
+/// The property names following a variable, e.g. ["request", "total_size"] for
+/// a.request.total_size.
+fn property_members(prop: &OC_PropertyOrLabelsExpressionContext<'_>) -> CompileResult<Vec<String>> {
+    let mut property_vec = vec![];
+    for property in prop.oC_PropertyLookup_all() {
+        match property.oC_PropertyKeyName() {
+            Some(prop_key) => property_vec.push(prop_key.get_text()),
+            None => {
+                return Err(error_at(
+                    &*property,
+                    format!("Expected a property name after . in {:?}", prop.get_text()),
+                ))
+            }
+        }
+    }
+    Ok(property_vec)
+}
+
+/// Parses the value a property is compared to, which has to be a literal or a
+/// $parameter.  Parameters are told apart by the parser, so '$foo' is a string.
+fn filter_value<'i, T: CypherParserContext<'i> + ?Sized>(ctx: &T) -> CompileResult<Value> {
+    let mut visitor = ParameterVisitor::default();
+    ctx.accept(&mut visitor);
+    if let [name] = visitor.parameters.as_slice() {
+        if ctx.get_text() == format!("${}", name) {
+            return Ok(Value::Parameter(name.clone()));
+        }
+    }
+    Value::from_literal(&ctx.get_text()).ok_or_else(|| {
+        error_at(
+            ctx,
+            format!(
+                "Unsupported value {:?}. Properties can be compared to numbers, booleans, quoted strings, durations such as duration('10ms') and $parameters",
+                ctx.get_text()
+            ),
+        )
+    })
+}
+
+/// The name of a parameter such as $threshold, without the $.
+fn parameter_name(ctx: &OC_ParameterContext<'_>) -> String {
+    ctx.get_text().trim_start_matches('$').to_string()
+}
+
+/***********************************/
+// Expressions:  turns arithmetic expressions into nested PropertyOrUDFs
+/***********************************/
+
+/// Lowers an expression such as b.response.total_size - a.request.total_size.
+/// Operators of the same precedence associate to the left.
+fn arithmetic_expression(
+    ctx: &OC_AddOrSubtractExpressionContext<'_>,
+) -> CompileResult<PropertyOrUDF> {
+    let operands = ctx
+        .oC_MultiplyDivideModuloExpression_all()
+        .iter()
+        .map(|operand| multiply_divide_modulo_expression(operand))
+        .collect::<CompileResult<_>>()?;
+    // the operators are the only children with a text of their own
+    let ops = ctx
+        .get_children()
+        .filter_map(|child| ArithmeticOp::from_symbol(&child.get_text()))
+        .collect();
+    Ok(fold_operands(operands, ops))
+}
+
+fn multiply_divide_modulo_expression(
+    ctx: &OC_MultiplyDivideModuloExpressionContext<'_>,
+) -> CompileResult<PropertyOrUDF> {
+    let operands = ctx
+        .oC_PowerOfExpression_all()
+        .iter()
+        .map(|operand| {
+            let unary_expressions = operand.oC_UnaryAddOrSubtractExpression_all();
+            if unary_expressions.len() != 1 {
+                return Err(error_at(
+                    &**operand,
+                    format!("Unsupported power expression {:?}", operand.get_text()),
+                ));
+            }
+            unary_expression(&unary_expressions[0])
+        })
+        .collect::<CompileResult<_>>()?;
+    let ops = ctx
+        .get_children()
+        .filter_map(|child| ArithmeticOp::from_symbol(&child.get_text()))
+        .collect();
+    Ok(fold_operands(operands, ops))
+}
+
+fn fold_operands(operands: Vec<PropertyOrUDF>, ops: Vec<ArithmeticOp>) -> PropertyOrUDF {
+    let mut operands = operands.into_iter();
+    let mut result = operands.next().unwrap();
+    for (op, operand) in ops.into_iter().zip(operands) {
+        result = PropertyOrUDF::Arithmetic(Arithmetic {
+            op,
+            left: Box::new(result),
+            right: Box::new(operand),
+        });
+    }
+    result
+}
+
+/// A leading minus negates the operand, so -a.x is 0 - a.x.
+fn unary_expression(
+    ctx: &OC_UnaryAddOrSubtractExpressionContext<'_>,
+) -> CompileResult<PropertyOrUDF> {
+    let operand_ctx = ctx.oC_StringListNullOperatorExpression().unwrap();
+    let prop = operand_ctx.oC_PropertyOrLabelsExpression().unwrap();
+    // string, list and null operators are not supported in expressions
+    if prop.get_text() != operand_ctx.get_text() {
+        return Err(error_at(
+            &*operand_ctx,
+            format!("Unsupported expression {:?}", operand_ctx.get_text()),
+        ));
+    }
+    let operand = atom_expression(&prop)?;
+    let num_minuses = ctx
+        .get_children()
+        .filter(|child| child.get_text() == "-")
+        .count();
+    if num_minuses % 2 == 0 {
+        return Ok(operand);
+    }
+    Ok(match operand {
+        PropertyOrUDF::Literal(literal) => PropertyOrUDF::Literal(format!("-{}", literal)),
+        _ => PropertyOrUDF::Arithmetic(Arithmetic {
+            op: ArithmeticOp::Sub,
+            left: Box::new(PropertyOrUDF::Literal("0".to_string())),
+            right: Box::new(operand),
+        }),
+    })
+}
+
+fn atom_expression(
+    prop: &OC_PropertyOrLabelsExpressionContext<'_>,
+) -> CompileResult<PropertyOrUDF> {
+    let atom = prop.oC_Atom().unwrap();
+    if let Some(func) = atom.oC_FunctionInvocation() {
+        // TODO: Technically, UDFs can return an object
+        Ok(PropertyOrUDF::UdfCall(udf_call(&func)?))
+    } else if let Some(var) = atom.oC_Variable() {
+        Ok(PropertyOrUDF::Property(Property {
+            parent: var.get_text(),
+            members: property_members(prop)?,
+        }))
+    } else if let Some(literal) = atom.oC_Literal() {
+        Ok(PropertyOrUDF::Literal(literal.get_text()))
+    } else if let Some(parameter) = atom.oC_Parameter() {
+        Ok(PropertyOrUDF::Parameter(parameter_name(&parameter)))
+    } else if let Some(inner) = atom
+        .oC_ParenthesizedExpression()
+        .and_then(|parenthesized| parenthesized.oC_Expression())
+        .and_then(|expression| arithmetic_in_expression(&expression))
+    {
+        arithmetic_expression(&inner)
+    } else {
+        Err(unsupported_atom(&atom, "expression"))
+    }
+}
+
+/// Returns the arithmetic expression if that is all the expression consists of,
+/// i.e. it has no boolean operators or comparisons.
+fn arithmetic_in_expression<'i>(
+    ctx: &OC_ExpressionContext<'i>,
+) -> Option<Rc<OC_AddOrSubtractExpressionContextAll<'i>>> {
+    let arithmetic = ctx
+        .oC_OrExpression()?
+        .oC_XorExpression(0)?
+        .oC_AndExpression(0)?
+        .oC_NotExpression(0)?
+        .oC_ComparisonExpression()?
+        .oC_AddOrSubtractExpression()?;
+    if arithmetic.get_text() != ctx.get_text() {
+        return None;
+    }
+    Some(arithmetic)
+}
+
+/// Returns the comparison as an expression filter, unless it compares a single
+/// property or UDF with a value, which is an attribute filter instead.
+fn expression_filter(
+    ctx: &OC_ComparisonExpressionContext<'_>,
+) -> CompileResult<Option<ExpressionFilter>> {
+    if parenthesized_expression(ctx).is_some() {
+        return Ok(None);
+    }
+    let right_clause = match ctx.oC_PartialComparisonExpression(0) {
+        Some(right_clause) => right_clause,
+        None => return Ok(None),
+    };
+    let left = arithmetic_expression(&ctx.oC_AddOrSubtractExpression().unwrap())?;
+    let right_expression = right_clause.oC_AddOrSubtractExpression().unwrap();
+    // duration('10ms') is written like a UDF call, but is a value
+    if Value::from_literal(&right_expression.get_text()).is_some() {
+        if let PropertyOrUDF::Property(_) | PropertyOrUDF::UdfCall(_) = left {
+            return Ok(None);
         }
     }
+    let right = arithmetic_expression(&right_expression)?;
+    match (&left, &right) {
+        (PropertyOrUDF::Property(_), PropertyOrUDF::Literal(_))
+        | (PropertyOrUDF::Property(_), PropertyOrUDF::Parameter(_))
+        | (PropertyOrUDF::UdfCall(_), PropertyOrUDF::Literal(_))
+        | (PropertyOrUDF::UdfCall(_), PropertyOrUDF::Parameter(_)) => return Ok(None),
+        _ => {}
+    }
+    let op = compare_op(&right_clause)?;
+    Ok(Some(ExpressionFilter { left, op, right }))
+}
+
+fn compare_op(
+    right_clause: &OC_PartialComparisonExpressionContext<'_>,
+) -> CompileResult<CompareOp> {
+    CompareOp::from_cypher_prefix(&right_clause.get_text()).ok_or_else(|| {
+        error_at(
+            right_clause,
+            format!(
+                "Unsupported comparison operator in {}",
+                right_clause.get_text()
+            ),
+        )
+    })
 }
 
 /***********************************/
@@ -111,9 +513,18 @@ impl<'i> CypherVisitor<'i> for PropertyAndUdfVisitor {
 
 pub struct FilterVisitor {
     struct_filters: Vec<StructuralFilter>,
-    attr_filters: Vec<AttributeFilter>,
+    attr_filters: Vec<Predicate>,
+    // the UNION branch of each attribute filter
+    attr_filter_branches: Vec<usize>,
+    expression_filters: Vec<ExpressionFilter>,
     return_items: Vec<IrReturn>,
     property_references: Vec<EntityReference>,
+    // the UNION branch being visited, which is also the index of its structural filter
+    branch: usize,
+    // the number of OPTIONAL MATCH clauses visited so far in this branch
+    optional_matches: usize,
+    // the first error found
+    error: Option<CompileError>,
 }
 
 impl Default for FilterVisitor {
@@ -121,8 +532,13 @@ impl Default for FilterVisitor {
         FilterVisitor {
             struct_filters: Vec::new(),
             attr_filters: Vec::new(),
+            attr_filter_branches: Vec::new(),
+            expression_filters: Vec::new(),
             return_items: Vec::new(),
             property_references: Vec::new(),
+            branch: 0,
+            optional_matches: 0,
+            error: None,
         }
     }
 }
@@ -131,272 +547,967 @@ impl<'i> ParseTreeVisitor<'i, CypherParserContextType> for FilterVisitor {
     fn visit_terminal(&mut self, _node: &TerminalNode<'i, CypherParserContextType>) {}
 }
 
-impl<'i> CypherVisitor<'i> for FilterVisitor {
-    // we do not want to visit returns in this case, ignore that part of the tree
-    // TODO: Apply the visitor directly to the MATCH body instead
-    fn visit_oC_Return(&mut self, _ctx: &OC_ReturnContext<'i>) {}
+impl<'i> FilterVisitor {
+    /// Returns the error found while visiting the last subtree, if there was one.
+    fn take_error(&mut self) -> CompileResult<()> {
+        match self.error.take() {
+            Some(err) => Err(err),
+            None => Ok(()),
+        }
+    }
 
-    fn visit_oC_PropertyOrLabelsExpression(
+    /// Turns a single comparison, e.g. a.response.code >= 500, into an attribute filter.
+    /// A parenthesized expression is lowered recursively instead.
+    fn comparison_to_predicate(
         &mut self,
-        ctx: &OC_PropertyOrLabelsExpressionContext<'i>,
-    ) {
-        log::debug!("Generating IrReturn");
-        let atom = ctx.oC_Atom().unwrap();
-        let mut entity: String;
-        let mut property_str = String::new();
-        for property in ctx.oC_PropertyLookup_all() {
-            // this includes the dots
-            property_str.push_str(&property.get_text())
+        ctx: &OC_ComparisonExpressionContext<'i>,
+    ) -> CompileResult<Option<Predicate>> {
+        if let Some(expression) = parenthesized_expression(ctx) {
+            return self.expression_to_predicate(&expression);
         }
-        log::debug!("Property String {:?}", property_str);
-
-        if let Some(func) = atom.oC_FunctionInvocation() {
-            entity = func.get_text();
-            log::debug!("Storing UDF: {:?}", entity);
-            // this is not the correct "parse-y" way to do it.  But I can't figure out another way
-            if let Some(index) = entity.find('(') {
-                property_str = entity[0..index].to_string();
-                entity = entity[index + 1..entity.len() - 1].to_string();
-            } else {
-                log::error!("could not find parenthesis in UDF");
-            }
-        } else if let Some(var) = atom.oC_Variable() {
-            entity = var.get_text();
-            log::debug!("Storing var: {:?}", entity);
-        } else if let Some(var) = atom.oC_Literal() {
-            entity = var.get_text();
-            log::debug!("Storing literal: {:?}", entity);
-        } else {
-            log::error!(
-                "Unsupported expression {:?}. Has type {:?}",
-                atom.get_text(),
-                ruleNames[atom.get_child(0).unwrap().get_rule_index()]
-            );
-            process::exit(1);
+        if let Some(operator_expression) = operator_expression(ctx) {
+            return self.operator_expression_to_predicate(&operator_expression);
         }
-
-        self.return_items.push(IrReturn {
-            entity,
-            property: property_str,
-        });
-
-        if atom.oC_Literal().is_some() {
-            //TODO: This check can be removed once we are done with the transition
-            return;
+        if expression_filter(ctx)?.is_some() {
+            return Err(error_at(
+                ctx,
+                format!(
+                    "Comparison {:?} can only be combined with others through AND",
+                    ctx.get_text()
+                ),
+            ));
         }
-        self.property_references.push(ctx.get_text());
-    }
 
-    fn visit_oC_ComparisonExpression(&mut self, ctx: &OC_ComparisonExpressionContext<'i>) {
         // get the left node
         ctx.oC_AddOrSubtractExpression().unwrap().accept(self);
+        self.take_error()?;
         let node = self.return_items[0].entity.clone();
         let property = self.return_items[0].property.clone();
         self.return_items.clear();
 
         if property.is_empty() {
             // skip
-            return;
+            return Ok(None);
         }
 
         // process the right node
-        let value;
-        if let Some(right_clause) = ctx.oC_PartialComparisonExpression(0) {
-            right_clause.accept(self);
-            value = self.return_items[0].entity.clone();
-        } else {
-            log::error!("Expected a right-hand side expression.");
-            process::exit(1);
-        }
+        let right_clause = match ctx.oC_PartialComparisonExpression(0) {
+            Some(right_clause) => right_clause,
+            None => {
+                return Err(error_at(
+                    ctx,
+                    format!("Expected a comparison in {:?}", ctx.get_text()),
+                ))
+            }
+        };
+        let op = compare_op(&right_clause)?;
+        let value = filter_value(&*right_clause.oC_AddOrSubtractExpression().unwrap())?;
+        let attr_filter = AttributeFilter {
+            node,
+            property,
+            op,
+            value,
+        };
+        Ok(Some(Predicate::Attribute(attr_filter)))
+    }
+
+    /// Turns a string, list or null operation on a property into a predicate:
+    /// a.request.path STARTS WITH '/api/v2' and a.request.useragent =~ '.*curl.*'
+    /// become attribute filters, b.x IN ['v2', 'v3'] compares b.x to each value, and
+    /// a.x IS NOT NULL checks that a.x was collected.
+    fn operator_expression_to_predicate(
+        &mut self,
+        ctx: &OC_StringListNullOperatorExpressionContext<'i>,
+    ) -> CompileResult<Option<Predicate>> {
+        ctx.oC_PropertyOrLabelsExpression().unwrap().accept(self);
+        self.take_error()?;
+        let node = self.return_items[0].entity.clone();
+        let property = self.return_items[0].property.clone();
         self.return_items.clear();
+        if property.is_empty() {
+            return Err(error_at(
+                ctx,
+                format!("Expected a property in {}", ctx.get_text()),
+            ));
+        }
+
+        if let Some(null_operator) = ctx.oC_NullOperatorExpression(0) {
+            let exists = Predicate::Exists(ExistenceFilter { node, property });
+            if null_operator.NOT().is_some() {
+                return Ok(Some(exists));
+            }
+            return Ok(Some(Predicate::Not(Box::new(exists))));
+        }
+
+        if let Some(list_operator) = ctx.oC_ListOperatorExpression(0) {
+            let values = match list_operator
+                .oC_PropertyOrLabelsExpression()
+                .filter(|_| list_operator.IN().is_some())
+                .and_then(|list| list.oC_Atom())
+                .and_then(|atom| atom.oC_Literal())
+                .and_then(|literal| literal.oC_ListLiteral())
+            {
+                Some(list) => list.oC_Expression_all(),
+                None => {
+                    return Err(error_at(
+                        &*list_operator,
+                        format!(
+                            "Unsupported list operation {:?}, only IN [...] is supported",
+                            list_operator.get_text()
+                        ),
+                    ))
+                }
+            };
+            if values.is_empty() {
+                return Err(error_at(
+                    &*list_operator,
+                    "IN needs at least one value, since no value is in an empty list".to_string(),
+                ));
+            }
+            let mut operands = Vec::new();
+            for value in values {
+                operands.push(Predicate::Attribute(AttributeFilter {
+                    node: node.clone(),
+                    property: property.clone(),
+                    op: CompareOp::Eq,
+                    value: filter_value(&*value)?,
+                }))
+            }
+            if operands.len() == 1 {
+                return Ok(operands.pop());
+            }
+            return Ok(Some(Predicate::Or(operands)));
+        }
+
+        let string_operator = ctx.oC_StringOperatorExpression(0).unwrap();
+        let op = if string_operator.STARTS().is_some() {
+            CompareOp::StartsWith
+        } else if string_operator.ENDS().is_some() {
+            CompareOp::EndsWith
+        } else if string_operator.CONTAINS().is_some() {
+            CompareOp::Contains
+        } else {
+            CompareOp::RegexMatch
+        };
+        let value = filter_value(&*string_operator.oC_PropertyOrLabelsExpression().unwrap())?;
         let attr_filter = AttributeFilter {
             node,
             property,
+            op,
             value,
         };
-        self.attr_filters.push(attr_filter);
+        attr_filter
+            .check_regex()
+            .map_err(|message| error_at(&*string_operator, message))?;
+        Ok(Some(Predicate::Attribute(attr_filter)))
     }
 
-    fn visit_oC_PatternElement(&mut self, ctx: &OC_PatternElementContext<'i>) {
-        if self.struct_filters.is_empty() {
+    /// Node labels and inline property maps are shorthands for WHERE clause comparisons.
+    /// A label names the workload, so (a:productpage-v1) means
+    /// a.node.metadata.WORKLOAD_NAME = 'productpage-v1'.  An inline property map, as in
+    /// (a {service_name: "productpage-v1"}), compares each property for equality.
+    fn add_node_pattern_filters(
+        &mut self,
+        node_pattern: &OC_NodePatternContext<'i>,
+    ) -> CompileResult<()> {
+        if let Some(variable) = node_pattern.oC_Variable() {
+            if let Some(labels) = node_pattern.oC_NodeLabels() {
+                for label in labels.oC_NodeLabel_all() {
+                    let mut workload_name = label.oC_LabelName().unwrap().get_text();
+                    workload_name.retain(|c| c != '`');
+                    self.attr_filters
+                        .push(Predicate::Attribute(AttributeFilter {
+                            node: variable.get_text(),
+                            property: ".node.metadata.WORKLOAD_NAME".to_string(),
+                            op: CompareOp::Eq,
+                            value: Value::Str(workload_name),
+                        }));
+                }
+            }
+            for (property, value) in inline_properties(node_pattern)? {
+                self.attr_filters
+                    .push(Predicate::Attribute(AttributeFilter {
+                        node: variable.get_text(),
+                        property: format!(".{}", property),
+                        op: CompareOp::Eq,
+                        value,
+                    }));
+            }
+        }
+        Ok(())
+    }
+
+    /// Adds the nodes and edges of a path such as (a)-[]->(b)<-[]-(c) to the structural
+    /// filter, along with the filters of its node patterns.
+    fn add_pattern_element(&mut self, ctx: &OC_PatternElementContext<'i>) -> CompileResult<()> {
+        if self.struct_filters.len() == self.branch {
             let new_struct_filter = StructuralFilter::default();
             self.struct_filters.push(new_struct_filter);
         }
-        let struct_filter = &mut self.struct_filters[0];
+        let struct_filter = &mut self.struct_filters[self.branch];
+        let mut node_patterns = vec![ctx.oC_NodePattern().unwrap()];
 
-        let mut left_node = ctx.oC_NodePattern().unwrap().oC_Variable().unwrap();
+        let mut left_node = node_variable(&ctx.oC_NodePattern().unwrap())?;
         struct_filter.vertices.insert(left_node.get_text());
         for pattern_element_i in ctx.oC_PatternElementChain_all() {
             let relationship = pattern_element_i.oC_RelationshipPattern().unwrap();
             let node_pattern = pattern_element_i.oC_NodePattern().unwrap();
+            node_patterns.push(node_pattern.clone());
 
-            let right_node = node_pattern.oC_Variable().unwrap();
+            let right_node = node_variable(&node_pattern)?;
             struct_filter.vertices.insert(right_node.get_text());
 
-            // only add right-side edges for now
-            if relationship.oC_RightArrowHead().is_some() {
-                struct_filter
-                    .edges
-                    .insert((left_node.get_text(), right_node.get_text()));
-            } else {
-                log::error!("Unsupported direction");
-                process::exit(1);
+            // edges are always stored from caller to callee
+            let edge = match (
+                relationship.oC_LeftArrowHead().is_some(),
+                relationship.oC_RightArrowHead().is_some(),
+            ) {
+                (false, true) => {
+                    let edge = (left_node.get_text(), right_node.get_text());
+                    struct_filter.edges.insert(edge.clone());
+                    edge
+                }
+                (true, false) => {
+                    let edge = (right_node.get_text(), left_node.get_text());
+                    struct_filter.edges.insert(edge.clone());
+                    edge
+                }
+                (false, false) => {
+                    let edge = (left_node.get_text(), right_node.get_text());
+                    struct_filter.undirected_edges.insert(edge.clone());
+                    edge
+                }
+                (true, true) => {
+                    return Err(error_at(
+                        &*relationship,
+                        format!(
+                            "Unsupported direction in {:?}: a call can not go both ways",
+                            relationship.get_text()
+                        ),
+                    ));
+                }
+            };
+            // a range such as [*1..3] lets the edge span several calls
+            if let Some(range) = relationship
+                .oC_RelationshipDetail()
+                .and_then(|detail| detail.oC_RangeLiteral())
+            {
+                match Hops::from_range_literal(&range.get_text()) {
+                    Ok(hops) => {
+                        if struct_filter.undirected_edges.contains(&edge) {
+                            return Err(error_at(
+                                &*relationship,
+                                format!(
+                                    "Undirected path {:?} is not supported, since paths are matched from caller to callee. Write which way it goes, as in -[*1..3]->",
+                                    relationship.get_text()
+                                ),
+                            ));
+                        }
+                        struct_filter.edge_hops.insert(edge.clone(), hops);
+                    }
+                    Err(msg) => {
+                        return Err(error_at(
+                            &*range,
+                            format!("Invalid range {:?}: {}", range.get_text(), msg),
+                        ));
+                    }
+                }
+            }
+            // every service has one caller, so a path to a node that is called
+            // twice would have to go up from it to one of its callers
+            let calls: Vec<&(String, String)> = struct_filter
+                .edges
+                .iter()
+                .filter(|other| other.1 == edge.1)
+                .collect();
+            if calls.len() > 1
+                && calls
+                    .iter()
+                    .any(|call| struct_filter.edge_hops.contains_key(*call))
+            {
+                return Err(error_at(
+                    &*relationship,
+                    format!(
+                        "{:?} is called by both {:?} and {:?}, one of them through a path, but a path can only be matched from the one caller of a service downwards",
+                        edge.1, calls[0].0, calls[1].0
+                    ),
+                ));
+            }
+            // a relationship variable names the call itself, as in (a)-[r]->(b)
+            if let Some(variable) = relationship
+                .oC_RelationshipDetail()
+                .and_then(|detail| detail.oC_Variable())
+            {
+                let name = variable.get_text();
+                if struct_filter.edge_hops.contains_key(&edge) {
+                    return Err(error_at(
+                        &*variable,
+                        format!(
+                            "Relationship variable {:?} can not be used with a range, since it would name more than one call",
+                            name
+                        ),
+                    ));
+                }
+                if struct_filter.vertices.contains(&name)
+                    || struct_filter.relationships.contains_key(&name)
+                {
+                    return Err(error_at(
+                        &*variable,
+                        format!("Variable {:?} is bound more than once", name),
+                    ));
+                }
+                struct_filter.relationships.insert(name, edge);
             }
             // update the left node
             left_node = right_node;
         }
-        if let Some(extra_struct) = ctx.oC_PatternElement() {
-            extra_struct.accept(self);
+        for node_pattern in node_patterns {
+            self.add_node_pattern_filters(&node_pattern)?;
         }
+        Ok(())
     }
 
-    /// This function visits a match clause.  It extracts the graph inside, complete with any
-    /// node attributes, and stores that information in a struct_filter.  It then extracts any information
-    /// in the where clause, which pertains to the whole graph, and stores that in an attribute_filter.
-    fn visit_oC_Match(&mut self, ctx: &OC_MatchContext<'i>) {
+    /// An OPTIONAL MATCH adds nodes and calls a trace may be missing.  Its pattern
+    /// has to extend the nodes matched before it, and its conditions may only refer
+    /// to what it adds, since failing them only leaves the added part unmatched.
+    fn add_optional_match(&mut self, ctx: &OC_MatchContext<'i>) -> CompileResult<()> {
+        let (bound_vertices, bound_edges) = match self.struct_filters.get(self.branch) {
+            Some(struct_filter) => (
+                struct_filter.vertices.clone(),
+                struct_filter
+                    .edges
+                    .union(&struct_filter.undirected_edges)
+                    .cloned()
+                    .collect::<IndexSet<(String, String)>>(),
+            ),
+            None => {
+                return Err(error_at(
+                    ctx,
+                    "OPTIONAL MATCH has to follow a MATCH".to_string(),
+                ))
+            }
+        };
+        let attr_filters_before = self.attr_filters.len();
+        let expression_filters_before = self.expression_filters.len();
         for p in ctx.oC_Pattern().unwrap().oC_PatternPart_all() {
             p.accept(self);
         }
         if let Some(where_clause) = ctx.oC_Where() {
             where_clause.accept(self);
         }
-    }
-}
+        self.take_error()?;
 
-/***********************************/
-// ReturnVisitor: Visits tree and fills out the return functions
-/***********************************/
+        let part = self.optional_matches;
+        self.optional_matches += 1;
+        let struct_filter = &mut self.struct_filters[self.branch];
+        let new_vertices: IndexSet<String> = struct_filter
+            .vertices
+            .difference(&bound_vertices)
+            .cloned()
+            .collect();
+        let new_edges: IndexSet<(String, String)> = struct_filter
+            .edges
+            .union(&struct_filter.undirected_edges)
+            .filter(|edge| !bound_edges.contains(*edge))
+            .cloned()
+            .collect();
+        for edge in &new_edges {
+            if bound_vertices.contains(&edge.0) && bound_vertices.contains(&edge.1) {
+                return Err(error_at(
+                    ctx,
+                    format!(
+                        "OPTIONAL MATCH can not add a call between {:?} and {:?}, since both are already matched",
+                        edge.0, edge.1
+                    ),
+                ));
+            }
+        }
+        let not_connected = || {
+            error_at(
+                ctx,
+                "OPTIONAL MATCH has to add nodes connected to the ones matched before it"
+                    .to_string(),
+            )
+        };
+        // the matcher leaves the added nodes out together, below the one call
+        // that attaches them
+        let attachments: Vec<&(String, String)> = new_edges
+            .iter()
+            .filter(|edge| bound_vertices.contains(&edge.0) || bound_vertices.contains(&edge.1))
+            .collect();
+        let attachment = match attachments[..] {
+            _ if new_vertices.is_empty() => return Err(not_connected()),
+            [] => return Err(not_connected()),
+            [edge] if bound_vertices.contains(&edge.0) && struct_filter.edges.contains(edge) => {
+                edge.clone()
+            }
+            _ => {
+                return Err(error_at(
+                    ctx,
+                    "OPTIONAL MATCH has to attach the nodes it adds with a single call from a node matched before it, as in OPTIONAL MATCH (b)-->(c)".to_string(),
+                ))
+            }
+        };
+        let mut attached = IndexSet::new();
+        let mut stack = vec![attachment.1];
+        while let Some(vertex) = stack.pop() {
+            if attached.insert(vertex.clone()) {
+                for edge in &new_edges {
+                    if edge.0 == vertex && !bound_vertices.contains(&edge.1) {
+                        stack.push(edge.1.clone());
+                    } else if edge.1 == vertex && !bound_vertices.contains(&edge.0) {
+                        stack.push(edge.0.clone());
+                    }
+                }
+            }
+        }
+        if attached != new_vertices {
+            return Err(not_connected());
+        }
+        let new_variables: IndexSet<String> = new_vertices
+            .iter()
+            .cloned()
+            .chain(
+                struct_filter
+                    .relationships
+                    .iter()
+                    .filter(|(_, edge)| new_edges.contains(*edge))
+                    .map(|(name, _)| name.clone()),
+            )
+            .collect();
+        for vertex in new_vertices {
+            struct_filter.optional_vertices.insert(vertex, part);
+        }
+        for edge in new_edges {
+            struct_filter.optional_edges.insert(edge, part);
+        }
 
-pub struct ReturnVisitor {
-    return_expr: IrReturnEnum,
-    obj_references: Vec<PropertyOrUDF>,
-}
+        if self.expression_filters.len() > expression_filters_before {
+            return Err(error_at(
+                ctx,
+                "Comparisons between expressions are not supported in OPTIONAL MATCH".to_string(),
+            ));
+        }
+        for predicate in &self.attr_filters[attr_filters_before..] {
+            if let Some(node) = predicate
+                .nodes()
+                .into_iter()
+                .find(|node| !new_variables.contains(node))
+            {
+                return Err(error_at(
+                    ctx,
+                    format!(
+                        "Conditions in OPTIONAL MATCH may only refer to the nodes and relationships it adds, but {:?} refers to {:?}",
+                        predicate, node
+                    ),
+                ));
+            }
+        }
+        Ok(())
+    }
 
-impl Default for ReturnVisitor {
-    fn default() -> Self {
-        ReturnVisitor {
-            return_expr: IrReturnEnum::default(),
-            obj_references: Vec::new(),
+    /// Visits one branch of a UNION, or the whole query if it has no UNION, and
+    /// tags the attribute filters it adds with the branch.  Conditions that are
+    /// not checked on the target graph can not be told apart by branch, so they
+    /// are rejected in a UNION.
+    fn add_branch(
+        &mut self,
+        branch: usize,
+        ctx: &OC_SingleQueryContext<'i>,
+        is_union: bool,
+    ) -> CompileResult<()> {
+        self.branch = branch;
+        self.optional_matches = 0;
+        let expression_filters_before = self.expression_filters.len();
+        ctx.accept(self);
+        self.take_error()?;
+        if is_union {
+            if self.struct_filters.len() != branch + 1 {
+                return Err(error_at(
+                    ctx,
+                    "Every branch of a UNION has to MATCH a pattern".to_string(),
+                ));
+            }
+            if self.expression_filters.len() > expression_filters_before {
+                return Err(error_at(
+                    ctx,
+                    "Comparisons between expressions are not supported in a UNION".to_string(),
+                ));
+            }
+            if self.attr_filters[self.attr_filter_branches.len()..]
+                .iter()
+                .any(|predicate| predicate.nodes().contains("trace"))
+            {
+                return Err(error_at(
+                    ctx,
+                    "Conditions on trace are not supported in a UNION".to_string(),
+                ));
+            }
         }
+        self.attr_filter_branches
+            .resize(self.attr_filters.len(), branch);
+        Ok(())
     }
-}
 
-impl<'i> ParseTreeVisitor<'i, CypherParserContextType> for ReturnVisitor {
-    fn visit_terminal(&mut self, _node: &TerminalNode<'i, CypherParserContextType>) {}
-}
+    fn add_where_filters(&mut self, ctx: &OC_WhereContext<'i>) -> CompileResult<()> {
+        self.add_conjuncts(&ctx.oC_Expression().unwrap())
+    }
 
-impl<'i> ReturnVisitor {
-    fn get_udf_function(&self, func: &OC_FunctionInvocationContext<'i>) -> UdfCall {
-        let udf_name: String;
-        if let Some(udf_name_) = func.oC_FunctionName() {
-            udf_name = udf_name_.get_text();
-        } else {
-            panic!("Compiler Bug: Missing UDF name.")
+    /// Adds the conjuncts of an expression as separate filters.  Parenthesized
+    /// conjunctions, as in a.x = 1 AND (b.y = 2 AND c.z = 3), are split up as well.
+    fn add_conjuncts(&mut self, expression: &OC_ExpressionContext<'i>) -> CompileResult<()> {
+        let xor_expressions = expression.oC_OrExpression().unwrap().oC_XorExpression_all();
+        let and_expressions = xor_expressions[0].oC_AndExpression_all();
+        if xor_expressions.len() == 1 && and_expressions.len() == 1 {
+            for not_expression in and_expressions[0].oC_NotExpression_all() {
+                let comparison = not_expression.oC_ComparisonExpression().unwrap();
+                if not_expression.NOT(0).is_none() {
+                    if let Some(inner) = parenthesized_expression(&comparison) {
+                        self.add_conjuncts(&inner)?;
+                        continue;
+                    }
+                    if let Some(filter) = expression_filter(&comparison)? {
+                        self.expression_filters.push(filter);
+                        continue;
+                    }
+                }
+                if let Some(predicate) = self.not_expression_to_predicate(&not_expression)? {
+                    self.add_attr_filter(predicate, &*not_expression)?;
+                }
+            }
+            return Ok(());
         }
-        let mut udf_args = vec![];
-        // TODO: We should not allocate a property visitor for each call
-        for arg in func.oC_Expression_all() {
-            // TODO: These can be complicated expressions
-            // For now we expect a simple entity reference
-            udf_args.push(arg.get_text());
+        if let Some(predicate) = self.expression_to_predicate(expression)? {
+            self.add_attr_filter(predicate, expression)?;
         }
-        log::debug!(
-            "Storing UDF with name: {:?} and args {:?}",
-            udf_name,
-            udf_args
-        );
-        UdfCall {
-            id: udf_name,
-            args: udf_args,
+        Ok(())
+    }
+
+    /// Each attribute filter is checked on the node it refers to, so OR, XOR and NOT
+    /// can only combine conditions on the same node.
+    fn add_attr_filter<T: ParserRuleContext<'i> + ?Sized>(
+        &mut self,
+        predicate: Predicate,
+        ctx: &T,
+    ) -> CompileResult<()> {
+        let nodes = predicate.nodes();
+        if nodes.len() > 1 {
+            return Err(error_at(
+                ctx,
+                format!(
+                    "Condition {:?} refers to {:?}, but OR, XOR and NOT can only combine conditions on the same node",
+                    ctx.get_text(),
+                    nodes
+                ),
+            ));
         }
+        self.attr_filters.push(predicate);
+        Ok(())
     }
-}
 
-impl<'i> CypherVisitor<'i> for ReturnVisitor {
-    // we do not want to visit matches in this case, ignore that part of the tree
-    // TODO: Apply the visitor directly to the RETURN body instead
-    fn visit_oC_Match(&mut self, _ctx: &OC_MatchContext<'i>) {}
+    fn not_expression_to_predicate(
+        &mut self,
+        ctx: &OC_NotExpressionContext<'i>,
+    ) -> CompileResult<Option<Predicate>> {
+        let mut predicate =
+            match self.comparison_to_predicate(&ctx.oC_ComparisonExpression().unwrap())? {
+                Some(predicate) => predicate,
+                None => return Ok(None),
+            };
+        // NOT_all() also returns the whitespace tokens, so count the NOTs directly
+        let mut num_nots = 0;
+        while ctx.NOT(num_nots).is_some() {
+            num_nots += 1;
+        }
+        for _ in 0..num_nots {
+            predicate = Predicate::Not(Box::new(predicate));
+        }
+        Ok(Some(predicate))
+    }
+
+    fn and_expression_to_predicate(
+        &mut self,
+        ctx: &OC_AndExpressionContext<'i>,
+    ) -> CompileResult<Option<Predicate>> {
+        let mut operands = Vec::new();
+        for not_expression in ctx.oC_NotExpression_all() {
+            operands.extend(self.not_expression_to_predicate(&not_expression)?);
+        }
+        Ok(combine_operands(operands, Predicate::And))
+    }
+
+    fn xor_expression_to_predicate(
+        &mut self,
+        ctx: &OC_XorExpressionContext<'i>,
+    ) -> CompileResult<Option<Predicate>> {
+        let mut operands = Vec::new();
+        for and_expression in ctx.oC_AndExpression_all() {
+            operands.extend(self.and_expression_to_predicate(&and_expression)?);
+        }
+        Ok(combine_operands(operands, Predicate::Xor))
+    }
+
+    fn expression_to_predicate(
+        &mut self,
+        ctx: &OC_ExpressionContext<'i>,
+    ) -> CompileResult<Option<Predicate>> {
+        let or_expression = ctx.oC_OrExpression().unwrap();
+        let mut operands = Vec::new();
+        for xor_expression in or_expression.oC_XorExpression_all() {
+            operands.extend(self.xor_expression_to_predicate(&xor_expression)?);
+        }
+        Ok(combine_operands(operands, Predicate::Or))
+    }
+}
+
+/// Returns the property names and values of an inline property map in a node pattern.
+/// Backticks around property names are dropped, so that (a {`request.total_size`: 5})
+/// refers to a.request.total_size.
+fn inline_properties(
+    node_pattern: &OC_NodePatternContext<'_>,
+) -> CompileResult<Vec<(String, Value)>> {
+    let mut inline_properties = Vec::new();
+    if let Some(properties) = node_pattern.oC_Properties() {
+        if let Some(map) = properties.oC_MapLiteral() {
+            for (key, value) in map
+                .oC_PropertyKeyName_all()
+                .iter()
+                .zip(map.oC_Expression_all())
+            {
+                let mut property = key.get_text();
+                property.retain(|c| c != '`');
+                inline_properties.push((property, filter_value(&*value)?));
+            }
+        } else {
+            return Err(error_at(
+                &*properties,
+                format!(
+                    "Unsupported property map {:?} in pattern",
+                    properties.get_text()
+                ),
+            ));
+        }
+    }
+    Ok(inline_properties)
+}
+
+/// Returns the variable naming the node, which every node in a pattern needs.
+fn node_variable<'i>(
+    node_pattern: &OC_NodePatternContext<'i>,
+) -> CompileResult<Rc<OC_VariableContextAll<'i>>> {
+    node_pattern.oC_Variable().ok_or_else(|| {
+        error_at(
+            node_pattern,
+            format!(
+                "Node {:?} has to be named, as in (a)",
+                node_pattern.get_text()
+            ),
+        )
+    })
+}
+
+fn combine_operands(
+    mut operands: Vec<Predicate>,
+    combine: fn(Vec<Predicate>) -> Predicate,
+) -> Option<Predicate> {
+    match operands.len() {
+        0 => None,
+        1 => operands.pop(),
+        _ => Some(combine(operands)),
+    }
+}
+
+/// Returns the inner expression if the whole comparison is just a parenthesized expression,
+/// as in (a.x = 1 OR a.x = 2).
+fn parenthesized_expression<'i>(
+    ctx: &OC_ComparisonExpressionContext<'i>,
+) -> Option<Rc<OC_ExpressionContextAll<'i>>> {
+    if ctx.oC_PartialComparisonExpression(0).is_some() {
+        return None;
+    }
+    let parenthesized = ctx
+        .oC_AddOrSubtractExpression()?
+        .oC_MultiplyDivideModuloExpression(0)?
+        .oC_PowerOfExpression(0)?
+        .oC_UnaryAddOrSubtractExpression(0)?
+        .oC_StringListNullOperatorExpression()?
+        .oC_PropertyOrLabelsExpression()?
+        .oC_Atom()?
+        .oC_ParenthesizedExpression()?;
+    // make sure there is nothing else, like (a.x) + 1
+    if parenthesized.get_text() != ctx.get_text() {
+        return None;
+    }
+    parenthesized.oC_Expression()
+}
+
+/// Returns the operator expression if the whole comparison is a single string, list or
+/// null operation, as in a.request.path STARTS WITH '/api/v2' or a.x IS NULL.
+fn operator_expression<'i>(
+    ctx: &OC_ComparisonExpressionContext<'i>,
+) -> Option<Rc<OC_StringListNullOperatorExpressionContextAll<'i>>> {
+    if ctx.oC_PartialComparisonExpression(0).is_some() {
+        return None;
+    }
+    let operator_expression = ctx
+        .oC_AddOrSubtractExpression()?
+        .oC_MultiplyDivideModuloExpression(0)?
+        .oC_PowerOfExpression(0)?
+        .oC_UnaryAddOrSubtractExpression(0)?
+        .oC_StringListNullOperatorExpression()?;
+    let num_operators = operator_expression.oC_StringOperatorExpression_all().len()
+        + operator_expression.oC_ListOperatorExpression_all().len()
+        + operator_expression.oC_NullOperatorExpression_all().len();
+    if num_operators != 1 || operator_expression.get_text() != ctx.get_text() {
+        return None;
+    }
+    Some(operator_expression)
+}
+
+impl<'i> CypherVisitor<'i> for FilterVisitor {
+    // we do not want to visit returns in this case, ignore that part of the tree
+    // TODO: Apply the visitor directly to the MATCH body instead
+    fn visit_oC_Return(&mut self, _ctx: &OC_ReturnContext<'i>) {}
 
     fn visit_oC_PropertyOrLabelsExpression(
         &mut self,
-        prop: &OC_PropertyOrLabelsExpressionContext<'i>,
+        ctx: &OC_PropertyOrLabelsExpressionContext<'i>,
     ) {
-        log::debug!("Generating Property");
-        let atom = prop.oC_Atom().unwrap();
+        log::debug!("Generating IrReturn");
+        let atom = ctx.oC_Atom().unwrap();
         let entity: String;
-        if let Some(var) = atom.oC_Variable() {
+        let mut property_str = String::new();
+        for property in ctx.oC_PropertyLookup_all() {
+            // this includes the dots
+            property_str.push_str(&property.get_text())
+        }
+        log::debug!("Property String {:?}", property_str);
+
+        if let Some(func) = atom.oC_FunctionInvocation() {
+            let call = match udf_call(&func) {
+                Ok(call) => call,
+                Err(err) => {
+                    self.error.get_or_insert(err);
+                    return;
+                }
+            };
+            log::debug!("Storing UDF: {:?}", call);
+            // the UDF result is stored as a property of the node it is applied to
+            let nodes = call.nodes();
+            if nodes.len() != 1 {
+                self.error.get_or_insert(error_at(
+                    &*func,
+                    format!(
+                        "UDF call {:?} has to refer to exactly one node, but refers to {:?}",
+                        call.to_ref_str(),
+                        nodes
+                    ),
+                ));
+                return;
+            }
+            entity = nodes[0].clone();
+            property_str = call.id;
+        } else if let Some(var) = atom.oC_Variable() {
             entity = var.get_text();
             log::debug!("Storing var: {:?}", entity);
-        } else if let Some(func) = atom.oC_FunctionInvocation() {
-            // TODO: Technically, UDFs can return an object
-            self.obj_references
-                .push(PropertyOrUDF::UdfCall(self.get_udf_function(&func)));
-            return;
         } else if let Some(var) = atom.oC_Literal() {
-            // TODO:  Literals are values, not property. How do we treat them?
-            log::debug!("Storing literal: {:?}", var);
-            return;
+            entity = var.get_text();
+            log::debug!("Storing literal: {:?}", entity);
         } else {
-            log::error!(
-                "Unsupported expression {:?}. Has type {:?}",
-                atom.get_text(),
-                ruleNames[atom.get_child(0).unwrap().get_rule_index()]
-            );
-            process::exit(1);
+            self.error
+                .get_or_insert(unsupported_atom(&atom, "expression"));
+            return;
         }
 
-        let mut property_vec = vec![];
-        for property in prop.oC_PropertyLookup_all() {
-            if let Some(prop_key) = property.oC_PropertyKeyName() {
-                property_vec.push(prop_key.get_text());
-            } else {
-                panic!("Expected identifer to follow property notation.")
+        self.return_items.push(IrReturn {
+            entity,
+            property: property_str,
+        });
+
+        if atom.oC_Literal().is_some() {
+            //TODO: This check can be removed once we are done with the transition
+            return;
+        }
+        self.property_references.push(ctx.get_text());
+    }
+
+    fn visit_oC_PatternElement(&mut self, ctx: &OC_PatternElementContext<'i>) {
+        if let Err(err) = self.add_pattern_element(ctx) {
+            self.error.get_or_insert(err);
+            return;
+        }
+        if let Some(extra_struct) = ctx.oC_PatternElement() {
+            extra_struct.accept(self);
+        }
+    }
+
+    /// The WHERE clause becomes a predicate tree.  Its top level conjuncts are stored
+    /// separately, so that each one can be checked on the node it refers to.  Conjuncts
+    /// comparing expressions become expression filters instead.
+    fn visit_oC_Where(&mut self, ctx: &OC_WhereContext<'i>) {
+        if let Err(err) = self.add_where_filters(ctx) {
+            self.error.get_or_insert(err);
+        }
+    }
+
+    /// Each branch of a UNION is matched against its own target graph.  A trace
+    /// is reported once, for the first branch it matches, so UNION ALL is rejected.
+    fn visit_oC_RegularQuery(&mut self, ctx: &OC_RegularQueryContext<'i>) {
+        let mut branches = vec![ctx.oC_SingleQuery().unwrap()];
+        for union in ctx.oC_Union_all() {
+            if union.ALL().is_some() {
+                self.error.get_or_insert(error_at(
+                    &*union,
+                    "UNION ALL is not supported, a trace matching several branches is reported once. Use UNION instead".to_string(),
+                ));
+                return;
+            }
+            branches.push(union.oC_SingleQuery().unwrap());
+        }
+        let is_union = branches.len() > 1;
+        for (branch, single_query) in branches.iter().enumerate() {
+            if let Err(err) = self.add_branch(branch, single_query, is_union) {
+                self.error.get_or_insert(err);
+                return;
             }
         }
-        self.obj_references.push(PropertyOrUDF::Property(Property {
-            parent: entity,
-            members: property_vec,
-        }));
     }
 
-    fn visit_oC_ProjectionItems(&mut self, ctx: &OC_ProjectionItemsContext<'i>) {
-        // For now we distinguish between aggregation and UDFs in the codegen
-        // FIXME: Make this more explicit with a dedicated visit tree
-        self.obj_references.clear();
+    /// This function visits a match clause.  It extracts the graph inside, complete with any
+    /// node attributes, and stores that information in a struct_filter.  It then extracts any information
+    /// in the where clause, which pertains to the whole graph, and stores that in an attribute_filter.
+    fn visit_oC_Match(&mut self, ctx: &OC_MatchContext<'i>) {
+        if ctx.OPTIONAL().is_some() {
+            if let Err(err) = self.add_optional_match(ctx) {
+                self.error.get_or_insert(err);
+            }
+            return;
+        }
+        if self.optional_matches > 0 {
+            self.error.get_or_insert(error_at(
+                ctx,
+                "MATCH can not follow OPTIONAL MATCH".to_string(),
+            ));
+            return;
+        }
+        for p in ctx.oC_Pattern().unwrap().oC_PatternPart_all() {
+            p.accept(self);
+        }
+        if let Some(where_clause) = ctx.oC_Where() {
+            where_clause.accept(self);
+        }
+    }
+}
+
+/***********************************/
+// ReturnVisitor: Visits tree and fills out the return functions
+/***********************************/
+
+pub struct ReturnVisitor {
+    return_expr: IrReturnEnum,
+    obj_references: Vec<PropertyOrUDF>,
+    // the first error found
+    error: Option<CompileError>,
+}
+
+impl Default for ReturnVisitor {
+    fn default() -> Self {
+        ReturnVisitor {
+            return_expr: IrReturnEnum::default(),
+            obj_references: Vec::new(),
+            error: None,
+        }
+    }
+}
+
+impl<'i> ParseTreeVisitor<'i, CypherParserContextType> for ReturnVisitor {
+    fn visit_terminal(&mut self, _node: &TerminalNode<'i, CypherParserContextType>) {}
+}
+
+impl<'i> ReturnVisitor {
+    fn projection_items_to_return(
+        &mut self,
+        ctx: &OC_ProjectionItemsContext<'i>,
+    ) -> CompileResult<IrReturnEnum> {
         let proj_items = ctx.oC_ProjectionItem_all();
-        let proj_items_len = proj_items.len();
-        if proj_items_len == 1 {
-            proj_items[0].accept(self);
-            if self.obj_references.is_empty() {
-                log::error!("Return term has unexpected format or was not found.");
-                process::exit(1)
+        let mut columns: Vec<(String, PropertyOrUDF)> = Vec::new();
+        let mut aliases = Vec::new();
+        for proj_item in &proj_items {
+            self.obj_references.clear();
+            proj_item.accept(self);
+            if let Some(err) = self.error.take() {
+                return Err(err);
             }
-            let return_item = self.obj_references[0].clone();
-            // return a value
-            self.return_expr = IrReturnEnum::PropertyOrUDF(return_item);
-        } else if proj_items_len == 2 {
-            proj_items[0].accept(self);
             if self.obj_references.is_empty() {
-                log::error!("Group term has unexpected format or was not found.");
-                process::exit(1)
+                return Err(error_at(
+                    &**proj_item,
+                    format!(
+                        "Return term {:?} has unexpected format or was not found.",
+                        proj_item.get_text()
+                    ),
+                ));
             }
             let return_item = self.obj_references[0].clone();
-            self.obj_references.clear();
-            proj_items[1].accept(self);
-            if self.obj_references.is_empty() {
-                log::error!("Agg term has unexpected format or was not found.");
-                process::exit(1)
+            // RETURN a.request.time AS start
+            let alias = proj_item.oC_Variable().map(|alias| alias.get_text());
+            let name = alias.clone().unwrap_or_else(|| return_item.to_ref_str());
+            if columns.iter().any(|(column, _)| *column == name) {
+                return Err(error_at(
+                    &**proj_item,
+                    format!("Return column {:?} is defined more than once.", name),
+                ));
             }
-            if let PropertyOrUDF::UdfCall(udf) = self.obj_references[0].clone() {
-                self.return_expr =
-                    IrReturnEnum::Aggregate(Aggregate::new_with_items(udf, vec![return_item]));
-            } else {
-                log::error!("Could not parse aggregation function");
-                process::exit(1);
+            columns.push((name, return_item));
+            aliases.push(alias);
+        }
+        match (columns.as_slice(), aliases.as_slice()) {
+            ([], _) => Err(error_at(
+                ctx,
+                "Return items have to be listed explicitly.".to_string(),
+            )),
+            // return a value
+            ([(_, return_item)], [None]) => Ok(IrReturnEnum::PropertyOrUDF(return_item.clone())),
+            // anything else is returned as a row with one column per item, whether
+            // one of them aggregates is decided by resolve_aggregates
+            _ => Ok(IrReturnEnum::Record(columns)),
+        }
+    }
+}
+
+impl<'i> CypherVisitor<'i> for ReturnVisitor {
+    // we do not want to visit matches in this case, ignore that part of the tree
+    // TODO: Apply the visitor directly to the RETURN body instead
+    fn visit_oC_Match(&mut self, _ctx: &OC_MatchContext<'i>) {}
+
+    /// All branches of a UNION report to the same result stream, so they have to
+    /// return the same items.
+    fn visit_oC_Union(&mut self, ctx: &OC_UnionContext<'i>) {
+        let previous = self.return_expr.clone();
+        self.visit_children(ctx);
+        if self.error.is_none() && self.return_expr != previous {
+            self.error.get_or_insert(error_at(
+                ctx,
+                "Every branch of a UNION has to return the same items".to_string(),
+            ));
+        }
+    }
+
+    fn visit_oC_AddOrSubtractExpression(&mut self, ctx: &OC_AddOrSubtractExpressionContext<'i>) {
+        log::debug!("Generating return item");
+        match arithmetic_expression(ctx) {
+            Ok(item) => self.obj_references.push(item),
+            Err(err) => {
+                self.error.get_or_insert(err);
+            }
+        }
+    }
+
+    fn visit_oC_ProjectionItems(&mut self, ctx: &OC_ProjectionItemsContext<'i>) {
+        match self.projection_items_to_return(ctx) {
+            Ok(return_expr) => self.return_expr = return_expr,
+            Err(err) => {
+                self.error.get_or_insert(err);
             }
-        } else {
-            log::error!("More than two return items not supported");
-            process::exit(1);
         }
     }
 
@@ -411,26 +1522,234 @@ impl<'i> CypherVisitor<'i> for ReturnVisitor {
 
 /// This is a function that aggregates all the functionality above;  it makes a visitor,
 /// visits everything in the query via accept, and then finds the map functions.
-pub fn visit_result(result: Rc<OC_CypherContextAll>, root_id: String) -> VisitorResults {
+pub fn visit_result(
+    result: Rc<OC_CypherContextAll>,
+    root_id: String,
+) -> CompileResult<VisitorResults> {
     let mut filter_visitor = FilterVisitor::default();
     let mut return_visitor = ReturnVisitor::default();
     let mut prop_visitor = PropertyAndUdfVisitor::default();
     let _res = result.accept(&mut filter_visitor);
-    // before we return, strip off the extra quotes if there are any
-    for attr_filter in &mut filter_visitor.attr_filters {
-        attr_filter.value.retain(|c| c != '\'');
-    }
+    filter_visitor.take_error()?;
     let _res = result.accept(&mut return_visitor);
+    if let Some(err) = return_visitor.error {
+        return Err(err);
+    }
     let _res = result.accept(&mut prop_visitor);
+    if let Some(err) = prop_visitor.error {
+        return Err(err);
+    }
 
-    VisitorResults {
+    Ok(VisitorResults {
         struct_filters: filter_visitor.struct_filters,
         attr_filters: filter_visitor.attr_filters,
+        attr_filter_branches: filter_visitor.attr_filter_branches,
+        expression_filters: filter_visitor.expression_filters,
         return_expr: return_visitor.return_expr,
         root_id,
         properties: prop_visitor.properties,
         udf_calls: prop_visitor.udf_calls,
+        parameters: prop_visitor.parameters,
+        spans: prop_visitor.spans,
+    })
+}
+
+/***********************************/
+// Parameters:  replaces $parameters with the values given on the command line
+/***********************************/
+
+/// The literal a parameter stands for.  Its value is typed as in attribute
+/// filters, so that $x is the same value wherever it appears, and a string,
+/// such as the one of --param workload=reviews-v1, is quoted.
+fn param_literal(name: &str, params: &IndexMap<String, String>) -> String {
+    Value::from_param(&params[name]).to_string()
+}
+
+fn bind_udf_call(call: &mut UdfCall, params: &IndexMap<String, String>) {
+    for arg in &mut call.args {
+        match arg {
+            UdfArg::Parameter(name) => *arg = UdfArg::Literal(param_literal(name, params)),
+            UdfArg::UdfCall(arg_call) => bind_udf_call(arg_call, params),
+            UdfArg::Property(_) | UdfArg::Literal(_) => {}
+        }
+    }
+}
+
+fn bind_item(item: &mut PropertyOrUDF, params: &IndexMap<String, String>) {
+    match item {
+        PropertyOrUDF::Parameter(name) => {
+            *item = PropertyOrUDF::Literal(param_literal(name, params))
+        }
+        PropertyOrUDF::UdfCall(call) => bind_udf_call(call, params),
+        PropertyOrUDF::Arithmetic(arithmetic) => {
+            bind_item(&mut arithmetic.left, params);
+            bind_item(&mut arithmetic.right, params);
+        }
+        PropertyOrUDF::Property(_) | PropertyOrUDF::Literal(_) => {}
+    }
+}
+
+/// Whether a UDF call is an aggregation depends on how the UDF is declared, which
+/// the parser does not know.  RETURN node.property, aggregation_function(...)
+/// aggregates per node.property, so the calls of aggregation UDFs at the end of
+/// the return items become Aggregates grouped by the items before them.
+pub fn resolve_aggregates(
+    query_data: &mut VisitorResults,
+    aggregation_udf_table: &IndexMap<String, AggregationUdf>,
+) -> CompileResult<()> {
+    let columns = match &query_data.return_expr {
+        IrReturnEnum::Record(columns) => columns.clone(),
+        // RETURN aggregation_function(...) aggregates over every match
+        IrReturnEnum::PropertyOrUDF(item) => vec![(item.to_ref_str(), item.clone())],
+        IrReturnEnum::Aggregate(_) => return Ok(()),
+    };
+    let is_aggregation = |item: &PropertyOrUDF| match item {
+        PropertyOrUDF::UdfCall(call) => aggregation_udf_table.contains_key(&call.id),
+        _ => false,
+    };
+    let error_at = |ref_str: &str, message: String| match query_data.spans.get(ref_str) {
+        Some(span) => CompileError::at(span, message),
+        None => CompileError::new(message),
+    };
+    let first = match columns.iter().position(|(_, item)| is_aggregation(item)) {
+        Some(first) => first,
+        None => return Ok(()),
+    };
+    let group_by = &columns[..first];
+    let mut aggregates: Vec<Aggregate> = Vec::new();
+    for (name, item) in &columns[first..] {
+        let udf = match item {
+            PropertyOrUDF::UdfCall(udf) if is_aggregation(item) => udf,
+            _ => {
+                return Err(error_at(
+                    &item.to_ref_str(),
+                    format!(
+                        "Return item {} has to come before the aggregations",
+                        item.to_ref_str()
+                    ),
+                ))
+            }
+        };
+        // the aggregation filter passes one value at a time to the UDF
+        if udf.args.len() != 1 {
+            return Err(error_at(
+                &udf.to_ref_str(),
+                format!(
+                    "Aggregation {} has to be passed exactly one value",
+                    udf.to_ref_str()
+                ),
+            ));
+        }
+        let mut args = Vec::new();
+        for arg in &udf.args {
+            match arg {
+                UdfArg::Property(prop) => args.push(PropertyOrUDF::Property(prop.clone())),
+                UdfArg::UdfCall(call) => args.push(PropertyOrUDF::UdfCall(call.clone())),
+                UdfArg::Literal(_) | UdfArg::Parameter(_) => {
+                    return Err(error_at(
+                        &udf.to_ref_str(),
+                        format!(
+                            "Literal {} can not be aggregated by {:?}",
+                            arg.to_ref_str(),
+                            udf.id
+                        ),
+                    ));
+                }
+            }
+        }
+        let mut aggregate = Aggregate::new_with_items(udf.clone(), args);
+        if *name != udf.to_ref_str() {
+            aggregate.alias = Some(name.clone());
+        }
+        aggregate.group_by = group_by.to_vec();
+        // each aggregation keeps its state and reports to storage under its key
+        if let Some(other) = aggregates
+            .iter()
+            .find(|other| other.key() == aggregate.key())
+        {
+            return Err(error_at(
+                &udf.to_ref_str(),
+                format!(
+                    "Aggregations {} and {} are both stored under {:?}, name one of them with AS",
+                    other.udf_reference.to_ref_str(),
+                    udf.to_ref_str(),
+                    aggregate.key()
+                ),
+            ));
+        }
+        aggregates.push(aggregate);
     }
+    query_data.return_expr = IrReturnEnum::Aggregate(aggregates);
+    Ok(())
+}
+
+/// Substitutes the values of the query's $parameters, so that code generation
+/// only sees literals.  Every parameter in the query must be given a value, and
+/// every value given must be used by the query.
+pub fn bind_parameters(
+    query_data: &mut VisitorResults,
+    params: &IndexMap<String, String>,
+) -> CompileResult<()> {
+    if let Some(name) = query_data
+        .parameters
+        .iter()
+        .find(|name| !params.contains_key(name.as_str()))
+    {
+        return Err(CompileError::new(format!(
+            "Parameter ${} has no value. Pass it with --param {}=<value>",
+            name, name
+        )));
+    }
+    if let Some(name) = params
+        .keys()
+        .find(|name| !query_data.parameters.contains(name.as_str()))
+    {
+        return Err(CompileError::new(format!(
+            "Parameter ${} is not used in the query",
+            name
+        )));
+    }
+
+    for predicate in &mut query_data.attr_filters {
+        for attr_filter in predicate.attributes_mut() {
+            if let Value::Parameter(name) = &attr_filter.value {
+                attr_filter.value = Value::from_param(&params[name.as_str()]);
+                attr_filter.check_regex().map_err(CompileError::new)?;
+            }
+        }
+    }
+    for filter in &mut query_data.expression_filters {
+        bind_item(&mut filter.left, params);
+        bind_item(&mut filter.right, params);
+    }
+    match &mut query_data.return_expr {
+        IrReturnEnum::PropertyOrUDF(item) => bind_item(item, params),
+        IrReturnEnum::Record(columns) => {
+            for (_, item) in columns {
+                bind_item(item, params);
+            }
+        }
+        IrReturnEnum::Aggregate(aggregates) => {
+            for aggregate in aggregates {
+                bind_udf_call(&mut aggregate.udf_reference, params);
+                for item in &mut aggregate.args {
+                    bind_item(item, params);
+                }
+                for (_, item) in &mut aggregate.group_by {
+                    bind_item(item, params);
+                }
+            }
+        }
+    }
+    query_data.udf_calls = query_data
+        .udf_calls
+        .drain(..)
+        .map(|mut call| {
+            bind_udf_call(&mut call, params);
+            call
+        })
+        .collect();
+    Ok(())
 }
 
 #[cfg(test)]
@@ -438,9 +1757,11 @@ mod tests {
     use super::*;
     use crate::antlr_gen::lexer::CypherLexer;
     use crate::antlr_gen::parser::CypherParser;
+    use crate::codegen_common::udf_tables;
     use antlr_rust::common_token_stream::CommonTokenStream;
     use antlr_rust::token_factory::CommonTokenFactory;
     use antlr_rust::InputStream;
+    use indexmap::IndexMap;
 
     fn run_parser<'a>(tf: &'a CommonTokenFactory, input: &'a str) -> Rc<OC_CypherContextAll<'a>> {
         let query_stream = InputStream::new_owned(input.to_string().into_boxed_str());
@@ -451,6 +1772,24 @@ mod tests {
         return result;
     }
 
+    static AVG: &str = "
+    // udf_type: Aggregation
+    // init_func: new
+    // exec_func: execute
+    // struct_name: Avg
+    // id: avg
+    ";
+
+    /// The IR of a query with avg declared as an aggregation UDF.
+    fn resolved_query(input: &str) -> CompileResult<VisitorResults> {
+        let tf = CommonTokenFactory::default();
+        let result = run_parser(&tf, input);
+        let mut query_data = visit_result(result, "a".to_string())?;
+        let (_, aggregation_udf_table) = udf_tables(&[AVG.to_string()])?;
+        resolve_aggregates(&mut query_data, &aggregation_udf_table)?;
+        Ok(query_data)
+    }
+
     #[test]
     fn test_structural_filter() {
         // non branching
@@ -503,94 +1842,987 @@ mod tests {
         let mut visitor = FilterVisitor::default();
         let _res = result.accept(&mut visitor);
         assert!(visitor.attr_filters.len() == 1);
-        assert!(visitor.attr_filters.contains(&AttributeFilter {
-            node: "a".to_string(),
-            property: ".request.total_size".to_string(),
-            value: "5".to_string()
-        }));
+        assert!(visitor
+            .attr_filters
+            .contains(&Predicate::Attribute(AttributeFilter {
+                node: "a".to_string(),
+                property: ".request.total_size".to_string(),
+                op: CompareOp::Eq,
+                value: Value::Int(5)
+            })));
     }
 
     #[test]
-    fn test_properties_and_udf_calls() {
+    fn test_attribute_filter_comparison_ops() {
         let tf = CommonTokenFactory::default();
         let result = run_parser(
             &tf,
-            "MATCH (a) -[]-> (b)-[]->(c) WHERE height(a) = 2 RETURN a.workload_name",
+            "MATCH (a) -[]-> (b) WHERE a.response.code >= 500 AND b.response.code <> 200 AND height(a) < 3 RETURN a.workload_name",
         );
-        let mut visitor = PropertyAndUdfVisitor::default();
-        let _res = result.accept(&mut visitor);
-        assert!(visitor.properties.len() == 1);
-        assert!(visitor.properties.contains(&Property {
-            parent: "a".to_string(),
-            members: vec!["workload_name".to_string()]
-        }));
-        assert!(visitor.udf_calls.len() == 1);
-        assert!(visitor.udf_calls.contains(&UdfCall {
-            id: "height".to_string(),
-            args: vec!["a".to_string()]
-        }));
-
-        // check that attribute part of regular visitor is working properly too
         let mut visitor = FilterVisitor::default();
         let _res = result.accept(&mut visitor);
         assert!(
-            visitor.attr_filters.len() == 1,
+            visitor.attr_filters.len() == 3,
             "attr filters are {:?}",
             visitor.attr_filters
         );
-        assert!(
-            visitor.attr_filters.contains(&AttributeFilter {
+        assert!(visitor
+            .attr_filters
+            .contains(&Predicate::Attribute(AttributeFilter {
+                node: "a".to_string(),
+                property: ".response.code".to_string(),
+                op: CompareOp::Ge,
+                value: Value::Int(500)
+            })));
+        assert!(visitor
+            .attr_filters
+            .contains(&Predicate::Attribute(AttributeFilter {
+                node: "b".to_string(),
+                property: ".response.code".to_string(),
+                op: CompareOp::Ne,
+                value: Value::Int(200)
+            })));
+        assert!(visitor
+            .attr_filters
+            .contains(&Predicate::Attribute(AttributeFilter {
                 node: "a".to_string(),
                 property: "height".to_string(),
-                value: "2".to_string()
-            }),
-            "attr filter is {:?}",
-            visitor.attr_filters[0]
-        );
+                op: CompareOp::Lt,
+                value: Value::Int(3)
+            })));
+    }
 
-        // copying another one here that seems to be failing?
+    #[test]
+    fn test_string_predicates() {
         let tf = CommonTokenFactory::default();
         let result = run_parser(
             &tf,
-            "MATCH (a) -[]-> (b)-[]->(c) WHERE c.node.metadata.WORKLOAD_NAME = 'ratings-v1' RETURN height(a), avg(height(a))"
+            "MATCH (a) -[]-> (b) WHERE a.request.path STARTS WITH '/api/v2' AND b.request.path ENDS WITH '.json' AND NOT a.request.host CONTAINS 'test' AND trace.request.useragent =~ '.*curl.*' RETURN a.workload_name",
         );
-        let mut visitor = PropertyAndUdfVisitor::default();
+        let mut visitor = FilterVisitor::default();
         let _res = result.accept(&mut visitor);
+        assert_eq!(
+            visitor.attr_filters,
+            vec![
+                Predicate::Attribute(AttributeFilter {
+                    node: "a".to_string(),
+                    property: ".request.path".to_string(),
+                    op: CompareOp::StartsWith,
+                    value: Value::Str("/api/v2".to_string())
+                }),
+                Predicate::Attribute(AttributeFilter {
+                    node: "b".to_string(),
+                    property: ".request.path".to_string(),
+                    op: CompareOp::EndsWith,
+                    value: Value::Str(".json".to_string())
+                }),
+                Predicate::Not(Box::new(Predicate::Attribute(AttributeFilter {
+                    node: "a".to_string(),
+                    property: ".request.host".to_string(),
+                    op: CompareOp::Contains,
+                    value: Value::Str("test".to_string())
+                }))),
+                Predicate::Attribute(AttributeFilter {
+                    node: "trace".to_string(),
+                    property: ".request.useragent".to_string(),
+                    op: CompareOp::RegexMatch,
+                    value: Value::Str(".*curl.*".to_string())
+                }),
+            ]
+        );
+
+        // invalid patterns are rejected before any filter runs
+        let tf = CommonTokenFactory::default();
+        let result = run_parser(
+            &tf,
+            "MATCH (a) WHERE a.request.path =~ '(api' RETURN a.request.path",
+        );
+        let err = visit_result(result, "a".to_string()).unwrap_err();
+        assert!(err
+            .message
+            .starts_with("Invalid regular expression \"(api\""));
+        assert!(err.span.is_some());
     }
 
     #[test]
-    fn test_return_expr() {
-        // regular return
+    fn test_list_and_null_predicates() {
         let tf = CommonTokenFactory::default();
         let result = run_parser(
             &tf,
-            "MATCH (a) -[]-> (b)-[]->(c) WHERE height(a) = 2 RETURN a.request.total_size",
+            "MATCH (a) -[]-> (b) WHERE b.node.metadata.WORKLOAD_NAME IN ['reviews-v2','reviews-v3'] AND a.response.grpc_status IS NOT NULL AND b.response.grpc_status IS NULL RETURN a.workload_name",
         );
-        let mut return_visitor = ReturnVisitor::default();
-        let _res = result.accept(&mut return_visitor);
-        assert!(
-            return_visitor.return_expr
-                == IrReturnEnum::PropertyOrUDF(PropertyOrUDF::Property({
-                    Property {
-                        parent: "a".to_string(),
-                        members: vec!["request".to_string(), "total_size".to_string()],
-                    }
-                }))
+        let mut visitor = FilterVisitor::default();
+        let _res = result.accept(&mut visitor);
+        let workload_name = |value: &str| {
+            Predicate::Attribute(AttributeFilter {
+                node: "b".to_string(),
+                property: ".node.metadata.WORKLOAD_NAME".to_string(),
+                op: CompareOp::Eq,
+                value: Value::Str(value.to_string()),
+            })
+        };
+        assert_eq!(
+            visitor.attr_filters,
+            vec![
+                Predicate::Or(vec![
+                    workload_name("reviews-v2"),
+                    workload_name("reviews-v3")
+                ]),
+                Predicate::Exists(ExistenceFilter {
+                    node: "a".to_string(),
+                    property: ".response.grpc_status".to_string(),
+                }),
+                Predicate::Not(Box::new(Predicate::Exists(ExistenceFilter {
+                    node: "b".to_string(),
+                    property: ".response.grpc_status".to_string(),
+                }))),
+            ]
         );
 
-        // aggregation
+        // no value is in an empty list
+        let result = run_parser(
+            &tf,
+            "MATCH (a) WHERE a.node.metadata.WORKLOAD_NAME IN [] RETURN a.request.path",
+        );
+        let err = visit_result(result, "a".to_string()).unwrap_err();
+        assert!(err.message.starts_with("IN needs at least one value"));
+        assert!(err.span.is_some());
+    }
+
+    #[test]
+    fn test_query_parameters() {
         let tf = CommonTokenFactory::default();
         let result = run_parser(
             &tf,
-            "MATCH (a) -[]-> (b)-[]->(c) WHERE height(a) = 2 RETURN a.request.total_size, avg(a.request.total_size)",
+            "MATCH (a) -[]-> (b) WHERE a.response.total_size > $threshold AND b.response.code IN [$code, 404] RETURN a.response.total_size * $scale",
+        );
+        let mut query_data = visit_result(result, "a".to_string()).unwrap();
+        let names: Vec<&str> = query_data.parameters.iter().map(|s| s.as_str()).collect();
+        assert_eq!(names, vec!["threshold", "code", "scale"]);
+
+        let mut params = IndexMap::new();
+        params.insert("threshold".to_string(), "1000".to_string());
+        params.insert("code".to_string(), "200".to_string());
+        params.insert("scale".to_string(), "2".to_string());
+        bind_parameters(&mut query_data, &params).unwrap();
+        let response_code = |value: i64| {
+            Predicate::Attribute(AttributeFilter {
+                node: "b".to_string(),
+                property: ".response.code".to_string(),
+                op: CompareOp::Eq,
+                value: Value::Int(value),
+            })
+        };
+        assert_eq!(
+            query_data.attr_filters,
+            vec![
+                Predicate::Attribute(AttributeFilter {
+                    node: "a".to_string(),
+                    property: ".response.total_size".to_string(),
+                    op: CompareOp::Gt,
+                    value: Value::Int(1000),
+                }),
+                Predicate::Or(vec![response_code(200), response_code(404)]),
+            ]
+        );
+        if let IrReturnEnum::PropertyOrUDF(PropertyOrUDF::Arithmetic(arithmetic)) =
+            &query_data.return_expr
+        {
+            assert_eq!(*arithmetic.right, PropertyOrUDF::Literal("2".to_string()));
+        } else {
+            assert!(false, "did not recognize arithmetic return");
+        }
+
+        // parameters are typed the same everywhere, so a string is quoted
+        let result = run_parser(
+            &tf,
+            "MATCH (a) WHERE a.node.metadata.WORKLOAD_NAME = $name RETURN $name",
+        );
+        let mut query_data = visit_result(result, "a".to_string()).unwrap();
+        let mut params = IndexMap::new();
+        params.insert("name".to_string(), "reviews-v1".to_string());
+        bind_parameters(&mut query_data, &params).unwrap();
+        assert_eq!(
+            query_data.return_expr,
+            IrReturnEnum::PropertyOrUDF(PropertyOrUDF::Literal("\"reviews-v1\"".to_string()))
+        );
+
+        // a quoted $ is part of a string, not a parameter
+        let tf = CommonTokenFactory::default();
+        let result = run_parser(
+            &tf,
+            "MATCH (a {service_name: '$name'}) WHERE a.request.path = '$foo' RETURN a.request.path",
+        );
+        let mut query_data = visit_result(result, "a".to_string()).unwrap();
+        assert!(query_data.parameters.is_empty());
+        bind_parameters(&mut query_data, &IndexMap::new()).unwrap();
+        let values: Vec<Value> = query_data
+            .attr_filters
+            .iter()
+            .map(|predicate| match predicate {
+                Predicate::Attribute(attr_filter) => attr_filter.value.clone(),
+                _ => panic!("unexpected predicate {:?}", predicate),
+            })
+            .collect();
+        assert_eq!(
+            values,
+            vec![
+                Value::Str("$name".to_string()),
+                Value::Str("$foo".to_string())
+            ]
+        );
+        assert_eq!(Value::from_param("$foo"), Value::Str("$foo".to_string()));
+    }
+
+    #[test]
+    fn test_boolean_predicates() {
+        let tf = CommonTokenFactory::default();
+        let result = run_parser(
+            &tf,
+            "MATCH (a) -[]-> (b) WHERE (b.node.metadata.WORKLOAD_NAME = 'reviews-v2' OR b.node.metadata.WORKLOAD_NAME = 'reviews-v3') AND NOT a.response.code = 200 RETURN a.workload_name",
+        );
+        let mut visitor = FilterVisitor::default();
+        let _res = result.accept(&mut visitor);
+        assert!(
+            visitor.attr_filters.len() == 2,
+            "attr filters are {:?}",
+            visitor.attr_filters
+        );
+        let workload_filter = |value: &str| {
+            Predicate::Attribute(AttributeFilter {
+                node: "b".to_string(),
+                property: ".node.metadata.WORKLOAD_NAME".to_string(),
+                op: CompareOp::Eq,
+                value: Value::Str(value.to_string()),
+            })
+        };
+        assert!(
+            visitor.attr_filters[0]
+                == Predicate::Or(vec![
+                    workload_filter("reviews-v2"),
+                    workload_filter("reviews-v3")
+                ]),
+            "first conjunct is {:?}",
+            visitor.attr_filters[0]
+        );
+        assert!(
+            visitor.attr_filters[1]
+                == Predicate::Not(Box::new(Predicate::Attribute(AttributeFilter {
+                    node: "a".to_string(),
+                    property: ".response.code".to_string(),
+                    op: CompareOp::Eq,
+                    value: Value::Int(200),
+                }))),
+            "second conjunct is {:?}",
+            visitor.attr_filters[1]
+        );
+
+        let tf = CommonTokenFactory::default();
+        let result = run_parser(
+            &tf,
+            "MATCH (a) -[]-> (b) WHERE a.response.code = 200 XOR a.response.code = 404 RETURN a.workload_name",
+        );
+        let mut visitor = FilterVisitor::default();
+        let _res = result.accept(&mut visitor);
+        assert!(visitor.attr_filters.len() == 1);
+        if let Predicate::Xor(operands) = &visitor.attr_filters[0] {
+            assert!(operands.len() == 2);
+        } else {
+            assert!(
+                false,
+                "did not recognize XOR, got {:?}",
+                visitor.attr_filters[0]
+            );
+        }
+
+        // conditions are checked on one node, so they can not be combined across nodes
+        let tf = CommonTokenFactory::default();
+        let result = run_parser(
+            &tf,
+            "MATCH (a) -[]-> (b) WHERE a.response.code = 200 XOR trace.request.total_size > 10 RETURN a.workload_name",
+        );
+        let err = visit_result(result, "a".to_string()).unwrap_err();
+        assert!(
+            err.message
+                .contains("can only combine conditions on the same node"),
+            "error is {:?}",
+            err.message
+        );
+        assert!(err.span.is_some());
+        let tf = CommonTokenFactory::default();
+        let result = run_parser(
+            &tf,
+            "MATCH (a) -[]-> (b) WHERE NOT (a.response.code = 200 AND b.response.code = 200) RETURN a.workload_name",
+        );
+        assert!(visit_result(result, "a".to_string()).is_err());
+
+        // nested groups of conjunctions are split into their conjuncts
+        let tf = CommonTokenFactory::default();
+        let result = run_parser(
+            &tf,
+            "MATCH (a) -[]-> (b) WHERE (a.response.code = 200 AND ((b.response.code = 404 OR b.response.code = 503) AND a.request.total_size > 10)) RETURN a.workload_name",
+        );
+        let mut visitor = FilterVisitor::default();
+        let _res = result.accept(&mut visitor);
+        assert_eq!(visitor.attr_filters.len(), 3);
+        assert!(matches!(visitor.attr_filters[1], Predicate::Or(_)));
+        assert!(visitor
+            .attr_filters
+            .iter()
+            .all(|predicate| predicate.nodes().len() == 1));
+    }
+
+    #[test]
+    fn test_inline_properties() {
+        let tf = CommonTokenFactory::default();
+        let result = run_parser(
+            &tf,
+            "MATCH (a {service_name: \"productpage-v1\"}) -[]-> (b {`request.total_size`: 5}) RETURN a.request.total_size",
+        );
+        let results = visit_result(result, "productpage-v1".to_string()).unwrap();
+        assert!(
+            results.attr_filters.len() == 2,
+            "attr filters are {:?}",
+            results.attr_filters
+        );
+        assert!(results
+            .attr_filters
+            .contains(&Predicate::Attribute(AttributeFilter {
+                node: "a".to_string(),
+                property: ".service_name".to_string(),
+                op: CompareOp::Eq,
+                value: Value::Str("productpage-v1".to_string())
+            })));
+        assert!(results
+            .attr_filters
+            .contains(&Predicate::Attribute(AttributeFilter {
+                node: "b".to_string(),
+                property: ".request.total_size".to_string(),
+                op: CompareOp::Eq,
+                value: Value::Int(5)
+            })));
+        assert!(results.properties.contains(&Property {
+            parent: "b".to_string(),
+            members: vec!["request".to_string(), "total_size".to_string()]
+        }));
+        assert!(results.properties.contains(&Property {
+            parent: "a".to_string(),
+            members: vec!["service_name".to_string()]
+        }));
+    }
+
+    #[test]
+    fn test_node_labels() {
+        let tf = CommonTokenFactory::default();
+        let result = run_parser(
+            &tf,
+            "MATCH (a:productpage-v1)-->(b:`reviews-v1`) RETURN a.request.total_size",
+        );
+        let mut visitor = FilterVisitor::default();
+        let _res = result.accept(&mut visitor);
+        let mut correct_vertices = IndexSet::new();
+        correct_vertices.insert("a".to_string());
+        correct_vertices.insert("b".to_string());
+        assert!(visitor.struct_filters[0].vertices == correct_vertices);
+        assert!(
+            visitor.attr_filters
+                == vec![
+                    Predicate::Attribute(AttributeFilter {
+                        node: "a".to_string(),
+                        property: ".node.metadata.WORKLOAD_NAME".to_string(),
+                        op: CompareOp::Eq,
+                        value: Value::Str("productpage-v1".to_string())
+                    }),
+                    Predicate::Attribute(AttributeFilter {
+                        node: "b".to_string(),
+                        property: ".node.metadata.WORKLOAD_NAME".to_string(),
+                        op: CompareOp::Eq,
+                        value: Value::Str("reviews-v1".to_string())
+                    }),
+                ],
+            "attr filters are {:?}",
+            visitor.attr_filters
+        );
+    }
+
+    #[test]
+    fn test_edge_directions() {
+        let tf = CommonTokenFactory::default();
+        let result = run_parser(
+            &tf,
+            "MATCH (a)<--(b)-->(c)--(d) RETURN a.request.total_size",
+        );
+        let mut visitor = FilterVisitor::default();
+        let _res = result.accept(&mut visitor);
+        let mut correct_edges = IndexSet::new();
+        correct_edges.insert(("b".to_string(), "a".to_string()));
+        correct_edges.insert(("b".to_string(), "c".to_string()));
+        assert!(visitor.struct_filters[0].edges == correct_edges);
+        let mut correct_undirected_edges = IndexSet::new();
+        correct_undirected_edges.insert(("c".to_string(), "d".to_string()));
+        assert!(visitor.struct_filters[0].undirected_edges == correct_undirected_edges);
+    }
+
+    #[test]
+    fn test_error_spans() {
+        let tf = CommonTokenFactory::default();
+        let result = run_parser(&tf, "MATCH (a) <-[]-> (b) RETURN a.request.total_size");
+        let err = visit_result(result, "a".to_string()).unwrap_err();
+        assert_eq!(
+            err.span,
+            Some(Span {
+                line: 1,
+                column: 10,
+                len: 6
+            })
+        );
+
+        let tf = CommonTokenFactory::default();
+        let result = run_parser(
+            &tf,
+            "MATCH (a) -[]-> (b)\nRETURN a.request.total_size, a.request.total_size",
+        );
+        let err = visit_result(result, "a".to_string()).unwrap_err();
+        assert_eq!(
+            err.message,
+            "Return column \"a.request.total_size\" is defined more than once."
+        );
+        assert_eq!(err.span.map(|span| (span.line, span.column)), Some((2, 29)));
+    }
+
+    #[test]
+    fn test_variable_length_paths() {
+        let tf = CommonTokenFactory::default();
+        let result = run_parser(
+            &tf,
+            "MATCH (b)<-[*1..3]-(a)-[*]->(c)-[*2]->(d)-->(e) RETURN a.request.total_size",
+        );
+        let mut visitor = FilterVisitor::default();
+        let _res = result.accept(&mut visitor);
+        let mut correct_hops = IndexMap::new();
+        correct_hops.insert(
+            ("a".to_string(), "b".to_string()),
+            Hops {
+                min: 1,
+                max: Some(3),
+            },
+        );
+        correct_hops.insert(
+            ("a".to_string(), "c".to_string()),
+            Hops { min: 1, max: None },
+        );
+        correct_hops.insert(
+            ("c".to_string(), "d".to_string()),
+            Hops {
+                min: 2,
+                max: Some(2),
+            },
+        );
+        assert!(
+            visitor.struct_filters[0].edge_hops == correct_hops,
+            "edge hops are {:?}",
+            visitor.struct_filters[0].edge_hops
+        );
+        assert!(
+            Hops::from_range_literal("*..4")
+                == Ok(Hops {
+                    min: 1,
+                    max: Some(4)
+                })
+        );
+        assert!(Hops::from_range_literal("*2..") == Ok(Hops { min: 2, max: None }));
+        assert!(Hops::from_range_literal("*0..2").is_err());
+        assert!(Hops::from_range_literal("*3..2").is_err());
+
+        // paths are matched from caller to callee, so they need a direction, and
+        // can not lead to a node that is called by something else as well
+        for query in &[
+            "MATCH (a)-[*2]-(b) RETURN a.request.total_size",
+            "MATCH (a)-[*1..3]->(b)<-[*]-(c) RETURN a.request.total_size",
+            "MATCH (a)-[*]->(b), (c)-->(b) RETURN a.request.total_size",
+        ] {
+            let tf = CommonTokenFactory::default();
+            let result = run_parser(&tf, query);
+            let err = visit_result(result, "a".to_string()).unwrap_err();
+            assert!(err.span.is_some(), "{}: {}", query, err.message);
+        }
+    }
+
+    #[test]
+    fn test_relationship_variables() {
+        let tf = CommonTokenFactory::default();
+        let result = run_parser(
+            &tf,
+            "MATCH (a)-[r]->(b)<-[s]-(c) WHERE r.response.code = 200 RETURN s.request.duration",
+        );
+        let query_data = visit_result(result, "a".to_string()).unwrap();
+        let mut correct_relationships = IndexMap::new();
+        correct_relationships.insert("r".to_string(), ("a".to_string(), "b".to_string()));
+        correct_relationships.insert("s".to_string(), ("c".to_string(), "b".to_string()));
+        assert_eq!(
+            query_data.struct_filters[0].relationships,
+            correct_relationships
+        );
+        assert_eq!(
+            query_data.relationship("s"),
+            Some(&("c".to_string(), "b".to_string()))
+        );
+        assert!(query_data.relationship("a").is_none());
+        assert_eq!(query_data.attr_filters[0].nodes().len(), 1);
+        assert!(query_data.attr_filters[0].nodes().contains("r"));
+
+        let tf = CommonTokenFactory::default();
+        let result = run_parser(&tf, "MATCH (a)-[r*1..3]->(b) RETURN r.response.code");
+        let err = visit_result(result, "a".to_string()).unwrap_err();
+        assert_eq!(
+            err.message,
+            "Relationship variable \"r\" can not be used with a range, since it would name more than one call"
+        );
+
+        let tf = CommonTokenFactory::default();
+        let result = run_parser(&tf, "MATCH (a)-[r]->(b)-[r]->(c) RETURN a.response.code");
+        let err = visit_result(result, "a".to_string()).unwrap_err();
+        assert_eq!(err.message, "Variable \"r\" is bound more than once");
+    }
+
+    #[test]
+    fn test_optional_match() {
+        let tf = CommonTokenFactory::default();
+        let result = run_parser(
+            &tf,
+            "MATCH (a)-->(b) OPTIONAL MATCH (b)-[r]->(c) WHERE c.response.code = 500 RETURN a.request.path, r.response.code",
+        );
+        let query_data = visit_result(result, "a".to_string()).unwrap();
+        let struct_filter = &query_data.struct_filters[0];
+        assert_eq!(struct_filter.vertices.len(), 3);
+        assert_eq!(struct_filter.optional_vertices.get("c"), Some(&0));
+        assert!(!struct_filter.optional_vertices.contains_key("b"));
+        assert_eq!(
+            struct_filter
+                .optional_edges
+                .get(&("b".to_string(), "c".to_string())),
+            Some(&0)
+        );
+        assert_eq!(query_data.optional_nodes("r"), vec!["c".to_string()]);
+        assert!(query_data.optional_nodes("a").is_empty());
+        assert_eq!(
+            struct_filter.optional_attachments(),
+            vec![("b".to_string(), "c".to_string())]
+        );
+
+        // a later OPTIONAL MATCH may attach to the nodes of an earlier one
+        let tf = CommonTokenFactory::default();
+        let result = run_parser(
+            &tf,
+            "MATCH (a)-->(b) OPTIONAL MATCH (b)-->(c)-->(d) OPTIONAL MATCH (c)-->(e) RETURN e.request.path",
+        );
+        let query_data = visit_result(result, "a".to_string()).unwrap();
+        assert_eq!(
+            query_data.struct_filters[0].optional_attachments(),
+            vec![
+                ("b".to_string(), "c".to_string()),
+                ("c".to_string(), "e".to_string())
+            ]
+        );
+
+        for query in &[
+            "MATCH (a)-->(b) OPTIONAL MATCH (c)-->(b) RETURN c.request.path",
+            "MATCH (a)-->(b) OPTIONAL MATCH (b)--(c) RETURN c.request.path",
+            "MATCH (a)-->(b) OPTIONAL MATCH (a)-->(c)<--(b) RETURN c.request.path",
+        ] {
+            let tf = CommonTokenFactory::default();
+            let result = run_parser(&tf, query);
+            let err = visit_result(result, "a".to_string()).unwrap_err();
+            assert!(err.message.starts_with(
+                "OPTIONAL MATCH has to attach the nodes it adds with a single call from a node matched before it"
+            ));
+        }
+        let tf = CommonTokenFactory::default();
+        let result = run_parser(
+            &tf,
+            "MATCH (a)-->(b) OPTIONAL MATCH (b)-->(c), (d)-->(e) RETURN c.request.path",
+        );
+        let err = visit_result(result, "a".to_string()).unwrap_err();
+        assert_eq!(
+            err.message,
+            "OPTIONAL MATCH has to add nodes connected to the ones matched before it"
+        );
+
+        let tf = CommonTokenFactory::default();
+        let result = run_parser(
+            &tf,
+            "MATCH (a)-->(b) OPTIONAL MATCH (b)-->(c) WHERE a.response.code = 500 RETURN c.request.path",
+        );
+        let err = visit_result(result, "a".to_string()).unwrap_err();
+        assert!(err.message.starts_with(
+            "Conditions in OPTIONAL MATCH may only refer to the nodes and relationships it adds"
+        ));
+
+        let tf = CommonTokenFactory::default();
+        let result = run_parser(
+            &tf,
+            "MATCH (a)-->(b) OPTIONAL MATCH (c)-->(d) RETURN c.request.path",
+        );
+        let err = visit_result(result, "a".to_string()).unwrap_err();
+        assert_eq!(
+            err.message,
+            "OPTIONAL MATCH has to add nodes connected to the ones matched before it"
+        );
+
+        let tf = CommonTokenFactory::default();
+        let result = run_parser(&tf, "OPTIONAL MATCH (a)-->(b) RETURN b.request.path");
+        let err = visit_result(result, "a".to_string()).unwrap_err();
+        assert_eq!(err.message, "OPTIONAL MATCH has to follow a MATCH");
+    }
+
+    #[test]
+    fn test_unconnected_patterns() {
+        let tf = CommonTokenFactory::default();
+        let result = run_parser(
+            &tf,
+            "MATCH (a)-->(b), (c)-->(d) MATCH (d)-->(e) WHERE e.response.code = 500 RETURN a.request.path",
+        );
+        let query_data = visit_result(result, "a".to_string()).unwrap();
+        // all parts of a pattern belong to the same trace
+        assert_eq!(query_data.struct_filters.len(), 1);
+        assert_eq!(query_data.struct_filters[0].vertices.len(), 5);
+        assert_eq!(query_data.struct_filters[0].edges.len(), 3);
+        assert_eq!(query_data.attr_filter_branches, vec![0]);
+    }
+
+    #[test]
+    fn test_typed_values() {
+        let tf = CommonTokenFactory::default();
+        let result = run_parser(
+            &tf,
+            "MATCH (a {service_name: '5'}) WHERE a.response.code = 5 AND a.connection.mlts = true AND a.request.size > -1 RETURN a.request.path",
+        );
+        let query_data = visit_result(result, "a".to_string()).unwrap();
+        let values: Vec<Value> = query_data
+            .attr_filters
+            .iter()
+            .map(|predicate| match predicate {
+                Predicate::Attribute(attr_filter) => attr_filter.value.clone(),
+                _ => panic!("unexpected predicate {:?}", predicate),
+            })
+            .collect();
+        // 5 and '5' are different values
+        assert_eq!(
+            values,
+            vec![
+                Value::Str("5".to_string()),
+                Value::Int(5),
+                Value::Bool(true),
+                Value::Int(-1)
+            ]
+        );
+        assert_eq!(
+            Value::from_param("reviews-v1"),
+            Value::Str("reviews-v1".to_string())
+        );
+        assert_eq!(Value::from_param("'500'"), Value::Str("500".to_string()));
+        assert_eq!(Value::from_param("500"), Value::Int(500));
+
+        // floats and durations, which are in nanoseconds
+        let tf = CommonTokenFactory::default();
+        let result = run_parser(
+            &tf,
+            "MATCH (a) WHERE a.response.code > 0.5 AND a.request.duration < duration('1.5ms') RETURN a.request.path",
+        );
+        let query_data = visit_result(result, "a".to_string()).unwrap();
+        assert!(query_data.expression_filters.is_empty());
+        let values: Vec<Value> = query_data
+            .attr_filters
+            .iter()
+            .map(|predicate| match predicate {
+                Predicate::Attribute(attr_filter) => attr_filter.value.clone(),
+                _ => panic!("unexpected predicate {:?}", predicate),
+            })
+            .collect();
+        assert_eq!(values, vec![Value::Float(0.5), Value::Duration(1_500_000)]);
+        assert_eq!(Value::Duration(1_500_000).to_string(), "duration('1500us')");
+        assert_eq!(
+            Value::from_literal("duration('10m')"),
+            Some(Value::Duration(600_000_000_000))
+        );
+        assert_eq!(Value::from_literal("duration('10 parsecs')"), None);
+        assert_eq!(Value::from_literal("inf"), None);
+
+        let tf = CommonTokenFactory::default();
+        let result = run_parser(
+            &tf,
+            "MATCH (a) WHERE a.response.code = [500] RETURN a.request.path",
+        );
+        let err = visit_result(result, "a".to_string()).unwrap_err();
+        assert!(
+            err.message.starts_with("Unsupported value \"[500]\""),
+            "error is {:?}",
+            err.message
+        );
+    }
+
+    #[test]
+    fn test_string_literals() {
+        let tf = CommonTokenFactory::default();
+        let result = run_parser(
+            &tf,
+            r#"MATCH (a) WHERE a.request.path = 'O\'Brien' AND a.request.host = "a\"b'c" RETURN a.request.path"#,
+        );
+        let query_data = visit_result(result, "a".to_string()).unwrap();
+        let values: Vec<Value> = query_data
+            .attr_filters
+            .iter()
+            .map(|predicate| match predicate {
+                Predicate::Attribute(attr_filter) => attr_filter.value.clone(),
+                _ => panic!("unexpected predicate {:?}", predicate),
+            })
+            .collect();
+        assert_eq!(
+            values,
+            vec![
+                Value::Str("O'Brien".to_string()),
+                Value::Str("a\"b'c".to_string())
+            ]
+        );
+        assert_eq!(
+            unquote(r"'tab\there\u00e9'"),
+            Some("tab\there\u{e9}".to_string())
+        );
+        assert_eq!(unquote("'$foo'"), Some("$foo".to_string()));
+        assert_eq!(unquote("500"), None);
+    }
+
+    #[test]
+    fn test_union() {
+        let tf = CommonTokenFactory::default();
+        let result = run_parser(
+            &tf,
+            "MATCH (a)-->(b) WHERE b.node.metadata.WORKLOAD_NAME = 'reviews-v1' RETURN a.request.path UNION MATCH (a)-->(b)-->(c) WHERE c.node.metadata.WORKLOAD_NAME = 'reviews-v3' RETURN a.request.path",
+        );
+        let query_data = visit_result(result, "a".to_string()).unwrap();
+        assert_eq!(query_data.struct_filters.len(), 2);
+        assert_eq!(query_data.struct_filters[1].vertices.len(), 3);
+        assert_eq!(query_data.attr_filter_branches, vec![0, 1]);
+        assert!(query_data.branch_attr_filters(1)[0].nodes().contains("c"));
+
+        let tf = CommonTokenFactory::default();
+        let result = run_parser(
+            &tf,
+            "MATCH (a)-->(b) RETURN a.request.path UNION MATCH (a)-->(b) RETURN b.request.path",
+        );
+        let err = visit_result(result, "a".to_string()).unwrap_err();
+        assert_eq!(
+            err.message,
+            "Every branch of a UNION has to return the same items"
+        );
+
+        let tf = CommonTokenFactory::default();
+        let result = run_parser(
+            &tf,
+            "MATCH (a)-->(b) WHERE trace.request.path = '/' RETURN a.request.path UNION MATCH (a)-->(b)-->(c) RETURN a.request.path",
+        );
+        let err = visit_result(result, "a".to_string()).unwrap_err();
+        assert_eq!(
+            err.message,
+            "Conditions on trace are not supported in a UNION"
+        );
+
+        // a trace is reported once, so the duplicates of UNION ALL are not kept
+        let tf = CommonTokenFactory::default();
+        let result = run_parser(
+            &tf,
+            "MATCH (a)-->(b) RETURN a.request.path UNION ALL MATCH (a)-->(b)-->(c) RETURN a.request.path",
+        );
+        let err = visit_result(result, "a".to_string()).unwrap_err();
+        assert!(err.message.starts_with("UNION ALL is not supported"));
+        assert!(err.span.is_some());
+    }
+
+    #[test]
+    fn test_properties_and_udf_calls() {
+        let tf = CommonTokenFactory::default();
+        let result = run_parser(
+            &tf,
+            "MATCH (a) -[]-> (b)-[]->(c) WHERE height(a) = 2 RETURN a.workload_name",
+        );
+        let mut visitor = PropertyAndUdfVisitor::default();
+        let _res = result.accept(&mut visitor);
+        assert!(visitor.properties.len() == 1);
+        assert!(visitor.properties.contains(&Property {
+            parent: "a".to_string(),
+            members: vec!["workload_name".to_string()]
+        }));
+        assert!(visitor.udf_calls.len() == 1);
+        assert!(visitor.udf_calls.contains(&UdfCall {
+            id: "height".to_string(),
+            args: vec![UdfArg::Property(Property {
+                parent: "a".to_string(),
+                members: Vec::new(),
+            })]
+        }));
+
+        // check that attribute part of regular visitor is working properly too
+        let mut visitor = FilterVisitor::default();
+        let _res = result.accept(&mut visitor);
+        assert!(
+            visitor.attr_filters.len() == 1,
+            "attr filters are {:?}",
+            visitor.attr_filters
+        );
+        assert!(
+            visitor
+                .attr_filters
+                .contains(&Predicate::Attribute(AttributeFilter {
+                    node: "a".to_string(),
+                    property: "height".to_string(),
+                    op: CompareOp::Eq,
+                    value: Value::Int(2)
+                })),
+            "attr filter is {:?}",
+            visitor.attr_filters[0]
+        );
+
+        // copying another one here that seems to be failing?
+        let tf = CommonTokenFactory::default();
+        let result = run_parser(
+            &tf,
+            "MATCH (a) -[]-> (b)-[]->(c) WHERE c.node.metadata.WORKLOAD_NAME = 'ratings-v1' RETURN height(a), avg(height(a))"
+        );
+        let mut visitor = PropertyAndUdfVisitor::default();
+        let _res = result.accept(&mut visitor);
+    }
+
+    #[test]
+    fn test_nested_udf_calls() {
+        let tf = CommonTokenFactory::default();
+        let result = run_parser(
+            &tf,
+            "MATCH (a) -[]-> (b) WHERE scale(height(a), a.request.total_size, 2) = 4 RETURN b.response.code",
+        );
+        let mut visitor = PropertyAndUdfVisitor::default();
+        let _res = result.accept(&mut visitor);
+        let height = UdfCall {
+            id: "height".to_string(),
+            args: vec![UdfArg::Property(Property {
+                parent: "a".to_string(),
+                members: Vec::new(),
+            })],
+        };
+        // arguments are visited before the call that uses them
+        let calls: Vec<&UdfCall> = visitor.udf_calls.iter().collect();
+        assert_eq!(calls.len(), 2);
+        assert_eq!(calls[0], &height);
+        assert_eq!(
+            calls[1].args,
+            vec![
+                UdfArg::UdfCall(height.clone()),
+                UdfArg::Property(Property {
+                    parent: "a".to_string(),
+                    members: vec!["request".to_string(), "total_size".to_string()],
+                }),
+                UdfArg::Literal("2".to_string()),
+            ]
+        );
+        assert!(visitor.properties.contains(&Property {
+            parent: "a".to_string(),
+            members: vec!["request".to_string(), "total_size".to_string()],
+        }));
+
+        let mut visitor = FilterVisitor::default();
+        let _res = result.accept(&mut visitor);
+        assert_eq!(
+            visitor.attr_filters,
+            vec![Predicate::Attribute(AttributeFilter {
+                node: "a".to_string(),
+                property: "scale".to_string(),
+                op: CompareOp::Eq,
+                value: Value::Int(4)
+            })]
+        );
+    }
+
+    #[test]
+    fn test_arithmetic_expressions() {
+        let tf = CommonTokenFactory::default();
+        let result = run_parser(
+            &tf,
+            "MATCH (a) -[]-> (b) WHERE b.response.total_size - a.request.total_size > 100 AND a.response.code = 200 RETURN (b.response.total_size - a.request.total_size) * 2 + -1",
+        );
+        let a_size = PropertyOrUDF::Property(Property {
+            parent: "a".to_string(),
+            members: vec!["request".to_string(), "total_size".to_string()],
+        });
+        let b_size = PropertyOrUDF::Property(Property {
+            parent: "b".to_string(),
+            members: vec!["response".to_string(), "total_size".to_string()],
+        });
+        let difference = PropertyOrUDF::Arithmetic(Arithmetic {
+            op: ArithmeticOp::Sub,
+            left: Box::new(b_size),
+            right: Box::new(a_size),
+        });
+
+        let mut visitor = FilterVisitor::default();
+        let _res = result.accept(&mut visitor);
+        assert_eq!(
+            visitor.expression_filters,
+            vec![ExpressionFilter {
+                left: difference.clone(),
+                op: CompareOp::Gt,
+                right: PropertyOrUDF::Literal("100".to_string()),
+            }]
+        );
+        // the other conjuncts are still attribute filters
+        assert_eq!(visitor.attr_filters.len(), 1);
+
+        let mut visitor = ReturnVisitor::default();
+        let _res = result.accept(&mut visitor);
+        let expected = PropertyOrUDF::Arithmetic(Arithmetic {
+            op: ArithmeticOp::Add,
+            left: Box::new(PropertyOrUDF::Arithmetic(Arithmetic {
+                op: ArithmeticOp::Mul,
+                left: Box::new(difference),
+                right: Box::new(PropertyOrUDF::Literal("2".to_string())),
+            })),
+            right: Box::new(PropertyOrUDF::Literal("-1".to_string())),
+        });
+        assert_eq!(
+            expected.to_ref_str(),
+            "((b.response.total_size - a.request.total_size) * 2) + -1"
+        );
+        assert_eq!(visitor.return_expr, IrReturnEnum::PropertyOrUDF(expected));
+    }
+
+    #[test]
+    fn test_return_expr() {
+        // regular return
+        let tf = CommonTokenFactory::default();
+        let result = run_parser(
+            &tf,
+            "MATCH (a) -[]-> (b)-[]->(c) WHERE height(a) = 2 RETURN a.request.total_size",
         );
         let mut return_visitor = ReturnVisitor::default();
         let _res = result.accept(&mut return_visitor);
+        assert!(
+            return_visitor.return_expr
+                == IrReturnEnum::PropertyOrUDF(PropertyOrUDF::Property({
+                    Property {
+                        parent: "a".to_string(),
+                        members: vec!["request".to_string(), "total_size".to_string()],
+                    }
+                }))
+        );
 
-        if let IrReturnEnum::Aggregate(agg) = return_visitor.return_expr {
+        // aggregation
+        let query_data = resolved_query(
+            "MATCH (a) -[]-> (b)-[]->(c) WHERE height(a) = 2 RETURN a.request.total_size, avg(a.request.total_size)",
+        )
+        .unwrap();
+        if let IrReturnEnum::Aggregate(aggs) = query_data.return_expr {
+            let agg = &aggs[0];
             assert!(agg.udf_reference.id == "avg".to_string());
             assert!(agg.udf_reference.args.len() == 1);
-            assert!(agg.udf_reference.args[0] == "a.request.total_size".to_string());
+            assert!(agg.udf_reference.args[0].to_ref_str() == "a.request.total_size".to_string());
 
             assert!(agg.args.len() == 1);
             if let PropertyOrUDF::Property(prop) = &agg.args[0] {
@@ -605,5 +2837,115 @@ mod tests {
                 "did not recognize aggregation function as aggregation"
             );
         }
+
+        // several columns
+        let tf = CommonTokenFactory::default();
+        let result = run_parser(
+            &tf,
+            "MATCH (a) -[]-> (b) RETURN a.request.total_size, height(a), b.response.code",
+        );
+        let mut return_visitor = ReturnVisitor::default();
+        let _res = result.accept(&mut return_visitor);
+        if let IrReturnEnum::Record(columns) = return_visitor.return_expr {
+            let names: Vec<&String> = columns.iter().map(|(name, _)| name).collect();
+            assert_eq!(
+                names,
+                vec!["a.request.total_size", "height(a)", "b.response.code"]
+            );
+        } else {
+            assert!(false, "did not recognize several return items as a record");
+        }
+
+        // aliases name the columns and the aggregation key
+        let tf = CommonTokenFactory::default();
+        let result = run_parser(
+            &tf,
+            "MATCH (a) -[]-> (b) RETURN height(a) AS depth, a.request.time AS start",
+        );
+        let mut return_visitor = ReturnVisitor::default();
+        let _res = result.accept(&mut return_visitor);
+        if let IrReturnEnum::Record(columns) = return_visitor.return_expr {
+            let names: Vec<&String> = columns.iter().map(|(name, _)| name).collect();
+            assert_eq!(names, vec!["depth", "start"]);
+        } else {
+            assert!(false, "did not recognize aliased return items as a record");
+        }
+
+        let query_data = resolved_query(
+            "MATCH (a) -[]-> (b) RETURN a.request.total_size, avg(a.request.total_size) AS mean_size",
+        )
+        .unwrap();
+        if let IrReturnEnum::Aggregate(aggs) = query_data.return_expr {
+            let agg = &aggs[0];
+            assert_eq!(agg.key(), "mean_size");
+        } else {
+            assert!(false, "did not recognize aliased aggregation");
+        }
+
+        // the other return items are the group key of the aggregation
+        let query_data = resolved_query(
+            "MATCH (a) -[]-> (b) RETURN a.node.metadata.WORKLOAD_NAME, b.response.code, avg(a.request.total_size)",
+        )
+        .unwrap();
+        if let IrReturnEnum::Aggregate(aggs) = query_data.return_expr {
+            let agg = &aggs[0];
+            let group_names: Vec<&String> = agg.group_by.iter().map(|(name, _)| name).collect();
+            assert_eq!(
+                group_names,
+                vec!["a.node.metadata.WORKLOAD_NAME", "b.response.code"]
+            );
+            assert_eq!(agg.args.len(), 1);
+            assert_eq!(agg.args[0].to_ref_str(), "a.request.total_size");
+        } else {
+            assert!(false, "did not recognize grouped aggregation");
+        }
+
+        // a scalar UDF next to another item is just one more column
+        let query_data =
+            resolved_query("MATCH (a) -[]-> (b) RETURN a.request.total_size, height(a)").unwrap();
+        assert!(matches!(query_data.return_expr, IrReturnEnum::Record(_)));
+
+        // several aggregations of the same UDF are told apart by their aliases
+        let query_data = resolved_query(
+            "MATCH (a) -[]-> (b) RETURN a.request.path, avg(a.request.total_size) AS p, avg(b.request.total_size) AS q",
+        )
+        .unwrap();
+        if let IrReturnEnum::Aggregate(aggs) = query_data.return_expr {
+            let keys: Vec<String> = aggs.iter().map(|agg| agg.key()).collect();
+            assert_eq!(keys, vec!["p", "q"]);
+            assert!(aggs.iter().all(|agg| agg.group_by.len() == 1));
+        } else {
+            assert!(false, "did not recognize both aggregations");
+        }
+        let err = resolved_query(
+            "MATCH (a) -[]-> (b) RETURN avg(a.request.total_size), avg(b.request.total_size)",
+        )
+        .unwrap_err();
+        assert!(err.message.contains("name one of them with AS"));
+
+        // the group comes first, and only the values of the trace are aggregated
+        let err =
+            resolved_query("MATCH (a) -[]-> (b) RETURN avg(a.request.total_size), a.request.path")
+                .unwrap_err();
+        assert!(err.message.contains("has to come before the aggregations"));
+        let err = resolved_query("MATCH (a) -[]-> (b) RETURN a.request.path, avg(1)").unwrap_err();
+        assert!(err.message.contains("can not be aggregated"));
+        assert!(err.span.is_some());
+        let err = resolved_query(
+            "MATCH (a) -[]-> (b) RETURN a.request.path, avg(a.request.total_size, b.request.total_size)",
+        )
+        .unwrap_err();
+        assert!(err.message.contains("exactly one value"));
+
+        // a lone aggregation aggregates over every match
+        let query_data =
+            resolved_query("MATCH (a) -[]-> (b) RETURN avg(a.request.total_size)").unwrap();
+        if let IrReturnEnum::Aggregate(aggs) = query_data.return_expr {
+            assert_eq!(aggs.len(), 1);
+            assert!(aggs[0].group_by.is_empty());
+            assert_eq!(aggs[0].alias, None);
+        } else {
+            assert!(false, "did not recognize lone aggregation");
+        }
     }
 }
@@ -9,10 +9,14 @@ use test_case::test_case; // Parametrized tests
 #[test_case("height.cql", vec!["height.rs"]; "height")]
 #[test_case("height_avg.cql", vec!["height.rs", "avg.rs"]; "height_avg")]
 #[test_case("histogram.cql", vec!["histogram.rs"]; "inconclusive - histogram")]
+#[test_case("optional_match.cql", vec![]; "optional_match")]
+#[test_case("optional_match_avg.cql", vec!["avg.rs"]; "optional_match_avg")]
 #[test_case("request_size.cql", vec![]; "request_size")]
 #[test_case("request_size_avg.cql", vec!["avg.rs"]; "request_size_avg")]
+#[test_case("request_size_avg_by_workload.cql", vec!["avg.rs"]; "request_size_avg_by_workload")]
 #[test_case("request_size_avg_trace_attr.cql", vec!["avg.rs"]; "request_size_avg_trace_attr")]
 #[test_case("request_time.cql", vec![]; "request_time")]
+#[test_case("unconnected_parts.cql", vec![]; "unconnected_parts")]
 #[test_case("latency.cql", vec!["latency.rs"]; "inconclusive - latency")]
 fn check_compilation_envoy(
     query_name: &str,
@@ -68,10 +72,14 @@ fn check_compilation_envoy(
 #[test_case("height.cql", vec!["height.rs"]; "height")]
 #[test_case("height_avg.cql", vec!["height.rs", "avg.rs"]; "height_avg")]
 #[test_case("histogram.cql", vec!["histogram.rs"]; "inconclusive - histogram")]
+#[test_case("optional_match.cql", vec![]; "optional_match")]
+#[test_case("optional_match_avg.cql", vec!["avg.rs"]; "optional_match_avg")]
 #[test_case("request_size.cql", vec![]; "request_size")]
 #[test_case("request_size_avg.cql", vec!["avg.rs"]; "request_size_avg")]
+#[test_case("request_size_avg_by_workload.cql", vec!["avg.rs"]; "request_size_avg_by_workload")]
 #[test_case("request_size_avg_trace_attr.cql", vec!["avg.rs"]; "request_size_avg_trace_attr")]
 #[test_case("request_time.cql", vec![]; "request_time")]
+#[test_case("unconnected_parts.cql", vec![]; "unconnected_parts")]
 #[test_case("latency.cql", vec!["latency.rs"]; "inconclusive - latency")]
 fn check_compilation_sim(
     query_name: &str,
@@ -122,3 +130,59 @@ fn check_compilation_sim(
     }
     Ok(())
 }
+
+#[test]
+fn check_emit_ir() -> Result<(), Box<dyn std::error::Error>> {
+    let proj_dir = Path::new("");
+    let query_file = proj_dir.join("example_queries/request_size_avg.cql");
+    let udf_file = proj_dir.join("example_udfs/avg.rs");
+    let ir_file = std::env::temp_dir().join("snicket_request_size_avg.json");
+    let mut cmd = Command::new(proj_dir.join("target/debug/snicket"));
+    cmd.args(vec![
+        "-q",
+        query_file.to_str().unwrap(),
+        "-u",
+        udf_file.to_str().unwrap(),
+        "--emit-ir",
+        ir_file.to_str().unwrap(),
+        "--root-node",
+        "productpage-v1",
+    ]);
+    cmd.assert().success();
+
+    let ir: serde_json::Value = serde_json::from_str(&fs::read_to_string(ir_file)?)?;
+    assert_eq!(ir["ir"]["root_id"], "productpage-v1");
+    assert_eq!(
+        ir["ir"]["struct_filters"][0]["vertices"],
+        serde_json::json!(["a", "b", "c"])
+    );
+    assert_eq!(
+        ir["ir"]["attr_filters"][0]["Attribute"]["value"]["Str"],
+        "reviews-v1"
+    );
+    assert_eq!(
+        ir["ir"]["return_expr"]["Aggregate"][0]["udf_reference"]["id"],
+        "avg"
+    );
+    assert!(ir["id_to_property"]["request.total_size"].is_u64());
+    assert_eq!(ir["aggregation_udf_table"]["avg"]["id"], "avg");
+    Ok(())
+}
+
+#[test]
+fn check_fmt() -> Result<(), Box<dyn std::error::Error>> {
+    let proj_dir = Path::new("");
+    let query_file = std::env::temp_dir().join("snicket_fmt.cql");
+    fs::write(
+        &query_file,
+        "match (a) -[]-> (b {})-->(c) where b.node.metadata.WORKLOAD_NAME = \"reviews-v1\" return a.request.total_size",
+    )?;
+    let mut cmd = Command::new(proj_dir.join("target/debug/snicket"));
+    cmd.args(vec!["fmt", query_file.to_str().unwrap()]);
+    cmd.assert().success();
+    assert_eq!(
+        fs::read_to_string(&query_file)?,
+        "MATCH (a)-[]->(b)-[]->(c) WHERE b.node.metadata.WORKLOAD_NAME = 'reviews-v1' RETURN a.request.total_size\n"
+    );
+    Ok(())
+}